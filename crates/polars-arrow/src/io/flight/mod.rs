@@ -31,6 +31,7 @@ pub fn serialize_batch(
     let mut dictionary_tracker = DictionaryTracker {
         dictionaries: Default::default(),
         cannot_replace: false,
+        emit_deltas: false,
     };
 
     let (encoded_dictionaries, encoded_batch) =
@@ -64,6 +64,7 @@ impl<R: Read + Seek + Write> FileWriter<R> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries,
                 cannot_replace: true,
+                emit_deltas: false,
             },
             encoded_message: Default::default(),
         })
@@ -42,8 +42,8 @@ pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<
 
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
-pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<()> {
-    zstd::stream::copy_encode(input_buf, output_buf, 0).map_err(|e| e.into())
+pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>, level: i32) -> PolarsResult<()> {
+    zstd::stream::copy_encode(input_buf, output_buf, level).map_err(|e| e.into())
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
@@ -52,7 +52,7 @@ pub fn compress_lz4(_input_buf: &[u8], _output_buf: &[u8]) -> PolarsResult<()> {
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn compress_zstd(_input_buf: &[u8], _output_buf: &[u8]) -> PolarsResult<()> {
+pub fn compress_zstd(_input_buf: &[u8], _output_buf: &[u8], _level: i32) -> PolarsResult<()> {
     panic!("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.")
 }
 
@@ -66,7 +66,7 @@ mod tests {
     fn round_trip_zstd() {
         let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
         let mut buffer = vec![];
-        compress_zstd(&data, &mut buffer).unwrap();
+        compress_zstd(&data, &mut buffer, 0).unwrap();
 
         let mut result = vec![0; 200];
         decompress_zstd(&buffer, &mut result).unwrap();
@@ -53,6 +53,17 @@ pub fn get_row_count<R: Read + Seek>(reader: &mut R) -> PolarsResult<i64> {
         .sum()
 }
 
+/// Read the number of rows of the record batch in `block`.
+pub fn get_record_batch_length<R: Read + Seek>(
+    reader: &mut R,
+    block: &arrow_format::ipc::Block,
+    message_scratch: &mut Vec<u8>,
+) -> PolarsResult<i64> {
+    let message = get_message_from_block(reader, block, message_scratch)?;
+    let record_batch = get_record_batch(message)?;
+    record_batch.length().map_err(|e| e.into())
+}
+
 pub(crate) fn get_dictionary_batch<'a>(
     message: &'a arrow_format::ipc::MessageRef,
 ) -> PolarsResult<arrow_format::ipc::DictionaryBatchRef<'a>> {
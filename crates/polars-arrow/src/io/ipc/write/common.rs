@@ -18,8 +18,8 @@ use crate::record_batch::RecordBatch;
 pub enum Compression {
     /// LZ4 (framed)
     LZ4,
-    /// ZSTD
-    ZSTD,
+    /// ZSTD, with a compression level between 1 and 22, or 0 for the default level
+    ZSTD(i32),
 }
 
 /// Options declaring the behaviour of writing to IPC
@@ -45,7 +45,7 @@ fn encode_dictionary(
             let dict_id = field.dictionary_id
                 .ok_or_else(|| polars_err!(InvalidOperation: "Dictionaries must have an associated id"))?;
 
-            let emit = dictionary_tracker.insert(dict_id, array)?;
+            let update = dictionary_tracker.insert(dict_id, array)?;
 
             let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
             let values = array.values();
@@ -56,13 +56,33 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
-                    dict_id,
-                    array,
-                    options,
-                    is_native_little_endian(),
-                ));
+            match update {
+                DictionaryUpdate::Unchanged => {},
+                DictionaryUpdate::Replace => {
+                    encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
+                        dict_id,
+                        array,
+                        false,
+                        options,
+                        is_native_little_endian(),
+                    ));
+                },
+                DictionaryUpdate::Delta(offset) => {
+                    // Only the values are written, so the delta needs no keys.
+                    let values = values.sliced(offset, values.len() - offset);
+                    let delta = DictionaryArray::<$T>::try_new(
+                        array.data_type().clone(),
+                        PrimitiveArray::from_vec(vec![]),
+                        values,
+                    )?;
+                    encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
+                        dict_id,
+                        &delta,
+                        true,
+                        options,
+                        is_native_little_endian(),
+                    ));
+                },
             };
             Ok(())
         }),
@@ -219,7 +239,7 @@ fn serialize_compression(
     if let Some(compression) = compression {
         let codec = match compression {
             Compression::LZ4 => arrow_format::ipc::CompressionType::Lz4Frame,
-            Compression::ZSTD => arrow_format::ipc::CompressionType::Zstd,
+            Compression::ZSTD(_) => arrow_format::ipc::CompressionType::Zstd,
         };
         Some(Box::new(arrow_format::ipc::BodyCompression {
             codec,
@@ -345,10 +365,11 @@ fn chunk_to_bytes_amortized(
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
-/// other for the data
+/// other for the data. A delta dictionary batch appends its values to the dictionary with the same id.
 fn dictionary_batch_to_bytes<K: DictionaryKey>(
     dict_id: i64,
     array: &DictionaryArray<K>,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -389,7 +410,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     compression,
                     variadic_buffer_counts,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -405,25 +426,44 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     }
 }
 
+/// Whether and how a dictionary has to be written, as returned by [`DictionaryTracker::insert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryUpdate {
+    /// The same dictionary was written already.
+    Unchanged,
+    /// The dictionary has to be written, replacing the dictionary with the same ID if any.
+    Replace,
+    /// The dictionary extends the dictionary that was written already, so only its values
+    /// from the given offset have to be written, as a delta dictionary.
+    Delta(usize),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
 pub struct DictionaryTracker {
     pub dictionaries: Dictionaries,
     pub cannot_replace: bool,
+    /// Whether a dictionary that extends the dictionary that was written already is written
+    /// as a delta, which only the stream format allows.
+    pub emit_deltas: bool,
 }
 
 impl DictionaryTracker {
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
-    /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
-    ///   that the dictionary was not actually inserted (because it's already been seen).
+    /// * If this ID has been written already and has the same data, return
+    ///   [`DictionaryUpdate::Unchanged`] to indicate that the dictionary was not actually
+    ///   inserted (because it's already been seen).
+    /// * If this ID has been written already, the tracker is configured to emit deltas and the
+    ///   values that were written are a prefix of the new values, return
+    ///   [`DictionaryUpdate::Delta`] with the number of values that were written.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
-    ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
-    ///   inserted.
-    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> PolarsResult<bool> {
+    ///   has never been seen before, return [`DictionaryUpdate::Replace`] to indicate that the
+    ///   dictionary was just inserted.
+    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> PolarsResult<DictionaryUpdate> {
         let values = match array.data_type() {
             ArrowDataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -441,7 +481,14 @@ impl DictionaryTracker {
         if let Some(last) = self.dictionaries.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(DictionaryUpdate::Unchanged);
+            } else if self.emit_deltas
+                && values.len() > last.len()
+                && values.sliced(0, last.len()).as_ref() == last.as_ref()
+            {
+                let offset = last.len();
+                self.dictionaries.insert(dict_id, values.clone());
+                return Ok(DictionaryUpdate::Delta(offset));
             } else if self.cannot_replace {
                 polars_bail!(InvalidOperation:
                     "Dictionary replacement detected when writing IPC file format. \
@@ -452,7 +499,7 @@ impl DictionaryTracker {
         };
 
         self.dictionaries.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(DictionaryUpdate::Replace)
     }
 }
 
@@ -60,6 +60,7 @@ where
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: true,
+                emit_deltas: false,
             },
             record_blocks: vec![],
             dictionary_blocks: vec![],
@@ -44,11 +44,20 @@ impl<W: Write> StreamWriter<W> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: false,
+                emit_deltas: false,
             },
             ipc_fields: None,
         }
     }
 
+    /// Write a dictionary that extends the dictionary that was written already, i.e. of which
+    /// the values that were written are a prefix, as a delta dictionary with only its new values.
+    /// Otherwise the dictionary replaces the earlier one. Defaults to `false`.
+    pub fn with_delta_dictionaries(mut self, emit_deltas: bool) -> Self {
+        self.dictionary_tracker.emit_deltas = emit_deltas;
+        self
+    }
+
     /// Starts the stream by writing a Schema message to it.
     /// Use `ipc_fields` to declare dictionary ids in the schema, for dictionary-reuse
     pub fn start(
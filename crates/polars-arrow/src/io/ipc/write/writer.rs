@@ -81,6 +81,7 @@ impl<W: Write> FileWriter<W> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: true,
+                emit_deltas: false,
             },
             encoded_message: Default::default(),
         }
@@ -138,7 +138,7 @@ impl CategoricalChunkedBuilder {
         // We will create a mapping from our local categoricals to global categoricals
         // and a mapping from global categoricals to our local categoricals.
         let mut local_to_global: Vec<u32> = Vec::with_capacity(categories.len());
-        let (id, local_to_global) = crate::STRING_CACHE.apply(|cache| {
+        let (id, local_to_global) = crate::active_string_cache().apply(|cache| {
             for (s, h) in categories.values_iter().zip(hashes) {
                 // SAFETY: we allocated enough.
                 unsafe { local_to_global.push_unchecked(cache.insert_from_hash(h, s)) }
@@ -223,7 +223,7 @@ impl CategoricalChunked {
         cats: UInt32Chunked,
         ordering: CategoricalOrdering,
     ) -> PolarsResult<CategoricalChunked> {
-        let len = crate::STRING_CACHE.read_map().len() as u32;
+        let len = crate::active_string_cache().read_map().len() as u32;
         let oob = cats.into_iter().flatten().any(|cat| cat >= len);
         polars_ensure!(
             !oob,
@@ -242,7 +242,8 @@ impl CategoricalChunked {
         cats: UInt32Chunked,
         ordering: CategoricalOrdering,
     ) -> CategoricalChunked {
-        let cache = crate::STRING_CACHE.read_map();
+        let string_cache = crate::active_string_cache();
+        let cache = string_cache.read_map();
 
         let cap = std::cmp::min(std::cmp::min(cats.len(), cache.len()), _HASHMAP_INIT_SIZE);
         let mut rev_map = PlHashMap::with_capacity(cap);
@@ -279,7 +280,7 @@ impl CategoricalChunked {
     ) -> Self {
         // Vec<u32> where the index is local and the value is the global index
         let mut local_to_global: Vec<u32> = Vec::with_capacity(values.len());
-        let (id, local_to_global) = crate::STRING_CACHE.apply(|cache| {
+        let (id, local_to_global) = crate::active_string_cache().apply(|cache| {
             // locally we don't need a hashmap because we all categories are 1 integer apart
             // so the index is local, and the values is global
             for s in values.values_iter() {
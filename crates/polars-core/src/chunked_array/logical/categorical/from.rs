@@ -1,8 +1,31 @@
+use arrow::array::DictionaryKey;
 use arrow::compute::cast::{cast, utf8view_to_utf8, CastOptions};
 use arrow::datatypes::IntegerType;
 
 use super::*;
 
+fn narrow_keys<K: DictionaryKey>(arr: &DictionaryArray<u32>) -> ArrayRef {
+    let keys = cast(
+        arr.keys(),
+        &ArrowDataType::from(K::KEY_TYPE),
+        CastOptions::unchecked(),
+    )
+    .unwrap();
+    let keys = keys
+        .as_any()
+        .downcast_ref::<PrimitiveArray<K>>()
+        .unwrap()
+        .clone();
+    let ArrowDataType::Dictionary(_, values_dtype, is_sorted) = arr.data_type() else {
+        unreachable!()
+    };
+    let dtype = ArrowDataType::Dictionary(K::KEY_TYPE, values_dtype.clone(), *is_sorted);
+    // SAFETY:
+    // the caller ensures the number of values fits in `K`, so the keys are in bounds
+    unsafe { DictionaryArray::try_new_unchecked(dtype, keys, arr.values().clone()).unwrap() }
+        .boxed()
+}
+
 fn convert_values(arr: &Utf8ViewArray, pl_flavor: bool) -> ArrayRef {
     if pl_flavor {
         arr.clone().boxed()
@@ -20,6 +43,59 @@ impl CategoricalChunked {
         }
     }
 
+    /// Convert to an arrow dictionary with the narrowest key type that can index all categories.
+    ///
+    /// Low-cardinality columns get `u8` or `u16` keys, which shrinks the keys buffer on export and
+    /// in IPC files. Dictionaries with narrow keys are widened to `u32` again on import.
+    ///
+    /// This only narrows the exported keys: the physical codes of categoricals are always `u32`
+    /// in memory, as all kernels on categoricals operate on their `u32` codes.
+    pub fn to_arrow_compact(&self, pl_flavor: bool) -> ArrayRef {
+        let key_type = Self::compact_key_type(self.get_rev_map().len());
+        Self::narrow_arrow_keys(&self.to_u32(pl_flavor), key_type)
+    }
+
+    /// The narrowest key type of an arrow dictionary that can index `n_categories` categories.
+    pub fn compact_key_type(n_categories: usize) -> IntegerType {
+        if n_categories <= u8::MAX as usize + 1 {
+            IntegerType::UInt8
+        } else if n_categories <= u16::MAX as usize + 1 {
+            IntegerType::UInt16
+        } else {
+            IntegerType::UInt32
+        }
+    }
+
+    /// Narrow the `u32` keys of `arr`, a dictionary of [`CategoricalChunked::to_arrow`], to
+    /// `key_type`.
+    ///
+    /// # Panics
+    /// Panics if `key_type` is a signed or 64-bit type, or if it can't index all values of `arr`.
+    pub fn narrow_arrow_keys(arr: &dyn Array, key_type: IntegerType) -> ArrayRef {
+        let arr = arr
+            .as_any()
+            .downcast_ref::<DictionaryArray<u32>>()
+            .expect("categorical dictionaries have u32 keys");
+        assert!(
+            arr.values().len() <= Self::max_categories(key_type),
+            "the keys of the dictionary don't fit in {key_type:?}"
+        );
+        match key_type {
+            IntegerType::UInt8 => narrow_keys::<u8>(arr),
+            IntegerType::UInt16 => narrow_keys::<u16>(arr),
+            IntegerType::UInt32 => arr.clone().boxed(),
+            _ => panic!("categorical dictionaries can't have {key_type:?} keys"),
+        }
+    }
+
+    fn max_categories(key_type: IntegerType) -> usize {
+        match key_type {
+            IntegerType::UInt8 => u8::MAX as usize + 1,
+            IntegerType::UInt16 => u16::MAX as usize + 1,
+            _ => usize::MAX,
+        }
+    }
+
     fn to_u32(&self, pl_flavor: bool) -> DictionaryArray<u32> {
         let values_dtype = if pl_flavor {
             ArrowDataType::Utf8View
@@ -24,6 +24,10 @@ bitflags! {
     }
 }
 
+/// A categorical or enum column: `u32` codes that index the categories of its [`RevMapping`].
+///
+/// The codes are `u32` regardless of the number of categories. Low-cardinality columns can be
+/// exported and written with narrower keys with [`CategoricalChunked::to_arrow_compact`].
 #[derive(Clone)]
 pub struct CategoricalChunked {
     physical: Logical<CategoricalType, UInt32Type>,
@@ -35,8 +39,10 @@ pub struct CategoricalChunked {
 
 impl CategoricalChunked {
     pub(crate) fn field(&self) -> Field {
-        let name = self.physical().name();
-        Field::new(name, self.dtype().clone())
+        let physical = self.physical().ref_field();
+        let mut field = Field::new(physical.name(), self.dtype().clone());
+        field.metadata = physical.metadata.clone();
+        field
     }
 
     pub fn is_empty(&self) -> bool {
@@ -438,6 +444,8 @@ impl<'a> ExactSizeIterator for CatIter<'a> {}
 
 #[cfg(test)]
 mod test {
+    use arrow::datatypes::IntegerType;
+
     use super::*;
     use crate::{disable_string_cache, enable_string_cache, SINGLE_LOCK};
 
@@ -466,6 +474,36 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_categorical_compact_keys() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        let ca = StringChunked::new("a", &[Some("foo"), None, Some("bar")]);
+        let ca = ca.cast(&DataType::Categorical(None, Default::default()))?;
+        let ca = ca.categorical().unwrap();
+
+        let arr = ca.to_arrow_compact(true);
+        assert!(matches!(
+            arr.data_type(),
+            ArrowDataType::Dictionary(IntegerType::UInt8, _, _)
+        ));
+        // Narrow keys are only used on export, the codes in memory are `u32`.
+        let s = Series::try_from(("a", arr))?;
+        assert_eq!(s.categorical()?.physical().dtype(), &DataType::UInt32);
+        assert_eq!(s.cast(&DataType::String)?, ca.cast(&DataType::String)?);
+
+        let values = (0..300).map(|i| i.to_string()).collect::<Vec<_>>();
+        let ca = StringChunked::from_iter_values("a", values.iter().map(|s| s.as_str()));
+        let ca = ca.cast(&DataType::Categorical(None, Default::default()))?;
+        let arr = ca.categorical().unwrap().to_arrow_compact(true);
+        assert!(matches!(
+            arr.data_type(),
+            ArrowDataType::Dictionary(IntegerType::UInt16, _, _)
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_append_categorical() {
         let _lock = SINGLE_LOCK.lock();
@@ -485,6 +523,32 @@ mod test {
         assert_eq!(appended.str_value(5).unwrap(), "y");
     }
 
+    #[test]
+    fn test_string_cache_scope() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        let cast = |values: &[&str]| {
+            Series::new("a", values).cast(&DataType::Categorical(None, Default::default()))
+        };
+
+        let (mut s1, s2) = {
+            let _scope = crate::StringCacheScope::new();
+            assert!(crate::using_string_cache());
+            (cast(&["a", "b"])?, cast(&["b", "c"])?)
+        };
+        assert!(!crate::using_string_cache());
+        // The categories of a scope are shared within the scope.
+        s1.append(&s2)?;
+        assert_eq!(s1.str_value(3)?, "c");
+
+        let s3 = {
+            let _scope = crate::StringCacheScope::new();
+            cast(&["a"])?
+        };
+        assert!(s1.append(&s3).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_fast_unique() {
         let _lock = SINGLE_LOCK.lock();
@@ -47,8 +47,7 @@ impl Default for RevMapping {
         let slice: &[Option<&str>] = &[];
         let cats = Utf8ViewArray::from_slice(slice);
         if using_string_cache() {
-            let cache = &mut crate::STRING_CACHE.lock_map();
-            let id = cache.uuid;
+            let id = crate::active_string_cache().read_map().uuid;
             RevMapping::Global(Default::default(), cats, id)
         } else {
             RevMapping::build_local(cats)
@@ -151,7 +150,8 @@ impl RevMapping {
             Self::Global(rev_map, a, id) => {
                 // fast path is check
                 if using_string_cache() {
-                    let map = crate::STRING_CACHE.read_map();
+                    let cache = crate::active_string_cache();
+                    let map = cache.read_map();
                     if map.uuid == *id {
                         return map.get_cat(value);
                     }
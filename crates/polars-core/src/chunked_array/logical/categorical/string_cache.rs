@@ -1,6 +1,9 @@
+use std::cell::RefCell;
 use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use ahash::RandomState;
 use hashbrown::hash_map::RawEntryMut;
@@ -16,6 +19,11 @@ static STRING_CACHE_REFCOUNT: Mutex<u32> = Mutex::new(0);
 static STRING_CACHE_ENABLED_GLOBALLY: AtomicBool = AtomicBool::new(false);
 static STRING_CACHE_UUID_CTR: AtomicU32 = AtomicU32::new(0);
 
+thread_local! {
+    /// The string cache of the innermost [`StringCacheScope`] of this thread.
+    static SCOPED_STRING_CACHE: RefCell<Option<Arc<StringCache>>> = const { RefCell::new(None) };
+}
+
 /// Enable the global string cache as long as the object is alive ([RAII]).
 ///
 /// # Examples
@@ -62,6 +70,90 @@ impl Drop for StringCacheHolder {
     }
 }
 
+/// Use a string cache of its own on this thread as long as the object is alive ([RAII]).
+///
+/// [`Categorical`] columns created in the scope share their categories, like columns created
+/// under the global string cache, but they don't share them with columns created outside of
+/// the scope or in other scopes. Queries that run on different threads can thus each use
+/// their own string cache, instead of contending on the global string cache, and the
+/// categories of a scope are dropped with the scope instead of growing the global string
+/// cache for the lifetime of the process.
+///
+/// The scope only applies to the thread that created it, and nested scopes replace the outer
+/// scope until they are dropped. Work that is run on other threads, such as the thread pool,
+/// has to enter the scope with [`StringCacheScope::enter`] to use its string cache. The lazy
+/// engine does so for the queries that are collected in a scope.
+///
+/// # Examples
+///
+/// ```
+/// use polars_core::StringCacheScope;
+///
+/// let _scope = StringCacheScope::new();
+/// ```
+///
+/// [`Categorical`]: crate::datatypes::DataType::Categorical
+/// [RAII]: https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization
+pub struct StringCacheScope {
+    previous: Option<Arc<StringCache>>,
+    // The scope belongs to the thread that created it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Default for StringCacheScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StringCacheScope {
+    /// Start a scope with an empty string cache.
+    pub fn new() -> StringCacheScope {
+        Self::enter_cache(Some(Arc::new(StringCache::default())))
+    }
+
+    /// The string cache of the innermost scope of this thread, if any.
+    pub fn current() -> Option<ScopedStringCache> {
+        SCOPED_STRING_CACHE.with(|scoped| scoped.borrow().clone().map(ScopedStringCache))
+    }
+
+    /// Start a scope on this thread with the string cache of a scope of another thread, so
+    /// that the work this thread does for it shares its categories. With `None`, this thread
+    /// uses the global string cache until the scope is dropped, like a thread without scope.
+    ///
+    /// Threads of the thread pool run the work of other threads while they wait, so work that
+    /// runs on the thread pool enters the scope of the thread it is done for, or `None`.
+    pub fn enter(cache: Option<&ScopedStringCache>) -> StringCacheScope {
+        Self::enter_cache(cache.map(|cache| cache.0.clone()))
+    }
+
+    fn enter_cache(cache: Option<Arc<StringCache>>) -> StringCacheScope {
+        let previous = SCOPED_STRING_CACHE.with(|scoped| scoped.replace(cache));
+        StringCacheScope {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// The string cache of a [`StringCacheScope`], which can be sent to other threads to enter the
+/// scope there with [`StringCacheScope::enter`].
+#[derive(Clone)]
+pub struct ScopedStringCache(Arc<StringCache>);
+
+impl std::fmt::Debug for ScopedStringCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ScopedStringCache")
+    }
+}
+
+impl Drop for StringCacheScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        SCOPED_STRING_CACHE.with(|scoped| scoped.replace(previous));
+    }
+}
+
 fn increment_string_cache_refcount() {
     let mut refcount = STRING_CACHE_REFCOUNT.lock().unwrap();
     *refcount += 1;
@@ -103,8 +195,12 @@ pub fn disable_string_cache() {
     }
 }
 
-/// Check whether the global string cache is enabled.
+/// Check whether a string cache is used on this thread, either the global string cache or the
+/// string cache of a [`StringCacheScope`].
 pub fn using_string_cache() -> bool {
+    if SCOPED_STRING_CACHE.with(|scoped| scoped.borrow().is_some()) {
+        return true;
+    }
     let refcount = STRING_CACHE_REFCOUNT.lock().unwrap();
     *refcount > 0
 }
@@ -241,7 +337,7 @@ impl StringCache {
     where
         F: FnOnce(&mut RwLockWriteGuard<SCacheInner>) -> T,
     {
-        let cache = &mut crate::STRING_CACHE.lock_map();
+        let cache = &mut self.lock_map();
 
         let result = fun(cache);
 
@@ -255,4 +351,30 @@ impl StringCache {
 
 pub(crate) static STRING_CACHE: Lazy<StringCache> = Lazy::new(Default::default);
 
+/// The string cache that is used on this thread.
+pub(crate) enum ActiveStringCache {
+    Global,
+    Scoped(Arc<StringCache>),
+}
+
+impl Deref for ActiveStringCache {
+    type Target = StringCache;
+
+    fn deref(&self) -> &StringCache {
+        match self {
+            ActiveStringCache::Global => &STRING_CACHE,
+            ActiveStringCache::Scoped(cache) => cache,
+        }
+    }
+}
+
+/// The string cache of the innermost [`StringCacheScope`] of this thread, or else the global
+/// string cache.
+pub(crate) fn active_string_cache() -> ActiveStringCache {
+    SCOPED_STRING_CACHE.with(|scoped| match scoped.borrow().as_ref() {
+        Some(cache) => ActiveStringCache::Scoped(cache.clone()),
+        None => ActiveStringCache::Global,
+    })
+}
+
 type StrHashGlobal = SmartString<LazyCompact>;
@@ -92,7 +92,9 @@ where
     Self: LogicalType,
 {
     pub fn field(&self) -> Field {
-        let name = self.0.ref_field().name();
-        Field::new(name, LogicalType::dtype(self).clone())
+        let physical = self.0.ref_field();
+        let mut field = Field::new(physical.name(), LogicalType::dtype(self).clone());
+        field.metadata = physical.metadata.clone();
+        field
     }
 }
@@ -272,6 +272,11 @@ impl StructChunked {
         self.field.set_name(name.into())
     }
 
+    /// Set the key/value metadata of the field of this [`StructChunked`].
+    pub fn set_metadata(&mut self, metadata: Option<Arc<FieldMetadata>>) {
+        self.field.metadata = metadata;
+    }
+
     pub(crate) fn try_apply_fields<F>(&self, func: F) -> PolarsResult<Self>
     where
         F: Fn(&Series) -> PolarsResult<Series>,
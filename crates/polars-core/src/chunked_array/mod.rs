@@ -410,7 +410,9 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     }
 
     pub(crate) unsafe fn set_dtype(&mut self, dtype: DataType) {
-        self.field = Arc::new(Field::new(self.name(), dtype))
+        let mut field = Field::new(self.name(), dtype);
+        field.metadata = self.field.metadata.clone();
+        self.field = Arc::new(field)
     }
 
     /// Name of the [`ChunkedArray`].
@@ -423,9 +425,16 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         &self.field
     }
 
-    /// Rename this [`ChunkedArray`].
+    /// Rename this [`ChunkedArray`]. The metadata of its field is kept.
     pub fn rename(&mut self, name: &str) {
-        self.field = Arc::new(Field::new(name, self.field.data_type().clone()))
+        let mut field = Field::new(name, self.field.data_type().clone());
+        field.metadata = self.field.metadata.clone();
+        self.field = Arc::new(field)
+    }
+
+    /// Set the key/value metadata of the field of this [`ChunkedArray`].
+    pub fn set_metadata(&mut self, metadata: Option<Arc<FieldMetadata>>) {
+        Arc::make_mut(&mut self.field).metadata = metadata;
     }
 
     /// Return this [`ChunkedArray`] with a new name.
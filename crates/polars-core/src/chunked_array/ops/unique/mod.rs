@@ -1,11 +1,19 @@
 use std::hash::Hash;
 
+use ahash::RandomState;
 use arrow::bitmap::MutableBitmap;
+use polars_utils::hashing::hash_to_partition;
 use polars_utils::total_ord::{ToTotalOrd, TotalHash};
+use rayon::prelude::*;
 
 use crate::hashing::_HASHMAP_INIT_SIZE;
 use crate::prelude::*;
 use crate::series::IsSorted;
+use crate::utils::_split_offsets;
+use crate::POOL;
+
+/// Arrays shorter than this find their first occurrences on a single thread.
+const PARALLEL_ARG_UNIQUE_THRESHOLD: usize = 1 << 16;
 
 fn finish_is_unique_helper(
     unique_idx: Vec<IdxSize>,
@@ -79,6 +87,67 @@ macro_rules! arg_unique_ca {
     }};
 }
 
+/// Find the first occurrences in `$ca` on `$n_partitions` threads.
+///
+/// Every contiguous part is deduplicated on its own first, and its candidates are routed
+/// to a partition by hash, like the keys of a hash group-by. The first occurrence of a
+/// value is its first occurrence in the earliest part that holds it, so every partition
+/// deduplicates its candidates of all parts once more, in the order of the parts, and the
+/// indices that survive are sorted back in order.
+macro_rules! arg_unique_par_ca {
+    ($ca:expr, $n_partitions:expr) => {{
+        let ca = $ca;
+        let n_partitions: usize = $n_partitions;
+        let random_state = RandomState::new();
+        // The candidates of every part, per partition.
+        let candidates: Vec<Vec<Vec<IdxSize>>> = POOL.install(|| {
+            _split_offsets(ca.len(), n_partitions)
+                .into_par_iter()
+                .map(|(offset, len)| {
+                    let part = ca.slice(offset as i64, len);
+                    let mut set = PlHashSet::new();
+                    let mut partitions = vec![vec![]; n_partitions];
+                    for (idx, val) in part.iter().enumerate() {
+                        let val = val.to_total_ord();
+                        let partition =
+                            hash_to_partition(random_state.hash_one(&val), n_partitions);
+                        if set.insert(val) {
+                            partitions[partition].push((offset + idx) as IdxSize);
+                        }
+                    }
+                    partitions
+                })
+                .collect()
+        });
+        let unique: Vec<Vec<IdxSize>> = POOL.install(|| {
+            (0..n_partitions)
+                .into_par_iter()
+                .map(|partition| {
+                    let mut set = PlHashSet::new();
+                    candidates
+                        .iter()
+                        .flat_map(|part| &part[partition])
+                        .filter_map(|&idx| {
+                            // SAFETY: the candidates are indices into `ca`.
+                            let val = unsafe { ca.get_unchecked(idx as usize) };
+                            set.insert(val.to_total_ord()).then_some(idx)
+                        })
+                        .collect()
+                })
+                .collect()
+        });
+        let mut unique = unique.into_iter().flatten().collect::<Vec<_>>();
+        POOL.install(|| unique.par_sort_unstable());
+        unique
+    }};
+}
+
+/// The number of threads to find first occurrences on, if it is worth it.
+fn arg_unique_partitions(len: usize) -> Option<usize> {
+    let n_threads = POOL.current_num_threads();
+    (len >= PARALLEL_ARG_UNIQUE_THRESHOLD && n_threads > 1).then_some(n_threads)
+}
+
 impl<T> ChunkUnique for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -127,7 +196,11 @@ where
     }
 
     fn arg_unique(&self) -> PolarsResult<IdxCa> {
-        Ok(IdxCa::from_vec(self.name(), arg_unique_ca!(self)))
+        let idx = match arg_unique_partitions(self.len()) {
+            Some(n_partitions) => arg_unique_par_ca!(self, n_partitions),
+            None => arg_unique_ca!(self),
+        };
+        Ok(IdxCa::from_vec(self.name(), idx))
     }
 
     fn n_unique(&self) -> PolarsResult<usize> {
@@ -215,7 +288,11 @@ impl ChunkUnique for BinaryChunked {
     }
 
     fn arg_unique(&self) -> PolarsResult<IdxCa> {
-        Ok(IdxCa::from_vec(self.name(), arg_unique_ca!(self)))
+        let idx = match arg_unique_partitions(self.len()) {
+            Some(n_partitions) => arg_unique_par_ca!(self, n_partitions),
+            None => arg_unique_ca!(self),
+        };
+        Ok(IdxCa::from_vec(self.name(), idx))
     }
 
     fn n_unique(&self) -> PolarsResult<usize> {
@@ -256,7 +333,7 @@ impl ChunkUnique for BooleanChunked {
 
 #[cfg(test)]
 mod test {
-    use crate::prelude::*;
+    use super::*;
 
     #[test]
     fn unique() {
@@ -290,4 +367,16 @@ mod test {
             vec![Some(0), Some(1), Some(4)]
         );
     }
+
+    #[test]
+    fn arg_unique_partitioned() {
+        let ca = Int32Chunked::from_iter((0..1000).map(|i| (i % 7 != 0).then_some(i % 37)));
+        let expected = ca.arg_unique().unwrap();
+        assert_eq!(arg_unique_par_ca!(&ca, 4), expected.cont_slice().unwrap());
+
+        let ca = StringChunked::from_iter((0..1000).map(|i| Some(format!("{}", (i * 13) % 101))));
+        let ca = ca.as_binary();
+        let expected = ca.arg_unique().unwrap();
+        assert_eq!(arg_unique_par_ca!(&ca, 3), expected.cont_slice().unwrap());
+    }
 }
@@ -250,56 +250,86 @@ where
     T: PolarsNumericType,
     T::Native: Float,
 {
+    fn rand_from<D: Distribution<f64>>(
+        name: &str,
+        length: usize,
+        dist: D,
+        seed: Option<u64>,
+    ) -> Self {
+        let rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let values: Vec<T::Native> = dist
+            .sample_iter(rng)
+            .take(length)
+            .map(|smpl| NumCast::from(smpl).unwrap())
+            .collect();
+        ChunkedArray::from_vec(name, values)
+    }
+
     /// Create [`ChunkedArray`] with samples from a Normal distribution.
     pub fn rand_normal(name: &str, length: usize, mean: f64, std_dev: f64) -> PolarsResult<Self> {
+        Self::rand_normal_seeded(name, length, mean, std_dev, None)
+    }
+
+    /// Create [`ChunkedArray`] with samples from a Normal distribution, drawn from a generator
+    /// seeded with `seed`, or with a random seed if it is `None`.
+    pub fn rand_normal_seeded(
+        name: &str,
+        length: usize,
+        mean: f64,
+        std_dev: f64,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
         let normal = Normal::new(mean, std_dev).map_err(to_compute_err)?;
-        let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
-        for _ in 0..length {
-            let smpl = normal.sample(&mut rng);
-            let smpl = NumCast::from(smpl).unwrap();
-            builder.append_value(smpl)
-        }
-        Ok(builder.finish())
+        Ok(Self::rand_from(name, length, normal, seed))
     }
 
     /// Create [`ChunkedArray`] with samples from a Standard Normal distribution.
     pub fn rand_standard_normal(name: &str, length: usize) -> Self {
-        let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
-        for _ in 0..length {
-            let smpl: f64 = rng.sample(StandardNormal);
-            let smpl = NumCast::from(smpl).unwrap();
-            builder.append_value(smpl)
-        }
-        builder.finish()
+        Self::rand_from(name, length, StandardNormal, None)
     }
 
     /// Create [`ChunkedArray`] with samples from a Uniform distribution.
     pub fn rand_uniform(name: &str, length: usize, low: f64, high: f64) -> Self {
-        let uniform = Uniform::new(low, high);
-        let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
-        for _ in 0..length {
-            let smpl = uniform.sample(&mut rng);
-            let smpl = NumCast::from(smpl).unwrap();
-            builder.append_value(smpl)
-        }
-        builder.finish()
+        Self::rand_from(name, length, Uniform::new(low, high), None)
+    }
+
+    /// Create [`ChunkedArray`] with samples from a Uniform distribution over `[low, high)`,
+    /// drawn from a generator seeded with `seed`, or with a random seed if it is `None`.
+    pub fn rand_uniform_seeded(
+        name: &str,
+        length: usize,
+        low: f64,
+        high: f64,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            low < high,
+            ComputeError: "uniform distribution requires `low` < `high`, got {} and {}", low, high
+        );
+        Ok(Self::rand_from(name, length, Uniform::new(low, high), seed))
     }
 }
 
 impl BooleanChunked {
     /// Create [`ChunkedArray`] with samples from a Bernoulli distribution.
     pub fn rand_bernoulli(name: &str, length: usize, p: f64) -> PolarsResult<Self> {
+        Self::rand_bernoulli_seeded(name, length, p, None)
+    }
+
+    /// Create [`ChunkedArray`] with samples from a Bernoulli distribution, drawn from a
+    /// generator seeded with `seed`, or with a random seed if it is `None`.
+    pub fn rand_bernoulli_seeded(
+        name: &str,
+        length: usize,
+        p: f64,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
         let dist = Bernoulli::new(p).map_err(to_compute_err)?;
-        let mut rng = rand::thread_rng();
-        let mut builder = BooleanChunkedBuilder::new(name, length);
-        for _ in 0..length {
-            let smpl = dist.sample(&mut rng);
-            builder.append_value(smpl)
-        }
-        Ok(builder.finish())
+        let rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        Ok(BooleanChunked::from_iter_values(
+            name,
+            dist.sample_iter(rng).take(length),
+        ))
     }
 }
 
@@ -1,4 +1,7 @@
+use std::fmt::Write;
+
 use crate::export::chrono::Duration as ChronoDuration;
+use crate::fmt::iso_duration_string;
 use crate::prelude::DataType::Duration;
 use crate::prelude::*;
 
@@ -60,6 +63,45 @@ impl DurationChunked {
         self.2 = Some(Duration(tu))
     }
 
+    /// Convert from Duration into String with the given format.
+    ///
+    /// The format is `"iso"` for ISO 8601 durations, e.g. `P1DT2H3M`, or `"polars"` for the
+    /// representation used when printing, e.g. `1d 2h 3m`.
+    pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
+        let iso = match format {
+            "iso" => true,
+            "polars" => false,
+            _ => polars_bail!(
+                InvalidOperation: "invalid Duration format '{}', expected one of 'iso' or 'polars'",
+                format
+            ),
+        };
+        let tu = self.time_unit();
+        let mut ca: StringChunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutablePlString::with_capacity(arr.len());
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        if iso {
+                            iso_duration_string(&mut buf, *v, tu);
+                        } else {
+                            write!(buf, "{}", AnyValue::Duration(*v, tu)).unwrap();
+                        }
+                        mutarr.push_value(&buf)
+                    },
+                }
+            }
+
+            mutarr.freeze().boxed()
+        });
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Construct a new [`DurationChunked`] from an iterator over [`ChronoDuration`].
     pub fn from_duration<I: IntoIterator<Item = ChronoDuration>>(
         name: &str,
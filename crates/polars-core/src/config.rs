@@ -1,3 +1,4 @@
+use crate::prelude::polars_warn;
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -48,6 +49,35 @@ pub fn get_file_prefetch_size() -> usize {
         .unwrap_or_else(|_| std::cmp::max(POOL.current_num_threads() * 2, 16))
 }
 
+/// Parse the environment variable `name` as a number. An invalid value is ignored with a
+/// warning, so that a typo in the configuration doesn't fail queries.
+fn parse_env_usize(name: &str) -> Option<usize> {
+    let value = std::env::var(name).ok()?;
+    match value.parse::<usize>() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            polars_warn!(
+                "{} must be a non-negative integer, got '{}'; it is ignored",
+                name,
+                value
+            );
+            None
+        },
+    }
+}
+
+/// The number of files of a multi-file scan that are opened ahead of the files that are
+/// read.
+pub fn get_file_readahead_size() -> usize {
+    parse_env_usize("POLARS_FILE_READAHEAD_SIZE").unwrap_or_else(get_file_prefetch_size)
+}
+
+/// The maximum compressed size in bytes of the row groups of a multi-file scan that are
+/// downloaded ahead of reading, or `None` if unlimited.
+pub fn get_file_readahead_bytes() -> Option<usize> {
+    parse_env_usize("POLARS_FILE_READAHEAD_BYTES")
+}
+
 pub fn get_rg_prefetch_size() -> usize {
     std::env::var("POLARS_ROW_GROUP_PREFETCH_SIZE")
         .map(|s| s.parse::<usize>().expect("integer"))
@@ -8,6 +8,8 @@ pub type TimeZone = String;
 
 pub static DTYPE_ENUM_KEY: &str = "POLARS.CATEGORICAL_TYPE";
 pub static DTYPE_ENUM_VALUE: &str = "ENUM";
+pub static DTYPE_CATEGORICAL_ORDERING_KEY: &str = "POLARS.CATEGORICAL_ORDERING";
+pub static DTYPE_CATEGORICAL_ORDERING_LEXICAL: &str = "LEXICAL";
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(
@@ -444,6 +446,11 @@ impl DataType {
                 DTYPE_ENUM_KEY.into(),
                 DTYPE_ENUM_VALUE.into(),
             )])),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_, CategoricalOrdering::Lexical) => Some(BTreeMap::from([(
+                DTYPE_CATEGORICAL_ORDERING_KEY.into(),
+                DTYPE_CATEGORICAL_ORDERING_LEXICAL.into(),
+            )])),
             DataType::BinaryOffset => Some(BTreeMap::from([(
                 "pl".to_string(),
                 "maintain_type".to_string(),
@@ -699,6 +706,28 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
     })
 }
 
+/// Whether `key` is a metadata key that polars uses to describe the data type of an Arrow
+/// field, rather than metadata of the column itself.
+pub(crate) fn is_polars_metadata_key(key: &str) -> bool {
+    key == DTYPE_ENUM_KEY || key == DTYPE_CATEGORICAL_ORDERING_KEY || key == "pl"
+}
+
+/// Restore the categorical data type that [`DataType::to_arrow_field`] recorded in the
+/// metadata of a dictionary field. The categories are not part of the metadata; they are
+/// set once the data is read.
+#[cfg(feature = "dtype-categorical")]
+pub(crate) fn categorical_dtype_from_metadata(metadata: &arrow::datatypes::Metadata) -> DataType {
+    let ordering = match metadata.get(DTYPE_CATEGORICAL_ORDERING_KEY) {
+        Some(v) if v == DTYPE_CATEGORICAL_ORDERING_LEXICAL => CategoricalOrdering::Lexical,
+        _ => CategoricalOrdering::Physical,
+    };
+    if metadata.get(DTYPE_ENUM_KEY).map(|v| v.as_str()) == Some(DTYPE_ENUM_VALUE) {
+        DataType::Enum(None, ordering)
+    } else {
+        DataType::Categorical(None, ordering)
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 pub fn create_enum_data_type(categories: Utf8ViewArray) -> DataType {
     let rev_map = RevMapping::build_local(categories);
@@ -1,9 +1,14 @@
+use std::collections::BTreeMap;
+
 use smartstring::alias::String as SmartString;
 
 use super::*;
 
+/// Key/value metadata of a column, e.g. its unit or a description.
+pub type FieldMetadata = BTreeMap<String, String>;
+
 /// Characterizes the name and the [`DataType`] of a column.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Eq)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-lazy"),
     derive(Serialize, Deserialize)
@@ -11,6 +16,15 @@ use super::*;
 pub struct Field {
     pub name: SmartString,
     pub dtype: DataType,
+    #[cfg_attr(any(feature = "serde", feature = "serde-lazy"), serde(default))]
+    pub metadata: Option<Arc<FieldMetadata>>,
+}
+
+// The metadata describes the column, it doesn't change what the column is.
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.dtype == other.dtype
+    }
 }
 
 pub type FieldRef = Arc<Field>;
@@ -31,11 +45,37 @@ impl Field {
         Field {
             name: name.into(),
             dtype,
+            metadata: None,
         }
     }
 
     pub fn from_owned(name: SmartString, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            metadata: None,
+        }
+    }
+
+    /// Returns this `Field` with the given key/value metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let f = Field::new("Weight", DataType::Float64)
+    ///     .with_metadata(FieldMetadata::from([("unit".to_string(), "kg".to_string())]));
+    ///
+    /// assert_eq!(f.metadata().unwrap()["unit"], "kg");
+    /// ```
+    pub fn with_metadata(mut self, metadata: FieldMetadata) -> Self {
+        self.metadata = (!metadata.is_empty()).then(|| Arc::new(metadata));
+        self
+    }
+
+    /// Returns a reference to the `Field` metadata, if any.
+    pub fn metadata(&self) -> Option<&FieldMetadata> {
+        self.metadata.as_deref()
     }
 
     /// Returns a reference to the `Field` name.
@@ -110,7 +150,13 @@ impl Field {
     /// assert_eq!(f.to_arrow(true), af);
     /// ```
     pub fn to_arrow(&self, pl_flavor: bool) -> ArrowField {
-        self.dtype.to_arrow_field(self.name.as_str(), pl_flavor)
+        let mut field = self.dtype.to_arrow_field(self.name.as_str(), pl_flavor);
+        if let Some(metadata) = &self.metadata {
+            field
+                .metadata
+                .extend(metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        field
     }
 }
 
@@ -199,6 +245,17 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
-        Field::new(&f.name, f.data_type().into())
+        let dtype = match f.data_type() {
+            #[cfg(feature = "dtype-categorical")]
+            ArrowDataType::Dictionary(_, _, _) => categorical_dtype_from_metadata(&f.metadata),
+            dt => dt.into(),
+        };
+        let metadata: FieldMetadata = f
+            .metadata
+            .iter()
+            .filter(|(k, _)| !is_polars_metadata_key(k))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        Field::new(&f.name, dtype).with_metadata(metadata)
     }
 }
@@ -981,6 +981,55 @@ fn format_duration(f: &mut Formatter, v: i64, sizes: &[i64], names: &[&str]) ->
     Ok(())
 }
 
+/// Write `v` as an ISO 8601 duration, e.g. `P1DT2H3M4.5S`.
+///
+/// Days are the largest component, as weeks are the only larger one with a fixed length.
+#[cfg(feature = "dtype-duration")]
+pub fn iso_duration_string(s: &mut String, v: i64, unit: TimeUnit) {
+    if v == 0 {
+        s.push_str("PT0S");
+        return;
+    }
+    let (per_second, fraction_digits) = match unit {
+        TimeUnit::Nanoseconds => (1_000_000_000, 9),
+        TimeUnit::Microseconds => (1_000_000, 6),
+        TimeUnit::Milliseconds => (1_000, 3),
+    };
+    if v < 0 {
+        s.push('-');
+    }
+    let v = v.unsigned_abs();
+    let fraction = v % per_second;
+    let total_seconds = v / per_second;
+    let days = total_seconds / 86_400;
+    let hours = total_seconds % 86_400 / 3_600;
+    let minutes = total_seconds % 3_600 / 60;
+    let seconds = total_seconds % 60;
+
+    s.push('P');
+    if days > 0 {
+        write!(s, "{days}D").unwrap();
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && fraction == 0 {
+        return;
+    }
+    s.push('T');
+    if hours > 0 {
+        write!(s, "{hours}H").unwrap();
+    }
+    if minutes > 0 {
+        write!(s, "{minutes}M").unwrap();
+    }
+    if seconds > 0 || fraction > 0 {
+        write!(s, "{seconds}").unwrap();
+        if fraction > 0 {
+            let fraction = format!("{fraction:0fraction_digits$}");
+            write!(s, ".{}", fraction.trim_end_matches('0')).unwrap();
+        }
+        s.push('S');
+    }
+}
+
 fn format_blob(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
     let width = get_str_len_limit() * 2;
     write!(f, "b\"")?;
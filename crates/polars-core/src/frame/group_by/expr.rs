@@ -5,4 +5,9 @@ pub trait PhysicalAggExpr {
     fn evaluate(&self, df: &DataFrame, groups: &GroupsProxy) -> PolarsResult<Series>;
 
     fn root_name(&self) -> PolarsResult<&str>;
+
+    /// Name of the aggregation, used to label its output.
+    fn name(&self) -> Option<&str> {
+        None
+    }
 }
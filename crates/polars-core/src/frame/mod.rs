@@ -32,6 +32,7 @@ use smartstring::alias::String as SmartString;
 use crate::hashing::_df_rows_to_hashes_threaded_vertical;
 #[cfg(feature = "zip_with")]
 use crate::prelude::min_max_binary::min_max_binary_series;
+use crate::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
 use crate::series::IsSorted;
 use crate::POOL;
@@ -55,6 +56,12 @@ pub enum UniqueKeepStrategy {
     /// This allows more optimizations
     #[default]
     Any,
+    /// Keep the row with the largest value in the column that is compared.
+    /// Null values are only kept if there is no other value, ties keep the first row.
+    MaxBy,
+    /// Keep the row with the smallest value in the column that is compared.
+    /// Null values are only kept if there is no other value, ties keep the first row.
+    MinBy,
 }
 
 /// A contiguous growable collection of `Series` that have the same length.
@@ -1811,8 +1818,10 @@ impl DataFrame {
             return Ok(out);
         }
 
-        if let Some((0, k)) = slice {
-            return self.bottom_k_impl(k, by_column, sort_options);
+        if let Some((offset, len)) = slice {
+            if offset >= 0 {
+                return self.bottom_k_slice_impl(offset as usize, len, by_column, sort_options);
+            }
         }
 
         #[cfg(feature = "dtype-struct")]
@@ -2678,7 +2687,7 @@ impl DataFrame {
         keep: UniqueKeepStrategy,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
-        self.unique_impl(true, subset, keep, slice)
+        self.unique_impl(true, subset, keep, None, slice)
     }
 
     /// Unstable distinct. See [`DataFrame::unique_stable`].
@@ -2689,21 +2698,28 @@ impl DataFrame {
         keep: UniqueKeepStrategy,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
-        self.unique_impl(false, subset, keep, slice)
+        self.unique_impl(false, subset, keep, None, slice)
     }
 
+    /// Distinct rows, where `keep_by` is the column that is compared by
+    /// [`UniqueKeepStrategy::MaxBy`] and [`UniqueKeepStrategy::MinBy`].
     #[cfg(feature = "algorithm_group_by")]
     pub fn unique_impl(
         &self,
         maintain_order: bool,
         subset: Option<&[String]>,
         keep: UniqueKeepStrategy,
+        keep_by: Option<&str>,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<Self> {
         let names = match &subset {
             Some(s) => s.iter().map(|s| &**s).collect(),
             None => self.get_column_names(),
         };
+        // Any row of a group will do, so the first `len` groups that are found are kept.
+        if let (UniqueKeepStrategy::Any, Some((0, len)), false) = (&keep, slice, names.is_empty()) {
+            return self.unique_any_head(&names, len);
+        }
         let mut df = self.clone();
         // take on multiple chunks is terrible
         df.as_single_chunk_par();
@@ -2761,10 +2777,102 @@ impl DataFrame {
                 };
                 return df.filter(&mask);
             },
+            (UniqueKeepStrategy::MaxBy | UniqueKeepStrategy::MinBy, _) => {
+                let Some(by) = keep_by else {
+                    polars_bail!(
+                        InvalidOperation: "keep strategy {:?} requires a column to compare",
+                        keep
+                    )
+                };
+                let max = keep == UniqueKeepStrategy::MaxBy;
+                return df.unique_keep_by(names, by, max, maintain_order, slice);
+            },
         };
         Ok(unsafe { DataFrame::new_no_checks(columns) })
     }
 
+    /// Keep the row with the max (or min) value of `by` per group, in a single pass over the
+    /// groups.
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique_keep_by(
+        &self,
+        names: Vec<&str>,
+        by: &str,
+        max: bool,
+        maintain_order: bool,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<Self> {
+        let by = self.column(by)?;
+        // The row encoding orders all dtypes. Nulls are encoded as the smallest value when
+        // looking for the max and as the largest value when looking for the min, so they
+        // are only picked if the group has no other value.
+        let encoded = _get_rows_encoded_ca(by.name(), &[by.clone()], &[false], !max)?;
+        let encoded = encoded.rechunk();
+        let encoded = encoded.downcast_iter().next().unwrap();
+        let pick = |idx: &mut dyn Iterator<Item = IdxSize>| {
+            let mut best = idx.next().unwrap();
+            // SAFETY: the group indices are in bounds.
+            let mut best_value = unsafe { encoded.value_unchecked(best as usize) };
+            for i in idx {
+                let value = unsafe { encoded.value_unchecked(i as usize) };
+                if (max && value > best_value) || (!max && value < best_value) {
+                    best = i;
+                    best_value = value;
+                }
+            }
+            best
+        };
+
+        let gb = self.group_by(names)?;
+        let mut keep_idx = gb
+            .get_groups()
+            .iter()
+            .map(|g| match g {
+                GroupsIndicator::Idx((_first, idx)) => pick(&mut idx.iter().copied()),
+                GroupsIndicator::Slice([first, len]) => pick(&mut (first..first + len)),
+            })
+            .collect::<Vec<_>>();
+        if maintain_order {
+            keep_idx.sort_unstable();
+        }
+        if let Some((offset, len)) = slice {
+            let (offset, len) = slice_offsets(offset, len, keep_idx.len());
+            keep_idx = keep_idx[offset..offset + len].to_vec();
+        }
+        let keep_idx = IdxCa::from_vec("", keep_idx);
+        Ok(unsafe { self.take_unchecked(&keep_idx) })
+    }
+
+    /// Keep the first row of each of the first `len` groups, in the order of the rows.
+    ///
+    /// The rows are scanned in morsels of growing size, so that the scan stops soon after
+    /// `len` groups are found.
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique_any_head(&self, names: &[&str], len: usize) -> PolarsResult<Self> {
+        let keys = self.select(names.iter().copied())?;
+        let descending = vec![false; keys.width()];
+        let mut seen = PlHashSet::with_capacity(len);
+        let mut keep_idx = Vec::with_capacity(len);
+        let mut offset = 0;
+        let mut morsel_size = len.clamp(1024, 1 << 16);
+        while offset < self.height() && keep_idx.len() < len {
+            let morsel = keys.slice(offset as i64, morsel_size);
+            let rows = _get_rows_encoded_ca("", morsel.get_columns(), &descending, false)?;
+            for (i, row) in rows.into_no_null_iter().enumerate() {
+                if keep_idx.len() == len {
+                    break;
+                }
+                if seen.insert(row.to_vec()) {
+                    keep_idx.push((offset + i) as IdxSize);
+                }
+            }
+            offset += morsel.height();
+            morsel_size *= 2;
+        }
+        let keep_idx = IdxCa::from_vec("", keep_idx);
+        Ok(unsafe { self.take_unchecked(&keep_idx) })
+    }
+
     /// Get a mask of all the unique rows in the [`DataFrame`].
     ///
     /// # Example
@@ -2815,6 +2923,67 @@ impl DataFrame {
         ))
     }
 
+    /// Get the rows that occur more than once, in their original order.
+    ///
+    /// `subset` is an optional slice of column names that identify a duplicate; if `None`,
+    /// all columns are considered. Every row is annotated with
+    /// - `group_id`: the id of its set of duplicates, numbered by first occurrence.
+    /// - `occurrence`: the 1-based position of the row within its set.
+    /// - `count`: the number of rows in its set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("Company" => &["Alphabet", "Apple", "Alphabet"],
+    ///                         "ISIN" => &["US02079K3059", "US0378331005", "US02079K1079"])?;
+    /// let duplicates = df.duplicates(Some(&["Company".to_string()]))?;
+    ///
+    /// assert_eq!(duplicates.height(), 2);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn duplicates(&self, subset: Option<&[String]>) -> PolarsResult<DataFrame> {
+        let names = match &subset {
+            Some(s) => s.iter().map(|s| &**s).collect(),
+            None => self.get_column_names(),
+        };
+        let gb = self.group_by_stable(names)?;
+
+        // (row, group_id, occurrence, count) of every duplicated row.
+        let mut rows = Vec::new();
+        for g in gb.get_groups().iter() {
+            let (first, len, idx) = match g {
+                GroupsIndicator::Idx((first, idx)) => (first, idx.len(), Some(idx)),
+                GroupsIndicator::Slice([first, len]) => (first, len as usize, None),
+            };
+            if len < 2 {
+                continue;
+            }
+            let group_id = rows.last().map_or(0, |&(_, id, _, _)| id + 1);
+            for occurrence in 0..len {
+                let row = match idx {
+                    Some(idx) => idx[occurrence],
+                    None => first + occurrence as IdxSize,
+                };
+                rows.push((row, group_id, occurrence as IdxSize + 1, len as IdxSize));
+            }
+        }
+        rows.sort_unstable_by_key(|&(row, _, _, _)| row);
+
+        let take_idx = IdxCa::from_vec("", rows.iter().map(|r| r.0).collect());
+        let annotations = [
+            IdxCa::from_vec("group_id", rows.iter().map(|r| r.1).collect()),
+            IdxCa::from_vec("occurrence", rows.iter().map(|r| r.2).collect()),
+            IdxCa::from_vec("count", rows.iter().map(|r| r.3).collect()),
+        ]
+        .map(|ca| ca.into_series());
+        // SAFETY: the row indices are in bounds.
+        let mut df = unsafe { self.take_unchecked(&take_idx) };
+        df.hstack_mut(&annotations)?;
+        Ok(df)
+    }
+
     /// Create a new [`DataFrame`] that shows the null counts per column.
     #[must_use]
     pub fn null_count(&self) -> Self {
@@ -3196,6 +3365,75 @@ mod test {
         assert!(df.equals(&valid));
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_duplicates() -> PolarsResult<()> {
+        let df = df! {
+            "int" => [1, 2, 1, 3, 2, 1],
+            "str" => ["a", "b", "c", "d", "b", "a"]
+        }?;
+        let out = df.duplicates(Some(&["int".to_string()]))?;
+        assert_eq!(
+            Vec::from(out.column("int")?.i32()?),
+            &[Some(1), Some(2), Some(1), Some(2), Some(1)]
+        );
+        let idx = |name| -> PolarsResult<Vec<Option<IdxSize>>> {
+            Ok(Vec::from(out.column(name)?.idx()?))
+        };
+        assert_eq!(
+            idx("group_id")?,
+            &[Some(0), Some(1), Some(0), Some(1), Some(0)]
+        );
+        assert_eq!(
+            idx("occurrence")?,
+            &[Some(1), Some(1), Some(2), Some(2), Some(3)]
+        );
+        assert_eq!(
+            idx("count")?,
+            &[Some(3), Some(2), Some(3), Some(2), Some(3)]
+        );
+
+        let out = df.duplicates(None)?;
+        assert_eq!(
+            Vec::from(out.column("str")?.str()?),
+            &[Some("a"), Some("b"), Some("b"), Some("a")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_unique_keep_max_by() -> PolarsResult<()> {
+        let df = df! {
+            "id" => [1, 1, 2, 2, 1, 3],
+            "updated" => [Some(3), Some(5), None, Some(4), Some(5), None],
+            "value" => ["a", "b", "c", "d", "e", "f"]
+        }?;
+        let subset = ["id".to_string()];
+
+        let keep = UniqueKeepStrategy::MaxBy;
+        let out = df.unique_impl(true, Some(&subset), keep, Some("updated"), None)?;
+        assert_eq!(
+            Vec::from(out.column("value")?.str()?),
+            &[Some("b"), Some("d"), Some("f")]
+        );
+
+        let keep = UniqueKeepStrategy::MinBy;
+        let out = df.unique_impl(true, Some(&subset), keep, Some("updated"), None)?;
+        assert_eq!(
+            Vec::from(out.column("value")?.str()?),
+            &[Some("a"), Some("d"), Some("f")]
+        );
+        let out = df.unique_impl(true, Some(&subset), keep, Some("updated"), Some((1, 5)))?;
+        assert_eq!(
+            Vec::from(out.column("value")?.str()?),
+            &[Some("d"), Some("f")]
+        );
+        // The column to compare is required.
+        assert!(df.unique_stable(Some(&subset), keep, None).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_vstack() {
         // check that it does not accidentally rechunks
@@ -14,6 +14,21 @@ impl DataFrame {
         self.bottom_k_impl(k, by_column, sort_options.with_order_reversed())
     }
 
+    /// The rows `offset..offset + len` of the frame sorted by `by_column`.
+    ///
+    /// Only the first `offset + len` rows are ordered, the remaining rows are never sorted.
+    #[doc(hidden)]
+    pub fn bottom_k_slice_impl(
+        &self,
+        offset: usize,
+        len: usize,
+        by_column: Vec<Series>,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<DataFrame> {
+        let df = self.bottom_k_impl(offset.saturating_add(len), by_column, sort_options)?;
+        Ok(df.slice(offset as i64, len))
+    }
+
     pub(crate) fn bottom_k_impl(
         &self,
         k: usize,
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 
@@ -16,6 +17,9 @@ use crate::utils::try_get_supertype;
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct Schema {
     inner: PlIndexMap<SmartString, DataType>,
+    /// The metadata of the fields that have any. It is not part of the comparison of schemas.
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    metadata: BTreeMap<SmartString, Arc<FieldMetadata>>,
 }
 
 impl Hash for Schema {
@@ -56,11 +60,19 @@ where
         let iter = iter.into_iter();
         let mut map: PlIndexMap<_, _> =
             IndexMap::with_capacity_and_hasher(iter.size_hint().0, ahash::RandomState::default());
+        let mut metadata = BTreeMap::new();
         for fld in iter {
             let fld = fld.into();
+            match fld.metadata {
+                Some(md) => metadata.insert(fld.name.clone(), md),
+                None => metadata.remove(&fld.name),
+            };
             map.insert(fld.name, fld.dtype);
         }
-        Self { inner: map }
+        Self {
+            inner: map,
+            metadata,
+        }
     }
 }
 
@@ -78,7 +90,10 @@ impl Schema {
     pub fn with_capacity(capacity: usize) -> Self {
         let map: PlIndexMap<_, _> =
             IndexMap::with_capacity_and_hasher(capacity, ahash::RandomState::default());
-        Self { inner: map }
+        Self {
+            inner: map,
+            metadata: BTreeMap::new(),
+        }
     }
 
     /// The number of fields in the schema
@@ -104,6 +119,10 @@ impl Schema {
         // Swap the two indices to move the originally last element back to the end and to move the new element back to
         // its original position
         self.inner.swap_indices(old_index, new_index);
+        if let Some(metadata) = self.metadata.remove(&old_name) {
+            self.metadata
+                .insert(self.inner.get_index(old_index)?.0.clone(), metadata);
+        }
 
         Some(old_name)
     }
@@ -141,6 +160,8 @@ impl Schema {
         new.inner.extend(iter.by_ref().take(index));
         new.inner.insert(name.clone(), dtype);
         new.inner.extend(iter);
+        new.metadata = self.metadata.clone();
+        new.metadata.remove(&name);
         Ok(new)
     }
 
@@ -172,6 +193,7 @@ impl Schema {
                     self.len()
         );
 
+        self.metadata.remove(&name);
         let (old_index, old_dtype) = self.inner.insert_full(name, dtype);
 
         // If we're moving an existing field, one-past-the-end will actually be out of bounds. Also, self.len() won't
@@ -226,7 +248,7 @@ impl Schema {
     pub fn get_field(&self, name: &str) -> Option<Field> {
         self.inner
             .get(name)
-            .map(|dtype| Field::new(name, dtype.clone()))
+            .map(|dtype| self.new_field(name, dtype))
     }
 
     /// Look up the name in the schema and return an owned [`Field`] by cloning the data
@@ -239,7 +261,32 @@ impl Schema {
         self.inner
             .get(name)
             .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name))
-            .map(|dtype| Field::new(name, dtype.clone()))
+            .map(|dtype| self.new_field(name, dtype))
+    }
+
+    fn new_field(&self, name: &str, dtype: &DataType) -> Field {
+        let mut field = Field::new(name, dtype.clone());
+        field.metadata = self.metadata.get(name).cloned();
+        field
+    }
+
+    /// Get the key/value metadata of the field named `name`, if it has any
+    pub fn get_metadata(&self, name: &str) -> Option<&FieldMetadata> {
+        self.metadata.get(name).map(|md| md.as_ref())
+    }
+
+    /// Set the key/value metadata of the field named `name`, replacing any existing metadata
+    ///
+    /// Empty metadata removes the metadata of the field. If `name` doesn't exist in the schema, the schema is not
+    /// modified.
+    pub fn set_metadata(&mut self, name: &str, metadata: FieldMetadata) {
+        if let Some((_, name, _)) = self.inner.get_full(name) {
+            if metadata.is_empty() {
+                self.metadata.remove(name);
+            } else {
+                self.metadata.insert(name.clone(), Arc::new(metadata));
+            }
+        }
     }
 
     /// Get references to the name and dtype of the field at `index`
@@ -270,6 +317,7 @@ impl Schema {
     /// is replaced by the last field, which takes its position. For a slower, but order-preserving, method, use
     /// [`shift_remove`][Self::shift_remove].
     pub fn remove(&mut self, name: &str) -> Option<DataType> {
+        self.metadata.remove(name);
         self.inner.swap_remove(name)
     }
 
@@ -280,6 +328,7 @@ impl Schema {
     /// This method does a `shift_remove`, which preserves the order of the fields in the schema but **is O(n)**. For a
     /// faster, but not order-preserving, method, use [`remove`][Self::remove].
     pub fn shift_remove(&mut self, name: &str) -> Option<DataType> {
+        self.metadata.remove(name);
         self.inner.shift_remove(name)
     }
 
@@ -290,7 +339,9 @@ impl Schema {
     /// This method does a `shift_remove`, which preserves the order of the fields in the schema but **is O(n)**. For a
     /// faster, but not order-preserving, method, use [`remove`][Self::remove].
     pub fn shift_remove_index(&mut self, index: usize) -> Option<(SmartString, DataType)> {
-        self.inner.shift_remove_index(index)
+        let (name, dtype) = self.inner.shift_remove_index(index)?;
+        self.metadata.remove(&name);
+        Some((name, dtype))
     }
 
     /// Whether the schema contains a field named `name`
@@ -335,6 +386,7 @@ impl Schema {
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn with_column(&mut self, name: SmartString, dtype: DataType) -> Option<DataType> {
+        self.metadata.remove(&name);
         self.inner.insert(name, dtype)
     }
 
@@ -346,6 +398,10 @@ impl Schema {
     /// - Fields that occur in both `self` and `other` are updated with the dtype from `other`, but keep their original
     ///   index
     pub fn merge(&mut self, other: Self) {
+        for name in other.inner.keys() {
+            self.metadata.remove(name);
+        }
+        self.metadata.extend(other.metadata);
         self.inner.extend(other.inner)
     }
 
@@ -354,7 +410,7 @@ impl Schema {
         let fields: Vec<_> = self
             .inner
             .iter()
-            .map(|(name, dtype)| dtype.to_arrow_field(name.as_str(), pl_flavor))
+            .map(|(name, dtype)| self.new_field(name, dtype).to_arrow(pl_flavor))
             .collect();
         ArrowSchema::from(fields)
     }
@@ -366,7 +422,7 @@ impl Schema {
     pub fn iter_fields(&self) -> impl ExactSizeIterator<Item = Field> + '_ {
         self.inner
             .iter()
-            .map(|(name, dtype)| Field::new(name, dtype.clone()))
+            .map(|(name, dtype)| self.new_field(name, dtype))
     }
 
     /// Iterates over references to the dtypes in this schema
@@ -280,8 +280,32 @@ impl Series {
                 panic!("activate dtype-categorical to convert dictionary arrays")
             },
             #[cfg(feature = "dtype-categorical")]
-            ArrowDataType::Dictionary(key_type, value_type, _) => {
+            dtype_arrow @ ArrowDataType::Dictionary(key_type, value_type, _) => {
                 use arrow::datatypes::IntegerType;
+                let dtype = md
+                    .map(categorical_dtype_from_metadata)
+                    .unwrap_or(DataType::Categorical(None, Default::default()));
+                // Every chunk has its own dictionary. Concatenating them would repeat the
+                // categories of an enum, so its chunks are converted one by one.
+                if chunks.len() > 1 && matches!(dtype, DataType::Enum(_, _)) {
+                    let mut chunks = chunks.into_iter();
+                    let first = chunks.next().unwrap();
+                    let mut out = Self::_try_from_arrow_unchecked_with_md(
+                        name,
+                        vec![first],
+                        dtype_arrow,
+                        md,
+                    )?;
+                    for chunk in chunks {
+                        out.append(&Self::_try_from_arrow_unchecked_with_md(
+                            name,
+                            vec![chunk],
+                            dtype_arrow,
+                            md,
+                        )?)?;
+                    }
+                    return Ok(out);
+                }
                 // don't spuriously call this; triggers a read on mmapped data
                 let arr = if chunks.len() > 1 {
                     concatenate_owned_unchecked(&chunks)?
@@ -341,30 +365,28 @@ impl Series {
                 let keys = keys.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
                 let values = values.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
 
-                if let Some(metadata) = md {
-                    if metadata.get(DTYPE_ENUM_KEY) == Some(&DTYPE_ENUM_VALUE.into()) {
+                match dtype {
+                    DataType::Enum(_, ordering) => {
                         // SAFETY:
                         // the invariants of an Arrow Dictionary guarantee the keys are in bounds
-                        return Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
                             UInt32Chunked::with_chunk(name, keys.clone()),
                             Arc::new(RevMapping::build_local(values.clone())),
                             true,
-                            Default::default(),
+                            ordering,
                         )
-                        .into_series());
-                    }
+                        .into_series())
+                    },
+                    DataType::Categorical(_, ordering) => {
+                        // SAFETY:
+                        // the invariants of an Arrow Dictionary guarantee the keys are in bounds
+                        Ok(
+                            CategoricalChunked::from_keys_and_values(name, keys, values, ordering)
+                                .into_series(),
+                        )
+                    },
+                    _ => unreachable!(),
                 }
-                // SAFETY:
-                // the invariants of an Arrow Dictionary guarantee the keys are in bounds
-                Ok(
-                    CategoricalChunked::from_keys_and_values(
-                        name,
-                        keys,
-                        values,
-                        Default::default(),
-                    )
-                    .into_series(),
-                )
             },
             #[cfg(feature = "object")]
             ArrowDataType::Extension(s, _, Some(_)) if s == EXTENSION_NAME => {
@@ -133,6 +133,10 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         self.0.physical_mut().rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<FieldMetadata>>) {
+        self.0.physical_mut().set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.physical().chunk_id()
     }
@@ -149,6 +149,10 @@ impl SeriesTrait for NullChunked {
         self.name = Arc::from(name)
     }
 
+    fn set_metadata(&mut self, _metadata: Option<Arc<FieldMetadata>>) {
+        // A null column has no field to keep the metadata in.
+    }
+
     fn chunks(&self) -> &Vec<ArrayRef> {
         &self.chunks
     }
@@ -82,6 +82,10 @@ where
         ObjectChunked::rename(&mut self.0, name)
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<FieldMetadata>>) {
+        ObjectChunked::set_metadata(&mut self.0, metadata)
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         ObjectChunked::chunk_id(&self.0)
     }
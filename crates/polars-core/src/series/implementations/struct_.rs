@@ -88,6 +88,10 @@ impl SeriesTrait for SeriesWrap<StructChunked> {
         self.0.rename(name)
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<FieldMetadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn has_validity(&self) -> bool {
         self.0.fields().iter().any(|s| s.has_validity())
     }
@@ -238,6 +238,21 @@ impl Series {
         self
     }
 
+    /// The key/value metadata of the field of this Series, e.g. its unit or a description.
+    ///
+    /// The metadata is kept by operations that keep the values of the Series, such as renames,
+    /// filters and slices, but not by operations that compute new values.
+    pub fn metadata(&self) -> Option<Arc<FieldMetadata>> {
+        self.field().metadata.clone()
+    }
+
+    /// Set the key/value metadata of the field of this Series. Empty metadata removes it.
+    pub fn set_metadata(&mut self, metadata: FieldMetadata) -> &mut Series {
+        let metadata = (!metadata.is_empty()).then(|| Arc::new(metadata));
+        self._get_inner_mut().set_metadata(metadata);
+        self
+    }
+
     pub fn from_arrow(name: &str, array: ArrayRef) -> PolarsResult<Series> {
         Self::try_from((name, array))
     }
@@ -1067,4 +1082,19 @@ mod test {
         let _ = series.slice(-6, 2);
         let _ = series.slice(4, 2);
     }
+
+    #[test]
+    fn metadata() {
+        let metadata = FieldMetadata::from([("unit".to_string(), "kg".to_string())]);
+        let mut series = Series::new("a", &[1.0, 2.0, 3.0]);
+        series.set_metadata(metadata.clone());
+        series.rename("b");
+        let series = series.slice(1, 2);
+        assert_eq!(series.metadata().as_deref(), Some(&metadata));
+
+        let df = DataFrame::new(vec![series]).unwrap();
+        assert_eq!(df.schema().get_metadata("b"), Some(&metadata));
+        // Computed values have no metadata.
+        assert_eq!((df.column("b").unwrap() * 2.0).metadata(), None);
+    }
 }
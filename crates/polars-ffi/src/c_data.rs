@@ -0,0 +1,330 @@
+//! Exchange of [`Series`] and [`DataFrame`]s with other native engines over the
+//! [Arrow C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html).
+//!
+//! A series is exported as a schema and an array per chunk, a `DataFrame` as a struct schema
+//! and a struct array per chunk. Extension types are stored in the field metadata as defined
+//! by the Arrow spec. On import the foreign buffers are adopted without copying where their
+//! layout matches the one of polars, after they are validated, so malformed input results in
+//! an error instead of undefined behavior.
+
+use arrow::array::specification::try_check_utf8;
+use arrow::array::{
+    Array, BinaryArray, BinaryViewArray, DictionaryArray, DictionaryKey, FixedSizeListArray,
+    ListArray, StructArray, Utf8Array, Utf8ViewArray,
+};
+use arrow::datatypes::{ArrowDataType, ArrowSchema as ArrowRecordSchema};
+use arrow::match_integer_type;
+use arrow::offset::{Offset, OffsetsBuffer};
+use polars_core::prelude::{polars_bail, polars_ensure, DataFrame, DataType};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use super::*;
+
+/// The extension type of a field, stored under the `ARROW:extension:name` and
+/// `ARROW:extension:metadata` keys of the field metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExtensionType {
+    pub name: String,
+    pub metadata: Option<String>,
+}
+
+fn with_extension(data_type: ArrowDataType, extension: Option<&ExtensionType>) -> ArrowDataType {
+    match extension {
+        Some(ext) => {
+            ArrowDataType::Extension(ext.name.clone(), Box::new(data_type), ext.metadata.clone())
+        },
+        None => data_type,
+    }
+}
+
+/// Split a data type in its storage type and extension type.
+fn split_extension(data_type: ArrowDataType) -> (ArrowDataType, Option<ExtensionType>) {
+    match data_type {
+        ArrowDataType::Extension(name, storage, metadata) => {
+            (*storage, Some(ExtensionType { name, metadata }))
+        },
+        data_type => (data_type, None),
+    }
+}
+
+fn check_offsets<O: Offset>(offsets: &OffsetsBuffer<O>, values_len: usize) -> PolarsResult<()> {
+    let offsets = offsets.buffer();
+    polars_ensure!(
+        offsets[0] >= O::default() && offsets.windows(2).all(|w| w[0] <= w[1]),
+        ComputeError: "offsets must be non-negative and monotonically increasing"
+    );
+    polars_ensure!(
+        offsets[offsets.len() - 1].to_usize() <= values_len,
+        ComputeError: "offsets must not exceed the values length"
+    );
+    Ok(())
+}
+
+fn check_dictionary<K: DictionaryKey>(arr: &dyn Array) -> PolarsResult<()> {
+    let arr = arr.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let len = arr.values().len();
+    for key in arr.keys().iter().flatten() {
+        match (*key).try_into() {
+            Ok(key) if key < len => {},
+            _ => polars_bail!(
+                ComputeError: "dictionary keys must be smaller than the number of values, {}", len
+            ),
+        }
+    }
+    validate_array(arr.values().as_ref())
+}
+
+/// Validate the invariants polars relies on of an array that was created by another engine.
+///
+/// The lengths of the buffers are checked when the array is imported; this checks the
+/// offsets, the dictionary keys and the utf8 values, recursing into nested arrays.
+pub fn validate_array(arr: &dyn Array) -> PolarsResult<()> {
+    use ArrowDataType as D;
+    match arr.data_type().to_logical_type() {
+        D::Utf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            check_offsets(arr.offsets(), arr.values().len())?;
+            try_check_utf8(arr.offsets(), arr.values())
+        },
+        D::LargeUtf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            check_offsets(arr.offsets(), arr.values().len())?;
+            try_check_utf8(arr.offsets(), arr.values())
+        },
+        D::Binary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            check_offsets(arr.offsets(), arr.values().len())
+        },
+        D::LargeBinary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            check_offsets(arr.offsets(), arr.values().len())
+        },
+        D::Utf8View => {
+            let arr = arr.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            Utf8ViewArray::try_new(
+                arr.data_type().clone(),
+                arr.views().clone(),
+                arr.data_buffers().clone(),
+                arr.validity().cloned(),
+            )
+            .map(|_| ())
+        },
+        D::BinaryView => {
+            let arr = arr.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            BinaryViewArray::try_new(
+                arr.data_type().clone(),
+                arr.views().clone(),
+                arr.data_buffers().clone(),
+                arr.validity().cloned(),
+            )
+            .map(|_| ())
+        },
+        D::List(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            check_offsets(arr.offsets(), arr.values().len())?;
+            validate_array(arr.values().as_ref())
+        },
+        D::LargeList(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            check_offsets(arr.offsets(), arr.values().len())?;
+            validate_array(arr.values().as_ref())
+        },
+        D::FixedSizeList(_, size) => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            polars_ensure!(
+                arr.values().len() >= arr.len() * size,
+                ComputeError: "fixed size list values are shorter than the list length"
+            );
+            validate_array(arr.values().as_ref())
+        },
+        D::Struct(_) => {
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            arr.values().iter().try_for_each(|values| {
+                polars_ensure!(
+                    values.len() >= arr.len(),
+                    ComputeError: "struct fields are shorter than the struct"
+                );
+                validate_array(values.as_ref())
+            })
+        },
+        D::Dictionary(key_type, _, _) => {
+            match_integer_type!(key_type, |$T| { check_dictionary::<$T>(arr) })
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Export `s` as a schema and an array per chunk.
+///
+/// If `extension` is given, the field has that extension type with the type of `s` as storage.
+pub fn export_series_to_c(
+    s: &Series,
+    extension: Option<&ExtensionType>,
+) -> (ArrowSchema, Vec<ArrowArray>) {
+    let data_type = with_extension(s.dtype().to_arrow(true), extension);
+    let field = ArrowField::new(s.name(), data_type, true);
+    let arrays = (0..s.chunks().len())
+        // Make sure we export the logical type.
+        .map(|i| ffi::export_array_to_c(s.to_arrow(i, true)))
+        .collect();
+    (ffi::export_field_to_c(&field), arrays)
+}
+
+/// Import a series from a schema and an array per chunk, together with the extension type of
+/// the field. The series holds the storage of an extension type.
+///
+/// # Safety
+/// `schema` and `arrays` must be valid and the arrays must have the type of `schema`.
+pub unsafe fn import_series_from_c(
+    schema: &ArrowSchema,
+    arrays: Vec<ArrowArray>,
+) -> PolarsResult<(Series, Option<ExtensionType>)> {
+    let field = ffi::import_field_from_c(schema)?;
+    let (data_type, extension) = split_extension(field.data_type);
+    if arrays.is_empty() {
+        let dtype = DataType::from(&data_type);
+        return Ok((Series::new_empty(&field.name, &dtype), extension));
+    }
+    let chunks = arrays
+        .into_iter()
+        .map(|array| {
+            let arr = ffi::import_array_from_c(array, data_type.clone())?;
+            validate_array(arr.as_ref())?;
+            Ok(arr)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let s = Series::try_from((field.name.as_str(), chunks))?;
+    Ok((s, extension))
+}
+
+/// Export `df` as a struct schema and a struct array per chunk.
+///
+/// The columns in `extensions` get the given extension type with the type of the column as
+/// storage.
+pub fn export_dataframe_to_c(
+    df: &DataFrame,
+    extensions: &[(&str, ExtensionType)],
+) -> PolarsResult<(ArrowSchema, Vec<ArrowArray>)> {
+    for (name, _) in extensions {
+        df.column(name)?;
+    }
+    let mut df = df.clone();
+    df.align_chunks();
+
+    let (storage_fields, fields): (Vec<_>, Vec<_>) = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let extension = extensions
+                .iter()
+                .find(|(name, _)| *name == s.name())
+                .map(|(_, extension)| extension);
+            let data_type = s.dtype().to_arrow(true);
+            (
+                ArrowField::new(s.name(), data_type.clone(), true),
+                ArrowField::new(s.name(), with_extension(data_type, extension), true),
+            )
+        })
+        .unzip();
+    // The arrays have the storage types, only the exported schema has the extension types.
+    let storage_type = ArrowDataType::Struct(storage_fields);
+    let arrays = df
+        .iter_chunks(true)
+        .map(|chunk| {
+            let arr = StructArray::new(storage_type.clone(), chunk.into_arrays(), None);
+            ffi::export_array_to_c(Box::new(arr))
+        })
+        .collect();
+    let field = ArrowField::new("", ArrowDataType::Struct(fields), false);
+    Ok((ffi::export_field_to_c(&field), arrays))
+}
+
+/// Import a `DataFrame` from a struct schema and a struct array per chunk, together with the
+/// columns that have an extension type. The columns hold the storage of the extension types.
+///
+/// # Safety
+/// `schema` and `arrays` must be valid and the arrays must have the type of `schema`.
+pub unsafe fn import_dataframe_from_c(
+    schema: &ArrowSchema,
+    arrays: Vec<ArrowArray>,
+) -> PolarsResult<(DataFrame, Vec<(String, ExtensionType)>)> {
+    let field = ffi::import_field_from_c(schema)?;
+    let ArrowDataType::Struct(fields) = field.data_type else {
+        polars_bail!(
+            ComputeError: "a DataFrame must be imported from a struct array, got {:?}",
+            field.data_type
+        )
+    };
+    let mut extensions = vec![];
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            let (data_type, extension) = split_extension(field.data_type);
+            if let Some(extension) = extension {
+                extensions.push((field.name.clone(), extension));
+            }
+            ArrowField::new(&field.name, data_type, field.is_nullable).with_metadata(field.metadata)
+        })
+        .collect::<Vec<_>>();
+    if arrays.is_empty() {
+        let schema = ArrowRecordSchema::from(fields);
+        return Ok((DataFrame::from(&schema), extensions));
+    }
+
+    let data_type = ArrowDataType::Struct(fields);
+    let dfs = arrays
+        .into_iter()
+        .map(|array| {
+            let arr = ffi::import_array_from_c(array, data_type.clone())?;
+            validate_array(arr.as_ref())?;
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            DataFrame::try_from(arr.clone())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok((accumulate_dataframes_vertical_unchecked(dfs), extensions))
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_c_data_roundtrip() -> PolarsResult<()> {
+        let extension = ExtensionType {
+            name: "example.uuid".into(),
+            metadata: Some("{}".into()),
+        };
+        let s = Series::new("a", ["x", "yy", "zzz", "w"]).slice(1, 2);
+        let (schema, arrays) = export_series_to_c(&s, Some(&extension));
+        let (out, out_extension) = unsafe { import_series_from_c(&schema, arrays)? };
+        assert!(out.equals(&s));
+        assert_eq!(out_extension, Some(extension.clone()));
+
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "x"],
+        ]?
+        .slice(1, 2);
+        let (schema, arrays) = export_dataframe_to_c(&df, &[("a", extension.clone())])?;
+        let (out, extensions) = unsafe { import_dataframe_from_c(&schema, arrays)? };
+        assert!(out.equals(&df));
+        assert_eq!(extensions, vec![("a".to_string(), extension)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_offsets() {
+        let arr = unsafe {
+            Utf8Array::<i64>::new_unchecked(
+                ArrowDataType::LargeUtf8,
+                OffsetsBuffer::new_unchecked(vec![0, 3, 1].into()),
+                b"abc".to_vec().into(),
+                None,
+            )
+        };
+        assert!(validate_array(&arr).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod c_data;
 pub mod version_0;
 
 use std::mem::ManuallyDrop;
@@ -3,7 +3,7 @@
 //!
 //! [parquet2]: https://crates.io/crates/parquet2
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use object_store::path::Path;
 use object_store::{MultipartId, ObjectStore};
@@ -13,12 +13,16 @@ use tokio::io::{AsyncWrite, AsyncWriteExt};
 use super::CloudOptions;
 use crate::pl_async::get_runtime;
 
-/// Adaptor which wraps the asynchronous interface of [ObjectStore::put_multipart](https://docs.rs/object_store/latest/object_store/trait.ObjectStore.html#tymethod.put_multipart)
-/// exposing a synchronous interface which implements `std::io::Write`.
-///
-/// This allows it to be used in sync code which would otherwise write to a simple File or byte stream,
-/// such as with `polars::prelude::CsvWriter`.
-pub struct CloudWriter {
+/// What is done with an upload when its [`CloudWriter`] is dropped.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OnDrop {
+    Complete,
+    Abort,
+    /// The upload was already completed by a [`CloudCommit`].
+    Nothing,
+}
+
+struct Upload {
     // Hold a reference to the store
     object_store: Arc<dyn ObjectStore>,
     // The path in the object_store which we want to write to
@@ -27,6 +31,47 @@ pub struct CloudWriter {
     multipart_id: MultipartId,
     // Internal writer, constructed at creation
     writer: Box<dyn AsyncWrite + Send + Unpin>,
+    on_drop: OnDrop,
+}
+
+impl Upload {
+    async fn abort(&self) -> PolarsResult<()> {
+        self.object_store
+            .abort_multipart(&self.path, &self.multipart_id)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    /// Complete the upload, and abort it if that fails.
+    async fn complete(&mut self) -> PolarsResult<()> {
+        let res = self.writer.shutdown().await;
+        if res.is_err() {
+            let _ = self.abort().await;
+        }
+        Ok(res?)
+    }
+}
+
+/// Adaptor which wraps the asynchronous interface of [ObjectStore::put_multipart](https://docs.rs/object_store/latest/object_store/trait.ObjectStore.html#tymethod.put_multipart)
+/// exposing a synchronous interface which implements `std::io::Write`.
+///
+/// This allows it to be used in sync code which would otherwise write to a simple File or byte stream,
+/// such as with `polars::prelude::CsvWriter`.
+pub struct CloudWriter {
+    // Shared with the [`CloudCommit`] of the writer, if it has one.
+    upload: Arc<Mutex<Upload>>,
+}
+
+/// Completes the upload of a [`CloudWriter`] once it is completely written.
+pub struct CloudCommit(Arc<Mutex<Upload>>);
+
+impl CloudCommit {
+    /// Complete the upload, which makes the object visible at its path.
+    pub fn commit(self) -> PolarsResult<()> {
+        let mut upload = self.0.lock().unwrap();
+        upload.on_drop = OnDrop::Nothing;
+        get_runtime().block_on(upload.complete())
+    }
 }
 
 impl CloudWriter {
@@ -40,14 +85,25 @@ impl CloudWriter {
         path: Path,
     ) -> PolarsResult<Self> {
         let (multipart_id, writer) = Self::build_writer(&object_store, &path).await?;
-        Ok(CloudWriter {
+        let upload = Upload {
             object_store,
             path,
             multipart_id,
             writer,
+            on_drop: OnDrop::Complete,
+        };
+        Ok(CloudWriter {
+            upload: Arc::new(Mutex::new(upload)),
         })
     }
 
+    /// Only complete the upload when [`CloudCommit::commit`] is called, and abort it if the
+    /// writer is dropped before.
+    pub fn deferred_commit(&mut self) -> CloudCommit {
+        self.upload.lock().unwrap().on_drop = OnDrop::Abort;
+        CloudCommit(self.upload.clone())
+    }
+
     /// Constructs a new CloudWriter from a path and an optional set of CloudOptions.
     ///
     /// Wrapper around `CloudWriter::new_with_object_store` that is useful if you only have a single write task.
@@ -65,31 +121,26 @@ impl CloudWriter {
         let (multipart_id, s3_writer) = object_store.put_multipart(path).await?;
         Ok((multipart_id, s3_writer))
     }
-
-    async fn abort(&self) -> PolarsResult<()> {
-        self.object_store
-            .abort_multipart(&self.path, &self.multipart_id)
-            .await
-            .map_err(to_compute_err)
-    }
 }
 
 impl std::io::Write for CloudWriter {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut upload = self.upload.lock().unwrap();
         get_runtime().block_on(async {
-            let res = self.writer.write(buf).await;
+            let res = upload.writer.write(buf).await;
             if res.is_err() {
-                let _ = self.abort().await;
+                let _ = upload.abort().await;
             }
             res
         })
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
+        let mut upload = self.upload.lock().unwrap();
         get_runtime().block_on(async {
-            let res = self.writer.flush().await;
+            let res = upload.writer.flush().await;
             if res.is_err() {
-                let _ = self.abort().await;
+                let _ = upload.abort().await;
             }
             res
         })
@@ -98,7 +149,18 @@ impl std::io::Write for CloudWriter {
 
 impl Drop for CloudWriter {
     fn drop(&mut self) {
-        let _ = get_runtime().block_on(self.writer.shutdown());
+        let Ok(mut upload) = self.upload.lock() else {
+            return;
+        };
+        match upload.on_drop {
+            OnDrop::Complete => {
+                let _ = get_runtime().block_on(upload.writer.shutdown());
+            },
+            OnDrop::Abort => {
+                let _ = get_runtime().block_on(upload.abort());
+            },
+            OnDrop::Nothing => {},
+        }
     }
 }
 
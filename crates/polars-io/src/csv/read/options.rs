@@ -25,6 +25,7 @@ pub struct CsvReaderOptions {
     pub decimal_comma: bool,
     pub n_threads: Option<usize>,
     pub low_memory: bool,
+    pub parse_error_policies: Option<Vec<(String, CsvParseErrorPolicy)>>,
 }
 
 impl Default for CsvReaderOptions {
@@ -49,6 +50,7 @@ impl Default for CsvReaderOptions {
             decimal_comma: false,
             n_threads: None,
             low_memory: false,
+            parse_error_policies: None,
         }
     }
 }
@@ -63,6 +65,20 @@ pub enum CsvEncoding {
     LossyUtf8,
 }
 
+/// What to do with the values of a column that cannot be parsed as the dtype of the column.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CsvParseErrorPolicy {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Replace the values with null.
+    Null,
+    /// Replace the values with null and keep the raw values in a `{column}_raw` String
+    /// column, which is null for the values that could be parsed.
+    Sidecar,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CommentPrefix {
@@ -53,15 +53,16 @@ pub fn count_rows(
         eol_char,
     );
 
+    let quote_char = quote_char.unwrap_or(b'"');
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
         let local_bytes = &reader_bytes[start..stop];
-        let row_iterator = SplitLines::new(local_bytes, quote_char.unwrap_or(b'"'), eol_char);
         if comment_prefix.is_some() {
+            let row_iterator = SplitLines::new(local_bytes, quote_char, eol_char);
             Ok(row_iterator
                 .filter(|line| !line.is_empty() && !is_comment_line(line, comment_prefix))
                 .count())
         } else {
-            Ok(row_iterator.count())
+            Ok(count_lines(local_bytes, quote_char, eol_char))
         }
     });
 
@@ -73,6 +74,26 @@ pub fn count_rows(
     }
 }
 
+/// Count the lines in `bytes` like [`SplitLines`] splits them, without visiting every byte.
+///
+/// Only the quote and end of line characters are searched for, which uses SIMD.
+fn count_lines(bytes: &[u8], quote_char: u8, eol_char: u8) -> usize {
+    let mut in_field = false;
+    let mut n_lines = 0;
+    // The position after the last end of line.
+    let mut line_start = 0;
+    for pos in memchr2_iter(quote_char, eol_char, bytes) {
+        if bytes[pos] == quote_char {
+            in_field = !in_field;
+        } else if !in_field {
+            n_lines += 1;
+            line_start = pos + 1;
+        }
+    }
+    // A last line without end of line character.
+    n_lines + (line_start < bytes.len()) as usize
+}
+
 /// Skip the utf-8 Byte Order Mark.
 /// credits to csv-core
 pub(super) fn skip_bom(input: &[u8]) -> &[u8] {
@@ -590,7 +611,7 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
 
 #[cfg(test)]
 mod test {
-    use super::SplitLines;
+    use super::{count_lines, SplitLines};
 
     #[test]
     fn test_splitlines() {
@@ -606,4 +627,24 @@ mod test {
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
     }
+
+    #[test]
+    fn test_count_lines() {
+        for input in [
+            "",
+            "a",
+            "a\n",
+            "a\nb",
+            "a\n\nb\n",
+            "1,\"foo\n\"\n2,\"foo\n\"\n",
+            "1,\"foo\n\"\n2,\"foo",
+        ] {
+            let expected = SplitLines::new(input.as_bytes(), b'"', b'\n').count();
+            assert_eq!(
+                count_lines(input.as_bytes(), b'"', b'\n'),
+                expected,
+                "{input:?}"
+            );
+        }
+    }
 }
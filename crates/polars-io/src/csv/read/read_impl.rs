@@ -670,7 +670,14 @@ impl<'a> CoreReader<'a> {
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
         let predicate = self.predicate.take();
-        let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
+        // In low memory mode a single chunk is decoded at a time.
+        let n_threads = self.n_threads.unwrap_or_else(|| {
+            if self.low_memory {
+                1
+            } else {
+                POOL.current_num_threads()
+            }
+        });
 
         let reader_bytes = self.reader_bytes.take().unwrap();
 
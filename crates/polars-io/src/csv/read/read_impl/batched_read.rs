@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
 
-use polars_core::datatypes::Field;
+use polars_core::datatypes::{DataType, Field};
 use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 use polars_core::POOL;
@@ -12,8 +12,11 @@ use polars_utils::IdxSize;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use super::{cast_columns, read_chunk, CoreReader};
-use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
+use crate::csv::read::options::{
+    CommentPrefix, CsvEncoding, CsvParseErrorPolicy, NullValuesCompiled,
+};
 use crate::csv::read::parser::next_line_position;
+use crate::csv::read::reader::apply_parse_error_policy;
 use crate::csv::read::CsvReader;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::update_row_counts2;
@@ -267,6 +270,7 @@ impl<'a> CoreReader<'a> {
             rows_read: 0,
             _cat_lock,
             decimal_comma: self.decimal_comma,
+            parse_error_policies: vec![],
         })
     }
 }
@@ -297,9 +301,20 @@ pub struct BatchedCsvReaderRead<'a> {
     #[cfg(not(feature = "dtype-categorical"))]
     _cat_lock: Option<u8>,
     decimal_comma: bool,
+    /// The columns that are read as String and parsed afterwards, see
+    /// [`CsvReader::with_parse_error_policies`].
+    parse_error_policies: Vec<(String, DataType, CsvParseErrorPolicy)>,
 }
 //
 impl<'a> BatchedCsvReaderRead<'a> {
+    pub(crate) fn with_parse_error_policies(
+        mut self,
+        policies: Vec<(String, DataType, CsvParseErrorPolicy)>,
+    ) -> Self {
+        self.parse_error_policies = policies;
+        self
+    }
+
     /// `n` number of batches.
     pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
         if n == 0 || self.finished {
@@ -367,6 +382,9 @@ impl<'a> BatchedCsvReaderRead<'a> {
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                    for (name, dtype, policy) in &self.parse_error_policies {
+                        apply_parse_error_policy(&mut df, name, dtype, *policy)?;
+                    }
 
                     if let Some(rc) = &self.row_index {
                         df.with_row_index_mut(&rc.name, Some(rc.offset));
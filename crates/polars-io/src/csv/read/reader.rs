@@ -8,7 +8,9 @@ use polars_time::prelude::*;
 use rayon::prelude::*;
 
 use super::infer_file_schema;
-use super::options::{CommentPrefix, CsvEncoding, CsvReaderOptions, NullValues};
+use super::options::{
+    CommentPrefix, CsvEncoding, CsvParseErrorPolicy, CsvReaderOptions, NullValues,
+};
 use super::read_impl::batched_mmap::{
     to_batched_owned_mmap, BatchedCsvReaderMmap, OwnedBatchedCsvReaderMmap,
 };
@@ -175,6 +177,20 @@ where
         self
     }
 
+    /// Set what to do with the values that cannot be parsed as the dtype of a column, per
+    /// column name. The dtypes of these columns must be given with [with_schema](Self::with_schema)
+    /// or [with_dtypes](Self::with_dtypes).
+    ///
+    /// By default parsing errors are raised, or the values are replaced with null if
+    /// [with_ignore_errors](Self::with_ignore_errors) is set.
+    pub fn with_parse_error_policies(
+        mut self,
+        policies: Option<Vec<(String, CsvParseErrorPolicy)>>,
+    ) -> Self {
+        self.options.parse_error_policies = policies;
+        self
+    }
+
     /// Raise an error if CSV is empty (otherwise return an empty frame)
     pub fn raise_if_empty(mut self, toggle: bool) -> Self {
         self.options.raise_if_empty = toggle;
@@ -198,12 +214,15 @@ where
     ///
     /// Note that this only works if this is initialized with `CsvReader::from_path`.
     /// Note that the number of cores is the maximum allowed number of threads.
+    /// This bounds the number of chunks that are decoded at the same time, and takes
+    /// precedence over low memory mode.
     pub fn with_n_threads(mut self, n: Option<usize>) -> Self {
         self.options.n_threads = n;
         self
     }
 
-    /// Reduce memory consumption at the expense of performance
+    /// Reduce memory consumption at the expense of performance. A single chunk is decoded at
+    /// a time, unless the number of threads is set.
     pub fn low_memory(mut self, toggle: bool) -> Self {
         self.options.low_memory = toggle;
         self
@@ -387,29 +406,65 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
         }
     }
 
+    /// Read the columns that have a parse error policy as String, so that they can be parsed
+    /// afterwards. Returns the columns with their dtype and policy.
+    fn prepare_parse_error_policies(
+        &mut self,
+    ) -> PolarsResult<Vec<(String, DataType, CsvParseErrorPolicy)>> {
+        let Some(policies) = self.options.parse_error_policies.take() else {
+            return Ok(vec![]);
+        };
+        policies
+            .into_iter()
+            .map(|(name, policy)| {
+                let schema = [
+                    &mut self.options.schema_overwrite,
+                    &mut self.options.schema,
+                ]
+                .into_iter()
+                .flatten()
+                .find(|schema| schema.contains(&name));
+                let Some(schema) = schema else {
+                    polars_bail!(
+                        ComputeError: "the dtype of column '{}' must be given to apply a parse error policy",
+                        name
+                    )
+                };
+                let schema = Arc::make_mut(schema);
+                let dtype = schema.get(&name).unwrap().clone();
+                schema.with_column(name.as_str().into(), DataType::String);
+                Ok((name, dtype, policy))
+            })
+            .collect()
+    }
+
     pub fn batched_borrowed_mmap(&'a mut self) -> PolarsResult<BatchedCsvReaderMmap<'a>> {
-        if let Some(schema) = self.options.schema_overwrite.as_deref() {
+        let to_parse = self.prepare_parse_error_policies()?;
+        let reader = if let Some(schema) = self.options.schema_overwrite.as_deref() {
             let (schema, to_cast, has_cat) = self.prepare_schema_overwrite(schema)?;
             let schema = Arc::new(schema);
 
             let csv_reader = self.core_reader(Some(schema), to_cast)?;
-            csv_reader.batched_mmap(has_cat)
+            csv_reader.batched_mmap(has_cat)?
         } else {
             let csv_reader = self.core_reader(self.options.schema.clone(), vec![])?;
-            csv_reader.batched_mmap(false)
-        }
+            csv_reader.batched_mmap(false)?
+        };
+        Ok(reader.with_parse_error_policies(to_parse))
     }
     pub fn batched_borrowed_read(&'a mut self) -> PolarsResult<BatchedCsvReaderRead<'a>> {
-        if let Some(schema) = self.options.schema_overwrite.as_deref() {
+        let to_parse = self.prepare_parse_error_policies()?;
+        let reader = if let Some(schema) = self.options.schema_overwrite.as_deref() {
             let (schema, to_cast, has_cat) = self.prepare_schema_overwrite(schema)?;
             let schema = Arc::new(schema);
 
             let csv_reader = self.core_reader(Some(schema), to_cast)?;
-            csv_reader.batched_read(has_cat)
+            csv_reader.batched_read(has_cat)?
         } else {
             let csv_reader = self.core_reader(self.options.schema.clone(), vec![])?;
-            csv_reader.batched_read(false)
-        }
+            csv_reader.batched_read(false)?
+        };
+        Ok(reader.with_parse_error_policies(to_parse))
     }
 }
 
@@ -503,6 +558,7 @@ where
 
     /// Read the file and create the DataFrame.
     fn finish(mut self) -> PolarsResult<DataFrame> {
+        let to_parse = self.prepare_parse_error_policies()?;
         let rechunk = self.rechunk;
         let schema_overwrite = self.options.schema_overwrite.clone();
         let low_memory = self.options.low_memory;
@@ -570,10 +626,48 @@ where
             };
             df = parse_dates(df, &fixed_schema)
         }
+
+        for (name, dtype, policy) in &to_parse {
+            apply_parse_error_policy(&mut df, name, dtype, *policy)?;
+        }
         Ok(df)
     }
 }
 
+/// Parse the String column `name` as `dtype`, handling the values that cannot be parsed
+/// according to `policy`.
+pub(super) fn apply_parse_error_policy(
+    df: &mut DataFrame,
+    name: &str,
+    dtype: &DataType,
+    policy: CsvParseErrorPolicy,
+) -> PolarsResult<()> {
+    // The column may not be projected.
+    let Some(idx) = df.get_column_index(name) else {
+        return Ok(());
+    };
+    let raw = df.get_columns()[idx].clone();
+    let parsed = match policy {
+        CsvParseErrorPolicy::Raise => raw.strict_cast(dtype)?,
+        CsvParseErrorPolicy::Null => raw.cast(dtype)?,
+        CsvParseErrorPolicy::Sidecar => {
+            let parsed = raw.cast(dtype)?;
+            let raw_name = format!("{name}_raw");
+            polars_ensure!(
+                df.get_column_index(&raw_name).is_none(),
+                Duplicate: "column '{}' for the unparsed values of '{}' already exists", raw_name, name
+            );
+            // Only keep the raw values that failed to parse.
+            let mut failed = raw.str()?.set(&parsed.is_not_null(), None)?;
+            failed.rename(&raw_name);
+            df.with_column(failed.into_series())?;
+            parsed
+        },
+    };
+    df.replace_column(idx, parsed)?;
+    Ok(())
+}
+
 #[cfg(feature = "temporal")]
 fn parse_dates(mut df: DataFrame, fixed_schema: &Schema) -> DataFrame {
     use polars_core::POOL;
@@ -3,6 +3,8 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::CommitStrategy;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +14,9 @@ pub struct CsvWriterOptions {
     pub batch_size: NonZeroUsize,
     pub maintain_order: bool,
     pub serialize_options: SerializeOptions,
+    /// How the file is made visible at its path when it is written by a sink.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub commit_strategy: CommitStrategy,
 }
 
 impl Default for CsvWriterOptions {
@@ -22,6 +27,7 @@ impl Default for CsvWriterOptions {
             batch_size: NonZeroUsize::new(1024).unwrap(),
             maintain_order: false,
             serialize_options: SerializeOptions::default(),
+            commit_strategy: CommitStrategy::default(),
         }
     }
 }
@@ -0,0 +1,815 @@
+//! Writing to Delta Lake tables.
+//!
+//! The parquet files of a write are first written to a staging directory in the root of the
+//! table, laid out in Hive partitions of the partition columns of the table. A
+//! [`DeltaTransaction`] then moves them into the table and commits their `add` actions, with
+//! their statistics, to the transaction log in `_delta_log`.
+//!
+//! A commit is linked to its path in the log, so that only one of the writers that commit the
+//! same version concurrently succeeds; the others commit after it, unless its changes conflict
+//! with theirs.
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::parquet::read::ParquetReader;
+use crate::SerReader;
+
+/// The number of times that a commit is retried after the commits of other writers.
+const MAX_COMMIT_ATTEMPTS: usize = 100;
+
+/// The characters that are percent-encoded in the paths of the actions.
+const PATH_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'%');
+
+/// How a write adds its data to a Delta table.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeltaWriteMode {
+    /// Add the files to the table.
+    #[default]
+    Append,
+    /// Remove the files of the partitions that are written and keep the other partitions. The
+    /// files of a table without partition columns are all removed.
+    OverwritePartitions,
+}
+
+/// Options for writing to a Delta table.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeltaWriteOptions {
+    /// The partition columns of a table that is created. An existing table keeps its
+    /// partition columns, which these must match if they are given.
+    pub partition_by: Vec<String>,
+    pub mode: DeltaWriteMode,
+}
+
+impl DeltaWriteOptions {
+    pub fn with_partition_by<I, S>(mut self, partition_by: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.partition_by = partition_by.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_mode(mut self, mode: DeltaWriteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+/// An id in the format of a UUID, which is unique for a process at a point in time.
+fn unique_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    let id = (nanos << 32) | std::process::id() as u128;
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        (id >> 96) as u32,
+        (id >> 80) as u16,
+        (id >> 64) as u16,
+        (id >> 48) as u16,
+        id as u64 & 0xffff_ffff_ffff
+    )
+}
+
+/// The Delta type of `dtype`.
+fn to_delta_type(dtype: &DataType) -> PolarsResult<Value> {
+    let delta_type = match dtype {
+        DataType::Boolean => json!("boolean"),
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => json!("byte"),
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => json!("short"),
+        DataType::Int32 => json!("integer"),
+        DataType::Int64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::String => json!("string"),
+        DataType::Binary => json!("binary"),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => json!("date"),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(TimeUnit::Microseconds, Some(_)) => json!("timestamp"),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(TimeUnit::Microseconds, None) => json!("timestamp_ntz"),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(Some(precision), Some(scale)) => {
+            json!(format!("decimal({precision},{scale})"))
+        },
+        DataType::List(inner) => json!({
+            "type": "array",
+            "elementType": to_delta_type(inner)?,
+            "containsNull": true,
+        }),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => json!({
+            "type": "struct",
+            "fields": fields
+                .iter()
+                .map(|field| to_delta_field(field.name(), field.data_type()))
+                .collect::<PolarsResult<Vec<_>>>()?,
+        }),
+        dtype => polars_bail!(
+            ComputeError: "the data type {} can't be written to a Delta table", dtype
+        ),
+    };
+    Ok(delta_type)
+}
+
+fn to_delta_field(name: &str, dtype: &DataType) -> PolarsResult<Value> {
+    Ok(json!({
+        "name": name,
+        "type": to_delta_type(dtype)?,
+        "nullable": true,
+        "metadata": {},
+    }))
+}
+
+/// The name and the type of the fields of a Delta schema.
+fn delta_schema_fields(schema_string: &str) -> PolarsResult<Vec<(String, Value)>> {
+    let schema: Value = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+    let fields = schema["fields"]
+        .as_array()
+        .ok_or_else(|| polars_err!(ComputeError: "invalid Delta schema: {}", schema_string))?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = field["name"]
+                .as_str()
+                .ok_or_else(|| polars_err!(ComputeError: "invalid Delta field: {}", field))?;
+            Ok((name.to_string(), field["type"].clone()))
+        })
+        .collect()
+}
+
+/// The state of a Delta table, replayed from the commits of its transaction log.
+struct DeltaTableState {
+    version: i64,
+    schema_fields: Vec<(String, Value)>,
+    partition_columns: Vec<String>,
+    configuration: Map<String, Value>,
+    /// The paths of the files of the table and their partition values.
+    files: PlIndexMap<String, Value>,
+}
+
+/// Check that this writer supports the features of a table with `protocol`.
+fn check_protocol(protocol: &Value) -> PolarsResult<()> {
+    let version = protocol["minWriterVersion"].as_i64().unwrap_or(0);
+    let features = protocol["writerFeatures"].as_array();
+    let supported = version <= 2
+        || (version == 7
+            && features.map_or(true, |features| {
+                features.iter().all(|f| f.as_str() == Some("timestampNtz"))
+            }));
+    polars_ensure!(
+        supported,
+        ComputeError: "writing to a Delta table with the writer protocol {} isn't supported",
+        protocol
+    );
+    Ok(())
+}
+
+/// A file of the transaction log of a Delta table.
+enum LogFile {
+    Commit(i64),
+    /// A part of the checkpoint of a version, `part` of `parts` starting at 1.
+    Checkpoint {
+        version: i64,
+        part: usize,
+        parts: usize,
+    },
+}
+
+fn parse_log_file(name: &str) -> Option<LogFile> {
+    let parse_version = |version: &str| {
+        Some(version)
+            .filter(|version| version.len() == 20)
+            .and_then(|version| version.parse::<i64>().ok())
+    };
+    if let Some(version) = name.strip_suffix(".json") {
+        return parse_version(version).map(LogFile::Commit);
+    }
+    let name = name.strip_suffix(".parquet")?;
+    let (version, checkpoint) = name.split_once(".checkpoint")?;
+    let version = parse_version(version)?;
+    let (part, parts) = match checkpoint {
+        "" => (1, 1),
+        parts => {
+            let (part, parts) = parts.strip_prefix('.')?.split_once('.')?;
+            (part.parse().ok()?, parts.parse().ok()?)
+        },
+    };
+    Some(LogFile::Checkpoint {
+        version,
+        part,
+        parts,
+    })
+}
+
+fn commit_path(log_dir: &Path, version: i64) -> PathBuf {
+    log_dir.join(format!("{version:020}.json"))
+}
+
+/// The actions of the commit of `version`.
+fn read_commit(log_dir: &Path, version: i64) -> PolarsResult<Vec<Value>> {
+    let commit = std::fs::read_to_string(commit_path(log_dir, version))?;
+    commit
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(to_compute_err))
+        .collect()
+}
+
+/// Convert a value of a checkpoint to JSON, as it is in the commits. The maps of the
+/// checkpoint, which are read as lists of `key`/`value` structs, become objects.
+fn checkpoint_value(value: AnyValue) -> Value {
+    match value {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(v) => json!(v),
+        AnyValue::String(v) => json!(v),
+        AnyValue::StringOwned(v) => json!(v.as_str()),
+        AnyValue::List(s) => {
+            let is_map = matches!(
+                s.dtype(),
+                DataType::Struct(fields) if fields.len() == 2
+                    && fields[0].name() == "key"
+                    && fields[1].name() == "value"
+            );
+            let values = s.iter().map(checkpoint_value);
+            if is_map {
+                let entries = values.filter_map(|entry| {
+                    let Value::Object(mut entry) = entry else {
+                        return None;
+                    };
+                    let key = entry.remove("key")?.as_str()?.to_string();
+                    Some((key, entry.remove("value").unwrap_or(Value::Null)))
+                });
+                Value::Object(entries.collect())
+            } else {
+                Value::Array(values.collect())
+            }
+        },
+        AnyValue::Struct(_, _, fields) => {
+            if value.is_nested_null() {
+                return Value::Null;
+            }
+            let values = fields
+                .iter()
+                .zip(value._iter_struct_av())
+                .map(|(field, value)| (field.name().to_string(), checkpoint_value(value)));
+            Value::Object(values.collect())
+        },
+        AnyValue::StructOwned(payload) => {
+            let (values, fields) = *payload;
+            if values.iter().all(|value| value.is_nested_null()) {
+                return Value::Null;
+            }
+            let values = fields
+                .iter()
+                .zip(values)
+                .map(|(field, value)| (field.name().to_string(), checkpoint_value(value)));
+            Value::Object(values.collect())
+        },
+        value if value.dtype().is_integer() => json!(value.extract::<i64>()),
+        value if value.dtype().is_float() => json!(value.extract::<f64>()),
+        _ => Value::Null,
+    }
+}
+
+/// The actions of a checkpoint, with a row per action and a column per kind of action.
+fn read_checkpoint(paths: &[PathBuf]) -> PolarsResult<Vec<Value>> {
+    let mut actions = vec![];
+    for path in paths {
+        let df = ParquetReader::new(File::open(path)?).finish()?;
+        let columns = ["protocol", "metaData", "add", "remove"]
+            .into_iter()
+            .filter_map(|kind| Some((kind, df.column(kind).ok()?)))
+            .collect::<Vec<_>>();
+        for i in 0..df.height() {
+            let mut action = Map::new();
+            for (kind, column) in &columns {
+                let value = checkpoint_value(column.get(i)?);
+                if !value.is_null() {
+                    action.insert(kind.to_string(), value);
+                }
+            }
+            actions.push(Value::Object(action));
+        }
+    }
+    Ok(actions)
+}
+
+impl DeltaTableState {
+    fn apply(&mut self, action: &Value) -> PolarsResult<()> {
+        if let Some(protocol) = action.get("protocol") {
+            check_protocol(protocol)?;
+        } else if let Some(metadata) = action.get("metaData") {
+            let schema_string = metadata["schemaString"].as_str().unwrap_or_default();
+            self.schema_fields = delta_schema_fields(schema_string)?;
+            self.partition_columns = metadata["partitionColumns"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|name| name.as_str().map(String::from))
+                .collect();
+            self.configuration = metadata["configuration"]
+                .as_object()
+                .cloned()
+                .unwrap_or_default();
+        } else if let Some(add) = action.get("add") {
+            if let Some(path) = add["path"].as_str() {
+                let partition_values = add["partitionValues"].clone();
+                self.files.insert(path.to_string(), partition_values);
+            }
+        } else if let Some(remove) = action.get("remove") {
+            if let Some(path) = remove["path"].as_str() {
+                self.files.shift_remove(path);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Replay the transaction log of the table at `root`, from its last complete checkpoint if it
+/// has one.
+fn read_table_state(root: &Path) -> PolarsResult<Option<DeltaTableState>> {
+    let log_dir = root.join("_delta_log");
+    if !log_dir.exists() {
+        return Ok(None);
+    }
+    let mut commits = vec![];
+    let mut checkpoints = PlHashMap::<i64, Vec<(usize, usize, PathBuf)>>::new();
+    for entry in std::fs::read_dir(&log_dir)? {
+        let entry = entry?;
+        match entry.file_name().to_str().and_then(parse_log_file) {
+            Some(LogFile::Commit(version)) => commits.push(version),
+            Some(LogFile::Checkpoint {
+                version,
+                part,
+                parts,
+            }) => checkpoints
+                .entry(version)
+                .or_default()
+                .push((part, parts, entry.path())),
+            None => {},
+        }
+    }
+    // A checkpoint of multiple parts can only be read once all of them are written.
+    let checkpoint = checkpoints
+        .into_iter()
+        .filter_map(|(version, mut parts)| {
+            parts.sort_unstable_by_key(|(part, _, _)| *part);
+            let n = parts.len();
+            let complete = parts
+                .iter()
+                .enumerate()
+                .all(|(i, (part, parts, _))| *part == i + 1 && *parts == n);
+            complete.then(|| {
+                (
+                    version,
+                    parts.into_iter().map(|(_, _, path)| path).collect(),
+                )
+            })
+        })
+        .max_by_key(|(version, _): &(i64, Vec<PathBuf>)| *version);
+
+    let first_commit = checkpoint.as_ref().map_or(0, |(version, _)| version + 1);
+    commits.retain(|version| *version >= first_commit);
+    commits.sort_unstable();
+    if checkpoint.is_none() && commits.is_empty() {
+        return Ok(None);
+    }
+    let contiguous = commits
+        .iter()
+        .enumerate()
+        .all(|(i, version)| *version == first_commit + i as i64);
+    polars_ensure!(
+        contiguous,
+        ComputeError: "the transaction log of the Delta table '{}' is missing commits",
+        root.display()
+    );
+
+    let mut state = DeltaTableState {
+        version: first_commit - 1,
+        schema_fields: vec![],
+        partition_columns: vec![],
+        configuration: Map::new(),
+        files: PlIndexMap::new(),
+    };
+    if let Some((_, paths)) = &checkpoint {
+        for action in read_checkpoint(paths)? {
+            state.apply(&action)?;
+        }
+    }
+    for version in commits {
+        for action in read_commit(&log_dir, version)? {
+            state.apply(&action)?;
+        }
+        state.version = version;
+    }
+    Ok(Some(state))
+}
+
+/// Convert a value of the statistics of a file to JSON, if Delta keeps statistics for its
+/// type.
+fn stats_value(value: AnyValue) -> Option<Value> {
+    match value {
+        AnyValue::Int8(v) => Some(json!(v)),
+        AnyValue::Int16(v) => Some(json!(v)),
+        AnyValue::Int32(v) => Some(json!(v)),
+        AnyValue::Int64(v) => Some(json!(v)),
+        AnyValue::Float32(v) if v.is_finite() => Some(json!(v)),
+        AnyValue::Float64(v) if v.is_finite() => Some(json!(v)),
+        AnyValue::String(v) => Some(json!(v)),
+        AnyValue::StringOwned(v) => Some(json!(v.as_str())),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(_) => Some(json!(value.to_string())),
+        _ => None,
+    }
+}
+
+/// The statistics of the parquet file at `path`, in the format of the `stats` of an `add`
+/// action.
+fn file_stats(path: &Path) -> PolarsResult<Value> {
+    let mut reader = ParquetReader::new(File::open(path)?);
+    let schema = reader.schema()?;
+    let metadata = reader.get_metadata()?;
+
+    let mut min_values = Map::new();
+    let mut max_values = Map::new();
+    let mut null_count = Map::new();
+    for field in schema.fields.iter() {
+        let mut mins = vec![];
+        let mut maxs = vec![];
+        let mut nulls = vec![];
+        for row_group in &metadata.row_groups {
+            let stats = polars_parquet::read::statistics::deserialize(field, row_group)?;
+            nulls.push(Series::try_from(("", stats.null_count))?);
+            mins.push(Series::try_from(("", stats.min_value))?);
+            maxs.push(Series::try_from(("", stats.max_value))?);
+        }
+        let concat = |series: Vec<Series>| {
+            series.into_iter().reduce(|mut acc, s| {
+                let _ = acc.append(&s);
+                acc
+            })
+        };
+        let (Some(mins), Some(maxs), Some(nulls)) = (concat(mins), concat(maxs), concat(nulls))
+        else {
+            continue;
+        };
+        // Without the null counts of all row groups, the statistics of the column are unknown.
+        if nulls.null_count() > 0 || nulls.dtype().is_nested() {
+            continue;
+        }
+        null_count.insert(field.name.clone(), json!(nulls.sum::<i64>()?));
+        if mins.null_count() == 0 && maxs.null_count() == 0 {
+            let min = stats_value(mins.min_as_series()?.get(0)?.into_static()?);
+            let max = stats_value(maxs.max_as_series()?.get(0)?.into_static()?);
+            if let (Some(min), Some(max)) = (min, max) {
+                min_values.insert(field.name.clone(), min);
+                max_values.insert(field.name.clone(), max);
+            }
+        }
+    }
+    Ok(json!({
+        "numRecords": metadata.num_rows,
+        "minValues": min_values,
+        "maxValues": max_values,
+        "nullCount": null_count,
+    }))
+}
+
+/// The partition values of the Hive partition directory `dir`, `key=value/...`.
+fn partition_values(dir: &Path) -> PolarsResult<Map<String, Value>> {
+    let mut values = Map::new();
+    for component in dir.components() {
+        let component = component.as_os_str().to_string_lossy();
+        let (key, value) = component.split_once('=').ok_or_else(
+            || polars_err!(ComputeError: "invalid partition directory '{}'", dir.display()),
+        )?;
+        let value = if value == "__HIVE_DEFAULT_PARTITION__" {
+            Value::Null
+        } else {
+            json!(percent_decode_str(value).decode_utf8_lossy())
+        };
+        values.insert(key.to_string(), value);
+    }
+    Ok(values)
+}
+
+/// The parquet files in `dir` and its subdirectories.
+fn staged_files(dir: &Path, out: &mut Vec<PathBuf>) -> PolarsResult<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            staged_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "parquet") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// A write to a Delta table, from the check of its schema to the commit of its files.
+pub struct DeltaTransaction {
+    root: PathBuf,
+    state: Option<DeltaTableState>,
+    schema: Schema,
+    partition_columns: Vec<String>,
+    mode: DeltaWriteMode,
+    staging_dir: PathBuf,
+}
+
+impl DeltaTransaction {
+    /// Start a write of data with `schema` to the Delta table at `root`, which is created if
+    /// it doesn't exist. The schema must match the schema of an existing table.
+    pub fn try_new(
+        root: &Path,
+        schema: &Schema,
+        options: &DeltaWriteOptions,
+    ) -> PolarsResult<Self> {
+        let state = read_table_state(root)?;
+        let partition_columns = match &state {
+            Some(state) => {
+                let fields = schema
+                    .iter()
+                    .map(|(name, dtype)| Ok((name.to_string(), to_delta_type(dtype)?)))
+                    .collect::<PolarsResult<PlHashMap<_, _>>>()?;
+                let matches = fields.len() == state.schema_fields.len()
+                    && state
+                        .schema_fields
+                        .iter()
+                        .all(|(name, delta_type)| fields.get(name) == Some(delta_type));
+                polars_ensure!(
+                    matches,
+                    SchemaMismatch: "the schema of the data doesn't match the schema of the \
+                    Delta table '{}'",
+                    root.display()
+                );
+                polars_ensure!(
+                    options.partition_by.is_empty()
+                        || options.partition_by == state.partition_columns,
+                    SchemaMismatch: "the partition columns {:?} don't match the partition \
+                    columns {:?} of the Delta table",
+                    options.partition_by, state.partition_columns
+                );
+                polars_ensure!(
+                    options.mode == DeltaWriteMode::Append
+                        || state.configuration.get("delta.appendOnly")
+                            != Some(&json!("true")),
+                    ComputeError: "the Delta table '{}' is append-only", root.display()
+                );
+                state.partition_columns.clone()
+            },
+            None => {
+                for name in &options.partition_by {
+                    polars_ensure!(
+                        schema.contains(name),
+                        ColumnNotFound: "the partition column '{}' isn't a column", name
+                    );
+                }
+                options.partition_by.clone()
+            },
+        };
+
+        let staging_dir = root.join(format!("_polars_staging_{}", unique_id()));
+        std::fs::create_dir_all(&staging_dir)?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            state,
+            schema: schema.clone(),
+            partition_columns,
+            mode: options.mode,
+            staging_dir,
+        })
+    }
+
+    /// The partition columns of the table.
+    pub fn partition_columns(&self) -> &[String] {
+        &self.partition_columns
+    }
+
+    /// The directory to which the parquet files are written before they are committed. The
+    /// files must be written to Hive partitions of the partition columns, without these
+    /// columns. The directory is removed once the transaction is dropped.
+    pub fn staging_dir(&self) -> &Path {
+        &self.staging_dir
+    }
+
+    /// The protocol of a table with `fields`, which needs the `timestampNtz` feature if a
+    /// field has a timestamp without a time zone.
+    fn protocol(fields: &[Value]) -> Value {
+        fn has_timestamp_ntz(value: &Value) -> bool {
+            match value {
+                Value::String(s) => s == "timestamp_ntz",
+                Value::Array(values) => values.iter().any(has_timestamp_ntz),
+                Value::Object(values) => values.values().any(has_timestamp_ntz),
+                _ => false,
+            }
+        }
+        if fields.iter().any(has_timestamp_ntz) {
+            json!({
+                "minReaderVersion": 3,
+                "minWriterVersion": 7,
+                "readerFeatures": ["timestampNtz"],
+                "writerFeatures": ["timestampNtz"],
+            })
+        } else {
+            json!({"minReaderVersion": 1, "minWriterVersion": 2})
+        }
+    }
+
+    /// Move the files in the staging directory into the table and commit them. Returns the
+    /// version of the table that is committed.
+    ///
+    /// If other writers committed in the meantime, the commit is retried after their commits,
+    /// unless these change the schema of the table, or change the partitions that this write
+    /// overwrites. The files are removed from the table if the commit fails.
+    pub fn commit(self) -> PolarsResult<i64> {
+        let mut moved = vec![];
+        let result = self.try_commit(&mut moved);
+        if result.is_err() {
+            // Without their commit, the files aren't part of the table.
+            for path in moved {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+        result
+    }
+
+    fn try_commit(&self, moved: &mut Vec<PathBuf>) -> PolarsResult<i64> {
+        let timestamp = now_millis();
+        let mut actions = vec![];
+        let version = match &self.state {
+            Some(state) => state.version + 1,
+            None => {
+                let fields = self
+                    .schema
+                    .iter()
+                    .map(|(name, dtype)| to_delta_field(name, dtype))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                actions.push(json!({"protocol": Self::protocol(&fields)}));
+                let schema_string = json!({"type": "struct", "fields": fields}).to_string();
+                actions.push(json!({"metaData": {
+                    "id": unique_id(),
+                    "format": {"provider": "parquet", "options": {}},
+                    "schemaString": schema_string,
+                    "partitionColumns": self.partition_columns,
+                    "configuration": {},
+                    "createdTime": timestamp,
+                }}));
+                0
+            },
+        };
+
+        let mut files = vec![];
+        staged_files(&self.staging_dir, &mut files)?;
+        files.sort();
+        let mut partitions = vec![];
+        for (i, file) in files.iter().enumerate() {
+            let dir = file
+                .parent()
+                .and_then(|dir| dir.strip_prefix(&self.staging_dir).ok())
+                .unwrap_or(Path::new(""));
+            let values = partition_values(dir)?;
+            let stats = file_stats(file)?;
+            let name = format!("part-{i:05}-{}.parquet", unique_id());
+            let target = self.root.join(dir).join(&name);
+            std::fs::create_dir_all(self.root.join(dir))?;
+            std::fs::rename(file, &target)?;
+            moved.push(target.clone());
+
+            let path = dir
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .chain(std::iter::once(name))
+                .collect::<Vec<_>>()
+                .join("/");
+            actions.push(json!({"add": {
+                "path": utf8_percent_encode(&path, PATH_ENCODE_SET).to_string(),
+                "partitionValues": values,
+                "size": std::fs::metadata(&target)?.len(),
+                "modificationTime": timestamp,
+                "dataChange": true,
+                "stats": stats.to_string(),
+            }}));
+            partitions.push(Value::Object(values));
+        }
+
+        if let (DeltaWriteMode::OverwritePartitions, Some(state)) = (self.mode, &self.state) {
+            for (path, values) in &state.files {
+                // Without partition columns, the whole table is a single partition.
+                if self.partition_columns.is_empty() || partitions.contains(values) {
+                    actions.push(json!({"remove": {
+                        "path": path,
+                        "partitionValues": values,
+                        "deletionTimestamp": timestamp,
+                        "dataChange": true,
+                    }}));
+                }
+            }
+        }
+        let mode = match self.mode {
+            DeltaWriteMode::Append => "Append",
+            DeltaWriteMode::OverwritePartitions => "Overwrite",
+        };
+        actions.push(json!({"commitInfo": {
+            "timestamp": timestamp,
+            "operation": "WRITE",
+            "operationParameters": {
+                "mode": mode,
+                "partitionBy": json!(self.partition_columns).to_string(),
+            },
+            "engineInfo": "polars",
+        }}));
+
+        let mut commit = String::new();
+        for action in actions {
+            commit.push_str(&action.to_string());
+            commit.push('\n');
+        }
+        let log_dir = self.root.join("_delta_log");
+        std::fs::create_dir_all(&log_dir)?;
+        let mut version = version;
+        for _ in 0..MAX_COMMIT_ATTEMPTS {
+            // Write the commit next to its path and link it, which fails if another writer
+            // committed this version in the meantime.
+            let tmp_path = log_dir.join(format!(".{version:020}.json.{}.tmp", unique_id()));
+            std::fs::write(&tmp_path, &commit)?;
+            let linked = std::fs::hard_link(&tmp_path, commit_path(&log_dir, version));
+            std::fs::remove_file(&tmp_path)?;
+            match linked {
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                    self.check_conflicts(&log_dir, version, &partitions)?;
+                    version += 1;
+                },
+                result => {
+                    result?;
+                    return Ok(version);
+                },
+            }
+        }
+        polars_bail!(
+            ComputeError: "the commit to the Delta table '{}' failed after {} concurrent commits",
+            self.root.display(), MAX_COMMIT_ATTEMPTS
+        )
+    }
+
+    /// Check that the commit of `version` of another writer doesn't conflict with this write,
+    /// which then commits after it.
+    fn check_conflicts(
+        &self,
+        log_dir: &Path,
+        version: i64,
+        partitions: &[Value],
+    ) -> PolarsResult<()> {
+        for action in read_commit(log_dir, version)? {
+            let conflicts = if action.get("protocol").is_some() || action.get("metaData").is_some()
+            {
+                // The commit creates the table, or changes its schema.
+                true
+            } else if self.mode == DeltaWriteMode::OverwritePartitions {
+                // Without partition columns, the whole table is a single partition.
+                ["add", "remove"]
+                    .into_iter()
+                    .filter_map(|kind| action.get(kind))
+                    .any(|file| {
+                        self.partition_columns.is_empty()
+                            || partitions.contains(&file["partitionValues"])
+                    })
+            } else {
+                false
+            };
+            polars_ensure!(
+                !conflicts,
+                ComputeError: "version {} of the Delta table '{}' was committed concurrently and \
+                conflicts with this write",
+                version, self.root.display()
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DeltaTransaction {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.staging_dir);
+    }
+}
@@ -0,0 +1,345 @@
+//! A reader of the Avro object container files that Iceberg stores its manifest lists and
+//! manifests in.
+//!
+//! Only what these files need is supported: the values are decoded with the schema of the
+//! writer, logical types are read as their underlying type, and the blocks may be stored
+//! without compression, or compressed with deflate or zstandard.
+use std::collections::BTreeMap;
+use std::io::Read;
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use serde_json::Value;
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+const SYNC_LEN: usize = 16;
+
+/// An Avro schema, in which references to named types are resolved.
+#[derive(Clone, Debug)]
+enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<(String, AvroSchema)>),
+    Enum(Vec<String>),
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+    Fixed(usize),
+}
+
+impl AvroSchema {
+    fn parse(value: &Value, named: &mut BTreeMap<String, AvroSchema>) -> PolarsResult<Self> {
+        let schema = match value {
+            Value::String(name) => match name.as_str() {
+                "null" => Self::Null,
+                "boolean" => Self::Boolean,
+                "int" => Self::Int,
+                "long" => Self::Long,
+                "float" => Self::Float,
+                "double" => Self::Double,
+                "bytes" => Self::Bytes,
+                "string" => Self::String,
+                name => named
+                    .get(name)
+                    .or_else(|| named.get(name.rsplit('.').next().unwrap()))
+                    .cloned()
+                    .ok_or_else(|| polars_err!(ComputeError: "unknown Avro type '{}'", name))?,
+            },
+            Value::Array(variants) => Self::Union(
+                variants
+                    .iter()
+                    .map(|variant| Self::parse(variant, named))
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            Value::Object(object) => {
+                let type_name = object.get("type").ok_or_else(
+                    || polars_err!(ComputeError: "Avro schema without a type: {}", value),
+                )?;
+                let schema = match type_name.as_str() {
+                    Some("record") => Self::Record(
+                        object["fields"]
+                            .as_array()
+                            .ok_or_else(|| polars_err!(ComputeError: "Avro record without fields"))?
+                            .iter()
+                            .map(|field| {
+                                let name = field["name"].as_str().unwrap_or_default().to_string();
+                                Ok((name, Self::parse(&field["type"], named)?))
+                            })
+                            .collect::<PolarsResult<_>>()?,
+                    ),
+                    Some("enum") => Self::Enum(
+                        object["symbols"]
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .map(|symbol| symbol.as_str().unwrap_or_default().to_string())
+                            .collect(),
+                    ),
+                    Some("array") => Self::Array(Box::new(Self::parse(&object["items"], named)?)),
+                    Some("map") => Self::Map(Box::new(Self::parse(&object["values"], named)?)),
+                    Some("fixed") => Self::Fixed(object["size"].as_u64().unwrap_or(0) as usize),
+                    // A primitive type, possibly with a logical type.
+                    _ => return Self::parse(type_name, named),
+                };
+                if let Some(name) = object.get("name").and_then(Value::as_str) {
+                    named.insert(name.to_string(), schema.clone());
+                    if let Some(namespace) = object.get("namespace").and_then(Value::as_str) {
+                        named.insert(format!("{namespace}.{name}"), schema.clone());
+                    }
+                }
+                schema
+            },
+            _ => polars_bail!(ComputeError: "invalid Avro schema: {}", value),
+        };
+        Ok(schema)
+    }
+}
+
+/// A decoded Avro value. The value of a union is the value of the variant that was written.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum AvroValue {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Bytes(Vec<u8>),
+    String(String),
+    Record(Vec<(String, AvroValue)>),
+    Enum(String),
+    Array(Vec<AvroValue>),
+    Map(Vec<(String, AvroValue)>),
+}
+
+impl AvroValue {
+    /// The value of the field `name` of a record, `None` if it is missing or null.
+    pub(super) fn field(&self, name: &str) -> Option<&AvroValue> {
+        match self {
+            Self::Record(fields) => fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value)
+                .filter(|value| !matches!(value, Self::Null)),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(v) => Some(*v as i64),
+            Self::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_array(&self) -> &[AvroValue] {
+        match self {
+            Self::Array(values) => values,
+            _ => &[],
+        }
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            len <= self.bytes.len(),
+            ComputeError: "unexpected end of an Avro file"
+        );
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn long(&mut self) -> PolarsResult<i64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                // Zigzag decoding.
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        polars_bail!(ComputeError: "invalid variable-length integer in an Avro file")
+    }
+
+    fn len(&mut self) -> PolarsResult<usize> {
+        let len = self.long()?;
+        polars_ensure!(len >= 0, ComputeError: "negative length in an Avro file");
+        Ok(len as usize)
+    }
+
+    fn bytes(&mut self) -> PolarsResult<&'a [u8]> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> PolarsResult<String> {
+        let bytes = self.bytes()?;
+        String::from_utf8(bytes.to_vec()).map_err(to_compute_err)
+    }
+
+    /// The number of items of the next block of an array or a map, 0 at the end.
+    fn block_len(&mut self) -> PolarsResult<usize> {
+        let len = self.long()?;
+        if len < 0 {
+            // The size of the block in bytes follows, which allows to skip it.
+            self.long()?;
+        }
+        Ok(len.unsigned_abs() as usize)
+    }
+
+    fn value(&mut self, schema: &AvroSchema) -> PolarsResult<AvroValue> {
+        let value = match schema {
+            AvroSchema::Null => AvroValue::Null,
+            AvroSchema::Boolean => AvroValue::Boolean(self.take(1)?[0] != 0),
+            AvroSchema::Int => AvroValue::Int(self.long()? as i32),
+            AvroSchema::Long => AvroValue::Long(self.long()?),
+            AvroSchema::Float => {
+                AvroValue::Float(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+            },
+            AvroSchema::Double => {
+                AvroValue::Double(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+            },
+            AvroSchema::Bytes => AvroValue::Bytes(self.bytes()?.to_vec()),
+            AvroSchema::String => AvroValue::String(self.string()?),
+            AvroSchema::Fixed(size) => AvroValue::Bytes(self.take(*size)?.to_vec()),
+            AvroSchema::Record(fields) => AvroValue::Record(
+                fields
+                    .iter()
+                    .map(|(name, schema)| Ok((name.clone(), self.value(schema)?)))
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            AvroSchema::Enum(symbols) => {
+                let index = self.len()?;
+                let symbol = symbols.get(index).ok_or_else(
+                    || polars_err!(ComputeError: "invalid enum index {} in an Avro file", index),
+                )?;
+                AvroValue::Enum(symbol.clone())
+            },
+            AvroSchema::Array(items) => {
+                let mut values = vec![];
+                loop {
+                    let len = self.block_len()?;
+                    if len == 0 {
+                        break;
+                    }
+                    for _ in 0..len {
+                        values.push(self.value(items)?);
+                    }
+                }
+                AvroValue::Array(values)
+            },
+            AvroSchema::Map(values_schema) => {
+                let mut values = vec![];
+                loop {
+                    let len = self.block_len()?;
+                    if len == 0 {
+                        break;
+                    }
+                    for _ in 0..len {
+                        values.push((self.string()?, self.value(values_schema)?));
+                    }
+                }
+                AvroValue::Map(values)
+            },
+            AvroSchema::Union(variants) => {
+                let index = self.len()?;
+                let variant = variants.get(index).ok_or_else(
+                    || polars_err!(ComputeError: "invalid union index {} in an Avro file", index),
+                )?;
+                self.value(variant)?
+            },
+        };
+        Ok(value)
+    }
+}
+
+fn decompress(codec: &str, block: &[u8]) -> PolarsResult<Vec<u8>> {
+    let mut out = vec![];
+    match codec {
+        "null" => out.extend_from_slice(block),
+        "deflate" => {
+            flate2::read::DeflateDecoder::new(block).read_to_end(&mut out)?;
+        },
+        "zstandard" => out = zstd::decode_all(block)?,
+        codec => polars_bail!(
+            ComputeError: "the Avro compression codec '{}' is not supported", codec
+        ),
+    }
+    Ok(out)
+}
+
+/// Reads all records of an Avro object container file.
+pub(super) fn read_avro_file(bytes: &[u8]) -> PolarsResult<Vec<AvroValue>> {
+    let mut decoder = Decoder { bytes };
+    polars_ensure!(
+        decoder.take(MAGIC.len())? == MAGIC,
+        ComputeError: "not an Avro object container file"
+    );
+    let metadata = decoder.value(&AvroSchema::Map(Box::new(AvroSchema::Bytes)))?;
+    let AvroValue::Map(metadata) = metadata else {
+        unreachable!()
+    };
+    let metadata = |key: &str| {
+        metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, value)| value.as_bytes())
+    };
+    let schema = metadata("avro.schema")
+        .ok_or_else(|| polars_err!(ComputeError: "Avro file without a schema"))?;
+    let schema: Value = serde_json::from_slice(schema).map_err(to_compute_err)?;
+    let schema = AvroSchema::parse(&schema, &mut BTreeMap::new())?;
+    let codec = metadata("avro.codec").map_or(Ok("null"), std::str::from_utf8);
+    let codec = codec.map_err(to_compute_err)?.to_string();
+    let sync = decoder.take(SYNC_LEN)?;
+
+    let mut records = vec![];
+    while !decoder.bytes.is_empty() {
+        let len = decoder.len()?;
+        let block = decompress(&codec, decoder.bytes()?)?;
+        let mut block_decoder = Decoder { bytes: &block };
+        for _ in 0..len {
+            records.push(block_decoder.value(&schema)?);
+        }
+        polars_ensure!(
+            decoder.take(SYNC_LEN)? == sync,
+            ComputeError: "invalid sync marker in an Avro file"
+        );
+    }
+    Ok(records)
+}
@@ -0,0 +1,844 @@
+//! Reading Apache Iceberg tables.
+//!
+//! An [`IcebergTable`] reads the metadata of a table and plans the scan of one of its
+//! snapshots: the manifests of the snapshot are pruned with the summaries of their partition
+//! values, and their data files with their partition values and column bounds. Every data
+//! file that is kept comes with the delete files that apply to it, which a reader has to
+//! apply to the rows of the file.
+//!
+//! The columns of the data files are matched with the fields of the table by their field id.
+//! Only tables whose files are on the local file system and whose data files are parquet
+//! files are supported.
+mod avro;
+
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use serde_json::Value;
+
+use self::avro::{read_avro_file, AvroValue};
+use crate::parquet::read::ParquetReader;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+
+/// The field id of the `file_path` column of the position delete files.
+const DELETE_FILE_PATH_ID: i64 = 2147483546;
+
+/// The local path of the file at `uri`.
+fn local_path(uri: &str) -> PolarsResult<PathBuf> {
+    let path = uri
+        .strip_prefix("file://")
+        .or_else(|| uri.strip_prefix("file:"))
+        .unwrap_or(uri);
+    polars_ensure!(
+        !path.contains("://"),
+        ComputeError: "only Iceberg tables on the local file system can be read, got '{}'", uri
+    );
+    Ok(PathBuf::from(path))
+}
+
+/// The version of a metadata file that is named `v{version}.metadata.json` or
+/// `{version}-{uuid}.metadata.json`.
+fn metadata_version(name: &str) -> Option<u64> {
+    let name = name.strip_suffix(".metadata.json")?;
+    let name = name.strip_prefix('v').unwrap_or(name);
+    let end = name
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(name.len());
+    name[..end].parse().ok()
+}
+
+/// The metadata file of the table at `path`, which is either the metadata file itself or the
+/// root directory of the table.
+fn metadata_file(path: &Path) -> PolarsResult<PathBuf> {
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+    let metadata_dir = path.join("metadata");
+    if let Ok(hint) = std::fs::read_to_string(metadata_dir.join("version-hint.text")) {
+        let hint = hint.trim();
+        let file = match hint.parse::<u64>() {
+            Ok(version) => metadata_dir.join(format!("v{version}.metadata.json")),
+            Err(_) => metadata_dir.join(hint),
+        };
+        if file.is_file() {
+            return Ok(file);
+        }
+    }
+    let mut latest = None;
+    for entry in std::fs::read_dir(&metadata_dir).map_err(
+        |err| polars_err!(ComputeError: "'{}' is not an Iceberg table: {}", path.display(), err),
+    )? {
+        let entry = entry?;
+        let version = entry.file_name().to_str().and_then(metadata_version);
+        if let Some(version) = version {
+            if latest
+                .as_ref()
+                .map_or(true, |(latest, _)| version > *latest)
+            {
+                latest = Some((version, entry.path()));
+            }
+        }
+    }
+    latest.map(|(_, file)| file).ok_or_else(
+        || polars_err!(ComputeError: "the Iceberg table '{}' has no metadata file", path.display()),
+    )
+}
+
+/// The Polars type of the Iceberg type `iceberg_type`.
+fn to_polars_type(iceberg_type: &Value) -> PolarsResult<DataType> {
+    let dtype = match iceberg_type {
+        Value::String(name) => match name.as_str() {
+            "boolean" => DataType::Boolean,
+            "int" => DataType::Int32,
+            "long" => DataType::Int64,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "string" => DataType::String,
+            "binary" | "uuid" => DataType::Binary,
+            name if name.starts_with("fixed[") => DataType::Binary,
+            #[cfg(feature = "dtype-date")]
+            "date" => DataType::Date,
+            #[cfg(feature = "dtype-time")]
+            "time" => DataType::Time,
+            #[cfg(feature = "dtype-datetime")]
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+            #[cfg(feature = "dtype-datetime")]
+            "timestamptz" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            #[cfg(feature = "dtype-datetime")]
+            "timestamp_ns" => DataType::Datetime(TimeUnit::Nanoseconds, None),
+            #[cfg(feature = "dtype-datetime")]
+            "timestamptz_ns" => DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".into())),
+            #[cfg(feature = "dtype-decimal")]
+            name if name.starts_with("decimal(") => {
+                let (precision, scale) = name["decimal(".len()..]
+                    .trim_end_matches(')')
+                    .split_once(',')
+                    .ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg type '{}'", name))?;
+                let parse = |v: &str| v.trim().parse::<usize>().map_err(to_compute_err);
+                DataType::Decimal(Some(parse(precision)?), Some(parse(scale)?))
+            },
+            name => polars_bail!(
+                ComputeError: "the Iceberg type '{}' is not supported", name
+            ),
+        },
+        Value::Object(object) => match object["type"].as_str() {
+            Some("list") => DataType::List(Box::new(to_polars_type(&object["element"])?)),
+            #[cfg(feature = "dtype-struct")]
+            Some("struct") => DataType::Struct(
+                object["fields"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|field| {
+                        let name = field["name"].as_str().unwrap_or_default();
+                        Ok(Field::new(name, to_polars_type(&field["type"])?))
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            #[cfg(feature = "dtype-struct")]
+            Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                Field::new("key", to_polars_type(&object["key"])?),
+                Field::new("value", to_polars_type(&object["value"])?),
+            ]))),
+            _ => polars_bail!(
+                ComputeError: "the Iceberg type {} is not supported", iceberg_type
+            ),
+        },
+        _ => polars_bail!(ComputeError: "invalid Iceberg type {}", iceberg_type),
+    };
+    Ok(dtype)
+}
+
+/// A top-level field of the schema of a table.
+struct TableField {
+    id: i64,
+    name: String,
+    iceberg_type: Value,
+    dtype: DataType,
+}
+
+impl TableField {
+    /// The value of this field that is encoded as a bound of the statistics or the summaries
+    /// of the partitions, in Iceberg's binary single-value serialization.
+    fn decode_bound(&self, bytes: &[u8]) -> Option<Series> {
+        let int = || -> Option<i64> {
+            match bytes.len() {
+                4 => Some(i32::from_le_bytes(bytes.try_into().ok()?) as i64),
+                8 => Some(i64::from_le_bytes(bytes.try_into().ok()?)),
+                _ => None,
+            }
+        };
+        let s = match self.iceberg_type.as_str()? {
+            "boolean" => Series::new("", [*bytes.first()? != 0]),
+            "int" | "date" => Series::new("", [int()? as i32]),
+            "long" | "timestamp" | "timestamptz" | "timestamp_ns" | "timestamptz_ns" => {
+                Series::new("", [int()?])
+            },
+            // Iceberg stores times in microseconds.
+            "time" => Series::new("", [int()? * 1000]),
+            "float" => Series::new("", [f32::from_le_bytes(bytes.try_into().ok()?)]),
+            "double" => match bytes.len() {
+                4 => Series::new("", [f32::from_le_bytes(bytes.try_into().ok()?) as f64]),
+                _ => Series::new("", [f64::from_le_bytes(bytes.try_into().ok()?)]),
+            },
+            "string" => Series::new("", [std::str::from_utf8(bytes).ok()?]),
+            _ => return None,
+        };
+        s.cast(&self.dtype).ok()
+    }
+
+    /// The value of this field that is stored as a partition value in a manifest.
+    fn decode_partition_value(&self, value: Option<&AvroValue>) -> Option<Series> {
+        let s = match value {
+            None => Series::full_null("", 1, &self.dtype),
+            Some(AvroValue::Boolean(v)) => Series::new("", [*v]),
+            Some(AvroValue::Int(v)) => Series::new("", [*v]),
+            Some(AvroValue::Long(v)) if self.iceberg_type == "time" => Series::new("", [*v * 1000]),
+            Some(AvroValue::Long(v)) => Series::new("", [*v]),
+            Some(AvroValue::Float(v)) => Series::new("", [*v]),
+            Some(AvroValue::Double(v)) => Series::new("", [*v]),
+            Some(AvroValue::String(v)) => Series::new("", [v.as_str()]),
+            _ => return None,
+        };
+        s.cast(&self.dtype).ok()
+    }
+}
+
+/// A partition field of a partition spec.
+struct PartitionField {
+    source_id: i64,
+    name: String,
+    transform: String,
+}
+
+struct PartitionSpec {
+    spec_id: i64,
+    fields: Vec<PartitionField>,
+}
+
+impl PartitionSpec {
+    fn parse(spec_id: i64, fields: &Value) -> Self {
+        let fields = fields
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|field| PartitionField {
+                source_id: field["source-id"].as_i64().unwrap_or(-1),
+                name: field["name"].as_str().unwrap_or_default().to_string(),
+                transform: field["transform"].as_str().unwrap_or_default().to_string(),
+            })
+            .collect();
+        Self { spec_id, fields }
+    }
+}
+
+/// The content of a file of a manifest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+/// A live file of a manifest.
+struct ManifestFile {
+    content: FileContent,
+    uri: String,
+    sequence_number: i64,
+    spec_id: i64,
+    partition: AvroValue,
+    data_file: AvroValue,
+}
+
+/// A delete file that applies to a data file, whose deleted rows are either given by their
+/// position in the data file or by the values of some of its columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IcebergDeleteFile {
+    /// A file with the `file_path` and `pos` columns, of which only the rows with the path of
+    /// the data file apply to it.
+    Position { path: PathBuf },
+    /// A file with the `columns` of the table, which deletes the rows with equal values.
+    Equality { path: PathBuf, columns: Vec<String> },
+}
+
+/// A data file to read in the scan of an Iceberg table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcebergDataFile {
+    /// The local path of the file.
+    pub path: PathBuf,
+    /// The path of the file as it is written in the metadata of the table, which is how
+    /// position delete files refer to it.
+    pub uri: String,
+    pub record_count: i64,
+    pub deletes: Vec<IcebergDeleteFile>,
+}
+
+/// A snapshot of an Iceberg table.
+pub struct IcebergTable {
+    metadata_file: PathBuf,
+    fields: Vec<TableField>,
+    schema: SchemaRef,
+    partition_specs: Vec<PartitionSpec>,
+    snapshot_id: Option<i64>,
+    manifest_list: Option<PathBuf>,
+}
+
+impl IcebergTable {
+    /// Open the table at `path`, which is either the root directory of the table or one of
+    /// its metadata files.
+    ///
+    /// The current snapshot of the table is read, or the snapshot `snapshot_id` if it is
+    /// given.
+    pub fn open(path: &Path, snapshot_id: Option<i64>) -> PolarsResult<Self> {
+        let metadata_file = metadata_file(path)?;
+        let metadata = std::fs::read(&metadata_file)?;
+        let metadata: Value = serde_json::from_slice(&metadata).map_err(to_compute_err)?;
+        let format_version = metadata["format-version"].as_i64().unwrap_or(1);
+        polars_ensure!(
+            format_version <= 2,
+            ComputeError: "version {} of the Iceberg table format is not supported", format_version
+        );
+
+        let snapshot_id = snapshot_id.or_else(|| {
+            metadata["current-snapshot-id"]
+                .as_i64()
+                .filter(|id| *id >= 0)
+        });
+        let snapshot = match snapshot_id {
+            Some(id) => Some(
+                metadata["snapshots"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .find(|snapshot| snapshot["snapshot-id"].as_i64() == Some(id))
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "the Iceberg table has no snapshot {}", id),
+                    )?,
+            ),
+            None => None,
+        };
+        let manifest_list = match snapshot {
+            Some(snapshot) => {
+                let manifest_list = snapshot["manifest-list"].as_str().ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "Iceberg snapshots without a manifest list are not supported"
+                    )
+                })?;
+                Some(local_path(manifest_list)?)
+            },
+            None => None,
+        };
+
+        // A snapshot is read with the schema it was written with.
+        let schema_id = snapshot
+            .and_then(|snapshot| snapshot["schema-id"].as_i64())
+            .or_else(|| metadata["current-schema-id"].as_i64());
+        let iceberg_schema = metadata["schemas"]
+            .as_array()
+            .and_then(|schemas| {
+                schemas
+                    .iter()
+                    .find(|schema| schema["schema-id"].as_i64() == schema_id)
+            })
+            .unwrap_or(&metadata["schema"]);
+        let fields = iceberg_schema["fields"]
+            .as_array()
+            .ok_or_else(|| polars_err!(ComputeError: "the Iceberg table has no schema"))?
+            .iter()
+            .map(|field| {
+                Ok(TableField {
+                    id: field["id"].as_i64().unwrap_or(-1),
+                    name: field["name"].as_str().unwrap_or_default().to_string(),
+                    iceberg_type: field["type"].clone(),
+                    dtype: to_polars_type(&field["type"])?,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let schema = fields
+            .iter()
+            .map(|field| Field::new(&field.name, field.dtype.clone()))
+            .collect::<Schema>();
+
+        let partition_specs = match metadata["partition-specs"].as_array() {
+            Some(specs) => specs
+                .iter()
+                .map(|spec| {
+                    PartitionSpec::parse(spec["spec-id"].as_i64().unwrap_or(0), &spec["fields"])
+                })
+                .collect(),
+            None => vec![PartitionSpec::parse(0, &metadata["partition-spec"])],
+        };
+
+        Ok(Self {
+            metadata_file,
+            fields,
+            schema: Arc::new(schema),
+            partition_specs,
+            snapshot_id,
+            manifest_list,
+        })
+    }
+
+    /// The schema of the snapshot.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// The id of the snapshot, which is `None` for a table without snapshots.
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+
+    /// The metadata file the table was read from.
+    pub fn metadata_file(&self) -> &Path {
+        &self.metadata_file
+    }
+
+    /// The names of the columns of the parquet file at `path` that hold the fields of the
+    /// table, in the order of the schema, or `None` for the fields that the file doesn't have.
+    ///
+    /// The columns are matched by their field id, so that columns that were renamed after the
+    /// file was written are still found. Files without field ids, such as files that were
+    /// imported into the table, are matched by name.
+    pub fn file_columns(&self, path: &Path) -> PolarsResult<Vec<Option<String>>> {
+        let mut reader = ParquetReader::new(polars_utils::open_file(path)?);
+        let metadata = reader.get_metadata()?;
+        let columns = metadata
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                let info = field.get_field_info();
+                (info.id, info.name.as_str())
+            })
+            .collect::<Vec<_>>();
+
+        let has_ids = columns.iter().any(|(id, _)| id.is_some());
+        let out = self
+            .fields
+            .iter()
+            .map(|field| {
+                columns
+                    .iter()
+                    .find(|(id, name)| match id {
+                        Some(id) => i64::from(*id) == field.id,
+                        None => !has_ids && *name == field.name,
+                    })
+                    .map(|(_, name)| name.to_string())
+            })
+            .collect();
+        Ok(out)
+    }
+
+    fn field(&self, id: i64) -> Option<&TableField> {
+        self.fields.iter().find(|field| field.id == id)
+    }
+
+    fn partition_spec(&self, spec_id: i64) -> PolarsResult<&PartitionSpec> {
+        self.partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == spec_id)
+            .ok_or_else(
+                || polars_err!(ComputeError: "the Iceberg table has no partition spec {}", spec_id),
+            )
+    }
+
+    /// The statistics of the columns that are partitioned by their identity, given the value
+    /// of every partition field.
+    fn partition_stats<'a>(
+        &'a self,
+        spec: &'a PartitionSpec,
+        mut stats: impl FnMut(usize, &PartitionField, &'a TableField) -> Option<ColumnStats>,
+    ) -> Vec<ColumnStats> {
+        spec.fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.transform == "identity")
+            .filter_map(|(i, field)| stats(i, field, self.field(field.source_id)?))
+            .collect()
+    }
+
+    /// Whether a manifest may contain rows for which the predicate holds, given the
+    /// summaries of its partition values.
+    fn should_read_manifest(
+        &self,
+        predicate: &dyn PhysicalIoExpr,
+        spec: &PartitionSpec,
+        summaries: &[AvroValue],
+    ) -> PolarsResult<bool> {
+        let Some(evaluator) = predicate.as_stats_evaluator() else {
+            return Ok(true);
+        };
+        let stats = self.partition_stats(spec, |i, _, field| {
+            let summary = summaries.get(i)?;
+            let bound = |name| {
+                summary
+                    .field(name)
+                    .and_then(AvroValue::as_bytes)
+                    .and_then(|bytes| field.decode_bound(bytes))
+            };
+            let contains_null = summary.field("contains_null").and_then(AvroValue::as_bool);
+            let null_count = (contains_null == Some(false))
+                .then(|| IdxCa::from_slice("", &[0 as IdxSize]).into_series());
+            Some(ColumnStats::new(
+                Field::new(&field.name, field.dtype.clone()),
+                null_count,
+                bound("lower_bound"),
+                bound("upper_bound"),
+            ))
+        });
+        if stats.is_empty() {
+            return Ok(true);
+        }
+        evaluator.should_read(&batch_stats(stats, None))
+    }
+
+    /// Whether a data file may contain rows for which the predicate holds, given its
+    /// partition values and the bounds of its columns.
+    fn should_read_file(
+        &self,
+        predicate: &dyn PhysicalIoExpr,
+        file: &ManifestFile,
+    ) -> PolarsResult<bool> {
+        let Some(evaluator) = predicate.as_stats_evaluator() else {
+            return Ok(true);
+        };
+        let spec = self.partition_spec(file.spec_id)?;
+        let mut stats = self.partition_stats(spec, |_, partition_field, field| {
+            let value =
+                field.decode_partition_value(file.partition.field(&partition_field.name))?;
+            Some(ColumnStats::from_column_literal(
+                value.with_name(&field.name),
+            ))
+        });
+
+        let record_count = file
+            .data_file
+            .field("record_count")
+            .and_then(AvroValue::as_i64);
+        let by_id = |name: &str, id: i64| {
+            file.data_file
+                .field(name)
+                .map(AvroValue::as_array)
+                .unwrap_or_default()
+                .iter()
+                .find(|kv| kv.field("key").and_then(AvroValue::as_i64) == Some(id))
+                .and_then(|kv| kv.field("value"))
+        };
+        for field in &self.fields {
+            if field.dtype.is_nested() || stats.iter().any(|s| s.field_name() == field.name) {
+                continue;
+            }
+            let bound = |name| {
+                by_id(name, field.id)
+                    .and_then(AvroValue::as_bytes)
+                    .and_then(|bytes| field.decode_bound(bytes))
+            };
+            let null_count = by_id("null_value_counts", field.id)
+                .and_then(AvroValue::as_i64)
+                .map(|count| IdxCa::from_slice("", &[count as IdxSize]).into_series());
+            let (min, max) = (bound("lower_bounds"), bound("upper_bounds"));
+            if null_count.is_some() || min.is_some() || max.is_some() {
+                stats.push(ColumnStats::new(
+                    Field::new(&field.name, field.dtype.clone()),
+                    null_count,
+                    min,
+                    max,
+                ));
+            }
+        }
+        if stats.is_empty() {
+            return Ok(true);
+        }
+        evaluator.should_read(&batch_stats(
+            stats,
+            record_count.map(|count| count as usize),
+        ))
+    }
+
+    /// The live files of the manifests of the snapshot. The data manifests are pruned with
+    /// the predicate, if one is given.
+    fn manifest_files(
+        &self,
+        predicate: Option<&dyn PhysicalIoExpr>,
+    ) -> PolarsResult<Vec<ManifestFile>> {
+        let Some(manifest_list) = &self.manifest_list else {
+            return Ok(vec![]);
+        };
+        let mut files = vec![];
+        for manifest in read_avro_file(&std::fs::read(manifest_list)?)? {
+            let is_data_manifest = manifest
+                .field("content")
+                .and_then(AvroValue::as_i64)
+                .unwrap_or(0)
+                == 0;
+            let manifest_sequence_number = manifest
+                .field("sequence_number")
+                .and_then(AvroValue::as_i64)
+                .unwrap_or(0);
+            let spec_id = manifest
+                .field("partition_spec_id")
+                .and_then(AvroValue::as_i64)
+                .unwrap_or(0);
+            if let (Some(predicate), true) = (predicate, is_data_manifest) {
+                let summaries = manifest.field("partitions").map(AvroValue::as_array);
+                let spec = self.partition_spec(spec_id)?;
+                if !self.should_read_manifest(predicate, spec, summaries.unwrap_or_default())? {
+                    continue;
+                }
+            }
+
+            let path = manifest
+                .field("manifest_path")
+                .and_then(AvroValue::as_str)
+                .ok_or_else(|| polars_err!(ComputeError: "Iceberg manifest without a path"))?;
+            for entry in read_avro_file(&std::fs::read(local_path(path)?)?)? {
+                // Skip the files that were deleted by the snapshot of the manifest.
+                if entry.field("status").and_then(AvroValue::as_i64) == Some(2) {
+                    continue;
+                }
+                let Some(data_file) = entry.field("data_file") else {
+                    continue;
+                };
+                let content = match data_file.field("content").and_then(AvroValue::as_i64) {
+                    None | Some(0) => FileContent::Data,
+                    Some(1) => FileContent::PositionDeletes,
+                    Some(2) => FileContent::EqualityDeletes,
+                    Some(content) => polars_bail!(
+                        ComputeError: "unknown content {} of an Iceberg data file", content
+                    ),
+                };
+                let format = data_file.field("file_format").and_then(AvroValue::as_str);
+                polars_ensure!(
+                    format.map_or(true, |format| format.eq_ignore_ascii_case("parquet")),
+                    ComputeError: "only Iceberg tables with parquet files can be read, got a {} file",
+                    format.unwrap_or_default()
+                );
+                let uri = data_file
+                    .field("file_path")
+                    .and_then(AvroValue::as_str)
+                    .ok_or_else(|| polars_err!(ComputeError: "Iceberg data file without a path"))?;
+                // Files that were added by the snapshot of the manifest inherit its sequence
+                // number.
+                let sequence_number = entry
+                    .field("sequence_number")
+                    .and_then(AvroValue::as_i64)
+                    .unwrap_or(manifest_sequence_number);
+                files.push(ManifestFile {
+                    content,
+                    uri: uri.to_string(),
+                    sequence_number,
+                    spec_id,
+                    partition: data_file
+                        .field("partition")
+                        .cloned()
+                        .unwrap_or(AvroValue::Record(vec![])),
+                    data_file: data_file.clone(),
+                });
+            }
+        }
+        Ok(files)
+    }
+
+    /// Whether the delete file `delete` applies to the data file `file`.
+    fn applies_to(&self, delete: &ManifestFile, file: &ManifestFile) -> PolarsResult<bool> {
+        let same_partition = delete.spec_id == file.spec_id && delete.partition == file.partition;
+        let applies = match delete.content {
+            FileContent::PositionDeletes => {
+                let bound = |name| {
+                    delete
+                        .data_file
+                        .field(name)
+                        .map(AvroValue::as_array)
+                        .unwrap_or_default()
+                        .iter()
+                        .find(|kv| {
+                            kv.field("key").and_then(AvroValue::as_i64) == Some(DELETE_FILE_PATH_ID)
+                        })
+                        .and_then(|kv| kv.field("value"))
+                        .and_then(AvroValue::as_bytes)
+                };
+                let in_bounds = bound("lower_bounds")
+                    .map_or(true, |lower| lower <= file.uri.as_bytes())
+                    && bound("upper_bounds").map_or(true, |upper| file.uri.as_bytes() <= upper);
+                file.sequence_number <= delete.sequence_number && same_partition && in_bounds
+            },
+            FileContent::EqualityDeletes => {
+                let is_global = self.partition_spec(delete.spec_id)?.fields.is_empty();
+                file.sequence_number < delete.sequence_number && (is_global || same_partition)
+            },
+            FileContent::Data => false,
+        };
+        Ok(applies)
+    }
+
+    fn delete_file(&self, delete: &ManifestFile) -> PolarsResult<IcebergDeleteFile> {
+        let path = local_path(&delete.uri)?;
+        if delete.content == FileContent::PositionDeletes {
+            return Ok(IcebergDeleteFile::Position { path });
+        }
+        let columns = delete
+            .data_file
+            .field("equality_ids")
+            .map(AvroValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|id| {
+                let id = id.as_i64().unwrap_or(-1);
+                self.field(id).map(|field| field.name.clone()).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "the equality deletes of the Iceberg table on the field {} \
+                        are not supported", id
+                    )
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        polars_ensure!(
+            !columns.is_empty(),
+            ComputeError: "Iceberg equality delete file '{}' without equality ids", delete.uri
+        );
+        Ok(IcebergDeleteFile::Equality { path, columns })
+    }
+
+    /// The data files of the snapshot with the delete files that apply to them.
+    ///
+    /// If a `predicate` is given, the manifests and the data files that can't contain rows
+    /// for which it holds are skipped. The predicate still has to be applied to the rows that
+    /// are read.
+    pub fn data_files(
+        &self,
+        predicate: Option<&dyn PhysicalIoExpr>,
+    ) -> PolarsResult<Vec<IcebergDataFile>> {
+        let (data_files, delete_files): (Vec<_>, Vec<_>) = self
+            .manifest_files(predicate)?
+            .into_iter()
+            .partition(|file| file.content == FileContent::Data);
+
+        let mut out = vec![];
+        for file in data_files {
+            if let Some(predicate) = predicate {
+                if !self.should_read_file(predicate, &file)? {
+                    continue;
+                }
+            }
+            let mut deletes = vec![];
+            for delete in &delete_files {
+                if self.applies_to(delete, &file)? {
+                    deletes.push(self.delete_file(delete)?);
+                }
+            }
+            out.push(IcebergDataFile {
+                path: local_path(&file.uri)?,
+                record_count: file
+                    .data_file
+                    .field("record_count")
+                    .and_then(AvroValue::as_i64)
+                    .unwrap_or(0),
+                uri: file.uri,
+                deletes,
+            });
+        }
+        Ok(out)
+    }
+}
+
+fn batch_stats(stats: Vec<ColumnStats>, num_rows: Option<usize>) -> BatchStats {
+    let schema = stats
+        .iter()
+        .map(|stats| Field::new(stats.field_name(), stats.dtype().clone()))
+        .collect::<Schema>();
+    BatchStats::new(Arc::new(schema), stats, num_rows)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{ArrayRef, Int64Array};
+    use arrow::datatypes::{ArrowDataType, ArrowSchema, Field as ArrowField};
+    use arrow::record_batch::RecordBatch;
+    use polars_parquet::arrow::FIELD_ID_META_KEY;
+    use polars_parquet::write::{
+        CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+    };
+
+    use super::*;
+
+    /// Write a parquet file with the `Int64` columns `columns`, with their field ids if they
+    /// have one.
+    fn write_file(path: &Path, columns: &[(&str, Option<i32>)]) -> PolarsResult<()> {
+        let fields = columns
+            .iter()
+            .map(|(name, id)| {
+                let field = ArrowField::new(*name, ArrowDataType::Int64, true);
+                match id {
+                    Some(id) => field
+                        .with_metadata([(FIELD_ID_META_KEY.to_string(), id.to_string())].into()),
+                    None => field,
+                }
+            })
+            .collect::<Vec<_>>();
+        let schema = ArrowSchema::from(fields);
+        let options = WriteOptions {
+            write_statistics: false,
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V2,
+            data_pagesize_limit: None,
+        };
+        let arrays = columns
+            .iter()
+            .map(|_| Box::new(Int64Array::from_slice([1])) as ArrayRef)
+            .collect();
+        let encodings = columns.iter().map(|_| vec![Encoding::Plain]).collect();
+        let row_groups = RowGroupIterator::try_new(
+            [RecordBatch::try_new(arrays)].into_iter(),
+            &schema,
+            options,
+            encodings,
+        )?;
+        let mut writer = FileWriter::try_new(std::fs::File::create(path)?, schema, options)?;
+        for row_group in row_groups {
+            writer.write(row_group?)?;
+        }
+        writer.end(None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_columns() -> PolarsResult<()> {
+        let field = |id, name: &str| TableField {
+            id,
+            name: name.to_string(),
+            iceberg_type: Value::String("long".into()),
+            dtype: DataType::Int64,
+        };
+        // `a` was renamed to `b` and the old name `a` was given to a new field.
+        let fields = vec![field(1, "b"), field(2, "a"), field(3, "c")];
+        let table = IcebergTable {
+            metadata_file: PathBuf::new(),
+            schema: Arc::new(
+                fields
+                    .iter()
+                    .map(|f| Field::new(&f.name, f.dtype.clone()))
+                    .collect(),
+            ),
+            fields,
+            partition_specs: vec![],
+            snapshot_id: None,
+            manifest_list: None,
+        };
+
+        let dir = std::env::temp_dir().join("polars_iceberg_file_columns");
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("ids.parquet");
+        write_file(&path, &[("a", Some(1)), ("c", Some(3))])?;
+        let columns = table.file_columns(&path)?;
+        assert_eq!(columns, [Some("a".into()), None, Some("c".into())]);
+
+        // Files without field ids are matched by name.
+        let path = dir.join("names.parquet");
+        write_file(&path, &[("a", None), ("c", None)])?;
+        let columns = table.file_columns(&path)?;
+        assert_eq!(columns, [None, Some("a".into()), Some("c".into())]);
+        Ok(())
+    }
+}
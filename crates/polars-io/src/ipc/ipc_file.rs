@@ -51,6 +51,17 @@ use crate::RowIndex;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptions {
     pub memory_map: bool,
+    /// Read a single file at a time to bound the memory usage.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub low_memory: bool,
+    /// The maximum number of files that are decoded at the same time. This takes precedence
+    /// over low memory mode.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_decoders: Option<usize>,
+    /// Skip this many leading rows of a single file scan. This is set by the optimizer when a
+    /// slice with a negative offset is resolved against the row count of the file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skip_rows: usize,
 }
 
 /// Read Arrows IPC format into a DataFrame
@@ -82,6 +93,31 @@ pub struct IpcReader<R: MmapBytesReader> {
     memory_map: bool,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
+    pub(super) skip_rows: usize,
+}
+
+/// Drop the leading record batches that only contain skipped rows from `metadata` and return
+/// the number of rows in them.
+pub(super) fn drop_skipped_batches<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &mut read::FileMetadata,
+    skip_rows: usize,
+) -> PolarsResult<usize> {
+    let mut skipped = 0;
+    if skip_rows > 0 {
+        let mut message_scratch = vec![];
+        let mut n_batches = 0;
+        for block in &metadata.blocks {
+            let len = read::get_record_batch_length(reader, block, &mut message_scratch)? as usize;
+            if skipped + len > skip_rows {
+                break;
+            }
+            skipped += len;
+            n_batches += 1;
+        }
+        metadata.blocks.drain(..n_batches);
+    }
+    Ok(skipped)
 }
 
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
@@ -118,6 +154,28 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Skip the first `n` rows of the file. The record batches that only contain skipped rows
+    /// are not read.
+    pub fn with_skip_rows(mut self, n: usize) -> Self {
+        self.skip_rows = n;
+        self
+    }
+
+    /// The row limit and row index for the record batches after the `skipped` rows, and the
+    /// number of rows that still have to be sliced off.
+    pub(super) fn args_after_skipped(
+        &self,
+        skipped: usize,
+    ) -> (Option<usize>, Option<RowIndex>, usize) {
+        let to_slice = self.skip_rows - skipped;
+        let n_rows = self.n_rows.map(|n| n.saturating_add(to_slice));
+        let row_index = self.row_index.clone().map(|mut rc| {
+            rc.offset += skipped as IdxSize;
+            rc
+        });
+        (n_rows, row_index, to_slice)
+    }
+
     /// Columns to select/ project
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
@@ -160,7 +218,9 @@ impl<R: MmapBytesReader> IpcReader<R> {
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        let skipped = drop_skipped_batches(&mut self.reader, &mut metadata, self.skip_rows)?;
+        let (n_rows, row_index, to_slice) = self.args_after_skipped(skipped);
 
         // NOTE: For some code paths this already happened. See
         // https://github.com/pola-rs/polars/pull/14984#discussion_r1520125000
@@ -175,9 +235,18 @@ impl<R: MmapBytesReader> IpcReader<R> {
             metadata.schema.clone()
         };
 
-        let reader = read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+        let reader = read::FileReader::new(self.reader, metadata, self.projection, n_rows);
 
-        finish_reader(reader, rechunk, None, predicate, &schema, self.row_index)
+        // The predicate is applied after the skipped rows are sliced off.
+        let (predicate, post_predicate) = if to_slice == 0 {
+            (predicate, None)
+        } else {
+            (None, predicate)
+        };
+        let df = finish_reader(reader, rechunk, None, predicate, &schema, row_index)?;
+        let mut df = df.slice(to_slice as i64, usize::MAX);
+        crate::predicates::apply_predicate(&mut df, post_predicate.as_deref(), true)?;
+        Ok(df)
     }
 }
 
@@ -202,6 +271,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             memory_map: true,
             metadata: None,
             schema: None,
+            skip_rows: 0,
         }
     }
 
@@ -218,7 +288,9 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        let skipped = drop_skipped_batches(&mut self.reader, &mut metadata, self.skip_rows)?;
+        let (n_rows, row_index, to_slice) = self.args_after_skipped(skipped);
         let schema = &metadata.schema;
 
         if let Some(columns) = &self.columns {
@@ -233,7 +305,8 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
         };
 
         let ipc_reader =
-            read::FileReader::new(self.reader, metadata.clone(), self.projection, self.n_rows);
-        finish_reader(ipc_reader, rechunk, None, None, &schema, self.row_index)
+            read::FileReader::new(self.reader, metadata.clone(), self.projection, n_rows);
+        let df = finish_reader(ipc_reader, rechunk, None, None, &schema, row_index)?;
+        Ok(df.slice(to_slice as i64, usize::MAX))
     }
 }
@@ -36,11 +36,15 @@
 use std::io::{Read, Write};
 use std::path::PathBuf;
 
+use arrow::array::{
+    Array, DictionaryArray, MutableBinaryViewArray, PrimitiveArray, Utf8Array, Utf8ViewArray,
+};
+use arrow::compute::cast::utf8view_to_utf8;
 use arrow::io::ipc::read::{StreamMetadata, StreamState};
-use arrow::io::ipc::write::WriteOptions;
 use arrow::io::ipc::{read, write};
 use polars_core::prelude::*;
 
+use super::write::write_options;
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader, WriterFactory};
 
@@ -207,7 +211,9 @@ where
 pub struct IpcStreamWriter<W> {
     writer: W,
     compression: Option<IpcCompression>,
+    compression_level: Option<i32>,
     pl_flavor: bool,
+    dictionary_mode: IpcDictionaryMode,
 }
 
 use polars_core::frame::ArrowChunk;
@@ -221,10 +227,45 @@ impl<W> IpcStreamWriter<W> {
         self
     }
 
+    /// Set the level of ZSTD compression, between 1 and 22. Defaults to 3.
+    pub fn with_compression_level(mut self, level: Option<i32>) -> Self {
+        self.compression_level = level;
+        self
+    }
+
     pub fn with_pl_flavor(mut self, pl_flavor: bool) -> Self {
         self.pl_flavor = pl_flavor;
         self
     }
+
+    /// Set how the [`BatchedStreamWriter`] writes the dictionaries of categorical columns.
+    /// Defaults to [`IpcDictionaryMode::Delta`].
+    pub fn with_dictionary_mode(mut self, dictionary_mode: IpcDictionaryMode) -> Self {
+        self.dictionary_mode = dictionary_mode;
+        self
+    }
+}
+
+impl<W: Write> IpcStreamWriter<W> {
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedStreamWriter<W>> {
+        let arrow_schema = schema.to_arrow(self.pl_flavor);
+        let mut writer = write::StreamWriter::new(
+            self.writer,
+            write_options(self.compression, self.compression_level)?,
+        )
+        .with_delta_dictionaries(self.dictionary_mode == IpcDictionaryMode::Delta);
+        writer.start(&arrow_schema, None)?;
+
+        Ok(BatchedStreamWriter {
+            writer,
+            schema: arrow_schema,
+            pl_flavor: self.pl_flavor,
+            dictionary_mode: self.dictionary_mode,
+            categorical: schema.iter_dtypes().map(is_categorical).collect(),
+            dictionaries: Default::default(),
+            batches: vec![],
+        })
+    }
 }
 
 impl<W> SerWriter<W> for IpcStreamWriter<W>
@@ -235,16 +276,16 @@ where
         IpcStreamWriter {
             writer,
             compression: None,
+            compression_level: None,
             pl_flavor: false,
+            dictionary_mode: IpcDictionaryMode::default(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         let mut ipc_stream_writer = write::StreamWriter::new(
             &mut self.writer,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
+            write_options(self.compression, self.compression_level)?,
         );
 
         ipc_stream_writer.start(&df.schema().to_arrow(self.pl_flavor), None)?;
@@ -259,6 +300,210 @@ where
     }
 }
 
+/// Whether the dictionaries of the columns of type `dtype` can differ between batches. Enum
+/// columns have the same dictionary in every batch, so they are written as is.
+fn is_categorical(dtype: &DataType) -> bool {
+    #[cfg(feature = "dtype-categorical")]
+    {
+        matches!(dtype, DataType::Categorical(..))
+    }
+    #[cfg(not(feature = "dtype-categorical"))]
+    {
+        let _ = dtype;
+        false
+    }
+}
+
+/// The categories of a categorical column of a stream, in the order in which they were first
+/// written.
+#[derive(Default)]
+struct StreamDictionary {
+    ids: PlHashMap<String, u32>,
+    values: MutableBinaryViewArray<str>,
+    /// The categories as the values of a dictionary, until categories are added.
+    frozen: Option<Box<dyn Array>>,
+}
+
+impl StreamDictionary {
+    fn id(&mut self, category: &str) -> u32 {
+        if let Some(id) = self.ids.get(category) {
+            return *id;
+        }
+        let id = self.ids.len() as u32;
+        self.values.push_value(category);
+        self.ids.insert(category.to_string(), id);
+        self.frozen = None;
+        id
+    }
+
+    /// The keys of `array` remapped to the categories of this dictionary, to which the
+    /// categories of `array` that are new are appended.
+    fn extend(&mut self, array: &DictionaryArray<u32>) -> PrimitiveArray<u32> {
+        let values = array.values().as_any();
+        let categories: Vec<Option<&str>> = match array.values().data_type() {
+            ArrowDataType::Utf8View => values
+                .downcast_ref::<Utf8ViewArray>()
+                .unwrap()
+                .iter()
+                .collect(),
+            ArrowDataType::LargeUtf8 => values
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .iter()
+                .collect(),
+            _ => values
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .iter()
+                .collect(),
+        };
+        let ids = categories
+            .into_iter()
+            .map(|category| category.map(|category| self.id(category)))
+            .collect::<Vec<_>>();
+
+        let iter = array
+            .keys()
+            .into_iter()
+            .map(|key| key.and_then(|key| ids[*key as usize]));
+        PrimitiveArray::from_trusted_len_iter(iter)
+    }
+
+    /// The categories as the values of a dictionary of type `dtype`. These are only built
+    /// again if categories were added since they were last built.
+    fn values(&mut self, dtype: &ArrowDataType) -> Box<dyn Array> {
+        let ArrowDataType::Dictionary(_, values_dtype, _) = dtype else {
+            unreachable!()
+        };
+        self.frozen
+            .get_or_insert_with(|| {
+                let values = self.values.clone().freeze();
+                match values_dtype.as_ref() {
+                    ArrowDataType::LargeUtf8 => utf8view_to_utf8::<i64>(&values).boxed(),
+                    ArrowDataType::Utf8 => utf8view_to_utf8::<i32>(&values).boxed(),
+                    _ => values.boxed(),
+                }
+            })
+            .clone()
+    }
+}
+
+/// Writes the batches of a stream, e.g. of a sink, to Arrow's Streaming IPC format.
+///
+/// The batches of a stream can each have other categories in their categorical columns. The
+/// writer keeps a dictionary per categorical column with the categories of all batches, to
+/// which it remaps their keys, so that every batch extends the dictionaries of the batches
+/// before it. Depending on the [`IpcDictionaryMode`], the new categories of a batch are written
+/// as delta dictionaries, or a single dictionary is written when the stream is finished.
+/// Enum columns have the same dictionary in every batch and are written as is.
+///
+/// Categorical columns nested in other types are written with the dictionaries of their batches.
+pub struct BatchedStreamWriter<W: Write> {
+    writer: write::StreamWriter<W>,
+    schema: ArrowSchema,
+    pl_flavor: bool,
+    dictionary_mode: IpcDictionaryMode,
+    /// Whether the columns are categorical, see [`is_categorical`].
+    categorical: Vec<bool>,
+    /// The dictionaries of the categorical columns, by the index of the column.
+    dictionaries: PlHashMap<usize, StreamDictionary>,
+    /// The batches that are held back until the stream is finished, with their categorical
+    /// columns as keys.
+    batches: Vec<Vec<Box<dyn Array>>>,
+}
+
+impl<W: Write> BatchedStreamWriter<W> {
+    /// Write a batch to the stream.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        for batch in df.iter_chunks(self.pl_flavor) {
+            let arrays = self.remap_keys(batch);
+            match self.dictionary_mode {
+                IpcDictionaryMode::Delta => {
+                    let values = self.dictionary_values();
+                    let batch = self.with_dictionaries(arrays, &values)?;
+                    self.writer.write(&batch, None)?
+                },
+                IpcDictionaryMode::UnifyAtFinish => self.batches.push(arrays),
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the batches that were held back, and the end of the stream.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        let batches = std::mem::take(&mut self.batches);
+        if !batches.is_empty() {
+            let values = self.dictionary_values();
+            for arrays in batches {
+                let batch = self.with_dictionaries(arrays, &values)?;
+                self.writer.write(&batch, None)?;
+            }
+        }
+        self.writer.finish()
+    }
+
+    /// The arrays of `batch`, of which the categorical columns are replaced by their keys
+    /// remapped to the dictionaries of the stream.
+    fn remap_keys(&mut self, batch: ArrowChunk) -> Vec<Box<dyn Array>> {
+        batch
+            .into_arrays()
+            .into_iter()
+            .enumerate()
+            .map(|(i, array)| {
+                if !self.categorical[i] {
+                    return array;
+                }
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<u32>>()
+                    .unwrap();
+                self.dictionaries
+                    .entry(i)
+                    .or_default()
+                    .extend(array)
+                    .boxed()
+            })
+            .collect()
+    }
+
+    /// The values of the dictionaries of the stream, by the index of the column.
+    fn dictionary_values(&mut self) -> PlHashMap<usize, Box<dyn Array>> {
+        let fields = &self.schema.fields;
+        self.dictionaries
+            .iter_mut()
+            .map(|(i, dictionary)| (*i, dictionary.values(&fields[*i].data_type)))
+            .collect()
+    }
+
+    /// The batch of which the categorical columns, given as keys, use the dictionary `values`.
+    fn with_dictionaries(
+        &self,
+        arrays: Vec<Box<dyn Array>>,
+        values: &PlHashMap<usize, Box<dyn Array>>,
+    ) -> PolarsResult<ArrowChunk> {
+        let arrays = arrays
+            .into_iter()
+            .enumerate()
+            .map(|(i, array)| {
+                let Some(values) = values.get(&i) else {
+                    return Ok(array);
+                };
+                let keys = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u32>>()
+                    .unwrap()
+                    .clone();
+                let dtype = self.schema.fields[i].data_type.clone();
+                Ok(DictionaryArray::try_new(dtype, keys, values.clone())?.boxed())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(ArrowChunk::new(arrays))
+    }
+}
+
 pub struct IpcStreamWriterOption {
     compression: Option<IpcCompression>,
     extension: PathBuf,
@@ -6,9 +6,9 @@ use memmap::Mmap;
 use polars_core::frame::ArrowChunk;
 use polars_core::prelude::*;
 
-use super::ipc_file::IpcReader;
+use super::ipc_file::{drop_skipped_batches, IpcReader};
 use crate::mmap::MmapBytesReader;
-use crate::predicates::PhysicalIoExpr;
+use crate::predicates::{apply_predicate, PhysicalIoExpr};
 use crate::shared::{finish_reader, ArrowReader};
 use crate::utils::{apply_projection, columns_to_projection};
 
@@ -20,7 +20,10 @@ impl<R: MmapBytesReader> IpcReader<R> {
         match self.reader.to_file() {
             Some(file) => {
                 let mmap = unsafe { memmap::Mmap::map(file).unwrap() };
-                let metadata = read::read_file_metadata(&mut std::io::Cursor::new(mmap.as_ref()))?;
+                let mut cursor = std::io::Cursor::new(mmap.as_ref());
+                let mut metadata = read::read_file_metadata(&mut cursor)?;
+                let skipped = drop_skipped_batches(&mut cursor, &mut metadata, self.skip_rows)?;
+                let (n_rows, row_index, to_slice) = self.args_after_skipped(skipped);
 
                 if let Some(columns) = &self.columns {
                     let schema = &metadata.schema;
@@ -36,15 +39,17 @@ impl<R: MmapBytesReader> IpcReader<R> {
 
                 let reader = MMapChunkIter::new(mmap, metadata, &self.projection)?;
 
-                finish_reader(
-                    reader,
-                    // don't rechunk, that would trigger a read.
-                    false,
-                    self.n_rows,
-                    predicate,
-                    &schema,
-                    self.row_index.clone(),
-                )
+                // The predicate is applied after the skipped rows are sliced off.
+                let (predicate, post_predicate) = if to_slice == 0 {
+                    (predicate, None)
+                } else {
+                    (None, predicate)
+                };
+                // don't rechunk, that would trigger a read.
+                let df = finish_reader(reader, false, n_rows, predicate, &schema, row_index)?;
+                let mut df = df.slice(to_slice as i64, usize::MAX);
+                apply_predicate(&mut df, post_predicate.as_deref(), false)?;
+                Ok(df)
             },
             None => polars_bail!(ComputeError: "cannot memory-map, you must provide a file"),
         }
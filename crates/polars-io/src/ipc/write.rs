@@ -1,22 +1,52 @@
 use std::io::Write;
 use std::path::PathBuf;
 
+use arrow::datatypes::IntegerType;
 use arrow::io::ipc::write;
 use arrow::io::ipc::write::WriteOptions;
+use polars_core::frame::ArrowChunk;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::WriterFactory;
+use crate::CommitStrategy;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcWriterOptions {
     /// Data page compression
     pub compression: Option<IpcCompression>,
+    /// The level of ZSTD compression, between 1 and 22. Defaults to 3.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compression_level: Option<i32>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// How the file is made visible at its path when it is written by a sink.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub commit_strategy: CommitStrategy,
+    /// Write the Arrow IPC stream format instead of the file format.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stream: bool,
+    /// How the dictionaries of categorical columns are written to a stream.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dictionary_mode: IpcDictionaryMode,
+}
+
+/// How the dictionaries of categorical columns are written to an IPC stream, whose batches can
+/// each have other categories.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IpcDictionaryMode {
+    /// Write the categories of the first batch as a dictionary, and the categories that later
+    /// batches add as delta dictionaries.
+    #[default]
+    Delta,
+    /// Hold the batches back until the stream is finished, and write a single dictionary with
+    /// the categories of all batches before them. This suits readers that don't support delta
+    /// dictionaries, at the cost of buffering the stream in memory.
+    UnifyAtFinish,
 }
 
 /// Write a DataFrame to Arrow's IPC format
@@ -41,6 +71,7 @@ pub struct IpcWriterOptions {
 pub struct IpcWriter<W> {
     pub(super) writer: W,
     pub(super) compression: Option<IpcCompression>,
+    pub(super) compression_level: Option<i32>,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) pl_flavor: bool,
 }
@@ -52,6 +83,12 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Set the level of ZSTD compression, between 1 and 22. Defaults to 3.
+    pub fn with_compression_level(mut self, level: Option<i32>) -> Self {
+        self.compression_level = level;
+        self
+    }
+
     pub fn with_pl_flavor(mut self, pl_flavor: bool) -> Self {
         self.pl_flavor = pl_flavor;
         self
@@ -60,16 +97,15 @@ impl<W: Write> IpcWriter<W> {
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let mut writer = write::FileWriter::new(
             self.writer,
-            Arc::new(schema.to_arrow(self.pl_flavor)),
+            Arc::new(file_schema(schema, self.pl_flavor)),
             None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
+            write_options(self.compression, self.compression_level)?,
         );
         writer.start()?;
 
         Ok(BatchedWriter {
             writer,
+            key_types: enum_key_types(schema),
             pl_flavor: self.pl_flavor,
         })
     }
@@ -83,24 +119,25 @@ where
         IpcWriter {
             writer,
             compression: None,
+            compression_level: None,
             pl_flavor: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let schema = df.schema();
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
-            Arc::new(df.schema().to_arrow(self.pl_flavor)),
+            Arc::new(file_schema(&schema, self.pl_flavor)),
             None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
+            write_options(self.compression, self.compression_level)?,
         )?;
         df.align_chunks();
+        let key_types = enum_key_types(&schema);
         let iter = df.iter_chunks(self.pl_flavor);
 
         for batch in iter {
-            ipc_writer.write(&batch, None)?
+            ipc_writer.write(&compact_enum_keys(batch, &key_types), None)?
         }
         ipc_writer.finish()?;
         Ok(())
@@ -109,6 +146,7 @@ where
 
 pub struct BatchedWriter<W: Write> {
     writer: write::FileWriter<W>,
+    key_types: Vec<Option<IntegerType>>,
     pl_flavor: bool,
 }
 
@@ -120,7 +158,8 @@ impl<W: Write> BatchedWriter<W> {
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         let iter = df.iter_chunks(self.pl_flavor);
         for batch in iter {
-            self.writer.write(&batch, None)?
+            self.writer
+                .write(&compact_enum_keys(batch, &self.key_types), None)?
         }
         Ok(())
     }
@@ -132,6 +171,57 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+/// The key type of the dictionary of every column in an IPC file: the narrowest type that can
+/// index all categories for enums, whose categories are known up front, and `None` for the
+/// other columns. Categoricals keep `u32` keys, as the batches of a file share its schema but
+/// can have more categories than the first one.
+pub(super) fn enum_key_types(schema: &Schema) -> Vec<Option<IntegerType>> {
+    schema
+        .iter_dtypes()
+        .map(|dtype| match dtype {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(Some(rev_map), _) => {
+                Some(CategoricalChunked::compact_key_type(rev_map.len()))
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The arrow schema of an IPC file, in which enums have the keys of [`enum_key_types`]. They
+/// are widened to `u32` again when the file is read.
+pub(super) fn file_schema(schema: &Schema, pl_flavor: bool) -> ArrowSchema {
+    let mut arrow_schema = schema.to_arrow(pl_flavor);
+    for (field, key_type) in arrow_schema.fields.iter_mut().zip(enum_key_types(schema)) {
+        if let (Some(key_type), ArrowDataType::Dictionary(_, values, is_sorted)) =
+            (key_type, &field.data_type)
+        {
+            field.data_type = ArrowDataType::Dictionary(key_type, values.clone(), *is_sorted);
+        }
+    }
+    arrow_schema
+}
+
+pub(super) fn compact_enum_keys(
+    batch: ArrowChunk,
+    key_types: &[Option<IntegerType>],
+) -> ArrowChunk {
+    if key_types.iter().all(Option::is_none) {
+        return batch;
+    }
+    let arrays = batch
+        .into_arrays()
+        .into_iter()
+        .zip(key_types)
+        .map(|(arr, key_type)| match key_type {
+            #[cfg(feature = "dtype-categorical")]
+            Some(key_type) => CategoricalChunked::narrow_arrow_keys(arr.as_ref(), *key_type),
+            _ => arr,
+        })
+        .collect();
+    ArrowChunk::new(arrays)
+}
+
 /// Compression codec
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -147,11 +237,45 @@ impl From<IpcCompression> for write::Compression {
     fn from(value: IpcCompression) -> Self {
         match value {
             IpcCompression::LZ4 => write::Compression::LZ4,
-            IpcCompression::ZSTD => write::Compression::ZSTD,
+            // Level 0 selects the default level of ZSTD.
+            IpcCompression::ZSTD => write::Compression::ZSTD(0),
         }
     }
 }
 
+impl IpcCompression {
+    /// Check that `level` is a compression level of `compression`. Only ZSTD has compression
+    /// levels, between 1 and 22.
+    pub fn check_level(compression: Option<Self>, level: Option<i32>) -> PolarsResult<()> {
+        match (compression, level) {
+            (_, None) => Ok(()),
+            (Some(IpcCompression::ZSTD), Some(level)) => {
+                polars_ensure!(
+                    (1..=22).contains(&level),
+                    InvalidOperation: "valid ZSTD compression levels are 1 to 22, got {}", level
+                );
+                Ok(())
+            },
+            (_, Some(_)) => {
+                polars_bail!(InvalidOperation: "only ZSTD compression has compression levels")
+            },
+        }
+    }
+}
+
+/// The options of arrow's IPC writers for `compression` at the compression `level`.
+pub(super) fn write_options(
+    compression: Option<IpcCompression>,
+    level: Option<i32>,
+) -> PolarsResult<WriteOptions> {
+    IpcCompression::check_level(compression, level)?;
+    let compression = match (compression, level) {
+        (Some(IpcCompression::ZSTD), Some(level)) => Some(write::Compression::ZSTD(level)),
+        (compression, _) => compression.map(|c| c.into()),
+    };
+    Ok(WriteOptions { compression })
+}
+
 pub struct IpcWriterOption {
     compression: Option<IpcCompression>,
     extension: PathBuf,
@@ -1,8 +1,9 @@
+use arrow::datatypes::IntegerType;
 use arrow::io::ipc::write::file_async::FileSink;
-use arrow::io::ipc::write::WriteOptions;
 use futures::{AsyncWrite, SinkExt};
 use polars_core::prelude::*;
 
+use crate::ipc::write::{compact_enum_keys, enum_key_types, file_schema, write_options};
 use crate::ipc::IpcWriter;
 
 impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
@@ -10,6 +11,7 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
         IpcWriter {
             writer,
             compression: None,
+            compression_level: None,
             pl_flavor: false,
         }
     }
@@ -17,14 +19,15 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
     pub fn batched_async(self, schema: &Schema) -> PolarsResult<BatchedWriterAsync<W>> {
         let writer = FileSink::new(
             self.writer,
-            schema.to_arrow(false),
+            file_schema(schema, false),
             None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
+            write_options(self.compression, self.compression_level)?,
         );
 
-        Ok(BatchedWriterAsync { writer })
+        Ok(BatchedWriterAsync {
+            writer,
+            key_types: enum_key_types(schema),
+        })
     }
 }
 
@@ -33,6 +36,7 @@ where
     W: AsyncWrite + Unpin + Send + 'a,
 {
     writer: FileSink<'a, W>,
+    key_types: Vec<Option<IntegerType>>,
 }
 
 impl<'a, W> BatchedWriterAsync<'a, W>
@@ -46,6 +50,7 @@ where
     pub async fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         let iter = df.iter_chunks(false);
         for batch in iter {
+            let batch = compact_enum_keys(batch, &self.key_types);
             self.writer.feed(batch.into()).await?;
         }
         Ok(())
@@ -78,12 +78,16 @@ use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
+use crate::CommitStrategy;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JsonWriterOptions {
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// How the file is made visible at its path when it is written by a sink.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub commit_strategy: CommitStrategy,
 }
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array) or `JsonLines` (each row output on a
@@ -1,7 +1,7 @@
 use std::fs::File;
 use std::io::Cursor;
 use std::num::NonZeroUsize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub use arrow::array::StructArray;
 use num_traits::pow::Pow;
@@ -233,13 +233,11 @@ impl<'a> CoreJsonReader<'a> {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
-                    parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
-                    DataFrame::new(
-                        buffers
-                            .into_values()
-                            .map(|buf| buf.into_series())
-                            .collect::<_>(),
+                    parse_ndjson(
+                        &bytes[start_pos..stop_at_nbytes],
+                        &self.schema,
+                        capacity,
+                        self.ignore_errors,
                     )
                 })
                 .collect::<PolarsResult<Vec<_>>>()
@@ -264,6 +262,163 @@ impl<'a> CoreJsonReader<'a> {
     }
 }
 
+/// Reads an NDJSON file in batches.
+///
+/// Every batch is a byte range of roughly `chunk_size` lines that ends on a line
+/// boundary. The ranges of a call to [`next_batches`](Self::next_batches) are decoded in
+/// parallel.
+pub struct BatchedJsonLineReader {
+    mmap: memmap::Mmap,
+    schema: SchemaRef,
+    offset: usize,
+    chunk_size_bytes: usize,
+    rows_per_chunk: usize,
+    n_rows: Option<usize>,
+    rows_read: usize,
+    ignore_errors: bool,
+}
+
+impl BatchedJsonLineReader {
+    /// Create a reader for the file at `path`. Only the columns in `schema` are decoded.
+    pub fn new(
+        path: &Path,
+        schema: SchemaRef,
+        chunk_size: NonZeroUsize,
+        n_rows: Option<usize>,
+        ignore_errors: bool,
+    ) -> PolarsResult<Self> {
+        let file = polars_utils::open_file(path)?;
+        let mmap = unsafe { memmap::Mmap::map(&file)? };
+        let mean_line_length = get_line_stats_json(&mmap, 1024)
+            .map(|(mean, _)| mean)
+            .unwrap_or(128.0);
+        let chunk_size_bytes = std::cmp::max(
+            (mean_line_length * usize::from(chunk_size) as f32) as usize,
+            1,
+        );
+        Ok(Self {
+            mmap,
+            schema,
+            offset: 0,
+            chunk_size_bytes,
+            rows_per_chunk: chunk_size.into(),
+            n_rows,
+            rows_read: 0,
+            ignore_errors,
+        })
+    }
+
+    fn is_finished(&self) -> bool {
+        self.offset >= self.mmap.len() || self.n_rows.is_some_and(|n| self.rows_read >= n)
+    }
+
+    /// Decode the next `n` batches. Returns `None` once the file or the
+    /// requested number of rows is exhausted.
+    pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        if self.is_finished() {
+            return Ok(None);
+        }
+        let bytes: &[u8] = &self.mmap;
+        let mut ranges = Vec::with_capacity(n);
+        while ranges.len() < n && self.offset < bytes.len() {
+            let end = next_line_boundary(bytes, self.offset + self.chunk_size_bytes);
+            ranges.push((self.offset, end));
+            self.offset = end;
+        }
+
+        let dfs = POOL.install(|| {
+            ranges
+                .into_par_iter()
+                .map(|(start, end)| {
+                    parse_ndjson(
+                        &bytes[start..end],
+                        &self.schema,
+                        self.rows_per_chunk,
+                        self.ignore_errors,
+                    )
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut out = Vec::with_capacity(dfs.len());
+        for mut df in dfs {
+            if let Some(n_rows) = self.n_rows {
+                let remaining = n_rows - self.rows_read;
+                if df.height() >= remaining {
+                    df = df.slice(0, remaining);
+                    self.offset = bytes.len();
+                }
+            }
+            self.rows_read += df.height();
+            out.push(df);
+            if self.is_finished() {
+                break;
+            }
+        }
+        Ok(Some(out))
+    }
+}
+
+/// Count the rows of the NDJSON file at `path` without parsing them.
+///
+/// Every line that isn't blank is a row, as a JSON value can't contain a line end.
+pub fn count_rows(path: &Path) -> PolarsResult<usize> {
+    let file = polars_utils::open_file(path)?;
+    let mmap = unsafe { memmap::Mmap::map(&file)? };
+    let bytes: &[u8] = &mmap;
+    let file_chunks = get_file_chunks_json(bytes, POOL.current_num_threads());
+    let n_rows = POOL.install(|| {
+        file_chunks
+            .into_par_iter()
+            .map(|(start, stop)| count_lines_json(&bytes[start..stop]))
+            .sum()
+    });
+    Ok(n_rows)
+}
+
+/// Count the lines in `bytes` that aren't blank.
+fn count_lines_json(bytes: &[u8]) -> usize {
+    let is_row = |line: &[u8]| !line.iter().all(u8::is_ascii_whitespace);
+    let mut n_rows = 0;
+    let mut line_start = 0;
+    for pos in memchr::memchr_iter(NEWLINE, bytes) {
+        n_rows += is_row(&bytes[line_start..pos]) as usize;
+        line_start = pos + 1;
+    }
+    n_rows + is_row(&bytes[line_start..]) as usize
+}
+
+/// Position after the first line end at or after `pos`.
+fn next_line_boundary(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() {
+        match next_line_position_naive_json(&bytes[pos..]) {
+            Some(offset) => return pos + offset,
+            None => match memchr::memchr(NEWLINE, &bytes[pos..]) {
+                // Not preceded by a closing bracket, search on.
+                Some(offset) => pos += offset + 1,
+                None => break,
+            },
+        }
+    }
+    bytes.len()
+}
+
+fn parse_ndjson(
+    bytes: &[u8],
+    schema: &Schema,
+    capacity: usize,
+    ignore_errors: bool,
+) -> PolarsResult<DataFrame> {
+    let mut buffers = init_buffers(schema, capacity, ignore_errors)?;
+    parse_lines(bytes, &mut buffers)?;
+    DataFrame::new(
+        buffers
+            .into_values()
+            .map(|buf| buf.into_series())
+            .collect::<_>(),
+    )
+}
+
 #[inline(always)]
 fn parse_impl(
     bytes: &[u8],
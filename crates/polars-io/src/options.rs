@@ -26,3 +26,182 @@ impl Default for HiveOptions {
         }
     }
 }
+
+/// What to do with the files that exist in the directory a partitioned dataset is written to.
+///
+/// `Error`, `DeleteMatching` and `Append` correspond to the `errorifexists`, dynamic partition
+/// `overwrite` and `append` save modes of Spark.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExistingDataBehavior {
+    /// Fail if the directory isn't empty.
+    #[default]
+    Error,
+    /// Overwrite the files with the names that are written and keep the other files.
+    OverwriteOrIgnore,
+    /// Delete the directories of the partitions that are written before writing them, and keep
+    /// the other partitions.
+    DeleteMatching,
+    /// Keep the existing files and add the new files next to them, with names that don't
+    /// collide with the existing files.
+    Append,
+}
+
+fn default_max_open_files() -> usize {
+    512
+}
+
+/// Options for writing a dataset partitioned into Hive-style directories, `key=value/...`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartitionedWriteOptions {
+    /// The columns to partition by, one directory level per column. The partition columns
+    /// aren't written to the files.
+    pub partition_by: Vec<String>,
+    /// The maximum number of rows of a file. If `None`, every partition is written to a
+    /// single file.
+    pub max_rows_per_file: Option<usize>,
+    pub existing_data_behavior: ExistingDataBehavior,
+    /// Write a `_metadata` file with the metadata of all files to the root directory. This
+    /// needs the [`ExistingDataBehavior::Error`] behavior, as the file can't be merged with
+    /// the metadata of existing files.
+    pub write_metadata_file: bool,
+    /// Write a `_statistics` file with the statistics of the row groups of all files to the
+    /// root directory, which scans use to skip files without reading their footers. The
+    /// statistics of the existing files that aren't replaced are kept in the file.
+    pub write_statistics_file: bool,
+    /// The maximum number of files that are open at once. Once it is reached, the file of the
+    /// partition that was written to least recently is finished, and the next rows of that
+    /// partition are written to a new file.
+    #[cfg_attr(feature = "serde", serde(default = "default_max_open_files"))]
+    pub max_open_files: usize,
+}
+
+impl Default for PartitionedWriteOptions {
+    fn default() -> Self {
+        Self {
+            partition_by: vec![],
+            max_rows_per_file: None,
+            existing_data_behavior: ExistingDataBehavior::default(),
+            write_metadata_file: false,
+            write_statistics_file: false,
+            max_open_files: default_max_open_files(),
+        }
+    }
+}
+
+impl PartitionedWriteOptions {
+    pub fn new<I, S>(partition_by: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            partition_by: partition_by.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_open_files(mut self, max_open_files: usize) -> Self {
+        self.max_open_files = max_open_files;
+        self
+    }
+
+    pub fn with_max_rows_per_file(mut self, max_rows_per_file: Option<usize>) -> Self {
+        self.max_rows_per_file = max_rows_per_file;
+        self
+    }
+
+    pub fn with_existing_data_behavior(mut self, behavior: ExistingDataBehavior) -> Self {
+        self.existing_data_behavior = behavior;
+        self
+    }
+
+    pub fn with_metadata_file(mut self, write_metadata_file: bool) -> Self {
+        self.write_metadata_file = write_metadata_file;
+        self
+    }
+
+    pub fn with_statistics_file(mut self, write_statistics_file: bool) -> Self {
+        self.write_statistics_file = write_statistics_file;
+        self
+    }
+}
+
+/// Options for splitting the output of a sink into multiple files in a directory.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SplitWriteOptions {
+    /// The maximum number of rows of a file.
+    pub max_rows_per_file: Option<usize>,
+    /// The size after which a file is closed and a new file is started. As the files are
+    /// written batch by batch, a file can be larger by up to one batch.
+    pub max_bytes_per_file: Option<usize>,
+    /// The names of the files, in which `{i}` is replaced by the index of the file, starting at
+    /// 0, and `{ext}` by the extension of the file type. The files of earlier runs that are
+    /// named after it are replaced, or removed if fewer files are written.
+    pub file_name_template: String,
+}
+
+impl Default for SplitWriteOptions {
+    fn default() -> Self {
+        Self {
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+            file_name_template: "part-{i}.{ext}".to_string(),
+        }
+    }
+}
+
+impl SplitWriteOptions {
+    pub fn with_max_rows_per_file(mut self, max_rows_per_file: Option<usize>) -> Self {
+        self.max_rows_per_file = max_rows_per_file;
+        self
+    }
+
+    pub fn with_max_bytes_per_file(mut self, max_bytes_per_file: Option<usize>) -> Self {
+        self.max_bytes_per_file = max_bytes_per_file;
+        self
+    }
+
+    pub fn with_file_name_template(mut self, file_name_template: impl Into<String>) -> Self {
+        self.file_name_template = file_name_template.into();
+        self
+    }
+
+    /// The name of the file with `index`.
+    pub fn file_name(&self, index: usize, extension: &str) -> String {
+        self.file_name_template
+            .replace("{i}", &index.to_string())
+            .replace("{ext}", extension)
+    }
+
+    /// The index of the file named `name`, or `None` if it isn't named after the template.
+    pub fn file_index(&self, name: &str, extension: &str) -> Option<usize> {
+        let (prefix, _) = self.file_name_template.split_once("{i}")?;
+        let digits = name.strip_prefix(prefix.replace("{ext}", extension).as_str())?;
+        let len = digits.bytes().take_while(u8::is_ascii_digit).count();
+        let index = digits[..len].parse().ok()?;
+        (self.file_name(index, extension) == name).then_some(index)
+    }
+}
+
+/// How the output of a sink is made visible at its target path.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CommitStrategy {
+    /// Write to the target path directly. A query that fails leaves a partial file behind.
+    ///
+    /// Uploads to cloud storage are completed once the file is completely written and
+    /// aborted otherwise, with either strategy.
+    #[default]
+    Direct,
+    /// Write to a temporary file next to the target path, which is synced to disk and renamed
+    /// to the target path once it is completely written.
+    ///
+    /// A partitioned sink writes its files to a directory next to the root directory and
+    /// moves every partition directory into the root with a rename once all files are
+    /// written. The partitions are moved one after the other, so the commit of a dataset
+    /// isn't atomic as a whole.
+    Atomic,
+}
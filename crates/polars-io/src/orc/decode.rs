@@ -0,0 +1,457 @@
+//! The decompression of ORC streams and the run length encodings of their values.
+use std::io::Read;
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+
+use super::proto::{varint, CompressionKind};
+
+/// Decompresses a stream, which consists of chunks that are each compressed, or stored as is
+/// when compression didn't make them smaller.
+pub(super) fn decompress(
+    compression: CompressionKind,
+    block_size: usize,
+    mut bytes: &[u8],
+) -> PolarsResult<Vec<u8>> {
+    if compression == CompressionKind::None {
+        return Ok(bytes.to_vec());
+    }
+    let mut out = Vec::with_capacity(bytes.len());
+    while !bytes.is_empty() {
+        polars_ensure!(
+            bytes.len() >= 3,
+            ComputeError: "unexpected end of a compressed ORC stream"
+        );
+        let header = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]);
+        let is_original = header & 1 == 1;
+        let len = (header >> 1) as usize;
+        polars_ensure!(
+            bytes.len() >= 3 + len,
+            ComputeError: "unexpected end of a compressed ORC stream"
+        );
+        let chunk = &bytes[3..3 + len];
+        bytes = &bytes[3 + len..];
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+        match compression {
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk).read_to_end(&mut out)?;
+            },
+            CompressionKind::Zstd => out.extend(zstd::decode_all(chunk)?),
+            CompressionKind::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(to_compute_err)?;
+                out.extend(decompressed)
+            },
+            CompressionKind::Lz4 => {
+                // A chunk decompresses to at most the compression block size.
+                let decompressed = lz4::block::decompress(chunk, Some(block_size as i32))?;
+                out.extend(decompressed)
+            },
+            _ => polars_bail!(
+                ComputeError: "the ORC compression {:?} is not supported", compression
+            ),
+        }
+    }
+    Ok(out)
+}
+
+struct Bytes<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Bytes<'a> {
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn byte(&mut self) -> PolarsResult<u8> {
+        let (&byte, rest) = self
+            .bytes
+            .split_first()
+            .ok_or_else(|| polars_err!(ComputeError: "unexpected end of an ORC stream"))?;
+        self.bytes = rest;
+        Ok(byte)
+    }
+
+    fn take(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            len <= self.bytes.len(),
+            ComputeError: "unexpected end of an ORC stream"
+        );
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn varint(&mut self, signed: bool) -> PolarsResult<i64> {
+        let value = varint(&mut self.bytes)?;
+        Ok(if signed { zigzag(value) } else { value as i64 })
+    }
+
+    /// An integer of `width` bytes, stored big endian.
+    fn big_endian(&mut self, width: usize) -> PolarsResult<u64> {
+        Ok(self
+            .take(width)?
+            .iter()
+            .fold(0u64, |value, byte| (value << 8) | *byte as u64))
+    }
+
+    /// `n` integers of `width` bits each, packed with the most significant bit first. The
+    /// remaining bits of the last byte are unused.
+    fn unpack(&mut self, n: usize, width: usize, out: &mut Vec<u64>) -> PolarsResult<()> {
+        let bytes = self.take((n * width).div_ceil(8))?;
+        let mut bit = 0;
+        for _ in 0..n {
+            let mut value = 0u64;
+            let mut remaining = width;
+            while remaining > 0 {
+                let byte = bytes[bit / 8];
+                let available = 8 - bit % 8;
+                let len = available.min(remaining);
+                let bits = (byte >> (available - len)) & ((1u16 << len) - 1) as u8;
+                value = (value << len) | bits as u64;
+                remaining -= len;
+                bit += len;
+            }
+            out.push(value);
+        }
+        Ok(())
+    }
+}
+
+fn zigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Decodes `n` bytes that are stored with the byte run length encoding.
+pub(super) fn decode_bytes(bytes: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut bytes = Bytes { bytes };
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let header = bytes.byte()?;
+        if header < 0x80 {
+            let value = bytes.byte()?;
+            out.extend(std::iter::repeat(value).take(header as usize + 3));
+        } else {
+            out.extend_from_slice(bytes.take(0x100 - header as usize)?);
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decodes `n` booleans, which are stored as bits with the byte run length encoding.
+pub(super) fn decode_booleans(bytes: &[u8], n: usize) -> PolarsResult<Vec<bool>> {
+    let bytes = decode_bytes(bytes, n.div_ceil(8))?;
+    Ok((0..n)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+/// Decodes `n` unscaled decimals, which are stored as zigzag encoded varints of up to 128
+/// bits.
+pub(super) fn decode_decimals(bytes: &[u8], n: usize) -> PolarsResult<Vec<i128>> {
+    let mut bytes = Bytes { bytes };
+    (0..n)
+        .map(|_| {
+            let mut value = 0u128;
+            for shift in (0..128).step_by(7) {
+                let byte = bytes.byte()?;
+                value |= ((byte & 0x7f) as u128) << shift;
+                if byte & 0x80 == 0 {
+                    return Ok((value >> 1) as i128 ^ -((value & 1) as i128));
+                }
+            }
+            polars_bail!(ComputeError: "invalid varint in an ORC decimal stream")
+        })
+        .collect()
+}
+
+/// The version of the run length encoding of integers, which follows from the encoding of
+/// a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum RleVersion {
+    V1,
+    V2,
+}
+
+/// Decodes `n` integers that are stored with a run length encoding.
+pub(super) fn decode_integers(
+    bytes: &[u8],
+    n: usize,
+    signed: bool,
+    version: RleVersion,
+) -> PolarsResult<Vec<i64>> {
+    let mut bytes = Bytes { bytes };
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n && !bytes.is_empty() {
+        match version {
+            RleVersion::V1 => decode_run_v1(&mut bytes, signed, &mut out)?,
+            RleVersion::V2 => decode_run_v2(&mut bytes, signed, &mut out)?,
+        }
+    }
+    polars_ensure!(
+        out.len() >= n,
+        ComputeError: "an ORC integer stream has {} values, expected {}", out.len(), n
+    );
+    out.truncate(n);
+    Ok(out)
+}
+
+fn decode_run_v1(bytes: &mut Bytes, signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = bytes.byte()?;
+    if header < 0x80 {
+        let len = header as usize + 3;
+        let delta = bytes.byte()? as i8 as i64;
+        let base = bytes.varint(signed)?;
+        out.extend((0..len as i64).map(|i| base.wrapping_add(i * delta)));
+    } else {
+        for _ in 0..0x100 - header as usize {
+            out.push(bytes.varint(signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// The width in bits of the values of a run, which is encoded in 5 bits.
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// The width in bits that the values of `width` bits are packed with.
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn decode_run_v2(bytes: &mut Bytes, signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = bytes.byte()?;
+    let decode = |value: u64| {
+        if signed {
+            zigzag(value)
+        } else {
+            value as i64
+        }
+    };
+    match header >> 6 {
+        // Short repeat.
+        0 => {
+            let width = ((header >> 3) & 0x7) as usize + 1;
+            let len = (header & 0x7) as usize + 3;
+            let value = decode(bytes.big_endian(width)?);
+            out.extend(std::iter::repeat(value).take(len));
+        },
+        // Direct.
+        1 => {
+            let width = decode_bit_width((header >> 1) & 0x1f);
+            let len = (((header & 1) as usize) << 8 | bytes.byte()? as usize) + 1;
+            let mut values = Vec::with_capacity(len);
+            bytes.unpack(len, width, &mut values)?;
+            out.extend(values.into_iter().map(decode));
+        },
+        // Patched base.
+        2 => {
+            let width = decode_bit_width((header >> 1) & 0x1f);
+            let len = (((header & 1) as usize) << 8 | bytes.byte()? as usize) + 1;
+            let third = bytes.byte()?;
+            let base_width = ((third >> 5) & 0x7) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1f);
+            let fourth = bytes.byte()?;
+            let gap_width = ((fourth >> 5) & 0x7) as usize + 1;
+            let patches_len = (fourth & 0x1f) as usize;
+            polars_ensure!(
+                patch_width + gap_width <= 64,
+                ComputeError: "invalid patch in an ORC integer stream"
+            );
+
+            // The base is stored as a sign and a magnitude.
+            let base = bytes.big_endian(base_width)?;
+            let sign_bit = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_bit != 0 {
+                -((base & !sign_bit) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(len);
+            bytes.unpack(len, width, &mut values)?;
+            let mut patches = Vec::with_capacity(patches_len);
+            bytes.unpack(
+                patches_len,
+                closest_fixed_bits(patch_width + gap_width),
+                &mut patches,
+            )?;
+            // Every patch holds the gap to the position of the previous patch, and the high
+            // bits of the value at its position.
+            let mut position = 0;
+            for patch in patches {
+                position += (patch >> patch_width) as usize;
+                let high_bits = patch & ((1u64 << patch_width) - 1);
+                if let Some(value) = values.get_mut(position) {
+                    *value |= high_bits << width;
+                }
+            }
+            out.extend(
+                values
+                    .into_iter()
+                    .map(|value| base.wrapping_add(value as i64)),
+            );
+        },
+        // Delta.
+        _ => {
+            let code = (header >> 1) & 0x1f;
+            let width = if code == 0 { 0 } else { decode_bit_width(code) };
+            // The number of values after the first one.
+            let len = ((header & 1) as usize) << 8 | bytes.byte()? as usize;
+            let first = bytes.varint(signed)?;
+            let delta = bytes.varint(true)?;
+            out.push(first);
+            if width == 0 {
+                // Every value differs from the previous one by `delta`.
+                let mut value = first;
+                for _ in 0..len {
+                    value = value.wrapping_add(delta);
+                    out.push(value);
+                }
+            } else if len > 0 {
+                let mut value = first.wrapping_add(delta);
+                out.push(value);
+                // The other deltas are magnitudes with the sign of the first delta.
+                let mut deltas = Vec::with_capacity(len - 1);
+                bytes.unpack(len - 1, width, &mut deltas)?;
+                for d in deltas {
+                    value = if delta < 0 {
+                        value.wrapping_sub(d as i64)
+                    } else {
+                        value.wrapping_add(d as i64)
+                    };
+                    out.push(value);
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_integers_v2() {
+        // The examples of the ORC specification.
+        let short_repeat = [0x0a, 0x27, 0x10];
+        let values = decode_integers(&short_repeat, 5, false, RleVersion::V2).unwrap();
+        assert_eq!(values, [10000; 5]);
+
+        let direct = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        let values = decode_integers(&direct, 4, false, RleVersion::V2).unwrap();
+        assert_eq!(values, [23713, 43806, 57005, 48879]);
+
+        let patched_base = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        let values = decode_integers(&patched_base, 20, false, RleVersion::V2).unwrap();
+        assert_eq!(
+            values,
+            [
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120,
+                2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+
+        let delta = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        let values = decode_integers(&delta, 10, false, RleVersion::V2).unwrap();
+        assert_eq!(values, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn test_decode_integers_v1() {
+        // A run of 100 values from 7 with a delta of -1, and two literals.
+        let bytes = [0x61, 0xff, 0x0e, 0xfe, 0x04, 0x05];
+        let values = decode_integers(&bytes, 102, true, RleVersion::V1).unwrap();
+        assert_eq!(values[..3], [7, 6, 5]);
+        assert_eq!(values[99], -92);
+        assert_eq!(values[100..], [2, -3]);
+    }
+
+    #[test]
+    fn test_decompress() {
+        use std::io::Write;
+
+        let data = b"the chunks of ORC streams, the chunks of ORC streams".repeat(10);
+        let mut zlib = flate2::write::DeflateEncoder::new(vec![], Default::default());
+        zlib.write_all(&data).unwrap();
+        let chunks = [
+            (CompressionKind::Zlib, zlib.finish().unwrap()),
+            (
+                CompressionKind::Zstd,
+                zstd::encode_all(&data[..], 3).unwrap(),
+            ),
+            (
+                CompressionKind::Snappy,
+                snap::raw::Encoder::new().compress_vec(&data).unwrap(),
+            ),
+            (
+                CompressionKind::Lz4,
+                lz4::block::compress(&data, None, false).unwrap(),
+            ),
+        ];
+        for (compression, chunk) in chunks {
+            // A compressed chunk, followed by a chunk that is stored as is.
+            let mut stream = ((chunk.len() as u32) << 1).to_le_bytes()[..3].to_vec();
+            stream.extend_from_slice(&chunk);
+            stream.extend_from_slice(&[(3 << 1) | 1, 0, 0]);
+            stream.extend_from_slice(b"end");
+
+            let out = decompress(compression, data.len(), &stream).unwrap();
+            assert_eq!(out[..data.len()], data[..], "{compression:?}");
+            assert_eq!(&out[data.len()..], b"end");
+        }
+    }
+
+    #[test]
+    fn test_decode_decimals() {
+        // 1, -1 and 2^64, zigzag encoded.
+        let bytes = [
+            0x02, 0x01, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x04,
+        ];
+        let values = decode_decimals(&bytes, 3).unwrap();
+        assert_eq!(values, [1, -1, 1 << 64]);
+    }
+
+    #[test]
+    fn test_decode_booleans() {
+        // A run of 3 bytes, then a literal byte.
+        let bytes = [0x00, 0xff, 0xff, 0x80];
+        let values = decode_booleans(&bytes, 25).unwrap();
+        assert!(values[..24].iter().all(|v| *v));
+        assert!(values[24]);
+    }
+}
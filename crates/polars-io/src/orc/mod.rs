@@ -0,0 +1,11 @@
+//! Read [Apache ORC] files into a [`DataFrame`](polars_core::prelude::DataFrame).
+//!
+//! The stripes of a file are read in parallel, and only the columns that are projected are
+//! decoded. Stripes whose statistics show that no row can satisfy the predicate are skipped.
+//!
+//! [Apache ORC]: https://orc.apache.org
+mod decode;
+mod proto;
+mod read;
+
+pub use read::*;
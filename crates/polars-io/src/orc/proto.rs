@@ -0,0 +1,500 @@
+//! A decoder of the protobuf messages in which ORC files store their metadata.
+//!
+//! Only the messages and fields that the reader needs are decoded, other fields are skipped.
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+
+/// A field of a protobuf message, by its wire type.
+#[derive(Clone, Copy, Debug)]
+enum Value<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl<'a> Value<'a> {
+    fn as_u64(self) -> u64 {
+        match self {
+            Value::Varint(v) | Value::Fixed64(v) => v,
+            Value::Fixed32(v) => v as u64,
+            Value::Bytes(_) => 0,
+        }
+    }
+
+    /// A `sint64` or `sint32`, which are zigzag encoded.
+    fn as_sint(self) -> i64 {
+        let v = self.as_u64();
+        (v >> 1) as i64 ^ -((v & 1) as i64)
+    }
+
+    fn as_f64(self) -> f64 {
+        f64::from_bits(self.as_u64())
+    }
+
+    fn as_bytes(self) -> &'a [u8] {
+        match self {
+            Value::Bytes(v) => v,
+            _ => &[],
+        }
+    }
+
+    fn as_string(self) -> PolarsResult<String> {
+        String::from_utf8(self.as_bytes().to_vec()).map_err(to_compute_err)
+    }
+
+    /// The values of a repeated integer field, which may be packed.
+    fn extend_packed(self, values: &mut Vec<u64>) -> PolarsResult<()> {
+        match self {
+            Value::Bytes(mut bytes) => {
+                while !bytes.is_empty() {
+                    values.push(varint(&mut bytes)?);
+                }
+            },
+            value => values.push(value.as_u64()),
+        }
+        Ok(())
+    }
+}
+
+pub(super) fn varint(bytes: &mut &[u8]) -> PolarsResult<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes
+            .split_first()
+            .ok_or_else(|| polars_err!(ComputeError: "unexpected end of an ORC varint"))?;
+        *bytes = rest;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    polars_bail!(ComputeError: "invalid varint in an ORC file")
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> PolarsResult<&'a [u8]> {
+    polars_ensure!(
+        len <= bytes.len(),
+        ComputeError: "unexpected end of an ORC protobuf message"
+    );
+    let (taken, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Ok(taken)
+}
+
+/// Calls `f` with the number and the value of every field of the message in `bytes`.
+fn for_each_field<'a>(
+    mut bytes: &'a [u8],
+    mut f: impl FnMut(u64, Value<'a>) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    while !bytes.is_empty() {
+        let key = varint(&mut bytes)?;
+        let value = match key & 0x7 {
+            0 => Value::Varint(varint(&mut bytes)?),
+            1 => Value::Fixed64(u64::from_le_bytes(take(&mut bytes, 8)?.try_into().unwrap())),
+            2 => {
+                let len = varint(&mut bytes)? as usize;
+                Value::Bytes(take(&mut bytes, len)?)
+            },
+            5 => Value::Fixed32(u32::from_le_bytes(take(&mut bytes, 4)?.try_into().unwrap())),
+            wire_type => polars_bail!(
+                ComputeError: "unsupported protobuf wire type {} in an ORC file", wire_type
+            ),
+        };
+        f(key >> 3, value)?;
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum CompressionKind {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Debug)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: u64,
+    pub metadata_length: u64,
+}
+
+impl PostScript {
+    pub fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut postscript = PostScript {
+            footer_length: 0,
+            compression: CompressionKind::None,
+            compression_block_size: 256 * 1024,
+            metadata_length: 0,
+        };
+        let mut magic = None;
+        for_each_field(bytes, |field, value| {
+            match field {
+                1 => postscript.footer_length = value.as_u64(),
+                2 => {
+                    postscript.compression = match value.as_u64() {
+                        0 => CompressionKind::None,
+                        1 => CompressionKind::Zlib,
+                        2 => CompressionKind::Snappy,
+                        3 => CompressionKind::Lzo,
+                        4 => CompressionKind::Lz4,
+                        5 => CompressionKind::Zstd,
+                        kind => polars_bail!(
+                            ComputeError: "unknown ORC compression kind {}", kind
+                        ),
+                    }
+                },
+                3 => postscript.compression_block_size = value.as_u64(),
+                5 => postscript.metadata_length = value.as_u64(),
+                8000 => magic = Some(value.as_bytes()),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        polars_ensure!(
+            magic.map_or(true, |magic| magic == b"ORC"),
+            ComputeError: "not an ORC file"
+        );
+        Ok(postscript)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut stripe = Self::default();
+        for_each_field(bytes, |field, value| {
+            match field {
+                1 => stripe.offset = value.as_u64(),
+                2 => stripe.index_length = value.as_u64(),
+                3 => stripe.data_length = value.as_u64(),
+                4 => stripe.footer_length = value.as_u64(),
+                5 => stripe.number_of_rows = value.as_u64(),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(stripe)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct OrcType {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u64>,
+    pub field_names: Vec<String>,
+    /// The precision and the scale of a decimal type.
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+}
+
+impl OrcType {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut kind = TypeKind::Boolean;
+        let mut subtypes = vec![];
+        let mut field_names = vec![];
+        let mut precision = None;
+        let mut scale = None;
+        for_each_field(bytes, |field, value| {
+            match field {
+                1 => {
+                    kind = match value.as_u64() {
+                        0 => TypeKind::Boolean,
+                        1 => TypeKind::Byte,
+                        2 => TypeKind::Short,
+                        3 => TypeKind::Int,
+                        4 => TypeKind::Long,
+                        5 => TypeKind::Float,
+                        6 => TypeKind::Double,
+                        7 => TypeKind::String,
+                        8 => TypeKind::Binary,
+                        9 => TypeKind::Timestamp,
+                        10 => TypeKind::List,
+                        11 => TypeKind::Map,
+                        12 => TypeKind::Struct,
+                        13 => TypeKind::Union,
+                        14 => TypeKind::Decimal,
+                        15 => TypeKind::Date,
+                        16 => TypeKind::Varchar,
+                        17 => TypeKind::Char,
+                        18 => TypeKind::TimestampInstant,
+                        kind => polars_bail!(ComputeError: "unknown ORC type kind {}", kind),
+                    }
+                },
+                2 => value.extend_packed(&mut subtypes)?,
+                3 => field_names.push(value.as_string()?),
+                5 => precision = Some(value.as_u64() as u32),
+                6 => scale = Some(value.as_u64() as u32),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(OrcType {
+            kind,
+            subtypes,
+            field_names,
+            precision,
+            scale,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Footer {
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<OrcType>,
+}
+
+impl Footer {
+    pub fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut footer = Footer {
+            stripes: vec![],
+            types: vec![],
+        };
+        for_each_field(bytes, |field, value| {
+            match field {
+                3 => footer
+                    .stripes
+                    .push(StripeInformation::decode(value.as_bytes())?),
+                4 => footer.types.push(OrcType::decode(value.as_bytes())?),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(footer)
+    }
+}
+
+/// The minimum and the maximum of the values of a column.
+#[derive(Clone, Debug)]
+pub(super) enum Bounds {
+    Int(i64, i64),
+    Double(f64, f64),
+    String(String, String),
+    Date(i32, i32),
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct ColumnStatistics {
+    /// The number of values that aren't null.
+    pub number_of_values: Option<u64>,
+    pub bounds: Option<Bounds>,
+}
+
+impl ColumnStatistics {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut stats = Self::default();
+        for_each_field(bytes, |field, value| {
+            match field {
+                1 => stats.number_of_values = Some(value.as_u64()),
+                2 => {
+                    stats.bounds =
+                        min_max(value, Value::as_sint)?.map(|(min, max)| Bounds::Int(min, max))
+                },
+                3 => {
+                    stats.bounds =
+                        min_max(value, Value::as_f64)?.map(|(min, max)| Bounds::Double(min, max))
+                },
+                4 => {
+                    let bounds = min_max(value, |value| value.as_bytes().to_vec())?;
+                    stats.bounds = match bounds {
+                        Some((min, max)) => {
+                            let min = String::from_utf8(min).map_err(to_compute_err)?;
+                            let max = String::from_utf8(max).map_err(to_compute_err)?;
+                            Some(Bounds::String(min, max))
+                        },
+                        None => None,
+                    }
+                },
+                7 => {
+                    stats.bounds = min_max(value, |value| value.as_sint() as i32)?
+                        .map(|(min, max)| Bounds::Date(min, max))
+                },
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(stats)
+    }
+}
+
+/// The minimum and the maximum, fields 1 and 2, of a typed statistics message. These are
+/// missing if the column has no values that aren't null.
+fn min_max<T>(value: Value, decode: impl Fn(Value) -> T) -> PolarsResult<Option<(T, T)>> {
+    let mut min = None;
+    let mut max = None;
+    for_each_field(value.as_bytes(), |field, value| {
+        match field {
+            1 => min = Some(decode(value)),
+            2 => max = Some(decode(value)),
+            _ => {},
+        }
+        Ok(())
+    })?;
+    Ok(min.zip(max))
+}
+
+/// The statistics of the columns of every stripe, indexed by the id of the column.
+pub(super) fn decode_metadata(bytes: &[u8]) -> PolarsResult<Vec<Vec<ColumnStatistics>>> {
+    let mut stripes = vec![];
+    for_each_field(bytes, |field, value| {
+        if field == 1 {
+            let mut columns = vec![];
+            for_each_field(value.as_bytes(), |field, value| {
+                if field == 1 {
+                    columns.push(ColumnStatistics::decode(value.as_bytes())?);
+                }
+                Ok(())
+            })?;
+            stripes.push(columns);
+        }
+        Ok(())
+    })?;
+    Ok(stripes)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// The streams that the reader doesn't read, such as the row indexes.
+    Other,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct Stream {
+    pub kind: StreamKind,
+    pub column: u64,
+    pub length: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ColumnEncodingKind {
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u64,
+}
+
+#[derive(Debug)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+    /// The time zone of the writer, in which the values of `Timestamp` columns are wall
+    /// clock times. Empty if the writer didn't record it.
+    pub writer_timezone: String,
+}
+
+impl StripeFooter {
+    pub fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut footer = StripeFooter {
+            streams: vec![],
+            columns: vec![],
+            writer_timezone: String::new(),
+        };
+        for_each_field(bytes, |field, value| {
+            match field {
+                1 => {
+                    let mut stream = Stream {
+                        kind: StreamKind::Present,
+                        column: 0,
+                        length: 0,
+                    };
+                    for_each_field(value.as_bytes(), |field, value| {
+                        match field {
+                            1 => {
+                                stream.kind = match value.as_u64() {
+                                    0 => StreamKind::Present,
+                                    1 => StreamKind::Data,
+                                    2 => StreamKind::Length,
+                                    3 => StreamKind::DictionaryData,
+                                    5 => StreamKind::Secondary,
+                                    _ => StreamKind::Other,
+                                }
+                            },
+                            2 => stream.column = value.as_u64(),
+                            3 => stream.length = value.as_u64(),
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    footer.streams.push(stream);
+                },
+                2 => {
+                    let mut encoding = ColumnEncoding {
+                        kind: ColumnEncodingKind::Direct,
+                        dictionary_size: 0,
+                    };
+                    for_each_field(value.as_bytes(), |field, value| {
+                        match field {
+                            1 => {
+                                encoding.kind = match value.as_u64() {
+                                    0 => ColumnEncodingKind::Direct,
+                                    1 => ColumnEncodingKind::Dictionary,
+                                    2 => ColumnEncodingKind::DirectV2,
+                                    3 => ColumnEncodingKind::DictionaryV2,
+                                    kind => polars_bail!(
+                                        ComputeError: "unknown ORC column encoding {}", kind
+                                    ),
+                                }
+                            },
+                            2 => encoding.dictionary_size = value.as_u64(),
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    footer.columns.push(encoding);
+                },
+                3 => footer.writer_timezone = value.as_string()?,
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(footer)
+    }
+}
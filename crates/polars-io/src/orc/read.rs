@@ -0,0 +1,905 @@
+use chrono::{DateTime, NaiveDate, Offset, TimeZone};
+use chrono_tz::Tz;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::POOL;
+use polars_error::to_compute_err;
+use rayon::prelude::*;
+
+use super::decode::{
+    decode_booleans, decode_bytes, decode_decimals, decode_integers, decompress, RleVersion,
+};
+use super::proto::{
+    decode_metadata, Bounds, ColumnEncoding, ColumnEncodingKind, ColumnStatistics, Footer, OrcType,
+    PostScript, StreamKind, StripeFooter, TypeKind,
+};
+use crate::mmap::MmapBytesReader;
+use crate::predicates::{apply_predicate, BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::utils::get_reader_bytes;
+use crate::SerReader;
+
+/// The seconds of ORC timestamps are relative to 2015-01-01 00:00:00.
+const TIMESTAMP_EPOCH: i64 = 1_420_070_400;
+/// The precision and the scale of decimal types that don't specify them.
+const DEFAULT_DECIMAL_PRECISION: u32 = 38;
+const DEFAULT_DECIMAL_SCALE: u32 = 10;
+
+/// The metadata in the tail of an ORC file.
+struct OrcMetadata {
+    postscript: PostScript,
+    footer: Footer,
+    /// The statistics of the columns of every stripe, empty if the file has none.
+    stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    /// The columns of the file, the columns of types that can't be read are
+    /// [`DataType::Unknown`].
+    schema: SchemaRef,
+    /// The id of the ORC column of every column of the schema.
+    column_ids: Vec<usize>,
+}
+
+fn slice(bytes: &[u8], offset: u64, len: u64) -> PolarsResult<&[u8]> {
+    let (offset, len) = (offset as usize, len as usize);
+    polars_ensure!(
+        offset.checked_add(len).map_or(false, |end| end <= bytes.len()),
+        ComputeError: "out-of-spec ORC file: a section is outside of the file"
+    );
+    Ok(&bytes[offset..offset + len])
+}
+
+fn decimal_precision_scale(orc_type: &OrcType) -> (usize, usize) {
+    let precision = orc_type.precision.unwrap_or(DEFAULT_DECIMAL_PRECISION);
+    let scale = orc_type.scale.unwrap_or(DEFAULT_DECIMAL_SCALE);
+    (precision as usize, scale as usize)
+}
+
+/// The type of a column, `None` if it can't be read.
+fn to_dtype(orc_type: &OrcType) -> Option<DataType> {
+    let dtype = match orc_type.kind {
+        TypeKind::Boolean => DataType::Boolean,
+        TypeKind::Byte => DataType::Int8,
+        TypeKind::Short => DataType::Int16,
+        TypeKind::Int => DataType::Int32,
+        TypeKind::Long => DataType::Int64,
+        TypeKind::Float => DataType::Float32,
+        TypeKind::Double => DataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => DataType::String,
+        TypeKind::Binary => DataType::Binary,
+        TypeKind::Date => DataType::Date,
+        TypeKind::Timestamp => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        // The values of these are instants, rather than wall clock times.
+        TypeKind::TimestampInstant => {
+            DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".to_string()))
+        },
+        TypeKind::Decimal => {
+            let (precision, scale) = decimal_precision_scale(orc_type);
+            DataType::Decimal(Some(precision), Some(scale))
+        },
+        TypeKind::List | TypeKind::Map | TypeKind::Struct | TypeKind::Union => return None,
+    };
+    Some(dtype)
+}
+
+/// The minimum and the maximum of a column as series of its type.
+fn bounds_to_series(bounds: &Bounds, field: &Field) -> Option<(Series, Series)> {
+    let name = field.name().as_str();
+    let dtype = field.data_type();
+    let (min, max) = match (bounds, dtype) {
+        (Bounds::Int(min, max), dtype) if dtype.is_integer() => {
+            (Series::new(name, &[*min]), Series::new(name, &[*max]))
+        },
+        (Bounds::Double(min, max), DataType::Float32 | DataType::Float64) => {
+            (Series::new(name, &[*min]), Series::new(name, &[*max]))
+        },
+        (Bounds::String(min, max), DataType::String) => (
+            Series::new(name, &[min.as_str()]),
+            Series::new(name, &[max.as_str()]),
+        ),
+        (Bounds::Date(min, max), DataType::Date) => {
+            (Series::new(name, &[*min]), Series::new(name, &[*max]))
+        },
+        _ => return None,
+    };
+    Some((min.cast(dtype).ok()?, max.cast(dtype).ok()?))
+}
+
+impl OrcMetadata {
+    fn read(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() > 3 && bytes.starts_with(b"ORC"),
+            ComputeError: "not an ORC file: the file doesn't start with 'ORC'"
+        );
+        let postscript_end = bytes.len() - 1;
+        let postscript_length = bytes[postscript_end] as u64;
+        let footer_end = (postscript_end as u64)
+            .checked_sub(postscript_length)
+            .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: invalid postscript"))?;
+        let postscript = PostScript::decode(slice(bytes, footer_end, postscript_length)?)?;
+
+        let footer_start = footer_end
+            .checked_sub(postscript.footer_length)
+            .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: invalid footer"))?;
+        let footer = decompress(
+            postscript.compression,
+            postscript.compression_block_size as usize,
+            slice(bytes, footer_start, postscript.footer_length)?,
+        )?;
+        let footer = Footer::decode(&footer)?;
+
+        let stripe_statistics = match footer_start.checked_sub(postscript.metadata_length) {
+            Some(metadata_start) if postscript.metadata_length > 0 => {
+                let metadata = decompress(
+                    postscript.compression,
+                    postscript.compression_block_size as usize,
+                    slice(bytes, metadata_start, postscript.metadata_length)?,
+                )?;
+                decode_metadata(&metadata)?
+            },
+            _ => vec![],
+        };
+
+        // The columns of the file are the fields of the struct that is the first type.
+        let root = footer
+            .types
+            .first()
+            .filter(|root| root.kind == TypeKind::Struct)
+            .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: invalid root type"))?;
+        let mut schema = Schema::with_capacity(root.subtypes.len());
+        let mut column_ids = Vec::with_capacity(root.subtypes.len());
+        for (name, &id) in root.field_names.iter().zip(&root.subtypes) {
+            let orc_type = footer
+                .types
+                .get(id as usize)
+                .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: invalid type"))?;
+            // Columns that can't be read only raise an error when they are projected.
+            let dtype = to_dtype(orc_type).unwrap_or(DataType::Unknown(Default::default()));
+            schema.with_column(name.as_str().into(), dtype);
+            column_ids.push(id as usize);
+        }
+
+        Ok(OrcMetadata {
+            postscript,
+            footer,
+            stripe_statistics,
+            schema: Arc::new(schema),
+            column_ids,
+        })
+    }
+
+    /// Raise an error if a column at the indexes in `projection` has a type that can't be
+    /// read.
+    fn check_projection(&self, projection: &[usize]) -> PolarsResult<()> {
+        for &i in projection {
+            let (name, dtype) = self.schema.get_at_index(i).ok_or_else(
+                || polars_err!(ComputeError: "projection index {} is out of bounds", i),
+            )?;
+            if matches!(dtype, DataType::Unknown(_)) {
+                let kind = self.footer.types[self.column_ids[i]].kind;
+                polars_bail!(
+                    ComputeError: "the ORC type {:?} of column '{}' is not supported", kind, name
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> PolarsResult<Vec<u8>> {
+        decompress(
+            self.postscript.compression,
+            self.postscript.compression_block_size as usize,
+            bytes,
+        )
+    }
+
+    /// The statistics of the columns of a stripe, if the file has them.
+    fn stripe_stats(&self, stripe: usize) -> Option<BatchStats> {
+        let columns = self.stripe_statistics.get(stripe)?;
+        let rows = self.footer.stripes[stripe].number_of_rows;
+        let stats = self
+            .schema
+            .iter_fields()
+            .zip(&self.column_ids)
+            .filter_map(|(field, &id)| {
+                let stats = columns.get(id)?;
+                let null_count = stats.number_of_values.map(|values| {
+                    IdxCa::from_slice("", &[rows.saturating_sub(values) as IdxSize]).into_series()
+                });
+                let (min, max) = stats
+                    .bounds
+                    .as_ref()
+                    .and_then(|bounds| bounds_to_series(bounds, &field))
+                    .unzip();
+                Some(ColumnStats::new(field, null_count, min, max))
+            })
+            .collect::<Vec<_>>();
+        // The statistics are looked up by the index of their column in the schema.
+        let schema = Schema::from_iter(
+            stats
+                .iter()
+                .map(|stats| Field::new(stats.field_name(), stats.dtype().clone())),
+        );
+        Some(BatchStats::new(
+            Arc::new(schema),
+            stats,
+            Some(rows as usize),
+        ))
+    }
+
+    /// Read the columns of the schema at the indexes in `projection` from a stripe.
+    fn read_stripe(
+        &self,
+        bytes: &[u8],
+        stripe: usize,
+        projection: &[usize],
+    ) -> PolarsResult<DataFrame> {
+        let info = &self.footer.stripes[stripe];
+        let footer_offset = info.offset + info.index_length + info.data_length;
+        let footer = self.decompress(slice(bytes, footer_offset, info.footer_length)?)?;
+        let footer = StripeFooter::decode(&footer)?;
+
+        // The streams are stored one after another from the start of the stripe.
+        let mut streams = PlHashMap::with_capacity(footer.streams.len());
+        let mut offset = info.offset;
+        for stream in &footer.streams {
+            if stream.kind != StreamKind::Other {
+                let data = slice(bytes, offset, stream.length)?;
+                streams.insert((stream.column as usize, stream.kind), data);
+            }
+            offset += stream.length;
+        }
+
+        let rows = info.number_of_rows as usize;
+        let columns = projection
+            .iter()
+            .map(|&i| {
+                let id = self.column_ids[i];
+                let (name, _) = self.schema.get_at_index(i).unwrap();
+                let encoding = footer.columns.get(id).ok_or_else(
+                    || polars_err!(ComputeError: "out-of-spec ORC file: missing column encoding"),
+                )?;
+                let column = ColumnReader {
+                    metadata: self,
+                    streams: &streams,
+                    id,
+                    encoding,
+                    writer_timezone: &footer.writer_timezone,
+                };
+                column.read(name, &self.footer.types[id], rows)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+/// Decodes the streams of a column in a stripe.
+struct ColumnReader<'a> {
+    metadata: &'a OrcMetadata,
+    streams: &'a PlHashMap<(usize, StreamKind), &'a [u8]>,
+    id: usize,
+    encoding: &'a ColumnEncoding,
+    writer_timezone: &'a str,
+}
+
+impl ColumnReader<'_> {
+    /// The decompressed stream, which is empty if the stripe doesn't have it.
+    fn stream(&self, kind: StreamKind) -> PolarsResult<Vec<u8>> {
+        match self.streams.get(&(self.id, kind)) {
+            Some(bytes) => self.metadata.decompress(bytes),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn integers(&self, kind: StreamKind, n: usize, signed: bool) -> PolarsResult<Vec<i64>> {
+        let version = match self.encoding.kind {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::Dictionary => RleVersion::V1,
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2 => RleVersion::V2,
+        };
+        decode_integers(&self.stream(kind)?, n, signed, version)
+    }
+
+    /// The `n` values of a string or binary column.
+    fn binary(
+        &self,
+        n: usize,
+        f: impl FnOnce(&[&[u8]]) -> PolarsResult<Series>,
+    ) -> PolarsResult<Series> {
+        let data = self.stream(StreamKind::Data)?;
+        match self.encoding.kind {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::DirectV2 => {
+                let lengths = self.integers(StreamKind::Length, n, false)?;
+                f(&split_values(&data, &lengths)?)
+            },
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2 => {
+                let size = self.encoding.dictionary_size as usize;
+                let lengths = self.integers(StreamKind::Length, size, false)?;
+                let dictionary = self.stream(StreamKind::DictionaryData)?;
+                let dictionary = split_values(&dictionary, &lengths)?;
+                let indices = self.integers(StreamKind::Data, n, false)?;
+                let values = indices
+                    .into_iter()
+                    .map(|i| {
+                        dictionary.get(i as usize).copied().ok_or_else(|| {
+                            polars_err!(ComputeError: "out-of-spec ORC file: invalid dictionary index")
+                        })
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                f(&values)
+            },
+        }
+    }
+
+    fn read(&self, name: &str, orc_type: &OrcType, rows: usize) -> PolarsResult<Series> {
+        let present = if self.streams.contains_key(&(self.id, StreamKind::Present)) {
+            Some(decode_booleans(&self.stream(StreamKind::Present)?, rows)?)
+        } else {
+            None
+        };
+        // The streams only have the values that aren't null.
+        let n = present
+            .as_ref()
+            .map_or(rows, |present| present.iter().filter(|v| **v).count());
+
+        let values = match orc_type.kind {
+            TypeKind::Boolean => {
+                let values = decode_booleans(&self.stream(StreamKind::Data)?, n)?;
+                BooleanChunked::from_slice(name, &values).into_series()
+            },
+            TypeKind::Byte => {
+                let values = decode_bytes(&self.stream(StreamKind::Data)?, n)?;
+                Int8Chunked::from_vec(name, values.into_iter().map(|v| v as i8).collect())
+                    .into_series()
+            },
+            TypeKind::Short => {
+                let values = self.integers(StreamKind::Data, n, true)?;
+                Int16Chunked::from_vec(name, values.into_iter().map(|v| v as i16).collect())
+                    .into_series()
+            },
+            TypeKind::Int => {
+                let values = self.integers(StreamKind::Data, n, true)?;
+                Int32Chunked::from_vec(name, values.into_iter().map(|v| v as i32).collect())
+                    .into_series()
+            },
+            TypeKind::Long => {
+                Int64Chunked::from_vec(name, self.integers(StreamKind::Data, n, true)?)
+                    .into_series()
+            },
+            TypeKind::Float => {
+                let data = self.stream(StreamKind::Data)?;
+                polars_ensure!(
+                    data.len() >= n * 4,
+                    ComputeError: "unexpected end of an ORC stream"
+                );
+                let values = data
+                    .chunks_exact(4)
+                    .take(n)
+                    .map(|v| f32::from_le_bytes(v.try_into().unwrap()))
+                    .collect();
+                Float32Chunked::from_vec(name, values).into_series()
+            },
+            TypeKind::Double => {
+                let data = self.stream(StreamKind::Data)?;
+                polars_ensure!(
+                    data.len() >= n * 8,
+                    ComputeError: "unexpected end of an ORC stream"
+                );
+                let values = data
+                    .chunks_exact(8)
+                    .take(n)
+                    .map(|v| f64::from_le_bytes(v.try_into().unwrap()))
+                    .collect();
+                Float64Chunked::from_vec(name, values).into_series()
+            },
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char => self.binary(n, |values| {
+                let values = values
+                    .iter()
+                    .map(|v| std::str::from_utf8(v).map_err(to_compute_err))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(StringChunked::from_slice(name, &values).into_series())
+            })?,
+            TypeKind::Binary => self.binary(n, |values| {
+                Ok(BinaryChunked::from_slice(name, values).into_series())
+            })?,
+            TypeKind::Date => {
+                let values = self.integers(StreamKind::Data, n, true)?;
+                Int32Chunked::from_vec(name, values.into_iter().map(|v| v as i32).collect())
+                    .into_date()
+                    .into_series()
+            },
+            TypeKind::Decimal => {
+                let (precision, scale) = decimal_precision_scale(orc_type);
+                let values = decode_decimals(&self.stream(StreamKind::Data)?, n)?;
+                // Every value has its own scale, they are rescaled to the scale of the column.
+                let scales = self.integers(StreamKind::Secondary, n, true)?;
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(value, value_scale)| rescale(value, value_scale, scale as i64))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Int128Chunked::from_vec(name, values)
+                    .into_decimal_unchecked(Some(precision), scale)
+                    .into_series()
+            },
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                // The seconds of `Timestamp` columns are relative to the start of 2015 in the
+                // time zone of the writer, and are read as the wall clock time there. Those of
+                // `TimestampInstant` columns are relative to the start of 2015 in UTC.
+                let (writer_timezone, time_zone) = match orc_type.kind {
+                    TypeKind::Timestamp => (parse_writer_timezone(self.writer_timezone)?, None),
+                    _ => (None, Some("UTC".to_string())),
+                };
+                let seconds = self.integers(StreamKind::Data, n, true)?;
+                let nanos = self.integers(StreamKind::Secondary, n, false)?;
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        // The trailing zeros of the nanoseconds are stripped, the lowest three
+                        // bits are their number minus one.
+                        let zeros = nanos & 7;
+                        let mut nanos = nanos >> 3;
+                        if zeros != 0 {
+                            nanos *= 10i64.pow(zeros as u32 + 1);
+                        }
+                        let mut seconds = match &writer_timezone {
+                            Some(timezone) => to_wall_clock(seconds, timezone),
+                            None => seconds + TIMESTAMP_EPOCH,
+                        };
+                        // Writers truncate the seconds of timestamps before 1970 towards zero.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds * 1_000_000_000 + nanos
+                    })
+                    .collect();
+                Int64Chunked::from_vec(name, values)
+                    .into_datetime(TimeUnit::Nanoseconds, time_zone)
+                    .into_series()
+            },
+            kind => polars_bail!(
+                ComputeError: "the ORC type {:?} of column '{}' is not supported", kind, name
+            ),
+        };
+
+        match present {
+            None => Ok(values),
+            Some(present) => {
+                // Spread the values over the rows, the rows without a value are null.
+                let mut next = 0 as IdxSize;
+                let indices = present
+                    .into_iter()
+                    .map(|present| {
+                        present.then(|| {
+                            next += 1;
+                            next - 1
+                        })
+                    })
+                    .collect::<IdxCa>();
+                values.take(&indices)
+            },
+        }
+    }
+}
+
+/// The time zone in which the writer of a stripe wrote its timestamps, `None` for UTC.
+fn parse_writer_timezone(timezone: &str) -> PolarsResult<Option<Tz>> {
+    match timezone {
+        "" | "UTC" | "GMT" | "Etc/UTC" | "Etc/GMT" => Ok(None),
+        timezone => timezone.parse::<Tz>().map(Some).map_err(|_| {
+            polars_err!(
+                ComputeError: "unknown time zone '{}' of the writer of an ORC file", timezone
+            )
+        }),
+    }
+}
+
+/// The wall clock time in `timezone`, as seconds since the unix epoch, of the instant that
+/// is `seconds` after the start of 2015 in `timezone`.
+fn to_wall_clock(seconds: i64, timezone: &Tz) -> i64 {
+    let start = NaiveDate::from_ymd_opt(2015, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let start = timezone
+        .from_local_datetime(&start)
+        .earliest()
+        .map_or(TIMESTAMP_EPOCH, |start| start.timestamp());
+    let instant = seconds + start;
+    let offset = DateTime::from_timestamp(instant, 0).map_or(0, |utc| {
+        timezone
+            .offset_from_utc_datetime(&utc.naive_utc())
+            .fix()
+            .local_minus_utc()
+    });
+    instant + offset as i64
+}
+
+/// Rescale an unscaled decimal from `from` to `to` decimal places.
+fn rescale(value: i128, from: i64, to: i64) -> PolarsResult<i128> {
+    let factor = 10i128.checked_pow(from.abs_diff(to) as u32);
+    let value = match factor {
+        Some(factor) if to >= from => value.checked_mul(factor),
+        Some(factor) => Some(value / factor),
+        None if to >= from => None,
+        None => Some(0),
+    };
+    value.ok_or_else(|| polars_err!(ComputeError: "an ORC decimal doesn't fit in 128 bits"))
+}
+
+/// Split the concatenated values of a string or binary column by their lengths.
+fn split_values<'a>(mut data: &'a [u8], lengths: &[i64]) -> PolarsResult<Vec<&'a [u8]>> {
+    lengths
+        .iter()
+        .map(|&len| {
+            polars_ensure!(
+                len >= 0 && len as usize <= data.len(),
+                ComputeError: "unexpected end of an ORC stream"
+            );
+            let (value, rest) = data.split_at(len as usize);
+            data = rest;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Read [Apache ORC] format into a [`DataFrame`].
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///             .with_columns(Some(vec!["id".to_string()]))
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R: MmapBytesReader> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    parallel: bool,
+}
+
+impl<R: MmapBytesReader> OrcReader<R> {
+    /// Get the schema of the ORC file.
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        Ok(OrcMetadata::read(&bytes)?.schema)
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only read the rows for which the predicate holds. The stripes whose statistics show
+    /// that it holds for none of their rows are skipped. The predicate must only refer to
+    /// columns that are read.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// Read the stripes of the file in parallel. Defaults to `true`.
+    pub fn set_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for OrcReader<R> {
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            predicate: None,
+            parallel: true,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let bytes: &[u8] = &reader_bytes;
+        let metadata = OrcMetadata::read(bytes)?;
+
+        let projection = match (self.columns, self.projection) {
+            (Some(columns), _) => columns
+                .iter()
+                .map(|name| Ok(metadata.schema.try_get_full(name)?.0))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            (None, Some(projection)) => projection,
+            (None, None) => (0..metadata.schema.len()).collect(),
+        };
+        metadata.check_projection(&projection)?;
+        let predicate = self.predicate.as_deref();
+
+        // Skip the stripes that can't have rows for which the predicate holds, and the
+        // stripes after the first `n_rows` rows if all rows are read.
+        let mut stripes = vec![];
+        let mut rows = 0;
+        for (i, stripe) in metadata.footer.stripes.iter().enumerate() {
+            if predicate.is_none() && self.n_rows.map_or(false, |n_rows| rows >= n_rows) {
+                break;
+            }
+            if let Some(evaluator) = predicate.and_then(|p| p.as_stats_evaluator()) {
+                if let Some(stats) = metadata.stripe_stats(i) {
+                    if !evaluator.should_read(&stats)? {
+                        continue;
+                    }
+                }
+            }
+            stripes.push(i);
+            rows += stripe.number_of_rows as usize;
+        }
+
+        let read_stripe = |&i: &usize| -> PolarsResult<DataFrame> {
+            let mut df = metadata.read_stripe(bytes, i, &projection)?;
+            apply_predicate(&mut df, predicate, false)?;
+            Ok(df)
+        };
+        let dfs = if self.parallel {
+            POOL.install(|| {
+                stripes
+                    .par_iter()
+                    .map(read_stripe)
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?
+        } else {
+            stripes
+                .iter()
+                .map(read_stripe)
+                .collect::<PolarsResult<Vec<_>>>()?
+        };
+
+        let mut df = if dfs.is_empty() {
+            let schema = projection
+                .iter()
+                .map(|&i| {
+                    let (name, dtype) = metadata.schema.get_at_index(i).unwrap();
+                    Field::new(name, dtype.clone())
+                })
+                .collect::<Schema>();
+            DataFrame::from(&schema)
+        } else {
+            accumulate_dataframes_vertical(dfs)?
+        };
+        if let Some(n_rows) = self.n_rows {
+            df = df.slice(0, n_rows);
+        }
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use polars_core::df;
+
+    use super::*;
+
+    /// A protobuf message, built field by field.
+    #[derive(Default)]
+    struct Message(Vec<u8>);
+
+    impl Message {
+        fn varint(&mut self, mut value: u64) {
+            while value >= 0x80 {
+                self.0.push(value as u8 | 0x80);
+                value >>= 7;
+            }
+            self.0.push(value as u8);
+        }
+
+        fn int(mut self, field: u64, value: u64) -> Self {
+            self.varint(field << 3);
+            self.varint(value);
+            self
+        }
+
+        fn bytes(mut self, field: u64, value: &[u8]) -> Self {
+            self.varint((field << 3) | 2);
+            self.varint(value.len() as u64);
+            self.0.extend_from_slice(value);
+            self
+        }
+
+        fn message(self, field: u64, value: Message) -> Self {
+            self.bytes(field, &value.0)
+        }
+    }
+
+    fn zigzag(value: i64) -> u64 {
+        ((value << 1) ^ (value >> 63)) as u64
+    }
+
+    /// An uncompressed file with the columns `id` and `name`, in two stripes of three rows.
+    fn orc_file() -> Vec<u8> {
+        type Stripe<'a> = (
+            &'a [u8],
+            Option<&'a [u8]>,
+            &'a [u8],
+            &'a [u8],
+            [i64; 2],
+            [&'a str; 2],
+        );
+        let stripes: [Stripe; 2] = [
+            // The ids 1, 2 and 3 as a run, the names "a", null and "ccc" with literal lengths.
+            (
+                &[0x00, 0x01, 0x02],
+                Some(&[0xff, 0xa0][..]),
+                b"accc",
+                &[0xfe, 0x01, 0x03],
+                [1, 3],
+                ["a", "ccc"],
+            ),
+            // The ids 4, 5 and 6, the names "d", "e" and "f" without nulls.
+            (
+                &[0x00, 0x01, 0x08],
+                None,
+                b"def",
+                &[0x00, 0x00, 0x01],
+                [4, 6],
+                ["d", "f"],
+            ),
+        ];
+
+        let mut file = b"ORC".to_vec();
+        let root = Message::default()
+            .int(1, 12)
+            .int(2, 1)
+            .int(2, 2)
+            .bytes(3, b"id")
+            .bytes(3, b"name");
+        let mut footer = Message::default()
+            .message(4, root)
+            .message(4, Message::default().int(1, 4))
+            .message(4, Message::default().int(1, 7));
+        let mut metadata = Message::default();
+        for (ids, present, names, lengths, [min, max], [min_name, max_name]) in stripes {
+            let offset = file.len() as u64;
+            let mut streams = vec![(1, 1, ids)];
+            if let Some(present) = present {
+                streams.push((2, 0, present));
+            }
+            streams.extend([(2, 1, names), (2, 2, lengths)]);
+
+            let mut stripe_footer = Message::default();
+            for (column, kind, data) in streams {
+                file.extend_from_slice(data);
+                let stream = Message::default()
+                    .int(1, kind)
+                    .int(2, column)
+                    .int(3, data.len() as u64);
+                stripe_footer = stripe_footer.message(1, stream);
+            }
+            for _ in 0..3 {
+                stripe_footer = stripe_footer.message(2, Message::default().int(1, 0));
+            }
+            let data_length = file.len() as u64 - offset;
+            file.extend_from_slice(&stripe_footer.0);
+            let stripe = Message::default()
+                .int(1, offset)
+                .int(2, 0)
+                .int(3, data_length)
+                .int(4, stripe_footer.0.len() as u64)
+                .int(5, 3);
+            footer = footer.message(3, stripe);
+
+            let int_stats = Message::default().int(1, zigzag(min)).int(2, zigzag(max));
+            let string_stats = Message::default()
+                .bytes(1, min_name.as_bytes())
+                .bytes(2, max_name.as_bytes());
+            let stats = Message::default()
+                .message(1, Message::default().int(1, 3))
+                .message(1, Message::default().int(1, 3).message(2, int_stats))
+                .message(
+                    1,
+                    Message::default()
+                        .int(1, 3 - present.is_some() as u64)
+                        .message(4, string_stats),
+                );
+            metadata = metadata.message(1, stats);
+        }
+
+        let postscript = Message::default()
+            .int(1, footer.0.len() as u64)
+            .int(2, 0)
+            .int(5, metadata.0.len() as u64)
+            .bytes(8000, b"ORC");
+        file.extend_from_slice(&metadata.0);
+        file.extend_from_slice(&footer.0);
+        file.extend_from_slice(&postscript.0);
+        file.push(postscript.0.len() as u8);
+        file
+    }
+
+    #[test]
+    fn test_read_orc() -> PolarsResult<()> {
+        let file = orc_file();
+        let expected = df!(
+            "id" => [1i64, 2, 3, 4, 5, 6],
+            "name" => [Some("a"), None, Some("ccc"), Some("d"), Some("e"), Some("f")],
+        )?;
+
+        let mut reader = OrcReader::new(Cursor::new(&file));
+        assert_eq!(reader.schema()?.as_ref(), &expected.schema());
+        let df = reader.finish()?;
+        assert!(df.equals_missing(&expected));
+
+        let df = OrcReader::new(Cursor::new(&file))
+            .with_columns(Some(vec!["name".to_string()]))
+            .with_n_rows(Some(4))
+            .set_parallel(false)
+            .finish()?;
+        assert!(df.equals_missing(&expected.select(["name"])?.head(Some(4))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_orc_timestamps_and_decimals() {
+        // 2024-07-01 12:00 in New York, relative to the start of 2015 there, is read as the
+        // wall clock time.
+        let seconds = 1_719_849_600 - 1_420_088_400;
+        let timezone = parse_writer_timezone("America/New_York").unwrap().unwrap();
+        assert_eq!(to_wall_clock(seconds, &timezone), 1_719_835_200);
+        assert!(parse_writer_timezone("UTC").unwrap().is_none());
+        assert!(parse_writer_timezone("Mars/Olympus_Mons").is_err());
+
+        assert_eq!(rescale(123, 1, 3).unwrap(), 12300);
+        assert_eq!(rescale(-12345, 4, 2).unwrap(), -123);
+        assert!(rescale(i128::MAX, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_orc_stripe_stats() -> PolarsResult<()> {
+        let metadata = OrcMetadata::read(&orc_file())?;
+        let stats = metadata.stripe_stats(0).unwrap();
+
+        let id = stats.get_stats("id")?;
+        assert_eq!(id.null_count(), Some(0));
+        assert_eq!(id.to_min().unwrap().get(0)?, AnyValue::Int64(1));
+        let name = stats.get_stats("name")?;
+        assert_eq!(name.null_count(), Some(1));
+        assert_eq!(
+            name.get_max_state().unwrap().get(0)?,
+            AnyValue::String("ccc")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use polars_core::prelude::*;
+use polars_parquet::arrow::bloom_filter::{
+    hash_byte, hash_native, insert, is_in_set, optimal_num_bytes,
+};
+
+/// The false positive probability of the bloom filters that are written.
+const FPP: f64 = 0.01;
+
+/// The hashes of the non-null values of `s`, as they are stored in parquet.
+///
+/// Returns `None` if bloom filters aren't supported for the dtype of `s`. This includes the
+/// floats, as the hashes of `0.0` and `-0.0` differ while the values compare equal.
+fn hash_values(s: &Series) -> Option<Vec<u64>> {
+    use DataType::*;
+    let s = match s.dtype() {
+        // Categoricals are written as strings.
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => s.cast(&String).ok()?,
+        _ => s.to_physical_repr().into_owned(),
+    };
+    let hashes = match s.dtype() {
+        Int8 | Int16 | Int32 | UInt8 | UInt16 => {
+            let s = s.cast(&Int32).ok()?;
+            s.i32()
+                .unwrap()
+                .into_iter()
+                .flatten()
+                .map(hash_native)
+                .collect()
+        },
+        // Unsigned integers are stored as the signed integers of the same width.
+        UInt32 => s
+            .u32()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(|v| hash_native(v as i32))
+            .collect(),
+        Int64 => s
+            .i64()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_native)
+            .collect(),
+        UInt64 => s
+            .u64()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(|v| hash_native(v as i64))
+            .collect(),
+        String => s
+            .str()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        Binary => s
+            .binary()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        _ => return None,
+    };
+    Some(hashes)
+}
+
+/// The bitset of a split-block bloom filter of the values of `s`, or `None` if bloom filters
+/// aren't supported for its dtype.
+pub(super) fn build_bloom_filter(s: &Series) -> Option<Vec<u8>> {
+    let mut hashes = hash_values(s)?;
+    hashes.sort_unstable();
+    hashes.dedup();
+    let mut bitset = vec![0; optimal_num_bytes(hashes.len(), FPP)];
+    for hash in hashes {
+        insert(&mut bitset, hash);
+    }
+    Some(bitset)
+}
+
+/// The split-block bloom filter of a column chunk.
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    dtype: DataType,
+    bitset: Arc<[u8]>,
+}
+
+impl BloomFilter {
+    pub(crate) fn new(dtype: DataType, bitset: Vec<u8>) -> Self {
+        Self {
+            dtype,
+            bitset: bitset.into(),
+        }
+    }
+
+    /// Whether the column chunk may contain any of `values`.
+    ///
+    /// This may return `true` for values that aren't in the column chunk, but never returns
+    /// `false` for values that are.
+    pub fn may_contain(&self, values: &Series) -> bool {
+        if values.null_count() > 0 {
+            return true;
+        }
+        let Ok(values) = values.strict_cast(&self.dtype) else {
+            return true;
+        };
+        match hash_values(&values) {
+            Some(hashes) => hashes.into_iter().any(|hash| is_in_set(&self.bitset, hash)),
+            None => true,
+        }
+    }
+}
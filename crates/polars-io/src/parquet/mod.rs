@@ -1,5 +1,10 @@
 //! Functionality for reading and writing Apache Parquet files.
 
+mod bloom_filter;
 pub mod metadata;
 pub mod read;
+mod statistics_file;
 pub mod write;
+
+pub use bloom_filter::BloomFilter;
+pub use statistics_file::{DatasetStatistics, FileFingerprint, STATISTICS_FILE_NAME};
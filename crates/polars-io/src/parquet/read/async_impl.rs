@@ -7,7 +7,7 @@ use object_store::path::Path as ObjectPath;
 use polars_core::config::{get_rg_prefetch_size, verbose};
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{FileDecryptionProperties, RowGroupMetaData};
 use polars_parquet::write::FileMetaData;
 use smartstring::alias::String as SmartString;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
@@ -30,6 +30,7 @@ pub struct ParquetObjectStore {
     path: ObjectPath,
     length: Option<usize>,
     metadata: Option<FileMetaDataRef>,
+    decryption: Option<Arc<FileDecryptionProperties>>,
 }
 
 impl ParquetObjectStore {
@@ -55,9 +56,15 @@ impl ParquetObjectStore {
             path,
             length: None,
             metadata,
+            decryption: None,
         })
     }
 
+    /// Read encrypted files with these keys.
+    pub fn set_decryption(&mut self, decryption: Option<Arc<FileDecryptionProperties>>) {
+        self.decryption = decryption;
+    }
+
     async fn get_range(&self, start: usize, length: usize) -> PolarsResult<Bytes> {
         self.store
             .get_range(&self.path, start..start + length)
@@ -85,7 +92,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetaData> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.decryption.as_deref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -116,6 +123,7 @@ pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<FileMetaData> {
     let footer_header_bytes = store
         .get_range(
@@ -130,22 +138,25 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    let footer_byte_length: usize = {
+    let (footer_byte_length, magic): (usize, _) = {
         let reader = &mut footer_header_bytes.as_ref();
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        if magic != polars_parquet::parquet::PARQUET_MAGIC
+            && magic != polars_parquet::parquet::PARQUET_MAGIC_ENCRYPTED_FOOTER
+        {
             return Err(polars_parquet::parquet::error::Error::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
             .into());
         }
-        footer_byte_size.try_into().map_err(|_| {
+        let footer_byte_length = footer_byte_size.try_into().map_err(|_| {
             polars_parquet::parquet::error::Error::OutOfSpec(
                 "negative footer byte length".to_string(),
             )
-        })?
+        })?;
+        (footer_byte_length, magic)
     };
 
     let footer_bytes = store
@@ -161,15 +172,71 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
+    let footer_bytes = footer_bytes.as_ref();
+    Ok(polars_parquet::parquet::read::deserialize_footer(
+        &footer_bytes[..footer_bytes.len() - polars_parquet::parquet::FOOTER_SIZE as usize],
+        magic,
+        decryption,
     )?)
 }
 
+/// The names of the top-level `columns`, as the fields of the column chunks are matched.
+pub(super) fn to_fields(columns: &[String]) -> Arc<[SmartString]> {
+    columns
+        .iter()
+        .map(|name| SmartString::from(name.as_str()))
+        .collect()
+}
+
+/// The offsets and the byte ranges of the column chunks of `fields` in `row_group`, or of all
+/// its column chunks if `fields` is `None`.
+fn column_chunk_ranges(
+    row_group: &RowGroupMetaData,
+    fields: Option<&[SmartString]>,
+) -> Vec<(u64, Range<usize>)> {
+    row_group
+        .columns()
+        .iter()
+        .filter(|meta| {
+            // A single column can have multiple matches (structs).
+            fields.map_or(true, |fields| {
+                fields
+                    .iter()
+                    .any(|name| meta.descriptor().path_in_schema[0] == name.as_str())
+            })
+        })
+        .map(|meta| {
+            let (offset, len) = meta.byte_range();
+            (offset, offset as usize..(offset + len) as usize)
+        })
+        .collect()
+}
+
+/// The compressed size of the column chunks of `fields` in `row_group`, or of all its column
+/// chunks if `fields` is `None`.
+pub(super) fn column_chunks_size(
+    row_group: &RowGroupMetaData,
+    fields: Option<&[SmartString]>,
+) -> usize {
+    column_chunk_ranges(row_group, fields)
+        .iter()
+        .map(|(_, range)| range.len())
+        .sum()
+}
+
+/// Download the column chunks of `fields` in `row_group`, or all its column chunks if
+/// `fields` is `None`, concurrently.
+pub(super) async fn fetch_column_chunks(
+    async_reader: &ParquetObjectStore,
+    row_group: &RowGroupMetaData,
+    fields: Option<&[SmartString]>,
+) -> PolarsResult<DownloadedRowGroup> {
+    let (offsets, ranges): (Vec<_>, Vec<_>) =
+        column_chunk_ranges(row_group, fields).into_iter().unzip();
+    let bytes = async_reader.get_ranges(&ranges).await?;
+    Ok(offsets.into_iter().zip(bytes).collect())
+}
+
 /// Download rowgroups for the column whose indexes are given in `projection`.
 /// We concurrently download the columns for each field.
 async fn download_projection(
@@ -179,41 +246,9 @@ async fn download_projection(
     sender: QueueSend,
     rg_index: usize,
 ) -> bool {
-    let async_reader = &async_reader;
-    let row_group = &row_group;
-    let fields = fields.as_ref();
-
-    let mut ranges = Vec::with_capacity(fields.len());
-    let mut offsets = Vec::with_capacity(fields.len());
-    fields.iter().for_each(|name| {
-        let columns = row_group.columns();
-
-        // A single column can have multiple matches (structs).
-        let iter = columns.iter().filter_map(|meta| {
-            if meta.descriptor().path_in_schema[0] == name.as_str() {
-                let (offset, len) = meta.byte_range();
-                Some((offset, offset as usize..(offset + len) as usize))
-            } else {
-                None
-            }
-        });
-
-        for (offset, range) in iter {
-            offsets.push(offset);
-            ranges.push(range);
-        }
-    });
-
-    let result = async_reader.get_ranges(&ranges).await.map(|bytes| {
-        (
-            rg_index,
-            bytes
-                .into_iter()
-                .zip(offsets)
-                .map(|(bytes, offset)| (offset, bytes))
-                .collect::<Vec<_>>(),
-        )
-    });
+    let result = fetch_column_chunks(&async_reader, &row_group, Some(fields.as_ref()))
+        .await
+        .map(|columns| (rg_index, columns));
     sender.send(result).await.is_ok()
 }
 
@@ -259,6 +294,26 @@ async fn download_row_group(
     sender.send(result).await.is_ok()
 }
 
+/// A row group of a file that is downloaded before the file is read, see
+/// `ParquetAsyncReader::prefetch_row_group`.
+pub(super) struct PrefetchedRowGroup {
+    pub(super) index: usize,
+    /// The fields that are downloaded, or `None` if all of them are.
+    pub(super) fields: Option<Arc<[SmartString]>>,
+    pub(super) columns: DownloadedRowGroup,
+}
+
+impl PrefetchedRowGroup {
+    /// Whether the downloaded fields contain all the fields that are read.
+    fn covers(&self, projected_fields: Option<&[SmartString]>) -> bool {
+        match (&self.fields, projected_fields) {
+            (None, _) => true,
+            (Some(fields), Some(projected)) => projected.iter().all(|f| fields.contains(f)),
+            (Some(_), None) => false,
+        }
+    }
+}
+
 pub struct FetchRowGroupsFromObjectStore {
     rg_q: Arc<Mutex<Receiver<PolarsResult<QueuePayload>>>>,
     prefetched_rg: PlHashMap<usize, DownloadedRowGroup>,
@@ -272,6 +327,7 @@ impl FetchRowGroupsFromObjectStore {
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         row_groups: &[RowGroupMetaData],
         limit: usize,
+        prefetched_row_groups: Vec<PrefetchedRowGroup>,
     ) -> PolarsResult<Self> {
         let projected_fields: Option<Arc<[SmartString]>> = projection.map(|projection| {
             projection
@@ -291,7 +347,7 @@ impl FetchRowGroupsFromObjectStore {
                 .enumerate()
                 .filter(|(i, rg)| {
                     let should_be_read =
-                        matches!(read_this_row_group(Some(pred), rg, &schema), Ok(true));
+                        matches!(read_this_row_group(Some(pred), rg, &schema, None), Ok(true));
 
                     // Already add the row groups that will be skipped to the prefetched data.
                     if !should_be_read {
@@ -304,6 +360,16 @@ impl FetchRowGroupsFromObjectStore {
         } else {
             row_groups.iter().cloned().enumerate().collect()
         };
+        // The row groups that were downloaded ahead of reading don't have to be downloaded
+        // again, if they contain all fields that are read.
+        for rg in prefetched_row_groups {
+            if rg.covers(projected_fields.as_deref())
+                && row_groups.iter().any(|(i, _)| *i == rg.index)
+            {
+                prefetched.insert(rg.index, rg.columns);
+            }
+        }
+        row_groups.retain(|(i, _)| !prefetched.contains_key(i));
         let reader = Arc::new(reader);
         let msg_limit = get_rg_prefetch_size();
 
@@ -365,7 +431,7 @@ impl FetchRowGroupsFromObjectStore {
 
         Ok(FetchRowGroupsFromObjectStore {
             rg_q: Arc::new(Mutex::new(rcv)),
-            prefetched_rg: Default::default(),
+            prefetched_rg: prefetched,
         })
     }
 
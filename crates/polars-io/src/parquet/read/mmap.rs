@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow::datatypes::Field;
 #[cfg(feature = "async")]
 use bytes::Bytes;
@@ -5,8 +7,8 @@ use bytes::Bytes;
 use polars_core::datatypes::PlHashMap;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
-    column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
-    PageReader,
+    column_iter_to_arrays, get_field_leaf_columns, ArrayIter, BasicDecompressor,
+    ColumnChunkMetaData, PageFilter, PageReader,
 };
 
 /// Store columns data in two scenarios:
@@ -26,14 +28,14 @@ pub enum ColumnStore<'a> {
     Fetched(PlHashMap<u64, Bytes>),
 }
 
-/// For local files memory maps all columns that are part of the parquet field `field_name`.
+/// For local files memory maps all columns that are part of the parquet field `field`.
 /// For cloud files the relevant memory regions should have been prefetched.
 pub(super) fn mmap_columns<'a>(
     store: &'a ColumnStore,
     columns: &'a [ColumnChunkMetaData],
-    field_name: &str,
+    field: &Field,
 ) -> Vec<(&'a ColumnChunkMetaData, &'a [u8])> {
-    get_field_columns(columns, field_name)
+    get_field_leaf_columns(columns, field)
         .into_iter()
         .map(|meta| _mmap_single_column(store, meta))
         .collect()
@@ -66,8 +68,28 @@ pub(super) fn to_deserializer<'a>(
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+) -> PolarsResult<ArrayIter<'a>> {
+    to_filtered_deserializer(columns, field, num_rows, chunk_size, Arc::new(|_, _| true))
+}
+
+/// Like [`to_deserializer`], but skips the data pages that the `pages_filter` rejects.
+/// `num_rows` are the rows of the pages that aren't skipped.
+pub(super) fn to_filtered_deserializer<'a>(
+    columns: Vec<(&ColumnChunkMetaData, &'a [u8])>,
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+    pages_filter: PageFilter,
 ) -> PolarsResult<ArrayIter<'a>> {
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
+    #[cfg(test)]
+    let pages_filter: PageFilter = Arc::new(move |descriptor, header| {
+        let decode = pages_filter(descriptor, header);
+        if decode {
+            test::DECODED_PAGES.with(|n| n.set(n.get() + 1));
+        }
+        decode
+    });
 
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
@@ -75,7 +97,7 @@ pub(super) fn to_deserializer<'a>(
             let pages = PageReader::new(
                 std::io::Cursor::new(chunk),
                 column_meta,
-                std::sync::Arc::new(|_, _| true),
+                pages_filter.clone(),
                 vec![],
                 usize::MAX,
             );
@@ -88,3 +110,13 @@ pub(super) fn to_deserializer<'a>(
 
     column_iter_to_arrays(columns, types, field, Some(chunk_size), num_rows)
 }
+
+#[cfg(test)]
+pub(super) mod test {
+    use std::cell::Cell;
+
+    thread_local! {
+        /// The number of data pages that were decoded on this thread.
+        pub(crate) static DECODED_PAGES: Cell<usize> = const { Cell::new(0) };
+    }
+}
@@ -23,7 +23,8 @@ mod read_impl;
 mod reader;
 mod utils;
 
-pub use options::{ParallelStrategy, ParquetOptions};
+pub use options::{NestedProjection, ParallelStrategy, ParquetDecryption, ParquetOptions};
+pub use polars_parquet::read::{FileDecryptionProperties, KeyRetriever};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
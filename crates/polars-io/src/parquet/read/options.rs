@@ -1,3 +1,9 @@
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+use polars_parquet::read::FileDecryptionProperties;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -6,7 +12,150 @@ use serde::{Deserialize, Serialize};
 pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
+    /// The maximum number of row groups that are decoded at the same time, which bounds the
+    /// memory used by decoding. Low memory mode decodes a single row group at a time.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_decoders: Option<usize>,
     pub use_statistics: bool,
+    /// Stop reading once this many rows passed the predicate. This is set by the optimizer
+    /// when a slice is pushed past the predicate of the scan.
+    pub n_rows_after_predicate: Option<usize>,
+    /// Skip this many leading rows of a single file scan. This is set by the optimizer when a
+    /// slice with a negative offset is resolved against the row count of the file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skip_rows: usize,
+}
+
+/// The keys to read encrypted files with.
+///
+/// The keys and the key retriever can't be compared, so the options of scans are only equal if
+/// they share the same [`FileDecryptionProperties`]. The keys aren't serialized, so serializing
+/// a plan that reads encrypted files fails.
+#[derive(Clone)]
+pub struct ParquetDecryption(pub Arc<FileDecryptionProperties>);
+
+impl std::fmt::Debug for ParquetDecryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq for ParquetDecryption {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ParquetDecryption {}
+
+impl Hash for ParquetDecryption {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ParquetDecryption {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(
+            "cannot serialize a scan of encrypted parquet files: its keys are not serialized",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> Deserialize<'a> for ParquetDecryption {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom(
+            "cannot deserialize a scan of encrypted parquet files: its keys are not serialized",
+        ))
+    }
+}
+
+/// The fields of the nested columns of a file that are read.
+///
+/// The fields of structs that aren't projected are left out of the schema of the file, so
+/// that their leaf columns are not decoded. Columns that aren't in the projection are read
+/// entirely.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NestedProjection {
+    /// The projected fields by name, empty if all fields are read.
+    fields: BTreeMap<String, NestedProjection>,
+}
+
+impl NestedProjection {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Read the field at `path`, which starts with the name of the column, e.g.
+    /// `["a", "b"]` for the field `b` of the struct column `a`. The fields of lists of
+    /// structs are selected by the fields of their elements.
+    pub fn add_path(&mut self, path: &[&str]) {
+        let Some((name, path)) = path.split_first() else {
+            // All fields are read.
+            self.fields.clear();
+            return;
+        };
+        match self.fields.get_mut(*name) {
+            Some(projection) if projection.is_empty() => {},
+            Some(projection) => projection.add_path(path),
+            None => {
+                let mut projection = Self::default();
+                if !path.is_empty() {
+                    projection.add_path(path);
+                }
+                self.fields.insert(name.to_string(), projection);
+            },
+        }
+    }
+
+    /// Leave the fields that aren't projected out of the nested columns of `schema`.
+    pub fn project_schema(&self, schema: &ArrowSchema) -> ArrowSchema {
+        let fields = schema
+            .fields
+            .iter()
+            .map(|field| match self.fields.get(&field.name) {
+                Some(projection) => projection.project_field(field),
+                None => field.clone(),
+            })
+            .collect::<Vec<_>>();
+        ArrowSchema::from(fields).with_metadata(schema.metadata.clone())
+    }
+
+    fn project_field(&self, field: &Field) -> Field {
+        if self.is_empty() {
+            return field.clone();
+        }
+        let data_type = match &field.data_type {
+            ArrowDataType::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .filter_map(|field| Some(self.fields.get(&field.name)?.project_field(field)))
+                    .collect::<Vec<_>>();
+                if fields.is_empty() {
+                    return field.clone();
+                }
+                ArrowDataType::Struct(fields)
+            },
+            ArrowDataType::List(inner) => ArrowDataType::List(Box::new(self.project_field(inner))),
+            ArrowDataType::LargeList(inner) => {
+                ArrowDataType::LargeList(Box::new(self.project_field(inner)))
+            },
+            _ => return field.clone(),
+        };
+        Field::new(field.name.clone(), data_type, field.is_nullable)
+            .with_metadata(field.metadata.clone())
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -23,3 +172,14 @@ pub enum ParallelStrategy {
     #[default]
     Auto,
 }
+
+impl ParallelStrategy {
+    /// The strategy to use when a single row group is decoded at a time, so that the memory
+    /// needed for decoding is bounded by a single row group.
+    pub(super) fn single_decoder(self) -> Self {
+        match self {
+            ParallelStrategy::Auto | ParallelStrategy::RowGroups => ParallelStrategy::Columns,
+            strategy => strategy,
+        }
+    }
+}
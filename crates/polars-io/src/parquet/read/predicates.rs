@@ -1,9 +1,17 @@
+use std::io::Cursor;
+
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
+use polars_parquet::arrow::bloom_filter;
+use polars_parquet::read::indexes::{
+    compute_page_row_intervals, read_columns_indexes, FieldPageStatistics, Interval,
+};
 use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{get_field_leaf_columns, read_pages_locations, RowGroupMetaData};
 
-use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use super::mmap::ColumnStore;
+use crate::parquet::BloomFilter;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr, StatsEvaluator};
 
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
@@ -39,23 +47,290 @@ pub(crate) fn collect_statistics(
     })
 }
 
+/// The statistics of a row group with the bloom filters of the columns that the predicate
+/// reads, or `None` if none of these columns has a bloom filter.
+fn with_bloom_filters(
+    stats: &BatchStats,
+    predicate: &dyn PhysicalIoExpr,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    bytes: &[u8],
+) -> PolarsResult<Option<BatchStats>> {
+    let live_variables = predicate.live_variables();
+    let mut has_bloom_filter = false;
+    let mut column_stats = Vec::with_capacity(schema.fields.len());
+    for (field, field_stats) in schema.fields.iter().zip(stats.column_stats()) {
+        let mut field_stats = field_stats.clone();
+        let is_live = live_variables.as_ref().map_or(true, |live| {
+            live.iter().any(|name| name.as_ref() == field.name.as_str())
+        });
+        let columns = get_field_leaf_columns(md.columns(), field);
+        if let ([column], true) = (columns.as_slice(), is_live) {
+            if column.descriptor().descriptor.max_rep_level == 0
+                && !column.is_encrypted()
+                && column.metadata().bloom_filter_offset.is_some()
+            {
+                let mut bitset = vec![];
+                bloom_filter::read(column, &mut Cursor::new(bytes), &mut bitset)?;
+                if !bitset.is_empty() {
+                    let dtype = field_stats.dtype().clone();
+                    field_stats = field_stats.with_bloom_filter(BloomFilter::new(dtype, bitset));
+                    has_bloom_filter = true;
+                }
+            }
+        }
+        column_stats.push(field_stats);
+    }
+    Ok(has_bloom_filter
+        .then(|| BatchStats::new(stats.schema().clone(), column_stats, stats.num_rows())))
+}
+
+fn should_read(predicate: &dyn StatsEvaluator, stats: &BatchStats) -> PolarsResult<bool> {
+    let should_read = predicate.should_read(stats);
+    // a parquet file may not have statistics of all columns
+    if matches!(should_read, Ok(false)) {
+        return Ok(false);
+    } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
+        let _ = should_read?;
+    }
+    Ok(true)
+}
+
+/// The number of rows of a row group for which `predicate` holds, if its statistics decide it,
+/// without reading its data pages.
+///
+/// No row holds if the statistics rule out all rows. Every row holds if they rule out all rows
+/// for `complement`, which must hold exactly for the rows without nulls for which `predicate`
+/// doesn't, and the columns of the predicate have no nulls in the row group.
+pub(super) fn count_matching_rows(
+    predicate: &dyn PhysicalIoExpr,
+    complement: &dyn PhysicalIoExpr,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+) -> PolarsResult<Option<usize>> {
+    let (Some(evaluator), Some(complement_evaluator)) = (
+        predicate.as_stats_evaluator(),
+        complement.as_stats_evaluator(),
+    ) else {
+        return Ok(None);
+    };
+    let Some(live_variables) = predicate.live_variables() else {
+        return Ok(None);
+    };
+    let Some(stats) = collect_statistics(md, schema)? else {
+        return Ok(None);
+    };
+    if !should_read(evaluator, &stats)? {
+        return Ok(Some(0));
+    }
+    let has_no_nulls = live_variables.iter().all(|name| {
+        stats
+            .get_stats(name)
+            .map_or(false, |stats| stats.null_count() == Some(0))
+    });
+    if has_no_nulls && !should_read(complement_evaluator, &stats)? {
+        return Ok(Some(md.num_rows()));
+    }
+    Ok(None)
+}
+
+/// Whether the row group may contain rows that pass the predicate, according to its
+/// statistics and, for local files, the bloom filters of its columns.
 pub(super) fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetaData,
     schema: &ArrowSchemaRef,
+    store: Option<&ColumnStore>,
 ) -> PolarsResult<bool> {
     if let Some(pred) = predicate {
-        if let Some(pred) = pred.as_stats_evaluator() {
+        if let Some(stats_pred) = pred.as_stats_evaluator() {
             if let Some(stats) = collect_statistics(md, schema)? {
-                let should_read = pred.should_read(&stats);
-                // a parquet file may not have statistics of all columns
-                if matches!(should_read, Ok(false)) {
+                if !should_read(stats_pred, &stats)? {
                     return Ok(false);
-                } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
-                    let _ = should_read?;
+                }
+                // The bloom filters are only read if the other statistics are not sufficient.
+                if let Some(bytes) = store.and_then(local_bytes) {
+                    if let Some(stats) = with_bloom_filters(&stats, pred, md, schema, bytes)? {
+                        return should_read(stats_pred, &stats);
+                    }
                 }
             }
         }
     }
     Ok(true)
 }
+
+fn local_bytes<'a>(store: &ColumnStore<'a>) -> Option<&'a [u8]> {
+    match store {
+        ColumnStore::Local(bytes) => Some(*bytes),
+        // Only the column chunks of fetched files are fetched, not their page indexes.
+        #[cfg(feature = "async")]
+        ColumnStore::Fetched(_) => None,
+    }
+}
+
+/// The row intervals of the pages of a column of a row group, from its offset index.
+///
+/// Returns `None` if the column is nested, as its pages don't hold whole rows, is encrypted
+/// or has no offset index.
+pub(super) fn page_row_intervals(
+    store: &ColumnStore,
+    md: &RowGroupMetaData,
+    field: &ArrowField,
+) -> PolarsResult<Option<Vec<Interval>>> {
+    let Some(bytes) = local_bytes(store) else {
+        return Ok(None);
+    };
+    let columns = get_field_leaf_columns(md.columns(), field);
+    let [column] = columns.as_slice() else {
+        return Ok(None);
+    };
+    if column.descriptor().descriptor.max_rep_level > 0
+        || column.is_encrypted()
+        || column.column_chunk().offset_index_offset.is_none()
+    {
+        return Ok(None);
+    }
+    let locations = read_pages_locations(&mut Cursor::new(bytes), std::slice::from_ref(*column))?;
+    match locations.first() {
+        Some(locations) if !locations.is_empty() => {
+            Ok(Some(compute_page_row_intervals(locations, md.num_rows())?))
+        },
+        _ => Ok(None),
+    }
+}
+
+/// The statistics of the pages of a column of a row group, from its column index.
+struct PageStatistics {
+    field: Field,
+    intervals: Vec<Interval>,
+    null_count: Series,
+    min_value: Series,
+    max_value: Series,
+}
+
+impl PageStatistics {
+    fn read(
+        store: &ColumnStore,
+        md: &RowGroupMetaData,
+        field: &ArrowField,
+    ) -> PolarsResult<Option<Self>> {
+        let Some(intervals) = page_row_intervals(store, md, field)? else {
+            return Ok(None);
+        };
+        let bytes = local_bytes(store).unwrap();
+        let column = get_field_leaf_columns(md.columns(), field)[0];
+        if column.column_chunk().column_index_offset.is_none() {
+            return Ok(None);
+        }
+        // The column indexes of some types can't be deserialized yet.
+        let Ok(mut stats) = read_columns_indexes(
+            &mut Cursor::new(bytes),
+            std::slice::from_ref(column),
+            std::slice::from_ref(field),
+        ) else {
+            return Ok(None);
+        };
+        let Some(FieldPageStatistics::Single(stats)) = stats.pop() else {
+            return Ok(None);
+        };
+        if stats.null_count.len() != intervals.len() {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            field: field.into(),
+            intervals,
+            null_count: Series::try_from(("", stats.null_count.boxed()))?,
+            min_value: Series::try_from(("", stats.min))?,
+            max_value: Series::try_from(("", stats.max))?,
+        }))
+    }
+
+    /// The statistics of the page at `page_i`.
+    fn page(&self, page_i: usize) -> ColumnStats {
+        let page_i = page_i as i64;
+        ColumnStats::new(
+            self.field.clone(),
+            Some(self.null_count.slice(page_i, 1)),
+            Some(self.min_value.slice(page_i, 1)),
+            Some(self.max_value.slice(page_i, 1)),
+        )
+    }
+}
+
+/// The rows of a row group that may pass the predicate, according to the page statistics of
+/// the columns that the predicate reads.
+///
+/// Returns `None` if all rows may pass, or if the rows can't be pruned, e.g. because the
+/// file has no page indexes or the predicate reads nested columns.
+pub(super) fn select_rows_by_page_index(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    store: &ColumnStore,
+) -> PolarsResult<Option<Vec<Interval>>> {
+    let Some(predicate) = predicate else {
+        return Ok(None);
+    };
+    let (Some(evaluator), Some(live_variables)) =
+        (predicate.as_stats_evaluator(), predicate.live_variables())
+    else {
+        return Ok(None);
+    };
+
+    let mut columns = Vec::with_capacity(live_variables.len());
+    for name in live_variables {
+        // The predicate may also read the row index or the hive partition columns.
+        let Some(field) = schema.fields.iter().find(|fld| fld.name == name.as_ref()) else {
+            return Ok(None);
+        };
+        match PageStatistics::read(store, md, field)? {
+            Some(column) => columns.push(column),
+            None => return Ok(None),
+        }
+    }
+    if columns.is_empty() {
+        return Ok(None);
+    }
+
+    // The predicate is evaluated on the ranges of rows between the page boundaries of all
+    // columns, each of which lies within a single page of every column.
+    let num_rows = md.num_rows();
+    let mut bounds = columns
+        .iter()
+        .flat_map(|column| column.intervals.iter().map(|page| page.start))
+        .chain(std::iter::once(num_rows))
+        .collect::<Vec<_>>();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let stats_schema: SchemaRef =
+        Arc::new(columns.iter().map(|column| column.field.clone()).collect());
+    let mut page_idx = vec![0; columns.len()];
+    let mut rows: Vec<Interval> = vec![];
+    for range in bounds.windows(2) {
+        let (start, end) = (range[0], range[1]);
+        let stats = columns
+            .iter()
+            .zip(page_idx.iter_mut())
+            .map(|(column, page_i)| {
+                while column.intervals[*page_i].start + column.intervals[*page_i].length <= start {
+                    *page_i += 1;
+                }
+                column.page(*page_i)
+            })
+            .collect();
+        // The pages may hold more rows than the range, so the number of rows is unknown.
+        let stats = BatchStats::new(stats_schema.clone(), stats, None);
+        if matches!(evaluator.should_read(&stats), Ok(false)) {
+            continue;
+        }
+        match rows.last_mut() {
+            Some(last) if last.start + last.length == start => last.length += end - start,
+            _ => rows.push(Interval::new(start, end - start)),
+        }
+    }
+
+    let all_rows = matches!(rows.as_slice(), [rows] if rows.length == num_rows);
+    Ok((!all_rows).then_some(rows))
+}
@@ -1,20 +1,23 @@
 use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::ops::{Deref, Range};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use arrow::array::new_empty_array;
+use arrow::array::{new_empty_array, BooleanArray};
+use arrow::bitmap::MutableBitmap;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
 use polars_parquet::read;
-use polars_parquet::read::{ArrayIter, FileMetaData, RowGroupMetaData};
+use polars_parquet::read::indexes::Interval;
+use polars_parquet::read::{ArrayIter, FileMetaData, PageFilter, RowGroupMetaData};
 use rayon::prelude::*;
 
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
-use super::predicates::read_this_row_group;
+use super::predicates::{page_row_intervals, read_this_row_group, select_rows_by_page_index};
 use super::utils::materialize_empty_df;
 use super::{mmap, ParallelStrategy};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
@@ -64,7 +67,7 @@ fn column_idx_to_series(
         assert_dtypes(field.data_type())
     }
 
-    let columns = mmap_columns(store, md.columns(), &field.name);
+    let columns = mmap_columns(store, md.columns(), field);
     let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
 
     if remaining_rows < md.num_rows() {
@@ -74,6 +77,91 @@ fn column_idx_to_series(
     }
 }
 
+/// Decode the `rows` of a column of a row group.
+///
+/// If the column has an offset index, only its pages that hold any of the `rows` are decoded.
+fn column_idx_to_series_of_rows(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    rows: &[Interval],
+    file_schema: &ArrowSchema,
+    store: &mmap::ColumnStore,
+) -> PolarsResult<Series> {
+    let field = &file_schema.fields[column_i];
+
+    let (s, decoded_rows) = match page_row_intervals(store, md, field)? {
+        Some(pages) => {
+            // Both the pages and the rows are sorted.
+            let mut remaining = rows;
+            let overlaps = |page: &Interval| {
+                while let [rows, rest @ ..] = remaining {
+                    if rows.start + rows.length > page.start {
+                        break;
+                    }
+                    remaining = rest;
+                }
+                remaining
+                    .first()
+                    .map_or(false, |rows| rows.start < page.start + page.length)
+            };
+            let selected = pages.iter().map(overlaps).collect::<Vec<_>>();
+            let decoded_rows = pages
+                .into_iter()
+                .zip(selected.iter())
+                .filter_map(|(page, selected)| selected.then_some(page))
+                .collect::<Vec<_>>();
+            let num_rows = decoded_rows.iter().map(|page| page.length).sum();
+
+            // The filter is called for the data pages of the column chunk in order.
+            let page_i = AtomicUsize::new(0);
+            let pages_filter: PageFilter =
+                Arc::new(move |_, _| selected[page_i.fetch_add(1, Ordering::Relaxed)]);
+            let columns = mmap_columns(store, md.columns(), field);
+            let iter = mmap::to_filtered_deserializer(
+                columns,
+                field.clone(),
+                num_rows,
+                None,
+                pages_filter,
+            )?;
+            (array_iter_to_series(iter, field, None)?, decoded_rows)
+        },
+        None => {
+            let s = column_idx_to_series(
+                column_i,
+                md,
+                md.num_rows(),
+                file_schema,
+                store,
+                md.num_rows(),
+            )?;
+            (s, vec![Interval::new(0, md.num_rows())])
+        },
+    };
+
+    // Filter the rows out of the decoded pages, which hold all of them.
+    let num_rows = rows.iter().map(|rows| rows.length).sum::<usize>();
+    if num_rows == s.len() {
+        return Ok(s);
+    }
+    let mut mask = MutableBitmap::from_len_zeroed(s.len());
+    let mut decoded_rows = decoded_rows.iter();
+    let mut page = decoded_rows.next().unwrap();
+    let mut offset = 0;
+    for rows in rows {
+        while page.start + page.length <= rows.start {
+            offset += page.length;
+            page = decoded_rows.next().unwrap();
+        }
+        let start = offset + rows.start - page.start;
+        for i in start..start + rows.length {
+            mask.set(i, true);
+        }
+    }
+    let mask = BooleanArray::new(ArrowDataType::Boolean, mask.into(), None);
+    s.filter(&BooleanChunked::with_chunk("", mask))
+}
+
 pub(super) fn array_iter_to_series(
     iter: ArrayIter,
     field: &ArrowField,
@@ -125,6 +213,142 @@ pub(crate) fn materialize_hive_partitions(
     }
 }
 
+/// The columns of the `projection` that the predicate reads, if the predicate only reads
+/// some of the projected columns. These are decoded first, see [`decode_row_group`].
+fn late_materialization_columns(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    projection: &[usize],
+    schema: &ArrowSchema,
+) -> Option<Vec<usize>> {
+    let live_variables = predicate?.live_variables()?;
+    let is_live = |column_i: &usize| {
+        live_variables
+            .iter()
+            .any(|name| name.as_ref() == schema.fields[*column_i].name)
+    };
+    // The predicate may also read the row index or the hive partition columns.
+    let all_projected = live_variables.iter().all(|name| {
+        projection
+            .iter()
+            .any(|column_i| name.as_ref() == schema.fields[*column_i].name)
+    });
+    let predicate_columns = projection
+        .iter()
+        .copied()
+        .filter(is_live)
+        .collect::<Vec<_>>();
+    let is_subset = !predicate_columns.is_empty() && predicate_columns.len() < projection.len();
+    (all_projected && is_subset).then_some(predicate_columns)
+}
+
+/// The rows of a row group for which the `mask` of its decoded `rows` is true.
+fn selected_rows(mask: &BooleanChunked, rows: &[Interval]) -> Vec<Interval> {
+    let mut selected: Vec<Interval> = vec![];
+    let row_numbers = rows
+        .iter()
+        .flat_map(|rows| rows.start..rows.start + rows.length);
+    for (row, keep) in row_numbers.zip(mask) {
+        if keep != Some(true) {
+            continue;
+        }
+        match selected.last_mut() {
+            Some(last) if last.start + last.length == row => last.length += 1,
+            _ => selected.push(Interval::new(row, 1)),
+        }
+    }
+    selected
+}
+
+/// Decode the `projection` of a row group, of which the `rows` are read.
+///
+/// With late materialization, the `predicate_columns` are decoded first and the predicate
+/// selects the rows of the row group that pass it. The other columns are then only decoded
+/// for these rows, which skips their pages that hold none of them if the columns have an
+/// offset index. `decode` decodes columns for the selected rows if these are given, and for
+/// the `rows` otherwise.
+///
+/// Returns `None` if no rows pass the predicate. Otherwise, returns the columns and, if the
+/// predicate was applied, the rows of the row group that they hold.
+fn decode_row_group<F>(
+    projection: &[usize],
+    predicate_columns: Option<&[usize]>,
+    predicate: Option<&dyn PhysicalIoExpr>,
+    rows: &[Interval],
+    decode: F,
+) -> PolarsResult<Option<(Vec<Series>, Option<Vec<Interval>>)>>
+where
+    F: Fn(&[usize], Option<&[Interval]>) -> PolarsResult<Vec<Series>>,
+{
+    let (Some(predicate_columns), Some(predicate)) = (predicate_columns, predicate) else {
+        return Ok(Some((decode(projection, None)?, None)));
+    };
+
+    let df = unsafe { DataFrame::new_no_checks(decode(predicate_columns, None)?) };
+    let mask = predicate.evaluate_io(&df)?;
+    let mask = mask
+        .bool()
+        .expect("filter predicates was not of type boolean");
+    let selected = selected_rows(mask, rows);
+    if selected.is_empty() {
+        return Ok(None);
+    }
+
+    let other_columns = projection
+        .iter()
+        .copied()
+        .filter(|column_i| !predicate_columns.contains(column_i))
+        .collect::<Vec<_>>();
+    let mut other_series = decode(&other_columns, Some(&selected))?.into_iter();
+    let mut predicate_series = df._filter_seq(mask)?.take_columns().into_iter();
+    let columns = projection
+        .iter()
+        .map(|column_i| {
+            if predicate_columns.contains(column_i) {
+                predicate_series.next().unwrap()
+            } else {
+                other_series.next().unwrap()
+            }
+        })
+        .collect();
+    Ok(Some((columns, Some(selected))))
+}
+
+/// The rows of a row group that are decoded, if the page indexes of the file prune any.
+///
+/// The page indexes are only used if the whole row group is read.
+fn select_rows(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetaData,
+    projection_height: usize,
+    schema: &ArrowSchema,
+    store: &mmap::ColumnStore,
+    use_statistics: bool,
+) -> PolarsResult<Option<Vec<Interval>>> {
+    if use_statistics && projection_height == md.num_rows() {
+        select_rows_by_page_index(predicate, md, schema, store)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Add the row index of the `rows` of a row group whose first row has the index `offset`.
+fn with_row_index_of_rows(
+    df: &mut DataFrame,
+    name: &str,
+    offset: IdxSize,
+    rows: &[Interval],
+) -> PolarsResult<()> {
+    let idx = rows
+        .iter()
+        .flat_map(|rows| rows.start..rows.start + rows.length)
+        .map(|i| offset + i as IdxSize)
+        .collect();
+    let mut ca = IdxCa::from_vec(name, idx);
+    ca.set_sorted_flag(IsSorted::Ascending);
+    df.insert_column(0, ca)?;
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -193,13 +417,19 @@ fn rg_to_dfs_optionally_par_over_columns(
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
+    let predicate_columns = late_materialization_columns(predicate, projection, schema);
 
     for rg_idx in row_group_start..row_group_end {
         let md = &file_metadata.row_groups[rg_idx];
         let current_row_count = md.num_rows() as IdxSize;
 
         if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+            && !read_this_row_group(
+                predicate,
+                &file_metadata.row_groups[rg_idx],
+                schema,
+                Some(store),
+            )?
         {
             *previous_row_count += current_row_count;
             continue;
@@ -211,48 +441,86 @@ fn rg_to_dfs_optionally_par_over_columns(
         }
 
         let projection_height = (*remaining_rows).min(md.num_rows());
-        let chunk_size = md.num_rows();
-        let columns = if let ParallelStrategy::Columns = parallel {
-            POOL.install(|| {
+        let rows = select_rows(
+            predicate,
+            md,
+            projection_height,
+            schema,
+            store,
+            use_statistics,
+        )?;
+        let decode = |projection: &[usize], selected: Option<&[Interval]>| {
+            let decode_column = |column_i: &usize| match selected.or(rows.as_deref()) {
+                Some(rows) => column_idx_to_series_of_rows(*column_i, md, rows, schema, store),
+                None => column_idx_to_series(
+                    *column_i,
+                    md,
+                    projection_height,
+                    schema,
+                    store,
+                    md.num_rows(),
+                ),
+            };
+            if let ParallelStrategy::Columns = parallel {
+                POOL.install(|| {
+                    projection
+                        .par_iter()
+                        .map(decode_column)
+                        .collect::<PolarsResult<Vec<_>>>()
+                })
+            } else {
                 projection
-                    .par_iter()
-                    .map(|column_i| {
-                        column_idx_to_series(
-                            *column_i,
-                            md,
-                            projection_height,
-                            schema,
-                            store,
-                            chunk_size,
-                        )
-                    })
+                    .iter()
+                    .map(decode_column)
                     .collect::<PolarsResult<Vec<_>>>()
-            })?
+            }
+        };
+        let decoded_rows = rows
+            .clone()
+            .unwrap_or_else(|| vec![Interval::new(0, projection_height)]);
+        let decoded = if decoded_rows.is_empty() {
+            None
         } else {
-            projection
-                .iter()
-                .map(|column_i| {
-                    column_idx_to_series(
-                        *column_i,
-                        md,
-                        projection_height,
-                        schema,
-                        store,
-                        chunk_size,
-                    )
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
+            decode_row_group(
+                projection,
+                predicate_columns.as_deref(),
+                predicate,
+                &decoded_rows,
+                decode,
+            )?
         };
 
         *remaining_rows -= projection_height;
 
+        let Some((columns, selected)) = decoded else {
+            *previous_row_count += current_row_count;
+            if *remaining_rows == 0 {
+                break;
+            }
+            continue;
+        };
+        let is_filtered = selected.is_some();
+        let rows = selected.or(rows);
+
         let mut df = unsafe { DataFrame::new_no_checks(columns) };
+        let height = match &rows {
+            Some(rows) => rows.iter().map(|rows| rows.length).sum(),
+            None => projection_height,
+        };
         if let Some(rc) = &row_index {
-            df.with_row_index_mut(&rc.name, Some(*previous_row_count + rc.offset));
+            let offset = *previous_row_count + rc.offset;
+            match &rows {
+                Some(rows) => with_row_index_of_rows(&mut df, &rc.name, offset, rows)?,
+                None => {
+                    df.with_row_index_mut(&rc.name, Some(offset));
+                },
+            }
         }
 
-        materialize_hive_partitions(&mut df, hive_partition_columns, projection_height);
-        apply_predicate(&mut df, predicate, true)?;
+        materialize_hive_partitions(&mut df, hive_partition_columns, height);
+        if !is_filtered {
+            apply_predicate(&mut df, predicate, true)?;
+        }
 
         *previous_row_count += current_row_count;
         dfs.push(df);
@@ -280,6 +548,7 @@ fn rg_to_dfs_par_over_rg(
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
+    let predicate_columns = late_materialization_columns(predicate, projection, schema);
     // compute the limits per row group and the row count offsets
     let row_groups = file_metadata
         .row_groups
@@ -308,6 +577,7 @@ fn rg_to_dfs_par_over_rg(
                             predicate,
                             &file_metadata.row_groups[rg_idx],
                             schema,
+                            Some(store),
                         )?
                 {
                     return Ok(None);
@@ -318,29 +588,71 @@ fn rg_to_dfs_par_over_rg(
                     assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
                 }
 
-                let chunk_size = md.num_rows();
-                let columns = projection
-                    .iter()
-                    .map(|column_i| {
-                        column_idx_to_series(
-                            *column_i,
-                            md,
-                            projection_height,
-                            schema,
-                            store,
-                            chunk_size,
-                        )
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()?;
+                let rows = select_rows(
+                    predicate,
+                    md,
+                    projection_height,
+                    schema,
+                    store,
+                    use_statistics,
+                )?;
+                let decoded_rows = rows
+                    .clone()
+                    .unwrap_or_else(|| vec![Interval::new(0, projection_height)]);
+                if decoded_rows.is_empty() {
+                    return Ok(None);
+                }
+                let decode = |projection: &[usize], selected: Option<&[Interval]>| {
+                    projection
+                        .iter()
+                        .map(|column_i| match selected.or(rows.as_deref()) {
+                            Some(rows) => {
+                                column_idx_to_series_of_rows(*column_i, md, rows, schema, store)
+                            },
+                            None => column_idx_to_series(
+                                *column_i,
+                                md,
+                                projection_height,
+                                schema,
+                                store,
+                                md.num_rows(),
+                            ),
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()
+                };
+                let Some((columns, selected)) = decode_row_group(
+                    projection,
+                    predicate_columns.as_deref(),
+                    predicate,
+                    &decoded_rows,
+                    decode,
+                )?
+                else {
+                    return Ok(None);
+                };
+                let is_filtered = selected.is_some();
+                let rows = selected.or(rows);
 
                 let mut df = unsafe { DataFrame::new_no_checks(columns) };
+                let height = match &rows {
+                    Some(rows) => rows.iter().map(|rows| rows.length).sum(),
+                    None => projection_height,
+                };
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(&rc.name, Some(row_count_start as IdxSize + rc.offset));
+                    let offset = row_count_start as IdxSize + rc.offset;
+                    match &rows {
+                        Some(rows) => with_row_index_of_rows(&mut df, &rc.name, offset, rows)?,
+                        None => {
+                            df.with_row_index_mut(&rc.name, Some(offset));
+                        },
+                    }
                 }
 
-                materialize_hive_partitions(&mut df, hive_partition_columns, projection_height);
-                apply_predicate(&mut df, predicate, false)?;
+                materialize_hive_partitions(&mut df, hive_partition_columns, height);
+                if !is_filtered {
+                    apply_predicate(&mut df, predicate, false)?;
+                }
 
                 Ok(Some(df))
             })
@@ -358,9 +670,12 @@ pub fn read_parquet<R: MmapBytesReader>(
     metadata: Option<FileMetaDataRef>,
     predicate: Option<&dyn PhysicalIoExpr>,
     mut parallel: ParallelStrategy,
+    max_decoders: usize,
     row_index: Option<RowIndex>,
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    n_rows_after_predicate: Option<usize>,
+    skip_rows: usize,
 ) -> PolarsResult<DataFrame> {
     // Fast path.
     if limit == 0 {
@@ -414,21 +729,108 @@ pub fn read_parquet<R: MmapBytesReader>(
     let bytes = reader.deref();
     let store = mmap::ColumnStore::Local(bytes);
 
-    let dfs = rg_to_dfs(
-        &store,
-        &mut 0,
-        0,
-        n_row_groups,
-        &mut limit,
-        &file_metadata,
-        reader_schema,
-        predicate,
-        row_index.clone(),
-        parallel,
-        &materialized_projection,
-        use_statistics,
-        hive_partition_columns,
-    )?;
+    // The row groups that only contain skipped rows are not read.
+    let mut previous_row_count: IdxSize = 0;
+    let mut rg_start = 0;
+    while rg_start < n_row_groups {
+        let num_rows = file_metadata.row_groups[rg_start].num_rows();
+        if previous_row_count as usize + num_rows > skip_rows {
+            break;
+        }
+        previous_row_count += num_rows as IdxSize;
+        rg_start += 1;
+    }
+
+    let mut dfs = vec![];
+    let mut n_rows_after_predicate = n_rows_after_predicate.filter(|_| predicate.is_some());
+    let skip_in_first = skip_rows.saturating_sub(previous_row_count as usize);
+    if rg_start < n_row_groups && skip_in_first > 0 {
+        // The first row group is sliced before the predicate is applied.
+        let mut first_limit = limit.saturating_add(skip_in_first);
+        let first_dfs = rg_to_dfs(
+            &store,
+            &mut previous_row_count,
+            rg_start,
+            rg_start + 1,
+            &mut first_limit,
+            &file_metadata,
+            reader_schema,
+            None,
+            row_index.clone(),
+            parallel,
+            &materialized_projection,
+            use_statistics,
+            hive_partition_columns,
+        )?;
+        limit = first_limit;
+        rg_start += 1;
+        for df in first_dfs {
+            let mut df = df.slice(skip_in_first as i64, usize::MAX);
+            apply_predicate(&mut df, predicate, true)?;
+            if let Some(remaining) = &mut n_rows_after_predicate {
+                *remaining = remaining.saturating_sub(df.height());
+            }
+            dfs.push(df);
+        }
+    }
+
+    match n_rows_after_predicate {
+        _ if limit == 0 => {},
+        // Read the row groups one by one until enough rows passed the predicate.
+        Some(mut remaining) => {
+            for rg_idx in rg_start..n_row_groups {
+                if remaining == 0 || limit == 0 {
+                    break;
+                }
+                let rg_dfs = rg_to_dfs(
+                    &store,
+                    &mut previous_row_count,
+                    rg_idx,
+                    rg_idx + 1,
+                    &mut limit,
+                    &file_metadata,
+                    reader_schema,
+                    predicate,
+                    row_index.clone(),
+                    parallel,
+                    &materialized_projection,
+                    use_statistics,
+                    hive_partition_columns,
+                )?;
+                let n_read = rg_dfs.iter().map(|df| df.height()).sum::<usize>();
+                remaining = remaining.saturating_sub(n_read);
+                dfs.extend(rg_dfs);
+            }
+        },
+        None => {
+            // Bound the number of row groups that are decoded at the same time.
+            let n_decoded = match parallel {
+                ParallelStrategy::RowGroups => max_decoders,
+                _ => n_row_groups,
+            }
+            .max(1);
+            for rg_idx in (rg_start..n_row_groups).step_by(n_decoded) {
+                if limit == 0 {
+                    break;
+                }
+                dfs.extend(rg_to_dfs(
+                    &store,
+                    &mut previous_row_count,
+                    rg_idx,
+                    std::cmp::min(rg_idx + n_decoded, n_row_groups),
+                    &mut limit,
+                    &file_metadata,
+                    reader_schema,
+                    predicate,
+                    row_index.clone(),
+                    parallel,
+                    &materialized_projection,
+                    use_statistics,
+                    hive_partition_columns,
+                )?);
+            }
+        },
+    }
 
     if dfs.is_empty() {
         Ok(materialize_empty_df(
@@ -800,3 +1202,68 @@ impl BatchedParquetIter {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::parquet::read::mmap::test::DECODED_PAGES;
+    use crate::parquet::read::ParquetReader;
+    use crate::parquet::write::ParquetWriter;
+    use crate::SerReader;
+
+    /// `a == 5`, without statistics, so that it doesn't skip any pages itself.
+    struct IsFive;
+
+    impl PhysicalIoExpr for IsFive {
+        fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+            Ok(df.column("a")?.equal(5)?.into_series())
+        }
+
+        fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+            Some(vec![Arc::from("a")])
+        }
+    }
+
+    /// Read the `columns` of the parquet file `buf`, and count the decoded data pages.
+    fn read_counting_pages(
+        buf: &[u8],
+        columns: &[&str],
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> PolarsResult<(DataFrame, usize)> {
+        DECODED_PAGES.with(|n| n.set(0));
+        let df = ParquetReader::new(Cursor::new(buf))
+            .with_columns(Some(columns.iter().map(|c| c.to_string()).collect()))
+            .with_predicate(predicate)
+            .read_parallel(ParallelStrategy::None)
+            .finish()?;
+        Ok((df, DECODED_PAGES.with(|n| n.get())))
+    }
+
+    #[test]
+    fn test_late_materialization_skips_pages() -> PolarsResult<()> {
+        let n = 10_000;
+        let a = (0..n)
+            .map(|i| if i == 3 { 5 } else { i % 2 })
+            .collect::<Vec<i32>>();
+        let b = (0..n).collect::<Vec<i32>>();
+        let mut df = DataFrame::new(vec![Series::new("a", a), Series::new("b", b)])?;
+        let mut buf = vec![];
+        ParquetWriter::new(&mut buf)
+            .with_data_page_size(Some(1024))
+            .finish(&mut df)?;
+
+        let (_, pages_a) = read_counting_pages(&buf, &["a"], None)?;
+        let (_, pages_b) = read_counting_pages(&buf, &["b"], None)?;
+        assert!(pages_b > 1);
+
+        // All the pages of `a` are decoded, but only the page of `b` that holds the row
+        // that passes the predicate.
+        let (out, pages) = read_counting_pages(&buf, &["a", "b"], Some(Arc::new(IsFive)))?;
+        assert_eq!(pages, pages_a + 1);
+        let expected = DataFrame::new(vec![Series::new("a", &[5i32]), Series::new("b", &[3i32])])?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+}
@@ -5,12 +5,15 @@ use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 #[cfg(feature = "cloud")]
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
 use polars_parquet::read;
 
 #[cfg(feature = "cloud")]
-use super::async_impl::FetchRowGroupsFromObjectStore;
-#[cfg(feature = "cloud")]
-use super::async_impl::ParquetObjectStore;
+use super::async_impl::{
+    column_chunks_size, fetch_column_chunks, to_fields, FetchRowGroupsFromObjectStore,
+    ParquetObjectStore, PrefetchedRowGroup,
+};
+use super::predicates::count_matching_rows;
 pub use super::read_impl::BatchedParquetReader;
 use super::read_impl::{read_parquet, FetchRowGroupsFromMmapReader};
 #[cfg(feature = "cloud")]
@@ -35,20 +38,50 @@ pub struct ParquetReader<R: Read + Seek> {
     schema: Option<ArrowSchemaRef>,
     row_index: Option<RowIndex>,
     low_memory: bool,
+    max_decoders: Option<usize>,
     metadata: Option<FileMetaDataRef>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     hive_partition_columns: Option<Vec<Series>>,
     use_statistics: bool,
+    n_rows_after_predicate: Option<usize>,
+    skip_rows: usize,
+    nested_projection: NestedProjection,
+    decryption: Option<Arc<FileDecryptionProperties>>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
     /// Try to reduce memory pressure at the expense of performance. If setting this does not reduce memory
     /// enough, turn off parallelization.
+    ///
+    /// In low memory mode at most a single row group is decoded at a time.
     pub fn set_low_memory(mut self, low_memory: bool) -> Self {
         self.low_memory = low_memory;
         self
     }
 
+    /// Decode at most `max_decoders` row groups at the same time. This bounds the memory used
+    /// for decoding, and takes precedence over low memory mode.
+    pub fn set_max_decoders(mut self, max_decoders: Option<usize>) -> Self {
+        self.max_decoders = max_decoders;
+        self
+    }
+
+    fn max_decoders(&self) -> usize {
+        max_concurrent_decoders(
+            self.low_memory,
+            self.max_decoders,
+            POOL.current_num_threads(),
+        )
+    }
+
+    fn parallel_strategy(&self) -> ParallelStrategy {
+        if self.max_decoders() == 1 {
+            self.parallel.single_decoder()
+        } else {
+            self.parallel
+        }
+    }
+
     /// Read the parquet file in parallel (default). The single threaded reader consumes less memory.
     pub fn read_parallel(mut self, parallel: ParallelStrategy) -> Self {
         self.parallel = parallel;
@@ -62,12 +95,33 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Stop reading row groups once this many rows passed the predicate. Unlike
+    /// [`with_n_rows`](Self::with_n_rows), the rows are counted after the predicate is applied,
+    /// so more rows may be returned.
+    pub fn with_n_rows_after_predicate(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows_after_predicate = num_rows;
+        self
+    }
+
+    /// Skip the first `n` rows of the file. The row groups that only contain skipped rows are
+    /// not read. The rows are skipped before the predicate is applied.
+    pub fn with_skip_rows(mut self, n: usize) -> Self {
+        self.skip_rows = n;
+        self
+    }
+
     /// Columns to select/ project
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
         self
     }
 
+    /// Only read the projected fields of nested columns.
+    pub fn with_nested_projection(mut self, nested_projection: NestedProjection) -> Self {
+        self.nested_projection = nested_projection;
+        self
+    }
+
     /// Set the reader's column projection. This counts from 0, meaning that
     /// `vec![0, 4]` would select the 1st and 5th column.
     pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
@@ -75,6 +129,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Read encrypted files with these keys.
+    pub fn with_decryption(mut self, decryption: Option<Arc<FileDecryptionProperties>>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Add a row index column.
     pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
         self.row_index = row_index;
@@ -99,6 +159,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         }
     }
 
+    /// The [`Schema`] of the file without the nested fields that aren't projected.
+    fn projected_schema(&mut self) -> PolarsResult<ArrowSchemaRef> {
+        let schema = self.schema()?;
+        Ok(project_nested_fields(schema, &self.nested_projection))
+    }
+
     /// Use statistics in the parquet to determine if pages
     /// can be skipped from reading.
     pub fn use_statistics(mut self, toggle: bool) -> Self {
@@ -112,6 +178,26 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         Ok(metadata.num_rows)
     }
 
+    /// The number of rows for which `predicate` holds, if the statistics of the row groups
+    /// decide it for all of them, without reading their data pages. `complement` must hold
+    /// exactly for the rows without nulls for which `predicate` doesn't.
+    pub fn num_rows_with_predicate(
+        &mut self,
+        predicate: &dyn PhysicalIoExpr,
+        complement: &dyn PhysicalIoExpr,
+    ) -> PolarsResult<Option<usize>> {
+        let schema = self.schema()?;
+        let metadata = self.get_metadata()?;
+        let mut num_rows = 0;
+        for md in &metadata.row_groups {
+            match count_matching_rows(predicate, complement, md, &schema)? {
+                Some(n) => num_rows += n,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(num_rows))
+    }
+
     pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
         self.hive_partition_columns = columns;
         self
@@ -119,7 +205,10 @@ impl<R: MmapBytesReader> ParquetReader<R> {
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetaDataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                self.decryption.as_deref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -133,8 +222,9 @@ impl<R: MmapBytesReader> ParquetReader<R> {
 impl<R: MmapBytesReader + 'static> ParquetReader<R> {
     pub fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.get_metadata()?.clone();
-        let schema = self.schema()?;
+        let schema = self.projected_schema()?;
 
+        let parallel = self.parallel_strategy();
         let row_group_fetcher = FetchRowGroupsFromMmapReader::new(Box::new(self.reader))?.into();
         BatchedParquetReader::new(
             row_group_fetcher,
@@ -147,7 +237,7 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
             chunk_size,
             self.use_statistics,
             self.hive_partition_columns,
-            self.parallel,
+            parallel,
         )
     }
 }
@@ -164,11 +254,16 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             parallel: Default::default(),
             row_index: None,
             low_memory: false,
+            max_decoders: None,
             metadata: None,
             predicate: None,
             schema: None,
             use_statistics: true,
             hive_partition_columns: None,
+            n_rows_after_predicate: None,
+            skip_rows: 0,
+            nested_projection: Default::default(),
+            decryption: None,
         }
     }
 
@@ -178,12 +273,14 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
-        let schema = self.schema()?;
+        let schema = self.projected_schema()?;
         let metadata = self.get_metadata()?.clone();
 
         if let Some(cols) = &self.columns {
             self.projection = Some(columns_to_projection(cols, schema.as_ref())?);
         }
+        let parallel = self.parallel_strategy();
+        let max_decoders = self.max_decoders();
 
         read_parquet(
             self.reader,
@@ -192,20 +289,39 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             &schema,
             Some(metadata),
             self.predicate.as_deref(),
-            self.parallel,
+            parallel,
+            max_decoders,
             self.row_index,
             self.use_statistics,
             self.hive_partition_columns.as_deref(),
+            self.n_rows_after_predicate,
+            self.skip_rows,
         )
         .map(|mut df| {
             if self.rechunk {
                 df.as_single_chunk_par();
             }
+            set_column_metadata(&mut df, &schema);
             df
         })
     }
 }
 
+/// Set the key/value metadata that the fields of `schema` have on the columns of `df`.
+fn set_column_metadata(df: &mut DataFrame, schema: &ArrowSchema) {
+    // SAFETY: the names and the lengths of the columns don't change.
+    let columns = unsafe { df.get_columns_mut() };
+    for field in &schema.fields {
+        let field = Field::from(field);
+        let Some(metadata) = field.metadata else {
+            continue;
+        };
+        if let Some(s) = columns.iter_mut().find(|s| s.name() == field.name.as_str()) {
+            s.set_metadata((*metadata).clone());
+        }
+    }
+}
+
 /// A Parquet reader on top of the async object_store API. Only the batch reader is implemented since
 /// parquet files on cloud storage tend to be big and slow to access.
 #[cfg(feature = "cloud")]
@@ -220,6 +336,10 @@ pub struct ParquetAsyncReader {
     hive_partition_columns: Option<Vec<Series>>,
     schema: Option<ArrowSchemaRef>,
     parallel: ParallelStrategy,
+    low_memory: bool,
+    max_decoders: Option<usize>,
+    nested_projection: NestedProjection,
+    prefetched_row_groups: Vec<PrefetchedRowGroup>,
 }
 
 #[cfg(feature = "cloud")]
@@ -241,6 +361,10 @@ impl ParquetAsyncReader {
             hive_partition_columns: None,
             schema,
             parallel: Default::default(),
+            low_memory: false,
+            max_decoders: None,
+            nested_projection: Default::default(),
+            prefetched_row_groups: vec![],
         })
     }
 
@@ -279,6 +403,19 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Only read the projected fields of nested columns.
+    pub fn with_nested_projection(mut self, nested_projection: NestedProjection) -> Self {
+        self.nested_projection = nested_projection;
+        self
+    }
+
+    /// Read encrypted files with these keys. This must be set before the metadata is
+    /// fetched.
+    pub fn with_decryption(mut self, decryption: Option<Arc<FileDecryptionProperties>>) -> Self {
+        self.reader.set_decryption(decryption);
+        self
+    }
+
     pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
         self.predicate = predicate;
         self
@@ -301,12 +438,34 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Try to reduce memory pressure at the expense of performance.
+    ///
+    /// In low memory mode at most a single row group is fetched and decoded at a time.
+    pub fn set_low_memory(mut self, low_memory: bool) -> Self {
+        self.low_memory = low_memory;
+        self
+    }
+
+    /// Fetch and decode at most `max_decoders` row groups at the same time. This bounds the
+    /// memory used for decoding, and takes precedence over low memory mode.
+    pub fn set_max_decoders(mut self, max_decoders: Option<usize>) -> Self {
+        self.max_decoders = max_decoders;
+        self
+    }
+
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
         let schema = match self.schema {
             Some(schema) => schema,
             None => self.schema().await?,
         };
+        let schema = project_nested_fields(schema, &self.nested_projection);
+        let parallel =
+            if max_concurrent_decoders(self.low_memory, self.max_decoders, usize::MAX) == 1 {
+                self.parallel.single_decoder()
+            } else {
+                self.parallel
+            };
         // row group fetched deals with projection
         let row_group_fetcher = FetchRowGroupsFromObjectStore::new(
             self.reader,
@@ -315,6 +474,7 @@ impl ParquetAsyncReader {
             self.predicate.clone(),
             &metadata.row_groups,
             self.n_rows.unwrap_or(usize::MAX),
+            self.prefetched_row_groups,
         )?
         .into();
         BatchedParquetReader::new(
@@ -328,7 +488,7 @@ impl ParquetAsyncReader {
             chunk_size,
             self.use_statistics,
             self.hive_partition_columns,
-            self.parallel,
+            parallel,
         )
     }
 
@@ -336,18 +496,56 @@ impl ParquetAsyncReader {
         self.reader.get_metadata().await
     }
 
+    /// The compressed size of the row group `row_group`, or only of its `columns`. This is
+    /// the number of bytes that [`Self::prefetch_row_group`] downloads.
+    pub async fn row_group_size(
+        &mut self,
+        row_group: usize,
+        columns: Option<&[String]>,
+    ) -> PolarsResult<usize> {
+        let fields = columns.map(to_fields);
+        let metadata = self.get_metadata().await?;
+        Ok(metadata
+            .row_groups
+            .get(row_group)
+            .map_or(0, |rg| column_chunks_size(rg, fields.as_deref())))
+    }
+
+    /// Download the row group `row_group`, or only its `columns`, ahead of reading, e.g. while
+    /// other files are read. The downloaded data is only used if it contains all the columns
+    /// that are read.
+    pub async fn prefetch_row_group(
+        &mut self,
+        row_group: usize,
+        columns: Option<&[String]>,
+    ) -> PolarsResult<()> {
+        let fields = columns.map(to_fields);
+        let metadata = self.get_metadata().await?.clone();
+        let Some(rg) = metadata.row_groups.get(row_group) else {
+            return Ok(());
+        };
+        let columns = fetch_column_chunks(&self.reader, rg, fields.as_deref()).await?;
+        self.prefetched_row_groups.push(PrefetchedRowGroup {
+            index: row_group,
+            fields,
+            columns,
+        });
+        Ok(())
+    }
+
     pub async fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
         let metadata = self.get_metadata().await?.clone();
-        let reader_schema = self.schema().await?;
+        let reader_schema = project_nested_fields(self.schema().await?, &self.nested_projection);
         let row_index = self.row_index.clone();
         let hive_partition_columns = self.hive_partition_columns.clone();
         let projection = self.projection.clone();
+        let n_batches = metadata.row_groups.len();
+        let max_decoders = max_concurrent_decoders(self.low_memory, self.max_decoders, n_batches);
 
         // batched reader deals with slice pushdown
         let reader = self.batched(usize::MAX).await?;
-        let n_batches = metadata.row_groups.len();
-        let mut iter = reader.iter(n_batches);
+        let mut iter = reader.iter(max_decoders);
 
         let mut chunks = Vec::with_capacity(n_batches);
         while let Some(result) = iter.next_().await {
@@ -369,3 +567,14 @@ impl ParquetAsyncReader {
         Ok(df)
     }
 }
+
+fn project_nested_fields(
+    schema: ArrowSchemaRef,
+    nested_projection: &NestedProjection,
+) -> ArrowSchemaRef {
+    if nested_projection.is_empty() {
+        schema
+    } else {
+        Arc::new(nested_projection.project_schema(&schema))
+    }
+}
@@ -0,0 +1,307 @@
+//! A sidecar file with the statistics of the row groups of the files of a dataset.
+//!
+//! The file is a parquet file with a row per row group, in the root directory of the dataset.
+//! A scan of many files reads it once, and skips the files whose row groups can't have rows
+//! for which its predicate holds, without reading their footers.
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use polars_core::prelude::*;
+
+use crate::mmap::MmapBytesReader;
+use crate::parquet::read::ParquetReader;
+use crate::parquet::write::ParquetWriter;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::SerReader;
+
+/// The name of the statistics file in the root directory of a dataset.
+pub const STATISTICS_FILE_NAME: &str = "_statistics";
+
+const PATH: &str = "path";
+const FILE_SIZE: &str = "file_size";
+const FILE_MODIFIED: &str = "file_modified";
+const NUM_ROWS: &str = "num_rows";
+const MIN: &str = "min";
+const MAX: &str = "max";
+const NULL_COUNT: &str = "null_count";
+const NDV: &str = "ndv";
+
+/// The name of the column with the statistic `stat` of `column`. The statistics don't contain
+/// `:`, so that the names of different columns can't collide.
+fn stats_column_name(column: &str, stat: &str) -> String {
+    format!("{column}:{stat}")
+}
+
+/// Whether the minimum and the maximum of a column are kept.
+fn has_min_max(dtype: &DataType) -> bool {
+    dtype.is_numeric()
+        || dtype.is_temporal()
+        || matches!(
+            dtype,
+            DataType::String | DataType::Binary | DataType::Boolean
+        )
+}
+
+/// The version of a file that statistics were computed for: its size and the time it was
+/// last modified, in nanoseconds since the unix epoch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FileFingerprint {
+    pub size: u64,
+    /// `None` if the file system doesn't record it.
+    pub modified: Option<i64>,
+}
+
+impl FileFingerprint {
+    /// The fingerprint of the file at `path` as it is now.
+    pub fn of(path: &Path) -> PolarsResult<Self> {
+        let metadata = std::fs::metadata(path)?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .and_then(|modified| i64::try_from(modified.as_nanos()).ok());
+        Ok(Self {
+            size: metadata.len(),
+            modified,
+        })
+    }
+}
+
+/// The fingerprint and the indices of the row groups of every file, by path.
+fn index_files(
+    row_groups: &DataFrame,
+) -> PolarsResult<PlHashMap<String, (FileFingerprint, Vec<usize>)>> {
+    let paths = row_groups.column(PATH)?.str()?;
+    let sizes = row_groups.column(FILE_SIZE)?.u64()?;
+    let modified = row_groups.column(FILE_MODIFIED)?.i64()?;
+
+    let mut files = PlHashMap::<String, (FileFingerprint, Vec<usize>)>::new();
+    for (i, ((path, size), modified)) in paths.into_iter().zip(sizes).zip(modified).enumerate() {
+        let (Some(path), Some(size)) = (path, size) else {
+            polars_bail!(ComputeError: "invalid statistics file: a row group has no file")
+        };
+        let fingerprint = FileFingerprint { size, modified };
+        let (_, row_groups) = files
+            .entry(path.to_string())
+            .or_insert_with(|| (fingerprint, vec![]));
+        row_groups.push(i);
+    }
+    Ok(files)
+}
+
+/// The statistics of the row groups of the files of a dataset.
+///
+/// Every row group has the null count and the number of distinct values of every column, and
+/// the minimum and maximum of the columns that can be ordered. Every file also has its
+/// [`FileFingerprint`], so that the statistics of files that were replaced since they were
+/// written aren't used.
+#[derive(Clone, Debug, Default)]
+pub struct DatasetStatistics {
+    /// A row per row group, with the path and the fingerprint of its file.
+    row_groups: DataFrame,
+    /// The fingerprint and the row groups of every file, by path.
+    files: PlHashMap<String, (FileFingerprint, Vec<usize>)>,
+}
+
+impl DatasetStatistics {
+    /// The statistics of `df`, which is written as a single row group, as a frame with a
+    /// single row. These are added to the statistics of a dataset with
+    /// [`DatasetStatistics::push_file`].
+    pub fn row_group_statistics(df: &DataFrame) -> PolarsResult<DataFrame> {
+        let mut columns =
+            vec![IdxCa::from_slice(NUM_ROWS, &[df.height() as IdxSize]).into_series()];
+        for s in df.get_columns() {
+            let name = s.name();
+            if has_min_max(s.dtype()) {
+                let mut min = s.min_as_series()?;
+                min.rename(&stats_column_name(name, MIN));
+                let mut max = s.max_as_series()?;
+                max.rename(&stats_column_name(name, MAX));
+                columns.extend([min, max]);
+            }
+            let null_count = s.null_count() as IdxSize;
+            // Nested columns may not support counting their distinct values.
+            let ndv = s.drop_nulls().n_unique().ok().map(|n| n as IdxSize);
+            columns.push(Series::new(
+                &stats_column_name(name, NULL_COUNT),
+                &[null_count],
+            ));
+            columns.push(Series::new(&stats_column_name(name, NDV), &[ndv]));
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Add the statistics of the row groups of a file. `path` is the path of the file
+    /// relative to the directory of the statistics file, with `/` as separator, and
+    /// `fingerprint` is the fingerprint of the file once it is written. The statistics of a
+    /// file that was written to the same path before are replaced.
+    pub fn push_file(
+        &mut self,
+        path: &str,
+        fingerprint: FileFingerprint,
+        row_groups: &[DataFrame],
+    ) -> PolarsResult<()> {
+        if self.files.contains_key(path) {
+            self.remove_files(|file| file == path)?;
+        }
+        for row_group in row_groups {
+            let mut row_group = row_group.clone();
+            row_group.insert_column(0, Series::new(PATH, &[path]))?;
+            row_group.insert_column(1, Series::new(FILE_SIZE, &[fingerprint.size]))?;
+            row_group.insert_column(2, Series::new(FILE_MODIFIED, &[fingerprint.modified]))?;
+
+            let (_, file_row_groups) = self
+                .files
+                .entry(path.to_string())
+                .or_insert_with(|| (fingerprint, vec![]));
+            file_row_groups.push(self.row_groups.height());
+            if self.row_groups.width() == 0 {
+                self.row_groups = row_group;
+            } else {
+                self.row_groups.vstack_mut(&row_group)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the statistics of the files for which `predicate` holds, such as the files of
+    /// partitions that are replaced.
+    pub fn remove_files<F: Fn(&str) -> bool>(&mut self, predicate: F) -> PolarsResult<()> {
+        if !self.files.keys().any(|path| predicate(path)) {
+            return Ok(());
+        }
+        let keep = self
+            .row_groups
+            .column(PATH)?
+            .str()?
+            .into_iter()
+            .map(|path| path.map_or(true, |path| !predicate(path)))
+            .collect::<BooleanChunked>();
+        self.row_groups = self.row_groups.filter(&keep)?;
+        self.files = index_files(&self.row_groups)?;
+        Ok(())
+    }
+
+    /// The number of row groups.
+    pub fn len(&self) -> usize {
+        self.row_groups.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write the statistics as a parquet file. Returns the size of the file.
+    pub fn write<W: Write>(&mut self, writer: W) -> PolarsResult<u64> {
+        ParquetWriter::new(writer).finish(&mut self.row_groups)
+    }
+
+    /// Read the statistics from a statistics file.
+    pub fn read<R: MmapBytesReader>(reader: R) -> PolarsResult<Self> {
+        let mut row_groups = ParquetReader::new(reader).finish()?;
+        // Files written before the modification times were stored have none.
+        if row_groups.column(FILE_MODIFIED).is_err() {
+            let modified = Int64Chunked::full_null(FILE_MODIFIED, row_groups.height());
+            row_groups.insert_column(2, modified.into_series())?;
+        }
+        let files = index_files(&row_groups)?;
+        Ok(Self { row_groups, files })
+    }
+
+    /// The statistics file in the root directory of the dataset of `paths`: the directory
+    /// that contains all of them, above the Hive partition directories, `key=value`, that
+    /// contain all of them.
+    pub fn find(paths: &[PathBuf]) -> Option<PathBuf> {
+        let mut dir = paths.first()?.parent()?;
+        for path in &paths[1..] {
+            while !path.starts_with(dir) {
+                dir = dir.parent()?;
+            }
+        }
+        while dir
+            .file_name()
+            .map_or(false, |name| name.to_string_lossy().contains('='))
+        {
+            dir = dir.parent()?;
+        }
+        Some(dir.join(STATISTICS_FILE_NAME)).filter(|path| path.is_file())
+    }
+
+    /// The path of `path` relative to `root`, the directory of the statistics file, with `/`
+    /// as separator, as it is stored in the statistics file.
+    pub fn relative_path(root: &Path, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(root).ok()?;
+        let components = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>();
+        Some(components.join("/"))
+    }
+
+    /// Whether the file at `path`, relative to the directory of the statistics file, can be
+    /// skipped: none of its row groups can have rows for which `predicate` holds.
+    ///
+    /// Files without statistics, or whose fingerprint isn't `fingerprint` anymore, can't be
+    /// skipped, nor can files whose modification time isn't known. `file_stats` are
+    /// statistics that hold for the whole file, such as the values of its hive partition
+    /// columns.
+    pub fn can_skip_file(
+        &self,
+        path: &str,
+        fingerprint: FileFingerprint,
+        predicate: &dyn PhysicalIoExpr,
+        schema: &Schema,
+        file_stats: &[ColumnStats],
+    ) -> PolarsResult<bool> {
+        let Some(evaluator) = predicate.as_stats_evaluator() else {
+            return Ok(false);
+        };
+        let Some((written, row_groups)) = self.files.get(path) else {
+            return Ok(false);
+        };
+        if *written != fingerprint || fingerprint.modified.is_none() {
+            return Ok(false);
+        }
+
+        // The statistics of the columns of the schema, if they have any.
+        let columns = schema
+            .iter_fields()
+            .filter_map(|field| {
+                let stats = |stat| {
+                    self.row_groups
+                        .column(&stats_column_name(field.name(), stat))
+                };
+                let null_count = stats(NULL_COUNT).ok()?;
+                let min_max = stats(MIN)
+                    .ok()
+                    .zip(stats(MAX).ok())
+                    .filter(|(min, _)| min.dtype() == field.data_type());
+                Some((field, null_count, min_max))
+            })
+            .collect::<Vec<_>>();
+        let num_rows = self.row_groups.column(NUM_ROWS)?.idx()?;
+
+        for &i in row_groups {
+            let slice = |s: &Series| s.slice(i as i64, 1);
+            let mut stats = columns
+                .iter()
+                .map(|(field, null_count, min_max)| {
+                    let (min, max) = min_max.map(|(min, max)| (slice(min), slice(max))).unzip();
+                    ColumnStats::new(field.clone(), Some(slice(null_count)), min, max)
+                })
+                .collect::<Vec<_>>();
+            stats.extend(file_stats.iter().cloned());
+            // The statistics are looked up by the index of their column in the schema.
+            let schema = stats
+                .iter()
+                .map(|stats| Field::new(stats.field_name(), stats.dtype().clone()))
+                .collect::<Schema>();
+            let num_rows = num_rows.get(i).map(|n| n as usize);
+            if evaluator.should_read(&BatchStats::new(Arc::new(schema), stats, num_rows))? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
@@ -8,39 +8,58 @@ use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    array_to_columns, compress, CompressedPage, Compressor, DynIter, DynStreamingIterator,
-    Encoding, FallibleStreamingIterator, FileWriter, ParquetType, RowGroupIter, SchemaDescriptor,
-    WriteOptions,
+    array_to_columns, compress, to_parquet_leaves, CompressedPage, CompressionOptions, Compressor,
+    DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator, FileWriter, KeyValue,
+    ParquetType, RowGroupIter, SchemaDescriptor, ThriftFileMetaData, WriteOptions,
 };
 use rayon::prelude::*;
 
+use crate::parquet::bloom_filter::build_bloom_filter;
+
+/// The bitsets of the bloom filters of the leaf columns of a row group. Empty if no bloom
+/// filters are written.
+pub type BloomFilters = Vec<Option<Vec<u8>>>;
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
     pub(super) writer: Mutex<FileWriter<W>>,
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
+    /// The compression of every field, which overrides the compression of the options.
+    pub(super) compressions: Vec<CompressionOptions>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
+    pub(super) bloom_filters: bool,
+    /// The custom key-value metadata of the footer.
+    pub(super) key_value_metadata: Option<Vec<KeyValue>>,
 }
 
 impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIter<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<(RowGroupIter<'static, PolarsError>, BloomFilters)>> + 'a
+    {
         let rb_iter = df.iter_chunks(true);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
+                let fields = self.parquet_schema.fields();
+                let bloom_filters = if self.bloom_filters {
+                    create_bloom_filters(&batch, fields, false)
+                } else {
+                    Ok(vec![])
+                };
                 let row_group = create_eager_serializer(
                     batch,
-                    self.parquet_schema.fields(),
+                    fields,
                     self.encodings.as_ref(),
+                    &self.compressions,
                     self.options,
                 );
 
-                Some(row_group)
+                Some(row_group.and_then(|row_group| Ok((row_group, bloom_filters?))))
             },
         })
     }
@@ -54,13 +73,16 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.compressions,
             self.options,
             self.parallel,
+            self.bloom_filters,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters) = group?;
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -71,12 +93,12 @@ impl<W: Write> BatchedWriter<W> {
 
     pub fn write_row_groups(
         &self,
-        rgs: Vec<RowGroupIter<'static, PolarsError>>,
+        rgs: Vec<(RowGroupIter<'static, PolarsError>, BloomFilters)>,
     ) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
-        for group in rgs {
-            writer.write(group)?;
+        for (group, bloom_filters) in rgs {
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -84,9 +106,14 @@ impl<W: Write> BatchedWriter<W> {
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn finish(&self) -> PolarsResult<u64> {
         let mut writer = self.writer.lock().unwrap();
-        let size = writer.end(None)?;
+        let size = writer.end(self.key_value_metadata.clone())?;
         Ok(size)
     }
+
+    /// The metadata of the file, which is available once [`Self::finish`] has been called.
+    pub fn metadata(&self) -> Option<ThriftFileMetaData> {
+        self.writer.lock().unwrap().metadata().cloned()
+    }
 }
 
 // Note that the df should be rechunked
@@ -94,29 +121,82 @@ fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    compressions: &'a [CompressionOptions],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIter<'static, PolarsError>>> + 'a {
+    bloom_filters: bool,
+) -> impl Iterator<Item = PolarsResult<(RowGroupIter<'static, PolarsError>, BloomFilters)>> + 'a {
     let rb_iter = df.iter_chunks(true);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
+            let fields = parquet_schema.fields();
+            let bloom_filters = if bloom_filters {
+                create_bloom_filters(&batch, fields, parallel)
+            } else {
+                Ok(vec![])
+            };
             let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+                create_serializer(batch, fields, encodings, compressions, options, parallel);
 
-            Some(row_group)
+            Some(row_group.and_then(|row_group| Ok((row_group, bloom_filters?))))
         },
     })
 }
 
+/// Build the bloom filters of the leaf columns of a row group. The leaves of nested columns
+/// don't get a bloom filter, as their values don't correspond to rows.
+fn create_bloom_filters(
+    batch: &RecordBatch<Box<dyn Array>>,
+    fields: &[ParquetType],
+    parallel: bool,
+) -> PolarsResult<BloomFilters> {
+    let func = |(array, type_): (&ArrayRef, &ParquetType)| -> PolarsResult<BloomFilters> {
+        Ok(match type_ {
+            ParquetType::PrimitiveType(_) => {
+                let s = Series::try_from(("", array.clone()))?;
+                vec![build_bloom_filter(&s)]
+            },
+            ParquetType::GroupType { .. } => vec![None; to_parquet_leaves(type_.clone()).len()],
+        })
+    };
+
+    let bloom_filters = if parallel {
+        POOL.install(|| {
+            batch
+                .columns()
+                .par_iter()
+                .zip(fields)
+                .map(func)
+                .collect::<PolarsResult<Vec<_>>>()
+        })?
+    } else {
+        batch
+            .columns()
+            .iter()
+            .zip(fields)
+            .map(func)
+            .collect::<PolarsResult<Vec<_>>>()?
+    };
+    Ok(bloom_filters.into_iter().flatten().collect())
+}
+
 fn create_serializer(
     batch: RecordBatch<Box<dyn Array>>,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
     parallel: bool,
 ) -> PolarsResult<RowGroupIter<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| {
+        let options = WriteOptions {
+            compression: *compression,
+            ..options
+        };
         let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
 
         encoded_columns
@@ -150,6 +230,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(compressions)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -159,6 +240,7 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(compressions)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -202,9 +284,17 @@ fn create_eager_serializer(
     batch: RecordBatch<Box<dyn Array>>,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
 ) -> PolarsResult<RowGroupIter<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| {
+        let options = WriteOptions {
+            compression: *compression,
+            ..options
+        };
         let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
 
         encoded_columns
@@ -231,6 +321,7 @@ fn create_eager_serializer(
         .iter()
         .zip(fields)
         .zip(encodings)
+        .zip(compressions)
         .flat_map(func)
         .collect::<Vec<_>>();
 
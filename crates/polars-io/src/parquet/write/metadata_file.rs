@@ -0,0 +1,38 @@
+use std::io::Write;
+
+use polars_error::{polars_bail, polars_ensure, PolarsResult};
+use polars_parquet::write::{write_metadata_sidecar, ThriftFileMetaData};
+
+/// Write a `_metadata` file with the schema and the row groups of a dataset of several files.
+///
+/// `files` are the paths of the files, relative to the directory of the `_metadata` file, and
+/// their metadata, which must have the same schema. Returns the size of the `_metadata` file.
+pub fn write_metadata_file<W: Write>(
+    writer: &mut W,
+    files: Vec<(String, ThriftFileMetaData)>,
+) -> PolarsResult<u64> {
+    let mut files = files.into_iter();
+    let Some((path, mut metadata)) = files.next() else {
+        polars_bail!(ComputeError: "a `_metadata` file needs the metadata of at least one file")
+    };
+    set_file_path(&mut metadata, &path);
+    for (path, mut file_metadata) in files {
+        polars_ensure!(
+            file_metadata.schema == metadata.schema,
+            SchemaMismatch: "the file '{}' has another schema than the other files of the dataset",
+            path
+        );
+        set_file_path(&mut file_metadata, &path);
+        metadata.num_rows += file_metadata.num_rows;
+        metadata.row_groups.append(&mut file_metadata.row_groups);
+    }
+    Ok(write_metadata_sidecar(writer, &metadata)?)
+}
+
+fn set_file_path(metadata: &mut ThriftFileMetaData, path: &str) {
+    for row_group in &mut metadata.row_groups {
+        for column in &mut row_group.columns {
+            column.file_path = Some(path.to_string());
+        }
+    }
+}
@@ -1,10 +1,15 @@
 //! Functionality for reading and writing Apache Parquet files.
 
 mod batched_writer;
+mod metadata_file;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
-pub use options::{BrotliLevel, GzipLevel, ParquetCompression, ParquetWriteOptions, ZstdLevel};
-pub use polars_parquet::write::RowGroupIter;
+pub use batched_writer::{BatchedWriter, BloomFilters};
+pub use metadata_file::write_metadata_file;
+pub use options::{
+    BrotliLevel, GzipLevel, ParquetColumnWriteOptions, ParquetCompression, ParquetEncoding,
+    ParquetSinkOptions, ParquetSortingColumn, ParquetWriteOptions, ZstdLevel,
+};
+pub use polars_parquet::write::{FileEncryptionProperties, RowGroupIter, ThriftFileMetaData};
 pub use writer::ParquetWriter;
@@ -1,11 +1,15 @@
+use std::collections::BTreeMap;
+
 use polars_error::PolarsResult;
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
+    BrotliLevel as BrotliLevelParquet, CompressionOptions, Encoding, GzipLevel as GzipLevelParquet,
     ZstdLevel as ZstdLevelParquet,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::CommitStrategy;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
@@ -17,8 +21,158 @@ pub struct ParquetWriteOptions {
     pub row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     pub data_pagesize_limit: Option<usize>,
+    /// Write split-block bloom filters of the columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bloom_filters: bool,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// How the file is made visible at its path when it is written by a sink.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub commit_strategy: CommitStrategy,
+}
+
+/// The options of a parquet sink: the [`ParquetWriteOptions`] of its files, and the options
+/// that refer to the columns of the files.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetSinkOptions {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub write_options: ParquetWriteOptions,
+    /// The options of single columns, by name, which override the options of the file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column_options: BTreeMap<String, ParquetColumnWriteOptions>,
+    /// The columns by which the rows of every row group are declared to be sorted.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sorting_columns: Vec<ParquetSortingColumn>,
+    /// Custom key-value metadata that is added to the footer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub key_value_metadata: BTreeMap<String, String>,
+}
+
+impl ParquetSinkOptions {
+    pub fn with_column_options(
+        mut self,
+        column_options: BTreeMap<String, ParquetColumnWriteOptions>,
+    ) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
+    pub fn with_sorting_columns(mut self, sorting_columns: Vec<ParquetSortingColumn>) -> Self {
+        self.sorting_columns = sorting_columns;
+        self
+    }
+
+    pub fn with_key_value_metadata(mut self, key_value_metadata: BTreeMap<String, String>) -> Self {
+        self.key_value_metadata = key_value_metadata;
+        self
+    }
+}
+
+impl From<ParquetWriteOptions> for ParquetSinkOptions {
+    fn from(write_options: ParquetWriteOptions) -> Self {
+        Self {
+            write_options,
+            ..Default::default()
+        }
+    }
+}
+
+/// A column by which the rows of the row groups are sorted, declared in the metadata of the
+/// row groups so that readers can exploit the order. The order isn't checked.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetSortingColumn {
+    /// The name of the column, which can't be nested.
+    pub name: String,
+    /// Whether the column is sorted in descending order.
+    pub descending: bool,
+    /// Whether the nulls come before the values.
+    pub nulls_first: bool,
+}
+
+impl ParquetSortingColumn {
+    /// A column that is sorted in ascending order, with the nulls last.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            descending: false,
+            nulls_first: false,
+        }
+    }
+
+    pub fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
+    pub fn with_nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = nulls_first;
+        self
+    }
+}
+
+/// The options of a single column, which override the options of the file.
+///
+/// The compression of nested columns applies to all their leaves, which are always plain
+/// encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnWriteOptions {
+    /// Whether to dictionary encode the column. If `None`, all columns but the floating point
+    /// columns are dictionary encoded, unless an `encoding` is given.
+    ///
+    /// Dictionary encoding falls back to the plain encoding for columns with many distinct
+    /// values. Categorical columns are always dictionary encoded.
+    pub dictionary: Option<bool>,
+    /// The encoding of the values, which can't be combined with dictionary encoding.
+    pub encoding: Option<ParquetEncoding>,
+    /// The compression of the pages of the column.
+    pub compression: Option<ParquetCompression>,
+}
+
+impl ParquetColumnWriteOptions {
+    pub fn with_dictionary(mut self, dictionary: bool) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    pub fn with_encoding(mut self, encoding: ParquetEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    pub fn with_compression(mut self, compression: ParquetCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+}
+
+/// The encodings of the values of a column that isn't dictionary encoded.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetEncoding {
+    /// The values one after the other. Supported by all columns.
+    Plain,
+    /// The deltas between the values, bit-packed. Supported by integer columns.
+    DeltaBinaryPacked,
+    /// The deltas between the lengths of the values, followed by the values. Supported by
+    /// string and binary columns.
+    DeltaLengthByteArray,
+    /// The bytes of the values split into one stream per byte, which compresses better.
+    /// Supported by floating point columns.
+    ByteStreamSplit,
+}
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        match value {
+            ParquetEncoding::Plain => Encoding::Plain,
+            ParquetEncoding::DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            ParquetEncoding::DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+            ParquetEncoding::ByteStreamSplit => Encoding::ByteStreamSplit,
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
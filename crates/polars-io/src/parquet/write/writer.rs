@@ -1,14 +1,18 @@
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::sync::Mutex;
 
 use arrow::datatypes::PhysicalType;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter, Version, WriteOptions,
+    to_parquet_schema, transverse, CompressionOptions, Encoding, FileEncryptionProperties,
+    FileWriter, KeyValue, SchemaDescriptor, SortingColumn, Version, WriteOptions,
 };
 
 use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::options::{
+    ParquetColumnWriteOptions, ParquetCompression, ParquetEncoding, ParquetSortingColumn,
+};
 use crate::prelude::chunk_df_for_writing;
 
 /// Write a DataFrame to Parquet format.
@@ -25,6 +29,16 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Write split-block bloom filters of the columns.
+    bloom_filters: bool,
+    /// Encrypt the file with these keys.
+    encryption: Option<FileEncryptionProperties>,
+    /// The options of single columns, by name.
+    column_options: BTreeMap<String, ParquetColumnWriteOptions>,
+    /// The columns by which the rows are declared to be sorted.
+    sorting_columns: Vec<ParquetSortingColumn>,
+    /// Custom key-value metadata of the footer.
+    key_value_metadata: BTreeMap<String, String>,
 }
 
 impl<W> ParquetWriter<W>
@@ -43,6 +57,11 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            bloom_filters: false,
+            encryption: None,
+            column_options: BTreeMap::new(),
+            sorting_columns: vec![],
+            key_value_metadata: BTreeMap::new(),
         }
     }
 
@@ -55,7 +74,8 @@ where
         self
     }
 
-    /// Compute and write statistic
+    /// Compute and write statistics. These include the column and offset indexes of the
+    /// pages, which readers use to skip the pages that can't match a predicate.
     pub fn with_statistics(mut self, statistics: bool) -> Self {
         self.statistics = statistics;
         self
@@ -74,6 +94,47 @@ where
         self
     }
 
+    /// Write a split-block bloom filter of every column chunk. Readers use these to skip the
+    /// row groups that can't contain the values of equality and `is_in` predicates.
+    ///
+    /// Bloom filters are written for the integer, temporal, string, binary and categorical
+    /// columns that aren't nested.
+    pub fn with_bloom_filters(mut self, bloom_filters: bool) -> Self {
+        self.bloom_filters = bloom_filters;
+        self
+    }
+
+    /// Encrypt the file with [parquet modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+    ///
+    /// Bloom filters aren't written for the encrypted columns.
+    pub fn with_encryption(mut self, encryption: Option<FileEncryptionProperties>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    /// Set the dictionary encoding, the encoding and the compression of single columns, by
+    /// name. These override the options of the file.
+    pub fn with_column_options(
+        mut self,
+        column_options: BTreeMap<String, ParquetColumnWriteOptions>,
+    ) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
+    /// Declare the columns by which the rows are sorted in the metadata of every row group, so
+    /// that readers can exploit the order. The order isn't checked.
+    pub fn with_sorting_columns(mut self, sorting_columns: Vec<ParquetSortingColumn>) -> Self {
+        self.sorting_columns = sorting_columns;
+        self
+    }
+
+    /// Add custom key-value metadata to the footer of the file.
+    pub fn with_key_value_metadata(mut self, key_value_metadata: BTreeMap<String, String>) -> Self {
+        self.key_value_metadata = key_value_metadata;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -84,17 +145,35 @@ where
         let fields = schema.to_arrow(true).fields;
         let schema = ArrowSchema::from(fields);
 
+        for name in self.column_options.keys() {
+            polars_ensure!(
+                schema.fields.iter().any(|f| &f.name == name),
+                ColumnNotFound: "parquet column options are given for '{}', which isn't a column",
+                name
+            );
+        }
+
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let sorting_columns = get_sorting_columns(&parquet_schema, &self.sorting_columns)?;
+        let key_value_metadata = get_key_value_metadata(self.key_value_metadata)?;
+        let encodings = get_encodings(&schema, &self.column_options)?;
+        let compressions = get_compressions(&schema, self.compression, &self.column_options);
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let mut writer = FileWriter::try_new(self.writer, schema, options)?;
+        if let Some(encryption) = self.encryption {
+            writer = writer.with_encryption(encryption)?;
+        }
+        let writer = Mutex::new(writer.with_sorting_columns(sorting_columns));
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
+            compressions,
             options,
             parallel: self.parallel,
+            bloom_filters: self.bloom_filters,
+            key_value_metadata,
         })
     }
 
@@ -116,19 +195,149 @@ where
     }
 }
 
-fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
+/// Resolve the names of the sorting columns to the indices of their leaf columns.
+fn get_sorting_columns(
+    parquet_schema: &SchemaDescriptor,
+    sorting_columns: &[ParquetSortingColumn],
+) -> PolarsResult<Option<Vec<SortingColumn>>> {
+    if sorting_columns.is_empty() {
+        return Ok(None);
+    }
+    sorting_columns
+        .iter()
+        .map(|column| {
+            let column_idx = parquet_schema
+                .columns()
+                .iter()
+                .position(|c| c.path_in_schema == [column.name.as_str()])
+                .ok_or_else(|| {
+                    polars_err!(
+                        ColumnNotFound: "the sorting column '{}' isn't a column or is nested",
+                        column.name
+                    )
+                })?;
+            Ok(SortingColumn {
+                column_idx,
+                descending: column.descending,
+                nulls_first: column.nulls_first,
+            })
+        })
+        .collect::<PolarsResult<_>>()
+        .map(Some)
+}
+
+fn get_key_value_metadata(
+    key_value_metadata: BTreeMap<String, String>,
+) -> PolarsResult<Option<Vec<KeyValue>>> {
+    if key_value_metadata.is_empty() {
+        return Ok(None);
+    }
+    key_value_metadata
+        .into_iter()
+        .map(|(key, value)| {
+            polars_ensure!(
+                key != "ARROW:schema",
+                InvalidOperation: "the key-value metadata key 'ARROW:schema' is reserved"
+            );
+            Ok(KeyValue {
+                key,
+                value: Some(value),
+            })
+        })
+        .collect::<PolarsResult<_>>()
+        .map(Some)
+}
+
+fn get_encodings(
+    schema: &ArrowSchema,
+    column_options: &BTreeMap<String, ParquetColumnWriteOptions>,
+) -> PolarsResult<Vec<Vec<Encoding>>> {
+    schema
+        .fields
+        .iter()
+        .map(|f| {
+            let options = column_options.get(&f.name).copied().unwrap_or_default();
+            // The pages of the leaves of nested columns are always plain encoded.
+            polars_ensure!(
+                !is_nested(&f.data_type)
+                    || (options.dictionary != Some(true)
+                        && matches!(options.encoding, None | Some(ParquetEncoding::Plain))),
+                InvalidOperation: "the leaves of the nested column '{}' can only be plain encoded",
+                f.name
+            );
+            transverse(&f.data_type, |data_type| {
+                encoding_map(&f.name, data_type, &options)
+            })
+            .into_iter()
+            .collect()
+        })
+        .collect()
+}
+
+fn is_nested(data_type: &ArrowDataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::List
+            | PhysicalType::LargeList
+            | PhysicalType::FixedSizeList
+            | PhysicalType::Struct
+            | PhysicalType::Map
+            | PhysicalType::Union
+    )
+}
+
+/// The compression of every field of `schema`.
+fn get_compressions(
+    schema: &ArrowSchema,
+    compression: CompressionOptions,
+    column_options: &BTreeMap<String, ParquetColumnWriteOptions>,
+) -> Vec<CompressionOptions> {
     schema
         .fields
         .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
+        .map(|f| {
+            column_options
+                .get(&f.name)
+                .and_then(|options| options.compression)
+                .map_or(compression, Into::into)
+        })
         .collect()
 }
 
 /// Declare encodings
-fn encoding_map(data_type: &ArrowDataType) -> Encoding {
-    match data_type.to_physical_type() {
-        PhysicalType::Dictionary(_)
-        | PhysicalType::LargeBinary
+fn encoding_map(
+    name: &str,
+    data_type: &ArrowDataType,
+    options: &ParquetColumnWriteOptions,
+) -> PolarsResult<Encoding> {
+    let physical_type = data_type.to_physical_type();
+    if let PhysicalType::Dictionary(_) = physical_type {
+        polars_ensure!(
+            options.dictionary != Some(false) && options.encoding.is_none(),
+            InvalidOperation: "the categorical column '{}' is always dictionary encoded", name
+        );
+        return Ok(Encoding::RleDictionary);
+    }
+    match (options.dictionary, options.encoding) {
+        (Some(true), Some(_)) => polars_bail!(
+            InvalidOperation: "the dictionary encoding of column '{}' can't be combined with another \
+            encoding", name
+        ),
+        (Some(true), None) => return Ok(Encoding::RleDictionary),
+        (_, Some(encoding)) => {
+            polars_ensure!(
+                supports_encoding(physical_type, encoding),
+                InvalidOperation: "{:?} encoding isn't supported for the column '{}' of type {:?}",
+                encoding, name, data_type
+            );
+            return Ok(encoding.into());
+        },
+        (Some(false), None) => return Ok(Encoding::Plain),
+        (None, None) => {},
+    }
+
+    let encoding = match physical_type {
+        PhysicalType::LargeBinary
         | PhysicalType::LargeUtf8
         | PhysicalType::Utf8View
         | PhysicalType::BinaryView => Encoding::RleDictionary,
@@ -141,5 +350,29 @@ fn encoding_map(data_type: &ArrowDataType) -> Encoding {
         },
         // remaining is plain
         _ => Encoding::Plain,
+    };
+    Ok(encoding)
+}
+
+fn supports_encoding(physical_type: PhysicalType, encoding: ParquetEncoding) -> bool {
+    use arrow::types::PrimitiveType::*;
+    match encoding {
+        ParquetEncoding::Plain => true,
+        ParquetEncoding::DeltaBinaryPacked => matches!(
+            physical_type,
+            PhysicalType::Primitive(
+                Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+            )
+        ),
+        ParquetEncoding::DeltaLengthByteArray => matches!(
+            physical_type,
+            PhysicalType::LargeBinary
+                | PhysicalType::LargeUtf8
+                | PhysicalType::Utf8View
+                | PhysicalType::BinaryView
+        ),
+        ParquetEncoding::ByteStreamSplit => {
+            matches!(physical_type, PhysicalType::Primitive(Float32 | Float64))
+        },
     }
 }
@@ -1,7 +1,12 @@
+use std::borrow::Cow;
+
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "parquet")]
+use crate::parquet::BloomFilter;
+
 pub trait PhysicalIoExpr: Send + Sync {
     /// Take a [`DataFrame`] and produces a boolean [`Series`] that serves
     /// as a predicate mask
@@ -13,13 +18,19 @@ pub trait PhysicalIoExpr: Send + Sync {
     fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
         None
     }
+
+    /// The names of the columns that the predicate reads, if known. Readers can use these to
+    /// decode the other columns only for the rows that pass the predicate.
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        None
+    }
 }
 
 pub trait StatsEvaluator {
     fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool>;
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "orc"))]
 pub fn apply_predicate(
     df: &mut DataFrame,
     predicate: Option<&dyn PhysicalIoExpr>,
@@ -44,7 +55,9 @@ pub fn apply_predicate(
 /// - Null count
 /// - Minimum value
 /// - Maximum value
-#[derive(Debug)]
+///
+/// The column chunks of parquet files may also have a bloom filter.
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
     field: Field,
@@ -52,6 +65,9 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg(feature = "parquet")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bloom_filter: Option<BloomFilter>,
 }
 
 impl ColumnStats {
@@ -67,6 +83,8 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
@@ -77,25 +95,77 @@ impl ColumnStats {
             null_count: None,
             min_value: None,
             max_value: None,
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
     /// Constructs a new [`ColumnStats`] from a single-value Series.
+    ///
+    /// The null count is known, as the Series is either null or not.
     pub fn from_column_literal(s: Series) -> Self {
         debug_assert_eq!(s.len(), 1);
+        let null_count = IdxCa::from_slice("", &[s.null_count() as IdxSize]).into_series();
         Self {
             field: s.field().into_owned(),
-            null_count: None,
+            null_count: Some(null_count),
             min_value: Some(s.clone()),
             max_value: Some(s),
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
+    /// Returns the name of the column.
+    pub fn field_name(&self) -> &str {
+        self.field.name()
+    }
+
     /// Returns the [`DataType`] of the column.
     pub fn dtype(&self) -> &DataType {
         self.field.data_type()
     }
 
+    /// Returns the [`ColumnStats`] of the field `name` of a struct column.
+    ///
+    /// Returns `None` if the column is not a struct or has no such field.
+    #[cfg(feature = "dtype-struct")]
+    pub fn struct_field(&self, name: &str) -> Option<Self> {
+        let DataType::Struct(fields) = self.dtype() else {
+            return None;
+        };
+        let field = fields.iter().find(|fld| fld.name().as_str() == name)?;
+        // The statistics of a struct column are structs of the statistics of its fields.
+        let get_field = |s: &Option<Series>| s.as_ref()?.struct_().ok()?.field_by_name(name).ok();
+        Some(Self {
+            field: field.clone(),
+            null_count: get_field(&self.null_count),
+            min_value: get_field(&self.min_value),
+            max_value: get_field(&self.max_value),
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
+        })
+    }
+
+    /// Sets the bloom filter of the column.
+    #[cfg(feature = "parquet")]
+    pub fn with_bloom_filter(mut self, bloom_filter: BloomFilter) -> Self {
+        self.bloom_filter = Some(bloom_filter);
+        self
+    }
+
+    /// Returns whether the column may contain any of `values`, according to its bloom filter.
+    ///
+    /// Returns `true` if the column has no bloom filter.
+    pub fn may_contain(&self, values: &Series) -> bool {
+        #[cfg(feature = "parquet")]
+        if let Some(bloom_filter) = &self.bloom_filter {
+            return bloom_filter.may_contain(values);
+        }
+        let _ = values;
+        true
+    }
+
     /// Returns the null count of each row group of the column.
     pub fn get_null_count_state(&self) -> Option<&Series> {
         self.null_count.as_ref()
@@ -199,7 +269,8 @@ fn use_min_max(dtype: &DataType) -> bool {
 pub struct BatchStats {
     schema: SchemaRef,
     stats: Vec<ColumnStats>,
-    // This might not be available, as when pruning hive partitions.
+    // This might not be available, as when the schema of hive partitions is given without
+    // values.
     num_rows: Option<usize>,
 }
 
@@ -232,6 +303,30 @@ impl BatchStats {
         self.schema.try_index_of(column).map(|i| &self.stats[i])
     }
 
+    /// Returns the [`ColumnStats`] of a column or of a (nested) field of a struct column,
+    /// given its path, e.g. `["a", "b"]` for the field `b` of the column `a`.
+    ///
+    /// Returns an `Err` if no statistics are available for the given path.
+    pub fn get_field_stats(&self, path: &[&str]) -> PolarsResult<Cow<'_, ColumnStats>> {
+        let (column, fields) = path
+            .split_first()
+            .ok_or_else(|| polars_err!(ColumnNotFound: "empty field path"))?;
+        let mut stats = Cow::Borrowed(self.get_stats(column)?);
+        for name in fields {
+            #[cfg(feature = "dtype-struct")]
+            let field_stats = stats.struct_field(name);
+            #[cfg(not(feature = "dtype-struct"))]
+            let field_stats = {
+                let _ = name;
+                None
+            };
+            stats = Cow::Owned(
+                field_stats.ok_or_else(|| polars_err!(ColumnNotFound: "{}", path.join(".")))?,
+            );
+        }
+        Ok(stats)
+    }
+
     /// Returns the number of rows in the batch.
     ///
     /// Returns `None` if the number of rows is unknown.
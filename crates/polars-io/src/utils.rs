@@ -2,6 +2,7 @@
 use std::borrow::Cow;
 use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use once_cell::sync::Lazy;
 use polars_core::prelude::*;
@@ -10,6 +11,7 @@ use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df_as_r
 use regex::{Regex, RegexBuilder};
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
+use crate::CommitStrategy;
 
 pub fn get_reader_bytes<'a, R: Read + MmapBytesReader + ?Sized>(
     reader: &'a mut R,
@@ -298,6 +300,20 @@ pub(crate) fn chunk_df_for_writing(
     Ok(result)
 }
 
+/// The number of row groups, files or chunks that a reader decodes at the same time.
+///
+/// This is at most `max_decoders` and at most `parallelism`, the number of units that would be
+/// decoded at the same time without a bound. In low memory mode a single unit is decoded at a
+/// time, unless `max_decoders` is set.
+pub fn max_concurrent_decoders(
+    low_memory: bool,
+    max_decoders: Option<usize>,
+    parallelism: usize,
+) -> usize {
+    let bound = max_decoders.unwrap_or(if low_memory { 1 } else { parallelism });
+    bound.clamp(1, parallelism.max(1))
+}
+
 static CLOUD_URL: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^(s3a?|gs|gcs|file|abfss?|azure|az|adl|https?)://").unwrap());
 
@@ -309,11 +325,130 @@ pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
     }
 }
 
+/// The characters that are percent-encoded in the values of Hive partition directories.
+const HIVE_VALUE_ENCODE_SET: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'*')
+    .add(b'/')
+    .add(b':')
+    .add(b'<')
+    .add(b'=')
+    .add(b'>')
+    .add(b'?')
+    .add(b'\\')
+    .add(b'|');
+
+/// The relative directory of a Hive partition, `key=value/...`, with the values of the keys
+/// percent-encoded and the nulls written as `__HIVE_DEFAULT_PARTITION__`.
+pub fn hive_partition_dir<S: AsRef<str>>(keys: &[S], values: &[AnyValue]) -> PathBuf {
+    keys.iter()
+        .zip(values)
+        .map(|(key, value)| {
+            let value = if value.is_null() {
+                "__HIVE_DEFAULT_PARTITION__".to_string()
+            } else {
+                let value = match value.get_str() {
+                    Some(s) => Cow::Borrowed(s),
+                    None => Cow::Owned(value.to_string()),
+                };
+                percent_encoding::utf8_percent_encode(&value, HIVE_VALUE_ENCODE_SET).to_string()
+            };
+            format!("{}={}", key.as_ref(), value)
+        })
+        .collect()
+}
+
+/// The file that is written for a target path according to a [`CommitStrategy`].
+///
+/// With [`CommitStrategy::Atomic`], the file is a temporary file next to the target path,
+/// which is only moved to the target path by [`FileCommit::commit`]. The temporary file is
+/// removed if the commit is dropped before.
+pub struct FileCommit {
+    path: PathBuf,
+    /// The temporary path and a handle to sync the file.
+    pending: Option<(PathBuf, std::fs::File)>,
+}
+
+impl FileCommit {
+    /// Create the file that is written for `path`.
+    pub fn create(path: &Path, strategy: CommitStrategy) -> PolarsResult<(std::fs::File, Self)> {
+        let path = path.to_path_buf();
+        match strategy {
+            CommitStrategy::Direct => {
+                let file = std::fs::File::create(&path)?;
+                Ok((
+                    file,
+                    Self {
+                        path,
+                        pending: None,
+                    },
+                ))
+            },
+            CommitStrategy::Atomic => {
+                let file_name = path.file_name().ok_or_else(
+                    || polars_err!(ComputeError: "'{}' isn't a file path", path.display()),
+                )?;
+                let mut temp_name = std::ffi::OsString::from(".");
+                temp_name.push(file_name);
+                // Sinks in the same process may write the same path at the same time.
+                static TEMP_FILES: AtomicU64 = AtomicU64::new(0);
+                let n = TEMP_FILES.fetch_add(1, Ordering::Relaxed);
+                temp_name.push(format!(".{}.{n}.tmp", std::process::id()));
+                let temp_path = path.with_file_name(temp_name);
+                let file = std::fs::File::create(&temp_path)?;
+                let handle = file.try_clone()?;
+                Ok((
+                    file,
+                    Self {
+                        path,
+                        pending: Some((temp_path, handle)),
+                    },
+                ))
+            },
+        }
+    }
+
+    /// Make the completely written file visible at its target path.
+    pub fn commit(mut self) -> PolarsResult<()> {
+        if let Some((temp_path, file)) = self.pending.take() {
+            file.sync_all()?;
+            std::fs::rename(&temp_path, &self.path)?;
+            // The rename is only durable once the directory that has the file is synced.
+            #[cfg(unix)]
+            {
+                let dir = self
+                    .path
+                    .parent()
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .unwrap_or(Path::new("."));
+                std::fs::File::open(dir)?.sync_all()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for FileCommit {
+    fn drop(&mut self) {
+        if let Some((temp_path, _)) = self.pending.take() {
+            let _ = std::fs::remove_file(temp_path);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
-    use super::{resolve_homedir, FLOAT_RE};
+    use polars_core::prelude::*;
+
+    use super::{
+        hive_partition_dir, max_concurrent_decoders, resolve_homedir, FileCommit, FLOAT_RE,
+    };
+    use crate::CommitStrategy;
 
     #[test]
     fn test_float_parse() {
@@ -373,4 +508,70 @@ mod tests {
         assert!(resolved[1].is_absolute());
         assert!(resolved[2].is_absolute());
     }
+
+    #[test]
+    fn test_hive_partition_dir() {
+        let dir = hive_partition_dir(
+            &["a", "b", "c"],
+            &[
+                AnyValue::Int32(1),
+                AnyValue::String("x/y z"),
+                AnyValue::Null,
+            ],
+        );
+        assert_eq!(
+            dir,
+            PathBuf::from("a=1/b=x%2Fy%20z/c=__HIVE_DEFAULT_PARTITION__")
+        );
+    }
+
+    #[test]
+    fn test_max_concurrent_decoders() {
+        // Without a bound all units are decoded at the same time.
+        assert_eq!(max_concurrent_decoders(false, None, 8), 8);
+        // Low memory mode decodes a single unit at a time.
+        assert_eq!(max_concurrent_decoders(true, None, 8), 1);
+        // An explicit bound takes precedence over low memory mode.
+        assert_eq!(max_concurrent_decoders(true, Some(3), 8), 3);
+        assert_eq!(max_concurrent_decoders(false, Some(3), 8), 3);
+        // The bound never exceeds the parallelism and always allows a decoder.
+        assert_eq!(max_concurrent_decoders(false, Some(16), 8), 8);
+        assert_eq!(max_concurrent_decoders(false, Some(0), 8), 1);
+        assert_eq!(max_concurrent_decoders(false, None, 0), 1);
+    }
+
+    #[test]
+    fn test_file_commit() -> PolarsResult<()> {
+        use std::io::Write;
+
+        let dir = std::env::temp_dir().join("polars_test_file_commit");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("out.csv");
+
+        let (mut file, commit) = FileCommit::create(&path, CommitStrategy::Atomic)?;
+        file.write_all(b"a\n1\n")?;
+        assert!(!path.exists());
+        commit.commit()?;
+        assert_eq!(std::fs::read(&path)?, b"a\n1\n");
+
+        // A commit that is dropped leaves the target path as is.
+        let (mut file, commit) = FileCommit::create(&path, CommitStrategy::Atomic)?;
+        file.write_all(b"partial")?;
+        drop(commit);
+        assert_eq!(std::fs::read(&path)?, b"a\n1\n");
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+
+        // Writes of the same path don't share their temporary file.
+        let (mut first, first_commit) = FileCommit::create(&path, CommitStrategy::Atomic)?;
+        let (mut second, second_commit) = FileCommit::create(&path, CommitStrategy::Atomic)?;
+        first.write_all(b"a\n2\n")?;
+        second.write_all(b"a\n3\n")?;
+        first_commit.commit()?;
+        assert_eq!(std::fs::read(&path)?, b"a\n2\n");
+        second_commit.commit()?;
+        assert_eq!(std::fs::read(&path)?, b"a\n3\n");
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+        Ok(())
+    }
 }
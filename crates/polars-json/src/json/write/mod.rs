@@ -12,7 +12,31 @@ pub use fallible_streaming_iterator::*;
 use polars_error::{PolarsError, PolarsResult};
 pub(crate) use serialize::new_serializer;
 use serialize::serialize;
-pub use utf8::serialize_to_utf8;
+pub use utf8::{serialize_to_utf8, serialize_to_utf8_with_options};
+
+/// Options to serialize arrays to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct JsonEncodeOptions {
+    /// The `strftime` format of dates, e.g. `2000-01-29` by default.
+    pub date_format: Option<String>,
+    /// The `strftime` format of datetimes, e.g. `2000-01-29 10:30:00` by default, or in
+    /// RFC 3339 for datetimes with a time zone.
+    pub datetime_format: Option<String>,
+    /// Leave out the fields of structs that are null instead of writing them as `null`.
+    pub skip_nulls: bool,
+    /// Write the fields of structs sorted by name instead of in the order of their dtype.
+    pub sort_keys: bool,
+}
+
+/// The options of the serializers that don't take any.
+static DEFAULT_OPTIONS: JsonEncodeOptions = JsonEncodeOptions {
+    date_format: None,
+    datetime_format: None,
+    skip_nulls: false,
+    sort_keys: false,
+};
 
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
@@ -88,7 +112,7 @@ impl<'a> RecordSerializer<'a> {
         let iterators = chunk
             .arrays()
             .iter()
-            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX))
+            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX, &DEFAULT_OPTIONS))
             .collect();
 
         Self {
@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use std::io::Write;
 
 use arrow::array::*;
@@ -13,10 +14,12 @@ use arrow::temporal_conversions::{
     timestamp_s_to_datetime, timestamp_to_datetime, timestamp_us_to_datetime,
 };
 use arrow::types::NativeType;
-use chrono::{Duration, NaiveDate, NaiveDateTime};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
+use polars_error::{polars_err, PolarsResult};
 use streaming_iterator::StreamingIterator;
 
-use super::utf8;
+use super::{utf8, JsonEncodeOptions};
 
 fn write_integer<I: itoa::Integer>(buf: &mut Vec<u8>, val: I) {
     let mut buffer = itoa::Buffer::new();
@@ -30,6 +33,27 @@ fn write_float<I: ryu::Float>(f: &mut Vec<u8>, val: I) {
     f.extend_from_slice(value.as_bytes())
 }
 
+/// Parse a `strftime` format that has been validated by [`check_formats`].
+fn parse_format(format: &str) -> Vec<Item<'_>> {
+    StrftimeItems::new(format)
+        .parse()
+        .expect("the format is checked before serializing")
+}
+
+/// Write `value`, formatted with a user-defined format, as a JSON string.
+fn write_formatted(buf: &mut Vec<u8>, value: impl Display) {
+    let start = buf.len();
+    write!(buf, "{value}").unwrap();
+    // The literal characters of a format may need escaping.
+    if utf8::find_escape(&buf[start..]) < buf.len() - start {
+        let value = String::from_utf8(buf.split_off(start)).unwrap();
+        utf8::write_str(buf, &value).unwrap();
+    } else {
+        buf.insert(start, b'"');
+        buf.push(b'"');
+    }
+}
+
 fn materialize_serializer<'a, I, F, T>(
     f: F,
     iterator: I,
@@ -162,6 +186,7 @@ fn struct_serializer<'a>(
     array: &'a StructArray,
     offset: usize,
     take: usize,
+    options: &'a JsonEncodeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -170,27 +195,38 @@ fn struct_serializer<'a>(
     //  {"a": 3, "b": c, "c": {"a": 3}},
     // ]
     //
-    let mut serializers = array
-        .values()
+    let mut fields = array
+        .fields()
         .iter()
-        .map(|x| x.as_ref())
-        .map(|arr| new_serializer(arr, offset, take))
+        .zip(array.values())
+        .map(|(field, arr)| {
+            (
+                field.name.as_str(),
+                new_serializer(arr.as_ref(), offset, take, options),
+            )
+        })
         .collect::<Vec<_>>();
+    // The serializers advance independently, so they can be reordered.
+    if options.sort_keys {
+        fields.sort_by_key(|(name, _)| *name);
+    }
+    let (names, mut serializers): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+    let skip_nulls = options.skip_nulls;
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new_with_validity(0..array.len(), array.validity()),
         move |maybe, buf| {
             if maybe.is_some() {
-                let names = array.fields().iter().map(|f| f.name.as_str());
-                serialize_item(
-                    buf,
-                    names.zip(
+                let record = names
+                    .iter()
+                    .copied()
+                    .zip(
                         serializers
                             .iter_mut()
                             .map(|serializer| serializer.next().unwrap()),
-                    ),
-                    true,
-                );
+                    )
+                    .filter(|(_, value)| !(skip_nulls && *value == b"null"));
+                serialize_item(buf, record, true);
             } else {
                 serializers.iter_mut().for_each(|iter| {
                     let _ = iter.next();
@@ -206,6 +242,7 @@ fn list_serializer<'a, O: Offset>(
     array: &'a ListArray<O>,
     offset: usize,
     take: usize,
+    options: &'a JsonEncodeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // [[1, 2], [3]]
     // [
@@ -216,7 +253,7 @@ fn list_serializer<'a, O: Offset>(
     let offsets = array.offsets().as_slice();
     let start = offsets[0].to_usize();
     let end = offsets.last().unwrap().to_usize();
-    let mut serializer = new_serializer(array.values().as_ref(), start, end - start);
+    let mut serializer = new_serializer(array.values().as_ref(), start, end - start, options);
 
     let f = move |offset: Option<&[O]>, buf: &mut Vec<u8>| {
         if let Some(offset) = offset {
@@ -245,8 +282,9 @@ fn fixed_size_list_serializer<'a>(
     array: &'a FixedSizeListArray,
     offset: usize,
     take: usize,
+    options: &'a JsonEncodeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    let mut serializer = new_serializer(array.values().as_ref(), offset, take);
+    let mut serializer = new_serializer(array.values().as_ref(), offset, take, options);
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new(0..array.len(), array.validity().map(|x| x.iter())),
@@ -274,6 +312,7 @@ fn fixed_size_list_serializer<'a>(
 fn date_serializer<'a, T, F>(
     array: &'a PrimitiveArray<T>,
     convert: F,
+    format: Option<&'a str>,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
@@ -281,10 +320,14 @@ where
     T: NativeType,
     F: Fn(T) -> NaiveDate + 'static + Send + Sync,
 {
+    let items = format.map(parse_format);
     let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let nd = convert(*x);
-            write!(buf, "\"{nd}\"").unwrap();
+            match &items {
+                Some(items) => write_formatted(buf, nd.format_with_items(items.iter())),
+                None => write!(buf, "\"{nd}\"").unwrap(),
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -318,16 +361,21 @@ where
 fn timestamp_serializer<'a, F>(
     array: &'a PrimitiveArray<i64>,
     convert: F,
+    format: Option<&'a str>,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
 where
     F: Fn(i64) -> NaiveDateTime + 'static + Send + Sync,
 {
+    let items = format.map(parse_format);
     let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let ndt = convert(*x);
-            write!(buf, "\"{ndt}\"").unwrap();
+            match &items {
+                Some(items) => write_formatted(buf, ndt.format_with_items(items.iter())),
+                None => write!(buf, "\"{ndt}\"").unwrap(),
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -339,15 +387,20 @@ fn timestamp_tz_serializer<'a>(
     array: &'a PrimitiveArray<i64>,
     time_unit: TimeUnit,
     tz: &str,
+    format: Option<&'a str>,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let items = format.map(parse_format);
     match parse_offset(tz) {
         Ok(parsed_tz) => {
             let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
                 if let Some(x) = x {
-                    let dt_str = timestamp_to_datetime(*x, time_unit, &parsed_tz).to_rfc3339();
-                    write!(buf, "\"{dt_str}\"").unwrap();
+                    let dt = timestamp_to_datetime(*x, time_unit, &parsed_tz);
+                    match &items {
+                        Some(items) => write_formatted(buf, dt.format_with_items(items.iter())),
+                        None => write!(buf, "\"{}\"", dt.to_rfc3339()).unwrap(),
+                    }
                 } else {
                     buf.extend_from_slice(b"null")
                 }
@@ -360,8 +413,11 @@ fn timestamp_tz_serializer<'a>(
             Ok(parsed_tz) => {
                 let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
                     if let Some(x) = x {
-                        let dt_str = timestamp_to_datetime(*x, time_unit, &parsed_tz).to_rfc3339();
-                        write!(buf, "\"{dt_str}\"").unwrap();
+                        let dt = timestamp_to_datetime(*x, time_unit, &parsed_tz);
+                        match &items {
+                            Some(items) => write_formatted(buf, dt.format_with_items(items.iter())),
+                            None => write!(buf, "\"{}\"", dt.to_rfc3339()).unwrap(),
+                        }
                     } else {
                         buf.extend_from_slice(b"null")
                     }
@@ -384,6 +440,7 @@ pub(crate) fn new_serializer<'a>(
     array: &'a dyn Array,
     offset: usize,
     take: usize,
+    options: &'a JsonEncodeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     match array.data_type().to_logical_type() {
         ArrowDataType::Boolean => {
@@ -425,15 +482,24 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Utf8View => {
             utf8view_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Struct(_) => {
-            struct_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::FixedSizeList(_, _) => {
-            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::LargeList(_) => {
-            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Struct(_) => struct_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::FixedSizeList(_, _) => fixed_size_list_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::LargeList(_) => list_serializer::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
         ArrowDataType::Dictionary(k, v, _) => match (k, &**v) {
             (IntegerType::UInt32, ArrowDataType::Utf8View) => {
                 let array = array
@@ -450,6 +516,7 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Date32 => date_serializer(
             array.as_any().downcast_ref().unwrap(),
             date32_to_date,
+            options.date_format.as_deref(),
             offset,
             take,
         ),
@@ -463,6 +530,7 @@ pub(crate) fn new_serializer<'a>(
             timestamp_serializer(
                 array.as_any().downcast_ref().unwrap(),
                 convert,
+                options.datetime_format.as_deref(),
                 offset,
                 take,
             )
@@ -471,6 +539,7 @@ pub(crate) fn new_serializer<'a>(
             array.as_any().downcast_ref().unwrap(),
             *time_unit,
             tz,
+            options.datetime_format.as_deref(),
             offset,
             take,
         ),
@@ -493,6 +562,65 @@ pub(crate) fn new_serializer<'a>(
     }
 }
 
+/// Check that the formats of `options` can format the temporal values of `data_type`, so
+/// that the serializers don't fail on them.
+pub(crate) fn check_formats(
+    data_type: &ArrowDataType,
+    options: &JsonEncodeOptions,
+) -> PolarsResult<()> {
+    fn check(
+        format: &str,
+        description: &str,
+        format_sample: impl Fn(&[Item]) -> std::fmt::Result,
+    ) -> PolarsResult<()> {
+        let err =
+            || polars_err!(ComputeError: "cannot format {description} with format '{format}'");
+        let items = StrftimeItems::new(format).parse().map_err(|_| err())?;
+        format_sample(&items).map_err(|_| err())
+    }
+
+    fn try_display(value: impl Display) -> std::fmt::Result {
+        std::fmt::write(&mut String::new(), format_args!("{value}"))
+    }
+
+    match data_type.to_logical_type() {
+        ArrowDataType::Date32 => {
+            if let Some(format) = &options.date_format {
+                check(format, "Date", |items| {
+                    try_display(NaiveDate::MIN.format_with_items(items.iter()))
+                })?
+            }
+        },
+        ArrowDataType::Timestamp(_, None) => {
+            if let Some(format) = &options.datetime_format {
+                check(format, "NaiveDateTime", |items| {
+                    try_display(NaiveDateTime::MIN.format_with_items(items.iter()))
+                })?
+            }
+        },
+        ArrowDataType::Timestamp(_, Some(_)) => {
+            if let Some(format) = &options.datetime_format {
+                let sample = FixedOffset::east_opt(0)
+                    .unwrap()
+                    .from_utc_datetime(&NaiveDateTime::MIN);
+                check(format, "DateTime", |items| {
+                    try_display(sample.format_with_items(items.iter()))
+                })?
+            }
+        },
+        ArrowDataType::Struct(fields) => {
+            for field in fields {
+                check_formats(&field.data_type, options)?
+            }
+        },
+        ArrowDataType::LargeList(field) | ArrowDataType::FixedSizeList(field, _) => {
+            check_formats(&field.data_type, options)?
+        },
+        _ => {},
+    }
+    Ok(())
+}
+
 fn serialize_item<'a>(
     buffer: &mut Vec<u8>,
     record: impl Iterator<Item = (&'a str, &'a [u8])>,
@@ -519,7 +647,8 @@ fn serialize_item<'a>(
 /// # Implementation
 /// This operation is CPU-bounded
 pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+    let options = JsonEncodeOptions::default();
+    let mut serializer = new_serializer(array, 0, usize::MAX, &options);
 
     (0..array.len()).for_each(|i| {
         if i != 0 {
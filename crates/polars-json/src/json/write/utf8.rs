@@ -2,7 +2,10 @@
 use std::io;
 
 use arrow::array::{Array, MutableBinaryViewArray, Utf8ViewArray};
+use polars_error::PolarsResult;
 
+use super::serialize::check_formats;
+use super::JsonEncodeOptions;
 use crate::json::write::new_serializer;
 
 pub fn write_str<W>(writer: &mut W, value: &str) -> io::Result<()>
@@ -13,21 +16,19 @@ where
     let bytes = value.as_bytes();
 
     let mut start = 0;
+    let mut i = find_escape(bytes);
 
-    for (i, &byte) in bytes.iter().enumerate() {
-        let escape = ESCAPE[byte as usize];
-        if escape == 0 {
-            continue;
-        }
-
+    while i < bytes.len() {
         if start < i {
             writer.write_all(&bytes[start..i])?;
         }
 
-        let char_escape = CharEscape::from_escape_table(escape, byte);
+        let byte = bytes[i];
+        let char_escape = CharEscape::from_escape_table(ESCAPE[byte as usize], byte);
         write_char_escape(writer, char_escape)?;
 
         start = i + 1;
+        i = start + find_escape(&bytes[start..]);
     }
 
     if start != bytes.len() {
@@ -36,6 +37,38 @@ where
     writer.write_all(b"\"")
 }
 
+/// The index of the first byte of `bytes` that needs escaping, or the length of `bytes` if
+/// there is none.
+///
+/// Checks 8 bytes at a time with bitwise operations on `u64`s, as most strings don't need
+/// escaping at all.
+pub(crate) fn find_escape(bytes: &[u8]) -> usize {
+    const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+    const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; 8]);
+    /// Whether any byte of `x` is smaller than `n`, for `n <= 0x80`.
+    #[inline]
+    fn has_less(x: u64, n: u8) -> bool {
+        x.wrapping_sub(ONES * n as u64) & !x & HIGH_BITS != 0
+    }
+    #[inline]
+    fn has_byte(x: u64, byte: u8) -> bool {
+        has_less(x ^ (ONES * byte as u64), 1)
+    }
+
+    let mut offset = 0;
+    for chunk in bytes.chunks_exact(8) {
+        let x = u64::from_ne_bytes(chunk.try_into().unwrap());
+        if has_less(x, 0x20) || has_byte(x, b'"') || has_byte(x, b'\\') {
+            break;
+        }
+        offset += 8;
+    }
+    bytes[offset..]
+        .iter()
+        .position(|&byte| ESCAPE[byte as usize] != 0)
+        .map_or(bytes.len(), |i| offset + i)
+}
+
 const BB: u8 = b'b'; // \x08
 const TT: u8 = b't'; // \x09
 const NN: u8 = b'n'; // \x0A
@@ -142,11 +175,20 @@ where
 }
 
 pub fn serialize_to_utf8(array: &dyn Array) -> Utf8ViewArray {
+    serialize_to_utf8_with_options(array, &JsonEncodeOptions::default()).unwrap()
+}
+
+/// Serialize every element of `array` to a JSON value.
+pub fn serialize_to_utf8_with_options(
+    array: &dyn Array,
+    options: &JsonEncodeOptions,
+) -> PolarsResult<Utf8ViewArray> {
+    check_formats(array.data_type(), options)?;
     let mut values = MutableBinaryViewArray::with_capacity(array.len());
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+    let mut serializer = new_serializer(array, 0, usize::MAX, options);
 
     while let Some(v) = serializer.next() {
         unsafe { values.push_value(std::str::from_utf8_unchecked(v)) }
     }
-    values.into()
+    Ok(values.into())
 }
@@ -5,10 +5,11 @@ use arrow::array::Array;
 pub use fallible_streaming_iterator::FallibleStreamingIterator;
 use polars_error::{PolarsError, PolarsResult};
 
-use super::super::json::write::new_serializer;
+use super::super::json::write::{new_serializer, JsonEncodeOptions};
 
 fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+    let options = JsonEncodeOptions::default();
+    let mut serializer = new_serializer(array, 0, usize::MAX, &options);
     (0..array.len()).for_each(|_| {
         buffer.extend_from_slice(serializer.next().unwrap());
         buffer.push(b'\n');
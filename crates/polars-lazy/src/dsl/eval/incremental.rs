@@ -0,0 +1,427 @@
+//! Aggregations that `cumulative_eval` and `rolling_eval` can update row by row
+//! instead of re-evaluating the expression on every window.
+use std::collections::VecDeque;
+
+use polars_core::export::num::{Float, NumCast, WrappingAdd, WrappingSub};
+use polars_core::prelude::*;
+use polars_core::{
+    with_match_physical_float_polars_type, with_match_physical_integer_polars_type,
+    with_match_physical_numeric_polars_type,
+};
+use polars_utils::float::IsFloat;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+pub(super) enum IncrementalAgg {
+    Sum,
+    Min,
+    Max,
+    Mean,
+    Var(u8),
+    Std(u8),
+    NUnique,
+}
+
+impl IncrementalAgg {
+    /// Returns the incremental form of `expr` if it is a supported aggregation
+    /// applied directly to the element.
+    pub(super) fn from_expr(expr: &Expr, dtype: &DataType) -> Option<Self> {
+        let Expr::Agg(agg) = expr else {
+            return None;
+        };
+        match agg.as_ref() {
+            Expr::Column(name) if name.is_empty() => {},
+            Expr::Nth(0) => {},
+            _ => return None,
+        }
+        let numeric = dtype.is_integer() || dtype.is_float();
+        match agg {
+            AggExpr::Sum(_) if numeric => Some(Self::Sum),
+            AggExpr::Min {
+                propagate_nans: false,
+                ..
+            } if numeric => Some(Self::Min),
+            AggExpr::Max {
+                propagate_nans: false,
+                ..
+            } if numeric => Some(Self::Max),
+            AggExpr::Mean(_) if numeric => Some(Self::Mean),
+            AggExpr::Var(_, ddof) if numeric => Some(Self::Var(*ddof)),
+            AggExpr::Std(_, ddof) if numeric => Some(Self::Std(*ddof)),
+            AggExpr::NUnique(_) => Some(Self::NUnique),
+            _ => None,
+        }
+    }
+
+    /// Evaluate the aggregation on every window of `s`. A window ends at its row and
+    /// spans `window_size` rows, or the whole prefix if `window_size` is `None`.
+    /// Windows with fewer than `min_periods` valid values yield null.
+    pub(super) fn evaluate(
+        self,
+        s: &Series,
+        window_size: Option<usize>,
+        min_periods: usize,
+    ) -> PolarsResult<Series> {
+        let windows = Windows::new(s, window_size, min_periods);
+        let out = match self {
+            Self::Sum => {
+                let s = match s.dtype() {
+                    DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
+                        s.cast(&DataType::Int64)?
+                    },
+                    _ => s.clone(),
+                };
+                if s.dtype().is_float() {
+                    with_match_physical_float_polars_type!(s.dtype(), |$T| {
+                        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                        float_sum(ca, &windows).into_series()
+                    })
+                } else {
+                    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                        integer_sum(ca, &windows).into_series()
+                    })
+                }
+            },
+            Self::Min | Self::Max => {
+                with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                    extremum(ca, &windows, matches!(self, Self::Min)).into_series()
+                })
+            },
+            Self::Mean | Self::Var(_) | Self::Std(_) => {
+                let s = s.cast(&DataType::Float64)?;
+                moments(s.f64()?, &windows, self).into_series()
+            },
+            Self::NUnique => n_unique(s, &windows)?.into_series(),
+        };
+        Ok(out)
+    }
+}
+
+/// Bounds of the windows and whether they hold enough valid values.
+struct Windows {
+    len: usize,
+    window_size: Option<usize>,
+    enough: Vec<bool>,
+}
+
+impl Windows {
+    fn new(s: &Series, window_size: Option<usize>, min_periods: usize) -> Self {
+        let valid = s.is_not_null().rechunk();
+        let is_valid = |idx| valid.get(idx).unwrap_or(false) as usize;
+        let mut n_valid = 0usize;
+        let enough = (0..s.len())
+            .map(|idx| {
+                n_valid += is_valid(idx);
+                if let Some(evicted) = window_size.and_then(|w| idx.checked_sub(w)) {
+                    n_valid -= is_valid(evicted);
+                }
+                n_valid >= min_periods
+            })
+            .collect();
+        Self {
+            len: s.len(),
+            window_size,
+            enough,
+        }
+    }
+
+    /// The row that leaves the window when `idx` enters it.
+    fn evicted(&self, idx: usize) -> Option<usize> {
+        self.window_size.and_then(|w| idx.checked_sub(w))
+    }
+}
+
+/// Integer sums wrap on overflow like `sum` does. Wrapping additions and subtractions
+/// cancel exactly, so the sum of a window is exact modulo the width of the type.
+fn integer_sum<T>(ca: &ChunkedArray<T>, windows: &Windows) -> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: WrappingAdd + WrappingSub,
+{
+    let ca = ca.rechunk();
+    let mut acc = T::Native::default();
+    (0..windows.len)
+        .map(|idx| {
+            if let Some(v) = ca.get(idx) {
+                acc = acc.wrapping_add(&v);
+            }
+            if let Some(v) = windows.evicted(idx).and_then(|i| ca.get(i)) {
+                acc = acc.wrapping_sub(&v);
+            }
+            windows.enough[idx].then_some(acc)
+        })
+        .collect()
+}
+
+/// The number of non-finite values in a window, which can't be subtracted from a running
+/// sum once they are added to it.
+#[derive(Default)]
+struct NonFinite {
+    nan: usize,
+    pos_inf: usize,
+    neg_inf: usize,
+}
+
+impl NonFinite {
+    /// Count `v` if it isn't finite, returns whether it is finite.
+    fn update<F: Float>(&mut self, v: F, add: bool) -> bool {
+        let count = if v.is_nan() {
+            &mut self.nan
+        } else if v == F::infinity() {
+            &mut self.pos_inf
+        } else if v == F::neg_infinity() {
+            &mut self.neg_inf
+        } else {
+            return true;
+        };
+        if add {
+            *count += 1;
+        } else {
+            *count -= 1;
+        }
+        false
+    }
+
+    /// The sum of the window if it has non-finite values.
+    fn sum<F: Float>(&self) -> Option<F> {
+        if self.nan > 0 || (self.pos_inf > 0 && self.neg_inf > 0) {
+            Some(F::nan())
+        } else if self.pos_inf > 0 {
+            Some(F::infinity())
+        } else if self.neg_inf > 0 {
+            Some(F::neg_infinity())
+        } else {
+            None
+        }
+    }
+}
+
+/// Only the finite values are added to the running sum, the non-finite ones are counted so
+/// that a NaN or an infinity doesn't affect the windows after it.
+fn float_sum<T>(ca: &ChunkedArray<T>, windows: &Windows) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let ca = ca.rechunk();
+    let mut acc = 0.0f64;
+    let mut non_finite = NonFinite::default();
+    (0..windows.len)
+        .map(|idx| {
+            if let Some(v) = ca.get(idx) {
+                if non_finite.update(v, true) {
+                    acc += v.to_f64().unwrap();
+                }
+            }
+            if let Some(v) = windows.evicted(idx).and_then(|i| ca.get(i)) {
+                if non_finite.update(v, false) {
+                    acc -= v.to_f64().unwrap();
+                }
+            }
+            if !windows.enough[idx] {
+                return None;
+            }
+            Some(
+                non_finite
+                    .sum()
+                    .unwrap_or_else(|| <T::Native as NumCast>::from(acc).unwrap()),
+            )
+        })
+        .collect()
+}
+
+fn extremum<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    windows: &Windows,
+    is_min: bool,
+) -> ChunkedArray<T> {
+    // Whether `a` should replace `b` as the extremum; NaN only wins over NaN.
+    let prefer = |a: T::Native, b: T::Native| {
+        if b.is_nan() {
+            true
+        } else if a.is_nan() {
+            false
+        } else if is_min {
+            a <= b
+        } else {
+            a >= b
+        }
+    };
+    let ca = ca.rechunk();
+    // Candidate rows, each preferred over the ones behind it.
+    let mut candidates = VecDeque::<(usize, T::Native)>::new();
+    let out: ChunkedArray<T> = (0..windows.len)
+        .map(|idx| {
+            if let Some(v) = ca.get(idx) {
+                while candidates.back().is_some_and(|&(_, b)| prefer(v, b)) {
+                    candidates.pop_back();
+                }
+                candidates.push_back((idx, v));
+            }
+            if let Some(evicted) = windows.evicted(idx) {
+                if candidates.front().is_some_and(|&(i, _)| i == evicted) {
+                    candidates.pop_front();
+                }
+            }
+            if windows.enough[idx] {
+                candidates.front().map(|&(_, v)| v)
+            } else {
+                None
+            }
+        })
+        .collect();
+    out
+}
+
+fn moments(ca: &Float64Chunked, windows: &Windows, agg: IncrementalAgg) -> Float64Chunked {
+    let ca = ca.rechunk();
+    // Welford's online algorithm, which can also remove values. Only the finite values are
+    // added to it, the non-finite ones are counted.
+    let (mut n, mut mean, mut m2) = (0usize, 0.0f64, 0.0f64);
+    let mut non_finite = NonFinite::default();
+    (0..windows.len)
+        .map(|idx| {
+            if let Some(x) = ca.get(idx) {
+                if non_finite.update(x, true) {
+                    n += 1;
+                    let delta = x - mean;
+                    mean += delta / n as f64;
+                    m2 += delta * (x - mean);
+                }
+            }
+            if let Some(x) = windows.evicted(idx).and_then(|i| ca.get(i)) {
+                if non_finite.update(x, false) {
+                    n -= 1;
+                    if n == 0 {
+                        (mean, m2) = (0.0, 0.0);
+                    } else {
+                        let delta = x - mean;
+                        mean -= delta / n as f64;
+                        m2 -= delta * (x - mean);
+                    }
+                }
+            }
+            if !windows.enough[idx] {
+                return None;
+            }
+            let n_non_finite = non_finite.nan + non_finite.pos_inf + non_finite.neg_inf;
+            let n_total = n + n_non_finite;
+            match agg {
+                IncrementalAgg::Mean => (n_total > 0).then(|| non_finite.sum().unwrap_or(mean)),
+                IncrementalAgg::Var(ddof) | IncrementalAgg::Std(ddof) => {
+                    if n_total <= ddof as usize {
+                        return None;
+                    }
+                    if n_non_finite > 0 {
+                        return Some(f64::NAN);
+                    }
+                    let var = m2.max(0.0) / (n - ddof as usize) as f64;
+                    Some(if matches!(agg, IncrementalAgg::Std(_)) {
+                        var.sqrt()
+                    } else {
+                        var
+                    })
+                },
+                _ => unreachable!(),
+            }
+        })
+        .collect()
+}
+
+fn n_unique(s: &Series, windows: &Windows) -> PolarsResult<IdxCa> {
+    // Map every row to the id of its group, nulls included like `n_unique` does.
+    let groups = s.group_tuples(true, false)?;
+    let mut ids = vec![0 as IdxSize; s.len()];
+    match &groups {
+        GroupsProxy::Idx(groups) => {
+            for (id, all) in groups.all().iter().enumerate() {
+                for &idx in all.iter() {
+                    ids[idx as usize] = id as IdxSize;
+                }
+            }
+        },
+        GroupsProxy::Slice { groups, .. } => {
+            for (id, &[first, len]) in groups.iter().enumerate() {
+                ids[first as usize..(first + len) as usize].fill(id as IdxSize);
+            }
+        },
+    }
+
+    let mut counts = vec![0 as IdxSize; groups.len()];
+    let mut distinct: IdxSize = 0;
+    Ok((0..windows.len)
+        .map(|idx| {
+            let id = ids[idx] as usize;
+            distinct += (counts[id] == 0) as IdxSize;
+            counts[id] += 1;
+            if let Some(evicted) = windows.evicted(idx) {
+                let id = ids[evicted] as usize;
+                counts[id] -= 1;
+                distinct -= (counts[id] == 0) as IdxSize;
+            }
+            windows.enough[idx].then_some(distinct)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn evaluate(agg: IncrementalAgg, s: &Series, window_size: Option<usize>) -> Series {
+        agg.evaluate(s, window_size, 1).unwrap()
+    }
+
+    #[test]
+    fn test_incremental_sum_non_finite() {
+        let s = Series::new("", [1.0, f64::NAN, 2.0, f64::INFINITY, 3.0, 4.0]);
+        let out = evaluate(IncrementalAgg::Sum, &s, Some(2));
+        let out = out.f64().unwrap();
+        assert_eq!(out.get(0), Some(1.0));
+        assert!(out.get(1).unwrap().is_nan());
+        assert!(out.get(2).unwrap().is_nan());
+        assert_eq!(out.get(3), Some(f64::INFINITY));
+        assert_eq!(out.get(4), Some(f64::INFINITY));
+        // The NaN and the infinity have left the window.
+        assert_eq!(out.get(5), Some(7.0));
+
+        let s = Series::new("", [f64::INFINITY, f64::NEG_INFINITY, 1.0, 2.0]);
+        let out = evaluate(IncrementalAgg::Sum, &s, Some(2));
+        let out = out.f64().unwrap();
+        assert!(out.get(1).unwrap().is_nan());
+        assert_eq!(out.get(2), Some(f64::NEG_INFINITY));
+        assert_eq!(out.get(3), Some(3.0));
+    }
+
+    #[test]
+    fn test_incremental_moments_non_finite() {
+        let s = Series::new("", [1.0, f64::NAN, 2.0, 4.0, 6.0]);
+        let mean = evaluate(IncrementalAgg::Mean, &s, Some(2));
+        let mean = mean.f64().unwrap();
+        assert!(mean.get(1).unwrap().is_nan());
+        assert!(mean.get(2).unwrap().is_nan());
+        assert_eq!(mean.get(3), Some(3.0));
+        assert_eq!(mean.get(4), Some(5.0));
+
+        let var = evaluate(IncrementalAgg::Var(1), &s, Some(2));
+        let var = var.f64().unwrap();
+        assert!(var.get(2).unwrap().is_nan());
+        assert_eq!(var.get(3), Some(2.0));
+        assert_eq!(var.get(4), Some(2.0));
+    }
+
+    #[test]
+    fn test_incremental_sum_wraps() {
+        let s = Series::new("", [i32::MAX, 1, 1, -1]);
+        let out = evaluate(IncrementalAgg::Sum, &s, Some(2));
+        let out: Vec<_> = out.i32().unwrap().into_no_null_iter().collect();
+        assert_eq!(out, [i32::MAX, i32::MIN, 2, 0]);
+
+        let out = evaluate(IncrementalAgg::Sum, &s, None);
+        let out: Vec<_> = out.i32().unwrap().into_no_null_iter().collect();
+        assert_eq!(out, [i32::MAX, i32::MIN, i32::MIN + 1, i32::MIN]);
+    }
+}
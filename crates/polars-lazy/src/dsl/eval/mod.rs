@@ -1,3 +1,6 @@
+mod incremental;
+
+use incremental::IncrementalAgg;
 use polars_core::prelude::*;
 use rayon::prelude::*;
 
@@ -37,22 +40,28 @@ pub(crate) fn eval_field_to_dtype(f: &Field, expr: &Expr, list: bool) -> Field {
     }
 }
 
-pub trait ExprEvalExtension: IntoExpr + Sized {
-    /// Run an expression over a sliding window that increases `1` slot every iteration.
-    ///
-    /// # Warning
-    /// This can be really slow as it can have `O(n^2)` complexity. Don't use this for operations
-    /// that visit all elements.
-    fn cumulative_eval(self, expr: Expr, min_periods: usize, parallel: bool) -> Expr {
-        let this = self.into_expr();
-        let expr2 = expr.clone();
-        let func = move |mut s: Series| {
-            let name = s.name().to_string();
-            s.rename("");
+/// Evaluate `expr` on windows ending at every row, spanning `window_size` rows or the
+/// whole prefix if `window_size` is `None`.
+fn eval_windows(
+    this: Expr,
+    expr: Expr,
+    window_size: Option<usize>,
+    min_periods: usize,
+    parallel: bool,
+) -> Expr {
+    let expr2 = expr.clone();
+    let func = move |mut s: Series| {
+        let name = s.name().to_string();
+        s.rename("");
 
-            // Ensure we get the new schema.
-            let output_field = eval_field_to_dtype(s.field().as_ref(), &expr, false);
+        // Ensure we get the new schema.
+        let output_field = eval_field_to_dtype(s.field().as_ref(), &expr, false);
 
+        let s = if let Some(agg) = IncrementalAgg::from_expr(&expr, s.dtype()) {
+            let mut out = agg.evaluate(&s, window_size, min_periods)?;
+            out.rename(&name);
+            out
+        } else {
             let expr = expr.clone();
             let mut arena = Arena::with_capacity(10);
             let aexpr = to_expr_ir(expr, &mut arena);
@@ -75,13 +84,18 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
                 );
                 Ok(out.get(0).unwrap().into_static().unwrap())
             };
+            let window = |end: usize| {
+                let offset = window_size.map_or(0, |w| end.saturating_sub(w));
+                s.slice(offset as i64, end - offset)
+            };
 
             let avs = if parallel {
                 (1..s.len() + 1)
                     .into_par_iter()
-                    .map(|len| {
-                        let s = s.slice(0, len);
-                        if (len - s.null_count()) >= min_periods {
+                    .map(|end| {
+                        let _sc = state.enter_string_cache();
+                        let s = window(end);
+                        if (s.len() - s.null_count()) >= min_periods {
                             let df = s.into_frame();
                             let out = phys_expr.evaluate(&df, &state)?;
                             finish(out)
@@ -93,9 +107,9 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
             } else {
                 let mut df_container = DataFrame::empty();
                 (1..s.len() + 1)
-                    .map(|len| {
-                        let s = s.slice(0, len);
-                        if (len - s.null_count()) >= min_periods {
+                    .map(|end| {
+                        let s = window(end);
+                        if (s.len() - s.null_count()) >= min_periods {
                             unsafe {
                                 df_container.get_columns_mut().push(s);
                                 let out = phys_expr.evaluate(&df_container, &state)?;
@@ -108,20 +122,54 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
                     })
                     .collect::<PolarsResult<Vec<_>>>()?
             };
-            let s = Series::new(&name, avs);
-
-            if s.dtype() != output_field.data_type() {
-                s.cast(output_field.data_type()).map(Some)
-            } else {
-                Ok(Some(s))
-            }
+            Series::new(&name, avs)
         };
 
-        this.apply(
-            func,
-            GetOutput::map_field(move |f| eval_field_to_dtype(f, &expr2, false)),
+        if s.dtype() != output_field.data_type() {
+            s.cast(output_field.data_type()).map(Some)
+        } else {
+            Ok(Some(s))
+        }
+    };
+
+    this.apply(
+        func,
+        GetOutput::map_field(move |f| eval_field_to_dtype(f, &expr2, false)),
+    )
+}
+
+pub trait ExprEvalExtension: IntoExpr + Sized {
+    /// Run an expression over a sliding window that increases `1` slot every iteration.
+    ///
+    /// `sum`, `min`, `max`, `mean`, `var`, `std` and `n_unique` of the element are
+    /// updated incrementally per row.
+    ///
+    /// # Warning
+    /// Other expressions are evaluated on every window, which can have `O(n^2)` complexity.
+    /// Don't use this for operations that visit all elements.
+    fn cumulative_eval(self, expr: Expr, min_periods: usize, parallel: bool) -> Expr {
+        eval_windows(self.into_expr(), expr, None, min_periods, parallel).with_fmt("expanding_eval")
+    }
+
+    /// Run an expression over a window of the last `window_size` rows, ending at every row.
+    ///
+    /// The same aggregations as in [`cumulative_eval`](Self::cumulative_eval) are updated
+    /// incrementally; other expressions are evaluated on every window.
+    fn rolling_eval(
+        self,
+        expr: Expr,
+        window_size: usize,
+        min_periods: usize,
+        parallel: bool,
+    ) -> Expr {
+        eval_windows(
+            self.into_expr(),
+            expr,
+            Some(window_size),
+            min_periods,
+            parallel,
         )
-        .with_fmt("expanding_eval")
+        .with_fmt("rolling_eval")
     }
 }
 
@@ -41,61 +41,18 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
 
 #[cfg(feature = "diagonal_concat")]
 /// Concat [LazyFrame]s diagonally.
-/// Calls [`concat`][concat()] internally.
+///
+/// The output schema is the union of the input schemas in order of appearance and columns
+/// that are missing in an input are filled with nulls. If `args.to_supertypes` is set, the
+/// columns are cast to the supertype of their dtypes in all inputs. The schemas are resolved
+/// when the query is planned, so projections and predicates are still pushed down into every
+/// input.
 pub fn concat_lf_diagonal<L: AsRef<[LazyFrame]>>(
     inputs: L,
-    args: UnionArgs,
+    mut args: UnionArgs,
 ) -> PolarsResult<LazyFrame> {
-    let lfs = inputs.as_ref();
-    let schemas = lfs
-        .iter()
-        .map(|lf| lf.schema())
-        .collect::<PolarsResult<Vec<_>>>()?;
-
-    let upper_bound_width = schemas.iter().map(|sch| sch.len()).sum();
-
-    // Use Vec to preserve order
-    let mut column_names = Vec::with_capacity(upper_bound_width);
-    let mut total_schema = Vec::with_capacity(upper_bound_width);
-
-    for sch in schemas.iter() {
-        sch.iter().for_each(|(name, dtype)| {
-            if !column_names.contains(name) {
-                column_names.push(name.clone());
-                total_schema.push((name.clone(), dtype.clone()));
-            }
-        });
-    }
-    let lfs_with_all_columns = lfs
-        .iter()
-        // Zip Frames with their Schemas
-        .zip(schemas)
-        .filter_map(|(lf, lf_schema)| {
-            if lf_schema.is_empty() {
-                // if the frame is empty we discard
-                return None;
-            };
-
-            let mut lf = lf.clone();
-            for (name, dtype) in total_schema.iter() {
-                // If a name from Total Schema is not present - append
-                if lf_schema.get_field(name).is_none() {
-                    lf = lf.with_column(NULL.lit().cast(dtype.clone()).alias(name))
-                }
-            }
-
-            // Now, reorder to match schema
-            let reordered_lf = lf.select(
-                column_names
-                    .iter()
-                    .map(|col_name| col(col_name))
-                    .collect::<Vec<Expr>>(),
-            );
-            Some(Ok(reordered_lf))
-        })
-        .collect::<PolarsResult<Vec<_>>>()?;
-
-    concat(lfs_with_all_columns, args)
+    args.diagonal = true;
+    concat_impl(inputs, args)
 }
 
 /// Concat [LazyFrame]s horizontally.
@@ -151,8 +108,17 @@ where
     I: IntoParallelIterator<Item = LazyFrame>,
 {
     let iter = lfs.into_par_iter();
-
-    polars_core::POOL.install(|| iter.map(|lf| lf.collect()).collect())
+    #[cfg(feature = "dtype-categorical")]
+    let string_cache = StringCacheScope::current();
+
+    polars_core::POOL.install(|| {
+        iter.map(|lf| {
+            #[cfg(feature = "dtype-categorical")]
+            let _sc = StringCacheScope::enter(string_cache.as_ref());
+            lf.collect()
+        })
+        .collect()
+    })
 }
 
 #[cfg(test)]
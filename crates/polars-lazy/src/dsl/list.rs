@@ -61,6 +61,7 @@ fn run_per_sublist(
         let ca: ListChunked = lst
             .par_iter()
             .map(|opt_s| {
+                let _sc = state.enter_string_cache();
                 opt_s.and_then(|s| {
                     let df = s.into_frame();
                     let out = phys_expr.evaluate(&df, &state);
@@ -0,0 +1,131 @@
+use super::*;
+
+/// How [`LazyFrame::assert_schema`] compares the schema of a frame with the expected schema.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SchemaAssertMode {
+    /// Every expected column must exist with the expected dtype. Other columns are allowed.
+    Subset,
+    /// The frame must have exactly the expected columns and dtypes, in any order.
+    #[default]
+    Exact,
+    /// The frame must have exactly the expected columns and dtypes, in the expected order.
+    Ordered,
+}
+
+/// Options of [`LazyFrame::assert_schema`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SchemaAssertOptions {
+    pub mode: SchemaAssertMode,
+    /// Columns of the expected schema that may not contain null values.
+    pub non_null: Vec<SmartString>,
+}
+
+impl From<SchemaAssertMode> for SchemaAssertOptions {
+    fn from(mode: SchemaAssertMode) -> Self {
+        SchemaAssertOptions {
+            mode,
+            ..Default::default()
+        }
+    }
+}
+
+/// Check `actual` against `expected` and return an error listing every difference.
+///
+/// Missing columns are prefixed with `-`, unexpected columns with `+` and columns with
+/// another dtype with `~`.
+fn check_schema(expected: &Schema, actual: &Schema, mode: SchemaAssertMode) -> PolarsResult<()> {
+    let mut diff = vec![];
+    for (name, dtype) in expected.iter() {
+        match actual.get(name) {
+            None => diff.push(format!("- {name}: {dtype}")),
+            Some(got) if got != dtype => {
+                diff.push(format!("~ {name}: expected {dtype}, got {got}"))
+            },
+            _ => {},
+        }
+    }
+    if mode != SchemaAssertMode::Subset {
+        for (name, dtype) in actual.iter() {
+            if !expected.contains(name) {
+                diff.push(format!("+ {name}: {dtype}"));
+            }
+        }
+    }
+    if diff.is_empty()
+        && mode == SchemaAssertMode::Ordered
+        && !expected.iter_names().eq(actual.iter_names())
+    {
+        let order = |schema: &Schema| schema.get_names().join(", ");
+        diff.push(format!(
+            "  order: expected [{}], got [{}]",
+            order(expected),
+            order(actual)
+        ));
+    }
+    polars_ensure!(
+        diff.is_empty(),
+        SchemaMismatch: "the frame doesn't match the expected schema:\n{}", diff.join("\n")
+    );
+    Ok(())
+}
+
+impl LazyFrame {
+    /// Assert that the frame matches the `schema` contract.
+    ///
+    /// The names and dtypes are checked against the resolved schema of the plan, so drift
+    /// in the upstream schema fails here with an error listing the differences. Because the
+    /// output of user-defined functions can't always be known up front, the check is
+    /// repeated on the data when the query runs, together with the check that the
+    /// `non_null` columns don't contain nulls.
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> PolarsResult<LazyFrame> {
+    ///     let schema = Schema::from_iter([
+    ///         Field::new("id", DataType::Int64),
+    ///         Field::new("name", DataType::String),
+    ///     ]);
+    ///     lf.assert_schema(&schema, SchemaAssertMode::Subset)
+    /// }
+    /// ```
+    pub fn assert_schema<O: Into<SchemaAssertOptions>>(
+        self,
+        schema: &Schema,
+        options: O,
+    ) -> PolarsResult<LazyFrame> {
+        let SchemaAssertOptions { mode, non_null } = options.into();
+        for name in &non_null {
+            polars_ensure!(
+                schema.contains(name),
+                ColumnNotFound: "non-null column {:?} is not part of the expected schema", name
+            );
+        }
+        let expected = Arc::new(schema.clone());
+        check_schema(&expected, &self.schema()?, mode)?;
+
+        let check = move |df: DataFrame| {
+            check_schema(&expected, &df.schema(), mode)?;
+            for name in &non_null {
+                let null_count = df.column(name)?.null_count();
+                polars_ensure!(
+                    null_count == 0,
+                    SchemaMismatch: "column {:?} may not contain nulls, found {} null values",
+                    name, null_count
+                );
+            }
+            Ok(df)
+        };
+        // The contract holds at this point of the plan, so the columns and rows may not be
+        // pruned before the check.
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            slice_pushdown: false,
+            streaming: true,
+            ..Default::default()
+        };
+        Ok(self.map(check, optimizations, None, Some("ASSERT_SCHEMA")))
+    }
+}
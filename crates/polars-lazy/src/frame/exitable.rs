@@ -13,6 +13,7 @@ impl LazyFrame {
         let (tx, rx) = channel();
         let token = state.cancel_token();
         POOL.spawn_fifo(move || {
+            let _sc = state.enter_string_cache();
             let result = physical_plan.execute(&mut state);
             tx.send(result).unwrap();
         });
@@ -0,0 +1,132 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use xxhash_rust::xxh3::Xxh3;
+
+use super::*;
+
+#[derive(Default)]
+struct ManifestStats {
+    num_rows: usize,
+    // The minimum and maximum of every batch, per partition column.
+    mins: Vec<Vec<Series>>,
+    maxs: Vec<Vec<Series>>,
+}
+
+/// Reduce the per-batch extrema of a column to a single value, which is null if no rows
+/// were written.
+fn reduce_extrema(field: &Field, extrema: &[Series], min: bool) -> PolarsResult<Series> {
+    if extrema.is_empty() {
+        return Ok(Series::full_null(field.name(), 1, field.data_type()));
+    }
+    let mut s = extrema[0].clone();
+    for other in &extrema[1..] {
+        s.append(other)?;
+    }
+    if min {
+        s.min_as_series()
+    } else {
+        s.max_as_series()
+    }
+}
+
+/// The xxh3 checksum of the file at `path` in hexadecimal.
+fn file_checksum(path: &Path) -> PolarsResult<String> {
+    let mut file = polars_utils::open_file(path)?;
+    let mut hasher = Xxh3::new();
+    let mut buf = vec![0u8; 1 << 16];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(format!("{:016x}", hasher.digest()))
+}
+
+impl LazyFrame {
+    /// Stream the query result to a file like the `sink_*` methods and return a manifest of
+    /// what was written.
+    ///
+    /// The manifest is a `DataFrame` with a single row with the columns:
+    /// * `path` - the file that was written.
+    /// * `num_rows` - the number of rows in the file.
+    /// * `size_bytes` - the size of the file.
+    /// * `checksum` - the xxh3 checksum of the file content in hexadecimal.
+    /// * `{column}_min` and `{column}_max` for every column in `partition_columns`.
+    ///
+    /// This allows registering the output in a catalog without listing the storage again.
+    pub fn sink_with_manifest(
+        self,
+        path: PathBuf,
+        file_type: FileType,
+        partition_columns: &[&str],
+    ) -> PolarsResult<DataFrame> {
+        let schema = self.schema()?;
+        let fields = partition_columns
+            .iter()
+            .map(|name| Ok(Field::new(name, schema.try_get(name)?.clone())))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let stats = Arc::new(Mutex::new(ManifestStats {
+            mins: vec![vec![]; fields.len()],
+            maxs: vec![vec![]; fields.len()],
+            ..Default::default()
+        }));
+        let batch_stats = stats.clone();
+        let names = partition_columns
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let function = move |df: DataFrame| {
+            let mut mins = Vec::with_capacity(names.len());
+            let mut maxs = Vec::with_capacity(names.len());
+            if df.height() > 0 {
+                for name in &names {
+                    let s = df.column(name)?;
+                    mins.push(s.min_as_series()?);
+                    maxs.push(s.max_as_series()?);
+                }
+            }
+
+            let mut stats = batch_stats.lock().unwrap();
+            stats.num_rows += df.height();
+            for (i, (min, max)) in mins.into_iter().zip(maxs).enumerate() {
+                stats.mins[i].push(min);
+                stats.maxs[i].push(max);
+            }
+            Ok(df)
+        };
+        let optimizations = AllowedOptimizations {
+            streaming: true,
+            ..Default::default()
+        };
+        let payload = SinkType::File {
+            path: Arc::new(path.clone()),
+            file_type,
+        };
+        self.map(function, optimizations, None, Some("SINK MANIFEST"))
+            .sink(payload, "collect().write_*()")?;
+
+        let stats = stats.lock().unwrap();
+        let size_bytes = std::fs::metadata(&path)?.len();
+        let mut columns = vec![
+            Series::new("path", &[path.to_string_lossy().as_ref()]),
+            Series::new("num_rows", &[stats.num_rows as u64]),
+            Series::new("size_bytes", &[size_bytes]),
+            Series::new("checksum", &[file_checksum(&path)?]),
+        ];
+        for (i, field) in fields.iter().enumerate() {
+            let mut min = reduce_extrema(field, &stats.mins[i], true)?;
+            min.rename(&format!("{}_min", field.name()));
+            let mut max = reduce_extrema(field, &stats.maxs[i], false)?;
+            max.rename(&format!("{}_max", field.name()));
+            columns.push(min);
+            columns.push(max);
+        }
+        DataFrame::new(columns)
+    }
+}
@@ -2,11 +2,28 @@
 #[cfg(feature = "python")]
 mod python;
 
+mod assert_schema;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(all(
+    feature = "sink_manifest",
+    any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "csv",
+        feature = "json"
+    )
+))]
+mod manifest;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "ipc")]
+mod result_cache;
+#[cfg(feature = "upsample")]
+mod upsample;
+mod validate;
+mod value_counts;
 
 #[cfg(any(
     feature = "parquet",
@@ -18,26 +35,41 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 pub use anonymous_scan::*;
+pub use assert_schema::{SchemaAssertMode, SchemaAssertOptions};
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "iceberg")]
+pub use iceberg::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+#[cfg(feature = "delta")]
+use polars_io::delta::DeltaTransaction;
 use polars_io::RowIndex;
 use polars_ops::frame::JoinCoalesce;
+#[cfg(feature = "asof_join")]
+use polars_ops::frame::{AsOfOptions, AsofStrategy};
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
 use polars_plan::global::FETCH_ROWS;
 use smartstring::alias::String as SmartString;
+pub use validate::Validation;
+pub use value_counts::ValueCountsOptions;
 
+use crate::physical_plan::dispatch_plan;
 use crate::physical_plan::executors::Executor;
-use crate::physical_plan::planner::{create_physical_expr, create_physical_plan};
+use crate::physical_plan::planner::{
+    create_physical_expr, create_physical_plan, create_physical_plan_with_expr_cache,
+};
 use crate::physical_plan::state::ExecutionState;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
@@ -86,6 +118,19 @@ impl From<DslPlan> for LazyFrame {
     }
 }
 
+/// Set the metadata of the columns of `df` that have metadata in `schema`, the output schema
+/// of the plan that computed `df`.
+fn set_column_metadata(df: &mut DataFrame, schema: &Schema) {
+    // SAFETY: the names and the lengths of the columns don't change.
+    for s in unsafe { df.get_columns_mut() } {
+        if let Some(metadata) = schema.get_metadata(s.name()) {
+            if s.metadata().as_deref() != Some(metadata) {
+                s.set_metadata(metadata.clone());
+            }
+        }
+    }
+}
+
 impl LazyFrame {
     /// Get a handle to the schema — a map from column names to data types — of the current
     /// `LazyFrame` computation.
@@ -124,6 +169,12 @@ impl LazyFrame {
 
     /// Turn off all optimizations.
     pub fn without_optimizations(self) -> Self {
+        let integer_overflow = self.opt_state.integer_overflow;
+        let nan_as_null = self.opt_state.nan_as_null;
+        let cast_policy = self.opt_state.cast_policy;
+        let chunk_policy = self.opt_state.chunk_policy;
+        #[cfg(feature = "streaming")]
+        let spill_options = self.opt_state.spill_options;
         self.with_optimizations(OptState {
             projection_pushdown: false,
             predicate_pushdown: false,
@@ -140,6 +191,14 @@ impl LazyFrame {
             eager: false,
             fast_projection: false,
             row_estimate: false,
+            join_reorder: false,
+            integer_overflow,
+            nan_as_null,
+            cast_policy,
+            chunk_policy,
+            #[cfg(feature = "streaming")]
+            spill_options,
+            expr_cache: false,
         })
     }
 
@@ -181,6 +240,14 @@ impl LazyFrame {
         self
     }
 
+    /// Cache the results of expressions that are evaluated in more than one node of the plan,
+    /// e.g. in a `with_columns` and a later `filter`, and reuse them when they are evaluated
+    /// on the same columns. The results are kept in memory until the query finishes.
+    pub fn with_expr_cache(mut self, toggle: bool) -> Self {
+        self.opt_state.expr_cache = toggle;
+        self
+    }
+
     /// Toggle slice pushdown optimization.
     pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.slice_pushdown = toggle;
@@ -199,12 +266,68 @@ impl LazyFrame {
         self
     }
 
+    /// Reorder chains of inner joins by their estimated cardinalities, so that the joins
+    /// that produce the fewest rows are done first.
+    ///
+    /// The rows of the inputs are estimated from the heights of in-memory frames and the
+    /// file metadata of scans, and the distinct values of the join keys are counted on a
+    /// sample of in-memory columns. Chains are left as-is if an input can't be estimated.
+    /// This changes the order of the rows that match more than one row of a join.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
         self
     }
 
+    /// Set how integer arithmetic and sums handle overflow. Defaults to [`IntegerOverflow::Wrap`].
+    ///
+    /// With [`IntegerOverflow::Error`] the query fails on the first overflowing row and
+    /// reports the column and row index.
+    pub fn with_integer_overflow(mut self, overflow: IntegerOverflow) -> Self {
+        self.opt_state.integer_overflow = overflow;
+        self
+    }
+
+    /// Treat the floating point `NaN` values as missing values in the aggregations,
+    /// comparisons and sorts of this query, like pandas does. Defaults to `false`, in which
+    /// case `NaN` values propagate through aggregations and sort as the largest value.
+    ///
+    /// Only `nan_min` and `nan_max` keep propagating `NaN` values. To convert the `NaN`
+    /// values of a single expression instead, use [`Expr::nan_to_null`].
+    pub fn with_nan_as_null(mut self, toggle: bool) -> Self {
+        self.opt_state.nan_as_null = toggle;
+        self
+    }
+
+    /// Set the policy for implicit casts during type coercion. Defaults to
+    /// [`CastPolicy::Lenient`].
+    pub fn with_cast_policy(mut self, cast_policy: CastPolicy) -> Self {
+        self.opt_state.cast_policy = cast_policy;
+        self
+    }
+
+    /// Set when operators may rechunk their data and how large the chunks of the result are.
+    ///
+    /// The default is [`ChunkPolicy::default`], which lets operators rechunk and keeps the
+    /// chunks of the result as they are.
+    pub fn with_chunk_policy(mut self, chunk_policy: ChunkPolicy) -> Self {
+        self.opt_state.chunk_policy = chunk_policy;
+        self
+    }
+
+    /// Set how the streaming engine spills data to disk when it runs out of memory, such as
+    /// the compression of the spilled files.
+    #[cfg(feature = "streaming")]
+    pub fn with_spill_options(mut self, spill_options: SpillOptions) -> Self {
+        self.opt_state.spill_options = spill_options;
+        self
+    }
+
     /// Return a String describing the naive (un-optimized) logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -253,6 +376,67 @@ impl LazyFrame {
         }
     }
 
+    /// Optimize the plan and return the decisions of the optimizer: which rules were
+    /// applied, and which pushdowns were blocked at which node and why.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn optimizer_trace(&self) -> PolarsResult<Vec<OptimizerEvent>> {
+        let (optimized, events) = trace_optimizer(|| self.optimized_plan());
+        optimized?;
+        Ok(events)
+    }
+
+    /// Estimate the rows, the output size and the peak memory of every node of the optimized
+    /// plan, in pre-order, without running the query.
+    ///
+    /// Nodes that hold all of their input at once, e.g. sorts, group-bys and joins, are
+    /// flagged to spill if their peak memory exceeds `budget` bytes. See [`estimate_memory`]
+    /// for how the estimates are made.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn estimate_memory(&self, budget: u64) -> PolarsResult<Vec<MemoryEstimate>> {
+        let mut expr_arena = Arena::with_capacity(64);
+        let mut lp_arena = Arena::with_capacity(64);
+        let lp_top = self.clone().optimize_with_scratch(
+            &mut lp_arena,
+            &mut expr_arena,
+            &mut vec![],
+            true,
+        )?;
+        Ok(estimate_memory(lp_top, &lp_arena, budget))
+    }
+
+    /// Return a String describing the estimated memory of every node of the optimized plan,
+    /// see [`LazyFrame::estimate_memory`].
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn explain_memory(&self, budget: u64) -> PolarsResult<String> {
+        let estimates = self.estimate_memory(budget)?;
+        Ok(estimates
+            .iter()
+            .map(|estimate| format!("{estimate}\n"))
+            .collect())
+    }
+
+    /// Return the lineage of every output column of the optimized plan: the source files and
+    /// columns it is computed from and the expressions applied along the way, see
+    /// [`column_lineage`].
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn column_lineage(&self) -> PolarsResult<Vec<ColumnLineage>> {
+        #[allow(unused_mut)]
+        let mut lf = self.clone();
+        // Common subexpressions would show up as temporary columns.
+        #[cfg(feature = "cse")]
+        {
+            lf.opt_state.comm_subexpr_elim = false;
+        }
+        let mut expr_arena = Arena::with_capacity(64);
+        let mut lp_arena = Arena::with_capacity(64);
+        let lp_top = lf.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], true)?;
+        Ok(column_lineage(lp_top, &lp_arena, &expr_arena))
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -413,6 +597,30 @@ impl LazyFrame {
         })
     }
 
+    /// Set the key/value metadata of the column `name`, replacing any metadata it had.
+    ///
+    /// The metadata follows the column through projections, aliases and renames, is written
+    /// by the IPC and parquet sinks, and can be read in expressions with [`col_meta`].
+    pub fn with_column_metadata(self, name: &str, metadata: FieldMetadata) -> Self {
+        let name = SmartString::from(name);
+        let schema = move |input_schema: &Schema| -> PolarsResult<SchemaRef> {
+            let mut schema = input_schema.clone();
+            schema.set_metadata(&name, metadata.clone());
+            Ok(Arc::new(schema))
+        };
+        // The data is passed through untouched.
+        let optimizations = AllowedOptimizations {
+            streaming: true,
+            ..Default::default()
+        };
+        self.map(
+            Ok,
+            optimizations,
+            Some(Arc::new(schema)),
+            Some("WITH_COLUMN_METADATA"),
+        )
+    }
+
     /// Removes columns from the DataFrame.
     /// Note that it's better to only select the columns you need
     /// and let the projection pushdown optimize away the unneeded columns.
@@ -512,6 +720,26 @@ impl LazyFrame {
         res
     }
 
+    /// Resolve the schema of the optimized query without reading any data.
+    ///
+    /// Unlike [`LazyFrame::schema`], this converts and optimizes the whole plan, so that errors
+    /// such as missing columns and invalid types are raised as they would be by a collect.
+    /// Map functions contribute the schema they declare, as they aren't called.
+    pub fn fetch_schema_only(self) -> PolarsResult<SchemaRef> {
+        let (node, lp_arena, _) = self.to_alp_optimized()?;
+        let schema = lp_arena.get(node).schema(&lp_arena).into_owned();
+        Ok(schema)
+    }
+
+    /// Return a DataFrame without rows with the schema of the query, without reading any data.
+    ///
+    /// This validates a query, like [`LazyFrame::fetch_schema_only`], at the cost of its
+    /// optimization only, regardless of the size of the inputs.
+    pub fn collect_empty(self) -> PolarsResult<DataFrame> {
+        let schema = self.fetch_schema_only()?;
+        Ok(DataFrame::from(schema.as_ref()))
+    }
+
     pub fn optimize(
         self,
         lp_arena: &mut Arena<IR>,
@@ -580,6 +808,7 @@ impl LazyFrame {
                     _fmt,
                     true,
                     opt_state.row_estimate,
+                    opt_state.spill_options,
                 )?;
             }
             #[cfg(not(feature = "streaming"))]
@@ -598,6 +827,8 @@ impl LazyFrame {
     where
         P: Fn(Node, &mut Arena<IR>, &mut Arena<AExpr>) -> PolarsResult<()>,
     {
+        let chunk_policy = self.opt_state.chunk_policy;
+        let expr_cache = self.opt_state.expr_cache;
         let mut expr_arena = Arena::with_capacity(16);
         let mut lp_arena = Arena::with_capacity(16);
         let mut scratch = vec![];
@@ -605,6 +836,7 @@ impl LazyFrame {
             self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch, false)?;
 
         post_opt(lp_top, &mut lp_arena, &mut expr_arena)?;
+        warn_large_rechunks(lp_top, &lp_arena, &chunk_policy);
 
         // sink should be replaced
         let no_file_sink = if check_sink {
@@ -612,9 +844,16 @@ impl LazyFrame {
         } else {
             true
         };
-        let physical_plan = create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+        let physical_plan = if expr_cache {
+            create_physical_plan_with_expr_cache(lp_top, &mut lp_arena, &mut expr_arena)?
+        } else {
+            create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?
+        };
 
-        let state = ExecutionState::new();
+        let mut state = ExecutionState::new();
+        if !chunk_policy.auto_rechunk {
+            state.disable_auto_rechunk();
+        }
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -623,8 +862,34 @@ impl LazyFrame {
     where
         P: Fn(Node, &mut Arena<IR>, &mut Arena<AExpr>) -> PolarsResult<()>,
     {
+        let target_chunk_size = self.opt_state.chunk_policy.target_chunk_size;
+        // The columns that the query computes don't have the metadata of the columns they are
+        // computed from, the output schema of the plan has it.
+        let output_schema = std::cell::RefCell::new(None);
+        let post_opt = |root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>| {
+            post_opt(root, lp_arena, expr_arena)?;
+            *output_schema.borrow_mut() = Some(lp_arena.get(root).schema(lp_arena).into_owned());
+            Ok(())
+        };
         let (mut state, mut physical_plan, _) = self.prepare_collect_post_opt(false, post_opt)?;
-        physical_plan.execute(&mut state)
+        let mut out = physical_plan.execute(&mut state)?;
+        if let Some(schema) = output_schema.into_inner() {
+            set_column_metadata(&mut out, &schema);
+        }
+        let Some(size) = target_chunk_size else {
+            return Ok(out);
+        };
+        polars_ensure!(size > 0, InvalidOperation: "the target chunk size must be positive");
+        out.as_single_chunk_par();
+        if out.height() <= size {
+            return Ok(out);
+        }
+        // Every chunk but the last has `size` rows.
+        let chunks = (0..out.height())
+            .step_by(size)
+            .map(|offset| out.slice(offset as i64, size))
+            .collect::<Vec<_>>();
+        Ok(accumulate_dataframes_vertical_unchecked(chunks))
     }
 
     #[allow(unused_mut)]
@@ -656,6 +921,15 @@ impl LazyFrame {
         self._collect_post_opt(|_, _, _| Ok(()))
     }
 
+    /// Execute the query and let `engine` execute the parts of the plan it supports.
+    ///
+    /// The parts that are not claimed by the engine run on the default engines.
+    pub fn collect_with_engine(self, engine: &dyn PhysicalPlanDispatch) -> PolarsResult<DataFrame> {
+        self._collect_post_opt(|root, lp_arena, expr_arena| {
+            dispatch_plan(root, lp_arena, expr_arena, engine)
+        })
+    }
+
     /// Profile a LazyFrame.
     ///
     /// This will run the query and return a tuple
@@ -675,7 +949,7 @@ impl LazyFrame {
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
     #[cfg(feature = "parquet")]
-    pub fn sink_parquet(self, path: PathBuf, options: ParquetWriteOptions) -> PolarsResult<()> {
+    pub fn sink_parquet(self, path: PathBuf, options: ParquetSinkOptions) -> PolarsResult<()> {
         self.sink(
             SinkType::File {
                 path: Arc::new(path),
@@ -685,6 +959,97 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into parquet files in Hive-style partition directories under
+    /// `path`, one directory level per partition column, e.g. `path/year=2024/month=1/`.
+    ///
+    /// The partition columns aren't written to the files, as they can be read from the paths.
+    /// Only local paths are supported. This methods will return an error if the query cannot
+    /// be completely done in a streaming fashion.
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet_partitioned(
+        self,
+        path: PathBuf,
+        options: ParquetSinkOptions,
+        partition_options: PartitionedWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Partitioned {
+                path: Arc::new(path),
+                file_type: FileType::Parquet(options),
+                options: partition_options,
+            },
+            "collect().write_parquet()",
+        )
+    }
+
+    /// Stream a query result into the Delta Lake table at `path`, which is created if it
+    /// doesn't exist. The schema of the query must match the schema of an existing table.
+    ///
+    /// The parquet files are written to a staging directory in the table first, and are only
+    /// added to the table by a commit to its transaction log once all of them are written.
+    /// This methods will return an error if the query cannot be completely done in a streaming
+    /// fashion.
+    #[cfg(feature = "delta")]
+    pub fn sink_delta(
+        self,
+        path: PathBuf,
+        options: ParquetSinkOptions,
+        delta_options: DeltaWriteOptions,
+    ) -> PolarsResult<()> {
+        #[allow(unused_mut)]
+        let mut lf = self;
+        // Delta stores timestamps in microseconds.
+        #[cfg(feature = "dtype-datetime")]
+        {
+            let casts = lf
+                .schema()?
+                .iter()
+                .filter_map(|(name, dtype)| match dtype {
+                    DataType::Datetime(tu, tz) if *tu != TimeUnit::Microseconds => {
+                        Some(col(name).cast(DataType::Datetime(TimeUnit::Microseconds, tz.clone())))
+                    },
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if !casts.is_empty() {
+                lf = lf.with_columns(casts);
+            }
+        }
+        let schema = lf.schema()?;
+        let transaction = DeltaTransaction::try_new(&path, &schema, &delta_options)?;
+        let staging_dir = transaction.staging_dir().to_path_buf();
+        if transaction.partition_columns().is_empty() {
+            lf.sink_parquet(staging_dir.join("part-0.parquet"), options)?;
+        } else {
+            let partition_options =
+                PartitionedWriteOptions::new(transaction.partition_columns().to_vec());
+            lf.sink_parquet_partitioned(staging_dir, options, partition_options)?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Stream a query result into parquet files in the directory `path`, starting a new file
+    /// whenever a file reaches the maximum number of rows or bytes of `split_options`. This
+    /// methods will return an error if the query cannot be completely done in a streaming
+    /// fashion.
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet_split(
+        self,
+        path: PathBuf,
+        options: ParquetSinkOptions,
+        split_options: SplitWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Split {
+                path: Arc::new(path),
+                file_type: FileType::Parquet(options),
+                options: split_options,
+            },
+            "collect().write_parquet()",
+        )
+    }
+
     /// Stream a query result into a parquet file on an ObjectStore-compatible cloud service. This is useful if the final result doesn't fit
     /// into memory, and where you do not want to write to a local file but to a location in the cloud.
     /// This method will return an error if the query cannot be completely done in a
@@ -694,7 +1059,7 @@ impl LazyFrame {
         self,
         uri: String,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
-        parquet_options: ParquetWriteOptions,
+        parquet_options: ParquetSinkOptions,
     ) -> PolarsResult<()> {
         self.sink(
             SinkType::Cloud {
@@ -720,6 +1085,27 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into ipc/arrow files in the directory `path`, starting a new file
+    /// whenever a file reaches the maximum number of rows or bytes of `split_options`. This
+    /// methods will return an error if the query cannot be completely done in a streaming
+    /// fashion.
+    #[cfg(feature = "ipc")]
+    pub fn sink_ipc_split(
+        self,
+        path: PathBuf,
+        options: IpcWriterOptions,
+        split_options: SplitWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Split {
+                path: Arc::new(path),
+                file_type: FileType::Ipc(options),
+                options: split_options,
+            },
+            "collect().write_ipc()",
+        )
+    }
+
     /// Stream a query result into an ipc/arrow file on an ObjectStore-compatible cloud service.
     /// This is useful if the final result doesn't fit
     /// into memory, and where you do not want to write to a local file but to a location in the cloud.
@@ -765,6 +1151,27 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into csv files in the directory `path`, starting a new file
+    /// whenever a file reaches the maximum number of rows or bytes of `split_options`. This
+    /// methods will return an error if the query cannot be completely done in a streaming
+    /// fashion.
+    #[cfg(feature = "csv")]
+    pub fn sink_csv_split(
+        self,
+        path: PathBuf,
+        options: CsvWriterOptions,
+        split_options: SplitWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Split {
+                path: Arc::new(path),
+                file_type: FileType::Csv(options),
+                options: split_options,
+            },
+            "collect().write_csv()",
+        )
+    }
+
     /// Stream a query result into a json file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1176,6 +1583,60 @@ impl LazyFrame {
         )
     }
 
+    /// Nearest join this query with another lazy query.
+    ///
+    /// Every row is matched to the row of `other` whose `right_on` value is closest to its
+    /// `left_on` value, looking in both directions. Ties are broken towards the larger key
+    /// and rows without a match within `tolerance` get nulls. Unlike an asof join the keys
+    /// don't have to be sorted and the rows keep their order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn nearest_join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .join_nearest(other, col("time"), col("time"), Some(AnyValue::Int64(5)))
+    /// }
+    /// ```
+    #[cfg(feature = "asof_join")]
+    pub fn join_nearest<E: Into<Expr>>(
+        self,
+        other: LazyFrame,
+        left_on: E,
+        right_on: E,
+        tolerance: Option<AnyValue<'static>>,
+    ) -> LazyFrame {
+        const ROW_INDEX: &str = "__POLARS_NEAREST_JOIN_ROW_INDEX";
+        let left_on = left_on.into();
+        let right_on = right_on.into();
+
+        // The asof machinery finds the nearest key on sorted inputs; the row index restores
+        // the original order afterwards. Stable sorts break ties between equal right keys
+        // towards the last of them, as the eager join does.
+        let sort_options = SortMultipleOptions::default().with_maintain_order(true);
+        let left = self
+            .with_row_index(ROW_INDEX, None)
+            .sort_by_exprs([left_on.clone()], sort_options.clone());
+        // The rows of `other` without a key can't be matched.
+        let right = other
+            .filter(right_on.clone().is_not_null())
+            .sort_by_exprs([right_on.clone()], sort_options);
+        left.join_builder()
+            .with(right)
+            .left_on([left_on])
+            .right_on([right_on])
+            .how(JoinType::AsOf(AsOfOptions {
+                strategy: AsofStrategy::Nearest,
+                tolerance,
+                ..Default::default()
+            }))
+            .finish()
+            .sort([ROW_INDEX], Default::default())
+            .drop([ROW_INDEX])
+    }
+
     /// Generic function to join two LazyFrames.
     ///
     /// `join` can join on multiple columns, given as two list of expressions, and with a
@@ -1307,6 +1768,73 @@ impl LazyFrame {
         )
     }
 
+    /// Add or replace multiple columns to a DataFrame, where an expression may refer to the
+    /// columns that are added or replaced by the expressions before it.
+    ///
+    /// The expressions are added in sequential stages, like a chain of
+    /// [`LazyFrame::with_columns`] calls. An expression starts a new stage if it refers to, or
+    /// replaces, a column of the current stage. Expressions whose inputs or output can't be
+    /// determined without a schema, e.g. wildcards and selectors, get a stage of their own.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn add_features(df: DataFrame) -> LazyFrame {
+    ///     df.lazy()
+    ///         .with_columns_chained([
+    ///             (col("price") * col("quantity")).alias("revenue"),
+    ///             (col("revenue") - col("cost")).alias("profit"),
+    ///             (col("profit") / col("revenue")).alias("margin"),
+    ///         ])
+    /// }
+    /// ```
+    pub fn with_columns_chained<E: AsRef<[Expr]>>(self, exprs: E) -> LazyFrame {
+        let mut lf = self;
+        let mut stage = vec![];
+        // The columns defined by the current stage, `None` if they aren't known.
+        let mut defined = Some(PlHashSet::new());
+        for e in exprs.as_ref() {
+            let has_dynamic_inputs = e.into_iter().any(|e| match e {
+                Expr::Column(name) => name.starts_with('^') && name.ends_with('$'),
+                Expr::Wildcard
+                | Expr::Nth(_)
+                | Expr::Columns(_)
+                | Expr::DtypeColumn(_)
+                | Expr::Selector(_) => true,
+                _ => false,
+            });
+            let output_name = expr_output_name(e).ok();
+            let depends_on_stage = match &defined {
+                Some(defined) => {
+                    has_dynamic_inputs
+                        || output_name
+                            .as_ref()
+                            .map_or(true, |name| defined.contains(name))
+                        || expr_to_leaf_column_names_iter(e).any(|name| defined.contains(&name))
+                },
+                None => true,
+            };
+            if depends_on_stage && !stage.is_empty() {
+                lf = lf.with_columns(std::mem::take(&mut stage));
+                defined = Some(PlHashSet::new());
+            }
+            stage.push(e.clone());
+            match (&mut defined, output_name) {
+                (Some(defined), Some(name)) if !has_dynamic_inputs => {
+                    defined.insert(name);
+                },
+                _ => defined = None,
+            }
+        }
+        if stage.is_empty() {
+            lf
+        } else {
+            lf.with_columns(stage)
+        }
+    }
+
     fn with_columns_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().with_columns(exprs, options).build();
@@ -1422,6 +1950,103 @@ impl LazyFrame {
         self.select(vec![col("*").null_count()])
     }
 
+    /// Profile all the columns for missing and degenerate values.
+    ///
+    /// Returns one row per column with the number of rows and the counts of nulls, NaNs,
+    /// empty strings and zeros, each with its percentage of the rows. Counts that don't
+    /// apply to the dtype of a column are null. All counts are computed in a single
+    /// aggregation over the input.
+    pub fn null_profile(self) -> PolarsResult<LazyFrame> {
+        const METRICS: [&str; 4] = ["null", "nan", "empty", "zero"];
+        let input_schema = self.schema()?;
+
+        let not_applicable = || lit(NULL).cast(IDX_DTYPE);
+        let count = |mask: Expr| mask.sum().cast(IDX_DTYPE);
+        let mut aggs = vec![len().alias("len")];
+        for (i, (name, dtype)) in input_schema.iter().enumerate() {
+            let c = col(name);
+            let counts = [
+                c.clone().null_count().cast(IDX_DTYPE),
+                if dtype.is_float() {
+                    count(c.clone().is_nan())
+                } else {
+                    not_applicable()
+                },
+                if dtype == &DataType::String {
+                    count(c.clone().eq(lit("")))
+                } else {
+                    not_applicable()
+                },
+                if dtype.is_numeric() {
+                    count(c.eq(lit(0)))
+                } else {
+                    not_applicable()
+                },
+            ];
+            for (metric, count) in METRICS.iter().zip(counts) {
+                aggs.push(count.alias(&format!("{metric}_{i}")));
+            }
+        }
+
+        let mut schema = Schema::with_capacity(2 + 2 * METRICS.len());
+        schema.with_column("column".into(), DataType::String);
+        schema.with_column("count".into(), IDX_DTYPE);
+        for metric in METRICS {
+            schema.with_column(format!("{metric}_count").into(), IDX_DTYPE);
+            schema.with_column(format!("{metric}_percentage").into(), DataType::Float64);
+        }
+        let schema = Arc::new(schema);
+
+        // Reshape the single row of counts into a row per column.
+        let names = input_schema
+            .iter_names()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>();
+        let names = Series::new("column", names);
+        let reshape = move |df: DataFrame| -> PolarsResult<DataFrame> {
+            let len = df.column("len")?.idx()?.get(0).unwrap_or(0);
+            let mut columns = vec![
+                names.clone(),
+                IdxCa::from_vec("count", vec![len; names.len()]).into_series(),
+            ];
+            for metric in METRICS {
+                let counts = (0..names.len())
+                    .map(|i| Ok(df.column(&format!("{metric}_{i}"))?.idx()?.get(0)))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let percentages = counts
+                    .iter()
+                    .map(|count| {
+                        count
+                            .filter(|_| len > 0)
+                            .map(|count| count as f64 / len as f64 * 100.0)
+                    })
+                    .collect::<Vec<_>>();
+                columns.push(
+                    IdxCa::from_slice_options(&format!("{metric}_count"), &counts).into_series(),
+                );
+                columns.push(Series::new(&format!("{metric}_percentage"), percentages));
+            }
+            DataFrame::new(columns)
+        };
+
+        // The reshape needs all the counts and changes the meaning of the columns, so
+        // nothing may be pushed past it.
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            streaming: false,
+            ..Default::default()
+        };
+        Ok(self.select(aggs).map(
+            reshape,
+            optimizations,
+            Some(Arc::new(move |_: &Schema| -> PolarsResult<SchemaRef> {
+                Ok(schema.clone())
+            })),
+            Some("NULL_PROFILE"),
+        ))
+    }
+
     /// Drop non-unique rows and maintain the order of kept rows.
     ///
     /// `subset` is an optional `Vec` of column names to consider for uniqueness; if
@@ -1431,15 +2056,7 @@ impl LazyFrame {
         subset: Option<Vec<String>>,
         keep_strategy: UniqueKeepStrategy,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
-        let options = DistinctOptions {
-            subset: subset.map(Arc::new),
-            maintain_order: true,
-            keep_strategy,
-            ..Default::default()
-        };
-        let lp = self.get_plan_builder().distinct(options).build();
-        Self::from_logical_plan(lp, opt_state)
+        self.unique_by(subset, keep_strategy, None, true)
     }
 
     /// Drop non-unique rows without maintaining the order of kept rows.
@@ -1453,18 +2070,65 @@ impl LazyFrame {
         self,
         subset: Option<Vec<String>>,
         keep_strategy: UniqueKeepStrategy,
+    ) -> LazyFrame {
+        self.unique_by(subset, keep_strategy, None, false)
+    }
+
+    /// Drop non-unique rows, where `keep_by` is the column that is compared by
+    /// [`UniqueKeepStrategy::MaxBy`] and [`UniqueKeepStrategy::MinBy`] to pick the row that
+    /// is kept.
+    pub fn unique_by(
+        self,
+        subset: Option<Vec<String>>,
+        keep_strategy: UniqueKeepStrategy,
+        keep_by: Option<&str>,
+        maintain_order: bool,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let options = DistinctOptions {
             subset: subset.map(Arc::new),
-            maintain_order: false,
+            maintain_order,
             keep_strategy,
+            keep_by: keep_by.map(Arc::from),
             ..Default::default()
         };
         let lp = self.get_plan_builder().distinct(options).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Keep only the rows that occur more than once, annotated with the `group_id`,
+    /// `occurrence` and `count` of their set of duplicates. See [`DataFrame::duplicates`].
+    ///
+    /// `subset` is an optional `Vec` of column names to consider for duplicates; if None,
+    /// all columns are considered.
+    pub fn duplicates(self, subset: Option<Vec<String>>) -> LazyFrame {
+        // Filters and slices change which rows are duplicated, so they cannot be pushed
+        // past this node.
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            streaming: false,
+            ..Default::default()
+        };
+        let schema = |input_schema: &Schema| -> PolarsResult<SchemaRef> {
+            let mut schema = input_schema.clone();
+            for name in ["group_id", "occurrence", "count"] {
+                polars_ensure!(
+                    !schema.contains(name),
+                    Duplicate: "unable to hstack, column with name {:?} already exists", name
+                );
+                schema.with_column(name.into(), IDX_DTYPE);
+            }
+            Ok(Arc::new(schema))
+        };
+        self.map(
+            move |df| df.duplicates(subset.as_deref()),
+            optimizations,
+            Some(Arc::new(schema)),
+            Some("DUPLICATES"),
+        )
+    }
+
     /// Drop rows containing None.
     ///
     /// `subset` is an optional `Vec` of column names to consider for nulls; if None, all
@@ -1748,9 +2412,28 @@ impl LazyGroupBy {
 
     /// Apply a function over the groups as a new DataFrame.
     ///
+    /// The groups are processed in parallel. Unless the order is maintained with
+    /// [`LazyFrame::group_by_stable`], the output groups are not in a defined order.
+    ///
     /// **It is not recommended that you use this as materializing the DataFrame is very
     /// expensive.**
     pub fn apply<F>(self, f: F, schema: SchemaRef) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        self.apply_with_options(f, schema, Default::default())
+    }
+
+    /// Apply a function over the groups as a new DataFrame, see [`LazyGroupBy::apply`].
+    ///
+    /// `options` set whether the groups are processed one after the other instead of in
+    /// parallel, and whether the output of every group is checked against `schema`.
+    pub fn apply_with_options<F>(
+        self,
+        f: F,
+        schema: SchemaRef,
+        map_groups: MapGroupsOptions,
+    ) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
@@ -1759,10 +2442,14 @@ impl LazyGroupBy {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
             slice: None,
+            map_groups,
         };
 
         #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
+        let options = GroupbyOptions {
+            slice: None,
+            map_groups,
+        };
 
         let lp = DslPlan::GroupBy {
             input: Arc::new(self.logical_plan),
@@ -1789,6 +2476,9 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    drop_key_expressions: bool,
+    #[cfg(feature = "approx_unique")]
+    expansion_guard: Option<JoinExpansionGuard>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1805,6 +2495,9 @@ impl JoinBuilder {
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
+            drop_key_expressions: false,
+            #[cfg(feature = "approx_unique")]
+            expansion_guard: None,
         }
     }
 
@@ -1882,6 +2575,28 @@ impl JoinBuilder {
         self
     }
 
+    /// Only use the results of the key expressions that aren't columns as join keys, instead
+    /// of adding them to the output in place of the columns they are named after.
+    ///
+    /// The keys are computed in temporary columns that are removed from the output, so the
+    /// columns of the inputs are kept as they are. The coalesced keys of an outer join are
+    /// kept, as they aren't in any other column.
+    pub fn drop_key_expressions(mut self, drop: bool) -> Self {
+        self.drop_key_expressions = drop;
+        self
+    }
+
+    /// Estimate the output rows of the join before running it, from the approximate number
+    /// of distinct keys, and raise or warn if the estimate exceeds `max_factor` times the
+    /// rows of the larger input.
+    ///
+    /// This protects against accidental many-to-many joins that blow up the output.
+    #[cfg(feature = "approx_unique")]
+    pub fn max_expansion(mut self, max_factor: u32, action: JoinExpansionAction) -> Self {
+        self.expansion_guard = Some(JoinExpansionGuard { max_factor, action });
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -1897,6 +2612,8 @@ impl JoinBuilder {
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
+            #[cfg(feature = "approx_unique")]
+            expansion_guard: self.expansion_guard,
         };
 
         let lp = self
@@ -1910,6 +2627,7 @@ impl JoinBuilder {
                     allow_parallel: self.allow_parallel,
                     force_parallel: self.force_parallel,
                     args,
+                    drop_key_expressions: self.drop_key_expressions,
                     ..Default::default()
                 }
                 .into(),
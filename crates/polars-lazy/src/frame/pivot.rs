@@ -14,7 +14,7 @@ use crate::physical_plan::exotic::{prepare_eval_expr, prepare_expression_for_con
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 
-struct PivotExpr(Expr);
+struct PivotExpr(Expr, Option<Arc<str>>);
 
 impl PhysicalAggExpr for PivotExpr {
     fn evaluate(&self, df: &DataFrame, groups: &GroupsProxy) -> PolarsResult<Series> {
@@ -29,6 +29,21 @@ impl PhysicalAggExpr for PivotExpr {
     fn root_name(&self) -> PolarsResult<&str> {
         Ok("")
     }
+
+    fn name(&self) -> Option<&str> {
+        self.1.as_deref()
+    }
+}
+
+fn to_pivot_agg(agg_expr: Expr) -> PivotAgg {
+    // An alias names the aggregation in the generated column names.
+    let name = match &agg_expr {
+        Expr::Alias(_, name) => Some(name.clone()),
+        _ => None,
+    };
+    // make sure that the root column is replaced
+    let expr = prepare_eval_expr(agg_expr);
+    PivotAgg::Expr(Arc::new(PivotExpr(expr, name)))
 }
 
 pub fn pivot<I0, I1, I2, S0, S1, S2>(
@@ -49,11 +64,7 @@ where
     S1: AsRef<str>,
     S2: AsRef<str>,
 {
-    // make sure that the root column is replaced
-    let agg_expr = agg_expr.map(|agg_expr| {
-        let expr = prepare_eval_expr(agg_expr);
-        PivotAgg::Expr(Arc::new(PivotExpr(expr)))
-    });
+    let agg_expr = agg_expr.map(to_pivot_agg);
     polars_ops::pivot::pivot(
         df,
         index,
@@ -83,11 +94,7 @@ where
     S1: AsRef<str>,
     S2: AsRef<str>,
 {
-    // make sure that the root column is replaced
-    let agg_expr = agg_expr.map(|agg_expr| {
-        let expr = prepare_eval_expr(agg_expr);
-        PivotAgg::Expr(Arc::new(PivotExpr(expr)))
-    });
+    let agg_expr = agg_expr.map(to_pivot_agg);
     polars_ops::pivot::pivot_stable(
         df,
         index,
@@ -98,3 +105,38 @@ where
         separator,
     )
 }
+
+/// Pivot with several aggregation expressions at once. See [`polars_ops::pivot::pivot_multi`]
+/// for the naming of the generated columns; an aliased expression is named by its alias.
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_multi<I0, I1, I2, S0, S1, S2>(
+    df: &DataFrame,
+    index: I0,
+    columns: I1,
+    values: Option<I2>,
+    sort_columns: bool,
+    agg_exprs: Vec<Expr>,
+    name_template: Option<&str>,
+    // used as separator/delimiter in generated column names.
+    separator: Option<&str>,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: AsRef<str>,
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    let agg_fns = agg_exprs.into_iter().map(to_pivot_agg).collect();
+    polars_ops::pivot::pivot_multi(
+        df,
+        index,
+        columns,
+        values,
+        sort_columns,
+        agg_fns,
+        name_template,
+        separator,
+    )
+}
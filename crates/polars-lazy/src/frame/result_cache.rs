@@ -0,0 +1,55 @@
+use std::cell::Cell;
+use std::path::Path;
+
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::{SerReader, SerWriter};
+
+use super::*;
+
+fn read_cached_result(path: &Path) -> PolarsResult<DataFrame> {
+    let file = polars_utils::open_file(path)?;
+    IpcReader::new(file).memory_mapped(false).finish()
+}
+
+impl LazyFrame {
+    /// Execute the query like [`LazyFrame::collect`] and cache the result as an IPC file in
+    /// `cache_dir`, keyed by a fingerprint of the optimized plan and the versions of the files
+    /// it scans.
+    ///
+    /// Running the same query again returns the cached result without executing it, until
+    /// one of the scanned files is modified. Queries whose result isn't determined by the plan
+    /// and its files, e.g. queries on in-memory data, with user-defined functions or on cloud
+    /// storage, are executed and not cached.
+    pub fn collect_cached<P: AsRef<Path>>(self, cache_dir: P) -> PolarsResult<DataFrame> {
+        let fingerprint = Cell::new(None);
+        let (mut state, mut physical_plan, _) =
+            self.prepare_collect_post_opt(false, |root, lp_arena, expr_arena| {
+                fingerprint.set(plan_fingerprint(root, lp_arena, expr_arena)?);
+                Ok(())
+            })?;
+        let Some(fingerprint) = fingerprint.get() else {
+            return physical_plan.execute(&mut state);
+        };
+
+        let cache_dir = cache_dir.as_ref();
+        let path = cache_dir.join(format!("{fingerprint:016x}.ipc"));
+        if path.exists() {
+            // An unreadable cache file is overwritten.
+            if let Ok(df) = read_cached_result(&path) {
+                if state.verbose() {
+                    eprintln!("read query result from cache: {}", path.display());
+                }
+                return Ok(df);
+            }
+        }
+
+        let mut df = physical_plan.execute(&mut state)?;
+        std::fs::create_dir_all(cache_dir)?;
+        // Write to a temporary file first, so that a concurrent query never reads a partially
+        // written result.
+        let tmp_path = path.with_extension(format!("ipc.{}.tmp", std::process::id()));
+        IpcWriter::new(std::fs::File::create(&tmp_path)?).finish(&mut df)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(df)
+    }
+}
@@ -0,0 +1,54 @@
+use polars_time::Duration;
+
+use super::*;
+
+impl LazyFrame {
+    /// Upsample at a regular frequency: the rows between the first and the last value of
+    /// `time_column` are completed to one row for every `every` interval, for every group of
+    /// the `by` columns. The inserted rows are null in all other columns, including `by`.
+    ///
+    /// This is the lazy counterpart of [`polars_time::PolarsUpsample::upsample_stable`]: the groups keep
+    /// the order in which they first appear, and the time column becomes the first column.
+    /// The frame is sorted by `time_column` first, unless it is known to be sorted.
+    ///
+    /// See [`LazyFrame::complete`] to fill in the keys of the groups.
+    pub fn upsample<I: IntoVec<String>>(
+        self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        offset: Duration,
+    ) -> LazyFrame {
+        let by = by.into_vec();
+        self.map_private(DslFunction::FunctionNode(FunctionNode::Upsample {
+            by: by.iter().map(|s| Arc::from(s.as_str())).collect(),
+            time_column: Arc::from(time_column),
+            every,
+            offset,
+        }))
+    }
+
+    /// Insert the missing rows of a time series: for every group of the `by` columns, a row
+    /// is added for every `every` interval between the first and the last value of
+    /// `time_column` that is missing.
+    ///
+    /// The inserted rows get the keys of their group. Their other columns are null, or are
+    /// filled with `fill` within the group, e.g. with [`FillNullStrategy::Forward`] to carry
+    /// the last value forward; the nulls of the existing rows are kept. The groups keep the order in which they first appear, and the
+    /// rows of every group are sorted by `time_column`.
+    pub fn complete<I: IntoVec<String>>(
+        self,
+        time_column: &str,
+        every: Duration,
+        by: I,
+        fill: Option<FillNullStrategy>,
+    ) -> LazyFrame {
+        let by = by.into_vec();
+        self.map_private(DslFunction::FunctionNode(FunctionNode::Complete {
+            time_column: Arc::from(time_column),
+            every,
+            by: by.iter().map(|s| Arc::from(s.as_str())).collect(),
+            fill,
+        }))
+    }
+}
@@ -0,0 +1,133 @@
+use super::*;
+
+fn mask_name(rule: usize) -> String {
+    format!("__POLARS_VALIDATE_{rule}")
+}
+
+/// Split a frame with a mask column per rule into the rows that pass all the rules and the
+/// rows that fail any of them, the latter with the names of the rules they fail.
+fn split(mut df: DataFrame, rules: &[String]) -> PolarsResult<(DataFrame, DataFrame)> {
+    let masks = (0..rules.len())
+        .map(|i| Ok(df.drop_in_place(&mask_name(i))?.bool()?.clone()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let passed = masks
+        .iter()
+        .fold(BooleanChunked::full("", true, df.height()), |acc, mask| {
+            &acc & mask
+        });
+    let failed = !&passed;
+
+    let clean = df.filter(&passed)?;
+    let mut rejected = df.filter(&failed)?;
+    let masks = masks
+        .iter()
+        .map(|mask| Ok(mask.filter(&failed)?.rechunk()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut builder = ListStringChunkedBuilder::new(
+        "failed_rules",
+        rejected.height(),
+        rejected.height() * rules.len(),
+    );
+    for row in 0..rejected.height() {
+        builder.append_values_iter(
+            rules
+                .iter()
+                .zip(&masks)
+                .filter(|(_, mask)| mask.get(row) == Some(false))
+                .map(|(rule, _)| rule.as_str()),
+        );
+    }
+    rejected.with_column(builder.finish().into_series())?;
+    Ok((clean, rejected))
+}
+
+/// The outcome of [`LazyFrame::validate`].
+///
+/// The clean and the rejected rows can be queried separately or, with
+/// [`collect`](Validation::collect), computed together from a single pass over the input.
+#[derive(Clone)]
+pub struct Validation {
+    input: LazyFrame,
+    rules: Vec<Expr>,
+    names: Arc<[String]>,
+}
+
+impl Validation {
+    /// A frame with the evaluated rules added as boolean columns. Nulls fail a rule.
+    fn annotated(&self) -> LazyFrame {
+        let masks = self
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| rule.clone().fill_null(lit(false)).alias(&mask_name(i)))
+            .collect::<Vec<_>>();
+        self.input.clone().with_columns(masks)
+    }
+
+    /// The rows that pass all the rules.
+    pub fn clean(&self) -> LazyFrame {
+        let passed = self
+            .rules
+            .iter()
+            .map(|rule| rule.clone().fill_null(lit(false)))
+            .fold(lit(true), |acc, rule| acc.and(rule));
+        self.input.clone().filter(passed)
+    }
+
+    /// The rows that fail any of the rules, with a `failed_rules` column listing the names
+    /// of the rules they fail.
+    pub fn rejected(&self) -> LazyFrame {
+        let rules = self.names.clone();
+        let n_rules = rules.len();
+        let schema = move |input_schema: &Schema| -> PolarsResult<SchemaRef> {
+            let mut schema = input_schema.clone();
+            for i in 0..n_rules {
+                schema.shift_remove(&mask_name(i));
+            }
+            schema.with_column(
+                "failed_rules".into(),
+                DataType::List(Box::new(DataType::String)),
+            );
+            Ok(Arc::new(schema))
+        };
+        // The rows that are kept depend on all the input rows and columns.
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            streaming: false,
+            ..Default::default()
+        };
+        self.annotated().map(
+            move |df| split(df, &rules).map(|(_, rejected)| rejected),
+            optimizations,
+            Some(Arc::new(schema)),
+            Some("VALIDATE"),
+        )
+    }
+
+    /// Execute the input once and return the clean and the rejected rows.
+    pub fn collect(self) -> PolarsResult<(DataFrame, DataFrame)> {
+        split(self.annotated().collect()?, &self.names)
+    }
+}
+
+impl LazyFrame {
+    /// Check every row against a set of boolean `rules`.
+    ///
+    /// A row passes a rule if the rule evaluates to `true`; `false` and null fail it. The
+    /// rules are named by their output names. The returned [`Validation`] gives access to
+    /// the rows that pass all the rules and to the rows that fail any of them, annotated with
+    /// the names of the failed rules.
+    pub fn validate<E: AsRef<[Expr]>>(self, rules: E) -> PolarsResult<Validation> {
+        let rules = rules.as_ref().to_vec();
+        let names = rules
+            .iter()
+            .map(|rule| Ok(expr_output_name(rule)?.to_string()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Validation {
+            input: self,
+            rules,
+            names: names.into(),
+        })
+    }
+}
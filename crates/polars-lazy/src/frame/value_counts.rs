@@ -0,0 +1,165 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_ops::series::SeriesMethods;
+
+use super::*;
+
+const BATCH: &str = "__POLARS_VALUE_COUNTS_BATCH";
+const FLOOR: &str = "__POLARS_VALUE_COUNTS_FLOOR";
+
+/// Options of [`LazyFrame::value_counts`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ValueCountsOptions {
+    /// Only return the `n` most frequent values.
+    pub top_n: Option<usize>,
+    /// Keep at most this many distinct values per batch and estimate the counts of the
+    /// most frequent values from these summaries. If `None`, the counts are exact.
+    pub max_counters: Option<usize>,
+}
+
+/// Summarize a batch by its `max_counters` most frequent values.
+///
+/// Every value that is dropped occurs at most `floor` times in the batch, which bounds the
+/// error of the merged estimates.
+fn summarize_batch(
+    df: DataFrame,
+    column: &str,
+    max_counters: usize,
+    batch: u64,
+) -> PolarsResult<DataFrame> {
+    let counts = df.column(column)?.value_counts(true, false)?;
+    let (counts, floor) = if counts.height() > max_counters {
+        let floor = counts.column("count")?.get(max_counters)?;
+        (
+            counts.head(Some(max_counters)),
+            floor.extract::<IdxSize>().unwrap(),
+        )
+    } else {
+        (counts, 0)
+    };
+    let height = counts.height();
+    counts.hstack(&[
+        Series::new(BATCH, [batch]).new_from_index(0, height),
+        Series::new(FLOOR, [floor]).new_from_index(0, height),
+    ])
+}
+
+/// Merge the batch summaries into estimated counts.
+///
+/// The estimate of a value is the sum of its counts in the summaries it is part of, plus
+/// the floors of the summaries it was dropped from. That added part is its `error`, so
+/// `count - error` is a lower bound of the true count.
+fn merge_summaries(df: DataFrame, column: &str, top_n: Option<usize>) -> PolarsResult<DataFrame> {
+    let total_floor = df
+        .clone()
+        .lazy()
+        .group_by([col(BATCH)])
+        .agg([col(FLOOR).first()])
+        .select([col(FLOOR).sum()])
+        .collect()?;
+    let total_floor = total_floor
+        .column(FLOOR)?
+        .get(0)?
+        .extract::<IdxSize>()
+        .unwrap_or(0);
+
+    let error = lit(total_floor) - col(FLOOR).sum();
+    let out = df
+        .lazy()
+        .group_by([col(column)])
+        .agg([
+            (col("count").sum() + error.clone()).alias("count"),
+            error.alias("error"),
+        ])
+        .sort(
+            ["count"],
+            SortMultipleOptions::default().with_order_descending(true),
+        );
+    match top_n {
+        Some(n) => out.limit(n as IdxSize).collect(),
+        None => out.collect(),
+    }
+}
+
+impl LazyFrame {
+    /// Count the occurrences of every value of `column`, most frequent first.
+    ///
+    /// The result has the columns `column` and `count`. By default the counts are exact
+    /// and computed with a streaming group by, so they are limited by the number of
+    /// distinct values that fit in memory.
+    ///
+    /// For columns with too many distinct values, set `max_counters` to find the most
+    /// frequent values approximately: every batch is summarized by its `max_counters`
+    /// most frequent values, and the summaries are merged into an estimated `count` with
+    /// an `error` column that bounds how much the estimate exceeds the true count. If a
+    /// batch has no more than `max_counters` distinct values its counts stay exact, so
+    /// the error is zero when the column fits in the summaries.
+    pub fn value_counts(self, column: &str, options: ValueCountsOptions) -> LazyFrame {
+        let ValueCountsOptions {
+            top_n,
+            max_counters,
+        } = options;
+        let input = self.select([col(column)]);
+        let Some(max_counters) = max_counters else {
+            let out = input
+                .group_by([col(column)])
+                .agg([len().alias("count")])
+                .sort(
+                    ["count"],
+                    SortMultipleOptions::default().with_order_descending(true),
+                );
+            return match top_n {
+                Some(n) => out.limit(n as IdxSize),
+                None => out,
+            };
+        };
+
+        let name = column.to_string();
+        let batch = AtomicU64::new(0);
+        let summarize = move |df: DataFrame| {
+            let batch = batch.fetch_add(1, Ordering::Relaxed);
+            summarize_batch(df, &name, max_counters.max(1), batch)
+        };
+        let summary_schema = |input_schema: &Schema| -> PolarsResult<SchemaRef> {
+            let mut schema = input_schema.clone();
+            schema.with_column("count".into(), IDX_DTYPE);
+            schema.with_column(BATCH.into(), DataType::UInt64);
+            schema.with_column(FLOOR.into(), IDX_DTYPE);
+            Ok(Arc::new(schema))
+        };
+        // The summaries don't pass their input through, so nothing may be pushed past them.
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            slice_pushdown: false,
+            ..Default::default()
+        };
+        // Every batch is summarized on its own, so the summaries can be built while
+        // streaming. The merge needs all of them and runs after the pipeline.
+        let summaries = input.map(
+            summarize,
+            AllowedOptimizations {
+                streaming: true,
+                ..optimizations
+            },
+            Some(Arc::new(summary_schema)),
+            Some("VALUE_COUNTS_SUMMARY"),
+        );
+
+        let name = column.to_string();
+        let merge = move |df: DataFrame| merge_summaries(df, &name, top_n);
+        let merged_schema = |input_schema: &Schema| -> PolarsResult<SchemaRef> {
+            let mut schema = input_schema.clone();
+            schema.shift_remove(BATCH);
+            schema.shift_remove(FLOOR);
+            schema.with_column("error".into(), IDX_DTYPE);
+            Ok(Arc::new(schema))
+        };
+        summaries.map(
+            merge,
+            optimizations,
+            Some(Arc::new(merged_schema)),
+            Some("VALUE_COUNTS_MERGE"),
+        )
+    }
+}
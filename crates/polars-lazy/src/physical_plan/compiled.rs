@@ -0,0 +1,103 @@
+use polars_core::prelude::*;
+
+use crate::physical_plan::planner::create_physical_expr;
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+
+/// An expression that is compiled against a schema once and can then be evaluated over many
+/// batches of that schema, without building a query for every batch.
+///
+/// The expression is evaluated as in a `select` on the batch, so aggregations evaluate to a
+/// single value per batch.
+///
+/// # Example
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// # fn example() -> PolarsResult<()> {
+/// let schema = Schema::from_iter([Field::new("price", DataType::Float64)]);
+/// let expr = CompiledExpr::try_new(col("price") * lit(2.0), Arc::new(schema))?;
+///
+/// let batch = vec![Series::new("price", [1.0, 2.5])];
+/// let out = expr.evaluate_columns(batch)?;
+/// assert_eq!(Vec::from(out.f64()?), [Some(2.0), Some(5.0)]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CompiledExpr {
+    schema: SchemaRef,
+    field: Field,
+    phys_expr: Arc<dyn PhysicalExpr>,
+}
+
+impl CompiledExpr {
+    /// Compile `expr` for batches with `schema`. The expression must have a single output.
+    pub fn try_new(expr: Expr, schema: SchemaRef) -> PolarsResult<Self> {
+        let mut lp_arena = Arena::with_capacity(8);
+        let mut expr_arena = Arena::with_capacity(16);
+
+        // Run type coercion and expression simplification on a select over an empty frame
+        // of the schema.
+        let lf = DataFrame::from(schema.as_ref())
+            .lazy()
+            .without_optimizations()
+            .with_simplify_expr(true)
+            .select([expr]);
+        let optimized = lf.optimize(&mut lp_arena, &mut expr_arena)?;
+        let mut exprs = lp_arena.get(optimized).get_exprs();
+        polars_ensure!(
+            exprs.len() == 1,
+            InvalidOperation: "a compiled expression must have a single output, got {}", exprs.len()
+        );
+        let expr = exprs.pop().unwrap();
+
+        let phys_expr = create_physical_expr(
+            &expr,
+            Context::Default,
+            &expr_arena,
+            Some(&schema),
+            &mut Default::default(),
+        )?;
+        let field = phys_expr.to_field(&schema)?;
+        Ok(Self {
+            schema,
+            field,
+            phys_expr,
+        })
+    }
+
+    /// The schema of the batches the expression is evaluated over.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// The name and the data type of the output of the expression.
+    pub fn output_field(&self) -> &Field {
+        &self.field
+    }
+
+    /// Evaluate the expression over `batch`, whose columns must match the schema the
+    /// expression was compiled against.
+    pub fn evaluate(&self, batch: &DataFrame) -> PolarsResult<Series> {
+        polars_ensure!(
+            batch.width() == self.schema.len(),
+            SchemaMismatch: "expected a batch with {} columns, got {}", self.schema.len(), batch.width()
+        );
+        for (s, (name, dtype)) in batch.get_columns().iter().zip(self.schema.iter()) {
+            polars_ensure!(
+                s.name() == name.as_str() && s.dtype() == dtype,
+                SchemaMismatch: "expected column '{}' of type {}, got column '{}' of type {}",
+                name, dtype, s.name(), s.dtype()
+            );
+        }
+        // A fresh state, so that no caches are shared between batches.
+        let state = ExecutionState::new();
+        self.phys_expr.evaluate(batch, &state)
+    }
+
+    /// Evaluate the expression over a batch of `columns`, which must have the same length.
+    pub fn evaluate_columns(&self, columns: Vec<Series>) -> PolarsResult<Series> {
+        self.evaluate(&DataFrame::new(columns)?)
+    }
+}
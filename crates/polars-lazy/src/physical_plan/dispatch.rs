@@ -0,0 +1,78 @@
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Executes a subtree of the plan that was claimed by a [`PhysicalPlanDispatch`].
+pub trait DispatchedExecutor: Send {
+    fn execute(&mut self) -> PolarsResult<DataFrame>;
+}
+
+/// An execution engine, e.g. on a GPU, that executes the parts of a query it supports.
+///
+/// The optimized plan is offered to the engine from the root down. Every subtree the engine
+/// claims in [`supports`](PhysicalPlanDispatch::supports) is executed by the executor it
+/// creates for it, the rest of the plan falls back to the CPU engines. Claimed subtrees may
+/// be part of a larger plan, so the output of the executor must have the schema of the
+/// claimed node.
+pub trait PhysicalPlanDispatch: Send + Sync {
+    /// The name of the engine, shown in the plan in place of the claimed subtrees.
+    fn name(&self) -> &'static str;
+
+    /// Whether the engine can execute the subtree with root `node`.
+    fn supports(&self, node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> bool;
+
+    /// Create the executor of a subtree that is supported by the engine.
+    fn create_executor(
+        &self,
+        node: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> PolarsResult<Box<dyn DispatchedExecutor>>;
+}
+
+/// Replace the subtrees of the plan at `root` that are claimed by `engine` with nodes that
+/// run the executors of the engine.
+pub fn dispatch_plan(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    engine: &dyn PhysicalPlanDispatch,
+) -> PolarsResult<()> {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if !engine.supports(node, lp_arena, expr_arena) {
+            lp_arena.get(node).copy_inputs(&mut stack);
+            continue;
+        }
+        let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+        let executor = Mutex::new(engine.create_executor(node, lp_arena, expr_arena)?);
+
+        // The map function will call its input, so we give it an empty one, the
+        // same as the streaming engine does.
+        let dummy = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty()),
+            schema: Arc::new(Schema::new()),
+            output_schema: None,
+            projection: None,
+            selection: None,
+        });
+        let function = move |_df: DataFrame| executor.lock().unwrap().execute();
+        let output_schema =
+            move |_input_schema: &Schema| -> PolarsResult<SchemaRef> { Ok(schema.clone()) };
+        let lp = IR::MapFunction {
+            input: dummy,
+            function: FunctionNode::Opaque {
+                function: Arc::new(function),
+                schema: Some(Arc::new(output_schema)),
+                predicate_pd: false,
+                projection_pd: false,
+                streamable: false,
+                fmt_str: engine.name(),
+            },
+        };
+        lp_arena.replace(node, lp);
+    }
+    Ok(())
+}
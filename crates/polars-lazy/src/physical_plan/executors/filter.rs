@@ -54,6 +54,7 @@ impl FilterExec {
         state: &ExecutionState,
     ) -> PolarsResult<DataFrame> {
         let iter = chunks.into_par_iter().map(|df| {
+            let _sc = state.enter_string_cache();
             let s = self.predicate.evaluate(&df, state)?;
             df.filter(series_to_mask(&s)?)
         });
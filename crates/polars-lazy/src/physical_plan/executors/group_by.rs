@@ -1,3 +1,4 @@
+use polars_core::utils::accumulate_dataframes_vertical;
 use rayon::prelude::*;
 
 use super::*;
@@ -11,6 +12,7 @@ pub(super) fn evaluate_aggs(
     POOL.install(|| {
         aggs.par_iter()
             .map(|expr| {
+                let _sc = state.enter_string_cache();
                 let agg = expr.evaluate_on_groups(df, groups, state)?.finalize();
                 polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
                 Ok(agg)
@@ -19,12 +21,81 @@ pub(super) fn evaluate_aggs(
     })
 }
 
+/// Check that the output of a group in `map_groups` has the declared `schema`.
+fn check_group_output(out: &DataFrame, schema: &Schema) -> PolarsResult<()> {
+    let matches = out.width() == schema.len()
+        && out
+            .get_columns()
+            .iter()
+            .zip(schema.iter())
+            .all(|(s, (name, dtype))| s.name() == name.as_str() && s.dtype() == dtype);
+    polars_ensure!(
+        matches,
+        SchemaMismatch: "the output of a group in `map_groups` has schema {:?}, but {:?} was declared",
+        out.schema(), schema
+    );
+    Ok(())
+}
+
+/// Apply `f` to every group of `df`, in parallel unless `options.sequential` is set.
+///
+/// A group is only materialized right before `f` is applied to it, so the group inputs are
+/// not all in memory at once. If the order doesn't have to be maintained, the largest
+/// groups are started first to balance the threads, and the output is in that order.
+fn map_groups(
+    df: &DataFrame,
+    groups: &GroupsProxy,
+    f: &dyn DataFrameUdf,
+    schema: &Schema,
+    options: MapGroupsOptions,
+    maintain_order: bool,
+    state: &ExecutionState,
+) -> PolarsResult<DataFrame> {
+    let apply = |i: usize| {
+        // SAFETY: the groups are in bounds.
+        let group = match groups.get(i) {
+            GroupsIndicator::Idx((_, idx)) => unsafe { df.take_slice_unchecked(idx) },
+            GroupsIndicator::Slice([first, len]) => df.slice(first as i64, len as usize),
+        };
+        let out = f.call_udf(group)?;
+        if options.validate_output {
+            check_group_output(&out, schema)?;
+        }
+        Ok(out)
+    };
+    let dfs = if options.sequential {
+        (0..groups.len())
+            .map(apply)
+            .collect::<PolarsResult<Vec<_>>>()?
+    } else {
+        let mut order = (0..groups.len()).collect::<Vec<_>>();
+        if !maintain_order {
+            order.sort_unstable_by_key(|&i| std::cmp::Reverse(groups.get(i).len()));
+        }
+        POOL.install(|| {
+            order
+                .into_par_iter()
+                .map(|i| {
+                    let _sc = state.enter_string_cache();
+                    apply(i)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?
+    };
+    if dfs.is_empty() {
+        return Ok(DataFrame::from(schema));
+    }
+    let mut out = accumulate_dataframes_vertical(dfs)?;
+    out.as_single_chunk_par();
+    Ok(out)
+}
+
 /// Take an input Executor and a multiple expressions
 pub struct GroupByExec {
     input: Box<dyn Executor>,
     keys: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Arc<dyn PhysicalExpr>>,
-    apply: Option<Arc<dyn DataFrameUdf>>,
+    apply: Option<(Arc<dyn DataFrameUdf>, SchemaRef, MapGroupsOptions)>,
     maintain_order: bool,
     input_schema: SchemaRef,
     slice: Option<(i64, usize)>,
@@ -36,7 +107,7 @@ impl GroupByExec {
         input: Box<dyn Executor>,
         keys: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Arc<dyn PhysicalExpr>>,
-        apply: Option<Arc<dyn DataFrameUdf>>,
+        apply: Option<(Arc<dyn DataFrameUdf>, SchemaRef, MapGroupsOptions)>,
         maintain_order: bool,
         input_schema: SchemaRef,
         slice: Option<(i64, usize)>,
@@ -58,20 +129,30 @@ pub(super) fn group_by_helper(
     mut df: DataFrame,
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
-    apply: Option<Arc<dyn DataFrameUdf>>,
+    apply: Option<(Arc<dyn DataFrameUdf>, SchemaRef, MapGroupsOptions)>,
     state: &ExecutionState,
     maintain_order: bool,
     slice: Option<(i64, usize)>,
 ) -> PolarsResult<DataFrame> {
-    df.as_single_chunk_par();
-    let gb = df.group_by_with_series(keys, true, maintain_order)?;
-
-    if let Some(f) = apply {
-        return gb.apply(move |df| f.call_udf(df));
+    if state.auto_rechunk() {
+        df.as_single_chunk_par();
     }
+    let gb = df.group_by_with_series(keys, true, maintain_order)?;
 
     let mut groups = gb.get_groups();
 
+    if let Some((f, schema, options)) = apply {
+        return map_groups(
+            &df,
+            groups,
+            f.as_ref(),
+            &schema,
+            options,
+            maintain_order,
+            state,
+        );
+    }
+
     #[allow(unused_assignments)]
     // it is unused because we only use it to keep the lifetime of sliced_group valid
     let mut sliced_groups = None;
@@ -83,6 +83,7 @@ fn run_partitions(
         dfs.into_par_iter()
             .zip(splitted_keys)
             .map(|(df, keys)| {
+                let _sc = state.enter_string_cache();
                 let gb = df.group_by_with_series(keys.into(), false, maintain_order)?;
                 let groups = gb.get_groups();
 
@@ -326,6 +327,7 @@ impl PartitionGroupByExec {
                 // we slice the keys off and finalize every aggregation
                 .zip(&df.get_columns()[self.phys_keys.len()..])
                 .map(|(expr, partitioned_s)| {
+                    let _sc = state.enter_string_cache();
                     let agg_expr = expr.as_partitioned_aggregator().unwrap();
                     agg_expr.finalize(partitioned_s.clone(), groups, state)
                 })
@@ -46,6 +46,7 @@ impl Executor for HConcatExec {
                             .into_par_iter()
                             .enumerate()
                             .map(|(idx, input)| {
+                                let _sc = state.enter_string_cache();
                                 let mut input = std::mem::take(input);
                                 let mut state = state.split();
                                 state.branch_idx += idx;
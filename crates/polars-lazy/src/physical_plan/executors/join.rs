@@ -57,10 +57,12 @@ impl Executor for JoinExec {
             POOL.join(
                 move || {
                     FETCH_ROWS.with(|fr| fr.set(fetch_rows));
+                    let _sc = state_left.enter_string_cache();
                     input_left.execute(&mut state_left)
                 },
                 move || {
                     FETCH_ROWS.with(|fr| fr.set(fetch_rows));
+                    let _sc = state_right.enter_string_cache();
                     input_right.execute(&mut state_right)
                 },
             )
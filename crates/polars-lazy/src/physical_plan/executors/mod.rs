@@ -17,6 +17,7 @@ mod scan;
 mod slice;
 mod sort;
 mod stack;
+mod top_k;
 mod udf;
 mod union;
 mod unique;
@@ -49,6 +50,7 @@ pub(super) use self::scan::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
 pub(super) use self::stack::*;
+pub(super) use self::top_k::*;
 pub(super) use self::udf::*;
 pub(super) use self::union::*;
 pub(super) use self::unique::*;
@@ -31,6 +31,7 @@ impl ProjectionExec {
         {
             let chunks = df.split_chunks().collect::<Vec<_>>();
             let iter = chunks.into_par_iter().map(|mut df| {
+                let _sc = state.enter_string_cache();
                 let selected_cols = evaluate_physical_expressions(
                     &mut df,
                     &self.cse_exprs,
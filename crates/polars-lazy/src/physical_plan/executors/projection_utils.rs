@@ -31,6 +31,7 @@ fn rolling_evaluate(
         rolling
             .par_iter()
             .map(|(options, partition)| {
+                let _sc = state.enter_string_cache();
                 // clear the cache for every partitioned group
                 let state = state.split();
 
@@ -46,7 +47,10 @@ fn rolling_evaluate(
                 }
                 partition
                     .par_iter()
-                    .map(|(idx, expr)| expr.evaluate(df, &state).map(|s| (*idx, s)))
+                    .map(|(idx, expr)| {
+                        let _sc = state.enter_string_cache();
+                        expr.evaluate(df, &state).map(|s| (*idx, s))
+                    })
                     .collect::<PolarsResult<Vec<_>>>()
             })
             .collect()
@@ -62,6 +66,7 @@ fn window_evaluate(
         window
             .par_iter()
             .map(|(_, partition)| {
+                let _sc = state.enter_string_cache();
                 // clear the cache for every partitioned group
                 let mut state = state.split();
                 // inform the expression it has window functions.
@@ -155,7 +160,10 @@ fn execute_projection_cached_window_fns(
     let mut selected_columns = POOL.install(|| {
         other
             .par_iter()
-            .map(|(idx, expr)| expr.evaluate(df, state).map(|s| (*idx, s)))
+            .map(|(idx, expr)| {
+                let _sc = state.enter_string_cache();
+                expr.evaluate(df, state).map(|s| (*idx, s))
+            })
             .collect::<PolarsResult<Vec<_>>>()
     })?;
 
@@ -199,7 +207,10 @@ fn run_exprs_par(
     POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
+            .map(|expr| {
+                let _sc = state.enter_string_cache();
+                expr.evaluate(df, state)
+            })
             .collect()
     })
 }
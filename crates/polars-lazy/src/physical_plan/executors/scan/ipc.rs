@@ -6,7 +6,7 @@ use polars_core::config;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::cloud::CloudOptions;
 use polars_io::predicates::apply_predicate;
-use polars_io::utils::is_cloud_url;
+use polars_io::utils::{is_cloud_url, max_concurrent_decoders};
 use polars_io::RowIndex;
 use rayon::prelude::*;
 
@@ -77,48 +77,68 @@ impl IpcExec {
             .map(|n| IdxSize::try_from(n).unwrap());
 
         let row_limit = n_rows.unwrap_or(IdxSize::MAX);
+        // Leading rows that are skipped, this only applies to a scan of a single file.
+        let skip_rows = if self.paths.len() == 1 {
+            self.options.skip_rows
+        } else {
+            0
+        };
 
         // Used to determine the next file to open. This guarantees the order.
         let path_index = AtomicUsize::new(0);
         let row_counter = RwLock::new(ConsecutiveCountState::new(self.paths.len()));
 
-        let index_and_dfs = (0..self.paths.len())
-            .into_par_iter()
-            .map(|_| -> PolarsResult<(usize, DataFrame)> {
-                let index = path_index.fetch_add(1, Ordering::Relaxed);
-                let path = &self.paths[index];
+        let read_file = |_| -> PolarsResult<(usize, DataFrame)> {
+            let index = path_index.fetch_add(1, Ordering::Relaxed);
+            let path = &self.paths[index];
 
-                let already_read_in_sequence = row_counter.read().unwrap().sum();
-                if already_read_in_sequence >= row_limit {
-                    return Ok((index, Default::default()));
-                }
-
-                let file = std::fs::File::open(path)?;
-
-                let df = IpcReader::new(file)
-                    .with_n_rows(
-                        // NOTE: If there is any file that by itself exceeds the
-                        // row limit, passing the total row limit to each
-                        // individual reader helps.
-                        n_rows.map(|n| {
-                            n.saturating_sub(already_read_in_sequence)
-                                .try_into()
-                                .unwrap()
-                        }),
-                    )
-                    .with_row_index(self.file_options.row_index.clone())
-                    .with_projection(projection.clone())
-                    .memory_mapped(self.options.memory_map)
-                    .finish()?;
+            let already_read_in_sequence = row_counter.read().unwrap().sum();
+            if already_read_in_sequence >= row_limit {
+                return Ok((index, Default::default()));
+            }
 
-                row_counter
-                    .write()
-                    .unwrap()
-                    .write(index, df.height().try_into().unwrap());
+            let file = std::fs::File::open(path)?;
+
+            let df = IpcReader::new(file)
+                .with_n_rows(
+                    // NOTE: If there is any file that by itself exceeds the
+                    // row limit, passing the total row limit to each
+                    // individual reader helps.
+                    n_rows.map(|n| {
+                        n.saturating_sub(already_read_in_sequence)
+                            .try_into()
+                            .unwrap()
+                    }),
+                )
+                .with_row_index(self.file_options.row_index.clone())
+                .with_skip_rows(skip_rows)
+                .with_projection(projection.clone())
+                .memory_mapped(self.options.memory_map)
+                .finish()?;
+
+            row_counter
+                .write()
+                .unwrap()
+                .write(index, df.height().try_into().unwrap());
+
+            Ok((index, df))
+        };
 
-                Ok((index, df))
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
+        // Bound the number of files that are decoded at the same time.
+        let max_decoders = max_concurrent_decoders(
+            self.options.low_memory,
+            self.options.max_decoders,
+            POOL.current_num_threads(),
+        );
+        let mut index_and_dfs = Vec::with_capacity(self.paths.len());
+        for start in (0..self.paths.len()).step_by(max_decoders) {
+            let end = std::cmp::min(start + max_decoders, self.paths.len());
+            let dfs = (start..end)
+                .into_par_iter()
+                .map(&read_file)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            index_and_dfs.extend(dfs);
+        }
 
         finish_index_and_dfs(
             index_and_dfs,
@@ -203,7 +223,11 @@ impl IpcExec {
                 }
             })
             .assert_send()
-            .buffer_unordered(config::get_file_prefetch_size())
+            .buffer_unordered(max_concurrent_decoders(
+                self.options.low_memory,
+                self.options.max_decoders,
+                config::get_file_prefetch_size(),
+            ))
             .try_collect::<Vec<_>>()
             .await?;
 
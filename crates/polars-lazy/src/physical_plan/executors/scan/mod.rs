@@ -6,6 +6,8 @@ mod ipc;
 mod ndjson;
 #[cfg(feature = "parquet")]
 mod parquet;
+#[cfg(all(feature = "parquet", feature = "cloud"))]
+mod readahead;
 
 #[cfg(feature = "ipc")]
 mod support;
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use super::*;
 
 impl AnonymousScan for LazyJsonLineReader {
@@ -38,4 +40,44 @@ impl AnonymousScan for LazyJsonLineReader {
     fn allows_projection_pushdown(&self) -> bool {
         true
     }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+
+    fn allows_count_rows(&self) -> bool {
+        true
+    }
+
+    fn count_rows(&self) -> PolarsResult<usize> {
+        polars_io::ndjson::core::count_rows(&self.path)
+    }
+
+    fn batched(
+        &self,
+        scan_opts: AnonymousScanArgs,
+        chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+        let chunk_size = self
+            .batch_size
+            .or_else(|| NonZeroUsize::new(chunk_size))
+            .unwrap_or(NonZeroUsize::MIN);
+        let reader = BatchedJsonLineReader::new(
+            &self.path,
+            schema,
+            chunk_size,
+            scan_opts.n_rows,
+            self.ignore_errors,
+        )?;
+        Ok(Box::new(Batches(reader)))
+    }
+}
+
+struct Batches(BatchedJsonLineReader);
+
+impl AnonymousScanBatches for Batches {
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        self.0.next_batches(n)
+    }
 }
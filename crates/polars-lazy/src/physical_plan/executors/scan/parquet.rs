@@ -2,14 +2,19 @@ use std::path::PathBuf;
 
 use polars_core::config;
 #[cfg(feature = "cloud")]
-use polars_core::config::{get_file_prefetch_size, verbose};
+use polars_core::config::{
+    get_file_prefetch_size, get_file_readahead_bytes, get_file_readahead_size, verbose,
+};
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
-use polars_io::parquet::read::materialize_empty_df;
-use polars_io::utils::is_cloud_url;
+use polars_io::parquet::read::{materialize_empty_df, FileDecryptionProperties, NestedProjection};
+use polars_io::parquet::{DatasetStatistics, FileFingerprint};
+use polars_io::utils::{is_cloud_url, max_concurrent_decoders};
 use polars_io::RowIndex;
 
+#[cfg(feature = "cloud")]
+use super::readahead::{PrefetchBudget, Readahead};
 use super::*;
 
 pub struct ParquetExec {
@@ -22,9 +27,12 @@ pub struct ParquetExec {
     file_options: FileScanOptions,
     #[allow(dead_code)]
     metadata: Option<FileMetaDataRef>,
+    nested_projection: NestedProjection,
+    decryption: Option<Arc<FileDecryptionProperties>>,
 }
 
 impl ParquetExec {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         paths: Arc<[PathBuf]>,
         file_info: FileInfo,
@@ -33,6 +41,8 @@ impl ParquetExec {
         cloud_options: Option<CloudOptions>,
         file_options: FileScanOptions,
         metadata: Option<FileMetaDataRef>,
+        nested_projection: NestedProjection,
+        decryption: Option<Arc<FileDecryptionProperties>>,
     ) -> Self {
         ParquetExec {
             paths,
@@ -42,7 +52,90 @@ impl ParquetExec {
             cloud_options,
             file_options,
             metadata,
+            nested_projection,
+            decryption,
+        }
+    }
+
+    /// The number of rows that have to pass the predicate before the scan can stop. This only
+    /// applies if the predicate is evaluated by the readers.
+    fn n_rows_after_predicate(&self) -> Option<usize> {
+        self.predicate
+            .as_ref()
+            .and(self.options.n_rows_after_predicate)
+    }
+
+    /// Skip the files whose row groups can't have rows for which the predicate holds, as
+    /// shown by the statistics file of the dataset, without reading their footers.
+    fn prune_with_statistics_file(&mut self) -> PolarsResult<()> {
+        let Some(predicate) = &self.predicate else {
+            return Ok(());
+        };
+        // Skipping files would change the row index and the rows that a slice takes.
+        if !self.options.use_statistics
+            || self.paths.len() < 2
+            || is_cloud_url(self.paths[0].as_path())
+            || self.file_options.row_index.is_some()
+            || self.file_options.n_rows.is_some()
+        {
+            return Ok(());
+        }
+        let Some(statistics_path) = DatasetStatistics::find(&self.paths) else {
+            return Ok(());
+        };
+        // A statistics file that can't be read only means that no files are skipped.
+        let statistics =
+            match polars_utils::open_file(&statistics_path).and_then(DatasetStatistics::read) {
+                Ok(statistics) => statistics,
+                Err(err) => {
+                    if config::verbose() {
+                        eprintln!(
+                            "the statistics file {} can't be read: {}",
+                            statistics_path.display(),
+                            err
+                        );
+                    }
+                    return Ok(());
+                },
+            };
+        let root = statistics_path.parent().unwrap();
+        let predicate = phys_expr_to_io_expr(predicate.clone());
+
+        let mut paths = Vec::with_capacity(self.paths.len());
+        for path in self.paths.iter() {
+            let skip = match DatasetStatistics::relative_path(root, path) {
+                Some(relative_path) => {
+                    let mut file_info = self.file_info.clone();
+                    file_info.update_hive_partitions(path)?;
+                    let hive_stats = file_info
+                        .hive_parts
+                        .as_ref()
+                        .map(|hive| hive.get_statistics().column_stats().to_vec())
+                        .unwrap_or_default();
+                    statistics.can_skip_file(
+                        &relative_path,
+                        FileFingerprint::of(path)?,
+                        predicate.as_ref(),
+                        &self.file_info.schema,
+                        &hive_stats,
+                    )?
+                },
+                None => false,
+            };
+            if !skip {
+                paths.push(path.clone());
+            }
         }
+        if config::verbose() {
+            eprintln!(
+                "the statistics file {} skipped {} of {} parquet files",
+                statistics_path.display(),
+                self.paths.len() - paths.len(),
+                self.paths.len()
+            );
+        }
+        self.paths = paths.into();
+        Ok(())
     }
 
     fn read_par(&mut self) -> PolarsResult<Vec<DataFrame>> {
@@ -57,13 +150,31 @@ impl ParquetExec {
 
         let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
         let mut base_row_index = self.file_options.row_index.take();
+        // The rows that still have to pass the predicate, if the slice was pushed past it.
+        let mut remaining_rows_after_predicate = self.n_rows_after_predicate();
+        // Leading rows that are skipped, this only applies to a scan of a single file.
+        let skip_rows = if self.paths.len() == 1 {
+            self.options.skip_rows
+        } else {
+            0
+        };
 
         // Limit no. of files at a time to prevent open file limits.
-        for paths in self
-            .paths
-            .chunks(std::cmp::min(POOL.current_num_threads(), 128))
-        {
-            if remaining_rows_to_read == 0 && !result.is_empty() {
+        // The files that are read at the same time share the bound on the decoders.
+        let max_decoders = max_concurrent_decoders(
+            self.options.low_memory,
+            self.options.max_decoders,
+            POOL.current_num_threads(),
+        );
+        let n_files_per_batch = std::cmp::min(max_decoders, 128);
+        for paths in self.paths.chunks(n_files_per_batch) {
+            let max_decoders_per_file = self
+                .options
+                .max_decoders
+                .map(|_| std::cmp::max(max_decoders / paths.len(), 1));
+            if (remaining_rows_to_read == 0 || remaining_rows_after_predicate == Some(0))
+                && !result.is_empty()
+            {
                 return Ok(result);
             }
 
@@ -94,7 +205,10 @@ impl ParquetExec {
                         .with_schema(self.file_info.reader_schema.clone())
                         .read_parallel(parallel)
                         .set_low_memory(self.options.low_memory)
+                        .set_max_decoders(max_decoders_per_file)
                         .use_statistics(self.options.use_statistics)
+                        .with_nested_projection(self.nested_projection.clone())
+                        .with_decryption(self.decryption.clone())
                         .set_rechunk(false)
                         .with_hive_partition_columns(hive_partitions);
 
@@ -133,6 +247,8 @@ impl ParquetExec {
 
                             reader
                                 .with_n_rows(remaining_rows_to_read)
+                                .with_n_rows_after_predicate(remaining_rows_after_predicate)
+                                .with_skip_rows(skip_rows)
                                 .with_row_index(row_index)
                                 .with_predicate(predicate.clone())
                                 .with_projection(projection.clone())
@@ -144,6 +260,9 @@ impl ParquetExec {
 
             let n_read = out.iter().map(|df| df.height()).sum();
             remaining_rows_to_read = remaining_rows_to_read.saturating_sub(n_read);
+            if let Some(remaining) = &mut remaining_rows_after_predicate {
+                *remaining = remaining.saturating_sub(n_read);
+            }
             if let Some(rc) = &mut base_row_index {
                 rc.offset += n_read as IdxSize;
             }
@@ -159,80 +278,115 @@ impl ParquetExec {
     #[cfg(feature = "cloud")]
     async fn read_async(&mut self) -> PolarsResult<Vec<DataFrame>> {
         let verbose = verbose();
-        let first_schema = self
-            .file_info
-            .reader_schema
-            .as_ref()
-            .expect("should be set");
-        let first_metadata = &self.metadata;
-        let cloud_options = self.cloud_options.as_ref();
-        let with_columns = self
-            .file_options
-            .with_columns
-            .as_ref()
-            .map(|v| v.as_slice());
+        let first_schema = self.file_info.reader_schema.clone().expect("should be set");
+        let first_metadata = self.metadata.clone();
+        let cloud_options = Arc::new(self.cloud_options.clone());
+        let with_columns = self.file_options.with_columns.clone();
 
         let mut result = vec![];
-        let batch_size = get_file_prefetch_size();
+        // The files that are read at the same time share the bound on the decoders.
+        let batch_size = max_concurrent_decoders(
+            self.options.low_memory,
+            self.options.max_decoders,
+            get_file_prefetch_size(),
+        );
+        let readahead_size = if self.options.low_memory {
+            1
+        } else {
+            get_file_readahead_size()
+        };
+        let byte_budget = get_file_readahead_bytes();
+        let prefetch_budget = PrefetchBudget::new(byte_budget);
 
         if verbose {
-            eprintln!("POLARS PREFETCH_SIZE: {}", batch_size)
+            eprintln!("POLARS PREFETCH_SIZE: {}", batch_size);
+            eprintln!(
+                "POLARS FILE_READAHEAD_SIZE: {}, FILE_READAHEAD_BYTES: {:?}",
+                readahead_size, byte_budget
+            )
         }
 
-        let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
-        let mut base_row_index = self.file_options.row_index.take();
-        let mut processed = 0;
-        for (batch_idx, paths) in self.paths.chunks(batch_size).enumerate() {
-            if remaining_rows_to_read == 0 && !result.is_empty() {
-                return Ok(result);
-            }
-            processed += paths.len();
-            if verbose {
-                eprintln!(
-                    "querying metadata of {}/{} files...",
-                    processed,
-                    self.paths.len()
-                );
-            }
-
-            // First initialize the readers and get the metadata concurrently.
-            let iter = paths.iter().enumerate().map(|(i, path)| async move {
-                let first_file = batch_idx == 0 && i == 0;
-                // use the cached one as this saves a cloud call
-                let (metadata, schema) = if first_file {
-                    (first_metadata.clone(), Some((*first_schema).clone()))
-                } else {
-                    (None, None)
-                };
+        // Initialize the readers and get the metadata and the first row group of the next files
+        // concurrently, while the data of the current files is read.
+        let paths = self.paths.clone();
+        let decryption = self.decryption.clone();
+        let mut readahead = Readahead::new(paths.len(), readahead_size, |i: usize| {
+            let path = paths[i].clone();
+            let first_file = i == 0;
+            // use the cached one as this saves a cloud call
+            let (metadata, schema) = if first_file {
+                (first_metadata.clone(), Some(first_schema.clone()))
+            } else {
+                (None, None)
+            };
+            let first_schema = first_schema.clone();
+            let cloud_options = cloud_options.clone();
+            let with_columns = with_columns.clone();
+            let decryption = decryption.clone();
+            let prefetch_budget = prefetch_budget.clone();
+            async move {
                 let mut reader = ParquetAsyncReader::from_uri(
                     &path.to_string_lossy(),
-                    cloud_options,
+                    cloud_options.as_ref().as_ref(),
                     // Schema must be the same for all files. The hive partitions are included in this schema.
                     schema,
                     metadata,
                 )
-                .await?;
+                .await?
+                .with_decryption(decryption);
 
                 if !first_file {
                     let schema = reader.schema().await?;
                     check_projected_arrow_schema(
                         first_schema.as_ref(),
                         schema.as_ref(),
-                        with_columns,
+                        with_columns.as_ref().map(|v| v.as_slice()),
                         "schema of all files in a single scan_parquet must be equal",
                     )?
                 }
 
                 let num_rows = reader.num_rows().await?;
-                PolarsResult::Ok((num_rows, reader))
-            });
-            let readers_and_metadata = futures::future::try_join_all(iter).await?;
+
+                // The projected columns of the first row group are downloaded ahead of reading
+                // as long as they fit in the budget, otherwise they are downloaded when the
+                // file is read.
+                let columns = with_columns.as_ref().map(|v| v.as_slice());
+                let n_bytes = reader.row_group_size(0, columns).await?;
+                let permit = prefetch_budget.try_reserve(n_bytes);
+                if permit.is_some() {
+                    reader.prefetch_row_group(0, columns).await?;
+                }
+                PolarsResult::Ok((num_rows, reader, permit))
+            }
+        });
+
+        let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
+        let mut base_row_index = self.file_options.row_index.take();
+        let mut remaining_rows_after_predicate = self.n_rows_after_predicate();
+        let mut processed = 0;
+        while processed < self.paths.len() {
+            if (remaining_rows_to_read == 0 || remaining_rows_after_predicate == Some(0))
+                && !result.is_empty()
+            {
+                return Ok(result);
+            }
+
+            let readers_and_metadata = readahead.next_batch(batch_size).await?;
+            let paths = &self.paths[processed..processed + readers_and_metadata.len()];
+            processed += paths.len();
+            if verbose {
+                eprintln!(
+                    "queried metadata of {}/{} files...",
+                    processed,
+                    self.paths.len()
+                );
+            }
 
             // Then compute `n_rows` to be taken per file up front, so we can actually read concurrently
             // after this.
             let iter = readers_and_metadata
                 .iter()
-                .map(|(num_rows, _)| num_rows)
+                .map(|(num_rows, _, _)| num_rows)
                 .copied();
 
             let rows_statistics = get_sequential_row_statistics(iter, remaining_rows_to_read);
@@ -241,6 +395,12 @@ impl ParquetExec {
             let file_info = &self.file_info;
             let file_options = &self.file_options;
             let use_statistics = self.options.use_statistics;
+            let low_memory = self.options.low_memory;
+            let max_decoders_per_file = self
+                .options
+                .max_decoders
+                .map(|_| std::cmp::max(batch_size / paths.len(), 1));
+            let nested_projection = &self.nested_projection;
             let predicate = &self.predicate;
             let base_row_index_ref = &base_row_index;
 
@@ -251,10 +411,13 @@ impl ParquetExec {
             let iter = readers_and_metadata
                 .into_iter()
                 .zip(rows_statistics.iter())
-                .zip(paths.as_ref().iter())
+                .zip(paths.iter())
                 .map(
                     |(
-                        ((num_rows_this_file, reader), (remaining_rows_to_read, cumulative_read)),
+                        (
+                            (num_rows_this_file, reader, prefetch_permit),
+                            (remaining_rows_to_read, cumulative_read),
+                        ),
                         path,
                     )| async move {
                         let mut file_info = file_info.clone();
@@ -285,17 +448,22 @@ impl ParquetExec {
                             hive_partitions.as_deref(),
                         );
 
-                        reader
+                        let out = reader
                             .with_n_rows(remaining_rows_to_read)
                             .with_row_index(row_index)
                             .with_projection(projection)
+                            .with_nested_projection(nested_projection.clone())
                             .use_statistics(use_statistics)
+                            .set_low_memory(low_memory)
+                            .set_max_decoders(max_decoders_per_file)
                             .with_predicate(predicate)
                             .set_rechunk(false)
                             .with_hive_partition_columns(hive_partitions)
                             .finish()
-                            .await
-                            .map(Some)
+                            .await;
+                        // The prefetched data is read, which frees its budget.
+                        drop(prefetch_permit);
+                        out.map(Some)
                     },
                 );
 
@@ -305,6 +473,9 @@ impl ParquetExec {
                 .map(|opt_df| opt_df.as_ref().map(|df| df.height()).unwrap_or(0))
                 .sum();
             remaining_rows_to_read = remaining_rows_to_read.saturating_sub(n_read);
+            if let Some(remaining) = &mut remaining_rows_after_predicate {
+                *remaining = remaining.saturating_sub(n_read);
+            }
             if let Some(rc) = &mut base_row_index {
                 rc.offset += n_read as IdxSize;
             }
@@ -326,6 +497,8 @@ impl ParquetExec {
             .and_then(|_| self.predicate.take())
             .map(phys_expr_to_io_expr);
 
+        self.prune_with_statistics_file()?;
+
         let is_cloud = match self.paths.first() {
             Some(p) => is_cloud_url(p.as_path()),
             None => {
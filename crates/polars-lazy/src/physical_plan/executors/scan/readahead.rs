@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::task::JoinHandle;
+
+use super::*;
+
+/// Bounds the compressed size of the row groups that are downloaded ahead of reading and
+/// that aren't read yet.
+#[derive(Clone)]
+pub(super) struct PrefetchBudget {
+    /// The maximum number of bytes, or `None` if unlimited.
+    limit: Option<usize>,
+    used: Arc<AtomicUsize>,
+}
+
+impl PrefetchBudget {
+    pub(super) fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            used: Default::default(),
+        }
+    }
+
+    /// Reserve `n_bytes` of the budget, which are released once the returned permit is
+    /// dropped. Returns `None` if they don't fit, then the data isn't prefetched.
+    pub(super) fn try_reserve(&self, n_bytes: usize) -> Option<PrefetchPermit> {
+        if let Some(limit) = self.limit {
+            self.used
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                    Some(used + n_bytes).filter(|&used| used <= limit)
+                })
+                .ok()?;
+        } else {
+            self.used.fetch_add(n_bytes, Ordering::AcqRel);
+        }
+        Some(PrefetchPermit {
+            n_bytes,
+            used: self.used.clone(),
+        })
+    }
+}
+
+/// A reservation of the [`PrefetchBudget`] for the data of a file, which is held until the
+/// file is read.
+pub(super) struct PrefetchPermit {
+    n_bytes: usize,
+    used: Arc<AtomicUsize>,
+}
+
+impl Drop for PrefetchPermit {
+    fn drop(&mut self) {
+        self.used.fetch_sub(self.n_bytes, Ordering::AcqRel);
+    }
+}
+
+/// Opens the files of a multi-file scan ahead of the files that are read, so that fetching
+/// their metadata, and possibly their first row group, overlaps with reading the data of
+/// the previous files.
+///
+/// The files are opened on the async runtime, at most `readahead` files ahead of the files
+/// that are taken, and taken in batches of at most `max_files` files.
+pub(super) struct Readahead<T, F> {
+    open: F,
+    n_files: usize,
+    /// The index of the next file to open.
+    next: usize,
+    readahead: usize,
+    pending: VecDeque<JoinHandle<PolarsResult<T>>>,
+}
+
+impl<T, F, Fut> Readahead<T, F>
+where
+    T: Send + 'static,
+    F: Fn(usize) -> Fut,
+    Fut: Future<Output = PolarsResult<T>> + Send + 'static,
+{
+    pub(super) fn new(n_files: usize, readahead: usize, open: F) -> Self {
+        let mut readahead = Self {
+            open,
+            n_files,
+            next: 0,
+            readahead: readahead.max(1),
+            pending: VecDeque::new(),
+        };
+        readahead.spawn_pending();
+        readahead
+    }
+
+    fn spawn_pending(&mut self) {
+        while self.next < self.n_files && self.pending.len() < self.readahead {
+            let open = (self.open)(self.next);
+            self.pending
+                .push_back(polars_io::pl_async::get_runtime().spawn(open));
+            self.next += 1;
+        }
+    }
+
+    /// Take the next batch of opened files, in order. Returns an empty batch once all files
+    /// are taken.
+    pub(super) async fn next_batch(&mut self, max_files: usize) -> PolarsResult<Vec<T>> {
+        let mut batch = vec![];
+        while batch.len() < max_files.max(1) {
+            let Some(handle) = self.pending.pop_front() else {
+                break;
+            };
+            self.spawn_pending();
+            let file = handle
+                .await
+                .map_err(|err| polars_err!(ComputeError: "failed to open file: {}", err))??;
+            batch.push(file);
+        }
+        Ok(batch)
+    }
+}
+
+impl<T, F> Drop for Readahead<T, F> {
+    fn drop(&mut self) {
+        // Stop opening the files that won't be read, e.g. once the row limit is reached.
+        for handle in &self.pending {
+            handle.abort();
+        }
+    }
+}
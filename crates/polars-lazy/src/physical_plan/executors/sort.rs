@@ -14,29 +14,38 @@ impl SortExec {
         mut df: DataFrame,
     ) -> PolarsResult<DataFrame> {
         state.should_stop()?;
-        df.as_single_chunk_par();
+        if state.auto_rechunk() {
+            df.as_single_chunk_par();
+        }
 
-        let by_columns = self
-            .by_column
-            .iter()
-            .enumerate()
-            .map(|(i, e)| {
-                let mut s = e.evaluate(&df, state)?;
-                // Polars core will try to set the sorted columns as sorted.
-                // This should only be done with simple col("foo") expressions,
-                // therefore we rename more complex expressions so that
-                // polars core does not match these.
-                if !matches!(e.as_expression(), Some(&Expr::Column(_))) {
-                    s.rename(&format!("_POLARS_SORT_BY_{i}"));
-                }
-                Ok(s)
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
+        let by_columns = evaluate_sort_columns(&self.by_column, &df, state)?;
 
         df.sort_impl(by_columns, self.sort_options.clone(), self.slice)
     }
 }
 
+pub(super) fn evaluate_sort_columns(
+    by_column: &[Arc<dyn PhysicalExpr>],
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Series>> {
+    by_column
+        .iter()
+        .enumerate()
+        .map(|(i, e)| {
+            let mut s = e.evaluate(df, state)?;
+            // Polars core will try to set the sorted columns as sorted.
+            // This should only be done with simple col("foo") expressions,
+            // therefore we rename more complex expressions so that
+            // polars core does not match these.
+            if !matches!(e.as_expression(), Some(&Expr::Column(_))) {
+                s.rename(&format!("_POLARS_SORT_BY_{i}"));
+            }
+            Ok(s)
+        })
+        .collect()
+}
+
 impl Executor for SortExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         #[cfg(debug_assertions)]
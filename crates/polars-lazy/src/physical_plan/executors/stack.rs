@@ -27,6 +27,7 @@ impl StackExec {
             {
                 let chunks = df.split_chunks().collect::<Vec<_>>();
                 let iter = chunks.into_par_iter().map(|mut df| {
+                    let _sc = state.enter_string_cache();
                     let res = evaluate_physical_expressions(
                         &mut df,
                         &self.cse_exprs,
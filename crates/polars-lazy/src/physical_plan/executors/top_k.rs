@@ -0,0 +1,50 @@
+use super::sort::evaluate_sort_columns;
+use super::*;
+
+pub(crate) struct TopKExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) by_column: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
+    pub(crate) sort_options: SortMultipleOptions,
+}
+
+impl TopKExec {
+    fn execute_impl(&mut self, state: &ExecutionState, df: DataFrame) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        let by_columns = evaluate_sort_columns(&self.by_column, &df, state)?;
+
+        df.bottom_k_slice_impl(self.offset, self.len, by_columns, self.sort_options.clone())
+    }
+}
+
+impl Executor for TopKExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run TopKExec")
+            }
+        }
+        let df = self.input.execute(state)?;
+
+        let profile_name = if state.has_node_timer() {
+            let by = self
+                .by_column
+                .iter()
+                .map(|s| Ok(s.to_field(&df.schema())?.name))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let name = comma_delimited("top_k".to_string(), &by);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        if state.has_node_timer() {
+            let new_state = state.clone();
+            new_state.record(|| self.execute_impl(state, df), profile_name)
+        } else {
+            self.execute_impl(state, df)
+        }
+    }
+}
@@ -94,6 +94,7 @@ impl Executor for UnionExec {
                             .into_par_iter()
                             .enumerate()
                             .map(|(idx, input)| {
+                                let _sc = state.enter_string_cache();
                                 let mut input = std::mem::take(input);
                                 let mut state = state.split();
                                 state.branch_idx += idx;
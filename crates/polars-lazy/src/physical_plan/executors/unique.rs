@@ -17,11 +17,17 @@ impl Executor for UniqueExec {
         let df = self.input.execute(state)?;
         let subset = self.options.subset.as_ref().map(|v| &***v);
         let keep = self.options.keep_strategy;
+        let keep_by = self.options.keep_by.as_deref();
 
         state.record(
-            || match self.options.maintain_order {
-                true => df.unique_stable(subset, keep, self.options.slice),
-                false => df.unique(subset, keep, self.options.slice),
+            || {
+                df.unique_impl(
+                    self.options.maintain_order,
+                    subset,
+                    keep,
+                    keep_by,
+                    self.options.slice,
+                )
             },
             Cow::Borrowed("unique()"),
         )
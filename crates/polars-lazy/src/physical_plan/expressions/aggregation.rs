@@ -10,6 +10,7 @@ use polars_core::utils::NoNull;
 use polars_core::POOL;
 #[cfg(feature = "propagate_nans")]
 use polars_ops::prelude::nan_propagating_aggregate;
+use polars_ops::prelude::{agg_sum_with_overflow, IntegerOverflow};
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::AggState::{AggregatedList, AggregatedScalar};
@@ -19,6 +20,8 @@ pub(crate) struct AggregationExpr {
     pub(crate) input: Arc<dyn PhysicalExpr>,
     pub(crate) agg_type: GroupByMethod,
     field: Option<Field>,
+    /// How integer sums handle overflow, if not by wrapping.
+    overflow: Option<IntegerOverflow>,
 }
 
 impl AggregationExpr {
@@ -27,8 +30,14 @@ impl AggregationExpr {
             input: expr,
             agg_type,
             field,
+            overflow: None,
         }
     }
+
+    pub fn with_overflow(mut self, overflow: IntegerOverflow) -> Self {
+        self.overflow = Some(overflow);
+        self
+    }
 }
 
 impl PhysicalExpr for AggregationExpr {
@@ -86,7 +95,10 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Sum => {
                     let (s, groups) = ac.get_final_aggregation();
-                    let agg_s = s.agg_sum(&groups);
+                    let agg_s = match self.overflow {
+                        Some(overflow) => agg_sum_with_overflow(&s, &groups, overflow)?,
+                        None => s.agg_sum(&groups),
+                    };
                     AggregatedScalar(rename_series(agg_s, &keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
@@ -86,7 +86,10 @@ impl ApplyExpr {
         groups: &'a GroupsProxy,
         state: &ExecutionState,
     ) -> PolarsResult<Vec<AggregationContext<'a>>> {
-        let f = |e: &Arc<dyn PhysicalExpr>| e.evaluate_on_groups(df, groups, state);
+        let f = |e: &Arc<dyn PhysicalExpr>| {
+            let _sc = state.enter_string_cache();
+            e.evaluate_on_groups(df, groups, state)
+        };
         if self.allow_threading {
             POOL.install(|| self.inputs.par_iter().map(f).collect())
         } else {
@@ -129,9 +132,58 @@ impl ApplyExpr {
             Ok(Series::full_null(field.name(), 1, field.data_type()))
         }
     }
+
+    /// `gather_every` per group in a single pass over the groups: the indices of all groups
+    /// are gathered at once, and the new groups are slices of the gathered values.
+    fn gather_every_groups<'a>(
+        &self,
+        mut ac: AggregationContext<'a>,
+        n: usize,
+        offset: usize,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n should be positive");
+        polars_ensure!(
+            !matches!(ac.agg_state(), AggState::AggregatedScalar(_)),
+            expr = self.expr,
+            ComputeError: "cannot aggregate, the column is already aggregated",
+        );
+
+        let mut idx = Vec::<IdxSize>::new();
+        let mut push_group = |group: &mut dyn Iterator<Item = IdxSize>| {
+            let start = idx.len() as IdxSize;
+            idx.extend(group);
+            [start, idx.len() as IdxSize - start]
+        };
+        let groups: Vec<[IdxSize; 2]> = match ac.groups().as_ref() {
+            GroupsProxy::Idx(groups) => groups
+                .all()
+                .iter()
+                .map(|g| push_group(&mut g.iter().skip(offset).step_by(n).copied()))
+                .collect(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .iter()
+                .map(|&[first, len]| {
+                    let start = first + (offset as IdxSize).min(len);
+                    push_group(&mut (start..first + len).step_by(n))
+                })
+                .collect(),
+        };
+
+        // SAFETY: the indices are indices of the groups, which are in bounds.
+        let gathered = unsafe { ac.flat_naive().take_slice_unchecked(&idx) };
+        ac.with_series(gathered, false, Some(&self.expr))?;
+        ac.with_groups(GroupsProxy::Slice {
+            groups,
+            rolling: false,
+        })
+        .set_original_len(false);
+        Ok(ac)
+    }
+
     fn apply_single_group_aware<'a>(
         &self,
         mut ac: AggregationContext<'a>,
+        state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
         let s = ac.series();
 
@@ -158,6 +210,7 @@ impl ApplyExpr {
         let f = |opt_s: Option<Series>| match opt_s {
             None => Ok(None),
             Some(mut s) => {
+                let _sc = state.enter_string_cache();
                 if self.pass_name_to_apply {
                     s.rename(&name);
                 }
@@ -313,7 +366,10 @@ impl PhysicalExpr for ApplyExpr {
     }
 
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
-        let f = |e: &Arc<dyn PhysicalExpr>| e.evaluate(df, state);
+        let f = |e: &Arc<dyn PhysicalExpr>| {
+            let _sc = state.enter_string_cache();
+            e.evaluate(df, state)
+        };
         let mut inputs = if self.allow_threading && self.inputs.len() > 1 {
             POOL.install(|| {
                 self.inputs
@@ -325,11 +381,15 @@ impl PhysicalExpr for ApplyExpr {
             self.inputs.iter().map(f).collect::<PolarsResult<Vec<_>>>()
         }?;
 
-        if self.allow_rename {
-            self.eval_and_flatten(&mut inputs)
-        } else {
-            let in_name = inputs[0].name().to_string();
-            Ok(self.eval_and_flatten(&mut inputs)?.with_name(&in_name))
+        let in_name = (!self.allow_rename).then(|| inputs[0].name().to_string());
+        // Seeded random expressions draw the values of the batch that is evaluated.
+        #[cfg(feature = "random")]
+        let out = with_random_batch(state.batch_idx, || self.eval_and_flatten(&mut inputs))?;
+        #[cfg(not(feature = "random"))]
+        let out = self.eval_and_flatten(&mut inputs)?;
+        match in_name {
+            Some(in_name) => Ok(out.with_name(&in_name)),
+            None => Ok(out),
         }
     }
 
@@ -354,7 +414,23 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 },
-                ApplyOptions::GroupWise => self.apply_single_group_aware(ac),
+                ApplyOptions::GroupWise => match &self.expr {
+                    Expr::Function {
+                        function: FunctionExpr::GatherEvery { n, offset },
+                        ..
+                    } if !matches!(ac.agg_state(), AggState::Literal(_)) => {
+                        self.gather_every_groups(ac, *n, *offset)
+                    },
+                    _ => match self.fill_null_strategy() {
+                        Some(strategy) => match self.fill_null_groups(vec![ac], strategy) {
+                            Ok(out) => out,
+                            Err(mut acs) => {
+                                self.apply_single_group_aware(acs.pop().unwrap(), state)
+                            },
+                        },
+                        None => self.apply_single_group_aware(ac, state),
+                    },
+                },
                 ApplyOptions::ElementWise => self.apply_single_elementwise(ac),
             }
         } else {
@@ -370,7 +446,13 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 },
-                ApplyOptions::GroupWise => self.apply_multiple_group_aware(acs, df),
+                ApplyOptions::GroupWise => match self.fill_null_strategy() {
+                    Some(strategy) => match self.fill_null_groups(acs, strategy) {
+                        Ok(out) => out,
+                        Err(acs) => self.apply_multiple_group_aware(acs, df),
+                    },
+                    None => self.apply_multiple_group_aware(acs, df),
+                },
                 ApplyOptions::ElementWise => {
                     let mut has_agg_list = false;
                     let mut has_agg_scalar = false;
@@ -509,17 +591,17 @@ impl ApplyExpr {
             } => (function, input),
             _ => return Ok(true),
         };
-        // Ensure the input of the function is only a `col(..)`.
+        // Ensure the input of the function is only a `col(..)` or a (nested) struct field of
+        // one, whose statistics are stored per leaf column.
         // If it does any arithmetic the code below is flawed.
-        if !matches!(input[0], Expr::Column(_)) {
+        let Some(path) = expr_to_field_path(&input[0]) else {
             return Ok(true);
-        }
+        };
+        let path = path.iter().map(|name| name.as_ref()).collect::<Vec<_>>();
 
         match function {
             FunctionExpr::Boolean(BooleanFunction::IsNull) => {
-                let root = expr_to_leaf_column_name(&self.expr)?;
-
-                match stats.get_stats(&root).ok() {
+                match stats.get_field_stats(&path).ok() {
                     Some(st) => match st.null_count() {
                         Some(0) => Ok(false),
                         _ => Ok(true),
@@ -528,9 +610,7 @@ impl ApplyExpr {
                 }
             },
             FunctionExpr::Boolean(BooleanFunction::IsNotNull) => {
-                let root = expr_to_leaf_column_name(&self.expr)?;
-
-                match stats.get_stats(&root).ok() {
+                match stats.get_field_stats(&path).ok() {
                     Some(st) => match st.null_count() {
                         Some(null_count)
                             if stats
@@ -547,13 +627,16 @@ impl ApplyExpr {
             #[cfg(feature = "is_in")]
             FunctionExpr::Boolean(BooleanFunction::IsIn) => {
                 let should_read = || -> Option<bool> {
-                    let root = expr_to_leaf_column_name(&input[0]).ok()?;
                     let Expr::Literal(LiteralValue::Series(input)) = &input[1] else {
                         return None;
                     };
                     #[allow(clippy::explicit_auto_deref)]
                     let input: &Series = &**input;
-                    let st = stats.get_stats(&root).ok()?;
+                    let st = stats.get_field_stats(&path).ok()?;
+                    // The bloom filter can rule out the values between the min and max values.
+                    if !st.may_contain(input) {
+                        return Some(false);
+                    }
                     let min = st.to_min()?;
                     let max = st.to_max()?;
 
@@ -574,7 +657,6 @@ impl ApplyExpr {
             #[cfg(feature = "is_between")]
             FunctionExpr::Boolean(BooleanFunction::IsBetween { closed }) => {
                 let should_read = || -> Option<bool> {
-                    let root: Arc<str> = expr_to_leaf_column_name(&input[0]).ok()?;
                     let Expr::Literal(left) = &input[1] else {
                         return None;
                     };
@@ -582,7 +664,7 @@ impl ApplyExpr {
                         return None;
                     };
 
-                    let st = stats.get_stats(&root).ok()?;
+                    let st = stats.get_field_stats(&path).ok()?;
                     let min = st.to_min()?;
                     let max = st.to_max()?;
 
@@ -181,8 +181,14 @@ impl PhysicalExpr for BinaryExpr {
         } else {
             let (opt_lhs, opt_rhs) = POOL.install(|| {
                 rayon::join(
-                    || self.left.evaluate(df, state),
-                    || self.right.evaluate(df, state),
+                    || {
+                        let _sc = state.enter_string_cache();
+                        self.left.evaluate(df, state)
+                    },
+                    || {
+                        let _sc = state.enter_string_cache();
+                        self.right.evaluate(df, state)
+                    },
                 )
             });
             (lhs, rhs) = (opt_lhs?, opt_rhs?);
@@ -205,8 +211,14 @@ impl PhysicalExpr for BinaryExpr {
     ) -> PolarsResult<AggregationContext<'a>> {
         let (result_a, result_b) = POOL.install(|| {
             rayon::join(
-                || self.left.evaluate_on_groups(df, groups, state),
-                || self.right.evaluate_on_groups(df, groups, state),
+                || {
+                    let _sc = state.enter_string_cache();
+                    self.left.evaluate_on_groups(df, groups, state)
+                },
+                || {
+                    let _sc = state.enter_string_cache();
+                    self.right.evaluate_on_groups(df, groups, state)
+                },
             )
         });
         let mut ac_l = result_a?;
@@ -258,6 +270,8 @@ impl PhysicalExpr for BinaryExpr {
 
 #[cfg(feature = "parquet")]
 mod stats {
+    use std::borrow::Cow;
+
     use polars_io::predicates::{BatchStats, StatsEvaluator};
 
     use super::*;
@@ -370,6 +384,8 @@ mod stats {
                     !matches!(op, Multiply | Divide | TrueDivide | FloorDivide | Modulus)
                 },
                 Column(_) | Literal(_) | Alias(_, _) => true,
+                // Parquet stores the statistics of the fields of struct columns.
+                Function { .. } => expr_to_field_path(e).is_some(),
                 _ => false,
             }) {
                 return Ok(true);
@@ -396,31 +412,43 @@ mod stats {
 
             let dummy = DataFrame::empty();
             let state = ExecutionState::new();
+            // The statistics of a column, or of a struct field if `e` accesses one.
+            let get_stats = |e: &Arc<dyn PhysicalExpr>, fld: &Field| match e
+                .as_expression()
+                .and_then(expr_to_field_path)
+            {
+                Some(path) => stats
+                    .get_field_stats(&path.iter().map(|name| name.as_ref()).collect::<Vec<_>>()),
+                None => stats.get_stats(fld.name()).map(Cow::Borrowed),
+            };
 
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
-                    let l = stats.get_stats(fld_l.name())?;
-                    match l.to_min_max() {
-                        None => Ok(true),
+                    let l = get_stats(&self.left, &fld_l)?;
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
+                    let read = match l.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op)
                         },
-                    }
+                    };
+                    // The bloom filter can rule out the values between the min and max values.
+                    Ok(read && (self.op != Operator::Eq || l.may_contain(&lit_s)))
                 },
                 (true, false) => {
-                    let r = stats.get_stats(fld_r.name())?;
-                    match r.to_min_max() {
-                        None => Ok(true),
+                    let r = get_stats(&self.right, &fld_r)?;
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
+                    let read = match r.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op)
                         },
-                    }
+                    };
+                    Ok(read && (self.op != Operator::Eq || r.may_contain(&lit_s)))
                 },
                 // Default: read the file
                 _ => Ok(true),
@@ -452,6 +480,11 @@ mod stats {
                     Or | LogicalOr => Ok(l.should_read(stats)? || r.should_read(stats)?),
                     _ => Ok(true),
                 },
+                // A conjunction can't be true if one of its sides can't, even if the other
+                // side can't be evaluated on the statistics.
+                (Some(e), None) | (None, Some(e)) if matches!(self.op, And | LogicalAnd) => {
+                    e.should_read(stats)
+                },
                 _ => self.impl_should_read(stats),
             }
         }
@@ -0,0 +1,68 @@
+use polars_core::prelude::*;
+
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+
+/// An expression that is evaluated in more than one node of the plan. Its result is
+/// cached in the [`ExecutionState`] and reused when it is evaluated on the same columns.
+pub struct CachedExpr {
+    pub(crate) physical_expr: Arc<dyn PhysicalExpr>,
+    expr: Expr,
+    columns: Vec<Arc<str>>,
+}
+
+impl CachedExpr {
+    pub fn new(physical_expr: Arc<dyn PhysicalExpr>, expr: Expr, columns: Vec<Arc<str>>) -> Self {
+        Self {
+            physical_expr,
+            expr,
+            columns,
+        }
+    }
+}
+
+impl PhysicalExpr for CachedExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let inputs = self
+            .columns
+            .iter()
+            .map(|name| df.column(name).cloned())
+            .collect::<PolarsResult<Vec<_>>>();
+        match inputs {
+            Ok(inputs) => state.get_or_eval_expr(&self.expr, inputs, df.height(), || {
+                self.physical_expr.evaluate(df, state)
+            }),
+            Err(_) => self.physical_expr.evaluate(df, state),
+        }
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        self.physical_expr.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.physical_expr.to_field(input_schema)
+    }
+
+    fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
+        self.physical_expr.as_partitioned_aggregator()
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
+        self.physical_expr.as_stats_evaluator()
+    }
+
+    fn is_literal(&self) -> bool {
+        self.physical_expr.is_literal()
+    }
+}
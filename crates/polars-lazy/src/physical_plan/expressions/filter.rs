@@ -25,8 +25,14 @@ impl PhysicalExpr for FilterExpr {
         Some(&self.expr)
     }
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
-        let s_f = || self.input.evaluate(df, state);
-        let predicate_f = || self.by.evaluate(df, state);
+        let s_f = || {
+            let _sc = state.enter_string_cache();
+            self.input.evaluate(df, state)
+        };
+        let predicate_f = || {
+            let _sc = state.enter_string_cache();
+            self.by.evaluate(df, state)
+        };
 
         let (series, predicate) = POOL.install(|| rayon::join(s_f, predicate_f));
         let (series, predicate) = (series?, predicate?);
@@ -40,8 +46,14 @@ impl PhysicalExpr for FilterExpr {
         groups: &'a GroupsProxy,
         state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
-        let ac_s_f = || self.input.evaluate_on_groups(df, groups, state);
-        let ac_predicate_f = || self.by.evaluate_on_groups(df, groups, state);
+        let ac_s_f = || {
+            let _sc = state.enter_string_cache();
+            self.input.evaluate_on_groups(df, groups, state)
+        };
+        let ac_predicate_f = || {
+            let _sc = state.enter_string_cache();
+            self.by.evaluate_on_groups(df, groups, state)
+        };
 
         let (ac_s, ac_predicate) = POOL.install(|| rayon::join(ac_s_f, ac_predicate_f));
         let (mut ac_s, mut ac_predicate) = (ac_s?, ac_predicate?);
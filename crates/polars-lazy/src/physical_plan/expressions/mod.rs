@@ -2,6 +2,7 @@ mod aggregation;
 mod alias;
 mod apply;
 mod binary;
+mod cache;
 mod cast;
 mod column;
 mod count;
@@ -26,6 +27,7 @@ pub(crate) use apply::*;
 use arrow::array::ArrayRef;
 use arrow::legacy::utils::CustomIterTools;
 pub(crate) use binary::*;
+pub(crate) use cache::*;
 pub(crate) use cast::*;
 pub(crate) use column::*;
 pub(crate) use count::*;
@@ -654,6 +656,13 @@ impl PhysicalIoExpr for PhysicalIoHelper {
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
     }
+
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        let mut live_variables = expr_to_leaf_column_names(self.expr.as_expression()?);
+        live_variables.sort_unstable();
+        live_variables.dedup();
+        Some(live_variables)
+    }
 }
 
 pub(crate) fn phys_expr_to_io_expr(expr: Arc<dyn PhysicalExpr>) -> Arc<dyn PhysicalIoExpr> {
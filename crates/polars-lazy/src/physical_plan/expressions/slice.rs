@@ -79,7 +79,10 @@ impl PhysicalExpr for SliceExpr {
         let results = POOL.install(|| {
             [&self.offset, &self.length, &self.input]
                 .par_iter()
-                .map(|e| e.evaluate(df, state))
+                .map(|e| {
+                    let _sc = state.enter_string_cache();
+                    e.evaluate(df, state)
+                })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
         let offset = &results[0];
@@ -99,7 +102,10 @@ impl PhysicalExpr for SliceExpr {
         let mut results = POOL.install(|| {
             [&self.offset, &self.length, &self.input]
                 .par_iter()
-                .map(|e| e.evaluate_on_groups(df, groups, state))
+                .map(|e| {
+                    let _sc = state.enter_string_cache();
+                    e.evaluate_on_groups(df, groups, state)
+                })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
         let mut ac = results.pop().unwrap();
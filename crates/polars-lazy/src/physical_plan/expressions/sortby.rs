@@ -174,17 +174,22 @@ impl PhysicalExpr for SortByExpr {
         Some(&self.expr)
     }
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
-        let series_f = || self.input.evaluate(df, state);
+        let series_f = || {
+            let _sc = state.enter_string_cache();
+            self.input.evaluate(df, state)
+        };
         let descending = prepare_descending(&self.sort_options.descending, self.by.len());
 
         let (series, sorted_idx) = if self.by.len() == 1 {
             let sorted_idx_f = || {
+                let _sc = state.enter_string_cache();
                 let s_sort_by = self.by[0].evaluate(df, state)?;
                 Ok(s_sort_by.arg_sort(SortOptions::from(&self.sort_options)))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         } else {
             let sorted_idx_f = || {
+                let _sc = state.enter_string_cache();
                 let s_sort_by = self
                     .by
                     .iter()
@@ -1,8 +1,9 @@
-use arrow::legacy::utils::CustomIterTools;
 use polars_core::chunked_array::builder::get_list_builder;
 use polars_core::prelude::*;
-use polars_core::utils::NoNull;
-use polars_ops::prelude::{convert_to_unsigned_index, is_positive_idx_uncertain};
+use polars_ops::prelude::{
+    convert_to_unsigned_index_with_policy, is_positive_idx_uncertain, resolve_index,
+    OutOfBoundsPolicy,
+};
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -12,6 +13,7 @@ pub struct TakeExpr {
     pub(crate) idx: Arc<dyn PhysicalExpr>,
     pub(crate) expr: Expr,
     pub(crate) returns_scalar: bool,
+    pub(crate) out_of_bounds: OutOfBoundsPolicy,
 }
 
 impl PhysicalExpr for TakeExpr {
@@ -41,12 +43,23 @@ impl PhysicalExpr for TakeExpr {
             dt if dt.is_integer() => {
                 // Unsigned integers will fall through and will use faster paths.
                 if !is_positive_idx_uncertain(s_idx) {
-                    return self.process_negative_indices_agg(ac, idx, groups);
+                    return self.process_indices_per_group(ac, idx, groups);
                 }
             },
             dt => polars_bail!(InvalidOperation: "expected numeric dtype as index, got {:?}", dt),
         }
 
+        // The positive indices of a single value per group are resolved in a single pass
+        // over the groups, other indices per group.
+        if self.out_of_bounds != OutOfBoundsPolicy::Error
+            && !matches!(
+                idx.state,
+                AggState::AggregatedScalar(_) | AggState::Literal(_)
+            )
+        {
+            return self.process_indices_per_group(ac, idx, groups);
+        }
+
         let idx = match idx.state {
             AggState::AggregatedScalar(s) => {
                 let idx = s.cast(&IDX_DTYPE)?;
@@ -101,7 +114,7 @@ impl TakeExpr {
         series: Series,
     ) -> PolarsResult<Series> {
         let idx = self.idx.evaluate(df, state)?;
-        let idx = convert_to_unsigned_index(&idx, series.len())?;
+        let idx = convert_to_unsigned_index_with_policy(&idx, series.len(), self.out_of_bounds)?;
         series.take(&idx)
     }
 
@@ -109,6 +122,21 @@ impl TakeExpr {
         polars_bail!(expr = self.expr, OutOfBounds: "index out of bounds");
     }
 
+    /// Resolve a positive index into a group of length `len`. Returns `None` if a null value
+    /// should be gathered.
+    fn resolve_group_index(
+        &self,
+        idx: Option<IdxSize>,
+        len: IdxSize,
+    ) -> PolarsResult<Option<IdxSize>> {
+        match idx {
+            Some(idx) if idx < len => Ok(Some(idx)),
+            _ if self.out_of_bounds == OutOfBoundsPolicy::Error => self.oob_err().map(|_| None),
+            None => Ok(None),
+            Some(idx) => resolve_index(idx as i64, len as usize, self.out_of_bounds),
+        }
+    }
+
     fn process_positive_indices_agg_scalar<'b>(
         &self,
         mut ac: AggregationContext<'b>,
@@ -126,32 +154,22 @@ impl TakeExpr {
 
         // Determine the gather indices.
         let idx: IdxCa = match groups.as_ref() {
-            GroupsProxy::Idx(groups) => {
-                if groups.all().iter().zip(idx).any(|(g, idx)| match idx {
-                    None => true,
-                    Some(idx) => idx >= g.len() as IdxSize,
-                }) {
-                    self.oob_err()?;
-                }
-
-                idx.into_iter()
-                    .zip(groups.first().iter())
-                    .map(|(idx, first)| idx.map(|idx| idx + first))
-                    .collect_trusted()
-            },
-            GroupsProxy::Slice { groups, .. } => {
-                if groups.iter().zip(idx).any(|(g, idx)| match idx {
-                    None => true,
-                    Some(idx) => idx >= g[1],
-                }) {
-                    self.oob_err()?;
-                }
-
-                idx.into_iter()
-                    .zip(groups.iter())
-                    .map(|(idx, g)| idx.map(|idx| idx + g[0]))
-                    .collect_trusted()
-            },
+            GroupsProxy::Idx(groups) => idx
+                .into_iter()
+                .zip(groups.all().iter().zip(groups.first()))
+                .map(|(idx, (g, first))| {
+                    let idx = self.resolve_group_index(idx, g.len() as IdxSize)?;
+                    Ok(idx.map(|idx| idx + first))
+                })
+                .collect::<PolarsResult<_>>()?,
+            GroupsProxy::Slice { groups, .. } => idx
+                .into_iter()
+                .zip(groups.iter())
+                .map(|(idx, g)| {
+                    let idx = self.resolve_group_index(idx, g[1])?;
+                    Ok(idx.map(|idx| idx + g[0]))
+                })
+                .collect::<PolarsResult<_>>()?,
         };
 
         let taken = ac.flat_naive().take(&idx)?;
@@ -171,53 +189,62 @@ impl TakeExpr {
         idx: &IdxCa,
     ) -> PolarsResult<AggregationContext<'b>> {
         if idx.len() == 1 {
-            match idx.get(0) {
-                None => polars_bail!(ComputeError: "cannot take by a null"),
-                Some(idx) => {
-                    if idx != 0 {
-                        // We must make sure that the column we take from is sorted by
-                        // groups otherwise we might point into the wrong group.
-                        ac.sort_by_groups()
-                    }
-                    // Make sure that we look at the updated groups.
-                    let groups = ac.groups();
-
-                    // We offset the groups first by idx.
-                    let idx: NoNull<IdxCa> = match groups.as_ref() {
-                        GroupsProxy::Idx(groups) => {
-                            if groups.all().iter().any(|g| idx >= g.len() as IdxSize) {
-                                self.oob_err()?;
-                            }
-
-                            groups.first().iter().map(|f| *f + idx).collect_trusted()
-                        },
-                        GroupsProxy::Slice { groups, .. } => {
-                            if groups.iter().any(|g| idx >= g[1]) {
-                                self.oob_err()?;
-                            }
-
-                            groups.iter().map(|g| g[0] + idx).collect_trusted()
-                        },
-                    };
-                    let taken = ac.flat_naive().take(&idx.into_inner())?;
-
-                    let taken = if self.returns_scalar {
-                        taken
-                    } else {
-                        taken.as_list().into_series()
-                    };
-
-                    ac.with_series(taken, true, Some(&self.expr))?;
-                    ac.with_update_groups(UpdateGroups::WithGroupsLen);
-                    Ok(ac)
-                },
+            let idx = idx.get(0);
+            polars_ensure!(
+                idx.is_some() || self.out_of_bounds != OutOfBoundsPolicy::Error,
+                ComputeError: "cannot take by a null"
+            );
+            if idx != Some(0) {
+                // We must make sure that the column we take from is sorted by
+                // groups otherwise we might point into the wrong group.
+                ac.sort_by_groups()
             }
+            // Make sure that we look at the updated groups.
+            let groups = ac.groups();
+
+            // We offset the groups first by idx.
+            let idx: IdxCa = match groups.as_ref() {
+                GroupsProxy::Idx(groups) => groups
+                    .all()
+                    .iter()
+                    .zip(groups.first())
+                    .map(|(g, first)| {
+                        let idx = self.resolve_group_index(idx, g.len() as IdxSize)?;
+                        Ok(idx.map(|idx| idx + first))
+                    })
+                    .collect::<PolarsResult<_>>()?,
+                GroupsProxy::Slice { groups, .. } => groups
+                    .iter()
+                    .map(|g| {
+                        let idx = self.resolve_group_index(idx, g[1])?;
+                        Ok(idx.map(|idx| idx + g[0]))
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            };
+            let taken = ac.flat_naive().take(&idx)?;
+
+            let taken = if self.returns_scalar {
+                taken
+            } else {
+                taken.as_list().into_series()
+            };
+
+            ac.with_series(taken, true, Some(&self.expr))?;
+            ac.with_update_groups(UpdateGroups::WithGroupsLen);
+            Ok(ac)
         } else {
-            let out = ac
-                .aggregated()
-                .list()
-                .unwrap()
-                .try_apply_amortized(|s| s.as_ref().take(idx))?;
+            let idx_s = idx.clone().into_series();
+            let out = ac.aggregated().list().unwrap().try_apply_amortized(|s| {
+                let s = s.as_ref();
+                match self.out_of_bounds {
+                    OutOfBoundsPolicy::Error => s.take(idx),
+                    policy => s.take(&convert_to_unsigned_index_with_policy(
+                        &idx_s,
+                        s.len(),
+                        policy,
+                    )?),
+                }
+            })?;
 
             ac.with_series(out.into_series(), true, Some(&self.expr))?;
             ac.with_update_groups(UpdateGroups::WithGroupsLen);
@@ -225,12 +252,64 @@ impl TakeExpr {
         }
     }
 
-    fn process_negative_indices_agg<'b>(
+    /// Gather the values of every group by the indices of the same group in a single pass
+    /// over the groups: the values of all groups are gathered at once, and the new groups are
+    /// slices of the gathered values.
+    fn gather_groups<'b>(
         &self,
         mut ac: AggregationContext<'b>,
         mut idx: AggregationContext<'b>,
+    ) -> PolarsResult<AggregationContext<'b>> {
+        // Make sure that we look at the updated groups.
+        ac.groups();
+        idx.groups();
+        let idx_values = idx.flat_naive().cast(&DataType::Int64)?.rechunk();
+        let idx_values = idx_values.i64().unwrap();
+        let groups = ac.groups.as_ref();
+        let idx_groups = idx.groups.as_ref();
+
+        let mut rows = Vec::<Option<IdxSize>>::with_capacity(idx_values.len());
+        let mut new_groups = Vec::with_capacity(groups.len());
+        for i in 0..groups.len() {
+            let len = group_len(groups, i);
+            let start = rows.len() as IdxSize;
+            for_each_in_group(idx_groups, i, |j| {
+                let row = match idx_values.get(j as usize) {
+                    Some(k) => resolve_index(k, len as usize, self.out_of_bounds)?
+                        .map(|k| group_row(groups, i, k)),
+                    None => None,
+                };
+                rows.push(row);
+                Ok(())
+            })?;
+            new_groups.push([start, rows.len() as IdxSize - start]);
+        }
+        let rows = rows.into_iter().collect::<IdxCa>();
+
+        let gathered = ac.flat_naive().take(&rows)?;
+        ac.with_series(gathered, false, Some(&self.expr))?;
+        ac.with_groups(GroupsProxy::Slice {
+            groups: new_groups,
+            rolling: false,
+        })
+        .set_original_len(false);
+        Ok(ac)
+    }
+
+    fn process_indices_per_group<'b>(
+        &self,
+        ac: AggregationContext<'b>,
+        mut idx: AggregationContext<'b>,
         groups: &'b GroupsProxy,
     ) -> PolarsResult<AggregationContext<'b>> {
+        // Indices per row are gathered in a single pass, lists of indices per group.
+        if matches!(ac.state, AggState::NotAggregated(_))
+            && matches!(idx.state, AggState::NotAggregated(_))
+            && idx.series().dtype().is_integer()
+        {
+            return self.gather_groups(ac, idx);
+        }
+        let mut ac = ac;
         let mut builder = get_list_builder(
             &ac.dtype(),
             idx.series().len(),
@@ -243,7 +322,11 @@ impl TakeExpr {
             for (s, idx) in iter {
                 match (s, idx) {
                     (Some(s), Some(idx)) => {
-                        let idx = convert_to_unsigned_index(idx.as_ref(), s.as_ref().len())?;
+                        let idx = convert_to_unsigned_index_with_policy(
+                            idx.as_ref(),
+                            s.as_ref().len(),
+                            self.out_of_bounds,
+                        )?;
                         let out = s.as_ref().take(&idx)?;
                         builder.append_series(&out)?;
                     },
@@ -256,3 +339,34 @@ impl TakeExpr {
         Ok(ac)
     }
 }
+
+/// The number of values of group `i`.
+fn group_len(groups: &GroupsProxy, i: usize) -> IdxSize {
+    match groups {
+        GroupsProxy::Idx(groups) => groups.all()[i].len() as IdxSize,
+        GroupsProxy::Slice { groups, .. } => groups[i][1],
+    }
+}
+
+/// The row of value `j` of group `i`.
+fn group_row(groups: &GroupsProxy, i: usize, j: IdxSize) -> IdxSize {
+    match groups {
+        GroupsProxy::Idx(groups) => groups.all()[i][j as usize],
+        GroupsProxy::Slice { groups, .. } => groups[i][0] + j,
+    }
+}
+
+/// Call `f` with the rows of group `i`.
+fn for_each_in_group(
+    groups: &GroupsProxy,
+    i: usize,
+    f: impl FnMut(IdxSize) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    match groups {
+        GroupsProxy::Idx(groups) => groups.all()[i].iter().copied().try_for_each(f),
+        GroupsProxy::Slice { groups, .. } => {
+            let [first, len] = groups[i];
+            (first..first + len).try_for_each(f)
+        },
+    }
+}
@@ -85,8 +85,14 @@ impl PhysicalExpr for TernaryExpr {
         let mask_series = self.predicate.evaluate(df, &state)?;
         let mask = mask_series.bool()?.clone();
 
-        let op_truthy = || self.truthy.evaluate(df, &state);
-        let op_falsy = || self.falsy.evaluate(df, &state);
+        let op_truthy = || {
+            let _sc = state.enter_string_cache();
+            self.truthy.evaluate(df, &state)
+        };
+        let op_falsy = || {
+            let _sc = state.enter_string_cache();
+            self.falsy.evaluate(df, &state)
+        };
         let (truthy, falsy) = if self.run_par {
             POOL.install(|| rayon::join(op_truthy, op_falsy))
         } else {
@@ -109,9 +115,18 @@ impl PhysicalExpr for TernaryExpr {
         groups: &'a GroupsProxy,
         state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
-        let op_mask = || self.predicate.evaluate_on_groups(df, groups, state);
-        let op_truthy = || self.truthy.evaluate_on_groups(df, groups, state);
-        let op_falsy = || self.falsy.evaluate_on_groups(df, groups, state);
+        let op_mask = || {
+            let _sc = state.enter_string_cache();
+            self.predicate.evaluate_on_groups(df, groups, state)
+        };
+        let op_truthy = || {
+            let _sc = state.enter_string_cache();
+            self.truthy.evaluate_on_groups(df, groups, state)
+        };
+        let op_falsy = || {
+            let _sc = state.enter_string_cache();
+            self.falsy.evaluate_on_groups(df, groups, state)
+        };
         let (ac_mask, (ac_truthy, ac_falsy)) = if self.run_par {
             POOL.install(|| rayon::join(op_mask, || rayon::join(op_truthy, op_falsy)))
         } else {
@@ -1,3 +1,5 @@
+mod compiled;
+mod dispatch;
 pub mod executors;
 #[cfg(any(feature = "list_eval", feature = "pivot"))]
 pub(crate) mod exotic;
@@ -8,6 +10,8 @@ pub(crate) mod state;
 #[cfg(feature = "streaming")]
 pub(crate) mod streaming;
 
+pub use compiled::CompiledExpr;
+pub use dispatch::*;
 use polars_core::prelude::*;
 
 use crate::physical_plan::state::ExecutionState;
@@ -1,3 +1,6 @@
+use std::cell::RefCell;
+use std::hash::{BuildHasher, Hash, Hasher};
+
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::_split_offsets;
@@ -6,8 +9,129 @@ use polars_plan::prelude::expr_ir::ExprIR;
 use rayon::prelude::*;
 
 use super::super::expressions as phys_expr;
+use super::create_physical_plan;
+use crate::physical_plan::executors::Executor;
 use crate::prelude::*;
 
+thread_local! {
+    // The expressions whose results are cached at runtime, by their node, set while a physical
+    // plan is created by [`create_physical_plan_with_expr_cache`].
+    static CACHED_EXPRS: RefCell<Option<PlHashMap<Node, Expr>>> = const { RefCell::new(None) };
+}
+
+/// A hash of the expression at a node, which is equal for equal expressions, and whether its
+/// result can be cached.
+#[derive(Clone, Copy)]
+struct ExprSummary {
+    hash: u64,
+    cacheable: bool,
+}
+
+/// Summarize the expression at `node` from the summaries of its inputs, so that every node of
+/// the arena is visited once.
+fn summarize(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    hasher: &PlRandomState,
+    summaries: &mut PlHashMap<Node, ExprSummary>,
+) -> ExprSummary {
+    if let Some(summary) = summaries.get(&node) {
+        return *summary;
+    }
+    let ae = expr_arena.get(node);
+    let mut inputs = vec![];
+    ae.nodes(&mut inputs);
+
+    let mut state = hasher.build_hasher();
+    ae.hash(&mut state);
+    // Windows cache their groups in the state themselves, and expressions that don't give
+    // the same result on the same input can't be reused.
+    let mut cacheable =
+        !matches!(ae, AExpr::Window { .. }) && !single_aexpr_is_non_deterministic(ae);
+    for input in inputs {
+        let input = summarize(input, expr_arena, hasher, summaries);
+        input.hash.hash(&mut state);
+        cacheable &= input.cacheable;
+    }
+    let summary = ExprSummary {
+        hash: state.finish(),
+        cacheable,
+    };
+    summaries.insert(node, summary);
+    summary
+}
+
+/// The expressions that are evaluated in more than one `filter`, `select` or `with_columns`
+/// of the plan at `root`, by their node.
+///
+/// Only the outermost repeated expressions are cached, their inputs are evaluated once when
+/// they are.
+pub(crate) fn repeated_expressions(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<Node, Expr> {
+    let hasher = PlRandomState::default();
+    let mut summaries = PlHashMap::new();
+    let mut counts = PlHashMap::<u64, usize>::new();
+    let mut exprs = vec![];
+    let mut roots = vec![];
+    for (_, lp) in lp_arena.iter(root) {
+        if !matches!(
+            lp,
+            IR::Filter { .. } | IR::Select { .. } | IR::HStack { .. }
+        ) {
+            continue;
+        }
+        exprs.clear();
+        lp.copy_exprs(&mut exprs);
+        let mut seen = PlHashSet::new();
+        for e in &exprs {
+            summarize(e.node(), expr_arena, &hasher, &mut summaries);
+            for (node, _) in expr_arena.iter(e.node()) {
+                seen.insert(summaries[&node].hash);
+            }
+            roots.push(e.node());
+        }
+        for hash in seen {
+            *counts.entry(hash).or_default() += 1;
+        }
+    }
+
+    let mut cached = PlHashMap::new();
+    let mut stack = roots;
+    while let Some(node) = stack.pop() {
+        let ae = expr_arena.get(node);
+        let summary = summaries[&node];
+        if summary.cacheable
+            && counts[&summary.hash] > 1
+            && !matches!(
+                ae,
+                AExpr::Column(_) | AExpr::Literal(_) | AExpr::Len | AExpr::Alias(..)
+            )
+        {
+            cached.insert(node, node_to_expr(node, expr_arena));
+        } else {
+            ae.nodes(&mut stack);
+        }
+    }
+    cached
+}
+
+/// Create the physical plan of `root` and cache the results of the expressions that are
+/// evaluated in more than one node, so they are not recomputed on the same columns.
+pub(crate) fn create_physical_plan_with_expr_cache(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let cached = repeated_expressions(root, lp_arena, expr_arena);
+    CACHED_EXPRS.with(|exprs| *exprs.borrow_mut() = Some(cached));
+    let out = create_physical_plan(root, lp_arena, expr_arena);
+    CACHED_EXPRS.with(|exprs| *exprs.borrow_mut() = None);
+    out
+}
+
 fn ok_checker(_state: &ExpressionConversionState) -> PolarsResult<()> {
     Ok(())
 }
@@ -145,6 +269,29 @@ fn create_physical_expr_inner(
     expr_arena: &Arena<AExpr>,
     schema: Option<&SchemaRef>,
     state: &mut ExpressionConversionState,
+) -> PolarsResult<Arc<dyn PhysicalExpr>> {
+    let phys_expr = create_physical_expr_uncached(expression, ctxt, expr_arena, schema, state)?;
+    let expr = CACHED_EXPRS.with(|exprs| exprs.borrow().as_ref()?.get(&expression).cloned());
+    match expr {
+        Some(expr) => {
+            let columns = aexpr_to_leaf_names(expression, expr_arena);
+            if columns.is_empty() {
+                return Ok(phys_expr);
+            }
+            Ok(Arc::new(phys_expr::CachedExpr::new(
+                phys_expr, expr, columns,
+            )))
+        },
+        None => Ok(phys_expr),
+    }
+}
+
+fn create_physical_expr_uncached(
+    expression: Node,
+    ctxt: Context,
+    expr_arena: &Arena<AExpr>,
+    schema: Option<&SchemaRef>,
+    state: &mut ExpressionConversionState,
 ) -> PolarsResult<Arc<dyn PhysicalExpr>> {
     use AExpr::*;
 
@@ -259,6 +406,7 @@ fn create_physical_expr_inner(
             expr,
             idx,
             returns_scalar,
+            out_of_bounds,
         } => {
             let phys_expr = create_physical_expr_inner(expr, ctxt, expr_arena, schema, state)?;
             let phys_idx = create_physical_expr_inner(idx, ctxt, expr_arena, schema, state)?;
@@ -267,6 +415,7 @@ fn create_physical_expr_inner(
                 idx: phys_idx,
                 expr: node_to_expr(expression, expr_arena),
                 returns_scalar,
+                out_of_bounds,
             }))
         },
         SortBy {
@@ -562,6 +711,26 @@ fn create_physical_expr_inner(
                 output_dtype,
             )))
         },
+        // An overflow aware sum reports the rows that overflow in the input, rather than in
+        // their group, so it aggregates with the groups.
+        Function {
+            input,
+            function: FunctionExpr::IntegerSum(overflow),
+            ..
+        } if matches!(ctxt, Context::Aggregation) => {
+            let field = schema
+                .map(|schema| {
+                    expr_arena
+                        .get(expression)
+                        .to_field(schema, Context::Aggregation, expr_arena)
+                })
+                .transpose()?;
+            let input =
+                create_physical_expr_inner(input[0].node(), ctxt, expr_arena, schema, state)?;
+            Ok(Arc::new(
+                AggregationExpr::new(input, GroupByMethod::Sum, field).with_overflow(overflow),
+            ))
+        },
         Function {
             input,
             function,
@@ -146,6 +146,12 @@ pub fn create_physical_plan(
                     "sink_{file_type:?} not yet supported in standard engine. Use 'collect().write_parquet()'"
                 )
             },
+            SinkType::Partitioned { .. } => {
+                polars_bail!(InvalidOperation: "partitioned sink not supported in standard engine.")
+            },
+            SinkType::Split { .. } => {
+                polars_bail!(InvalidOperation: "split sink not supported in standard engine.")
+            },
             #[cfg(feature = "cloud")]
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
@@ -264,6 +270,8 @@ pub fn create_physical_plan(
                     options,
                     cloud_options,
                     metadata,
+                    nested_projection,
+                    decryption,
                 } => Ok(Box::new(executors::ParquetExec::new(
                     paths,
                     file_info,
@@ -272,6 +280,8 @@ pub fn create_physical_plan(
                     cloud_options,
                     file_options,
                     metadata,
+                    nested_projection,
+                    decryption.map(|d| d.0),
                 ))),
                 FileScan::Anonymous { function, .. } => {
                     Ok(Box::new(executors::AnonymousScanExec {
@@ -375,6 +385,30 @@ pub fn create_physical_plan(
                 sort_options,
             }))
         },
+        TopK {
+            input,
+            by_column,
+            offset,
+            len,
+            sort_options,
+        } => {
+            let input_schema = lp_arena.get(input).schema(lp_arena);
+            let by_column = create_physical_expressions_from_irs(
+                &by_column,
+                Context::Default,
+                expr_arena,
+                Some(input_schema.as_ref()),
+                &mut Default::default(),
+            )?;
+            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            Ok(Box::new(executors::TopKExec {
+                input,
+                by_column,
+                offset,
+                len,
+                sort_options,
+            }))
+        },
         Cache {
             input,
             id,
@@ -483,7 +517,7 @@ pub fn create_physical_plan(
                     input,
                     phys_keys,
                     phys_aggs,
-                    apply,
+                    apply.map(|f| (f, schema, options.map_groups)),
                     maintain_order,
                     input_schema,
                     options.slice,
@@ -7,6 +7,7 @@ use once_cell::sync::OnceCell;
 use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
+use polars_plan::prelude::Expr;
 
 use crate::physical_plan::node_timer::NodeTimer;
 
@@ -26,6 +27,8 @@ bitflags! {
         /// If set, the expression is evaluated in the
         /// streaming engine.
         const IN_STREAMING = 0x08;
+        /// Operations that only prefer contiguous input don't rechunk it.
+        const NO_AUTO_RECHUNK = 0x10;
     }
 }
 
@@ -56,11 +59,18 @@ impl From<u8> for StateFlags {
 }
 
 type CachedValue = Arc<(AtomicI64, OnceCell<DataFrame>)>;
+/// An expression and the identities of the columns it reads and the number of rows.
+type ExprCacheKey = (Expr, Vec<usize>, usize);
+/// The result of an expression and the columns it was computed from. The columns are
+/// kept alive, so their identities are not reused for other data.
+type ExprCache = Arc<Mutex<PlHashMap<ExprCacheKey, (Vec<Series>, Series)>>>;
 
 /// State/ cache that is maintained during the Execution of the physical plan.
 pub struct ExecutionState {
     // cached by a `.cache` call and kept in memory for the duration of the plan.
     df_cache: Arc<Mutex<PlHashMap<usize, CachedValue>>>,
+    // results of expressions that are evaluated in more than one node of the plan.
+    expr_cache: ExprCache,
     pub(super) schema_cache: RwLock<Option<SchemaRef>>,
     /// Used by Window Expression to prevent redundant grouping
     pub(super) group_tuples: GroupsProxyCache,
@@ -68,10 +78,23 @@ pub struct ExecutionState {
     pub(super) join_tuples: JoinTuplesCache,
     // every join/union split gets an increment to distinguish between schema state
     pub(super) branch_idx: usize,
+    /// The index of the batch of the streaming engine that is evaluated, from which the seeded
+    /// random expressions derive the seed of its values.
+    pub(super) batch_idx: u64,
     pub(super) flags: AtomicU8,
     pub(super) ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<AtomicBool>,
+    /// The string cache scope of the thread that created the state, if any.
+    #[cfg(feature = "dtype-categorical")]
+    string_cache: Option<ScopedStringCache>,
+}
+
+/// Uses the string cache of a query on the thread that holds it, see
+/// [`ExecutionState::enter_string_cache`].
+pub(crate) struct StringCacheGuard {
+    #[cfg(feature = "dtype-categorical")]
+    _scope: StringCacheScope,
 }
 
 impl ExecutionState {
@@ -82,14 +105,31 @@ impl ExecutionState {
         }
         Self {
             df_cache: Default::default(),
+            expr_cache: Default::default(),
             schema_cache: Default::default(),
             group_tuples: Default::default(),
             join_tuples: Default::default(),
             branch_idx: 0,
+            batch_idx: 0,
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
             stop: Arc::new(AtomicBool::new(false)),
+            #[cfg(feature = "dtype-categorical")]
+            string_cache: StringCacheScope::current(),
+        }
+    }
+
+    /// Use the string cache of the [`StringCacheScope`] of the thread that created the state
+    /// on this thread, or the global string cache if it had none, as long as the guard is
+    /// alive.
+    ///
+    /// Work that runs on the thread pool holds the guard, so that the categoricals that it
+    /// creates share their categories with the rest of the query.
+    pub(crate) fn enter_string_cache(&self) -> StringCacheGuard {
+        StringCacheGuard {
+            #[cfg(feature = "dtype-categorical")]
+            _scope: StringCacheScope::enter(self.string_cache.as_ref()),
         }
     }
 
@@ -134,14 +174,18 @@ impl ExecutionState {
     pub(super) fn split(&self) -> Self {
         Self {
             df_cache: self.df_cache.clone(),
+            expr_cache: self.expr_cache.clone(),
             schema_cache: Default::default(),
             group_tuples: Default::default(),
             join_tuples: Default::default(),
             branch_idx: self.branch_idx,
+            batch_idx: self.batch_idx,
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            #[cfg(feature = "dtype-categorical")]
+            string_cache: self.string_cache.clone(),
         }
     }
 
@@ -149,14 +193,18 @@ impl ExecutionState {
     pub(super) fn clone(&self) -> Self {
         Self {
             df_cache: self.df_cache.clone(),
+            expr_cache: self.expr_cache.clone(),
             schema_cache: self.schema_cache.read().unwrap().clone().into(),
             group_tuples: self.group_tuples.clone(),
             join_tuples: self.join_tuples.clone(),
             branch_idx: self.branch_idx,
+            batch_idx: self.batch_idx,
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            #[cfg(feature = "dtype-categorical")]
+            string_cache: self.string_cache.clone(),
         }
     }
 
@@ -190,6 +238,39 @@ impl ExecutionState {
         let _ = guard.remove(&key).unwrap();
     }
 
+    /// Get the result of `expr` on the columns `inputs` of a frame with `height` rows, or
+    /// evaluate and cache it.
+    pub(super) fn get_or_eval_expr<F>(
+        &self,
+        expr: &Expr,
+        inputs: Vec<Series>,
+        height: usize,
+        eval: F,
+    ) -> PolarsResult<Series>
+    where
+        F: FnOnce() -> PolarsResult<Series>,
+    {
+        let ids = inputs
+            .iter()
+            .map(|s| Arc::as_ptr(&s.0) as *const () as usize)
+            .collect();
+        let key = (expr.clone(), ids, height);
+        if let Some((_, out)) = self.expr_cache.lock().unwrap().get(&key) {
+            if self.verbose() {
+                eprintln!("expression cache hit: {expr}")
+            }
+            return Ok(out.clone());
+        }
+        // Don't hold the lock while evaluating, other expressions may be evaluated in
+        // parallel.
+        let out = eval()?;
+        self.expr_cache
+            .lock()
+            .unwrap()
+            .insert(key, (inputs, out.clone()));
+        Ok(out)
+    }
+
     /// Clear the cache used by the Window expressions
     pub(crate) fn clear_window_expr_cache(&self) {
         {
@@ -258,6 +339,19 @@ impl ExecutionState {
         let flags: StateFlags = self.flags.load(Ordering::Relaxed).into();
         flags.contains(StateFlags::IN_STREAMING)
     }
+
+    pub(crate) fn disable_auto_rechunk(&mut self) {
+        self.set_flags(&|mut flags| {
+            flags.insert(StateFlags::NO_AUTO_RECHUNK);
+            flags
+        });
+    }
+
+    /// Whether operations that prefer contiguous input may rechunk it.
+    pub(super) fn auto_rechunk(&self) -> bool {
+        let flags: StateFlags = self.flags.load(Ordering::Relaxed).into();
+        !flags.contains(StateFlags::NO_AUTO_RECHUNK)
+    }
 }
 
 impl Default for ExecutionState {
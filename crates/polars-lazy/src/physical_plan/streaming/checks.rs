@@ -1,5 +1,7 @@
 use polars_core::chunked_array::ops::SortMultipleOptions;
+use polars_core::prelude::*;
 use polars_ops::prelude::*;
+use polars_pipe::pipeline::distinct_by_sorting;
 use polars_plan::logical_plan::expr_ir::ExprIR;
 use polars_plan::prelude::*;
 
@@ -17,6 +19,24 @@ pub(super) fn is_streamable_sort(
     }
 }
 
+/// Check if a distinct can run in the streaming engine. If the distinct rows are found by
+/// sorting, the keys have to be sortable, and a slice of the result isn't supported.
+pub(super) fn is_streamable_distinct(options: &DistinctOptions, input_schema: &Schema) -> bool {
+    if !distinct_by_sorting(options) {
+        return true;
+    }
+    let sortable = |name: &str| {
+        input_schema.get(name).map_or(false, |dtype| {
+            !dtype.is_nested() && !dtype.is_object() && !matches!(dtype, DataType::Null)
+        })
+    };
+    let keys_sortable = match &options.subset {
+        Some(subset) => subset.iter().all(|name| sortable(name)),
+        None => input_schema.iter_names().all(|name| sortable(name)),
+    };
+    options.slice.is_none() && keys_sortable && options.keep_by.as_deref().map_or(true, sortable)
+}
+
 /// check if all expressions are a simple column projection
 pub(super) fn all_column(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> bool {
     exprs
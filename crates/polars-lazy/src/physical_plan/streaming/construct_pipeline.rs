@@ -18,31 +18,40 @@ use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::streaming::tree::{PipelineNode, Tree};
 use crate::prelude::*;
 
-pub struct Wrap(Arc<dyn PhysicalExpr>);
+pub struct Wrap {
+    expr: Arc<dyn PhysicalExpr>,
+    /// Whether the expression draws random values, which depend on the batch index of the chunk.
+    has_random: bool,
+}
 
 impl PhysicalIoExpr for Wrap {
     fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
         let h = PhysicalIoHelper {
-            expr: self.0.clone(),
+            expr: self.expr.clone(),
             has_window_function: false,
         };
         h.evaluate_io(df)
     }
     fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
-        self.0.as_stats_evaluator()
+        self.expr.as_stats_evaluator()
     }
 }
 impl PhysicalPipedExpr for Wrap {
     fn evaluate(&self, chunk: &DataChunk, state: &dyn Any) -> PolarsResult<Series> {
         let state = state.downcast_ref::<ExecutionState>().unwrap();
-        self.0.evaluate(&chunk.data, state)
+        if self.has_random {
+            let mut state = state.clone();
+            state.batch_idx = chunk.batch_index;
+            return self.expr.evaluate(&chunk.data, &state);
+        }
+        self.expr.evaluate(&chunk.data, state)
     }
     fn field(&self, input_schema: &Schema) -> PolarsResult<Field> {
-        self.0.to_field(input_schema)
+        self.expr.to_field(input_schema)
     }
 
     fn expression(&self) -> Expr {
-        self.0.as_expression().unwrap().clone()
+        self.expr.as_expression().unwrap().clone()
     }
 }
 
@@ -51,6 +60,18 @@ fn to_physical_piped_expr(
     expr_arena: &Arena<AExpr>,
     schema: Option<&SchemaRef>,
 ) -> PolarsResult<Arc<dyn PhysicalPipedExpr>> {
+    #[cfg(feature = "random")]
+    let has_random = has_aexpr(expr.node(), expr_arena, |e| {
+        matches!(
+            e,
+            AExpr::Function {
+                function: FunctionExpr::Random { .. },
+                ..
+            }
+        )
+    });
+    #[cfg(not(feature = "random"))]
+    let has_random = false;
     // this is a double Arc<dyn> explore if we can create a single of it.
     create_physical_expr(
         expr,
@@ -59,7 +80,7 @@ fn to_physical_piped_expr(
         schema,
         &mut ExpressionConversionState::new(false),
     )
-    .map(|e| Arc::new(Wrap(e)) as Arc<dyn PhysicalPipedExpr>)
+    .map(|expr| Arc::new(Wrap { expr, has_random }) as Arc<dyn PhysicalPipedExpr>)
 }
 
 fn jit_insert_slice(
@@ -104,6 +125,7 @@ pub(super) fn construct(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     fmt: bool,
+    spill_options: SpillOptions,
 ) -> PolarsResult<Option<Node>> {
     use IR::*;
 
@@ -199,6 +221,7 @@ pub(super) fn construct(
             expr_arena,
             to_physical_piped_expr,
             is_verbose,
+            spill_options,
             &mut sink_cache,
             &mut callbacks,
         )?;
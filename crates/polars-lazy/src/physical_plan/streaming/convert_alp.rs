@@ -106,6 +106,7 @@ pub(crate) fn insert_streaming_nodes(
     // to streaming
     allow_partial: bool,
     row_estimate: bool,
+    spill_options: SpillOptions,
 ) -> PolarsResult<bool> {
     scratch.clear();
 
@@ -209,6 +210,16 @@ pub(crate) fn insert_streaming_nodes(
                 state.operators_sinks.push(PipelineNode::Sink(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
+            TopK {
+                input,
+                by_column,
+                sort_options,
+                ..
+            } if !sort_options.maintain_order && all_column(by_column, expr_arena) => {
+                state.streamable = true;
+                state.operators_sinks.push(PipelineNode::Sink(root));
+                stack.push(StackFrame::new(*input, state, current_idx))
+            },
             Select { input, expr, .. } if all_streamable(expr, expr_arena, Context::Default) => {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Operator(root));
@@ -371,8 +382,7 @@ pub(crate) fn insert_streaming_nodes(
                 }
             },
             Distinct { input, options }
-                if !options.maintain_order
-                    && !matches!(options.keep_strategy, UniqueKeepStrategy::None) =>
+                if is_streamable_distinct(options, &lp_arena.get(*input).schema(lp_arena)) =>
             {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Sink(root));
@@ -485,7 +495,8 @@ pub(crate) fn insert_streaming_nodes(
     let mut inserted = false;
     for tree in pipeline_trees {
         if is_valid_tree(&tree)
-            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt)?.is_some()
+            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt, spill_options)?
+                .is_some()
         {
             inserted = true;
         }
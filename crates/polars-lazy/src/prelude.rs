@@ -1,20 +1,37 @@
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
+#[cfg(feature = "delta")]
+pub use polars_io::delta::{DeltaWriteMode, DeltaWriteOptions};
 #[cfg(feature = "ipc")]
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
-pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+pub use polars_io::parquet::write::{ParquetSinkOptions, ParquetWriteOptions};
+pub use polars_io::{
+    CommitStrategy, ExistingDataBehavior, PartitionedWriteOptions, SplitWriteOptions,
+};
+#[cfg(feature = "outliers")]
+pub use polars_ops::prelude::OutlierMethod;
+pub use polars_ops::prelude::{
+    ByNullPolicy, ByTiePolicy, IntegerOverflow, JoinArgs, JoinType, JoinValidation,
+    MinMaxByOptions, OutOfBoundsPolicy,
+};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
+#[cfg(feature = "sink_manifest")]
+pub use polars_plan::logical_plan::FileType;
 pub use polars_plan::logical_plan::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
     NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
+#[cfg(feature = "streaming")]
+pub use polars_plan::prelude::SpillOptions;
 pub(crate) use polars_plan::prelude::*;
+pub use polars_plan::prelude::{
+    CastPolicy, ChunkPolicy, ChunkRequirement, ColumnLineage, MapGroupsOptions, OptimizerEvent,
+    OptimizerOutcome, SourceColumn, UnionArgs,
+};
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
 #[cfg(feature = "dynamic_group_by")]
@@ -24,4 +41,9 @@ pub(crate) use polars_utils::arena::{Arena, Node};
 pub use crate::dsl::*;
 pub use crate::frame::*;
 pub use crate::physical_plan::expressions::*;
+pub use crate::physical_plan::{CompiledExpr, DispatchedExecutor, PhysicalPlanDispatch};
+#[cfg(feature = "unbounded_scan")]
+pub use crate::scan::unbounded::{
+    MicroBatch, SourcePoll, UnboundedCommit, UnboundedScanOptions, UnboundedSource,
+};
 pub(crate) use crate::scan::*;
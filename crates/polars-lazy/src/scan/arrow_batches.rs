@@ -0,0 +1,210 @@
+use std::any::Any;
+
+use polars_core::frame::ArrowChunk;
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Reads in-memory Arrow record batches.
+///
+/// The arrays are shared with the batches, only the projected columns and the rows of the
+/// pushed down slice are read.
+#[derive(Clone)]
+struct ArrowBatchesScan {
+    fields: Arc<[ArrowField]>,
+    batches: Arc<[ArrowChunk]>,
+}
+
+impl ArrowBatchesScan {
+    /// The indices of the projected columns.
+    fn projection(&self, args: &AnonymousScanArgs) -> PolarsResult<Vec<usize>> {
+        match &args.with_columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| {
+                    self.fields
+                        .iter()
+                        .position(|field| &field.name == name)
+                        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))
+                })
+                .collect(),
+            None => Ok((0..self.fields.len()).collect()),
+        }
+    }
+
+    /// The projected columns of `len` rows of a batch, starting at `offset`.
+    fn read(
+        &self,
+        batch: &ArrowChunk,
+        projection: &[usize],
+        offset: usize,
+        len: usize,
+    ) -> PolarsResult<DataFrame> {
+        let columns = projection
+            .iter()
+            .map(|&i| {
+                let arr = &batch.columns()[i];
+                let arr = if offset == 0 && len == arr.len() {
+                    arr.clone()
+                } else {
+                    arr.sliced(offset, len)
+                };
+                Series::try_from((&self.fields[i], arr))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+impl AnonymousScan for ArrowBatchesScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        // Every record batch becomes a chunk of the frame.
+        let mut reader = ArrowBatchesReader::new(self, &scan_opts, usize::MAX)?;
+        let Some(mut df) = reader.next_batch()? else {
+            let schema = reader
+                .projection
+                .iter()
+                .map(|&i| Field::from(&self.fields[i]))
+                .collect::<Schema>();
+            return Ok(DataFrame::from(&schema));
+        };
+        while let Some(other) = reader.next_batch()? {
+            df.vstack_mut(&other)?;
+        }
+        Ok(df)
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(Schema::from_iter(
+            self.fields.iter().map(Field::from),
+        )))
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+
+    fn batched(
+        &self,
+        scan_opts: AnonymousScanArgs,
+        chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        Ok(Box::new(ArrowBatchesReader::new(
+            self,
+            &scan_opts,
+            chunk_size.max(1),
+        )?))
+    }
+}
+
+/// Reads the record batches of an [`ArrowBatchesScan`] in chunks of at most `chunk_size`
+/// rows.
+struct ArrowBatchesReader {
+    scan: ArrowBatchesScan,
+    projection: Vec<usize>,
+    chunk_size: usize,
+    // the position of the next row to read
+    batch_idx: usize,
+    offset: usize,
+    // the number of rows that are left to read
+    remaining: usize,
+}
+
+impl ArrowBatchesReader {
+    fn new(
+        scan: &ArrowBatchesScan,
+        args: &AnonymousScanArgs,
+        chunk_size: usize,
+    ) -> PolarsResult<Self> {
+        Ok(Self {
+            scan: scan.clone(),
+            projection: scan.projection(args)?,
+            chunk_size,
+            batch_idx: 0,
+            offset: 0,
+            remaining: args.n_rows.unwrap_or(usize::MAX),
+        })
+    }
+
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        while self.remaining > 0 {
+            let Some(batch) = self.scan.batches.get(self.batch_idx) else {
+                break;
+            };
+            if self.offset >= batch.len() {
+                self.batch_idx += 1;
+                self.offset = 0;
+                continue;
+            }
+            let len = (batch.len() - self.offset)
+                .min(self.chunk_size)
+                .min(self.remaining);
+            let df = self.scan.read(batch, &self.projection, self.offset, len)?;
+            self.offset += len;
+            self.remaining -= len;
+            return Ok(Some(df));
+        }
+        Ok(None)
+    }
+}
+
+impl AnonymousScanBatches for ArrowBatchesReader {
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        let mut batches = Vec::with_capacity(n);
+        while batches.len() < n {
+            match self.next_batch()? {
+                Some(df) => batches.push(df),
+                None => break,
+            }
+        }
+        Ok((!batches.is_empty()).then_some(batches))
+    }
+}
+
+impl LazyFrame {
+    /// Scan in-memory Arrow record batches with the fields of `schema`, e.g. the batches of
+    /// an Arrow table produced by another library.
+    ///
+    /// The batches are not copied into a [`DataFrame`] up front: only the projected columns
+    /// and the rows of a pushed down slice are read when the query runs, sharing the Arrow
+    /// buffers where the types allow it. In the streaming engine the batches are read one
+    /// chunk at a time.
+    pub fn scan_arrow_batches(schema: ArrowSchema, batches: Vec<ArrowChunk>) -> PolarsResult<Self> {
+        for batch in &batches {
+            polars_ensure!(
+                batch.columns().len() == schema.fields.len(),
+                ShapeMismatch: "record batch has {} columns, but the schema has {} fields",
+                batch.columns().len(), schema.fields.len()
+            );
+            for (arr, field) in batch.columns().iter().zip(&schema.fields) {
+                polars_ensure!(
+                    arr.data_type() == field.data_type(),
+                    SchemaMismatch: "column '{}' of a record batch has type {:?}, expected {:?}",
+                    field.name, arr.data_type(), field.data_type()
+                );
+            }
+        }
+        let scan = ArrowBatchesScan {
+            fields: schema.fields.into(),
+            batches: batches.into(),
+        };
+        let args = ScanArgsAnonymous {
+            schema: Some(scan.schema(None)?),
+            name: "ARROW BATCHES",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(scan), args)
+    }
+}
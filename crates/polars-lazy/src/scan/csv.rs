@@ -1,7 +1,9 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
-use polars_io::csv::read::{infer_file_schema, CommentPrefix, CsvEncoding, NullValues};
+use polars_io::csv::read::{
+    infer_file_schema, CommentPrefix, CsvEncoding, CsvParseErrorPolicy, NullValues,
+};
 use polars_io::utils::get_reader_bytes;
 use polars_io::RowIndex;
 
@@ -37,6 +39,7 @@ pub struct LazyCsvReader {
     try_parse_dates: bool,
     raise_if_empty: bool,
     glob: bool,
+    parse_error_policies: Option<Vec<(String, CsvParseErrorPolicy)>>,
 }
 
 #[cfg(feature = "csv")]
@@ -74,6 +77,7 @@ impl LazyCsvReader {
             n_threads: None,
             decimal_comma: false,
             glob: true,
+            parse_error_policies: None,
         }
     }
 
@@ -198,13 +202,22 @@ impl LazyCsvReader {
         self
     }
 
-    /// Reduce memory usage at the expense of performance
+    /// Reduce memory usage at the expense of performance. A single chunk of the file is
+    /// decoded at a time, unless the number of threads is set.
     #[must_use]
     pub fn low_memory(mut self, toggle: bool) -> Self {
         self.low_memory = toggle;
         self
     }
 
+    /// Set the number of threads used to read the file. This bounds the number of chunks of
+    /// the file that are decoded at the same time, and takes precedence over low memory mode.
+    #[must_use]
+    pub fn with_n_threads(mut self, n_threads: Option<usize>) -> Self {
+        self.n_threads = n_threads;
+        self
+    }
+
     /// Set  [`CsvEncoding`]
     #[must_use]
     pub fn with_encoding(mut self, enc: CsvEncoding) -> Self {
@@ -240,6 +253,18 @@ impl LazyCsvReader {
         self
     }
 
+    /// Set what to do with the values that cannot be parsed as the dtype of a column, per
+    /// column name. The dtypes of these columns must be given with [with_schema](Self::with_schema)
+    /// or [with_dtype_overwrite](Self::with_dtype_overwrite).
+    #[must_use]
+    pub fn with_parse_error_policies(
+        mut self,
+        policies: Option<Vec<(String, CsvParseErrorPolicy)>>,
+    ) -> Self {
+        self.parse_error_policies = policies;
+        self
+    }
+
     #[must_use]
     /// Expand path given via globbing rules.
     pub fn with_glob(mut self, toggle: bool) -> Self {
@@ -295,10 +320,51 @@ impl LazyCsvReader {
 
         Ok(self.with_schema(Some(Arc::new(schema))))
     }
+
+    /// Read the columns that have a parse error policy as String and return the expressions
+    /// that parse them afterwards.
+    fn prepare_parse_error_policies(&mut self) -> PolarsResult<Vec<Expr>> {
+        let Some(policies) = self.parse_error_policies.take() else {
+            return Ok(vec![]);
+        };
+        let mut exprs = Vec::with_capacity(policies.len());
+        for (name, policy) in policies {
+            let schema = [&mut self.schema_overwrite, &mut self.schema]
+                .into_iter()
+                .flatten()
+                .find(|schema| schema.contains(&name));
+            let Some(schema) = schema else {
+                polars_bail!(
+                    ComputeError: "the dtype of column '{}' must be given to apply a parse error policy",
+                    name
+                )
+            };
+            let schema = Arc::make_mut(schema);
+            let dtype = schema.get(&name).unwrap().clone();
+            schema.with_column(name.as_str().into(), DataType::String);
+
+            match policy {
+                CsvParseErrorPolicy::Raise => exprs.push(col(&name).strict_cast(dtype)),
+                CsvParseErrorPolicy::Null => exprs.push(col(&name).cast(dtype)),
+                CsvParseErrorPolicy::Sidecar => {
+                    // Only keep the raw values that failed to parse.
+                    exprs.push(
+                        when(col(&name).cast(dtype.clone()).is_null())
+                            .then(col(&name))
+                            .otherwise(lit(NULL).cast(DataType::String))
+                            .alias(&format!("{name}_raw")),
+                    );
+                    exprs.push(col(&name).cast(dtype));
+                },
+            }
+        }
+        Ok(exprs)
+    }
 }
 
 impl LazyFileListReader for LazyCsvReader {
-    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+    fn finish_no_glob(mut self) -> PolarsResult<LazyFrame> {
+        let parse_exprs = self.prepare_parse_error_policies()?;
         let mut lf: LazyFrame = DslBuilder::scan_csv(
             self.path,
             self.separator,
@@ -328,6 +394,9 @@ impl LazyFileListReader for LazyCsvReader {
         .build()
         .into();
         lf.opt_state.file_caching = true;
+        if !parse_exprs.is_empty() {
+            lf = lf.with_columns(parse_exprs);
+        }
         Ok(lf)
     }
 
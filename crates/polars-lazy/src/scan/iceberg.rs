@@ -0,0 +1,191 @@
+use std::any::Any;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::iceberg::{IcebergDataFile, IcebergDeleteFile, IcebergTable};
+use polars_io::RowIndex;
+
+use super::io_predicate;
+use crate::prelude::*;
+
+/// The name of the column with the position of the rows in their data file, which position
+/// deletes refer to.
+const POSITION: &str = "__POLARS_ICEBERG_POS";
+
+#[derive(Clone, Default)]
+pub struct ScanArgsIceberg {
+    /// The snapshot to read, the current snapshot of the table if `None`.
+    pub snapshot_id: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+}
+
+/// Reads a snapshot of an Iceberg table.
+///
+/// The manifests and data files that can't contain rows for which the pushed down predicate
+/// holds are skipped, after which the data files are read with their delete files applied.
+struct IcebergScan {
+    table: IcebergTable,
+}
+
+impl IcebergScan {
+    /// The rows of a data file that aren't deleted, with the columns of the table.
+    fn read_file(&self, file: &IcebergDataFile) -> PolarsResult<LazyFrame> {
+        let args = ScanArgsParquet {
+            row_index: Some(RowIndex {
+                name: POSITION.into(),
+                offset: 0,
+            }),
+            ..Default::default()
+        };
+        let lf = LazyFrame::scan_parquet(&file.path, args)?;
+        let file_columns = self.table.file_columns(&file.path)?;
+        // The columns that were added to the table after the file was written are null.
+        let columns = self
+            .table
+            .schema()
+            .iter_fields()
+            .zip(file_columns)
+            .map(|(field, column)| match column {
+                Some(column) => col(&column)
+                    .cast(field.data_type().clone())
+                    .alias(field.name()),
+                None => lit(NULL)
+                    .cast(field.data_type().clone())
+                    .alias(field.name()),
+            })
+            .chain([col(POSITION)])
+            .collect::<Vec<_>>();
+        let mut lf = lf.select(columns);
+
+        for delete in &file.deletes {
+            let (deleted, keys) = match delete {
+                IcebergDeleteFile::Position { path } => {
+                    let positions = LazyFrame::scan_parquet(path, Default::default())?
+                        .filter(col("file_path").eq(lit(file.uri.as_str())))
+                        .select([col("pos").cast(IDX_DTYPE).alias(POSITION)]);
+                    (positions, vec![col(POSITION)])
+                },
+                IcebergDeleteFile::Equality { path, columns } => {
+                    let schema = self.table.schema();
+                    let delete_columns = self.table.file_columns(path)?;
+                    let keys = columns
+                        .iter()
+                        .map(|name| {
+                            let (i, _, dtype) = schema.try_get_full(name)?;
+                            let column = delete_columns[i].as_deref().ok_or_else(|| {
+                                polars_err!(
+                                    ComputeError: "the Iceberg equality delete file '{}' has no \
+                                    column for the field '{}'", path.display(), name
+                                )
+                            })?;
+                            Ok(col(column).cast(dtype.clone()).alias(name))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    let deleted = LazyFrame::scan_parquet(path, Default::default())?.select(&keys);
+                    (deleted, columns.iter().map(|name| col(name)).collect())
+                },
+            };
+            lf = lf
+                .join_builder()
+                .with(deleted)
+                .left_on(keys.clone())
+                .right_on(keys)
+                .how(JoinType::Anti)
+                .join_nulls(true)
+                .finish();
+        }
+        Ok(lf.drop([POSITION]))
+    }
+}
+
+impl AnonymousScan for IcebergScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let io_predicate = scan_opts
+            .predicate
+            .as_ref()
+            .map(|predicate| io_predicate(predicate, self.table.schema()))
+            .transpose()?;
+        let files = self.table.data_files(io_predicate.as_deref())?;
+        if files.is_empty() {
+            let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+            return Ok(DataFrame::from(schema.as_ref()));
+        }
+
+        let finish = |mut lf: LazyFrame| {
+            // The statistics only skip files, the rows of the files that are read are filtered.
+            if let Some(predicate) = &scan_opts.predicate {
+                lf = lf.filter(predicate.clone());
+            }
+            if let Some(columns) = &scan_opts.with_columns {
+                lf = lf.select(columns.iter().map(|name| col(name)).collect::<Vec<_>>());
+            }
+            lf
+        };
+        let Some(n_rows) = scan_opts.n_rows else {
+            let files = files
+                .iter()
+                .map(|file| Ok(finish(self.read_file(file)?)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            return concat(files, UnionArgs::default())?.collect();
+        };
+
+        // With a slice, the files are read one after the other until there are enough rows,
+        // as the number of rows that are deleted from a file isn't known before reading it.
+        let mut dfs = vec![];
+        let mut remaining = n_rows;
+        for file in &files {
+            if remaining == 0 && !dfs.is_empty() {
+                break;
+            }
+            let df = finish(self.read_file(file)?)
+                .slice(0, remaining as IdxSize)
+                .collect()?;
+            remaining -= df.height();
+            dfs.push(df);
+        }
+        accumulate_dataframes_vertical(dfs)
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.table.schema().clone())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Scan an Apache Iceberg table, given the path of its root directory or of one of its
+    /// metadata files.
+    ///
+    /// A pushed down predicate skips the manifests and the data files whose partition values
+    /// and column bounds show that they have no matching rows. The positional and equality
+    /// delete files of the snapshot are applied to the data files they refer to.
+    pub fn scan_iceberg(path: impl AsRef<Path>, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let table = IcebergTable::open(path.as_ref(), args.snapshot_id)?;
+        let scan = IcebergScan { table };
+        let args = ScanArgsAnonymous {
+            schema: Some(scan.schema(None)?),
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "ICEBERG SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(scan), args)
+    }
+}
@@ -14,6 +14,9 @@ pub struct ScanArgsIpc {
     pub rechunk: bool,
     pub row_index: Option<RowIndex>,
     pub memory_map: bool,
+    pub low_memory: bool,
+    /// The maximum number of files that are decoded at the same time.
+    pub max_decoders: Option<usize>,
     pub cloud_options: Option<CloudOptions>,
 }
 
@@ -25,6 +28,8 @@ impl Default for ScanArgsIpc {
             rechunk: false,
             row_index: None,
             memory_map: true,
+            low_memory: false,
+            max_decoders: None,
             cloud_options: Default::default(),
         }
     }
@@ -69,6 +74,9 @@ impl LazyFileListReader for LazyIpcReader {
 
         let options = IpcScanOptions {
             memory_map: args.memory_map,
+            low_memory: args.low_memory,
+            max_decoders: args.max_decoders,
+            skip_rows: 0,
         };
 
         let mut lf: LazyFrame = DslBuilder::scan_ipc(
@@ -1,13 +1,61 @@
 pub(super) mod anonymous_scan;
+pub(super) mod arrow_batches;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
 pub(super) mod file_list_reader;
+#[cfg(feature = "iceberg")]
+pub(super) mod iceberg;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
+#[cfg(feature = "orc")]
+pub(super) mod orc;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(feature = "range")]
+pub(super) mod range;
+#[cfg(feature = "unbounded_scan")]
+pub(super) mod unbounded;
 
 #[cfg(feature = "json")]
 use file_list_reader::*;
+#[cfg(any(feature = "iceberg", feature = "orc"))]
+use polars_core::prelude::*;
+#[cfg(any(feature = "iceberg", feature = "orc"))]
+use polars_io::predicates::PhysicalIoExpr;
+
+#[cfg(any(feature = "iceberg", feature = "orc"))]
+use crate::physical_plan::expressions::phys_expr_to_io_expr;
+#[cfg(any(feature = "iceberg", feature = "orc"))]
+use crate::physical_plan::planner::create_physical_expr;
+#[cfg(any(feature = "iceberg", feature = "orc"))]
+use crate::prelude::*;
+
+/// The predicate of a scan as an expression that can be evaluated on the statistics of the
+/// files, or of the parts of a file, that are scanned, and on the rows that are read.
+#[cfg(any(feature = "iceberg", feature = "orc"))]
+fn io_predicate(predicate: &Expr, schema: &Schema) -> PolarsResult<Arc<dyn PhysicalIoExpr>> {
+    let mut lp_arena = Arena::with_capacity(8);
+    let mut expr_arena = Arena::with_capacity(10);
+
+    // Optimize the predicate on an empty frame with the schema of the scan, so that type
+    // coercion and the simplification of expressions run.
+    let lf = DataFrame::from(schema)
+        .lazy()
+        .without_optimizations()
+        .with_simplify_expr(true)
+        .select([predicate.clone()]);
+    let optimized = lf.optimize(&mut lp_arena, &mut expr_arena)?;
+    let lp = lp_arena.get(optimized);
+    let aexpr = lp.get_exprs().pop().unwrap();
+
+    let expr = create_physical_expr(
+        &aexpr,
+        Context::Default,
+        &expr_arena,
+        None,
+        &mut Default::default(),
+    )?;
+    Ok(phys_expr_to_io_expr(expr))
+}
@@ -0,0 +1,119 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::orc::OrcReader;
+use polars_io::{RowIndex, SerReader};
+use polars_plan::utils::expr_to_leaf_column_names_iter;
+
+use super::io_predicate;
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsOrc {
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    /// Read the stripes of the file in parallel.
+    pub parallel: bool,
+}
+
+impl Default for ScanArgsOrc {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            row_index: None,
+            parallel: true,
+        }
+    }
+}
+
+/// Reads an ORC file, skipping the stripes whose statistics show that they have no rows for
+/// which the pushed down predicate holds.
+struct OrcScan {
+    path: PathBuf,
+    schema: SchemaRef,
+    parallel: bool,
+}
+
+impl AnonymousScan for OrcScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let io_predicate = scan_opts
+            .predicate
+            .as_ref()
+            .map(|predicate| io_predicate(predicate, &self.schema))
+            .transpose()?;
+        // The reader filters the rows of the stripes, for which it needs the columns of the
+        // predicate.
+        let columns = scan_opts.with_columns.as_ref().map(|columns| {
+            let mut columns = columns.as_ref().clone();
+            if let Some(predicate) = &scan_opts.predicate {
+                for name in expr_to_leaf_column_names_iter(predicate) {
+                    if !columns
+                        .iter()
+                        .any(|column| column.as_str() == name.as_ref())
+                    {
+                        columns.push(name.to_string());
+                    }
+                }
+            }
+            columns
+        });
+
+        let file = polars_utils::open_file(&self.path)?;
+        let df = OrcReader::new(file)
+            .with_columns(columns)
+            .with_predicate(io_predicate)
+            .with_n_rows(scan_opts.n_rows)
+            .set_parallel(self.parallel)
+            .finish()?;
+        match scan_opts.with_columns {
+            Some(columns) => df.select(columns.iter()),
+            None => Ok(df),
+        }
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Scan an Apache ORC file.
+    ///
+    /// Only the projected columns are decoded, and the stripes of the file are read in
+    /// parallel. A pushed down predicate skips the stripes whose column statistics show that
+    /// they have no matching rows.
+    pub fn scan_orc(path: impl AsRef<Path>, args: ScanArgsOrc) -> PolarsResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let schema = OrcReader::new(polars_utils::open_file(&path)?).schema()?;
+        let scan = OrcScan {
+            path,
+            schema: schema.clone(),
+            parallel: args.parallel,
+        };
+        let args = ScanArgsAnonymous {
+            schema: Some(schema),
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "ORC SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(scan), args)
+    }
+}
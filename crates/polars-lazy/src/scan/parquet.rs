@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
+use polars_io::parquet::read::{FileDecryptionProperties, ParallelStrategy};
 use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
@@ -16,10 +16,14 @@ pub struct ScanArgsParquet {
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
     pub low_memory: bool,
+    /// The maximum number of row groups that are decoded at the same time.
+    pub max_decoders: Option<usize>,
     pub rechunk: bool,
     pub cache: bool,
     /// Expand path given via globbing rules.
     pub glob: bool,
+    /// The keys to read encrypted files with.
+    pub decryption: Option<Arc<FileDecryptionProperties>>,
 }
 
 impl Default for ScanArgsParquet {
@@ -33,8 +37,10 @@ impl Default for ScanArgsParquet {
             use_statistics: true,
             rechunk: false,
             low_memory: false,
+            max_decoders: None,
             cache: true,
             glob: true,
+            decryption: None,
         }
     }
 }
@@ -87,9 +93,11 @@ impl LazyFileListReader for LazyParquetReader {
             None,
             self.args.rechunk,
             self.args.low_memory,
+            self.args.max_decoders,
             self.args.cloud_options,
             self.args.use_statistics,
             self.args.hive_options,
+            self.args.decryption,
         )?
         .build()
         .into();
@@ -0,0 +1,239 @@
+use std::any::Any;
+
+#[cfg(feature = "dtype-date")]
+use polars_core::export::chrono::NaiveDate;
+use polars_core::prelude::*;
+use smartstring::alias::String as SmartString;
+
+use crate::prelude::*;
+
+/// Generates the values `start, start + step, ...` up to, but not including, `end`.
+///
+/// The values are generated as `Int64` and cast to the output `dtype`.
+struct RangeScan {
+    name: SmartString,
+    start: i64,
+    end: i64,
+    step: i64,
+    dtype: DataType,
+}
+
+fn range_len(start: i64, end: i64, step: i64) -> i64 {
+    let span = if step > 0 {
+        end.saturating_sub(start)
+    } else {
+        start.saturating_sub(end)
+    };
+    if span <= 0 {
+        0
+    } else {
+        (span - 1) / step.abs() + 1
+    }
+}
+
+/// The integer value of a literal on the physical scale of the range.
+fn literal_to_i64(lv: &LiteralValue) -> Option<i64> {
+    match lv.to_any_value()? {
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(days) => Some(days as i64),
+        av if av.dtype().is_integer() => av.extract::<i64>(),
+        _ => None,
+    }
+}
+
+/// Narrow the half-open interval `[lo, hi)` of the values that can pass `predicate`.
+///
+/// Only comparisons of the range column with literals, combined with `&`, are understood;
+/// anything else doesn't narrow the interval.
+fn narrow(predicate: &Expr, name: &str, lo: &mut i64, hi: &mut i64) {
+    let Expr::BinaryExpr { left, op, right } = predicate else {
+        return;
+    };
+    if matches!(op, Operator::And | Operator::LogicalAnd) {
+        narrow(left, name, lo, hi);
+        narrow(right, name, lo, hi);
+        return;
+    }
+    let (op, value) = match (left.as_ref(), right.as_ref()) {
+        (Expr::Column(column), Expr::Literal(lv)) if column.as_ref() == name => (*op, lv),
+        (Expr::Literal(lv), Expr::Column(column)) if column.as_ref() == name => {
+            let op = match op {
+                Operator::Lt => Operator::Gt,
+                Operator::LtEq => Operator::GtEq,
+                Operator::Gt => Operator::Lt,
+                Operator::GtEq => Operator::LtEq,
+                op => *op,
+            };
+            (op, lv)
+        },
+        _ => return,
+    };
+    let Some(value) = literal_to_i64(value) else {
+        return;
+    };
+    match op {
+        Operator::Eq => {
+            *lo = (*lo).max(value);
+            *hi = (*hi).min(value.saturating_add(1));
+        },
+        Operator::Gt => *lo = (*lo).max(value.saturating_add(1)),
+        Operator::GtEq => *lo = (*lo).max(value),
+        Operator::Lt => *hi = (*hi).min(value),
+        Operator::LtEq => *hi = (*hi).min(value.saturating_add(1)),
+        _ => {},
+    }
+}
+
+impl RangeScan {
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::from_iter([Field::new(
+            &self.name,
+            self.dtype.clone(),
+        )]))
+    }
+
+    /// The bounds of the range after the pushed down slice and predicate are applied.
+    fn bounds(&self, args: &AnonymousScanArgs) -> (i64, i64) {
+        let (mut start, mut end) = (self.start, self.end);
+        if let (Some(predicate), true) = (&args.predicate, self.step > 0) {
+            let (mut lo, mut hi) = (i64::MIN, i64::MAX);
+            narrow(predicate, &self.name, &mut lo, &mut hi);
+            if lo > start {
+                let skip = (lo - start - 1) / self.step + 1;
+                start = start.saturating_add(skip.saturating_mul(self.step));
+            }
+            end = end.min(hi);
+        }
+        if let Some(n_rows) = args.n_rows {
+            let len = range_len(start, end, self.step).min(n_rows as i64);
+            end = start.saturating_add(len.saturating_mul(self.step));
+        }
+        (start, end)
+    }
+
+    fn generate(&self, start: i64, len: i64) -> PolarsResult<Series> {
+        let step = self.step;
+        Int64Chunked::from_iter_values(&self.name, (0..len).map(|i| start + i * step))
+            .into_series()
+            .cast(&self.dtype)
+    }
+}
+
+impl AnonymousScan for RangeScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let (start, end) = self.bounds(&scan_opts);
+        let s = self.generate(start, range_len(start, end, self.step))?;
+        let df = DataFrame::new(vec![s])?;
+        // The bounds only account for simple comparisons, the full predicate decides.
+        match scan_opts.predicate {
+            Some(predicate) => df.lazy().filter(predicate).collect(),
+            None => Ok(df),
+        }
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+
+    fn batched(
+        &self,
+        scan_opts: AnonymousScanArgs,
+        chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        let (start, end) = self.bounds(&scan_opts);
+        Ok(Box::new(RangeBatches {
+            scan: RangeScan {
+                name: self.name.clone(),
+                start,
+                end,
+                step: self.step,
+                dtype: self.dtype.clone(),
+            },
+            remaining: range_len(start, end, self.step),
+            chunk_size: chunk_size.max(1) as i64,
+        }))
+    }
+}
+
+struct RangeBatches {
+    scan: RangeScan,
+    remaining: i64,
+    chunk_size: i64,
+}
+
+impl AnonymousScanBatches for RangeBatches {
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let mut batches = Vec::with_capacity(n);
+        while batches.len() < n && self.remaining > 0 {
+            let len = self.chunk_size.min(self.remaining);
+            let s = self.scan.generate(self.scan.start, len)?;
+            batches.push(DataFrame::new(vec![s])?);
+            self.scan.start += len * self.scan.step;
+            self.remaining -= len;
+        }
+        Ok(Some(batches))
+    }
+}
+
+fn range_frame(scan: RangeScan) -> PolarsResult<LazyFrame> {
+    polars_ensure!(scan.step != 0, InvalidOperation: "range step must not be zero");
+    let args = ScanArgsAnonymous {
+        schema: Some(scan.schema()),
+        name: "RANGE",
+        ..Default::default()
+    };
+    LazyFrame::anonymous_scan(Arc::new(scan), args)
+}
+
+impl LazyFrame {
+    /// A frame with a single `Int64` column `range` with the values from `start` up to, but
+    /// not including, `end`, spaced by `step`.
+    ///
+    /// The values are generated when the query runs. Slices and filters on the column are
+    /// pushed into the source, so only the values that are needed are generated, and the
+    /// values are generated batch by batch in the streaming engine.
+    pub fn range(start: i64, end: i64, step: i64) -> PolarsResult<Self> {
+        range_frame(RangeScan {
+            name: "range".into(),
+            start,
+            end,
+            step,
+            dtype: DataType::Int64,
+        })
+    }
+
+    /// A frame with a single `Date` column `date` with the dates from `start` up to, but not
+    /// including, `end`, spaced by `step_days` days.
+    ///
+    /// This is the date counterpart of [`LazyFrame::range`], e.g. to build a calendar.
+    #[cfg(feature = "dtype-date")]
+    pub fn date_range(start: NaiveDate, end: NaiveDate, step_days: i64) -> PolarsResult<Self> {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        range_frame(RangeScan {
+            name: "date".into(),
+            start: (start - epoch).num_days(),
+            end: (end - epoch).num_days(),
+            step: step_days,
+            dtype: DataType::Date,
+        })
+    }
+}
@@ -0,0 +1,249 @@
+use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// A micro-batch pulled from an [`UnboundedSource`].
+pub struct MicroBatch {
+    pub df: DataFrame,
+    /// The event time up to which the source has delivered all records, if the source tracks
+    /// event time. Records with an older event time that arrive later are late.
+    pub watermark: Option<i64>,
+}
+
+/// The outcome of polling an [`UnboundedSource`].
+pub enum SourcePoll {
+    /// A micro-batch with at most the requested number of rows.
+    Ready(MicroBatch),
+    /// No records are available at the moment.
+    Pending,
+    /// The source is closed and will not produce records anymore.
+    Finished,
+}
+
+/// A pull-based source of records that may never end, such as the consumer of a message
+/// queue.
+///
+/// The streaming engine pulls micro-batches from the source with
+/// [`UnboundedSource::poll_next`]. They are acknowledged with [`UnboundedSource::commit`] once
+/// the results of the query are written, see [`UnboundedCommit`].
+pub trait UnboundedSource: Send + Sync {
+    /// The schema of the micro-batches.
+    fn schema(&self) -> SchemaRef;
+
+    /// Pull the next micro-batch of at most `max_rows` rows. This should not block when no
+    /// records are available, but return [`SourcePoll::Pending`].
+    fn poll_next(&mut self, max_rows: usize) -> PolarsResult<SourcePoll>;
+
+    /// Acknowledge that all micro-batches pulled so far are processed and their results are
+    /// written, e.g. to commit the offsets of a consumer.
+    ///
+    /// Defaults to doing nothing.
+    fn commit(&mut self) -> PolarsResult<()> {
+        Ok(())
+    }
+}
+
+/// Options of [`LazyFrame::scan_unbounded`].
+#[derive(Clone, Debug)]
+pub struct UnboundedScanOptions {
+    /// The maximum number of micro-batches that are pulled before they are processed. This
+    /// bounds the memory of the records that are buffered by the scan.
+    pub max_buffered_batches: usize,
+    /// The time to wait before polling a source again when it had no records.
+    pub poll_interval: Duration,
+    /// Finish the scan when the source had no records for this long. If `None`, the scan runs
+    /// until the source finishes.
+    pub idle_timeout: Option<Duration>,
+    /// Add the watermark of every micro-batch as an `Int64` column of this name.
+    pub watermark_column: Option<String>,
+}
+
+impl Default for UnboundedScanOptions {
+    fn default() -> Self {
+        Self {
+            max_buffered_batches: 16,
+            poll_interval: Duration::from_millis(100),
+            idle_timeout: None,
+            watermark_column: None,
+        }
+    }
+}
+
+type SharedSource = Arc<Mutex<Box<dyn UnboundedSource>>>;
+
+/// Commits the micro-batches that an unbounded scan pulled from its source.
+///
+/// The micro-batches are only committed once the results of the query are durably written,
+/// e.g. once `sink_parquet` returned, so that the records of a query that fails are pulled
+/// again by the next one.
+#[derive(Clone)]
+pub struct UnboundedCommit {
+    source: SharedSource,
+}
+
+impl UnboundedCommit {
+    /// Acknowledge all micro-batches that were pulled from the source so far.
+    pub fn commit(&self) -> PolarsResult<()> {
+        self.source.lock().unwrap().commit()
+    }
+}
+
+struct UnboundedScan {
+    source: SharedSource,
+    scanned: AtomicBool,
+    source_schema: SchemaRef,
+    options: UnboundedScanOptions,
+}
+
+impl UnboundedScan {
+    fn output_schema(&self) -> SchemaRef {
+        match &self.options.watermark_column {
+            Some(name) => {
+                let mut schema = self.source_schema.as_ref().clone();
+                schema.with_column(name.as_str().into(), DataType::Int64);
+                Arc::new(schema)
+            },
+            None => self.source_schema.clone(),
+        }
+    }
+}
+
+impl AnonymousScan for UnboundedScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        polars_bail!(InvalidOperation: "an unbounded scan can only be run by the streaming engine")
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.output_schema())
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+
+    fn batched(
+        &self,
+        _scan_opts: AnonymousScanArgs,
+        chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        polars_ensure!(
+            !self.scanned.swap(true, Ordering::Relaxed),
+            InvalidOperation: "an unbounded source can only be scanned once"
+        );
+        Ok(Box::new(UnboundedBatches {
+            source: self.source.clone(),
+            source_schema: self.source_schema.clone(),
+            options: self.options.clone(),
+            chunk_size: chunk_size.max(1),
+            finished: false,
+        }))
+    }
+}
+
+struct UnboundedBatches {
+    source: SharedSource,
+    source_schema: SchemaRef,
+    options: UnboundedScanOptions,
+    chunk_size: usize,
+    finished: bool,
+}
+
+impl UnboundedBatches {
+    fn finish_batch(&self, batch: MicroBatch) -> PolarsResult<DataFrame> {
+        let mut df = batch.df;
+        polars_ensure!(
+            df.schema() == *self.source_schema,
+            SchemaMismatch: "micro-batch does not match the schema of the unbounded source"
+        );
+        if let Some(name) = &self.options.watermark_column {
+            let watermark = Series::new(name, &[batch.watermark]).new_from_index(0, df.height());
+            df.with_column(watermark)?;
+        }
+        Ok(df)
+    }
+}
+
+impl AnonymousScanBatches for UnboundedBatches {
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let n = n.clamp(1, self.options.max_buffered_batches.max(1));
+        let mut batches = Vec::with_capacity(n);
+        let mut idle_since = Instant::now();
+        while batches.len() < n {
+            let poll = self.source.lock().unwrap().poll_next(self.chunk_size)?;
+            match poll {
+                SourcePoll::Ready(batch) => {
+                    idle_since = Instant::now();
+                    if batch.df.height() > 0 {
+                        batches.push(self.finish_batch(batch)?);
+                    }
+                },
+                // Don't hold back the records that are ready.
+                SourcePoll::Pending if !batches.is_empty() => break,
+                SourcePoll::Pending => {
+                    if let Some(timeout) = self.options.idle_timeout {
+                        if idle_since.elapsed() >= timeout {
+                            self.finished = true;
+                            break;
+                        }
+                    }
+                    std::thread::sleep(self.options.poll_interval);
+                },
+                SourcePoll::Finished => {
+                    self.finished = true;
+                    break;
+                },
+            }
+        }
+
+        if batches.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(batches))
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Scan an [`UnboundedSource`], such as the consumer of a message queue.
+    ///
+    /// The scan can only be run by the streaming engine, which pulls micro-batches from the
+    /// source as it processes them. Combined with a sink, e.g. `sink_parquet`, this runs a
+    /// continuous pipeline until the source finishes or is idle for longer than
+    /// [`UnboundedScanOptions::idle_timeout`].
+    ///
+    /// The pulled micro-batches are committed with the returned [`UnboundedCommit`] once the
+    /// results of the query are written.
+    pub fn scan_unbounded(
+        source: Box<dyn UnboundedSource>,
+        options: UnboundedScanOptions,
+    ) -> PolarsResult<(Self, UnboundedCommit)> {
+        let source_schema = source.schema();
+        let source = Arc::new(Mutex::new(source));
+        let scan = UnboundedScan {
+            source: source.clone(),
+            scanned: AtomicBool::new(false),
+            source_schema,
+            options,
+        };
+        let args = ScanArgsAnonymous {
+            schema: Some(scan.output_schema()),
+            name: "UNBOUNDED",
+            ..Default::default()
+        };
+        let lf = LazyFrame::anonymous_scan(Arc::new(scan), args)?.with_streaming(true);
+        Ok((lf, UnboundedCommit { source }))
+    }
+}
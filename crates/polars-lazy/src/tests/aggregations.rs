@@ -571,3 +571,264 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_gather_in_groups_out_of_bounds() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "a" => [1, 2, 3, 4, 5],
+        "i" => [0, -1, 5, 1, 7],
+    ]?;
+    // The indices are relative to every group.
+    let gather = |out_of_bounds: OutOfBoundsPolicy| -> PolarsResult<Vec<Option<i32>>> {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_stable([col("g")])
+            .agg([col("a").gather_with_policy(col("i"), out_of_bounds)])
+            .collect()?;
+        Ok(Vec::from(out.column("a")?.explode()?.i32()?))
+    };
+
+    assert_eq!(
+        gather(OutOfBoundsPolicy::Null)?,
+        &[Some(1), Some(3), None, Some(5), None]
+    );
+    assert_eq!(
+        gather(OutOfBoundsPolicy::Clamp)?,
+        &[Some(1), Some(3), Some(3), Some(5), Some(5)]
+    );
+    assert!(gather(OutOfBoundsPolicy::Error).is_err());
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("a").gather_every(2, 1).alias("every")])
+        .collect()?;
+    let every = out.column("every")?.explode()?;
+    assert_eq!(Vec::from(every.i32()?), &[Some(2), Some(5)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ols")]
+fn test_least_squares_in_groups() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2, 2],
+        "x" => [1.0, 2.0, 3.0, 1.0, 2.0, 3.0],
+        "y" => [3.0, 5.0, 7.0, 1.0, 0.0, -1.0],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([least_squares(col("y"), vec![col("x")], true)])
+        .collect()?;
+    let fit = out.column("y")?.struct_()?;
+    let round = |s: Series| -> PolarsResult<Vec<Option<f64>>> {
+        Ok(s.f64()?.into_iter().map(|v| v.map(|v| v.round())).collect())
+    };
+    assert_eq!(round(fit.field_by_name("x")?)?, &[Some(2.0), Some(-1.0)]);
+    assert_eq!(
+        round(fit.field_by_name("intercept")?)?,
+        &[Some(1.0), Some(2.0)]
+    );
+
+    let out = df
+        .lazy()
+        .select([rolling_ols(col("y"), vec![col("x")], 3, 3, true).over([col("g")])])
+        .collect()?;
+    let fit = out.column("y")?.struct_()?;
+    assert_eq!(
+        round(fit.field_by_name("x")?)?,
+        &[None, None, Some(2.0), None, None, Some(-1.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "time_weighted")]
+fn test_time_weighted_aggregations() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "t" => [0i64, 1, 3, 0, 10],
+        "v" => [Some(0.0), Some(2.0), Some(2.0), Some(1.0), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            time_weighted_mean(col("v"), col("t")).alias("mean"),
+            trapezoidal_integral(col("v"), col("t")).alias("integral"),
+        ])
+        .collect()?;
+    let mean = out.column("mean")?.f64()?;
+    assert!((mean.get(0).unwrap() - 5.0 / 3.0).abs() < 1e-12);
+    assert_eq!(mean.get(1), Some(1.0));
+    assert_eq!(
+        Vec::from(out.column("integral")?.f64()?),
+        &[Some(5.0), Some(0.0)]
+    );
+
+    // Temporal times are measured in seconds.
+    #[cfg(feature = "dtype-datetime")]
+    {
+        let out = df
+            .lazy()
+            .with_column(
+                (col("t") * lit(1000)).cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+            )
+            .group_by_stable([col("g")])
+            .agg([trapezoidal_integral(col("v"), col("t"))])
+            .collect()?;
+        assert_eq!(Vec::from(out.column("v")?.f64()?), &[Some(5.0), Some(0.0)]);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "outliers")]
+fn test_winsorize_and_outliers() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 1, 1, 2, 2, 2],
+        "v" => [Some(1), Some(2), Some(3), Some(4), Some(100), Some(5), None, Some(6)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("v").winsorize(0.0, 0.5).alias("winsorized"),
+            col("v").is_outlier(OutlierMethod::Iqr, None).alias("iqr"),
+        ])
+        .collect()?;
+    // The median of [1, 2, 3, 4, 100, 5, 6] is 4.
+    assert_eq!(
+        Vec::from(out.column("winsorized")?.f64()?),
+        &[
+            Some(1.0),
+            Some(2.0),
+            Some(3.0),
+            Some(4.0),
+            Some(4.0),
+            Some(4.0),
+            None,
+            Some(4.0)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("iqr")?.bool()?),
+        &[
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            Some(false)
+        ]
+    );
+
+    // The statistics are computed per group.
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("v").winsorize(0.25, 0.75).alias("winsorized"),
+            col("v").is_outlier(OutlierMethod::Mad, None).alias("mad"),
+            col("v")
+                .is_outlier(OutlierMethod::ZScore, Some(1.0))
+                .alias("zscore"),
+        ])
+        .explode([col("winsorized"), col("mad"), col("zscore")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("winsorized")?.f64()?),
+        &[
+            Some(2.0),
+            Some(2.0),
+            Some(3.0),
+            Some(4.0),
+            Some(4.0),
+            Some(5.25),
+            None,
+            Some(5.75)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("mad")?.bool()?),
+        &[
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            Some(false)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("zscore")?.bool()?),
+        &[
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            Some(false)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_multi_output_aggregations() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "a" => [Some(1), Some(5), Some(3), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").min_max(),
+            col("a").mean_std(1).alias("moments"),
+            col("a")
+                .quantile_bounds(0.0, 1.0, QuantileInterpolOptions::Linear)
+                .alias("bounds"),
+        ])
+        .unnest(["a", "moments", "bounds"])
+        .collect()?;
+    let expected = df![
+        "min" => [1],
+        "max" => [5],
+        "mean" => [3.0],
+        "std" => [2.0],
+        "lower" => [1.0],
+        "upper" => [5.0],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("a").min_max()])
+        .unnest(["a"])
+        .collect()?;
+    let expected = df![
+        "g" => [1, 2],
+        "min" => [1, 3],
+        "max" => [5, 3],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
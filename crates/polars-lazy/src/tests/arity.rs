@@ -75,3 +75,60 @@ fn test_lazy_ternary() {
         .unwrap();
     assert_eq!(43, df.column("new").unwrap().sum::<i32>().unwrap());
 }
+
+#[test]
+fn test_case_when() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [Some(1), Some(5), None, Some(9), Some(3)],
+        "b" => [10.0, 20.0, 30.0, 40.0, 50.0],
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            case()
+                .when(col("a").lt(lit(3)))
+                .then(lit(1))
+                .when(col("a").lt(lit(6)))
+                .then(col("b"))
+                .otherwise(lit(NULL))
+                .alias("case"),
+            when(col("a").lt(lit(3)))
+                .then(lit(1))
+                .when(col("a").lt(lit(6)))
+                .then(col("b"))
+                .otherwise(lit(NULL))
+                .alias("when"),
+        ])
+        .collect()?;
+    let expected = Series::new("case", [Some(1.0), Some(20.0), None, None, Some(50.0)]);
+    assert!(out.column("case")?.equals_missing(&expected));
+    assert!(out.column("when")?.equals_missing(&expected));
+
+    // The first true condition wins.
+    let out = df
+        .lazy()
+        .group_by_stable([col("a").is_null()])
+        .agg([case()
+            .when(col("b").gt(lit(15.0)))
+            .then(lit("gt_15"))
+            .when(col("b").gt(lit(5.0)))
+            .then(lit("gt_5"))
+            .otherwise(lit("other"))
+            .alias("case")])
+        .collect()?;
+    let case = out.column("case")?.explode()?;
+    let case = case.str()?;
+    assert_eq!(
+        Vec::from(case),
+        &[
+            Some("gt_5"),
+            Some("gt_15"),
+            Some("gt_15"),
+            Some("gt_15"),
+            Some("gt_15")
+        ]
+    );
+    Ok(())
+}
@@ -34,6 +34,41 @@ fn test_cse_self_joins() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_cse_self_joins_resolved_paths() -> PolarsResult<()> {
+    // The same file, spelled differently.
+    let path = format!("./{FOODS_IPC}");
+    let lf = scan_foods_ipc();
+    let other = LazyFrame::scan_ipc(&path, Default::default())?;
+
+    let q = lf
+        .clone()
+        .left_join(other, col("fats_g"), col("fats_g"))
+        .with_comm_subplan_elim(true);
+    cached_before_root(q.clone());
+    let expected = lf
+        .clone()
+        .left_join(lf, col("fats_g"), col("fats_g"))
+        .with_comm_subplan_elim(false)
+        .collect()?;
+    assert_eq!(q.collect()?.shape(), expected.shape());
+
+    // Scans with different options are not shared.
+    let other = LazyFrame::scan_ipc(
+        &path,
+        ScanArgsIpc {
+            n_rows: Some(3),
+            ..Default::default()
+        },
+    )?;
+    let q = scan_foods_ipc()
+        .left_join(other, col("fats_g"), col("fats_g"))
+        .with_comm_subplan_elim(true);
+    assert_eq!(count_caches(q), 0);
+
+    Ok(())
+}
+
 #[test]
 fn test_cse_unions() -> PolarsResult<()> {
     let lf = scan_foods_ipc();
@@ -416,6 +416,8 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             rechunk: false,
             row_index: None,
             memory_map: true,
+            low_memory: false,
+            max_decoders: None,
             cloud_options: None,
         },
     )?
@@ -684,3 +686,1270 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "parquet", not(target_os = "windows")))]
+fn test_hive_filtered_count_star() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_hive_filtered_count_star");
+    for (part, n) in [(1, 3), (2, 4), (3, 5)] {
+        let dir = root.join(format!("part={part}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut df = df!["a" => (0..n).collect::<Vec<i32>>()]?;
+        let f = std::fs::File::create(dir.join("data.parquet")).unwrap();
+        ParquetWriter::new(f).finish(&mut df)?;
+    }
+
+    let glob = root.join("**/*.parquet");
+    let q = LazyFrame::scan_parquet(glob.to_str().unwrap(), Default::default())?
+        .filter(col("part").gt_eq(lit(2i64)))
+        .select([len()]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(matches!(
+        lp_arena.get(lp),
+        IR::MapFunction {
+            function: FunctionNode::Count { .. },
+            ..
+        }
+    ));
+
+    let out = q.collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(9));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_statistics_filtered_count_star() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_statistics_filtered_count_star.parquet");
+    // Row groups of [0, 3), [3, 6), [6, 9) and [9, 10).
+    let mut df = df![
+        "a" => (0..10).collect::<Vec<i32>>(),
+        "b" => (0..10).map(|i| (i != 1).then_some(i as f64)).collect::<Vec<_>>(),
+    ]?;
+    let f = std::fs::File::create(&path).unwrap();
+    ParquetWriter::new(f)
+        .with_row_group_size(Some(3))
+        .finish(&mut df)?;
+
+    let count = |predicate: Expr| -> PolarsResult<(bool, IdxSize)> {
+        let q = LazyFrame::scan_parquet(&path, Default::default())?
+            .filter(predicate)
+            .select([len()]);
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        let from_statistics = matches!(lp_arena.get(lp), IR::DataFrameScan { .. });
+        let out = q.collect()?;
+        let AnyValue::IdxSize(n) = out.column("len")?.get(0)? else {
+            unreachable!()
+        };
+        Ok((from_statistics, n))
+    };
+
+    // The row groups are either fully in or fully out of the range.
+    assert_eq!(
+        count(col("a").gt_eq(lit(3)).and(col("a").lt(lit(9))))?,
+        (true, 6)
+    );
+    assert_eq!(
+        count(col("a").lt(lit(3)).or(col("a").gt(lit(8))))?,
+        (true, 4)
+    );
+    // A row group is partly in the range, so its data has to be read.
+    assert_eq!(count(col("a").gt(lit(4)))?, (false, 5));
+    // The statistics of floats don't bound their NaNs.
+    assert_eq!(count(col("b").gt(lit(0.0)))?, (false, 8));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", not(target_os = "windows")))]
+fn test_hive_null_count_pruning() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_hive_null_count_pruning");
+    for (part, n) in [("1", 3), ("__HIVE_DEFAULT_PARTITION__", 4)] {
+        let dir = root.join(format!("part={part}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut df = df!["a" => (0..n).collect::<Vec<i32>>()]?;
+        let f = std::fs::File::create(dir.join("data.parquet")).unwrap();
+        ParquetWriter::new(f).finish(&mut df)?;
+    }
+
+    let glob = root.join("**/*.parquet");
+    for (predicate, height) in [(col("part").is_null(), 4), (col("part").is_not_null(), 3)] {
+        let q =
+            LazyFrame::scan_parquet(glob.to_str().unwrap(), Default::default())?.filter(predicate);
+
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        let IR::Scan { paths, .. } = lp_arena.get(lp) else {
+            panic!("expected a scan")
+        };
+        assert_eq!(paths.len(), 1);
+
+        assert_eq!(q.collect()?.height(), height);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "sink_manifest"))]
+fn test_sink_with_manifest() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_sink_with_manifest.parquet");
+    let manifest = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .sink_with_manifest(
+            path.clone(),
+            FileType::Parquet(Default::default()),
+            &["A", "fruits"],
+        )?;
+
+    assert_eq!(
+        manifest.get_column_names(),
+        &[
+            "path",
+            "num_rows",
+            "size_bytes",
+            "checksum",
+            "A_min",
+            "A_max",
+            "fruits_min",
+            "fruits_max"
+        ]
+    );
+    let row = manifest.get_row(0)?.0;
+    assert_eq!(row[1], AnyValue::UInt64(4));
+    assert_eq!(row[2], AnyValue::UInt64(std::fs::metadata(&path)?.len()));
+    assert_eq!(row[4], AnyValue::Int32(2));
+    assert_eq!(row[5], AnyValue::Int32(5));
+    assert_eq!(row[6], AnyValue::String("apple"));
+    assert_eq!(row[7], AnyValue::String("banana"));
+
+    let checksum = manifest.column("checksum")?.str()?.get(0).unwrap();
+    assert_eq!(checksum.len(), 16);
+    let out = LazyFrame::scan_parquet(&path, Default::default())?.collect()?;
+    assert_eq!(out.height(), 4);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming"))]
+fn test_sink_parquet_partitioned() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_sink_parquet_partitioned");
+    let _ = std::fs::remove_dir_all(&root);
+    let df = df![
+        "year" => [2023, 2024, 2023, 2024, 2024],
+        "name" => ["a", "b", "c", "d", "e"],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+    let options = PartitionedWriteOptions::new(["year"])
+        .with_max_rows_per_file(Some(2))
+        .with_metadata_file(true);
+    df.clone().lazy().sink_parquet_partitioned(
+        root.clone(),
+        Default::default(),
+        options.clone(),
+    )?;
+
+    let read = |path: PathBuf| {
+        let file = polars_utils::open_file(path)?;
+        ParquetReader::new(file).finish()
+    };
+    let out = read(root.join("year=2023").join("part-0.parquet"))?;
+    assert!(out.equals(&df![
+        "name" => ["a", "c"],
+        "value" => [1, 3],
+    ]?));
+    let out = read(root.join("year=2024").join("part-0.parquet"))?;
+    assert_eq!(out.height(), 2);
+    let out = read(root.join("year=2024").join("part-1.parquet"))?;
+    assert_eq!(out.height(), 1);
+
+    let file = polars_utils::open_file(root.join("_metadata"))?;
+    let mut reader = ParquetReader::new(file);
+    let metadata = reader.get_metadata()?;
+    assert_eq!(metadata.num_rows, 5);
+    assert_eq!(metadata.row_groups.len(), 3);
+
+    // The directory isn't empty anymore.
+    let result = df.clone().lazy().sink_parquet_partitioned(
+        root.clone(),
+        Default::default(),
+        options.clone(),
+    );
+    assert!(result.is_err());
+
+    // Only the partitions that are written are replaced.
+    let options = PartitionedWriteOptions::new(["year"])
+        .with_existing_data_behavior(ExistingDataBehavior::DeleteMatching);
+    df.lazy()
+        .filter(col("year").eq(lit(2024)))
+        .sink_parquet_partitioned(root.clone(), Default::default(), options)?;
+    assert_eq!(
+        read(root.join("year=2024").join("part-0.parquet"))?.height(),
+        3
+    );
+    assert!(!root.join("year=2024").join("part-1.parquet").exists());
+    assert!(root.join("year=2023").join("part-0.parquet").exists());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming"))]
+fn test_sink_parquet_partitioned_buffers_and_open_files() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_sink_parquet_partitioned_open_files");
+    let df = df![
+        "key" => (0..3000).map(|i| i % 3).collect::<Vec<i32>>(),
+        "value" => (0..3000).collect::<Vec<i32>>(),
+    ]?;
+    // The files of a partition in the order they are written.
+    let read_partition = |key: i32| -> PolarsResult<(DataFrame, Vec<usize>)> {
+        let dir = root.join(format!("key={key}"));
+        let mut out = DataFrame::empty();
+        let mut row_groups = vec![];
+        for i in 0..std::fs::read_dir(&dir)?.count() {
+            let file = polars_utils::open_file(dir.join(format!("part-{i}.parquet")))?;
+            let mut reader = ParquetReader::new(file);
+            row_groups.push(reader.get_metadata()?.row_groups.len());
+            out.vstack_mut(&reader.finish()?)?;
+        }
+        Ok((out, row_groups))
+    };
+    let expected = |key: i32| {
+        df.clone()
+            .lazy()
+            .filter(col("key").eq(lit(key)))
+            .select([col("value")])
+            .collect()
+    };
+
+    // The small parts of the chunks are buffered into a single row group per partition.
+    let _ = std::fs::remove_dir_all(&root);
+    df.clone().lazy().sink_parquet_partitioned(
+        root.clone(),
+        Default::default(),
+        PartitionedWriteOptions::new(["key"]),
+    )?;
+    for key in 0..3 {
+        let (out, row_groups) = read_partition(key)?;
+        assert!(out.equals(&expected(key)?));
+        assert_eq!(row_groups, [1]);
+    }
+
+    // With a single open file, the files are finished when other partitions are written,
+    // and the next rows of their partitions are written to new files.
+    let _ = std::fs::remove_dir_all(&root);
+    let options = ParquetWriteOptions {
+        row_group_size: Some(100),
+        ..Default::default()
+    };
+    df.clone().lazy().sink_parquet_partitioned(
+        root.clone(),
+        options.into(),
+        PartitionedWriteOptions::new(["key"]).with_max_open_files(1),
+    )?;
+    for key in 0..3 {
+        let (out, _) = read_partition(key)?;
+        assert!(out.equals(&expected(key)?));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming"))]
+fn test_sink_parquet_partitioned_statistics_file() -> PolarsResult<()> {
+    use polars_io::parquet::{DatasetStatistics, STATISTICS_FILE_NAME};
+
+    let root = std::env::temp_dir().join("polars_sink_parquet_partitioned_statistics_file");
+    let _ = std::fs::remove_dir_all(&root);
+    let df = df![
+        "year" => [2023, 2024, 2023, 2024, 2024],
+        "name" => ["a", "b", "c", "d", "e"],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+    let options = PartitionedWriteOptions::new(["year"])
+        .with_max_rows_per_file(Some(2))
+        .with_statistics_file(true);
+    df.lazy()
+        .sink_parquet_partitioned(root.clone(), Default::default(), options)?;
+
+    let file = polars_utils::open_file(root.join(STATISTICS_FILE_NAME))?;
+    assert_eq!(DatasetStatistics::read(file)?.len(), 3);
+
+    // The file with the value 5 is skipped without reading it, so that its content doesn't
+    // matter as long as its size and its modification time are the same.
+    let skipped = root.join("year=2024").join("part-1.parquet");
+    let metadata = std::fs::metadata(&skipped)?;
+    let overwrite = |modified| -> PolarsResult<()> {
+        let file = std::fs::File::create(&skipped)?;
+        file.set_len(metadata.len())?;
+        file.set_modified(modified)?;
+        Ok(())
+    };
+    overwrite(metadata.modified()?)?;
+
+    let scan = |pattern: PathBuf| {
+        LazyFrame::scan_parquet(pattern, Default::default())?
+            .filter(col("value").lt(lit(4)))
+            .select([col("name"), col("value")])
+            .sort(["value"], Default::default())
+            .collect()
+    };
+    let out = scan(root.join("**").join("*.parquet"))?;
+    assert!(out.equals(&df![
+        "name" => ["a", "b", "c"],
+        "value" => [1, 2, 3],
+    ]?));
+    // The statistics file is found in the root directory above the partition directories.
+    let out = scan(root.join("year=2024").join("*.parquet"))?;
+    assert!(out.equals(&df![
+        "name" => ["b"],
+        "value" => [2],
+    ]?));
+
+    // A file that was modified since the statistics were written is read.
+    overwrite(metadata.modified()? + std::time::Duration::from_secs(1))?;
+    assert!(scan(root.join("**").join("*.parquet")).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming"))]
+fn test_sink_parquet_partitioned_existing_data() -> PolarsResult<()> {
+    use polars_io::parquet::{DatasetStatistics, STATISTICS_FILE_NAME};
+
+    let root = std::env::temp_dir().join("polars_sink_parquet_partitioned_existing_data");
+    let _ = std::fs::remove_dir_all(&root);
+    let df = df![
+        "year" => [2023, 2024, 2024],
+        "value" => [1, 2, 3],
+    ]?;
+    let sink = |lf: LazyFrame, behavior, commit_strategy| {
+        let options = ParquetWriteOptions {
+            commit_strategy,
+            ..Default::default()
+        };
+        let partition_options =
+            PartitionedWriteOptions::new(["year"]).with_existing_data_behavior(behavior);
+        lf.sink_parquet_partitioned(root.clone(), options.into(), partition_options)
+    };
+    let count_files = |dir: &str| std::fs::read_dir(root.join(dir)).unwrap().count();
+
+    sink(
+        df.clone().lazy(),
+        ExistingDataBehavior::Error,
+        CommitStrategy::Atomic,
+    )?;
+    assert_eq!(count_files("year=2024"), 1);
+    // Only the partition directories are left after committing.
+    assert_eq!(std::fs::read_dir(&root)?.count(), 2);
+
+    // Appending adds files next to the existing ones.
+    sink(
+        df.clone().lazy(),
+        ExistingDataBehavior::Append,
+        CommitStrategy::Direct,
+    )?;
+    sink(
+        df.clone().lazy(),
+        ExistingDataBehavior::Append,
+        CommitStrategy::Atomic,
+    )?;
+    assert_eq!(count_files("year=2023"), 3);
+    assert!(root.join("year=2024").join("part-2.parquet").exists());
+
+    // A query that fails leaves the existing partitions as they are.
+    let result = sink(
+        df.clone().lazy().with_column(
+            col("value")
+                .cast(DataType::String)
+                .strict_cast(DataType::Boolean),
+        ),
+        ExistingDataBehavior::DeleteMatching,
+        CommitStrategy::Atomic,
+    );
+    assert!(result.is_err());
+    assert_eq!(count_files("year=2024"), 3);
+    assert_eq!(std::fs::read_dir(&root)?.count(), 2);
+
+    // The partitions that are written are replaced once the query succeeds.
+    sink(
+        df.lazy().filter(col("year").eq(lit(2024))),
+        ExistingDataBehavior::DeleteMatching,
+        CommitStrategy::Atomic,
+    )?;
+    assert_eq!(count_files("year=2024"), 1);
+    assert_eq!(count_files("year=2023"), 3);
+
+    // The statistics of the partitions that aren't replaced are kept.
+    let sink_with_statistics = |lf: LazyFrame, metadata: bool| {
+        let partition_options = PartitionedWriteOptions::new(["year"])
+            .with_existing_data_behavior(ExistingDataBehavior::DeleteMatching)
+            .with_statistics_file(true)
+            .with_metadata_file(metadata);
+        lf.sink_parquet_partitioned(root.clone(), Default::default(), partition_options)
+    };
+    sink_with_statistics(df.clone().lazy(), false)?;
+    sink_with_statistics(df.clone().lazy().filter(col("year").eq(lit(2024))), false)?;
+    let statistics = polars_utils::open_file(root.join(STATISTICS_FILE_NAME))?;
+    assert_eq!(DatasetStatistics::read(statistics)?.len(), 2);
+
+    // A `_metadata` file would only have the metadata of the replaced partitions.
+    assert!(sink_with_statistics(df.lazy(), true).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "delta", feature = "streaming"))]
+fn test_sink_delta() -> PolarsResult<()> {
+    use polars_io::delta::DeltaTransaction;
+
+    let root = std::env::temp_dir().join("polars_sink_delta");
+    let _ = std::fs::remove_dir_all(&root);
+    let df = df![
+        "year" => [2023, 2024, 2024],
+        "value" => [1, 2, 3],
+    ]?;
+    let read_commit = |version: usize| {
+        let path = root.join("_delta_log").join(format!("{version:020}.json"));
+        std::fs::read_to_string(path).unwrap()
+    };
+    let options = DeltaWriteOptions::default().with_partition_by(["year"]);
+
+    df.clone()
+        .lazy()
+        .sink_delta(root.clone(), Default::default(), options.clone())?;
+    let commit = read_commit(0);
+    assert!(commit.contains(r#""partitionColumns":["year"]"#));
+    assert_eq!(commit.matches(r#"{"add":"#).count(), 2);
+    assert!(commit.contains(r#"\"numRecords\":2"#));
+    // The staging directory is removed after the commit.
+    assert_eq!(std::fs::read_dir(&root)?.count(), 3);
+
+    df.clone()
+        .lazy()
+        .sink_delta(root.clone(), Default::default(), Default::default())?;
+    assert_eq!(read_commit(1).matches(r#"{"add":"#).count(), 2);
+    assert_eq!(std::fs::read_dir(root.join("year=2024"))?.count(), 2);
+
+    // Only the files of the partitions that are written are removed.
+    let options = options.with_mode(DeltaWriteMode::OverwritePartitions);
+    df.clone()
+        .lazy()
+        .filter(col("year").eq(lit(2024)))
+        .sink_delta(root.clone(), Default::default(), options)?;
+    let commit = read_commit(2);
+    assert_eq!(commit.matches(r#"{"add":"#).count(), 1);
+    assert_eq!(commit.matches(r#"{"remove":"#).count(), 2);
+
+    // The schema of the table is enforced.
+    let result = df
+        .lazy()
+        .with_column(col("value").cast(DataType::Float64))
+        .sink_delta(root.clone(), Default::default(), Default::default());
+    assert!(result.is_err());
+    assert!(!root
+        .join("_delta_log")
+        .join(format!("{:020}.json", 3))
+        .exists());
+
+    // A transaction whose files are staged before another writer commits.
+    let stage = |options: &DeltaWriteOptions| -> PolarsResult<DeltaTransaction> {
+        let transaction = DeltaTransaction::try_new(&root, &df.schema(), options)?;
+        let dir = transaction.staging_dir().join("year=2024");
+        std::fs::create_dir_all(&dir)?;
+        let mut part = df.drop("year")?;
+        ParquetWriter::new(std::fs::File::create(dir.join("part.parquet"))?).finish(&mut part)?;
+        Ok(transaction)
+    };
+    let n_files = || std::fs::read_dir(root.join("year=2024")).unwrap().count();
+
+    // Concurrent appends are committed after each other.
+    let transaction = stage(&Default::default())?;
+    df.clone()
+        .lazy()
+        .sink_delta(root.clone(), Default::default(), Default::default())?;
+    assert_eq!(transaction.commit()?, 4);
+    assert_eq!(read_commit(4).matches(r#"{"add":"#).count(), 1);
+
+    // An overwrite of a partition that was appended to concurrently fails, and its files are
+    // removed.
+    let overwrite = DeltaWriteOptions::default().with_mode(DeltaWriteMode::OverwritePartitions);
+    let transaction = stage(&overwrite)?;
+    df.clone()
+        .lazy()
+        .sink_delta(root.clone(), Default::default(), Default::default())?;
+    let files = n_files();
+    assert!(transaction.commit().is_err());
+    assert_eq!(n_files(), files);
+    assert!(!root
+        .join("_delta_log")
+        .join(format!("{:020}.json", 6))
+        .exists());
+    Ok(())
+}
+
+#[cfg(feature = "iceberg")]
+fn avro_long(buf: &mut Vec<u8>, v: i64) {
+    let mut v = ((v << 1) ^ (v >> 63)) as u64;
+    while v >= 0x80 {
+        buf.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+#[cfg(feature = "iceberg")]
+fn avro_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    avro_long(buf, bytes.len() as i64);
+    buf.extend_from_slice(bytes);
+}
+
+/// Write an Avro object container file with a single block of encoded `records`.
+#[cfg(feature = "iceberg")]
+fn write_avro(path: &std::path::Path, schema: &str, records: &[Vec<u8>]) -> PolarsResult<()> {
+    let sync = [7u8; 16];
+    let mut buf = b"Obj\x01".to_vec();
+    avro_long(&mut buf, 1);
+    avro_bytes(&mut buf, b"avro.schema");
+    avro_bytes(&mut buf, schema.as_bytes());
+    avro_long(&mut buf, 0);
+    buf.extend_from_slice(&sync);
+    avro_long(&mut buf, records.len() as i64);
+    avro_bytes(&mut buf, &records.concat());
+    buf.extend_from_slice(&sync);
+    Ok(std::fs::write(path, buf)?)
+}
+
+#[test]
+#[cfg(feature = "iceberg")]
+fn test_scan_iceberg() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_scan_iceberg");
+    let _ = std::fs::remove_dir_all(&root);
+    let (data_dir, metadata_dir) = (root.join("data"), root.join("metadata"));
+    std::fs::create_dir_all(&data_dir)?;
+    std::fs::create_dir_all(&metadata_dir)?;
+    let write_parquet = |name: &str, mut df: DataFrame| -> PolarsResult<String> {
+        let path = data_dir.join(name);
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        Ok(format!("file:{}", path.display()))
+    };
+    let data_2023 = write_parquet(
+        "2023.parquet",
+        df!["id" => [1i64, 2, 3], "year" => [2023; 3]]?,
+    )?;
+    let data_2024 = write_parquet(
+        "2024.parquet",
+        df!["id" => [4i64, 5, 6], "year" => [2024; 3]]?,
+    )?;
+    let position_deletes = write_parquet(
+        "position_deletes.parquet",
+        df!["file_path" => [data_2023.as_str()], "pos" => [1i64]]?,
+    )?;
+    let equality_deletes = write_parquet("equality_deletes.parquet", df!["id" => [4i64]]?)?;
+
+    let manifest_schema = r#"{"type": "record", "name": "manifest_entry", "fields": [
+        {"name": "status", "type": "int"},
+        {"name": "sequence_number", "type": ["null", "long"]},
+        {"name": "data_file", "type": {"type": "record", "name": "r2", "fields": [
+            {"name": "content", "type": "int"},
+            {"name": "file_path", "type": "string"},
+            {"name": "file_format", "type": "string"},
+            {"name": "partition", "type": {"type": "record", "name": "r102", "fields": [
+                {"name": "year", "type": ["null", "int"]}
+            ]}},
+            {"name": "record_count", "type": "long"},
+            {"name": "lower_bounds", "type": ["null", {"type": "array", "items": {
+                "type": "record", "name": "k126_v127", "fields": [
+                    {"name": "key", "type": "int"}, {"name": "value", "type": "bytes"}
+                ]
+            }}]},
+            {"name": "upper_bounds", "type": ["null", {"type": "array", "items": "k126_v127"}]},
+            {"name": "equality_ids", "type": ["null", {"type": "array", "items": "int"}]}
+        ]}}
+    ]}"#;
+    // A live file with the bounds of the `id` column, or equality ids if it has any.
+    let entry = |content: i64, uri: &str, year: i64, count: i64, ids: [i64; 2], eq: bool| {
+        let mut buf = vec![];
+        avro_long(&mut buf, 1);
+        avro_long(&mut buf, 0);
+        avro_long(&mut buf, content);
+        avro_bytes(&mut buf, uri.as_bytes());
+        avro_bytes(&mut buf, b"PARQUET");
+        avro_long(&mut buf, 1);
+        avro_long(&mut buf, year);
+        avro_long(&mut buf, count);
+        for bound in ids {
+            avro_long(&mut buf, 1);
+            avro_long(&mut buf, 1);
+            avro_long(&mut buf, 1);
+            avro_bytes(&mut buf, &bound.to_le_bytes());
+            avro_long(&mut buf, 0);
+        }
+        if eq {
+            avro_long(&mut buf, 1);
+            avro_long(&mut buf, 1);
+            avro_long(&mut buf, 1);
+            avro_long(&mut buf, 0);
+        } else {
+            avro_long(&mut buf, 0);
+        }
+        buf
+    };
+    let data_manifest = metadata_dir.join("data.avro");
+    write_avro(
+        &data_manifest,
+        manifest_schema,
+        &[
+            entry(0, &data_2023, 2023, 3, [1, 3], false),
+            entry(0, &data_2024, 2024, 3, [4, 6], false),
+        ],
+    )?;
+    let delete_manifest = metadata_dir.join("deletes.avro");
+    write_avro(
+        &delete_manifest,
+        manifest_schema,
+        &[
+            entry(1, &position_deletes, 2023, 1, [0, 0], false),
+            entry(2, &equality_deletes, 2024, 1, [4, 4], true),
+        ],
+    )?;
+
+    let manifest_list_schema = r#"{"type": "record", "name": "manifest_file", "fields": [
+        {"name": "manifest_path", "type": "string"},
+        {"name": "content", "type": "int"},
+        {"name": "sequence_number", "type": "long"},
+        {"name": "partition_spec_id", "type": "int"},
+        {"name": "partitions", "type": {"type": "array", "items": {
+            "type": "record", "name": "field_summary", "fields": [
+                {"name": "contains_null", "type": "boolean"},
+                {"name": "lower_bound", "type": ["null", "bytes"]},
+                {"name": "upper_bound", "type": ["null", "bytes"]}
+            ]
+        }}}
+    ]}"#;
+    let manifest = |path: &std::path::Path, content: i64, sequence_number: i64| {
+        let mut buf = vec![];
+        avro_bytes(&mut buf, path.to_str().unwrap().as_bytes());
+        avro_long(&mut buf, content);
+        avro_long(&mut buf, sequence_number);
+        avro_long(&mut buf, 0);
+        avro_long(&mut buf, 1);
+        buf.push(0);
+        for year in [2023i32, 2024] {
+            avro_long(&mut buf, 1);
+            avro_bytes(&mut buf, &year.to_le_bytes());
+        }
+        avro_long(&mut buf, 0);
+        buf
+    };
+    let data_list = metadata_dir.join("snap-1.avro");
+    write_avro(
+        &data_list,
+        manifest_list_schema,
+        &[manifest(&data_manifest, 0, 1)],
+    )?;
+    let delete_list = metadata_dir.join("snap-2.avro");
+    write_avro(
+        &delete_list,
+        manifest_list_schema,
+        &[
+            manifest(&data_manifest, 0, 1),
+            manifest(&delete_manifest, 1, 2),
+        ],
+    )?;
+
+    // The `note` column was added after the files were written.
+    let metadata = format!(
+        r#"{{
+            "format-version": 2,
+            "current-schema-id": 0,
+            "schemas": [{{"type": "struct", "schema-id": 0, "fields": [
+                {{"id": 1, "name": "id", "required": false, "type": "long"}},
+                {{"id": 2, "name": "year", "required": false, "type": "int"}},
+                {{"id": 3, "name": "note", "required": false, "type": "string"}}
+            ]}}],
+            "partition-specs": [{{"spec-id": 0, "fields": [
+                {{"source-id": 2, "field-id": 1000, "name": "year", "transform": "identity"}}
+            ]}}],
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {{"snapshot-id": 1, "sequence-number": 1, "manifest-list": "{}"}},
+                {{"snapshot-id": 2, "sequence-number": 2, "manifest-list": "{}"}}
+            ]
+        }}"#,
+        data_list.display(),
+        delete_list.display()
+    );
+    std::fs::write(metadata_dir.join("v1.metadata.json"), metadata)?;
+    std::fs::write(metadata_dir.join("version-hint.text"), "1")?;
+
+    let ids = |df: DataFrame| -> PolarsResult<Vec<Option<i64>>> {
+        Ok(df.column("id")?.i64()?.into_iter().collect())
+    };
+    // The position delete removes the row 1 of the 2023 file and the equality delete the id 4.
+    let df = LazyFrame::scan_iceberg(&root, Default::default())?
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(ids(df.clone())?, [Some(1), Some(3), Some(5), Some(6)]);
+    assert_eq!(df.column("note")?.null_count(), 4);
+    assert_eq!(df.column("note")?.dtype(), &DataType::String);
+
+    let args = ScanArgsIceberg {
+        snapshot_id: Some(1),
+        ..Default::default()
+    };
+    let df = LazyFrame::scan_iceberg(&root, args)?.collect()?;
+    assert_eq!(df.height(), 6);
+
+    // A slice stops reading the files once it has enough rows, so the 2024 file isn't read.
+    let file_2024 = data_dir.join("2024.parquet");
+    let moved = data_dir.join("moved.parquet");
+    std::fs::rename(&file_2024, &moved)?;
+    let df = LazyFrame::scan_iceberg(&root, Default::default())?
+        .limit(2)
+        .collect()?;
+    assert_eq!(ids(df)?, [Some(1), Some(3)]);
+    std::fs::rename(&moved, &file_2024)?;
+
+    // The 2023 file is pruned by its bounds and partition value, so it isn't read.
+    std::fs::remove_file(data_dir.join("2023.parquet"))?;
+    let df = LazyFrame::scan_iceberg(&root, Default::default())?
+        .filter(col("id").gt_eq(lit(4i64)))
+        .select([col("id")])
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(ids(df)?, [Some(5), Some(6)]);
+    let df = LazyFrame::scan_iceberg(&root, Default::default())?
+        .filter(col("year").eq(lit(2024)))
+        .collect()?;
+    assert_eq!(df.height(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "csv", feature = "streaming"))]
+fn test_sink_split() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_sink_split");
+    let _ = std::fs::remove_dir_all(&dir);
+    let df = df![
+        "name" => ["a", "b", "c", "d", "e"],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+
+    let options = SplitWriteOptions::default().with_max_rows_per_file(Some(2));
+    df.clone()
+        .lazy()
+        .sink_parquet_split(dir.join("parquet"), Default::default(), options)?;
+    let read = |path: PathBuf| {
+        let file = polars_utils::open_file(path)?;
+        ParquetReader::new(file).finish()
+    };
+    let out = read(dir.join("parquet").join("part-0.parquet"))?;
+    assert!(out.equals(&df.head(Some(2))));
+    assert_eq!(
+        read(dir.join("parquet").join("part-2.parquet"))?.height(),
+        1
+    );
+    assert!(!dir.join("parquet").join("part-3.parquet").exists());
+
+    // The files of the earlier run that aren't written again are removed.
+    std::fs::write(dir.join("parquet").join("other.parquet"), b"")?;
+    let options = SplitWriteOptions::default().with_max_rows_per_file(Some(4));
+    df.clone()
+        .lazy()
+        .sink_parquet_split(dir.join("parquet"), Default::default(), options)?;
+    assert_eq!(
+        read(dir.join("parquet").join("part-1.parquet"))?.height(),
+        1
+    );
+    assert!(!dir.join("parquet").join("part-2.parquet").exists());
+    assert!(dir.join("parquet").join("other.parquet").exists());
+
+    let options = SplitWriteOptions::default()
+        .with_max_rows_per_file(Some(3))
+        .with_file_name_template("data_{i}.{ext}");
+    df.clone()
+        .lazy()
+        .sink_csv_split(dir.join("csv"), Default::default(), options)?;
+    let out = LazyCsvReader::new(dir.join("csv").join("data_1.csv"))
+        .finish()?
+        .collect()?;
+    assert!(out.column("name")?.equals(df.slice(3, 2).column("name")?));
+
+    // An empty result is written to a single file.
+    df.clone()
+        .lazy()
+        .filter(col("value").gt(lit(5)))
+        .sink_parquet_split(dir.join("empty"), Default::default(), Default::default())?;
+    let out = read(dir.join("empty").join("part-0.parquet"))?;
+    assert_eq!(out.schema(), df.schema());
+    assert_eq!(out.height(), 0);
+
+    let options = SplitWriteOptions::default().with_file_name_template("data.csv");
+    let result = df
+        .lazy()
+        .sink_csv_split(dir.join("invalid"), Default::default(), options);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "streaming"))]
+fn test_sink_atomic_commit() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_sink_atomic_commit");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("out.csv");
+    let df = df![
+        "name" => ["a", "b", "c"],
+    ]?;
+    let options = CsvWriterOptions {
+        commit_strategy: CommitStrategy::Atomic,
+        ..Default::default()
+    };
+
+    df.clone().lazy().sink_csv(path.clone(), options.clone())?;
+    let out = LazyCsvReader::new(&path).finish()?.collect()?;
+    assert!(out.equals(&df));
+    // The temporary file is renamed.
+    assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+
+    // A failing query leaves the existing file as it is.
+    let result = df
+        .clone()
+        .lazy()
+        .select([col("name").strict_cast(DataType::Int64)])
+        .sink_csv(path.clone(), options);
+    assert!(result.is_err());
+    let out = LazyCsvReader::new(&path).finish()?.collect()?;
+    assert!(out.equals(&df));
+    assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_csv_parse_error_policies() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_csv_parse_error_policies.csv");
+    std::fs::write(&path, "a,b,c\n1,x,1.5\nfoo,2,\n3,bar,2.5\n")?;
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Int64),
+        Field::new("c", DataType::Float64),
+    ]));
+    let policies = vec![
+        ("a".to_string(), CsvParseErrorPolicy::Sidecar),
+        ("b".to_string(), CsvParseErrorPolicy::Null),
+        ("c".to_string(), CsvParseErrorPolicy::Raise),
+    ];
+
+    let expected = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [None, Some(2i64), None],
+        "c" => [Some(1.5), None, Some(2.5)],
+        "a_raw" => [None, Some("foo"), None],
+    ]?;
+    let out = CsvReader::from_path(&path)?
+        .with_schema(Some(schema.clone()))
+        .with_parse_error_policies(Some(policies.clone()))
+        .finish()?;
+    assert!(out.equals_missing(&expected));
+    let out = LazyCsvReader::new(&path)
+        .with_schema(Some(schema.clone()))
+        .with_parse_error_policies(Some(policies))
+        .finish()?
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    let policies = Some(vec![("b".to_string(), CsvParseErrorPolicy::Raise)]);
+    assert!(CsvReader::from_path(&path)?
+        .with_schema(Some(schema.clone()))
+        .with_parse_error_policies(policies.clone())
+        .finish()
+        .is_err());
+    assert!(LazyCsvReader::new(&path)
+        .with_schema(Some(schema))
+        .with_parse_error_policies(policies)
+        .finish()?
+        .collect()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    not(target_os = "windows")
+))]
+fn test_scan_bounded_decoders() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let check = |scan: &dyn Fn(bool, Option<usize>) -> LazyFrame| -> PolarsResult<()> {
+        let expected = scan(false, None).collect()?;
+        for (low_memory, max_decoders) in [(true, None), (false, Some(1)), (true, Some(2))] {
+            let out = scan(low_memory, max_decoders).collect()?;
+            assert!(out.equals(&expected));
+            let out = scan(low_memory, max_decoders)
+                .with_streaming(true)
+                .collect()?;
+            assert!(out.equals(&expected));
+        }
+        Ok(())
+    };
+
+    check(&|low_memory, max_decoders| {
+        let args = ScanArgsParquet {
+            low_memory,
+            max_decoders,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet("../../examples/datasets/foods*.parquet", args)
+            .unwrap()
+            .filter(col("calories").gt(lit(50)))
+    })?;
+    check(&|low_memory, max_decoders| {
+        let args = ScanArgsIpc {
+            low_memory,
+            max_decoders,
+            ..Default::default()
+        };
+        LazyFrame::scan_ipc("../../examples/datasets/foods*.ipc", args)
+            .unwrap()
+            .filter(col("calories").gt(lit(50)))
+    })?;
+    // The number of threads bounds the chunks of a csv file that are decoded at the same time.
+    check(&|low_memory, max_decoders| {
+        LazyCsvReader::new(FOODS_CSV)
+            .low_memory(low_memory)
+            .with_n_threads(max_decoders)
+            .finish()
+            .unwrap()
+            .filter(col("calories").gt(lit(50)))
+    })?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+fn test_parquet_categorical_round_trip() -> PolarsResult<()> {
+    use polars_core::utils::arrow::array::Utf8ViewArray;
+
+    let enum_dtype = create_enum_data_type(Utf8ViewArray::from_slice_values([
+        "low", "medium", "high", "unused",
+    ]));
+    let mut df = df![
+        "cat" => ["b", "a", "c", "a", "b", "c"],
+        "enum" => [Some("high"), Some("low"), None, Some("medium"), Some("low"), Some("high")],
+    ]?
+    .lazy()
+    .with_columns([
+        col("cat").cast(DataType::Categorical(None, CategoricalOrdering::Lexical)),
+        col("enum").cast(enum_dtype.clone()),
+    ])
+    .collect()?;
+
+    let path = std::env::temp_dir().join("polars_parquet_categorical_round_trip.parquet");
+    let f = std::fs::File::create(&path).unwrap();
+    // Multiple row groups, each with its own dictionary page.
+    ParquetWriter::new(f)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+
+    let scan = LazyFrame::scan_parquet(path.to_str().unwrap(), Default::default())?;
+    let schema = scan.schema()?;
+    assert!(matches!(
+        schema.get("cat"),
+        Some(DataType::Categorical(_, CategoricalOrdering::Lexical))
+    ));
+    assert!(matches!(schema.get("enum"), Some(DataType::Enum(_, _))));
+
+    let out = scan.collect()?;
+    assert!(out.equals_missing(&df));
+    let cat = out.column("cat")?.categorical()?;
+    assert!(cat.uses_lexical_ordering());
+    let enum_ = out.column("enum")?;
+    assert_eq!(enum_.dtype(), &enum_dtype);
+    let categories = enum_.categorical()?.get_rev_map().get_categories().clone();
+    assert_eq!(
+        categories.values_iter().collect::<Vec<_>>(),
+        ["low", "medium", "high", "unused"]
+    );
+    // The physical order is kept.
+    let sorted = out
+        .lazy()
+        .select([col("enum").sort(Default::default()).cast(DataType::String)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(sorted.column("enum")?.str()?),
+        &[
+            None,
+            Some("low"),
+            Some("low"),
+            Some("medium"),
+            Some("high"),
+            Some("high")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", feature = "streaming"))]
+fn test_ipc_column_metadata_round_trip() -> PolarsResult<()> {
+    let metadata = FieldMetadata::from([
+        ("unit".to_string(), "m/s".to_string()),
+        ("description".to_string(), "wind speed".to_string()),
+    ]);
+    let path = std::env::temp_dir().join("polars_ipc_column_metadata_round_trip.ipc");
+    df![
+        "speed" => [1.5, 3.0],
+        "station" => ["a", "b"],
+    ]?
+    .lazy()
+    .with_column_metadata("speed", metadata.clone())
+    .sink_ipc(path.clone(), Default::default())?;
+
+    let scan = LazyFrame::scan_ipc(path.to_str().unwrap(), Default::default())?;
+    let schema = scan.clone().schema()?;
+    assert_eq!(schema.get_metadata("speed"), Some(&metadata));
+    assert_eq!(schema.get_metadata("station"), None);
+
+    let out = scan.select([col_meta("speed", "unit")]).collect()?;
+    assert_eq!(out.column("speed")?.str()?.get(0), Some("m/s"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_column_metadata_round_trip() -> PolarsResult<()> {
+    let metadata = FieldMetadata::from([("unit".to_string(), "m/s".to_string())]);
+    let mut speed = Series::new("speed", &[1.5, 3.0, 4.5]);
+    speed.set_metadata(metadata.clone());
+    let mut df = DataFrame::new(vec![speed, Series::new("station", &["a", "b", "c"])])?;
+
+    let path = std::env::temp_dir().join("polars_parquet_column_metadata_round_trip.parquet");
+    ParquetWriter::new(std::fs::File::create(&path).unwrap()).finish(&mut df)?;
+
+    let out = ParquetReader::new(std::fs::File::open(&path).unwrap()).finish()?;
+    assert_eq!(out.column("speed")?.metadata().as_deref(), Some(&metadata));
+    assert_eq!(out.column("station")?.metadata(), None);
+
+    let out = LazyFrame::scan_parquet(path.to_str().unwrap(), Default::default())?
+        .filter(col("speed").gt(lit(2.0)))
+        .select([col("speed").alias("v"), col_meta("speed", "unit")])
+        .collect()?;
+    assert_eq!(out.column("v")?.metadata().as_deref(), Some(&metadata));
+    assert_eq!(out.column("speed")?.str()?.get(0), Some("m/s"));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "ipc"))]
+fn test_negative_slice_skips_leading_rows() -> PolarsResult<()> {
+    let mut df = df!["a" => [0i32, 1, 2]]?;
+    for i in 1..4 {
+        df.vstack_mut(&df!["a" => [3 * i, 3 * i + 1, 3 * i + 2]]?)?;
+    }
+    // Four row groups or record batches of three rows.
+    let parquet_path = std::env::temp_dir().join("polars_negative_slice.parquet");
+    ParquetWriter::new(std::fs::File::create(&parquet_path).unwrap())
+        .with_row_group_size(Some(3))
+        .finish(&mut df)?;
+    let ipc_path = std::env::temp_dir().join("polars_negative_slice.ipc");
+    IpcWriter::new(std::fs::File::create(&ipc_path).unwrap()).finish(&mut df)?;
+
+    let scans = [
+        LazyFrame::scan_parquet(parquet_path.to_str().unwrap(), Default::default())?,
+        LazyFrame::scan_ipc(ipc_path.to_str().unwrap(), Default::default())?,
+    ];
+    for scan in scans {
+        let q = scan.clone().with_row_index("idx", None).slice(-5, 3);
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        assert!((&lp_arena).iter(lp).any(|(_, lp)| match lp {
+            IR::Scan {
+                scan_type: FileScan::Parquet { options, .. },
+                ..
+            } => options.skip_rows == 7,
+            IR::Scan {
+                scan_type: FileScan::Ipc { options, .. },
+                ..
+            } => options.skip_rows == 7,
+            _ => false,
+        }));
+
+        let out = q.collect()?;
+        let expected = df![
+            "idx" => [7 as IdxSize, 8, 9],
+            "a" => [7i32, 8, 9],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = scan.clone().tail(4).collect()?;
+        assert!(out.equals(&df!["a" => [8i32, 9, 10, 11]]?));
+        let out = scan.slice(-20, 2).collect()?;
+        assert!(out.equals(&df!["a" => [0i32, 1]]?));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+fn test_scan_parquet_nested_projection() -> PolarsResult<()> {
+    let s = df![
+        "x" => [1i32, 2, 3],
+        "y" => ["a", "b", "c"],
+        "z" => [1.0f64, 2.0, 3.0],
+    ]?
+    .into_struct("s")
+    .into_series();
+    let mut df = DataFrame::new(vec![Series::new("id", [1i32, 2, 3]), s])?;
+    let path = std::env::temp_dir().join("polars_nested_projection.parquet");
+    ParquetWriter::new(std::fs::File::create(&path).unwrap()).finish(&mut df)?;
+    let scan = || LazyFrame::scan_parquet(path.to_str().unwrap(), Default::default());
+    let nested_projection = |q: &LazyFrame| -> PolarsResult<NestedProjection> {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        Ok((&lp_arena)
+            .iter(lp)
+            .find_map(|(_, lp)| match lp {
+                IR::Scan {
+                    scan_type:
+                        FileScan::Parquet {
+                            nested_projection, ..
+                        },
+                    ..
+                } => Some(nested_projection.clone()),
+                _ => None,
+            })
+            .unwrap())
+    };
+
+    let q = scan()?
+        .filter(col("s").struct_().field_by_name("z").gt(lit(1.0)))
+        .select([col("s").struct_().field_by_name("x")]);
+    let mut expected = NestedProjection::default();
+    expected.add_path(&["s", "x"]);
+    expected.add_path(&["s", "z"]);
+    assert_eq!(nested_projection(&q)?, expected);
+    let out = q.collect()?;
+    assert!(out.equals(&df!["x" => [2i32, 3]]?));
+
+    // The struct column is passed on entirely.
+    let q = scan()?.select([col("s"), col("s").struct_().field_by_name("x").alias("x")]);
+    assert!(nested_projection(&q)?.is_empty());
+    assert_eq!(q.collect()?.column("s")?, df.column("s")?);
+
+    // The fields of lists of structs are the fields of their elements.
+    let mut l = df.column("s")?.implode()?.into_series();
+    l.rename("l");
+    let mut df = DataFrame::new(vec![l])?;
+    ParquetWriter::new(std::fs::File::create(&path).unwrap()).finish(&mut df)?;
+    let q = scan()?.select([
+        col("l").list().first().struct_().field_by_name("x"),
+        col("l")
+            .explode()
+            .struct_()
+            .field_by_name("z")
+            .sum()
+            .alias("z"),
+    ]);
+    let mut expected = NestedProjection::default();
+    expected.add_path(&["l", "x"]);
+    expected.add_path(&["l", "z"]);
+    assert_eq!(nested_projection(&q)?, expected);
+    let out = q.collect()?;
+    assert!(out.equals(&df!["x" => [1i32], "z" => [6.0f64]]?));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "serde"))]
+fn test_scan_parquet_decryption_serde() -> PolarsResult<()> {
+    use polars_io::parquet::read::FileDecryptionProperties;
+
+    let mut df = df!["a" => [1i32, 2, 3]]?;
+    let path = std::env::temp_dir().join("polars_decryption_serde.parquet");
+    ParquetWriter::new(std::fs::File::create(&path).unwrap()).finish(&mut df)?;
+
+    let q = LazyFrame::scan_parquet(path.to_str().unwrap(), Default::default())?;
+    let json = serde_json::to_string(&q.logical_plan).unwrap();
+    let plan = serde_json::from_str::<DslPlan>(&json).unwrap();
+    assert!(LazyFrame::from(plan).collect()?.equals(&df));
+
+    // The keys aren't serialized, so serializing the plan fails instead of losing them.
+    let decryption = FileDecryptionProperties::new().with_footer_key(b"0123456789012345".to_vec());
+    let args = ScanArgsParquet {
+        decryption: Some(Arc::new(decryption)),
+        ..Default::default()
+    };
+    let q = LazyFrame::scan_parquet(path.to_str().unwrap(), args)?;
+    let err = serde_json::to_string(&q.logical_plan).unwrap_err();
+    assert!(err.to_string().contains("keys are not serialized"));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "ipc"))]
+fn test_collect_cached() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_collect_cached.csv");
+    let cache_dir = std::env::temp_dir().join("polars_collect_cached");
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    let n_cached = || std::fs::read_dir(&cache_dir).map_or(0, |entries| entries.count());
+    let query = || -> PolarsResult<LazyFrame> {
+        Ok(LazyCsvReader::new(&path)
+            .finish()?
+            .filter(col("a").gt(lit(1)))
+            .select([col("a").sum()]))
+    };
+
+    std::fs::write(&path, "a\n1\n2\n3\n")?;
+    let out = query()?.collect_cached(&cache_dir)?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(5));
+    assert_eq!(n_cached(), 1);
+    let out = query()?.collect_cached(&cache_dir)?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(5));
+    assert_eq!(n_cached(), 1);
+
+    // Modifying the file invalidates the cached result.
+    std::fs::write(&path, "a\n1\n2\n3\n4\n")?;
+    let out = query()?.collect_cached(&cache_dir)?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(9));
+    assert_eq!(n_cached(), 2);
+
+    // The fast count of the rows of a file is invalidated too.
+    let count = || {
+        LazyCsvReader::new(&path)
+            .finish()?
+            .select([len()])
+            .collect_cached(&cache_dir)
+    };
+    assert_eq!(count()?.column("len")?.idx()?.get(0), Some(4));
+    std::fs::write(&path, "a\n1\n")?;
+    assert_eq!(count()?.column("len")?.idx()?.get(0), Some(1));
+    assert_eq!(n_cached(), 4);
+
+    // Queries that draw random values aren't cached.
+    #[cfg(feature = "random")]
+    {
+        LazyCsvReader::new(&path)
+            .finish()?
+            .select([col("a").shuffle(None)])
+            .collect_cached(&cache_dir)?;
+        assert_eq!(n_cached(), 4);
+    }
+
+    // Queries on in-memory data aren't cached.
+    let out = df!["a" => [1i64, 2]]?
+        .lazy()
+        .select([col("a").sum()])
+        .collect_cached(&cache_dir)?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(3));
+    assert_eq!(n_cached(), 4);
+    Ok(())
+}
@@ -51,6 +51,70 @@ fn test_duration() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "strings", feature = "temporal", feature = "dtype-duration"))]
+fn test_duration_to_string_round_trip() -> PolarsResult<()> {
+    let tu = TimeUnit::Microseconds;
+    let df = df![
+        "d" => [Some(0i64), Some(93_784_500_000), Some(-90_000_000), Some(1), None],
+    ]?
+    .lazy()
+    .select([col("d").cast(DataType::Duration(tu))]);
+
+    let out = df
+        .clone()
+        .select([
+            col("d").dt().to_string("iso").alias("iso"),
+            col("d").dt().to_string("polars").alias("polars"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("iso")?.str()?),
+        &[
+            Some("PT0S"),
+            Some("P1DT2H3M4.5S"),
+            Some("-PT1M30S"),
+            Some("PT0.000001S"),
+            None
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("polars")?.str()?),
+        &[
+            Some("0µs"),
+            Some("1d 2h 3m 4s 500ms"),
+            Some("-1m -30s"),
+            Some("1µs"),
+            None
+        ]
+    );
+
+    let expected = df.clone().collect()?;
+    for format in ["iso", "polars"] {
+        let out = df
+            .clone()
+            .select([col("d").dt().to_string(format).str().to_duration(tu, true)])
+            .collect()?;
+        assert!(out.equals_missing(&expected), "{format}");
+    }
+
+    let invalid = df!["d" => ["P1Y", "1 day", "PT1H30M"]]?.lazy();
+    let out = invalid
+        .clone()
+        .select([col("d").str().to_duration(TimeUnit::Milliseconds, false)])
+        .collect()?;
+    assert_eq!(
+        out.column("d")?.get(2)?,
+        AnyValue::Duration(5_400_000, TimeUnit::Milliseconds)
+    );
+    assert_eq!(out.column("d")?.null_count(), 2);
+    assert!(invalid
+        .select([col("d").str().to_duration(TimeUnit::Milliseconds, true)])
+        .collect()
+        .is_err());
+    Ok(())
+}
+
 fn print_plans(lf: &LazyFrame) {
     println!("LOGICAL PLAN\n\n{}\n", lf.describe_plan());
     println!(
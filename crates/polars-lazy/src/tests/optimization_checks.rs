@@ -189,6 +189,38 @@ pub fn test_simple_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+pub fn test_slice_after_predicate_at_parquet_scan() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let q = scan_foods_parquet(false)
+        .filter(col("calories").gt(lit(50)))
+        .slice(1, 3);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    // The slice is kept on top, the scan stops once enough rows passed the predicate.
+    assert!(matches!(lp_arena.get(lp), IR::Slice { .. }));
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| matches!(
+        lp,
+        IR::Scan {
+            scan_type: FileScan::Parquet { options, .. },
+            ..
+        } if options.n_rows_after_predicate == Some(4)
+    )));
+
+    let expected = scan_foods_parquet(false)
+        .collect()?
+        .lazy()
+        .filter(col("calories").gt(lit(50)))
+        .slice(1, 3)
+        .collect()?;
+    let out = q.collect()?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 #[cfg(feature = "cse")]
@@ -274,8 +306,8 @@ pub fn test_slice_pushdown_sort() -> PolarsResult<()> {
     assert!((&lp_arena).iter(lp).all(|(_, lp)| {
         use IR::*;
         match lp {
-            Sort { slice, .. } => *slice == Some((1, 3)),
-            Slice { .. } => false,
+            TopK { offset, len, .. } => (*offset, *len) == (1, 3),
+            Sort { .. } | Slice { .. } => false,
             _ => true,
         }
     }));
@@ -519,6 +551,25 @@ fn test_slice_at_scan_group_by() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "csv")]
+fn test_slice_pushdown_melt() -> PolarsResult<()> {
+    let args = MeltArgs {
+        id_vars: vec!["category".into()],
+        value_vars: vec!["calories".into(), "sugars_g".into()],
+        ..Default::default()
+    };
+    let q = scan_foods_csv().melt(args).limit(3);
+
+    // a prefix of the melted rows only needs the same prefix of the input rows
+    assert!(slice_at_scan(q.clone()));
+    let expected = q.clone().with_slice_pushdown(false).collect()?;
+    let out = q.collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.shape(), (3, 3));
+    Ok(())
+}
+
 #[test]
 fn test_flatten_unions() -> PolarsResult<()> {
     let (mut expr_arena, mut lp_arena) = get_arenas();
@@ -548,3 +599,435 @@ fn test_flatten_unions() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_optimizer_trace() -> PolarsResult<()> {
+    let events = scan_foods_csv().limit(3).optimizer_trace()?;
+    assert!(events
+        .iter()
+        .any(|e| e.optimization == "slice_pushdown" && e.outcome == OptimizerOutcome::Applied));
+
+    let events = scan_foods_csv()
+        .select([lit(1).alias("one")])
+        .limit(3)
+        .optimizer_trace()?;
+    let blocked = events
+        .iter()
+        .find(|e| e.optimization == "slice_pushdown" && e.is_blocked())
+        .unwrap();
+    assert_eq!(
+        blocked.to_string(),
+        "slice_pushdown blocked at projection: projection doesn't refer to any column of its input"
+    );
+
+    let events = scan_foods_csv()
+        .limit(3)
+        .filter(col("calories").gt(lit(5)))
+        .group_by([col("category")])
+        .agg([col("calories").sum()])
+        .filter(col("calories").gt(lit(10)))
+        .optimizer_trace()?;
+    let blocked = events
+        .iter()
+        .filter(|e| e.optimization == "predicate_pushdown" && e.is_blocked())
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        blocked,
+        &[
+            "predicate_pushdown blocked at slice: filtering before a slice changes which rows are sliced",
+            "predicate_pushdown blocked at aggregate: predicate refers to an aggregated column or counts the rows",
+        ]
+    );
+
+    let events = scan_foods_csv()
+        .limit(3)
+        .with_slice_pushdown(false)
+        .optimizer_trace()?;
+    assert!(events.iter().any(|e| e.optimization == "slice_pushdown"
+        && e.outcome == OptimizerOutcome::Blocked("disabled")));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "diagonal_concat")]
+fn test_diagonal_concat_relaxed_pushdown() -> PolarsResult<()> {
+    let a = df![
+        "a" => [1i32, 2],
+        "b" => ["x", "y"],
+    ]?;
+    let b = df![
+        "c" => [1.5, 2.5],
+        "a" => [3i64, 4],
+        "d" => [true, false],
+    ]?;
+
+    let q = concat_lf_diagonal(
+        [a.lazy(), b.lazy()],
+        UnionArgs {
+            to_supertypes: true,
+            ..Default::default()
+        },
+    )?
+    .filter(col("a").gt(lit(1)))
+    .select([col("a"), col("c")]);
+
+    // The predicate is pushed into the input that isn't cast and the unused columns are
+    // never read.
+    assert!(predicate_at_scan(q.clone()));
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).all(|(_, lp)| match lp {
+        IR::DataFrameScan { output_schema, .. } => output_schema
+            .as_ref()
+            .map_or(false, |schema| !schema.contains("b")
+                && !schema.contains("d")),
+        _ => true,
+    }));
+
+    let out = q.collect()?;
+    let expected = df![
+        "a" => [2i64, 3, 4],
+        "c" => [None, Some(1.5), Some(2.5)],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // Without `to_supertypes`, differing dtypes are an error.
+    let a = df!["a" => [1i32]]?;
+    let b = df!["a" => [1i64]]?;
+    assert!(
+        concat_lf_diagonal([a.lazy(), b.lazy()], Default::default())?
+            .collect()
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cross_join")]
+fn test_slice_pushdown_cross_join() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3, 4, 5, 6, 7, 8]
+    ]?
+    .lazy();
+    let right = df![
+        "b" => ["x", "y", "z"]
+    ]?
+    .lazy();
+
+    for (offset, length) in [(0, 2), (4, 3), (20, 2)] {
+        let q = left.clone().cross_join(right.clone()).slice(offset, length);
+
+        // Both inputs only need the first `offset + len` rows.
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        let IR::Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } = lp_arena.get(lp)
+        else {
+            panic!("expected a join at the root")
+        };
+        assert_eq!(options.args.slice, Some((offset, length as usize)));
+        for input in [input_left, input_right] {
+            assert!(matches!(
+                lp_arena.get(*input),
+                IR::Slice { offset: 0, len, .. } if *len as i64 == offset + length as i64
+            ));
+        }
+
+        let expected = q.clone().with_slice_pushdown(false).collect()?;
+        assert_eq!(q.collect()?, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder() -> PolarsResult<()> {
+    let fact = df![
+        "id" => (0..100).collect::<Vec<i32>>(),
+        "a" => (0..100).map(|i| i % 50).collect::<Vec<i32>>(),
+        "b" => (0..100).map(|i| i % 10).collect::<Vec<i32>>(),
+    ]?
+    .lazy();
+    let dim_a = df![
+        "a" => (0..50).collect::<Vec<i32>>(),
+        "x" => (0..50).map(|i| i * 2).collect::<Vec<i32>>(),
+    ]?
+    .lazy();
+    let dim_b = df![
+        "b" => [0, 1],
+        "y" => ["zero", "one"],
+    ]?
+    .lazy();
+
+    let q = fact
+        .inner_join(dim_a, col("a"), col("a"))
+        .inner_join(dim_b, col("b"), col("b"))
+        .with_join_reorder(true);
+
+    // The selective join with `dim_b` is done first.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let IR::SimpleProjection { input, .. } = lp_arena.get(lp) else {
+        panic!("expected a projection at the root")
+    };
+    let IR::Join { input_left, .. } = lp_arena.get(*input) else {
+        panic!("expected a join")
+    };
+    let IR::Join { input_right, .. } = lp_arena.get(*input_left) else {
+        panic!("expected a join")
+    };
+    assert!(matches!(
+        lp_arena.get(*input_right),
+        IR::DataFrameScan { df, .. } if df.height() == 2
+    ));
+
+    let out = q.clone().sort(["id"], Default::default()).collect()?;
+    let expected = q
+        .with_join_reorder(false)
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["id", "a", "b", "x", "y"]);
+    assert_eq!(out, expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+fn test_is_in_to_semi_join() -> PolarsResult<()> {
+    let df = df![
+        "id" => (0..5000).collect::<Vec<i32>>(),
+        "v" => (0..5000).map(|i| i % 7).collect::<Vec<i32>>(),
+    ]?;
+    let has_semi_join = |q: LazyFrame| {
+        let (node, lp_arena, _) = q.to_alp_optimized().unwrap();
+        (&lp_arena).iter(node).any(
+            |(_, lp)| matches!(lp, IR::Join { options, .. } if options.args.how == JoinType::Semi),
+        )
+    };
+
+    let values = Series::new("values", (0..4000).step_by(2).collect::<Vec<i32>>());
+    let q = df.clone().lazy().filter(
+        col("id")
+            .is_in(lit(values.clone()))
+            .and(col("v").gt(lit(2))),
+    );
+    assert!(has_semi_join(q.clone()));
+    let expected = q.clone().with_predicate_pushdown(false).collect()?;
+    assert_eq!(q.collect()?, expected);
+    assert_eq!(expected.height(), 2000 * 4 / 7);
+
+    // Predicates that depend on all rows must see the rows the join would remove. The mean of
+    // `v` is just below 3.
+    let q = df.clone().lazy().filter(
+        col("id")
+            .is_in(lit(values))
+            .and(col("v").gt(col("v").mean())),
+    );
+    assert!(!has_semi_join(q.clone()));
+    assert_eq!(q.collect()?, expected);
+
+    // Short lists and lists with nulls are evaluated as predicates.
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("id").is_in(lit(Series::new("values", [1, 2, 3]))));
+    assert!(!has_semi_join(q));
+    let values = Series::new(
+        "values",
+        (0..2000).map(|i| (i > 0).then_some(i)).collect::<Vec<_>>(),
+    );
+    let q = df.lazy().filter(col("id").is_in(lit(values)));
+    assert!(!has_semi_join(q));
+    Ok(())
+}
+
+#[test]
+fn test_estimate_memory() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i64>>(),
+        "b" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let q = df
+        .lazy()
+        .filter(col("a").gt(lit(10)))
+        .sort(["a"], Default::default());
+
+    let estimates = q.estimate_memory(u64::MAX)?;
+    let nodes = estimates.iter().map(|e| e.node).collect::<Vec<_>>();
+    assert_eq!(nodes, ["sort", "df"]);
+    assert_eq!(estimates[1].depth, 1);
+    assert_eq!(estimates[1].rows, Some(1000));
+    assert_eq!(estimates[1].output_bytes, Some(12000));
+    assert_eq!(estimates[0].peak_bytes, Some(24000));
+    assert!(estimates.iter().all(|e| !e.spills));
+
+    // The sort holds all of its input, the scan doesn't.
+    let estimates = q.estimate_memory(16000)?;
+    assert!(estimates[0].spills);
+    assert!(!estimates[1].spills);
+    assert!(q.explain_memory(16000)?.starts_with("sort: rows 1000"));
+    Ok(())
+}
+
+#[test]
+fn test_simplify_boolean_predicates() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, Some(3)],
+    ]?;
+
+    // x == x only drops the nulls.
+    let q = df.clone().lazy().filter(col("a").eq(col("a")));
+    assert!(q.describe_optimized_plan()?.contains("is_not_null"));
+    assert_eq!(q.collect()?.height(), 2);
+
+    // Comparisons of literals are folded.
+    let q = df
+        .lazy()
+        .filter(lit("x").eq(lit("y")).or(col("a").gt(lit(1))));
+    assert!(!q.describe_optimized_plan()?.contains("String(x)"));
+    assert_eq!(q.collect()?.height(), 1);
+
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [3, 2, 1],
+        "f" => [true, false, true],
+    ]?;
+    let predicate = |q: &LazyFrame| {
+        let (node, lp_arena, expr_arena) = q
+            .clone()
+            .with_predicate_pushdown(false)
+            .to_alp_optimized()
+            .unwrap();
+        (&lp_arena)
+            .iter(node)
+            .find_map(|(_, lp)| match lp {
+                IR::Filter { predicate, .. } => Some(expr_arena.get(predicate.node()).clone()),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    // not(a & b) => !a | !b
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(1)).and(col("b").gt(lit(1))).not());
+    assert!(matches!(
+        predicate(&q),
+        AExpr::BinaryExpr {
+            op: Operator::Or,
+            ..
+        }
+    ));
+    assert_eq!(q.collect()?.height(), 2);
+
+    // not(a | b) => !a & !b
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(2)).or(col("b").gt(lit(2))).not());
+    assert!(matches!(
+        predicate(&q),
+        AExpr::BinaryExpr {
+            op: Operator::And,
+            ..
+        }
+    ));
+    assert_eq!(q.collect()?.height(), 1);
+
+    // not(not(x)) => x
+    let q = df.lazy().filter(col("f").not().not());
+    assert!(matches!(predicate(&q), AExpr::Column(name) if name.as_ref() == "f"));
+    assert_eq!(q.collect()?.height(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_column_lineage() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1i32, 2, 3],
+        "b" => [4i32, 5, 6],
+    ]?;
+    let right = df![
+        "a" => [1i32, 2],
+        "x" => ["x", "y"],
+    ]?;
+    let q = left
+        .clone()
+        .lazy()
+        .with_column((col("a") + col("b")).alias("c"))
+        .join(
+            right.lazy(),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .select([col("c"), col("x").alias("y"), col("a")]);
+
+    let source = |column: &str| SourceColumn {
+        source: "DataFrame".to_string(),
+        column: column.to_string(),
+    };
+    let lineage = q.column_lineage()?;
+    let columns = lineage
+        .iter()
+        .map(|l| l.column.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(columns, ["c", "y", "a"]);
+
+    assert_eq!(
+        lineage[0].sources,
+        [source("a"), source("b")].into_iter().collect()
+    );
+    assert_eq!(lineage[0].expressions.len(), 1);
+    assert_eq!(lineage[1].sources, [source("x")].into_iter().collect());
+    assert_eq!(lineage[1].expressions.len(), 1);
+    assert_eq!(lineage[2].sources, [source("a")].into_iter().collect());
+    assert!(lineage[2].expressions.is_empty());
+
+    // The coalesced keys of a join come from both sides.
+    let right = df![
+        "k" => [1i32, 2],
+        "x" => ["x", "y"],
+    ]?;
+    let q = left.clone().lazy().join(
+        right.lazy(),
+        [col("a")],
+        [col("k")],
+        JoinArgs::new(JoinType::Left),
+    );
+    let lineage = q.column_lineage()?;
+    assert_eq!(lineage[0].column, "a");
+    assert_eq!(
+        lineage[0].sources,
+        [source("a"), source("k")].into_iter().collect()
+    );
+
+    // The columns of a union come from all of its inputs.
+    let other = df![
+        "z" => [7i32],
+        "b" => [8i32],
+    ]?;
+    let q = concat(
+        [
+            left.lazy(),
+            other
+                .lazy()
+                .select([(col("z") * lit(2)).alias("a"), col("b")]),
+        ],
+        Default::default(),
+    )?;
+    let lineage = q.column_lineage()?;
+    assert_eq!(
+        lineage[0].sources,
+        [source("a"), source("z")].into_iter().collect()
+    );
+    assert_eq!(lineage[0].expressions.len(), 1);
+    assert_eq!(lineage[1].sources, [source("b")].into_iter().collect());
+    Ok(())
+}
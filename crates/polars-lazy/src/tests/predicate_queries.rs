@@ -333,3 +333,88 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_predicate_pushdown_window_conjunction() -> PolarsResult<()> {
+    let df = df![
+        "key" => [1, 1, 2, 2, 2],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+
+    // The predicate on the partition key is split from the window predicate and pushed down.
+    let q = df.clone().lazy().filter(
+        len()
+            .over([col("key")])
+            .gt(lit(2))
+            .and(col("key").eq(lit(2))),
+    );
+    assert!(predicate_at_scan(q.clone()));
+    assert_eq!(q.collect()?.shape(), (3, 2));
+
+    // The partition key is overwritten by the projection, so the predicate cannot pass.
+    let q = df
+        .lazy()
+        .with_columns([
+            col("value").sum().over([col("key")]).alias("sum"),
+            (col("key") + lit(1)).alias("key"),
+        ])
+        .filter(col("key").eq(lit(2)));
+    assert!(!predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(out.column("sum")?, &Series::new("sum", [3, 3]));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "strings"))]
+fn test_scan_predicate_ranges() -> PolarsResult<()> {
+    let q = scan_foods_parquet(false).filter(
+        col("calories")
+            .gt(lit(45))
+            .and(col("category").str().contains_literal(lit("vege")))
+            .and(col("calories").gt_eq(lit(60)))
+            .and(lit(200).gt(col("calories"))),
+    );
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let predicate = (&lp_arena)
+        .iter(root)
+        .find_map(|(_, lp)| match lp {
+            IR::Scan {
+                predicate: Some(predicate),
+                ..
+            } => Some(predicate.node()),
+            _ => None,
+        })
+        .unwrap();
+    // Only the tightest bounds of the range of `calories` are kept.
+    let comparisons = (&expr_arena)
+        .iter(predicate)
+        .filter(|(_, e)| {
+            matches!(
+                e,
+                AExpr::BinaryExpr {
+                    op: Operator::Gt | Operator::GtEq | Operator::Lt | Operator::LtEq,
+                    ..
+                }
+            )
+        })
+        .count();
+    assert_eq!(comparisons, 2);
+
+    let out = q.collect()?;
+    let expected = scan_foods_parquet(false)
+        .filter(
+            col("category")
+                .str()
+                .contains_literal(lit("vege"))
+                .and(col("calories").gt_eq(lit(60)))
+                .and(col("calories").lt(lit(200))),
+        )
+        .with_predicate_pushdown(false)
+        .collect()?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
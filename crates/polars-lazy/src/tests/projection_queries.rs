@@ -130,3 +130,44 @@ fn concat_str_regex_expansion() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_join_unused_keys_pruned() -> PolarsResult<()> {
+    let left = df![
+        "key" => [1, 2, 3],
+        "a" => [1, 2, 3],
+    ]?
+    .lazy();
+    let right = df![
+        "key" => [2, 3, 4],
+        "b" => [4, 5, 6],
+    ]?
+    .lazy();
+
+    let q = left
+        .outer_join(right, col("key"), col("key"))
+        .select([col("a"), col("b")]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    // The projection directly on top of the join should not carry the keys.
+    let join_projection_schema = (&lp_arena)
+        .iter(root)
+        .find_map(|(_, lp)| match lp {
+            IR::Select { input, .. } | IR::SimpleProjection { input, .. }
+                if matches!(lp_arena.get(*input), IR::Join { .. }) =>
+            {
+                Some(lp.schema(&lp_arena).into_owned())
+            },
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(join_projection_schema.len(), 2);
+    assert!(!join_projection_schema.contains("key"));
+
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert_eq!(out.height(), 4);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
+use polars_ops::frame::JoinCoalesce;
 
 use super::*;
 
@@ -14,6 +15,45 @@ fn test_lazy_with_column() {
     assert!(df.column("foo").is_ok());
 }
 
+#[test]
+fn test_lazy_with_columns_chained() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [10, 20, 30],
+    ]?;
+
+    // `with_columns_seq` evaluates its expressions on the input only.
+    assert!(df
+        .clone()
+        .lazy()
+        .with_columns_seq([(col("a") * lit(2)).alias("c"), col("c").alias("d")])
+        .collect()
+        .is_err());
+
+    let out = df
+        .lazy()
+        .with_columns_chained([
+            col("a").sum().alias("total"),
+            (col("a") * lit(2)).alias("c"),
+            (col("c") + col("b")).alias("d"),
+            // Redefine an input column in terms of a derived one.
+            (col("d") - lit(1)).alias("a"),
+            col("a").alias("e"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "a" => [11, 23, 35],
+        "b" => [10, 20, 30],
+        "total" => [6, 6, 6],
+        "c" => [2, 4, 6],
+        "d" => [12, 24, 36],
+        "e" => [11, 23, 35],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_exec() {
     let df = get_df();
@@ -555,7 +595,15 @@ fn test_simplify_expr() {
 
     let mut expr_arena = Arena::new();
     let mut lp_arena = Arena::new();
-    let lp_top = to_alp(plan, &mut expr_arena, &mut lp_arena, true, false).unwrap();
+    let lp_top = to_alp(
+        plan,
+        &mut expr_arena,
+        &mut lp_arena,
+        true,
+        false,
+        Default::default(),
+    )
+    .unwrap();
     let plan = node_to_lp(lp_top, &expr_arena, &mut lp_arena);
     assert!(
         matches!(plan, DslPlan::Select{ expr, ..} if matches!(&expr[0], Expr::BinaryExpr{left, ..} if **left == Expr::Literal(LiteralValue::Float(2.0))))
@@ -634,7 +682,15 @@ fn test_type_coercion() {
 
     let mut expr_arena = Arena::new();
     let mut lp_arena = Arena::new();
-    let lp_top = to_alp(lp, &mut expr_arena, &mut lp_arena, true, true).unwrap();
+    let lp_top = to_alp(
+        lp,
+        &mut expr_arena,
+        &mut lp_arena,
+        true,
+        true,
+        Default::default(),
+    )
+    .unwrap();
     let lp = node_to_lp(lp_top, &expr_arena, &mut lp_arena);
 
     if let DslPlan::Select { expr, .. } = lp {
@@ -1384,6 +1440,34 @@ fn test_categorical_addition() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_string_cache_scope_on_thread_pool() -> PolarsResult<()> {
+    let cat = DataType::Categorical(None, Default::default());
+    let _scope = StringCacheScope::new();
+
+    // The inputs of the union and the expressions of the projections run on the thread pool.
+    let lfs = (0..4)
+        .map(|_| {
+            fruits_cars().lazy().select([
+                col("fruits").cast(cat.clone()),
+                col("cars").cast(cat.clone()),
+            ])
+        })
+        .collect::<Vec<_>>();
+    let out = concat(lfs, UnionArgs::default())?.collect()?;
+
+    let local = Series::new("", ["banana", "audi", "pear"]).cast(&cat)?;
+    let local = local.categorical()?.get_rev_map();
+    assert!(local.is_global());
+    for name in ["fruits", "cars"] {
+        let rev_map = out.column(name)?.categorical()?.get_rev_map();
+        assert!(rev_map.same_src(local));
+    }
+    assert_eq!(out.column("cars")?.str_value(1)?, "audi");
+    Ok(())
+}
+
 #[test]
 fn test_error_duplicate_names() {
     let df = fruits_cars();
@@ -1544,6 +1628,210 @@ fn test_fill_nan() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_nan_to_null() -> PolarsResult<()> {
+    let df = df![
+        "int" => [1, 2, 3],
+        "float" => [Some(1.0), Some(f64::NAN), None],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("int").nan_to_null(),
+            col("float").nan_to_null().alias("nan_to_null"),
+            col("float").nan_to_null().mean().alias("mean"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("int")?, &Series::new("int", [1, 2, 3]));
+    assert_eq!(
+        Vec::from(out.column("nan_to_null")?.f64()?),
+        &[Some(1.0), None, None]
+    );
+    assert_eq!(out.column("mean")?.f64()?.get(0), Some(1.0));
+
+    Ok(())
+}
+
+#[test]
+fn test_nan_as_null() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "a" => [Some(3.0), Some(f64::NAN), None, Some(1.0)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_nan_as_null(true)
+        .select([
+            col("a").sum().alias("sum"),
+            col("a").mean().alias("mean"),
+            col("a").max().alias("max"),
+            col("a").nan_max().alias("nan_max"),
+            col("a").count().alias("count"),
+            col("a").gt(lit(0.0)).alias("gt"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("sum")?.f64()?.get(0), Some(4.0));
+    assert_eq!(out.column("mean")?.f64()?.get(0), Some(2.0));
+    assert_eq!(out.column("max")?.f64()?.get(0), Some(3.0));
+    assert!(out.column("nan_max")?.f64()?.get(0).unwrap().is_nan());
+    assert_eq!(out.column("count")?.get(0)?, AnyValue::IdxSize(2));
+    assert_eq!(
+        Vec::from(out.column("gt")?.bool()?),
+        &[Some(true), None, None, Some(true)]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_nan_as_null(true)
+        .group_by_stable([col("g")])
+        .agg([col("a").mean()])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.f64()?), &[Some(3.0), Some(1.0)]);
+
+    // The `NaN` sorts with the nulls.
+    let out = df
+        .clone()
+        .lazy()
+        .with_nan_as_null(true)
+        .sort(
+            ["a"],
+            SortMultipleOptions::default()
+                .with_nulls_last(true)
+                .with_maintain_order(true),
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("g")?.i32()?),
+        &[Some(2), Some(1), Some(1), Some(2)]
+    );
+    assert!(out.column("a")?.f64()?.get(2).unwrap().is_nan());
+
+    // Without the option, `NaN` values propagate.
+    let out = df.lazy().select([col("a").sum()]).collect()?;
+    assert!(out.column("a")?.f64()?.get(0).unwrap().is_nan());
+
+    Ok(())
+}
+
+#[test]
+fn test_integer_overflow_modes() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2],
+        "a" => [i32::MAX, 1, i32::MAX],
+        "b" => [1, 1, 1],
+    ]?;
+    let q = |overflow| {
+        df.clone()
+            .lazy()
+            .with_integer_overflow(overflow)
+            .select([(col("a") + col("b")).alias("add"), col("a").sum()])
+    };
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([(col("a") + col("b")).alias("add")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("add")?.i32()?),
+        &[Some(i32::MIN), Some(2), Some(i32::MIN)]
+    );
+
+    let out = q(IntegerOverflow::Saturate).collect()?;
+    assert_eq!(
+        Vec::from(out.column("add")?.i32()?),
+        &[Some(i32::MAX), Some(2), Some(i32::MAX)]
+    );
+    assert_eq!(out.column("a")?.i32()?.get(0), Some(i32::MAX));
+
+    let err = df
+        .clone()
+        .lazy()
+        .with_integer_overflow(IntegerOverflow::Error)
+        .select([col("b") * lit(2) + col("a")])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("'b' + 'a' at row 0"));
+
+    let out = df
+        .lazy()
+        .with_integer_overflow(IntegerOverflow::Saturate)
+        .group_by_stable([col("g")])
+        .agg([col("a").sum()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(i32::MAX), Some(i32::MAX)]
+    );
+
+    // The row of an overflow in a group is its row in the frame.
+    let err = df![
+        "g" => [1, 2, 1],
+        "a" => [i32::MAX, 1, 1],
+    ]?
+    .lazy()
+    .with_integer_overflow(IntegerOverflow::Error)
+    .group_by([col("g")])
+    .agg([col("a").sum()])
+    .collect()
+    .unwrap_err();
+    assert!(err.to_string().contains("sum of 'a' at row 2"));
+
+    Ok(())
+}
+
+#[test]
+fn test_strict_cast_policy() -> PolarsResult<()> {
+    let df = df![
+        "int" => [1i64, 2],
+        "float" => [1.0f32, 2.0],
+        "small" => [1i16, 2],
+        "str" => ["1", "2"],
+    ]?;
+    let q = |e: Expr| {
+        df.clone()
+            .lazy()
+            .with_cast_policy(CastPolicy::Strict)
+            .select([e])
+            .collect()
+    };
+
+    let err = q(col("int") + col("float")).unwrap_err();
+    assert!(err.to_string().contains("implicit cast of 'int'"));
+    let err = q(col("int").eq(col("str"))).unwrap_err();
+    assert!(err.to_string().contains("strict cast policy"));
+    // The default lenient policy casts to the supertype.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("int") + col("float")])
+        .collect()?;
+    assert_eq!(out.column("int")?.dtype(), &DataType::Float64);
+    // Lossless casts and explicit casts are allowed.
+    q(col("small") + col("float"))?;
+    q(col("int").cast(DataType::Float64) + col("float"))?;
+
+    let other = df![
+        "int" => [1.5f64],
+    ]?;
+    let args = UnionArgs {
+        to_supertypes: true,
+        ..Default::default()
+    };
+    let lf = df.clone().lazy().select([col("int")]);
+    assert!(concat([lf.clone(), other.clone().lazy()], args)?
+        .with_cast_policy(CastPolicy::Strict)
+        .collect()
+        .is_err());
+    assert_eq!(concat([lf, other.lazy()], args)?.collect()?.height(), 3);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "regex")]
 fn test_exclude_regex() -> PolarsResult<()> {
@@ -1917,3 +2205,946 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+// The output dtype is resolved under the GIL with the python feature.
+#[cfg(all(feature = "cumulative_eval", not(feature = "python")))]
+fn test_incremental_cumulative_eval() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(3), None, Some(1), Some(4), Some(1), Some(5)],
+    ]?;
+    // Aliasing the aggregation evaluates it on every window instead.
+    let pair = |name: &str, agg: Expr, window_size: Option<usize>| {
+        let [fast, slow] = [agg.clone(), agg.alias("reference")].map(|e| match window_size {
+            Some(w) => col("a").rolling_eval(e, w, 2, false),
+            None => col("a").cumulative_eval(e, 1, false),
+        });
+        [fast.alias(name), slow.alias(&format!("{name}_reference"))]
+    };
+    let exprs = [
+        pair("sum", col("").sum(), None),
+        pair("min", col("").min(), None),
+        pair("n_unique", col("").n_unique(), None),
+        pair("rolling_sum", col("").sum(), Some(3)),
+        pair("rolling_max", col("").max(), Some(3)),
+        pair("rolling_n_unique", col("").n_unique(), Some(3)),
+    ];
+    let out = df
+        .clone()
+        .lazy()
+        .select(exprs.into_iter().flatten().collect::<Vec<_>>())
+        .collect()?;
+    for name in [
+        "sum",
+        "min",
+        "n_unique",
+        "rolling_sum",
+        "rolling_max",
+        "rolling_n_unique",
+    ] {
+        let reference = out.column(&format!("{name}_reference"))?;
+        assert!(out.column(name)?.equals_missing(reference), "{name}");
+    }
+    assert_eq!(
+        Vec::from(out.column("rolling_sum")?.i32()?),
+        &[None, None, Some(4), Some(5), Some(6), Some(10)]
+    );
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").rolling_eval(col("").var(1), 3, 2, false),
+            col("a")
+                .rolling_eval(col("").var(1).alias("reference"), 3, 2, false)
+                .alias("reference"),
+        ])
+        .collect()?;
+    let diff = out.column("a")? - out.column("reference")?;
+    assert!(diff.f64()?.into_iter().flatten().all(|d| d.abs() < 1e-12));
+    assert_eq!(diff.null_count(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_join_nearest() -> PolarsResult<()> {
+    use polars_ops::frame::AsofJoin;
+
+    let left = df![
+        "t" => [Some(10), Some(1), Some(7), None],
+        "l" => ["a", "b", "c", "d"],
+    ]?;
+    let right = df![
+        "t" => [0, 8, 3],
+        "r" => [100, 200, 300],
+    ]?;
+
+    let out = left
+        .clone()
+        .lazy()
+        .join_nearest(right.clone().lazy(), col("t"), col("t"), None)
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["t", "l", "r"]);
+    assert_eq!(
+        Vec::from(out.column("r")?.i32()?),
+        &[Some(200), Some(100), Some(200), None]
+    );
+    assert!(out.equals_missing(&left.join_nearest(&right, "t", "t", None, None)?));
+
+    let tolerance = Some(AnyValue::Int32(1));
+    let out = left
+        .clone()
+        .lazy()
+        .join_nearest(right.clone().lazy(), col("t"), col("t"), tolerance.clone())
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("r")?.i32()?),
+        &[None, Some(100), Some(200), None]
+    );
+    assert!(out.equals_missing(&left.join_nearest(&right, "t", "t", tolerance, None)?));
+
+    // Right rows without a key aren't matched and equal right keys match the last of them.
+    let right = df![
+        "t" => [Some(8), None, Some(0), Some(8), None],
+        "r" => [100, 200, 300, 400, 500],
+    ]?;
+    let out = left
+        .clone()
+        .lazy()
+        .join_nearest(right.clone().lazy(), col("t"), col("t"), None)
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("r")?.i32()?),
+        &[Some(400), Some(300), Some(400), None]
+    );
+    assert!(out.equals_missing(&left.join_nearest(&right, "t", "t", None, None)?));
+
+    // A duration tolerance is in the time unit of the keys.
+    let to_datetime = |df: DataFrame| {
+        df.lazy().with_column(
+            (col("t") * lit(1_000_000i64)).cast(DataType::Datetime(TimeUnit::Microseconds, None)),
+        )
+    };
+    let tolerance = Some(AnyValue::Duration(1_000, TimeUnit::Milliseconds));
+    let out = to_datetime(left.clone())
+        .join_nearest(
+            to_datetime(right.clone()),
+            col("t"),
+            col("t"),
+            tolerance.clone(),
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("r")?.i32()?),
+        &[None, Some(300), Some(400), None]
+    );
+    let eager = to_datetime(left).collect()?.join_nearest(
+        &to_datetime(right).collect()?,
+        "t",
+        "t",
+        tolerance,
+        None,
+    )?;
+    assert!(out.equals_missing(&eager));
+    Ok(())
+}
+
+#[test]
+fn test_null_profile() -> PolarsResult<()> {
+    let df = df![
+        "int" => [Some(0), Some(1), None, Some(0)],
+        "flt" => [Some(f64::NAN), Some(0.0), None, None],
+        "str" => [Some(""), Some("a"), Some(""), None],
+    ]?;
+    let out = df.lazy().null_profile()?.collect()?;
+    assert_eq!(
+        Vec::from(out.column("column")?.str()?),
+        &[Some("int"), Some("flt"), Some("str")]
+    );
+    let idx =
+        |name| -> PolarsResult<Vec<Option<IdxSize>>> { Ok(Vec::from(out.column(name)?.idx()?)) };
+    assert_eq!(idx("count")?, &[Some(4), Some(4), Some(4)]);
+    assert_eq!(idx("null_count")?, &[Some(1), Some(2), Some(1)]);
+    assert_eq!(idx("nan_count")?, &[None, Some(1), None]);
+    assert_eq!(idx("empty_count")?, &[None, None, Some(2)]);
+    assert_eq!(idx("zero_count")?, &[Some(2), Some(1), None]);
+    assert_eq!(
+        Vec::from(out.column("null_percentage")?.f64()?),
+        &[Some(25.0), Some(50.0), Some(25.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_validate() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 2, 3, 4],
+        "price" => [Some(10), Some(-1), None, Some(5)],
+        "name" => ["a", "", "c", ""],
+    ]?;
+    let validation = df.lazy().validate([
+        col("price").gt(lit(0)).alias("positive_price"),
+        col("name").neq(lit("")).alias("has_name"),
+    ])?;
+
+    let (clean, rejected) = validation.clone().collect()?;
+    assert_eq!(Vec::from(clean.column("id")?.i32()?), &[Some(1)]);
+    assert_eq!(clean.get_column_names(), &["id", "price", "name"]);
+    assert_eq!(
+        Vec::from(rejected.column("id")?.i32()?),
+        &[Some(2), Some(3), Some(4)]
+    );
+    let failed = rejected.column("failed_rules")?.explode()?;
+    assert_eq!(
+        Vec::from(failed.str()?),
+        &[
+            Some("positive_price"),
+            Some("has_name"),
+            Some("positive_price"),
+            Some("has_name")
+        ]
+    );
+
+    assert!(validation.clean().collect()?.equals_missing(&clean));
+    assert!(validation.rejected().collect()?.equals_missing(&rejected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_random_expressions() -> PolarsResult<()> {
+    let df = df![
+        "id" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let q = df.lazy().select([
+        col("id")
+            .random_uniform(-1.0, 1.0, Some(0))
+            .alias("uniform"),
+        col("id").random_normal(10.0, 1.0, Some(0)).alias("normal"),
+        col("id").random_bernoulli(0.5, Some(0)).alias("bernoulli"),
+    ]);
+    let out = q.clone().collect()?;
+    assert_eq!(out.height(), 1000);
+
+    let uniform = out.column("uniform")?.f64()?;
+    assert!(uniform.min().unwrap() >= -1.0 && uniform.max().unwrap() < 1.0);
+    let mean = out.column("normal")?.mean().unwrap();
+    assert!((mean - 10.0).abs() < 0.5);
+    let n_true = out.column("bernoulli")?.bool()?.sum().unwrap();
+    assert!(n_true > 0 && n_true < 1000);
+
+    // A seeded query is reproducible.
+    assert!(q.collect()?.equals(&out));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "repeat_by")]
+fn test_repeat_by_explode() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "y", "z"],
+        "n" => [Some(2i64), Some(0), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").repeat_by(col("n"))])
+        .collect()?;
+    let lists = out.column("a")?.list()?;
+    assert_eq!(
+        lists
+            .into_iter()
+            .map(|s| s.map(|s| s.len()))
+            .collect::<Vec<_>>(),
+        &[Some(2), Some(0), None]
+    );
+
+    let q = df
+        .clone()
+        .lazy()
+        .select([col("a").repeat_by(col("n")).explode()]);
+    assert!(q.describe_optimized_plan()?.contains("repeat_by_exploded"));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.str()?),
+        &[Some("x"), Some("x"), None, None]
+    );
+
+    // The counts may be an aggregation.
+    let out = df
+        .lazy()
+        .select([col("a").repeat_by(col("n").max()).explode()])
+        .collect()?;
+    assert_eq!(out.height(), 6);
+    Ok(())
+}
+
+#[test]
+fn test_min_max_by() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "c"],
+        "v" => [1, 2, 3, 4, 5, 6],
+        "by" => [Some(3), Some(1), Some(1), None, Some(7), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("v").min_by(col("by"), Default::default()).alias("min"),
+            col("v")
+                .min_by(
+                    col("by"),
+                    MinMaxByOptions {
+                        ties: ByTiePolicy::Last,
+                        ..Default::default()
+                    },
+                )
+                .alias("min_last"),
+            col("v").max_by(col("by"), Default::default()).alias("max"),
+            col("v")
+                .max_by(
+                    col("by"),
+                    MinMaxByOptions {
+                        nulls: ByNullPolicy::Propagate,
+                        ..Default::default()
+                    },
+                )
+                .alias("max_propagate"),
+        ])
+        .collect()?;
+    let column = |name: &str| Vec::from(out.column(name).unwrap().i32().unwrap());
+    assert_eq!(column("min"), &[Some(2), Some(5), None]);
+    assert_eq!(column("min_last"), &[Some(3), Some(5), None]);
+    assert_eq!(column("max"), &[Some(1), Some(5), None]);
+    assert_eq!(column("max_propagate"), &[Some(1), None, None]);
+
+    let out = df
+        .lazy()
+        .select([col("v")
+            .max_by(col("by"), Default::default())
+            .over([col("g")])])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(1), Some(1), Some(1), Some(5), Some(5), None]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chunk_policy() -> PolarsResult<()> {
+    let mut df = df![
+        "g" => [1, 2, 1],
+        "v" => [3, 2, 1],
+    ]?;
+    df.vstack_mut(&df![
+        "g" => [2, 1, 2],
+        "v" => [6, 5, 4],
+    ]?)?;
+    assert_eq!(df.n_chunks(), 2);
+
+    let policy = ChunkPolicy {
+        auto_rechunk: false,
+        ..Default::default()
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .with_chunk_policy(policy)
+        .group_by_stable([col("g")])
+        .agg([col("v").sum()])
+        .sort(["v"], Default::default())
+        .collect()?;
+    assert_eq!(Vec::from(out.column("g")?.i32()?), &[Some(1), Some(2)]);
+    assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(9), Some(12)]);
+
+    let policy = ChunkPolicy {
+        target_chunk_size: Some(4),
+        ..Default::default()
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .with_chunk_policy(policy)
+        .sort(["v"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out.column("v")?.chunk_lengths().collect::<Vec<_>>(),
+        &[4, 2]
+    );
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]
+    );
+
+    let mut lp_arena = Default::default();
+    let mut expr_arena = Default::default();
+    let root = df
+        .lazy()
+        .sort(["v"], Default::default())
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    let sort = lp_arena.get(root);
+    assert_eq!(sort.chunk_requirement(), ChunkRequirement::Preferred);
+    assert!(sort.rechunks_input(&ChunkPolicy::default()));
+    assert!(!sort.rechunks_input(&ChunkPolicy {
+        auto_rechunk: false,
+        ..Default::default()
+    }));
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_engine() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct ScanExec {
+        df: DataFrame,
+        executed: Arc<AtomicUsize>,
+    }
+
+    impl DispatchedExecutor for ScanExec {
+        fn execute(&mut self) -> PolarsResult<DataFrame> {
+            self.executed.fetch_add(1, Ordering::Relaxed);
+            Ok(self.df.clone())
+        }
+    }
+
+    // Claims the in-memory scans without a pushed down predicate.
+    struct ScanEngine {
+        executed: Arc<AtomicUsize>,
+    }
+
+    impl PhysicalPlanDispatch for ScanEngine {
+        fn name(&self) -> &'static str {
+            "SCAN_ENGINE"
+        }
+
+        fn supports(&self, node: Node, lp_arena: &Arena<IR>, _: &Arena<AExpr>) -> bool {
+            matches!(
+                lp_arena.get(node),
+                IR::DataFrameScan {
+                    selection: None,
+                    ..
+                }
+            )
+        }
+
+        fn create_executor(
+            &self,
+            node: Node,
+            lp_arena: &Arena<IR>,
+            _: &Arena<AExpr>,
+        ) -> PolarsResult<Box<dyn DispatchedExecutor>> {
+            let IR::DataFrameScan { df, projection, .. } = lp_arena.get(node) else {
+                unreachable!()
+            };
+            let df = match projection {
+                Some(projection) => df.select(projection.iter())?,
+                None => (**df).clone(),
+            };
+            Ok(Box::new(ScanExec {
+                df,
+                executed: self.executed.clone(),
+            }))
+        }
+    }
+
+    let engine = ScanEngine {
+        executed: Default::default(),
+    };
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([(col("a") + col("b")).alias("c")])
+        .collect_with_engine(&engine)?;
+    assert_eq!(
+        Vec::from(out.column("c")?.i32()?),
+        &[Some(5), Some(7), Some(9)]
+    );
+    assert_eq!(engine.executed.load(Ordering::Relaxed), 1);
+
+    // The predicate is pushed into the scan, which the engine doesn't support.
+    let out = df
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .collect_with_engine(&engine)?;
+    assert_eq!(out.height(), 2);
+    assert_eq!(engine.executed.load(Ordering::Relaxed), 1);
+    Ok(())
+}
+
+#[test]
+fn test_expr_cache() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let df = df![
+        "a" => [1, 2, 3, 4],
+    ]?;
+    let run = |expr_cache: bool| -> PolarsResult<(DataFrame, usize)> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_udf = calls.clone();
+        let doubled = col("a").map(
+            move |s| {
+                calls_udf.fetch_add(1, Ordering::Relaxed);
+                Ok(Some(&s * 2))
+            },
+            GetOutput::same_type(),
+        );
+        let out = df
+            .clone()
+            .lazy()
+            .with_columns([doubled.clone().alias("b")])
+            .filter(doubled.gt(lit(4)))
+            .with_predicate_pushdown(false)
+            .with_expr_cache(expr_cache)
+            .collect()?;
+        Ok((out, calls.load(Ordering::Relaxed)))
+    };
+
+    // User-defined functions may not be deterministic, so they are evaluated every time.
+    let (out, calls) = run(true)?;
+    assert_eq!(calls, 2);
+    let expected = df![
+        "a" => [3, 4],
+        "b" => [6, 8],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let (uncached, calls) = run(false)?;
+    assert_eq!(calls, 2);
+    assert!(uncached.equals(&expected));
+
+    // Only the outermost repeated expression is cached.
+    use crate::physical_plan::planner::repeated_expressions;
+    let doubled = col("a") * lit(2) + lit(1);
+    let q = df
+        .clone()
+        .lazy()
+        .with_columns([doubled.clone().alias("b")])
+        .filter(doubled.clone().gt(lit(4)))
+        .with_predicate_pushdown(false);
+    let (mut lp_arena, mut expr_arena) = (Arena::new(), Arena::new());
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let cached = repeated_expressions(root, &lp_arena, &expr_arena);
+    assert_eq!(cached.into_values().collect::<PlHashSet<_>>().len(), 1);
+    assert!(q.with_expr_cache(true).collect()?.equals(&df![
+        "a" => [2, 3, 4],
+        "b" => [5, 7, 9],
+    ]?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_expr_cache_random() -> PolarsResult<()> {
+    // Random values are drawn again for every evaluation.
+    let random = col("a")
+        .cast(DataType::Float64)
+        .random_uniform(0.0, 1.0, None);
+    let out = df!["a" => (0..100).collect::<Vec<i32>>()]?
+        .lazy()
+        .with_columns([random.clone().alias("b")])
+        .with_columns([random.alias("c")])
+        .with_expr_cache(true)
+        .collect()?;
+    assert!(!out.column("b")?.equals(out.column("c")?));
+    Ok(())
+}
+
+#[test]
+fn test_group_by_apply_schema() -> PolarsResult<()> {
+    let df = df![
+        "g" => [2, 1, 2, 3, 1],
+        "a" => [1, 2, 3, 4, 5],
+    ]?;
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("g", DataType::Int32),
+        Field::new("a", DataType::Int32),
+    ]));
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .apply(|df| Ok(df.head(Some(1))), schema.clone())
+        .collect()?;
+    let expected = df![
+        "g" => [2, 1, 3],
+        "a" => [1, 2, 4],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("g").gt(lit(5)))
+        .group_by([col("g")])
+        .apply(Ok, schema.clone())
+        .collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert_eq!(out.height(), 0);
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by([col("g")])
+        .apply(|df| df.select(["a"]), schema.clone())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a"]);
+
+    let options = MapGroupsOptions {
+        sequential: true,
+        validate_output: true,
+    };
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .apply_with_options(|df| df.select(["a"]), schema, options)
+        .collect();
+    assert!(matches!(out, Err(PolarsError::SchemaMismatch(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "list_rolling")]
+fn test_list_rolling() -> PolarsResult<()> {
+    let df = df![
+        "a" => [
+            Series::new("", &[Some(1i32), Some(2), Some(3), Some(4)]),
+            Series::new("", &[Some(5i32), None, Some(7)]),
+        ]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").list().rolling_sum(2, 2).alias("sum"),
+            col("a").list().rolling_mean(2, 1).alias("mean"),
+            col("a").list().rolling_max(3, 1).alias("max"),
+        ])
+        .collect()?;
+
+    let sum = out.column("sum")?.explode()?;
+    assert_eq!(
+        Vec::from(sum.i32()?),
+        &[None, Some(3), Some(5), Some(7), None, None, None]
+    );
+    let mean = out.column("mean")?.explode()?;
+    assert_eq!(
+        Vec::from(mean.f64()?),
+        &[
+            Some(1.0),
+            Some(1.5),
+            Some(2.5),
+            Some(3.5),
+            Some(5.0),
+            Some(5.0),
+            Some(7.0)
+        ]
+    );
+    let max = out.column("max")?.explode()?;
+    assert_eq!(
+        Vec::from(max.i32()?),
+        &[
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(5),
+            Some(7)
+        ]
+    );
+
+    // Small integers are summed as `Int64`, and a `NaN` only affects the windows with it.
+    let df = df![
+        "a" => [Series::new("", &[100i8, 100, 100])],
+        "b" => [Series::new("", &[1.0, f64::NAN, 2.0, 3.0])],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").list().rolling_sum(2, 1),
+            col("b").list().rolling_sum(2, 1),
+        ])
+        .collect()?;
+    let a = out.column("a")?.explode()?;
+    assert_eq!(Vec::from(a.i64()?), &[Some(100), Some(200), Some(200)]);
+    let b = out.column("b")?.explode()?;
+    let b = b.f64()?;
+    assert_eq!(b.get(0), Some(1.0));
+    assert!(b.get(1).unwrap().is_nan() && b.get(2).unwrap().is_nan());
+    assert_eq!(b.get(3), Some(5.0));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "approx_unique",
+    feature = "cross_join",
+    feature = "semi_anti_join"
+))]
+fn test_join_max_expansion() -> PolarsResult<()> {
+    // Every row on the left matches every row on the right.
+    let left = df![
+        "key" => vec![1i32; 100],
+        "a" => (0..100i32).collect::<Vec<_>>(),
+    ]?;
+    let right = df![
+        "key" => vec![1i32; 100],
+        "b" => (0..100i32).collect::<Vec<_>>(),
+    ]?;
+    let join = |max_factor, how: JoinType| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .on([col("key")])
+            .how(how)
+            .max_expansion(max_factor, JoinExpansionAction::Raise)
+            .finish()
+            .collect()
+    };
+
+    assert!(join(10, JoinType::Inner).is_err());
+    assert!(join(10, JoinType::Cross).is_err());
+    assert_eq!(join(100, JoinType::Inner)?.height(), 10_000);
+    // A semi join never produces more rows than its left input.
+    assert_eq!(join(1, JoinType::Semi)?.height(), 100);
+
+    // Distinct keys don't expand.
+    let right = df![
+        "key" => (0..100i32).collect::<Vec<_>>(),
+    ]?;
+    let out = left
+        .lazy()
+        .join_builder()
+        .with(right.lazy())
+        .on([col("key")])
+        .max_expansion(1, JoinExpansionAction::Raise)
+        .finish()
+        .collect()?;
+    assert_eq!(out.height(), 100);
+    Ok(())
+}
+
+#[test]
+fn test_join_drop_key_expressions() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1i32, 2, 3],
+        "x" => ["p", "q", "r"],
+    ]?;
+    let right = df![
+        "b" => [2i32, 4, 6],
+        "y" => [10i32, 20, 30],
+    ]?;
+    let join = |how: JoinType, drop: bool| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on([col("a") * lit(2)])
+            .right_on([col("b")])
+            .how(how)
+            .coalesce(JoinCoalesce::CoalesceColumns)
+            .drop_key_expressions(drop)
+            .finish()
+            .sort(["x"], Default::default())
+            .collect()
+    };
+
+    // By default the key expressions replace the columns they are named after.
+    let out = join(JoinType::Left, false)?;
+    assert_eq!(out.get_column_names(), &["a", "x", "y"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        [Some(2), Some(4), Some(6)]
+    );
+
+    let out = join(JoinType::Left, true)?;
+    assert_eq!(out.get_column_names(), &["a", "x", "y"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        [Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("y")?.i32()?),
+        [Some(10), Some(20), Some(30)]
+    );
+
+    // The coalesced keys of an outer join are kept, under a free name.
+    let out = join(JoinType::Outer, true)?;
+    assert_eq!(out.get_column_names(), &["a", "x", "a_right", "y"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        [Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("a_right")?.i32()?),
+        [Some(2), Some(4), Some(6)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_compiled_expr_batches() -> PolarsResult<()> {
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int32),
+        Field::new("b", DataType::Float64),
+    ]));
+    let expr = CompiledExpr::try_new((col("a") + col("b")).alias("c"), schema)?;
+    assert_eq!(expr.output_field(), &Field::new("c", DataType::Float64));
+
+    let out = expr.evaluate_columns(vec![
+        Series::new("a", [1i32, 2]),
+        Series::new("b", [0.5, 1.5]),
+    ])?;
+    assert_eq!(out.name(), "c");
+    assert_eq!(Vec::from(out.f64()?), [Some(1.5), Some(3.5)]);
+
+    let batch = df![
+        "a" => [10i32, 20, 30],
+        "b" => [1.0, 2.0, 3.0],
+    ]?;
+    let out = expr.evaluate(&batch)?;
+    assert_eq!(Vec::from(out.f64()?), [Some(11.0), Some(22.0), Some(33.0)]);
+
+    // The columns of a batch must match the schema.
+    let batch = df![
+        "a" => [1i64],
+        "b" => [1.0],
+    ]?;
+    assert!(expr.evaluate(&batch).is_err());
+
+    let schema = Arc::new(batch.schema());
+    assert!(CompiledExpr::try_new(col("*"), schema).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "upsample")]
+fn test_lazy_upsample_complete() -> PolarsResult<()> {
+    use polars_time::Duration;
+
+    let df = df![
+        "t" => [1i64, 3, 2, 5],
+        "id" => ["a", "a", "b", "b"],
+        "value" => [Some(1), None, Some(2), Some(5)],
+    ]?;
+    let every = Duration::parse("1i");
+    let out = df
+        .clone()
+        .lazy()
+        .complete("t", every, ["id"], Some(FillNullStrategy::Forward(None)))
+        .collect()?;
+    let t = out
+        .column("t")?
+        .i64()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(t, [1, 2, 3, 2, 3, 4, 5]);
+    let id = out
+        .column("id")?
+        .str()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(id, ["a", "a", "a", "b", "b", "b", "b"]);
+    // The nulls of the existing rows aren't filled.
+    let value = out.column("value")?.i32()?.into_iter().collect::<Vec<_>>();
+    assert_eq!(
+        value,
+        [Some(1), Some(1), None, Some(2), Some(2), Some(2), Some(5)]
+    );
+
+    // The inserted rows are null in all other columns.
+    let out = df
+        .lazy()
+        .upsample(["id"], "t", every, Duration::parse("0ns"))
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["t", "id", "value"]);
+    assert_eq!(out.height(), 7);
+    assert_eq!(out.column("id")?.null_count(), 3);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "dtype-struct", feature = "dtype-date"))]
+fn test_struct_json_encode_options() -> PolarsResult<()> {
+    let df = df![
+        "text" => [Some("a long \"quoted\" text\n"), None],
+        "date" => [Some(19751i32), None],
+    ]?;
+    let encode = |options: JsonEncodeOptions| -> PolarsResult<Vec<Option<String>>> {
+        let out = df
+            .clone()
+            .lazy()
+            .select([
+                as_struct(vec![col("text"), col("date").cast(DataType::Date)])
+                    .struct_()
+                    .json_encode_with_options(options)
+                    .alias("json"),
+            ])
+            .collect()?;
+        Ok(out
+            .column("json")?
+            .str()?
+            .into_iter()
+            .map(|v| v.map(String::from))
+            .collect())
+    };
+
+    assert_eq!(
+        encode(Default::default())?,
+        [
+            Some(r#"{"text":"a long \"quoted\" text\n","date":"2024-01-29"}"#.to_string()),
+            Some(r#"{"text":null,"date":null}"#.to_string()),
+        ]
+    );
+    let options = JsonEncodeOptions {
+        date_format: Some("%d/%m/%Y".into()),
+        skip_nulls: true,
+        sort_keys: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        encode(options)?,
+        [
+            Some(r#"{"date":"29/01/2024","text":"a long \"quoted\" text\n"}"#.to_string()),
+            Some("{}".to_string()),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "serde"))]
+fn test_struct_json_encode_serde() -> PolarsResult<()> {
+    // Plans with the default options are serialized as before the options existed.
+    let expr = col("a").struct_().json_encode();
+    let json = serde_json::to_string(&expr).unwrap();
+    assert!(json.contains(r#""StructExpr":"JsonEncode""#));
+    let expr = col("a")
+        .struct_()
+        .json_encode_with_options(Default::default());
+    assert_eq!(serde_json::to_string(&expr).unwrap(), json);
+
+    let options = JsonEncodeOptions {
+        sort_keys: true,
+        ..Default::default()
+    };
+    let expr = col("a").struct_().json_encode_with_options(options.clone());
+    let json = serde_json::to_string(&expr).unwrap();
+    assert_eq!(serde_json::from_str::<Expr>(&json).unwrap(), expr);
+    // Missing options take their defaults.
+    let partial = serde_json::from_str::<JsonEncodeOptions>(r#"{"sort_keys":true}"#).unwrap();
+    assert_eq!(partial, options);
+    Ok(())
+}
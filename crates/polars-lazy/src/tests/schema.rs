@@ -36,3 +36,171 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_column_metadata_through_plan() -> PolarsResult<()> {
+    let df = df![
+        "weight" => [1.0, 2.0],
+        "id" => [1, 2],
+    ]?;
+    let metadata = FieldMetadata::from([("unit".to_string(), "kg".to_string())]);
+
+    let q = df
+        .lazy()
+        .with_column_metadata("weight", metadata.clone())
+        .rename(["weight"], ["mass"])
+        .select([col("mass").alias("m"), col("id")]);
+
+    let schema = q.clone().schema()?;
+    assert_eq!(schema.get_metadata("m"), Some(&metadata));
+    assert_eq!(schema.get_metadata("id"), None);
+
+    let out = q.clone().collect()?;
+    assert_eq!(out.column("m")?.metadata().as_deref(), Some(&metadata));
+
+    let out = q
+        .select([col_meta("m", "unit"), col_meta("id", "unit")])
+        .collect()?;
+    assert_eq!(out.column("m")?.str()?.get(0), Some("kg"));
+    assert_eq!(out.column("id")?.str()?.get(0), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_assert_schema() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i64, 2],
+        "b" => [Some("x"), None],
+    ]?;
+    let expected = Schema::from_iter([
+        Field::new("b", DataType::String),
+        Field::new("a", DataType::Int64),
+    ]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .assert_schema(&expected, SchemaAssertMode::Exact)?
+        .collect()?;
+    assert!(out.equals_missing(&df));
+
+    let err = df
+        .clone()
+        .lazy()
+        .assert_schema(&expected, SchemaAssertMode::Ordered)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("order: expected [b, a], got [a, b]"));
+
+    let drifted = df
+        .clone()
+        .lazy()
+        .with_columns([col("a").cast(DataType::Float64), lit(1).alias("c")]);
+    let err = drifted
+        .assert_schema(&expected, SchemaAssertMode::Exact)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("~ a: expected i64, got f64"));
+    assert!(err.contains("+ c: i32"));
+
+    let options = SchemaAssertOptions {
+        mode: SchemaAssertMode::Subset,
+        non_null: vec!["b".into()],
+    };
+    assert!(df
+        .lazy()
+        .assert_schema(&expected, options)?
+        .collect()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_selectors() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1i64, 2],
+        "weight" => [1.0, 2.0],
+        "name" => ["a", "b"],
+        "flag" => [true, false],
+        "height" => [3.0, 4.0],
+    ]?;
+    let metadata = FieldMetadata::from([("unit".to_string(), "kg".to_string())]);
+    let lf = df.lazy().with_column_metadata("weight", metadata);
+    let schema = lf.clone().schema()?;
+
+    let expand = |s: Selector| -> PolarsResult<Vec<String>> {
+        Ok(s.expand(&schema)?.iter().map(|n| n.to_string()).collect())
+    };
+    assert_eq!(
+        expand(Selector::numeric().exclude(["id"]))?,
+        ["weight", "height"]
+    );
+    assert_eq!(
+        expand(Selector::float() ^ Selector::by_name(["weight", "name"]))?,
+        ["height", "name"]
+    );
+    assert_eq!(
+        expand(!(Selector::numeric() | Selector::boolean()))?,
+        ["name"]
+    );
+    assert_eq!(
+        expand(Selector::by_metadata("unit", Some("kg")) & Selector::float())?,
+        ["weight"]
+    );
+    assert!(expand(Selector::by_metadata("unit", Some("g")))?.is_empty());
+    #[cfg(feature = "regex")]
+    {
+        assert_eq!(expand(Selector::matches("ght$"))?, ["weight", "height"]);
+        assert_eq!(expand(Selector::matches("^na|^id$"))?, ["id", "name"]);
+        assert!(expand(Selector::matches("^eight"))?.is_empty());
+    }
+
+    let selected = Selector::by_dtype([DataType::Float64]).select_schema(&schema)?;
+    assert_eq!(
+        selected.iter_names().collect::<Vec<_>>(),
+        ["weight", "height"]
+    );
+
+    // Selectors are resolved against the schema at the point of the plan.
+    let out = lf
+        .with_column(col("id").cast(DataType::Float64))
+        .select([Selector::float().into()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["id", "weight", "height"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_collect_empty() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+
+    // The map function declares its output type and is never called.
+    let q = df
+        .lazy()
+        .with_column(
+            col("a")
+                .map(
+                    |_| panic!("the data is touched"),
+                    GetOutput::from_type(DataType::Float64),
+                )
+                .alias("c"),
+        )
+        .filter(col("a").gt(lit(1)));
+    let out = q.clone().collect_empty()?;
+    assert_eq!(out.height(), 0);
+    assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(out.column("c")?.dtype(), &DataType::Float64);
+    assert_eq!(q.clone().fetch_schema_only()?.as_ref(), &out.schema());
+
+    // Errors of the plan are raised.
+    let result = q.select([col("d")]).collect_empty();
+    assert!(result.is_err());
+    Ok(())
+}
@@ -61,6 +61,49 @@ fn test_streaming_csv() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_melt() -> PolarsResult<()> {
+    let q = get_csv_file();
+
+    let args = MeltArgs {
+        id_vars: vec!["category".into()],
+        value_vars: vec!["calories".into(), "sugars_g".into()],
+        streamable: true,
+        ..Default::default()
+    };
+    let q = q
+        .melt(args)
+        .group_by([col("category"), col("variable")])
+        .agg([col("value").sum(), col("value").count().alias("count")])
+        .sort(["category", "variable"], Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_streaming_ndjson() -> PolarsResult<()> {
+    let get_ndjson_file = || {
+        LazyJsonLineReader::new("../../examples/datasets/foods1.ndjson")
+            // Decode multiple byte ranges per batch.
+            .with_batch_size(std::num::NonZeroUsize::new(2))
+            .finish()
+            .unwrap()
+    };
+
+    let q = get_ndjson_file()
+        .select([col("sugars_g"), col("calories")])
+        .group_by([col("sugars_g")])
+        .agg([col("calories").sum()])
+        .sort(["sugars_g"], Default::default());
+    assert_streaming_with_default(q, true, false);
+
+    let q = get_ndjson_file().select([col("category")]).slice(0, 7);
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_glob() -> PolarsResult<()> {
     let q = get_csv_glob();
@@ -144,6 +187,53 @@ fn test_streaming_unique() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_unique_by_sorting() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(3), Some(1), Some(2), Some(1), Some(3), None, None, Some(4), Some(3)],
+        "b" => [Some(1), Some(2), None, Some(4), Some(5), Some(6), Some(6), Some(8), Some(5)],
+    ]?;
+    for keep in [
+        UniqueKeepStrategy::First,
+        UniqueKeepStrategy::Last,
+        UniqueKeepStrategy::None,
+        UniqueKeepStrategy::MaxBy,
+        UniqueKeepStrategy::MinBy,
+    ] {
+        let q = df
+            .clone()
+            .lazy()
+            .unique_by(Some(vec!["a".into()]), keep, Some("b"), true);
+        assert_streaming_with_default(q, true, false);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_unique_any_slice() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..100_000i32).map(|i| i % 10).collect::<Vec<_>>(),
+        "b" => (0..100_000i32).map(|i| i % 20).collect::<Vec<_>>(),
+    ]?;
+    let q = df
+        .lazy()
+        .unique(Some(vec!["a".into()]), UniqueKeepStrategy::Any)
+        .slice(0, 3);
+
+    for streaming in [false, true] {
+        let out = q.clone().with_streaming(streaming).collect()?;
+        assert_eq!(out.height(), 3);
+        assert_eq!(out.column("a")?.n_unique()?, 3);
+        // Every row is a row of the input.
+        let a = out.column("a")?.i32()?;
+        let b = out.column("b")?.i32()?;
+        for (a, b) in a.into_no_null_iter().zip(b.into_no_null_iter()) {
+            assert_eq!(b % 10, a);
+        }
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_aggregate_slice() -> PolarsResult<()> {
     let q = get_parquet_file();
@@ -273,6 +363,20 @@ fn test_streaming_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_top_k() -> PolarsResult<()> {
+    let q = get_csv_glob()
+        .sort_by_exprs([all()], SortMultipleOptions::default())
+        .slice(5, 10);
+    assert_streaming_with_default(q.clone(), true, false);
+
+    // Compare with a full sort.
+    let out = q.clone().with_streaming(true).collect()?;
+    let expected = q.with_slice_pushdown(false).collect()?;
+    assert_eq!(out, expected);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_partial() -> PolarsResult<()> {
     let lf_left = df![
@@ -424,3 +528,233 @@ fn test_streaming_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "range")]
+fn test_streaming_range() -> PolarsResult<()> {
+    let q = LazyFrame::range(0, 100_000, 3)?
+        .filter(
+            col("range")
+                .gt_eq(lit(30_000i64))
+                .and(col("range").lt(lit(30_010i64))),
+        )
+        .select([col("range")]);
+    assert_streaming_with_default(q.clone(), true, false);
+
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("range")?.i64()?),
+        &[Some(30_000), Some(30_003), Some(30_006), Some(30_009)]
+    );
+
+    let out = LazyFrame::range(10, 0, -4)?.limit(2).collect()?;
+    assert_eq!(Vec::from(out.column("range")?.i64()?), &[Some(10), Some(6)]);
+    Ok(())
+}
+
+#[test]
+fn test_scan_arrow_batches() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => (0..5).collect::<Vec<i32>>(),
+        "b" => ["a", "b", "c", "d", "e"],
+    ]?;
+    df.vstack_mut(&df![
+        "a" => (5..12).collect::<Vec<i32>>(),
+        "b" => ["f", "g", "h", "i", "j", "k", "l"],
+    ]?)?;
+    let schema = df.schema().to_arrow(true);
+    let batches = df.iter_chunks(true).collect::<Vec<_>>();
+    assert_eq!(batches.len(), 2);
+
+    let lf = LazyFrame::scan_arrow_batches(schema, batches)?;
+    assert_eq!(lf.clone().collect()?, df);
+
+    // The slice spans both batches.
+    let q = lf.clone().select([col("a")]).slice(3, 4);
+    let out = q.clone().collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(3), Some(4), Some(5), Some(6)]
+    );
+
+    let q = lf.filter(col("a").gt(lit(2))).select([col("b")]);
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "range")]
+fn test_streaming_value_counts() -> PolarsResult<()> {
+    // Every fourth value is a zero, the other values are unique.
+    let is_zero = (col("range") % lit(4i64)).eq(lit(0i64));
+    let lf = LazyFrame::range(0, 40_000, 1)?.select([when(is_zero)
+        .then(lit(0i64))
+        .otherwise(col("range"))
+        .alias("v")]);
+
+    let options = ValueCountsOptions {
+        top_n: Some(1),
+        max_counters: None,
+    };
+    let q = lf.clone().value_counts("v", options);
+    assert!(optimization_checks::has_pipeline(
+        q.clone().with_streaming(true)
+    ));
+    let out = q.with_streaming(true).collect()?;
+    assert_eq!(Vec::from(out.column("v")?.i64()?), &[Some(0)]);
+    assert_eq!(
+        out.column("count")?.get(0)?.extract::<IdxSize>(),
+        Some(10_000)
+    );
+
+    // With enough counters the estimates are exact.
+    let options = ValueCountsOptions {
+        top_n: Some(1),
+        max_counters: Some(100_000),
+    };
+    let out = lf.clone().value_counts("v", options).collect()?;
+    assert_eq!(
+        out.column("count")?.get(0)?.extract::<IdxSize>(),
+        Some(10_000)
+    );
+    assert_eq!(out.column("error")?.get(0)?.extract::<IdxSize>(), Some(0));
+
+    // Otherwise the true count lies between `count - error` and `count`.
+    let options = ValueCountsOptions {
+        top_n: Some(1),
+        max_counters: Some(8),
+    };
+    let out = lf
+        .value_counts("v", options)
+        .with_streaming(true)
+        .collect()?;
+    assert_eq!(Vec::from(out.column("v")?.i64()?), &[Some(0)]);
+    let count = out.column("count")?.get(0)?.extract::<IdxSize>().unwrap();
+    let error = out.column("error")?.get(0)?.extract::<IdxSize>().unwrap();
+    assert!(count - error <= 10_000 && 10_000 <= count);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "unbounded_scan")]
+fn test_streaming_unbounded_scan() -> PolarsResult<()> {
+    use std::collections::VecDeque;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    struct Queue {
+        polls: VecDeque<Option<(Vec<i32>, i64)>>,
+        commits: Arc<AtomicUsize>,
+    }
+
+    impl UnboundedSource for Queue {
+        fn schema(&self) -> SchemaRef {
+            Arc::new(Schema::from_iter([Field::new("a", DataType::Int32)]))
+        }
+
+        fn poll_next(&mut self, _max_rows: usize) -> PolarsResult<SourcePoll> {
+            Ok(match self.polls.pop_front() {
+                Some(Some((values, watermark))) => SourcePoll::Ready(MicroBatch {
+                    df: df!["a" => values]?,
+                    watermark: Some(watermark),
+                }),
+                Some(None) => SourcePoll::Pending,
+                None => SourcePoll::Finished,
+            })
+        }
+
+        fn commit(&mut self) -> PolarsResult<()> {
+            self.commits.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+
+    let commits = Arc::new(AtomicUsize::new(0));
+    let source = Queue {
+        polls: VecDeque::from([
+            Some((vec![1, 2], 10)),
+            None,
+            Some((vec![3], 20)),
+            None,
+            None,
+            Some((vec![4, 5, 6], 30)),
+        ]),
+        commits: commits.clone(),
+    };
+    let options = UnboundedScanOptions {
+        poll_interval: Duration::from_millis(1),
+        watermark_column: Some("watermark".into()),
+        ..Default::default()
+    };
+    let (lf, commit) = LazyFrame::scan_unbounded(Box::new(source), options)?;
+    let out = lf
+        .filter(col("a").neq(lit(2)))
+        .collect()?
+        .sort(["a"], Default::default())?;
+
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(3), Some(4), Some(5), Some(6)]
+    );
+    assert_eq!(
+        Vec::from(out.column("watermark")?.i64()?),
+        &[Some(10), Some(20), Some(30), Some(30), Some(30)]
+    );
+    // The micro-batches are only committed once the results are written.
+    assert_eq!(commits.load(Ordering::Relaxed), 0);
+    commit.commit()?;
+    assert_eq!(commits.load(Ordering::Relaxed), 1);
+
+    // The micro-batches of a query that fails are not committed.
+    let commits = Arc::new(AtomicUsize::new(0));
+    let source = Queue {
+        polls: VecDeque::from([Some((vec![-1, 2], 10))]),
+        commits: commits.clone(),
+    };
+    let (lf, _commit) = LazyFrame::scan_unbounded(Box::new(source), Default::default())?;
+    let out = lf
+        .with_column(col("a").strict_cast(DataType::UInt32).alias("b"))
+        .collect();
+    assert!(out.is_err());
+    assert_eq!(commits.load(Ordering::Relaxed), 0);
+
+    // A source with no records finishes once it is idle for too long.
+    let source = Queue {
+        polls: std::iter::repeat_with(|| None).take(1000).collect(),
+        commits: Arc::new(AtomicUsize::new(0)),
+    };
+    let options = UnboundedScanOptions {
+        poll_interval: Duration::from_millis(1),
+        idle_timeout: Some(Duration::from_millis(5)),
+        ..Default::default()
+    };
+    let (lf, _) = LazyFrame::scan_unbounded(Box::new(source), options)?;
+    assert_eq!(lf.collect()?.height(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_streaming_random_expressions() -> PolarsResult<()> {
+    let df = df![
+        "id" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    // Every input is a source of its own, so the halves are drawn for different batches.
+    let q = concat([df.clone().lazy(), df.lazy()], Default::default())?
+        .select([col("id").random_uniform(0.0, 1.0, Some(0)).alias("uniform")])
+        .with_streaming(true);
+    assert!(optimization_checks::is_pipeline(q.clone()));
+
+    let out = q.clone().collect()?;
+    assert_eq!(out.height(), 2000);
+    assert!(!out.slice(0, 1000).equals(&out.slice(1000, 1000)));
+    // The seeds of the batches don't depend on the other queries that ran before.
+    let _ = df![
+        "id" => [1, 2, 3],
+    ]?
+    .lazy()
+    .with_streaming(true)
+    .collect()?;
+    assert!(q.collect()?.equals(&out));
+    Ok(())
+}
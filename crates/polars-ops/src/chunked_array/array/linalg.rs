@@ -0,0 +1,226 @@
+use arrow::array::{Array, FixedSizeListArray};
+use arrow::bitmap::Bitmap;
+use arrow::legacy::utils::CustomIterTools;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// The aggregation that reduces an axis of the matrices of an `Array(Array)` column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ArrayAxisAgg {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+impl ArrayAxisAgg {
+    fn reduce(self, values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+        let mut count = 0usize;
+        let out = values
+            .flatten()
+            .inspect(|_| count += 1)
+            .reduce(|a, b| match self {
+                ArrayAxisAgg::Sum | ArrayAxisAgg::Mean => a + b,
+                ArrayAxisAgg::Min => a.min(b),
+                ArrayAxisAgg::Max => a.max(b),
+            });
+        match self {
+            ArrayAxisAgg::Mean => out.map(|sum| sum / count as f64),
+            _ => out,
+        }
+    }
+}
+
+/// The shape `(rows, columns)` of the matrices of an `Array(Array)` dtype.
+fn matrix_shape(dtype: &DataType) -> PolarsResult<(usize, usize)> {
+    match dtype {
+        DataType::Array(inner, rows) => match inner.as_ref() {
+            DataType::Array(_, columns) => Ok((*rows, *columns)),
+            _ => polars_bail!(InvalidOperation: "expected a nested array dtype, got `{}`", dtype),
+        },
+        _ => polars_bail!(InvalidOperation: "expected a nested array dtype, got `{}`", dtype),
+    }
+}
+
+/// The dtype of the mat-vec product of `matrix` with arrays.
+pub fn matmul_output_dtype(matrix: &DataType) -> PolarsResult<DataType> {
+    let (rows, _) = matrix_shape(matrix)?;
+    Ok(DataType::Array(Box::new(DataType::Float64), rows))
+}
+
+/// The dtype of the reduction of `axis` of the matrices of `dtype`.
+pub fn reduce_axis_output_dtype(dtype: &DataType, axis: u8) -> PolarsResult<DataType> {
+    let (rows, columns) = matrix_shape(dtype)?;
+    let width = match axis {
+        0 => columns,
+        1 => rows,
+        _ => polars_bail!(OutOfBounds: "axis {} is out of bounds for a matrix", axis),
+    };
+    Ok(DataType::Array(Box::new(DataType::Float64), width))
+}
+
+/// The values of the subarrays of `ca` as `f64`, flattened over all nesting levels.
+///
+/// The values of null subarrays are `None`.
+fn flat_values(ca: &ArrayChunked) -> PolarsResult<Vec<Option<f64>>> {
+    let inner = ca.get_inner();
+    let mut values: Vec<Option<f64>> = match inner.dtype() {
+        DataType::Array(_, _) => flat_values(inner.array()?)?,
+        dt if dt.is_numeric() => inner.cast(&DataType::Float64)?.f64()?.into_iter().collect(),
+        dt => {
+            polars_bail!(InvalidOperation: "linear algebra is not supported for arrays of dtype `{}`", dt)
+        },
+    };
+    if ca.null_count() > 0 {
+        let stride = values.len() / ca.len();
+        for (i, is_null) in ca.is_null().into_no_null_iter().enumerate() {
+            if is_null {
+                values[i * stride..(i + 1) * stride].fill(None);
+            }
+        }
+    }
+    Ok(values)
+}
+
+fn check_vectors(ca: &ArrayChunked) -> PolarsResult<()> {
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_numeric(),
+        InvalidOperation: "expected arrays of numeric dtype, got arrays of dtype `{}`", inner_dtype
+    );
+    Ok(())
+}
+
+fn check_broadcast(ca: &ArrayChunked, other: &ArrayChunked) -> PolarsResult<()> {
+    polars_ensure!(
+        other.len() == 1 || other.len() == ca.len(),
+        ShapeMismatch: "cannot combine arrays of length {} with arrays of length {}", ca.len(), other.len()
+    );
+    Ok(())
+}
+
+/// A row is valid if it is valid in `ca` and in the (broadcast) `other`.
+fn combined_validity(ca: &ArrayChunked, other: &ArrayChunked) -> Option<Bitmap> {
+    if ca.null_count() == 0 && other.null_count() == 0 {
+        return None;
+    }
+    let other = other.is_not_null();
+    let validity = ca
+        .is_not_null()
+        .into_no_null_iter()
+        .enumerate()
+        .map(|(i, valid)| valid && other.get(if other.len() == 1 { 0 } else { i }).unwrap())
+        .collect::<Bitmap>();
+    Some(validity)
+}
+
+/// Build an array column of `Float64` from its flattened values.
+fn float_array(
+    name: &str,
+    values: Vec<Option<f64>>,
+    width: usize,
+    validity: Option<Bitmap>,
+) -> ArrayChunked {
+    let values: Float64Chunked = values.into_iter().collect_trusted();
+    let values = values.rechunk().downcast_iter().next().unwrap().clone();
+    let arr = FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(ArrowDataType::Float64, width),
+        values.boxed(),
+        validity,
+    );
+    ArrayChunked::with_chunk(name, arr)
+}
+
+/// The dot product of every array of `ca` with the array of `other` in the same row.
+///
+/// An `other` of length 1 is broadcast to all rows.
+pub(super) fn array_dot(ca: &ArrayChunked, other: &ArrayChunked) -> PolarsResult<Float64Chunked> {
+    let width = ca.width();
+    polars_ensure!(
+        other.width() == width,
+        ShapeMismatch: "cannot compute the dot product of arrays of width {} and {}", width, other.width()
+    );
+    check_vectors(ca)?;
+    check_vectors(other)?;
+    check_broadcast(ca, other)?;
+    let lhs = flat_values(ca)?;
+    let rhs = flat_values(other)?;
+    let rhs_stride = if other.len() == 1 { 0 } else { width };
+    let out: Float64Chunked = (0..ca.len())
+        .map(|i| {
+            let lhs = &lhs[i * width..(i + 1) * width];
+            let rhs = &rhs[i * rhs_stride..i * rhs_stride + width];
+            lhs.iter()
+                .zip(rhs)
+                .map(|(a, b)| Some((*a)? * (*b)?))
+                .sum::<Option<f64>>()
+        })
+        .collect_trusted();
+    Ok(out.with_name(ca.name()))
+}
+
+/// Multiply the matrices of `matrix` with the arrays of `ca` as column vectors.
+///
+/// A `matrix` of length 1 is broadcast to all rows.
+pub(super) fn array_matmul(ca: &ArrayChunked, matrix: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+    let (rows, columns) = matrix_shape(matrix.dtype())?;
+    polars_ensure!(
+        columns == ca.width(),
+        ShapeMismatch: "cannot multiply a matrix of shape ({}, {}) with arrays of width {}", rows, columns, ca.width()
+    );
+    check_vectors(ca)?;
+    check_broadcast(ca, matrix)?;
+    let vectors = flat_values(ca)?;
+    let matrices = flat_values(matrix)?;
+    let matrix_stride = if matrix.len() == 1 { 0 } else { rows * columns };
+    let values = (0..ca.len())
+        .flat_map(|i| {
+            let vector = &vectors[i * columns..(i + 1) * columns];
+            let matrix = &matrices[i * matrix_stride..i * matrix_stride + rows * columns];
+            matrix.chunks_exact(columns).map(move |row| {
+                row.iter()
+                    .zip(vector)
+                    .map(|(a, b)| Some((*a)? * (*b)?))
+                    .sum::<Option<f64>>()
+            })
+        })
+        .collect();
+    let validity = combined_validity(ca, matrix);
+    Ok(float_array(ca.name(), values, rows, validity))
+}
+
+/// Reduce `axis` of the matrices of `ca`, skipping null values.
+///
+/// Axis 0 reduces every column of a matrix, axis 1 reduces every row.
+pub(super) fn array_reduce_axis(
+    ca: &ArrayChunked,
+    agg: ArrayAxisAgg,
+    axis: u8,
+) -> PolarsResult<ArrayChunked> {
+    let (rows, columns) = matrix_shape(ca.dtype())?;
+    let width = match reduce_axis_output_dtype(ca.dtype(), axis)? {
+        DataType::Array(_, width) => width,
+        _ => unreachable!(),
+    };
+    let matrices = flat_values(ca)?;
+    let values = (0..ca.len())
+        .flat_map(|i| {
+            let matrix = &matrices[i * rows * columns..(i + 1) * rows * columns];
+            (0..width).map(move |j| match axis {
+                0 => agg.reduce((0..rows).map(|r| matrix[r * columns + j])),
+                _ => agg.reduce(matrix[j * columns..(j + 1) * columns].iter().copied()),
+            })
+        })
+        .collect();
+    let validity = ca
+        .rechunk()
+        .downcast_iter()
+        .next()
+        .unwrap()
+        .validity()
+        .cloned();
+    Ok(float_array(ca.name(), values, width, validity))
+}
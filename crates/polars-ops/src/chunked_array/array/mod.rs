@@ -4,12 +4,16 @@ mod count;
 mod dispersion;
 mod get;
 mod join;
+#[cfg(feature = "array_linalg")]
+mod linalg;
 mod min_max;
 mod namespace;
 mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
 
+#[cfg(feature = "array_linalg")]
+pub use linalg::{matmul_output_dtype, reduce_axis_output_dtype, ArrayAxisAgg};
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
 #[cfg(feature = "array_to_struct")]
@@ -138,6 +138,24 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    #[cfg(feature = "array_linalg")]
+    fn array_dot(&self, other: &ArrayChunked) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_array();
+        linalg::array_dot(ca, other)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_matmul(&self, matrix: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        linalg::array_matmul(ca, matrix)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_reduce_axis(&self, agg: ArrayAxisAgg, axis: u8) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        linalg::array_reduce_axis(ca, agg, axis)
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
@@ -8,6 +8,8 @@ mod dispersion;
 pub(crate) mod hash;
 mod min_max;
 mod namespace;
+#[cfg(feature = "list_rolling")]
+mod rolling;
 #[cfg(feature = "list_sets")]
 mod sets;
 mod sum_mean;
@@ -19,6 +21,8 @@ pub use count::*;
 #[cfg(not(feature = "list_count"))]
 use count::*;
 pub use namespace::*;
+#[cfg(feature = "list_rolling")]
+pub use rolling::{ListRollingMethod, ListRollingOptions};
 #[cfg(feature = "list_sets")]
 pub use sets::*;
 #[cfg(feature = "list_to_struct")]
@@ -289,6 +289,14 @@ pub trait ListNameSpaceImpl: AsList {
         })
     }
 
+    #[cfg(feature = "list_rolling")]
+    /// Apply a rolling aggregation within every list, treating every list as a series of its
+    /// own.
+    fn lst_rolling(&self, options: ListRollingOptions) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        rolling::rolling_list(ca, options)
+    }
+
     #[cfg(feature = "diff")]
     fn lst_diff(&self, n: i64, null_behavior: NullBehavior) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
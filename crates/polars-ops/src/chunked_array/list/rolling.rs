@@ -0,0 +1,257 @@
+use arrow::array::{Array, ListArray, PrimitiveArray};
+use arrow::types::NativeType;
+use polars_core::export::num::ToPrimitive;
+use polars_core::with_match_physical_numeric_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ListRollingMethod {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+/// Options of the rolling aggregations within the lists of a list column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ListRollingOptions {
+    pub method: ListRollingMethod,
+    /// The number of values in a window, ending at the current value.
+    pub window_size: usize,
+    /// The number of non-null values a window needs for a result, otherwise it is null.
+    pub min_periods: usize,
+}
+
+impl ListRollingOptions {
+    /// The inner type of the lists that the aggregation returns for lists of `dtype`.
+    pub fn output_dtype(&self, dtype: &DataType) -> DataType {
+        use DataType::*;
+        match (self.method, dtype) {
+            // Like `sum`, small integers are summed as `Int64`.
+            (ListRollingMethod::Sum, Int8 | Int16 | UInt8 | UInt16) => Int64,
+            (ListRollingMethod::Mean, Float32) => Float32,
+            (ListRollingMethod::Mean, _) => Float64,
+            _ => dtype.clone(),
+        }
+    }
+}
+
+fn min<T: NativeType + PartialOrd>(window: &[T]) -> T {
+    window
+        .iter()
+        .copied()
+        .reduce(|a, b| if b < a { b } else { a })
+        .unwrap()
+}
+
+fn max<T: NativeType + PartialOrd>(window: &[T]) -> T {
+    window
+        .iter()
+        .copied()
+        .reduce(|a, b| if b > a { b } else { a })
+        .unwrap()
+}
+
+/// The running sum of a window, which wraps on overflow for integers like `sum` does.
+trait WindowSum: NativeType {
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+    /// Whether the value can be subtracted from the sum again, which isn't the case for
+    /// infinite and `NaN` values.
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_window_sum_int {
+    ($($T:ty),*) => {$(
+        impl WindowSum for $T {
+            fn add(self, other: Self) -> Self {
+                self.wrapping_add(other)
+            }
+            fn sub(self, other: Self) -> Self {
+                self.wrapping_sub(other)
+            }
+            fn is_finite(self) -> bool {
+                true
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_window_sum_float {
+    ($($T:ty),*) => {$(
+        impl WindowSum for $T {
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+            fn sub(self, other: Self) -> Self {
+                self - other
+            }
+            fn is_finite(self) -> bool {
+                <$T>::is_finite(self)
+            }
+        }
+    )*};
+}
+
+impl_window_sum_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_window_sum_float!(f32, f64);
+
+/// Aggregate the windows that end at every value of the lists of `arr`.
+///
+/// The windows are taken from the values between the offsets of a list, so they never
+/// cross into the next list. The output has the offsets and the validity of `arr`.
+fn rolling_chunk<T, O>(
+    arr: &ListArray<i64>,
+    options: ListRollingOptions,
+    agg: impl Fn(&[T]) -> O,
+) -> ArrayRef
+where
+    T: NativeType,
+    O: NativeType,
+{
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let mut out = vec![None; values.len()];
+    let mut window = Vec::with_capacity(options.window_size);
+    for w in arr.offsets().as_slice().windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        for i in start..end {
+            let first = (i + 1).saturating_sub(options.window_size).max(start);
+            window.clear();
+            window.extend((first..=i).filter_map(|j| values.get(j)));
+            if !window.is_empty() && window.len() >= options.min_periods {
+                out[i] = Some(agg(&window));
+            }
+        }
+    }
+    let out_values: PrimitiveArray<O> = out.into();
+    Box::new(ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(out_values.data_type().clone()),
+        arr.offsets().clone(),
+        out_values.boxed(),
+        arr.validity().cloned(),
+    ))
+}
+
+/// Sum the windows that end at every value of the lists of `arr`, and pass the sum and the
+/// number of non-null values of every window to `finish`.
+///
+/// The sum is updated as the window moves, by adding the incoming value and subtracting the
+/// outgoing one. It is only recomputed when the outgoing value is infinite or `NaN`.
+fn rolling_sum_chunk<T, S, O>(
+    arr: &ListArray<i64>,
+    options: ListRollingOptions,
+    to_sum: impl Fn(T) -> S,
+    finish: impl Fn(S, usize) -> O,
+) -> ArrayRef
+where
+    T: NativeType,
+    S: WindowSum,
+    O: NativeType,
+{
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let mut out = vec![None; values.len()];
+    for w in arr.offsets().as_slice().windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        let mut sum = S::default();
+        let mut count = 0;
+        for i in start..end {
+            if let Some(v) = values.get(i) {
+                sum = sum.add(to_sum(v));
+                count += 1;
+            }
+            let first = (i + 1).saturating_sub(options.window_size).max(start);
+            if first > start {
+                if let Some(v) = values.get(first - 1).map(&to_sum) {
+                    count -= 1;
+                    sum = if v.is_finite() {
+                        sum.sub(v)
+                    } else {
+                        (first..=i)
+                            .filter_map(|j| values.get(j))
+                            .fold(S::default(), |acc, v| acc.add(to_sum(v)))
+                    };
+                }
+            }
+            if count > 0 && count >= options.min_periods {
+                out[i] = Some(finish(sum, count));
+            }
+        }
+    }
+    let out_values: PrimitiveArray<O> = out.into();
+    Box::new(ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(out_values.data_type().clone()),
+        arr.offsets().clone(),
+        out_values.boxed(),
+        arr.validity().cloned(),
+    ))
+}
+
+/// Apply a rolling aggregation within every list of `ca`, treating every list as a series
+/// of its own.
+pub(super) fn rolling_list(
+    ca: &ListChunked,
+    options: ListRollingOptions,
+) -> PolarsResult<ListChunked> {
+    polars_ensure!(options.window_size > 0, ComputeError: "`window_size` should be positive");
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_numeric(),
+        InvalidOperation: "list rolling operations are not supported for lists of dtype `{}`", inner_dtype
+    );
+    let output_dtype = options.output_dtype(&inner_dtype);
+    let cast;
+    let ca = if options.method == ListRollingMethod::Sum && output_dtype != inner_dtype {
+        cast = ca.cast(&DataType::List(Box::new(output_dtype.clone())))?;
+        cast.list()?
+    } else {
+        ca
+    };
+    let inner_dtype = ca.inner_dtype();
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            with_match_physical_numeric_type!(&inner_dtype, |$T| {
+                match (options.method, &inner_dtype) {
+                    (ListRollingMethod::Sum, _) => {
+                        rolling_sum_chunk(arr, options, |v: $T| v, |sum: $T, _| sum)
+                    },
+                    (ListRollingMethod::Mean, DataType::Float32) => rolling_sum_chunk(
+                        arr,
+                        options,
+                        |v: $T| v.to_f32().unwrap(),
+                        |sum: f32, count| sum / count as f32,
+                    ),
+                    (ListRollingMethod::Mean, _) => rolling_sum_chunk(
+                        arr,
+                        options,
+                        |v: $T| v.to_f64().unwrap(),
+                        |sum: f64, count| sum / count as f64,
+                    ),
+                    (ListRollingMethod::Min, _) => rolling_chunk(arr, options, min::<$T>),
+                    (ListRollingMethod::Max, _) => rolling_chunk(arr, options, max::<$T>),
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    // SAFETY: the chunks are lists of the output dtype.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name(),
+            chunks,
+            DataType::List(Box::new(output_dtype)),
+        )
+    })
+}
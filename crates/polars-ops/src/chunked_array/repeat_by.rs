@@ -1,6 +1,7 @@
 use arrow::array::ListArray;
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::OffsetsBuffer;
 use polars_core::prelude::*;
-use polars_core::with_match_physical_numeric_polars_type;
 
 type LargeListArray = ListArray<i64>;
 
@@ -13,124 +14,75 @@ fn check_lengths(length_srs: usize, length_by: usize) -> PolarsResult<()> {
     Ok(())
 }
 
-fn new_by(by: &IdxCa, len: usize) -> IdxCa {
-    IdxCa::new(
-        "",
-        std::iter::repeat(by.get(0).unwrap())
-            .take(len)
-            .collect::<Vec<IdxSize>>(),
-    )
-}
-
-fn repeat_by_primitive<T>(ca: &ChunkedArray<T>, by: &IdxCa) -> PolarsResult<ListChunked>
-where
-    T: PolarsNumericType,
-{
-    check_lengths(ca.len(), by.len())?;
-
-    match (ca.len(), by.len()) {
-        (left_len, right_len) if left_len == right_len => {
-            Ok(arity::binary(ca, by, |arr, by| {
-                let iter = arr.into_iter().zip(by).map(|(opt_v, opt_by)| {
-                    opt_by.map(|by| std::iter::repeat(opt_v.copied()).take(*by as usize))
-                });
-
-                // SAFETY: length of iter is trusted.
-                unsafe {
-                    LargeListArray::from_iter_primitive_trusted_len(
-                        iter,
-                        T::get_dtype().to_arrow(true),
-                    )
-                }
-            }))
-        },
-        (_, 1) => {
-            let by = new_by(by, ca.len());
-            repeat_by_primitive(ca, &by)
-        },
-        (1, _) => {
-            let new_array = ca.new_from_index(0, by.len());
-            repeat_by_primitive(&new_array, by)
-        },
+/// Broadcast a unit length `s` or `by` to the length of the other.
+fn broadcast(s: &Series, by: &IdxCa) -> PolarsResult<(Series, IdxCa)> {
+    check_lengths(s.len(), by.len())?;
+    Ok(match (s.len(), by.len()) {
+        (left_len, right_len) if left_len == right_len => (s.clone(), by.clone()),
+        (len, 1) => (s.clone(), by.new_from_index(0, len)),
+        (1, len) => (s.new_from_index(0, len), by.clone()),
         // we have already checked the length
         _ => unreachable!(),
-    }
+    })
 }
 
-fn repeat_by_bool(ca: &BooleanChunked, by: &IdxCa) -> PolarsResult<ListChunked> {
-    check_lengths(ca.len(), by.len())?;
-
-    match (ca.len(), by.len()) {
-        (left_len, right_len) if left_len == right_len => {
-            Ok(arity::binary(ca, by, |arr, by| {
-                let iter = arr.into_iter().zip(by).map(|(opt_v, opt_by)| {
-                    opt_by.map(|by| std::iter::repeat(opt_v).take(*by as usize))
-                });
-
-                // SAFETY: length of iter is trusted.
-                unsafe { LargeListArray::from_iter_bool_trusted_len(iter) }
-            }))
-        },
-        (_, 1) => {
-            let by = new_by(by, ca.len());
-            repeat_by_bool(ca, &by)
-        },
-        (1, _) => {
-            let new_array = ca.new_from_index(0, by.len());
-            repeat_by_bool(&new_array, by)
-        },
-        // we have already checked the length
-        _ => unreachable!(),
+/// Repeat every value of `s` the number of times given by `by` and collect the repeats of a
+/// value in a list. A null count gives a null list.
+///
+/// The offsets of the lists follow directly from the counts, so the values are gathered in
+/// one go instead of building a list per row.
+pub fn repeat_by(s: &Series, by: &IdxCa) -> PolarsResult<ListChunked> {
+    if s.dtype().is_object() {
+        polars_bail!(opq = repeat_by, s.dtype());
     }
-}
+    let (s, by) = broadcast(s, by)?;
 
-fn repeat_by_binary(ca: &BinaryChunked, by: &IdxCa) -> PolarsResult<ListChunked> {
-    check_lengths(ca.len(), by.len())?;
+    let mut idx: Vec<IdxSize> = Vec::new();
+    let mut offsets = Vec::with_capacity(by.len() + 1);
+    offsets.push(0i64);
+    let mut validity = (by.null_count() > 0).then(|| MutableBitmap::with_capacity(by.len()));
+    for (i, count) in by.into_iter().enumerate() {
+        idx.extend(std::iter::repeat(i as IdxSize).take(count.unwrap_or(0) as usize));
+        offsets.push(idx.len() as i64);
+        if let Some(validity) = validity.as_mut() {
+            validity.push(count.is_some());
+        }
+    }
 
-    match (ca.len(), by.len()) {
-        (left_len, right_len) if left_len == right_len => {
-            Ok(arity::binary(ca, by, |arr, by| {
-                let iter = arr.into_iter().zip(by).map(|(opt_v, opt_by)| {
-                    opt_by.map(|by| std::iter::repeat(opt_v).take(*by as usize))
-                });
+    let s_phys = s.to_physical_repr();
+    let values = s_phys.take(&IdxCa::from_vec("", idx))?.rechunk();
+    let values = values.to_arrow(0, true);
+    // SAFETY: the offsets start at zero and are monotonically increasing.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.data_type().clone()),
+        offsets,
+        values,
+        validity.map(|validity| validity.into()),
+    );
+    let ca = ListChunked::with_chunk(s.name(), arr);
 
-                // SAFETY: length of iter is trusted.
-                unsafe { LargeListArray::from_iter_binary_trusted_len(iter, ca.len()) }
-            }))
-        },
-        (_, 1) => {
-            let by = new_by(by, ca.len());
-            repeat_by_binary(ca, &by)
-        },
-        (1, _) => {
-            let new_array = ca.new_from_index(0, by.len());
-            repeat_by_binary(&new_array, by)
-        },
-        // we have already checked the length
-        _ => unreachable!(),
+    let logical_type = s.dtype();
+    if s_phys.dtype() != logical_type {
+        ca.apply_to_inner(&|s| unsafe { s.cast_unchecked(logical_type) })
+    } else {
+        Ok(ca)
     }
 }
 
-pub fn repeat_by(s: &Series, by: &IdxCa) -> PolarsResult<ListChunked> {
-    let s_phys = s.to_physical_repr();
-    use DataType::*;
-    let out = match s_phys.dtype() {
-        Boolean => repeat_by_bool(s_phys.bool().unwrap(), by),
-        String => {
-            let ca = s_phys.str().unwrap();
-            repeat_by_binary(&ca.as_binary(), by)
-        },
-        Binary => repeat_by_binary(s_phys.binary().unwrap(), by),
-        dt if dt.is_numeric() => {
-            with_match_physical_numeric_polars_type!(dt, |$T| {
-                let ca: &ChunkedArray<$T> = s_phys.as_ref().as_ref().as_ref();
-                repeat_by_primitive(ca, by)
-            })
-        },
-        _ => polars_bail!(opq = repeat_by, s.dtype()),
-    };
-    out.and_then(|ca| {
-        let logical_type = s.dtype();
-        ca.apply_to_inner(&|s| unsafe { s.cast_unchecked(logical_type) })
-    })
+/// The result of [`repeat_by`] followed by an explode, without the intermediate lists.
+///
+/// As with an explode, a count of zero or null gives a single null row.
+pub fn repeat_by_exploded(s: &Series, by: &IdxCa) -> PolarsResult<Series> {
+    let (s, by) = broadcast(s, by)?;
+
+    let idx: IdxCa = by
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, count)| match count {
+            Some(count) if count > 0 => std::iter::repeat(Some(i as IdxSize)).take(count as usize),
+            _ => std::iter::repeat(None).take(1),
+        })
+        .collect();
+    s.take(&idx)
 }
@@ -0,0 +1,275 @@
+use arrow::array::{MutableBinaryViewArray, Utf8ViewArray};
+use polars_core::export::regex::Regex;
+use polars_core::utils::_split_offsets;
+use polars_core::POOL;
+use polars_utils::aliases::PlHashMap;
+use rayon::prelude::*;
+
+use super::*;
+
+/// The built-in pattern library, a subset of the Logstash grok patterns.
+///
+/// Atomic groups and possessive quantifiers of the original patterns are replaced with
+/// regular groups, as they are not supported by the regex engine.
+static GROK_PATTERNS: &[(&str, &str)] = &[
+    ("USERNAME", r"[a-zA-Z0-9._-]+"),
+    ("USER", r"%{USERNAME}"),
+    (
+        "EMAILLOCALPART",
+        r"[a-zA-Z0-9!#$%&'*+\-/=?^_`{|}~]+(?:\.[a-zA-Z0-9!#$%&'*+\-/=?^_`{|}~]+)*",
+    ),
+    ("EMAILADDRESS", r"%{EMAILLOCALPART}@%{HOSTNAME}"),
+    ("INT", r"(?:[+-]?(?:[0-9]+))"),
+    ("BASE10NUM", r"(?:[+-]?(?:[0-9]+(?:\.[0-9]+)?|\.[0-9]+))"),
+    ("NUMBER", r"(?:%{BASE10NUM})"),
+    ("BASE16NUM", r"(?:[+-]?(?:0x)?(?:[0-9A-Fa-f]+))"),
+    ("POSINT", r"\b(?:[1-9][0-9]*)\b"),
+    ("NONNEGINT", r"\b(?:[0-9]+)\b"),
+    ("WORD", r"\b\w+\b"),
+    ("NOTSPACE", r"\S+"),
+    ("SPACE", r"\s*"),
+    ("DATA", r".*?"),
+    ("GREEDYDATA", r".*"),
+    (
+        "QUOTEDSTRING",
+        r#"(?:"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|`(?:[^`\\]|\\.)*`)"#,
+    ),
+    ("QS", r"%{QUOTEDSTRING}"),
+    (
+        "UUID",
+        r"[A-Fa-f0-9]{8}-(?:[A-Fa-f0-9]{4}-){3}[A-Fa-f0-9]{12}",
+    ),
+    ("MAC", r"(?:[A-Fa-f0-9]{2}[:-]){5}[A-Fa-f0-9]{2}"),
+    ("IPV6", r"(?:[0-9A-Fa-f]{0,4}:){2,7}[0-9A-Fa-f]{0,4}"),
+    (
+        "IPV4",
+        r"(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)",
+    ),
+    ("IP", r"(?:%{IPV6}|%{IPV4})"),
+    (
+        "HOSTNAME",
+        r"\b(?:[0-9A-Za-z][0-9A-Za-z-]{0,62})(?:\.(?:[0-9A-Za-z][0-9A-Za-z-]{0,62}))*\.?",
+    ),
+    ("IPORHOST", r"(?:%{IP}|%{HOSTNAME})"),
+    ("HOSTPORT", r"%{IPORHOST}:%{POSINT}"),
+    ("UNIXPATH", r"(?:/[^/\s]*)+"),
+    ("WINPATH", r"(?:[A-Za-z]+:|\\)(?:\\[^\\?*]*)+"),
+    ("PATH", r"(?:%{UNIXPATH}|%{WINPATH})"),
+    ("URIPROTO", r"[A-Za-z][A-Za-z0-9+\-.]*"),
+    ("URIHOST", r"%{IPORHOST}(?::%{POSINT})?"),
+    ("URIPATH", r"(?:/[A-Za-z0-9$.+!*'(){},~:;=@#%&_\-]*)+"),
+    ("URIPARAM", r"\?[A-Za-z0-9$.+!*'|(){},~@#%&/=:;_?\-\[\]<>]*"),
+    ("URIPATHPARAM", r"%{URIPATH}(?:%{URIPARAM})?"),
+    (
+        "URI",
+        r"%{URIPROTO}://(?:%{USER}(?::[^@]*)?@)?(?:%{URIHOST})?(?:%{URIPATHPARAM})?",
+    ),
+    (
+        "MONTH",
+        r"\b(?:[Jj]an(?:uary)?|[Ff]eb(?:ruary)?|[Mm]ar(?:ch)?|[Aa]pr(?:il)?|[Mm]ay|[Jj]un(?:e)?|[Jj]ul(?:y)?|[Aa]ug(?:ust)?|[Ss]ep(?:tember)?|[Oo]ct(?:ober)?|[Nn]ov(?:ember)?|[Dd]ec(?:ember)?)\b",
+    ),
+    ("MONTHNUM", r"(?:0?[1-9]|1[0-2])"),
+    ("MONTHDAY", r"(?:(?:0[1-9])|(?:[12][0-9])|(?:3[01])|[1-9])"),
+    (
+        "DAY",
+        r"(?:Mon(?:day)?|Tue(?:sday)?|Wed(?:nesday)?|Thu(?:rsday)?|Fri(?:day)?|Sat(?:urday)?|Sun(?:day)?)",
+    ),
+    ("YEAR", r"(?:\d\d){1,2}"),
+    ("HOUR", r"(?:2[0123]|[01]?[0-9])"),
+    ("MINUTE", r"(?:[0-5][0-9])"),
+    ("SECOND", r"(?:(?:[0-5]?[0-9]|60)(?:[:.,][0-9]+)?)"),
+    ("TIME", r"%{HOUR}:%{MINUTE}(?::%{SECOND})?"),
+    ("ISO8601_TIMEZONE", r"(?:Z|[+-]%{HOUR}(?::?%{MINUTE}))"),
+    (
+        "TIMESTAMP_ISO8601",
+        r"%{YEAR}-%{MONTHNUM}-%{MONTHDAY}[T ]%{HOUR}:?%{MINUTE}(?::?%{SECOND})?%{ISO8601_TIMEZONE}?",
+    ),
+    ("SYSLOGTIMESTAMP", r"%{MONTH} +%{MONTHDAY} %{TIME}"),
+    ("HTTPDATE", r"%{MONTHDAY}/%{MONTH}/%{YEAR}:%{TIME} %{INT}"),
+    (
+        "LOGLEVEL",
+        r"(?:[Aa]lert|ALERT|[Tt]race|TRACE|[Dd]ebug|DEBUG|[Nn]otice|NOTICE|[Ii]nfo(?:rmation)?|INFO(?:RMATION)?|[Ww]arn?(?:ing)?|WARN?(?:ING)?|[Ee]rr?(?:or)?|ERR?(?:OR)?|[Cc]rit?(?:ical)?|CRIT?(?:ICAL)?|[Ff]atal|FATAL|[Ss]evere|SEVERE|EMERG(?:ENCY)?|[Ee]merg(?:ency)?)",
+    ),
+    ("HTTPDUSER", r"(?:%{EMAILADDRESS}|%{USER})"),
+    (
+        "COMMONAPACHELOG",
+        r#"%{IPORHOST:clientip} %{HTTPDUSER:ident} %{USER:auth} \[%{HTTPDATE:timestamp}\] "(?:%{WORD:verb} %{NOTSPACE:request}(?: HTTP/%{NUMBER:httpversion})?|%{DATA:rawrequest})" %{NUMBER:response:int} (?:%{NUMBER:bytes:int}|-)"#,
+    ),
+    (
+        "COMBINEDAPACHELOG",
+        r"%{COMMONAPACHELOG} %{QS:referrer} %{QS:agent}",
+    ),
+];
+
+/// Patterns that reference themselves would expand forever.
+const MAX_GROK_DEPTH: usize = 32;
+
+/// The prefix of the names of the capture groups of grok fields. The field names are not
+/// used as group names, as they may contain characters that a group name can't contain.
+const GROK_GROUP_PREFIX: &str = "__grok_";
+
+fn grok_dtype(type_hint: &str) -> PolarsResult<DataType> {
+    let dtype = match type_hint {
+        "int" | "integer" | "long" => DataType::Int64,
+        "float" | "double" => DataType::Float64,
+        "string" => DataType::String,
+        _ => polars_bail!(
+            ComputeError: "unknown grok type `{}`, expected one of `int`, `float` or `string`",
+            type_hint
+        ),
+    };
+    Ok(dtype)
+}
+
+fn expand_grok(
+    pattern: &str,
+    library: &PlHashMap<&str, &str>,
+    fields: &mut Vec<Field>,
+    depth: usize,
+) -> PolarsResult<String> {
+    polars_ensure!(
+        depth <= MAX_GROK_DEPTH,
+        ComputeError: "grok patterns are nested more than {} levels deep; is a pattern recursive?",
+        MAX_GROK_DEPTH
+    );
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find("%{") {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            polars_bail!(ComputeError: "unclosed grok pattern in `{}`", pattern)
+        };
+        let mut parts = rest[start + 2..start + len].splitn(3, ':');
+        let syntax = parts.next().unwrap();
+        let Some(definition) = library.get(syntax) else {
+            polars_bail!(ComputeError: "unknown grok pattern `{}`", syntax)
+        };
+        // Assign the field before expanding the definition, so fields are ordered like the
+        // capture groups.
+        let name = parts.next();
+        if let Some(name) = name {
+            let dtype = parts.next().map_or(Ok(DataType::String), grok_dtype)?;
+            out.push_str(&format!("(?P<{}{}>", GROK_GROUP_PREFIX, fields.len()));
+            fields.push(Field::new(name, dtype));
+        } else {
+            out.push_str("(?:");
+        }
+        out.push_str(&expand_grok(definition, library, fields, depth + 1)?);
+        out.push(')');
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Expand the grok patterns of `pattern` to a regex, and return it with the struct dtype of
+/// the fields that it extracts.
+///
+/// A grok pattern has the form `%{SYNTAX}`, `%{SYNTAX:name}` or `%{SYNTAX:name:type}`, where
+/// `SYNTAX` is a pattern of the built-in library or of `custom_patterns`, `name` is the name
+/// of the field the match is extracted into and `type` is `int`, `float` or `string`. Named
+/// capture groups of the regex are extracted as `String` fields as well.
+pub fn expand_grok_pattern(
+    pattern: &str,
+    custom_patterns: &[(&str, &str)],
+) -> PolarsResult<(String, DataType)> {
+    let library = GROK_PATTERNS
+        .iter()
+        .chain(custom_patterns)
+        .copied()
+        .collect::<PlHashMap<_, _>>();
+    let mut grok_fields = vec![];
+    let regex = expand_grok(pattern, &library, &mut grok_fields, 0)?;
+
+    let reg = Regex::new(&regex)?;
+    let mut fields: Vec<Field> = Vec::with_capacity(grok_fields.len());
+    for name in reg.capture_names().flatten() {
+        let field = match name.strip_prefix(GROK_GROUP_PREFIX) {
+            Some(idx) => grok_fields[idx.parse::<usize>().unwrap()].clone(),
+            None => Field::new(name, DataType::String),
+        };
+        polars_ensure!(
+            fields.iter().all(|f| f.name() != field.name()),
+            Duplicate: "the pattern extracts the field `{}` more than once", field.name()
+        );
+        fields.push(field);
+    }
+    Ok((regex, DataType::Struct(fields)))
+}
+
+fn extract_pattern_array(arr: &Utf8ViewArray, reg: &Regex, groups: &[usize]) -> Vec<ArrayRef> {
+    let mut builders = groups
+        .iter()
+        .map(|_| MutableBinaryViewArray::<str>::with_capacity(arr.len()))
+        .collect::<Vec<_>>();
+
+    let mut locs = reg.capture_locations();
+    for opt_v in arr {
+        if let Some(s) = opt_v {
+            if reg.captures_read(&mut locs, s).is_some() {
+                for (group, builder) in groups.iter().zip(builders.iter_mut()) {
+                    builder.push(locs.get(*group).map(|(start, stop)| &s[start..stop]));
+                }
+                continue;
+            }
+        }
+
+        // Push nulls if either the string is null or there was no match.
+        builders.iter_mut().for_each(|arr| arr.push_null());
+    }
+
+    builders
+        .into_iter()
+        .map(|builder| builder.freeze().boxed())
+        .collect()
+}
+
+/// Extract the fields of a regex that is expanded by [`expand_grok_pattern`] into a struct
+/// of `dtype`. The strings are matched in parallel.
+pub(super) fn extract_pattern(
+    ca: &StringChunked,
+    regex: &str,
+    dtype: &DataType,
+) -> PolarsResult<StructChunked> {
+    let reg = Regex::new(regex)?;
+    let DataType::Struct(fields) = dtype else {
+        polars_bail!(ComputeError: "expected a struct dtype, got `{}`", dtype)
+    };
+    let groups = reg
+        .capture_names()
+        .enumerate()
+        .filter_map(|(idx, name)| name.map(|_| idx))
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        groups.len() == fields.len(),
+        ComputeError: "the pattern has {} named groups, but the dtype has {} fields",
+        groups.len(), fields.len()
+    );
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = _split_offsets(arr.len(), POOL.current_num_threads());
+    let parts = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let part = arr.clone().sliced(offset, len);
+                extract_pattern_array(&part, &reg, &groups)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let chunks = parts.iter().map(|part| part[i].clone()).collect::<Vec<_>>();
+            // SAFETY: the chunks are string arrays.
+            let s = unsafe {
+                Series::from_chunks_and_dtype_unchecked(field.name(), chunks, &DataType::String)
+            };
+            s.cast(field.data_type())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    StructChunked::new(ca.name(), &fields)
+}
@@ -0,0 +1,116 @@
+use hmac::{Hmac, Mac};
+use polars_core::prelude::StringChunked;
+use sha2::Sha256;
+use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+
+pub(super) fn mask_except_last<'a>(
+    ca: &'a StringChunked,
+    n: usize,
+    mask_char: char,
+) -> StringChunked {
+    // amortize allocation
+    let mut buf = String::new();
+    let f = |s: &'a str| {
+        let len = s.chars().count();
+        if len <= n {
+            s
+        } else {
+            let keep_from = s
+                .char_indices()
+                .nth(len - n)
+                .map_or(s.len(), |(idx, _)| idx);
+            buf.clear();
+            for _ in 0..len - n {
+                buf.push(mask_char)
+            }
+            buf.push_str(&s[keep_from..]);
+            // extend lifetime
+            // lifetime is bound to 'a
+            let slice = buf.as_str();
+            unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+        }
+    };
+    ca.apply_mut(f)
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+fn random_char(state: &mut u64, first: u8, n: u64) -> char {
+    (first + (splitmix64(state) % n) as u8) as char
+}
+
+pub(super) fn tokenize_format_preserving<'a>(ca: &'a StringChunked, key: &str) -> StringChunked {
+    let seed = xxh3_64(key.as_bytes());
+    // amortize allocation
+    let mut buf = String::new();
+    let f = |s: &'a str| {
+        // The replacements are drawn from a generator seeded by the keyed hash of the whole
+        // value, so equal values get equal tokens.
+        let mut state = xxh3_64_with_seed(s.as_bytes(), seed);
+        buf.clear();
+        for c in s.chars() {
+            let c = match c {
+                '0'..='9' => random_char(&mut state, b'0', 10),
+                'a'..='z' => random_char(&mut state, b'a', 26),
+                'A'..='Z' => random_char(&mut state, b'A', 26),
+                c => c,
+            };
+            buf.push(c);
+        }
+        // extend lifetime
+        // lifetime is bound to 'a
+        let slice = buf.as_str();
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
+pub(super) fn pseudonymize<'a>(ca: &'a StringChunked, key: &[u8]) -> StringChunked {
+    // HMAC takes keys of any length.
+    let mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    // amortize allocation
+    let mut buf = String::new();
+    let f = |s: &'a str| {
+        let mut mac = mac.clone();
+        mac.update(s.as_bytes());
+        buf.clear();
+        buf.push_str(&hex::encode(mac.finalize().into_bytes()));
+        // extend lifetime
+        // lifetime is bound to 'a
+        let slice = buf.as_str();
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_pseudonymize() {
+        // Test case 2 of RFC 4231.
+        let ca = StringChunked::new("", &[Some("what do ya want for nothing?"), None]);
+        let out = pseudonymize(&ca, b"Jefe");
+        assert_eq!(
+            out.get(0),
+            Some("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+        );
+        assert_eq!(out.get(1), None);
+
+        let ca = StringChunked::new("", &["a", "b", "a"]);
+        let out = pseudonymize(&ca, b"key");
+        assert_eq!(out.get(0), out.get(2));
+        assert_ne!(out.get(0), out.get(1));
+        let other_key = pseudonymize(&ca, b"other key");
+        assert_ne!(out.get(0), other_key.get(0));
+    }
+}
@@ -6,8 +6,12 @@ mod concat;
 mod extract;
 #[cfg(feature = "find_many")]
 mod find_many;
+#[cfg(feature = "extract_pattern")]
+mod grok;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
+#[cfg(feature = "string_mask")]
+mod mask;
 #[cfg(feature = "strings")]
 mod namespace;
 #[cfg(feature = "string_pad")]
@@ -20,6 +24,8 @@ mod split;
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "string_url")]
+mod url;
 
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
@@ -28,6 +34,8 @@ mod unicode_internals;
 pub use concat::*;
 #[cfg(feature = "find_many")]
 pub use find_many::*;
+#[cfg(feature = "extract_pattern")]
+pub use grok::expand_grok_pattern;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
 #[cfg(feature = "strings")]
@@ -37,6 +45,8 @@ use polars_core::prelude::*;
 pub use split::*;
 #[cfg(feature = "strings")]
 pub use strip::*;
+#[cfg(feature = "string_url")]
+pub use url::{parse_query_string_dtype, parse_url_dtype};
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
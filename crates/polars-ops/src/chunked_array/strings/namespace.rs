@@ -515,6 +515,14 @@ pub trait StringNameSpaceImpl: AsString {
         super::extract::extract_groups(ca, pat, dtype)
     }
 
+    #[cfg(feature = "extract_pattern")]
+    /// Extract the named groups of a regex that is expanded by
+    /// [`expand_grok_pattern`](super::expand_grok_pattern) into a struct of `dtype`.
+    fn extract_pattern(&self, regex: &str, dtype: &DataType) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+        super::grok::extract_pattern(ca, regex, dtype)
+    }
+
     /// Count all successive non-overlapping regex matches.
     fn count_matches(&self, pat: &str, literal: bool) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_string();
@@ -600,6 +608,74 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Replace all but the last `n` characters with `mask_char`.
+    ///
+    /// Strings with at most `n` characters are returned as-is.
+    #[must_use]
+    #[cfg(feature = "string_mask")]
+    fn str_mask_except_last(&self, n: usize, mask_char: char) -> StringChunked {
+        let ca = self.as_string();
+        mask::mask_except_last(ca, n, mask_char)
+    }
+
+    /// Replace every ASCII digit and letter with a digit or letter of the same case derived
+    /// from `key` and the whole string. Other characters are kept, so the format of the
+    /// string is preserved and equal strings get equal tokens.
+    #[must_use]
+    #[cfg(feature = "string_mask")]
+    fn str_tokenize_format_preserving(&self, key: &str) -> StringChunked {
+        let ca = self.as_string();
+        mask::tokenize_format_preserving(ca, key)
+    }
+
+    /// Replace every string with the hexadecimal HMAC-SHA256 of the string, keyed by `key`.
+    ///
+    /// Equal strings get equal pseudonyms, which can't be linked back to the strings without
+    /// the key.
+    #[must_use]
+    #[cfg(feature = "string_mask")]
+    fn str_pseudonymize(&self, key: &[u8]) -> StringChunked {
+        let ca = self.as_string();
+        mask::pseudonymize(ca, key)
+    }
+
+    /// Decode the percent-encoded characters of the string values.
+    ///
+    /// Strings that don't decode to valid UTF-8 become null.
+    #[must_use]
+    #[cfg(feature = "string_url")]
+    fn str_url_decode(&self) -> StringChunked {
+        let ca = self.as_string();
+        url::url_decode(ca)
+    }
+
+    /// Percent-encode all characters of the string values except the unreserved ones:
+    /// ASCII letters and digits and `-`, `_`, `.` and `~`.
+    #[must_use]
+    #[cfg(feature = "string_url")]
+    fn str_url_encode(&self) -> StringChunked {
+        let ca = self.as_string();
+        url::url_encode(ca)
+    }
+
+    /// Split the string values into the components of a URL: `scheme`, `host`, `port`,
+    /// `path`, `query` and `fragment`. Missing components are null.
+    #[cfg(feature = "string_url")]
+    fn str_parse_url(&self) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+        url::parse_url(ca)
+    }
+
+    /// Parse the string values as query strings into lists of `key` and `value` structs.
+    ///
+    /// The keys and values are decoded, with `+` as a space. A key without `=` has a null
+    /// value.
+    #[cfg(feature = "string_url")]
+    fn str_parse_query_string(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        url::parse_query_string(ca)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use arrow::array::ListArray;
+use arrow::offset::OffsetsBuffer;
+use polars_core::prelude::*;
+
+/// The dtype of the structs returned by `parse_url`.
+pub fn parse_url_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("scheme", DataType::String),
+        Field::new("host", DataType::String),
+        Field::new("port", DataType::UInt16),
+        Field::new("path", DataType::String),
+        Field::new("query", DataType::String),
+        Field::new("fragment", DataType::String),
+    ])
+}
+
+/// The dtype of the lists returned by `parse_query_string`.
+pub fn parse_query_string_dtype() -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new("key", DataType::String),
+        Field::new("value", DataType::String),
+    ])))
+}
+
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decode the percent-encoded bytes of `s`, and `+` as a space if `plus_as_space`.
+///
+/// Malformed escapes are kept as-is. Returns `None` if the decoded bytes are not valid UTF-8.
+fn percent_decode(s: &str, plus_as_space: bool) -> Option<Cow<'_, str>> {
+    if !s.bytes().any(|b| b == b'%' || (plus_as_space && b == b'+')) {
+        return Some(Cow::Borrowed(s));
+    }
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                if let (Some(hi), Some(lo)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                    out.push((hi << 4) | lo);
+                    i += 3;
+                    continue;
+                }
+                out.push(b'%')
+            },
+            b'+' if plus_as_space => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8(out).ok().map(Cow::Owned)
+}
+
+pub(super) fn url_decode(ca: &StringChunked) -> StringChunked {
+    ca.apply_generic(|opt_s| {
+        opt_s
+            .and_then(|s| percent_decode(s, false))
+            .map(Cow::into_owned)
+    })
+}
+
+pub(super) fn url_encode<'a>(ca: &'a StringChunked) -> StringChunked {
+    // amortize allocation
+    let mut buf = String::new();
+    let f = |s: &'a str| {
+        buf.clear();
+        for b in s.bytes() {
+            // Only the unreserved characters of RFC 3986 are kept.
+            if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~') {
+                buf.push(b as char)
+            } else {
+                write!(buf, "%{:02X}", b).unwrap()
+            }
+        }
+        // extend lifetime
+        // lifetime is bound to 'a
+        let slice = buf.as_str();
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
+#[derive(Default)]
+struct Url<'a> {
+    scheme: Option<&'a str>,
+    host: Option<&'a str>,
+    port: Option<u16>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+fn is_scheme(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+/// Split the host and the port of an authority. An empty port is taken to be missing, as
+/// RFC 3986 allows.
+fn split_host_port(s: &str) -> PolarsResult<(&str, Option<u16>)> {
+    let colon = if s.starts_with('[') {
+        // The colons of an IPv6 literal don't separate the port.
+        s.find(']')
+            .and_then(|i| s[i + 1..].starts_with(':').then_some(i + 1))
+    } else {
+        s.rfind(':')
+    };
+    match colon {
+        Some(i) if i + 1 == s.len() => Ok((&s[..i], None)),
+        Some(i) => {
+            let port = &s[i + 1..];
+            let port = port.parse().map_err(
+                |_| polars_err!(ComputeError: "invalid port in URL authority '{}': '{}'", s, port),
+            )?;
+            Ok((&s[..i], Some(port)))
+        },
+        None => Ok((s, None)),
+    }
+}
+
+/// Split `s` into the components of a URL, following the generic syntax of RFC 3986.
+fn split_url(s: &str) -> PolarsResult<Url<'_>> {
+    let mut url = Url::default();
+    let mut rest = s;
+    if let Some((before, fragment)) = rest.split_once('#') {
+        url.fragment = Some(fragment);
+        rest = before;
+    }
+    if let Some((before, query)) = rest.split_once('?') {
+        url.query = Some(query);
+        rest = before;
+    }
+    if let Some((scheme, after)) = rest.split_once(':') {
+        if is_scheme(scheme) {
+            url.scheme = Some(scheme);
+            rest = after;
+        }
+    }
+    if let Some(after) = rest.strip_prefix("//") {
+        let end = after.find('/').unwrap_or(after.len());
+        let authority = &after[..end];
+        let host_port = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_userinfo, host_port)| host_port);
+        let (host, port) = split_host_port(host_port)?;
+        url.host = (!host.is_empty()).then_some(host);
+        url.port = port;
+        rest = &after[end..];
+    }
+    url.path = rest;
+    Ok(url)
+}
+
+pub(super) fn parse_url(ca: &StringChunked) -> PolarsResult<StructChunked> {
+    let urls = ca
+        .into_iter()
+        .map(|opt_s| opt_s.map(split_url).transpose())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let string_field = |name: &str, get: fn(&Url<'_>) -> Option<&str>| {
+        let ca: StringChunked = urls.iter().map(|url| url.as_ref().and_then(get)).collect();
+        ca.with_name(name).into_series()
+    };
+    let port: UInt16Chunked = urls
+        .iter()
+        .map(|url| url.as_ref().and_then(|url| url.port))
+        .collect();
+    let fields = [
+        string_field("scheme", |url| url.scheme),
+        string_field("host", |url| url.host),
+        port.with_name("port").into_series(),
+        string_field("path", |url| Some(url.path)),
+        string_field("query", |url| url.query),
+        string_field("fragment", |url| url.fragment),
+    ];
+    StructChunked::new(ca.name(), &fields)
+}
+
+pub(super) fn parse_query_string(ca: &StringChunked) -> PolarsResult<ListChunked> {
+    let mut keys: Vec<Option<String>> = vec![];
+    let mut values: Vec<Option<String>> = vec![];
+    let mut offsets = Vec::with_capacity(ca.len() + 1);
+    offsets.push(0i64);
+    for opt_s in ca.into_iter() {
+        if let Some(s) = opt_s {
+            let s = s.strip_prefix('?').unwrap_or(s);
+            for pair in s.split('&').filter(|pair| !pair.is_empty()) {
+                let (key, value) = match pair.split_once('=') {
+                    Some((key, value)) => (key, Some(value)),
+                    None => (pair, None),
+                };
+                keys.push(percent_decode(key, true).map(Cow::into_owned));
+                values.push(
+                    value
+                        .and_then(|value| percent_decode(value, true))
+                        .map(Cow::into_owned),
+                );
+            }
+        }
+        offsets.push(keys.len() as i64);
+    }
+
+    let keys: StringChunked = keys.into_iter().collect();
+    let values: StringChunked = values.into_iter().collect();
+    let pairs = StructChunked::new(
+        "",
+        &[
+            keys.with_name("key").into_series(),
+            values.with_name("value").into_series(),
+        ],
+    )?
+    .into_series()
+    .rechunk()
+    .to_arrow(0, true);
+    let validity = (ca.null_count() > 0).then(|| {
+        let is_valid = ca.is_not_null().rechunk();
+        is_valid.downcast_iter().next().unwrap().values().clone()
+    });
+    // SAFETY: the offsets are monotonically increasing and end at the number of pairs.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let arr = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(pairs.data_type().clone()),
+        offsets,
+        pairs,
+        validity,
+    );
+    // SAFETY: the chunk is a list of structs of the output dtype.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name(),
+            vec![Box::new(arr)],
+            parse_query_string_dtype(),
+        )
+    })
+}
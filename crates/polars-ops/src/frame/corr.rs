@@ -0,0 +1,126 @@
+use polars_core::export::rayon::prelude::*;
+use polars_core::prelude::*;
+use polars_core::POOL;
+
+use crate::chunked_array::cov::{cov, pearson_corr};
+
+/// Method used by [`DataFrameOps::corr`](super::DataFrameOps::corr).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CorrelationMatrixMethod {
+    #[default]
+    Pearson,
+    #[cfg(feature = "rank")]
+    Spearman,
+}
+
+#[cfg(feature = "rank")]
+fn spearman_corr(a: &Series, b: &Series, ddof: u8) -> PolarsResult<Option<f64>> {
+    use polars_core::utils::coalesce_nulls_series;
+
+    use crate::series::{RankMethod, RankOptions, SeriesRank};
+
+    // Rank on the rows where both columns are valid.
+    let (a, b) = coalesce_nulls_series(a, b);
+    let options = RankOptions {
+        method: RankMethod::Average,
+        ..Default::default()
+    };
+    let a = a
+        .drop_nulls()
+        .rank(options, None)
+        .cast(&DataType::Float64)?;
+    let b = b
+        .drop_nulls()
+        .rank(options, None)
+        .cast(&DataType::Float64)?;
+    Ok(pearson_corr(a.f64()?, b.f64()?, ddof))
+}
+
+/// Evaluate `f` for every pair of numeric columns in parallel and collect the results
+/// in a square matrix. `f` only runs once per unordered pair as the matrix is symmetric.
+fn pairwise_matrix<F>(df: &DataFrame, f: F) -> PolarsResult<DataFrame>
+where
+    F: Fn(&Series, &Series) -> PolarsResult<Option<f64>> + Send + Sync,
+{
+    let columns = df
+        .get_columns()
+        .iter()
+        .filter(|s| s.dtype().is_numeric())
+        .map(|s| s.cast(&DataType::Float64))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let n = columns.len();
+    let pairs = (0..n)
+        .flat_map(|i| (i..n).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+
+    let values = POOL.install(|| {
+        pairs
+            .par_iter()
+            .map(|&(i, j)| f(&columns[i], &columns[j]))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let mut matrix = vec![vec![None; n]; n];
+    for (&(i, j), v) in pairs.iter().zip(values) {
+        matrix[i][j] = v;
+        matrix[j][i] = v;
+    }
+    DataFrame::new(
+        columns
+            .iter()
+            .zip(matrix)
+            .map(|(s, values)| Series::new(s.name(), values))
+            .collect(),
+    )
+}
+
+/// Compute the correlation between every pair of numeric columns of `df`.
+///
+/// Nulls are handled pairwise: a row is ignored for a pair if either value is null.
+pub fn corr_matrix(
+    df: &DataFrame,
+    method: CorrelationMatrixMethod,
+    ddof: u8,
+) -> PolarsResult<DataFrame> {
+    match method {
+        CorrelationMatrixMethod::Pearson => {
+            pairwise_matrix(df, |a, b| Ok(pearson_corr(a.f64()?, b.f64()?, ddof)))
+        },
+        #[cfg(feature = "rank")]
+        CorrelationMatrixMethod::Spearman => pairwise_matrix(df, |a, b| spearman_corr(a, b, ddof)),
+    }
+}
+
+/// Compute the covariance between every pair of numeric columns of `df`.
+///
+/// Nulls are handled pairwise: a row is ignored for a pair if either value is null.
+pub fn cov_matrix(df: &DataFrame, ddof: u8) -> PolarsResult<DataFrame> {
+    pairwise_matrix(df, |a, b| Ok(cov(a.f64()?, b.f64()?, ddof)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_corr_matrix() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1.0), Some(2.0), Some(3.0), None],
+            "b" => [2, 4, 6, 100],
+            "c" => ["x", "y", "z", "w"],
+        ]?;
+
+        let out = corr_matrix(&df, CorrelationMatrixMethod::Pearson, 1)?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        // The null row is only ignored for the pairs that include "a".
+        assert!((out.column("a")?.f64()?.get(1).unwrap() - 1.0).abs() < 1e-12);
+        assert!((out.column("b")?.f64()?.get(1).unwrap() - 1.0).abs() < 1e-12);
+
+        let out = cov_matrix(&df, 1)?;
+        assert_eq!(out.column("a")?.f64()?.get(0), Some(1.0));
+        assert_eq!(out.column("a")?.f64()?.get(1), Some(2.0));
+        assert_eq!(out.column("b")?.f64()?.get(0), Some(2.0));
+
+        Ok(())
+    }
+}
@@ -27,6 +27,35 @@ pub struct JoinArgs {
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
     pub coalesce: JoinCoalesce,
+    #[cfg(feature = "approx_unique")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expansion_guard: Option<JoinExpansionGuard>,
+}
+
+/// Guards a join against an output that is many times larger than its inputs, e.g. an
+/// accidental many-to-many join on keys with few distinct values.
+///
+/// The output size is estimated before the join from the approximate number of distinct keys
+/// of both sides.
+#[cfg(feature = "approx_unique")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JoinExpansionGuard {
+    /// The largest allowed ratio of the estimated output rows to the rows of the larger input.
+    pub max_factor: u32,
+    pub action: JoinExpansionAction,
+}
+
+/// What to do when the estimated output of a join exceeds its [`JoinExpansionGuard`].
+#[cfg(feature = "approx_unique")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinExpansionAction {
+    /// Abort the join with an error.
+    #[default]
+    Raise,
+    /// Emit a warning and run the join.
+    Warn,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
@@ -67,6 +96,8 @@ impl Default for JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            #[cfg(feature = "approx_unique")]
+            expansion_guard: None,
         }
     }
 }
@@ -80,6 +111,8 @@ impl JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            #[cfg(feature = "approx_unique")]
+            expansion_guard: None,
         }
     }
 
@@ -88,6 +121,12 @@ impl JoinArgs {
         self
     }
 
+    #[cfg(feature = "approx_unique")]
+    pub fn with_expansion_guard(mut self, guard: JoinExpansionGuard) -> Self {
+        self.expansion_guard = Some(guard);
+        self
+    }
+
     pub fn suffix(&self) -> &str {
         self.suffix.as_deref().unwrap_or("_right")
     }
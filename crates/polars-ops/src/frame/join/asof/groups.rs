@@ -9,7 +9,7 @@ use polars_core::hashing::{
 };
 use polars_core::prelude::*;
 use polars_core::utils::flatten::flatten_nullable;
-use polars_core::utils::{_set_partition_size, split_ca, split_df};
+use polars_core::utils::{_set_partition_size, slice_offsets, split_ca, split_df};
 use polars_core::{with_match_physical_float_polars_type, IdBuildHasher, POOL};
 use polars_utils::abs_diff::AbsDiff;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
@@ -124,6 +124,7 @@ fn asof_in_group<'a, T, A, F>(
     right_grp_idxs: &[IdxSize],
     group_states: &mut PlHashMap<IdxSize, A>,
     filter: F,
+    allow_eq: bool,
 ) -> Option<IdxSize>
 where
     T: PolarsDataType,
@@ -133,7 +134,7 @@ where
     // We use the index of the first element in a group as an identifier to
     // associate with the group state.
     let id = right_grp_idxs.first()?;
-    let grp_state = group_states.entry(*id).or_default();
+    let grp_state = group_states.entry(*id).or_insert_with(|| A::new(allow_eq));
 
     unsafe {
         let r_grp_idx = grp_state.next(
@@ -160,6 +161,7 @@ fn asof_join_by_numeric<T, S, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr>
 where
     T: PolarsDataType,
@@ -223,6 +225,7 @@ where
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
+                    allow_eq,
                 );
                 results.push(materialize_nullable(id));
             }
@@ -239,6 +242,7 @@ fn asof_join_by_binary<T, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    allow_eq: bool,
 ) -> IdxArr
 where
     T: PolarsDataType,
@@ -289,6 +293,7 @@ where
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
+                    allow_eq,
                 );
 
                 results.push(materialize_nullable(id));
@@ -305,6 +310,7 @@ fn asof_join_by_multiple<T, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    allow_eq: bool,
 ) -> IdxArr
 where
     T: PolarsDataType,
@@ -370,6 +376,7 @@ where
                         &right_grp_idxs[..],
                         &mut group_states,
                         &filter,
+                        allow_eq,
                     );
 
                     results.push(materialize_nullable(id));
@@ -388,6 +395,7 @@ fn dispatch_join_by_type<T, A, F>(
     left_by: &mut DataFrame,
     right_by: &mut DataFrame,
     filter: F,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr>
 where
     T: PolarsDataType,
@@ -406,12 +414,16 @@ where
             DataType::String => {
                 let left_by = &left_by_s.str().unwrap().as_binary();
                 let right_by = right_by_s.str().unwrap().as_binary();
-                asof_join_by_binary::<T, A, F>(left_by, &right_by, left_asof, right_asof, filter)
+                asof_join_by_binary::<T, A, F>(
+                    left_by, &right_by, left_asof, right_asof, filter, allow_eq,
+                )
             },
             DataType::Binary => {
                 let left_by = &left_by_s.binary().unwrap();
                 let right_by = right_by_s.binary().unwrap();
-                asof_join_by_binary::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
+                asof_join_by_binary::<T, A, F>(
+                    left_by, right_by, left_asof, right_asof, filter, allow_eq,
+                )
             },
             _ => {
                 if left_by_s.dtype().is_float() {
@@ -419,20 +431,20 @@ where
                         let left_by: &ChunkedArray<$T> = left_by_s.as_ref().as_ref().as_ref();
                         let right_by: &ChunkedArray<$T> = right_by_s.as_ref().as_ref().as_ref();
                         asof_join_by_numeric::<T, $T, A, F>(
-                            left_by, right_by, left_asof, right_asof, filter,
+                            left_by, right_by, left_asof, right_asof, filter, allow_eq,
                         )?
                     })
                 } else if left_by_s.bit_repr_is_large() {
                     let left_by = left_by_s.bit_repr_large();
                     let right_by = right_by_s.bit_repr_large();
                     asof_join_by_numeric::<T, UInt64Type, A, F>(
-                        &left_by, &right_by, left_asof, right_asof, filter,
+                        &left_by, &right_by, left_asof, right_asof, filter, allow_eq,
                     )?
                 } else {
                     let left_by = left_by_s.bit_repr_small();
                     let right_by = right_by_s.bit_repr_small();
                     asof_join_by_numeric::<T, UInt32Type, A, F>(
-                        &left_by, &right_by, left_asof, right_asof, filter,
+                        &left_by, &right_by, left_asof, right_asof, filter, allow_eq,
                     )?
                 }
             },
@@ -445,7 +457,7 @@ where
             #[cfg(feature = "dtype-categorical")]
             _check_categorical_src(lhs.dtype(), rhs.dtype())?;
         }
-        asof_join_by_multiple::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
+        asof_join_by_multiple::<T, A, F>(left_by, right_by, left_asof, right_asof, filter, allow_eq)
     };
     Ok(out)
 }
@@ -457,6 +469,7 @@ fn dispatch_join_strategy<T: PolarsDataType>(
     left_by: &mut DataFrame,
     right_by: &mut DataFrame,
     strategy: AsofStrategy,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr>
 where
     for<'a> T::Physical<'a>: PartialOrd,
@@ -466,10 +479,10 @@ where
     let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
     match strategy {
         AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof, right_asof, left_by, right_by, filter, allow_eq,
         ),
         AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof, right_asof, left_by, right_by, filter, allow_eq,
         ),
         AsofStrategy::Nearest => unimplemented!(),
     }
@@ -483,6 +496,7 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
     right_by: &mut DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr> {
     let right_ca = left_asof.unpack_series_matching_type(right_asof)?;
 
@@ -492,26 +506,26 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
         let filter = |a: T::Native, b: T::Native| a.abs_diff(b) <= abs_tolerance;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
         }
     } else {
         let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
         }
     }
@@ -525,39 +539,56 @@ fn dispatch_join_type(
     right_by: &mut DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr> {
     match left_asof.dtype() {
         DataType::Int64 => {
             let ca = left_asof.i64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Int32 => {
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::UInt64 => {
             let ca = left_asof.u64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::UInt32 => {
             let ca = left_asof.u32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Float32 => {
             let ca = left_asof.f32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Float64 => {
             let ca = left_asof.f64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Boolean => {
             let ca = left_asof.bool().unwrap();
-            dispatch_join_strategy::<BooleanType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BooleanType>(
+                ca, right_asof, left_by, right_by, strategy, allow_eq,
+            )
         },
         DataType::Binary => {
             let ca = left_asof.binary().unwrap();
-            dispatch_join_strategy::<BinaryType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BinaryType>(
+                ca, right_asof, left_by, right_by, strategy, allow_eq,
+            )
         },
         DataType::String => {
             let ca = left_asof.str().unwrap();
@@ -568,13 +599,22 @@ fn dispatch_join_type(
                 left_by,
                 right_by,
                 strategy,
+                allow_eq,
             )
         },
         _ => {
             let left_asof = left_asof.cast(&DataType::Int32).unwrap();
             let right_asof = right_asof.cast(&DataType::Int32).unwrap();
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, &right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                &right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_eq,
+            )
         },
     }
 }
@@ -593,6 +633,8 @@ pub trait AsofJoinBy: IntoDf {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<&str>,
         slice: Option<(i64, usize)>,
+        allow_eq: bool,
+        right_index_name: Option<&str>,
     ) -> PolarsResult<DataFrame> {
         let (self_sliced_slot, other_sliced_slot); // Keeps temporaries alive.
         let (self_df, other_df);
@@ -616,6 +658,7 @@ pub trait AsofJoinBy: IntoDf {
             tolerance.is_some(),
             left_by.is_empty() && right_by.is_empty(),
         )?;
+        let tolerance = tolerance_in_key_unit(tolerance, self_df.column(left_on)?.dtype())?;
 
         let mut left_by = self_df.select(left_by)?;
         let mut right_by = other_df.select(right_by)?;
@@ -640,6 +683,7 @@ pub trait AsofJoinBy: IntoDf {
             &mut right_by,
             strategy,
             tolerance,
+            allow_eq,
         )?;
 
         let mut drop_these = right_by.get_column_names();
@@ -657,11 +701,20 @@ pub trait AsofJoinBy: IntoDf {
 
         let left = self_df.clone();
 
+        let take_idx = IdxCa::with_chunk("", right_join_tuples);
         // SAFETY: join tuples are in bounds.
-        let right_df =
-            unsafe { proj_other_df.take_unchecked(&IdxCa::with_chunk("", right_join_tuples)) };
-
-        _finish_join(left, right_df, suffix)
+        let right_df = unsafe { proj_other_df.take_unchecked(&take_idx) };
+
+        let mut out = _finish_join(left, right_df, suffix)?;
+        if let Some(name) = right_index_name {
+            // The indices of the matches are relative to the sliced right frame.
+            let offset = slice.map_or(0, |(offset, len)| {
+                slice_offsets(offset, len, other.height()).0
+            });
+            let right_index = &take_idx + offset as IdxSize;
+            out.hstack_mut(&[right_index.with_name(name).into_series()])?;
+        }
+        Ok(out)
     }
 
     /// This is similar to a left-join except that we match on nearest key
@@ -687,7 +740,8 @@ pub trait AsofJoinBy: IntoDf {
         let left_by = left_by.into_iter().map(|s| s.as_ref().into()).collect();
         let right_by = right_by.into_iter().map(|s| s.as_ref().into()).collect();
         self_df._join_asof_by(
-            other, left_on, right_on, left_by, right_by, strategy, tolerance, None, None,
+            other, left_on, right_on, left_by, right_by, strategy, tolerance, None, None, true,
+            None,
         )
     }
 }
@@ -6,6 +6,7 @@ use default::*;
 pub use groups::AsofJoinBy;
 use polars_core::prelude::*;
 use polars_core::utils::ensure_sorted_arg;
+use polars_core::with_match_physical_numeric_polars_type;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -15,7 +16,11 @@ use super::_check_categorical_src;
 use super::{_finish_join, build_tables, prepare_bytes};
 use crate::frame::IntoDf;
 
-trait AsofJoinState<T>: Default {
+trait AsofJoinState<T>: Sized {
+    /// A fresh state. If `allow_eq` is false, right values equal to the left value are
+    /// never matched.
+    fn new(allow_eq: bool) -> Self;
+
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
         left_val: &T,
@@ -24,12 +29,19 @@ trait AsofJoinState<T>: Default {
     ) -> Option<IdxSize>;
 }
 
-#[derive(Default)]
 struct AsofJoinForwardState {
+    allow_eq: bool,
     scan_offset: IdxSize,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
+    fn new(allow_eq: bool) -> Self {
+        AsofJoinForwardState {
+            allow_eq,
+            scan_offset: 0,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -39,7 +51,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     ) -> Option<IdxSize> {
         while (self.scan_offset) < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val >= *left_val {
+                if right_val > *left_val || (self.allow_eq && right_val == *left_val) {
                     return Some(self.scan_offset);
                 }
             }
@@ -49,14 +61,22 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinBackwardState {
-    // best_bound is the greatest right index <= left_val.
+    allow_eq: bool,
+    // best_bound is the greatest right index <= left_val (< left_val if !allow_eq).
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
+    fn new(allow_eq: bool) -> Self {
+        AsofJoinBackwardState {
+            allow_eq,
+            best_bound: None,
+            scan_offset: 0,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -66,7 +86,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     ) -> Option<IdxSize> {
         while self.scan_offset < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val <= *left_val {
+                if right_val < *left_val || (self.allow_eq && right_val == *left_val) {
                     self.best_bound = Some(self.scan_offset);
                 } else {
                     break;
@@ -78,14 +98,67 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinNearestState {
+    allow_eq: bool,
     // best_bound is the nearest value to left_val, with ties broken towards the last element.
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
+    // Without exact matches, the nearest value is the nearest of the strictly smaller and
+    // the strictly greater candidates.
+    backward: AsofJoinBackwardState,
+    forward: AsofJoinForwardState,
+}
+
+impl AsofJoinNearestState {
+    fn next_strict<T: NumericNative, F: FnMut(IdxSize) -> Option<T>>(
+        &mut self,
+        left_val: &T,
+        mut right: F,
+        n_right: IdxSize,
+    ) -> Option<IdxSize> {
+        let below = self.backward.next(left_val, &mut right, n_right);
+        let Some(above) = self.forward.next(left_val, &mut right, n_right) else {
+            return below;
+        };
+        // SAFETY: the states only return indices of non-null values.
+        let above_val = unsafe { right(above).unwrap_unchecked() };
+        if let Some(below) = below {
+            let below_val = unsafe { right(below).unwrap_unchecked() };
+            if left_val.abs_diff(below_val) < left_val.abs_diff(above_val) {
+                return Some(below);
+            }
+        }
+        // Break ties towards the last element, also within a run of equal values.
+        let mut best = above;
+        for i in above + 1..n_right {
+            match right(i) {
+                Some(val) if val == above_val => best = i,
+                Some(_) => break,
+                None => {},
+            }
+        }
+        Some(best)
+    }
 }
 
 impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
+    fn new(allow_eq: bool) -> Self {
+        AsofJoinNearestState {
+            allow_eq,
+            best_bound: None,
+            scan_offset: 0,
+            backward: AsofJoinBackwardState {
+                allow_eq: false,
+                best_bound: None,
+                scan_offset: 0,
+            },
+            forward: AsofJoinForwardState {
+                allow_eq: false,
+                scan_offset: 0,
+            },
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -93,6 +166,10 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
         mut right: F,
         n_right: IdxSize,
     ) -> Option<IdxSize> {
+        if !self.allow_eq {
+            return self.next_strict(left_val, right, n_right);
+        }
+
         // Skipping ahead to the first value greater than left_val. This is
         // cheaper than computing differences.
         while self.scan_offset < n_right {
@@ -142,7 +219,7 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AsOfOptions {
     pub strategy: AsofStrategy,
@@ -156,6 +233,38 @@ pub struct AsOfOptions {
     pub tolerance_str: Option<SmartString>,
     pub left_by: Option<Vec<SmartString>>,
     pub right_by: Option<Vec<SmartString>>,
+    /// Whether rows with equal keys may be matched. If `false`, only strictly smaller
+    /// (backward), strictly greater (forward) or unequal (nearest) keys are matched.
+    #[cfg_attr(feature = "serde", serde(default = "default_allow_exact_matches"))]
+    pub allow_exact_matches: bool,
+    /// Add a column with this name that holds the index of the matched row of the right
+    /// frame, or null for the rows without a match.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub right_index_name: Option<SmartString>,
+    /// A String column of the left frame with the strategy of every row: "backward",
+    /// "forward" or "nearest". The rows where it is null use `strategy`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strategy_column: Option<SmartString>,
+}
+
+#[cfg(feature = "serde")]
+fn default_allow_exact_matches() -> bool {
+    true
+}
+
+impl Default for AsOfOptions {
+    fn default() -> Self {
+        AsOfOptions {
+            strategy: AsofStrategy::default(),
+            tolerance: None,
+            tolerance_str: None,
+            left_by: None,
+            right_by: None,
+            allow_exact_matches: true,
+            right_index_name: None,
+            strategy_column: None,
+        }
+    }
 }
 
 fn check_asof_columns(
@@ -191,6 +300,40 @@ fn check_asof_columns(
     Ok(())
 }
 
+/// The tolerance of an asof join in the physical unit of keys of type `dtype`. A duration is
+/// converted to the time unit of the keys, or to days for dates. Other tolerances are already
+/// in the physical unit.
+pub(super) fn tolerance_in_key_unit(
+    tolerance: Option<AnyValue<'static>>,
+    dtype: &DataType,
+) -> PolarsResult<Option<AnyValue<'static>>> {
+    #[cfg(feature = "dtype-duration")]
+    if let Some(AnyValue::Duration(v, tu)) = tolerance {
+        const NS_IN_DAY: i64 = 86_400_000_000_000;
+        let ns = match tu {
+            TimeUnit::Nanoseconds => v,
+            TimeUnit::Microseconds => v.saturating_mul(1_000),
+            TimeUnit::Milliseconds => v.saturating_mul(1_000_000),
+        };
+        let v = match dtype {
+            DataType::Datetime(tu, _) | DataType::Duration(tu) => match tu {
+                TimeUnit::Nanoseconds => ns,
+                TimeUnit::Microseconds => ns / 1_000,
+                TimeUnit::Milliseconds => ns / 1_000_000,
+            },
+            DataType::Date => ns / NS_IN_DAY,
+            DataType::Time => ns,
+            dt => polars_bail!(
+                InvalidOperation: "a duration tolerance requires temporal keys, got {}", dt
+            ),
+        };
+        return Ok(Some(AnyValue::Int64(v)));
+    }
+    #[cfg(not(feature = "dtype-duration"))]
+    let _ = dtype;
+    Ok(tolerance)
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AsofStrategy {
@@ -203,6 +346,89 @@ pub enum AsofStrategy {
     Nearest,
 }
 
+/// Join the rows of `left` with the strategy of every row, which is given by the column
+/// `strategy_column`, or is `strategy` where that is null. The rows of every strategy are
+/// joined by `join` separately, after which they are put back in their original order.
+pub(super) fn join_asof_per_row_strategy<F>(
+    left: &DataFrame,
+    left_on: &str,
+    strategy_column: &str,
+    strategy: AsofStrategy,
+    slice: Option<(i64, usize)>,
+    join: F,
+) -> PolarsResult<DataFrame>
+where
+    F: Fn(&DataFrame, AsofStrategy, Option<(i64, usize)>) -> PolarsResult<DataFrame>,
+{
+    let strategies = left.column(strategy_column)?;
+    polars_ensure!(
+        strategies.dtype() == &DataType::String,
+        SchemaMismatch: "asof join strategy column '{}' must be of type String, got {}",
+        strategy_column, strategies.dtype()
+    );
+    let strategies = strategies.str()?;
+    if let Some(invalid) = strategies
+        .into_iter()
+        .flatten()
+        .find(|s| !matches!(*s, "backward" | "forward" | "nearest"))
+    {
+        polars_bail!(
+            InvalidOperation: "invalid asof join strategy '{}', expected one of \
+            'backward', 'forward' or 'nearest'", invalid
+        );
+    }
+    if strategies.null_count() == strategies.len() {
+        return join(left, strategy, slice);
+    }
+
+    // The rows of a strategy keep the order of the left key.
+    let sorted = left.column(left_on)?.is_sorted_flag();
+    let mut parts = vec![];
+    let mut rows = Vec::with_capacity(left.height());
+    for (name, row_strategy) in [
+        ("backward", AsofStrategy::Backward),
+        ("forward", AsofStrategy::Forward),
+        ("nearest", AsofStrategy::Nearest),
+    ] {
+        let mask = strategies
+            .into_iter()
+            .map(|s| s.map_or(row_strategy == strategy, |s| s == name))
+            .collect::<BooleanChunked>();
+        if !mask.any() {
+            continue;
+        }
+        rows.extend(
+            mask.into_no_null_iter()
+                .enumerate()
+                .filter_map(|(i, keep)| keep.then_some(i as IdxSize)),
+        );
+        let mut part = left.filter(&mask)?;
+        part.apply(left_on, |s| {
+            let mut s = s.clone();
+            s.set_sorted_flag(sorted);
+            s
+        })?;
+        parts.push(join(&part, row_strategy, None)?);
+    }
+
+    let mut out = parts.remove(0);
+    for part in &parts {
+        out.vstack_mut(part)?;
+    }
+    // The position in the output of every row of `left`.
+    let mut positions = vec![0 as IdxSize; rows.len()];
+    for (position, row) in rows.into_iter().enumerate() {
+        positions[row as usize] = position as IdxSize;
+    }
+    let positions = IdxCa::from_vec("", positions);
+    let positions = match slice {
+        Some((offset, len)) => positions.slice(offset, len),
+        None => positions,
+    };
+    // SAFETY: the positions are in bounds.
+    Ok(unsafe { out.take_unchecked(&positions) })
+}
+
 pub trait AsofJoin: IntoDf {
     #[doc(hidden)]
     #[allow(clippy::too_many_arguments)]
@@ -215,58 +441,61 @@ pub trait AsofJoin: IntoDf {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        allow_eq: bool,
+        right_index_name: Option<&str>,
     ) -> PolarsResult<DataFrame> {
         let self_df = self.to_df();
         let left_key = self_df.column(left_on)?;
         let right_key = other.column(right_on)?;
 
         check_asof_columns(left_key, right_key, tolerance.is_some(), true)?;
+        let tolerance = tolerance_in_key_unit(tolerance, left_key.dtype())?;
         let left_key = left_key.to_physical_repr();
         let right_key = right_key.to_physical_repr();
 
         let mut take_idx = match left_key.dtype() {
             DataType::Int64 => {
                 let ca = left_key.i64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Int32 => {
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::UInt64 => {
                 let ca = left_key.u64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::UInt32 => {
                 let ca = left_key.u32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Float32 => {
                 let ca = left_key.f32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Float64 => {
                 let ca = left_key.f64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Boolean => {
                 let ca = left_key.bool().unwrap();
-                join_asof::<BooleanType>(ca, &right_key, strategy)
+                join_asof::<BooleanType>(ca, &right_key, strategy, allow_eq)
             },
             DataType::Binary => {
                 let ca = left_key.binary().unwrap();
-                join_asof::<BinaryType>(ca, &right_key, strategy)
+                join_asof::<BinaryType>(ca, &right_key, strategy, allow_eq)
             },
             DataType::String => {
                 let ca = left_key.str().unwrap();
                 let right_binary = right_key.cast(&DataType::Binary).unwrap();
-                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy)
+                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy, allow_eq)
             },
             _ => {
                 let left_key = left_key.cast(&DataType::Int32).unwrap();
                 let right_key = right_key.cast(&DataType::Int32).unwrap();
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
         }?;
 
@@ -286,7 +515,11 @@ pub trait AsofJoin: IntoDf {
         // SAFETY: join tuples are in bounds.
         let right_df = unsafe { other.take_unchecked(&take_idx) };
 
-        _finish_join(left, right_df, suffix.as_deref())
+        let mut out = _finish_join(left, right_df, suffix.as_deref())?;
+        if let Some(name) = right_index_name {
+            out.hstack_mut(&[take_idx.with_name(name).into_series()])?;
+        }
+        Ok(out)
     }
 
     /// This is similar to a left-join except that we match on nearest key rather than equal keys.
@@ -300,8 +533,146 @@ pub trait AsofJoin: IntoDf {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<String>,
     ) -> PolarsResult<DataFrame> {
-        self._join_asof(other, left_on, right_on, strategy, tolerance, suffix, None)
+        self._join_asof(
+            other, left_on, right_on, strategy, tolerance, suffix, None, true, None,
+        )
+    }
+
+    /// This is similar to a left-join except that every row is matched to the row of `other`
+    /// whose key is closest to its key, looking in both directions. Ties are broken towards
+    /// the larger key. Rows without a match within `tolerance` get nulls.
+    ///
+    /// Unlike [`join_asof`](AsofJoin::join_asof) the keys don't have to be sorted and the
+    /// output keeps the row order of `self`. The keys must be numeric or temporal.
+    fn join_nearest(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        tolerance: Option<AnyValue<'static>>,
+        suffix: Option<String>,
+    ) -> PolarsResult<DataFrame> {
+        let self_df = self.to_df();
+        let left_key = self_df.column(left_on)?;
+        let right_key = other.column(right_on)?;
+        polars_ensure!(
+            left_key.dtype().to_physical().is_numeric()
+                && right_key.dtype().to_physical().is_numeric(),
+            InvalidOperation: "nearest join is only supported on numeric/temporal keys"
+        );
+        check_asof_columns(left_key, right_key, tolerance.is_some(), false)?;
+        let tolerance = tolerance_in_key_unit(tolerance, left_key.dtype())?;
+
+        // Stable sorts break ties between equal right keys towards the last of them, as the
+        // lazy join does.
+        let sort_options = SortOptions {
+            maintain_order: true,
+            ..Default::default()
+        };
+        let left_order = left_key.arg_sort(sort_options);
+        // The rows of `other` without a key can't be matched; they are sorted first.
+        let right_nulls = right_key.null_count();
+        let right_order = right_key
+            .arg_sort(sort_options)
+            .slice(right_nulls as i64, right_key.len() - right_nulls);
+        // SAFETY: sort indices are in bounds.
+        let (left_sorted, right_sorted) = unsafe {
+            (
+                left_key.to_physical_repr().take_unchecked(&left_order),
+                right_key.to_physical_repr().take_unchecked(&right_order),
+            )
+        };
+        let sorted_idx = with_match_physical_numeric_polars_type!(left_sorted.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = left_sorted.as_ref().as_ref().as_ref();
+            join_asof_numeric(ca, &right_sorted, AsofStrategy::Nearest, tolerance, true)
+        })?;
+
+        // Map the matches back from sorted positions to the original rows on both sides.
+        // SAFETY: the join tuples are in bounds of `right_order`.
+        let right_idx = unsafe { right_order.take_unchecked(&sorted_idx) };
+        let mut take_idx = vec![None; left_order.len()];
+        for (left, right) in left_order.into_no_null_iter().zip(right_idx.iter()) {
+            take_idx[left as usize] = right;
+        }
+        let take_idx = IdxCa::from_slice_options("", &take_idx);
+
+        // Drop right join column.
+        let other = if left_on == right_on {
+            Cow::Owned(other.drop(right_on)?)
+        } else {
+            Cow::Borrowed(other)
+        };
+
+        // SAFETY: join tuples are in bounds.
+        let right_df = unsafe { other.take_unchecked(&take_idx) };
+
+        _finish_join(self_df.clone(), right_df, suffix.as_deref())
     }
 }
 
 impl AsofJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use polars_core::series::IsSorted;
+
+    use super::*;
+    use crate::frame::join::{DataFrameJoinOps, JoinArgs, JoinType};
+
+    fn set_sorted(df: &mut DataFrame, column: &str) -> PolarsResult<()> {
+        df.apply(column, |s| {
+            let mut s = s.clone();
+            s.set_sorted_flag(IsSorted::Ascending);
+            s
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_asof_strategy_per_row() -> PolarsResult<()> {
+        let mut left = df![
+            "a" => [1, 2, 3, 4, 5],
+            "strategy" => [
+                Some("forward"),
+                None,
+                Some("nearest"),
+                Some("backward"),
+                Some("forward")
+            ]
+        ]?;
+        let mut right = df![
+            "a" => [2, 4, 4, 6],
+            "right_vals" => [20, 40, 41, 60]
+        ]?;
+        set_sorted(&mut left, "a")?;
+        set_sorted(&mut right, "a")?;
+
+        let options = AsOfOptions {
+            strategy_column: Some("strategy".into()),
+            ..Default::default()
+        };
+        let mut args = JoinArgs::new(JoinType::AsOf(options.clone()));
+        let out = left.join(&right, ["a"], ["a"], args.clone())?;
+        assert_eq!(out.get_column_names(), &["a", "strategy", "right_vals"]);
+        let vals = out.column("right_vals")?.i32()?;
+        assert_eq!(
+            Vec::from(vals),
+            &[Some(20), Some(20), Some(41), Some(41), Some(60)]
+        );
+
+        // The slice applies to the rows in their original order.
+        args.slice = Some((1, 3));
+        let out = left.join(&right, ["a"], ["a"], args)?;
+        let vals = out.column("right_vals")?.i32()?;
+        assert_eq!(Vec::from(vals), &[Some(20), Some(41), Some(41)]);
+
+        let mut left = df![
+            "a" => [1, 2],
+            "strategy" => ["forward", "sideways"]
+        ]?;
+        set_sorted(&mut left, "a")?;
+        let args = JoinArgs::new(JoinType::AsOf(options));
+        assert!(left.join(&right, ["a"], ["a"], args).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+use crate::series::approx_n_unique;
+
+fn approx_n_unique_keys(keys: &Series) -> PolarsResult<f64> {
+    let n_unique = approx_n_unique(keys)?;
+    Ok(n_unique.idx()?.get(0).unwrap_or(0).max(1) as f64)
+}
+
+impl JoinExpansionGuard {
+    /// Estimate the output rows of a join on the given keys and apply the guard if the
+    /// estimate exceeds `max_factor` times the rows of the larger input.
+    ///
+    /// The estimate assumes that the rows are spread evenly over the distinct keys of the
+    /// side with the most of them, as is usual for join cardinality estimation.
+    pub(super) fn check(
+        &self,
+        how: &JoinType,
+        left_keys: &Series,
+        right_keys: &Series,
+    ) -> PolarsResult<()> {
+        let (left, right) = (left_keys.len() as f64, right_keys.len() as f64);
+        let estimate = match how {
+            JoinType::Inner | JoinType::Left | JoinType::Outer => {
+                let n_unique =
+                    approx_n_unique_keys(left_keys)?.max(approx_n_unique_keys(right_keys)?);
+                let matches = left * right / n_unique;
+                match how {
+                    JoinType::Left => matches.max(left),
+                    JoinType::Outer => matches.max(left).max(right),
+                    _ => matches,
+                }
+            },
+            // The other joins produce at most a row per row of the left side.
+            _ => return Ok(()),
+        };
+        self.apply(how, estimate, left, right)
+    }
+
+    /// Apply the guard to a cross join, whose output rows are known.
+    pub(super) fn check_cross(&self, left_rows: usize, right_rows: usize) -> PolarsResult<()> {
+        let (left, right) = (left_rows as f64, right_rows as f64);
+        self.apply(&JoinType::Cross, left * right, left, right)
+    }
+
+    fn apply(&self, how: &JoinType, estimate: f64, left: f64, right: f64) -> PolarsResult<()> {
+        if estimate <= self.max_factor as f64 * left.max(right).max(1.0) {
+            return Ok(());
+        }
+        let msg = format!(
+            "{} join is estimated to produce {} rows from inputs of {} and {} rows, \
+            exceeding the maximum expansion factor of {}",
+            how, estimate as u64, left, right, self.max_factor
+        );
+        match self.action {
+            JoinExpansionAction::Raise => polars_bail!(ComputeError: msg),
+            JoinExpansionAction::Warn => polars_warn!(msg),
+        }
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@ mod asof;
 #[cfg(feature = "dtype-categorical")]
 mod checks;
 mod cross_join;
+#[cfg(feature = "approx_unique")]
+mod expansion;
 mod general;
 mod hash_join;
 #[cfg(feature = "merge_sorted")]
@@ -17,6 +19,8 @@ use ahash::RandomState;
 pub use args::*;
 use arrow::trusted_len::TrustedLen;
 #[cfg(feature = "asof_join")]
+use asof::join_asof_per_row_strategy;
+#[cfg(feature = "asof_join")]
 pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
 #[cfg(feature = "dtype-categorical")]
 pub(crate) use checks::*;
@@ -95,6 +99,10 @@ pub trait DataFrameJoinOps: IntoDf {
         let df_left = self.to_df();
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = args.how {
+            #[cfg(feature = "approx_unique")]
+            if let Some(guard) = &args.expansion_guard {
+                guard.check_cross(left_df.height(), other.height())?;
+            }
             return df_left.cross_join(other, args.suffix.as_deref(), None);
         }
         let selected_left = df_left.select_series(left_on)?;
@@ -202,6 +210,10 @@ pub trait DataFrameJoinOps: IntoDf {
         if selected_left.len() == 1 {
             let s_left = &selected_left[0];
             let s_right = &selected_right[0];
+            #[cfg(feature = "approx_unique")]
+            if let Some(guard) = &args.expansion_guard {
+                guard.check(&args.how, s_left, s_right)?;
+            }
             return match args.how {
                 JoinType::Inner => {
                     left_df._inner_join_from_series(other, s_left, s_right, args, _verbose, None)
@@ -231,30 +243,48 @@ pub trait DataFrameJoinOps: IntoDf {
                     let left_on = selected_left[0].name();
                     let right_on = selected_right[0].name();
 
-                    match (options.left_by, options.right_by) {
+                    let join = |left_df: &DataFrame, strategy, slice| match (
+                        &options.left_by,
+                        &options.right_by,
+                    ) {
                         (Some(left_by), Some(right_by)) => left_df._join_asof_by(
                             other,
                             left_on,
                             right_on,
-                            left_by,
-                            right_by,
-                            options.strategy,
-                            options.tolerance,
+                            left_by.clone(),
+                            right_by.clone(),
+                            strategy,
+                            options.tolerance.clone(),
                             args.suffix.as_deref(),
-                            args.slice,
+                            slice,
+                            options.allow_exact_matches,
+                            options.right_index_name.as_deref(),
                         ),
                         (None, None) => left_df._join_asof(
                             other,
                             left_on,
                             right_on,
-                            options.strategy,
-                            options.tolerance,
-                            args.suffix,
-                            args.slice,
+                            strategy,
+                            options.tolerance.clone(),
+                            args.suffix.clone(),
+                            slice,
+                            options.allow_exact_matches,
+                            options.right_index_name.as_deref(),
                         ),
                         _ => {
                             panic!("expected by arguments on both sides")
                         },
+                    };
+                    match &options.strategy_column {
+                        Some(strategy_column) => join_asof_per_row_strategy(
+                            left_df,
+                            left_on,
+                            strategy_column,
+                            options.strategy,
+                            args.slice,
+                            join,
+                        ),
+                        None => join(left_df, options.strategy, args.slice),
                     }
                 },
                 JoinType::Cross => {
@@ -266,6 +296,10 @@ pub trait DataFrameJoinOps: IntoDf {
         let lhs_keys = prepare_keys_multiple(&selected_left, args.join_nulls)?.into_series();
         let rhs_keys = prepare_keys_multiple(&selected_right, args.join_nulls)?.into_series();
         let names_right = selected_right.iter().map(|s| s.name()).collect::<Vec<_>>();
+        #[cfg(feature = "approx_unique")]
+        if let Some(guard) = &args.expansion_guard {
+            guard.check(&args.how, &lhs_keys, &rhs_keys)?;
+        }
 
         // Multiple keys.
         match args.how {
@@ -1,7 +1,11 @@
+#[cfg(feature = "cov")]
+mod corr;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
+#[cfg(feature = "cov")]
+pub use corr::*;
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::export::rayon::prelude::*;
@@ -111,4 +115,21 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Compute the correlation matrix of the numeric columns.
+    ///
+    /// All pairs are computed in parallel and nulls are handled pairwise. The output has one
+    /// column per numeric input column, with the rows in the same order.
+    #[cfg(feature = "cov")]
+    fn corr(&self, method: CorrelationMatrixMethod, ddof: u8) -> PolarsResult<DataFrame> {
+        corr_matrix(self.to_df(), method, ddof)
+    }
+
+    /// Compute the covariance matrix of the numeric columns.
+    ///
+    /// See [`DataFrameOps::corr`] for the layout of the output.
+    #[cfg(feature = "cov")]
+    fn cov(&self, ddof: u8) -> PolarsResult<DataFrame> {
+        cov_matrix(self.to_df(), ddof)
+    }
 }
@@ -23,6 +23,24 @@ pub enum PivotAgg {
     Expr(Arc<dyn PhysicalAggExpr + Send + Sync>),
 }
 
+impl PivotAgg {
+    /// Name of the aggregation in generated column names.
+    pub fn name(&self) -> &str {
+        use PivotAgg::*;
+        match self {
+            First => "first",
+            Sum => "sum",
+            Min => "min",
+            Max => "max",
+            Mean => "mean",
+            Median => "median",
+            Count => "count",
+            Last => "last",
+            Expr(expr) => expr.name().unwrap_or("expr"),
+        }
+    }
+}
+
 fn restore_logical_type(s: &Series, logical_type: &DataType) -> Series {
     // restore logical type
     match (logical_type, s.dtype()) {
@@ -113,9 +131,10 @@ where
         &index,
         &columns,
         &values,
-        agg_fn,
+        &[agg_fn],
         sort_columns,
         false,
+        None,
         separator,
     )
 }
@@ -156,13 +175,77 @@ where
         &index,
         &columns,
         &values,
-        agg_fn,
+        &[agg_fn],
         sort_columns,
         true,
+        None,
         separator,
     )
 }
 
+/// Do a pivot operation that computes several aggregation functions on several values columns
+/// at once.
+///
+/// Every combination of a values column and an aggregation function yields a block of
+/// columns. The names of these columns follow `name_template`, in which `{value}` is replaced
+/// by the name of the values column, `{agg}` by the name of the aggregation (see
+/// [`PivotAgg::name`]), `{on}` by the name of the pivoted column and `{header}` by the
+/// pivoted value. If no template is given, the names are joined with `separator`.
+///
+/// # Note
+/// Polars'/arrow memory is not ideal for transposing operations like pivots.
+/// If you have a relatively large table, consider using a group_by over a pivot.
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_multi<I0, I1, I2, S0, S1, S2>(
+    pivot_df: &DataFrame,
+    index: I0,
+    columns: I1,
+    values: Option<I2>,
+    sort_columns: bool,
+    agg_fns: Vec<PivotAgg>,
+    name_template: Option<&str>,
+    separator: Option<&str>,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: AsRef<str>,
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    polars_ensure!(!agg_fns.is_empty(), ComputeError: "agg_fns cannot be zero length");
+    let index = index
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    let columns = columns
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    let values = get_values_columns(pivot_df, &index, &columns, values);
+    let agg_fns = agg_fns.into_iter().map(Some).collect::<Vec<_>>();
+    let out = pivot_impl(
+        pivot_df,
+        &index,
+        &columns,
+        &values,
+        &agg_fns,
+        sort_columns,
+        true,
+        name_template,
+        separator,
+    )?;
+    let mut names = PlHashSet::with_capacity(out.width());
+    for name in out.get_column_names() {
+        polars_ensure!(
+            names.insert(name),
+            Duplicate: "pivot generated the column name {:?} more than once, please use a different `name_template`", name
+        );
+    }
+    Ok(out)
+}
+
 /// Determine `values` columns, which is optional in `pivot` calls.
 ///
 /// If not specified (i.e. is `None`), use all remaining columns in the
@@ -198,10 +281,12 @@ fn pivot_impl(
     columns: &[String],
     // these columns will be aggregated in the nested group_by
     values: &[String],
-    // aggregation function
-    agg_fn: Option<PivotAgg>,
+    // aggregation functions, every one is applied to every values column
+    agg_fns: &[Option<PivotAgg>],
     sort_columns: bool,
     stable: bool,
+    // template of the generated column names.
+    name_template: Option<&str>,
     // used as separator/delimiter in generated column names.
     separator: Option<&str>,
 ) -> PolarsResult<DataFrame> {
@@ -227,8 +312,9 @@ fn pivot_impl(
             index,
             &column,
             values,
-            agg_fn,
+            agg_fns,
             sort_columns,
+            name_template,
             separator,
         )
     } else {
@@ -237,20 +323,23 @@ fn pivot_impl(
             index,
             unsafe { columns.get_unchecked(0) },
             values,
-            agg_fn,
+            agg_fns,
             sort_columns,
+            name_template,
             separator,
         )
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn pivot_impl_single_column(
     pivot_df: &DataFrame,
     index: &[String],
     column: &str,
     values: &[String],
-    agg_fn: Option<PivotAgg>,
+    agg_fns: &[Option<PivotAgg>],
     sort_columns: bool,
+    name_template: Option<&str>,
     separator: Option<&str>,
 ) -> PolarsResult<DataFrame> {
     let sep = separator.unwrap_or("_");
@@ -272,89 +361,104 @@ fn pivot_impl_single_column(
         for value_col_name in values {
             let value_col = pivot_df.column(value_col_name)?;
 
-            use PivotAgg::*;
-            let value_agg = unsafe {
-                match &agg_fn {
-                    None => match value_col.len() > groups.len() {
-                        true => polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function"),
-                        false => value_col.agg_first(&groups),
-                    }
-                    Some(agg_fn) => match agg_fn {
-                        Sum => value_col.agg_sum(&groups),
-                        Min => value_col.agg_min(&groups),
-                        Max => value_col.agg_max(&groups),
-                        Last => value_col.agg_last(&groups),
-                        First => value_col.agg_first(&groups),
-                        Mean => value_col.agg_mean(&groups),
-                        Median => value_col.agg_median(&groups),
-                        Count => groups.group_count().into_series(),
-                        Expr(ref expr) => {
-                            let name = expr.root_name()?;
-                            let mut value_col = value_col.clone();
-                            value_col.rename(name);
-                            let tmp_df = value_col.into_frame();
-                            let mut aggregated = expr.evaluate(&tmp_df, &groups)?;
-                            aggregated.rename(value_col_name);
-                            aggregated
+            for agg_fn in agg_fns {
+                use PivotAgg::*;
+                let value_agg = unsafe {
+                    match agg_fn {
+                        None => match value_col.len() > groups.len() {
+                            true => polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function"),
+                            false => value_col.agg_first(&groups),
                         }
-                    },
+                        Some(agg_fn) => match agg_fn {
+                            Sum => value_col.agg_sum(&groups),
+                            Min => value_col.agg_min(&groups),
+                            Max => value_col.agg_max(&groups),
+                            Last => value_col.agg_last(&groups),
+                            First => value_col.agg_first(&groups),
+                            Mean => value_col.agg_mean(&groups),
+                            Median => value_col.agg_median(&groups),
+                            Count => groups.group_count().into_series(),
+                            Expr(ref expr) => {
+                                let name = expr.root_name()?;
+                                let mut value_col = value_col.clone();
+                                value_col.rename(name);
+                                let tmp_df = value_col.into_frame();
+                                let mut aggregated = expr.evaluate(&tmp_df, &groups)?;
+                                aggregated.rename(value_col_name);
+                                aggregated
+                            }
+                        },
+                    }
+                };
+
+                let headers = column_agg.unique_stable()?.cast(&DataType::String)?;
+                let mut headers = headers.str().unwrap().clone();
+                let agg_name = agg_fn.as_ref().map_or("first", |agg_fn| agg_fn.name());
+                if let Some(template) = name_template {
+                    headers = headers.apply_values(|v| {
+                        Cow::from(
+                            template
+                                .replace("{value}", value_col_name)
+                                .replace("{agg}", agg_name)
+                                .replace("{on}", column)
+                                .replace("{header}", v),
+                        )
+                    })
+                } else if agg_fns.len() > 1 {
+                    headers = headers.apply_values(|v| Cow::from(format!("{value_col_name}{sep}{agg_name}{sep}{column}{sep}{v}")))
+                } else if values.len() > 1 {
+                    // TODO! MILESTONE 1.0: change to `format!("{value_col_name}{sep}{v}")`
+                    headers = headers.apply_values(|v| Cow::from(format!("{value_col_name}{sep}{column}{sep}{v}")))
                 }
-            };
 
-            let headers = column_agg.unique_stable()?.cast(&DataType::String)?;
-            let mut headers = headers.str().unwrap().clone();
-            if values.len() > 1 {
-                // TODO! MILESTONE 1.0: change to `format!("{value_col_name}{sep}{v}")`
-                headers = headers.apply_values(|v| Cow::from(format!("{value_col_name}{sep}{column}{sep}{v}")))
-            }
+                let n_cols = headers.len();
+                let value_agg_phys = value_agg.to_physical_repr();
+                let logical_type = value_agg.dtype();
 
-            let n_cols = headers.len();
-            let value_agg_phys = value_agg.to_physical_repr();
-            let logical_type = value_agg.dtype();
+                debug_assert_eq!(row_locations.len(), col_locations.len());
+                debug_assert_eq!(value_agg_phys.len(), row_locations.len());
 
-            debug_assert_eq!(row_locations.len(), col_locations.len());
-            debug_assert_eq!(value_agg_phys.len(), row_locations.len());
+                let mut cols = if value_agg_phys.dtype().is_numeric() {
+                    macro_rules! dispatch {
+                        ($ca:expr) => {{
+                            positioning::position_aggregates_numeric(
+                                n_rows,
+                                n_cols,
+                                &row_locations,
+                                &col_locations,
+                                $ca,
+                                logical_type,
+                                &headers,
+                            )
+                        }};
+                    }
+                    downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
+                } else {
+                    positioning::position_aggregates(
+                        n_rows,
+                        n_cols,
+                        &row_locations,
+                        &col_locations,
+                        &value_agg_phys,
+                        logical_type,
+                        &headers,
+                    )
+                };
 
-            let mut cols = if value_agg_phys.dtype().is_numeric() {
-                macro_rules! dispatch {
-                    ($ca:expr) => {{
-                        positioning::position_aggregates_numeric(
-                            n_rows,
-                            n_cols,
-                            &row_locations,
-                            &col_locations,
-                            $ca,
-                            logical_type,
-                            &headers,
-                        )
-                    }};
+                if sort_columns {
+                    cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
                 }
-                downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
-            } else {
-                positioning::position_aggregates(
-                    n_rows,
-                    n_cols,
-                    &row_locations,
-                    &col_locations,
-                    &value_agg_phys,
-                    logical_type,
-                    &headers,
-                )
-            };
 
-            if sort_columns {
-                cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
+                let cols = if count == 0 {
+                    let mut final_cols = row_index.take().unwrap();
+                    final_cols.extend(cols);
+                    final_cols
+                } else {
+                    cols
+                };
+                count += 1;
+                final_cols.extend_from_slice(&cols);
             }
-
-            let cols = if count == 0 {
-                let mut final_cols = row_index.take().unwrap();
-                final_cols.extend(cols);
-                final_cols
-            } else {
-                cols
-            };
-            count += 1;
-            final_cols.extend_from_slice(&cols);
         }
         Ok(())
     });
@@ -26,6 +26,7 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
     match s.dtype() {
         Boolean => s.bool().and_then(approx_n_unique_ca),
         Binary => s.binary().and_then(approx_n_unique_ca),
+        BinaryOffset => s.binary_offset().and_then(approx_n_unique_ca),
         String => {
             let ca = s.str().unwrap().as_binary();
             approx_n_unique_ca(&ca)
@@ -0,0 +1,113 @@
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+/// The length of the output of `case_when`, where inputs of length 1 are broadcast.
+fn output_len<'a>(inputs: impl IntoIterator<Item = &'a Series>) -> PolarsResult<usize> {
+    let mut len = 1;
+    for s in inputs {
+        if s.len() != 1 {
+            polars_ensure!(
+                len == 1 || len == s.len(),
+                ShapeMismatch: "shapes of the conditions and values of 'case_when' don't match: \
+                {} and {}", len, s.len()
+            );
+            len = s.len();
+        }
+    }
+    Ok(len)
+}
+
+/// The branch of the first condition that is true for every row, or `conditions.len()` for
+/// the rows where no condition is true. A null condition counts as false.
+fn select_branches(conditions: &[Series], len: usize) -> PolarsResult<Vec<IdxSize>> {
+    let mut branches = vec![conditions.len() as IdxSize; len];
+    // Visit the conditions in reverse, so that the first true condition wins.
+    for (branch, cond) in conditions.iter().enumerate().rev() {
+        if cond.dtype() == &DataType::Null {
+            continue;
+        }
+        let cond = cond.bool()?;
+        if cond.len() == 1 {
+            if cond.get(0) == Some(true) {
+                branches.fill(branch as IdxSize);
+            }
+            continue;
+        }
+        let mut offset = 0;
+        for arr in cond.downcast_iter() {
+            let values = arr.values();
+            match arr.validity() {
+                Some(validity) if arr.null_count() > 0 => {
+                    for (i, (v, valid)) in values.iter().zip(validity.iter()).enumerate() {
+                        if v && valid {
+                            branches[offset + i] = branch as IdxSize;
+                        }
+                    }
+                },
+                _ => {
+                    for (i, v) in values.iter().enumerate() {
+                        if v {
+                            branches[offset + i] = branch as IdxSize;
+                        }
+                    }
+                },
+            }
+            offset += arr.len();
+        }
+    }
+    Ok(branches)
+}
+
+/// Take the value of the branch of the first true condition for every row, or the value of
+/// `otherwise` if no condition is true.
+///
+/// If all values are scalars, they are appended into a jump table with one entry per branch
+/// that is gathered with the selected branch of every row in a single pass. Otherwise every
+/// branch is zipped into the output with a mask of the rows that select it, which doesn't
+/// materialize the values of all branches at once.
+///
+/// Conditions and values of length 1 are broadcast. The values are cast to their supertype
+/// and the output takes the name of the first value.
+pub fn case_when(
+    conditions: &[Series],
+    values: &[Series],
+    otherwise: &Series,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        conditions.len() == values.len(),
+        ComputeError: "'case_when' expects as many conditions as values, got {} and {}",
+        conditions.len(), values.len()
+    );
+    let len = output_len(conditions.iter().chain(values).chain([otherwise]))?;
+
+    let mut dtype = otherwise.dtype().clone();
+    for s in values {
+        dtype = try_get_supertype(&dtype, s.dtype())?;
+    }
+
+    let branches = select_branches(conditions, len)?;
+
+    let name = values.first().unwrap_or(otherwise).name();
+    if values.iter().chain([otherwise]).all(|s| s.len() == 1) {
+        let mut table = Series::new_empty(name, &dtype);
+        for s in values.iter().chain([otherwise]) {
+            table.append(&s.cast(&dtype)?)?;
+        }
+        // SAFETY: the table has an entry for every branch and for `otherwise`.
+        let out = unsafe { table.take_slice_unchecked(&branches) };
+        return Ok(out.with_name(name));
+    }
+
+    let mut out = otherwise.cast(&dtype)?;
+    if out.len() != len {
+        out = out.new_from_index(0, len);
+    }
+    for (branch, s) in values.iter().enumerate() {
+        let mask =
+            BooleanChunked::from_iter_values("", branches.iter().map(|b| *b as usize == branch));
+        if mask.any() {
+            out = s.cast(&dtype)?.zip_with(&mask, &out)?;
+        }
+    }
+    Ok(out.with_name(name))
+}
@@ -0,0 +1,151 @@
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+/// Point the null rows of `rows` to the last valid row before them, filling at most `limit`
+/// consecutive null rows. The other null rows keep their index.
+fn fill_rows(
+    rows: impl Iterator<Item = IdxSize>,
+    validity: &Bitmap,
+    limit: FillNullLimit,
+    idx: &mut [Option<IdxSize>],
+) {
+    let mut last_valid = None;
+    let mut n_filled = 0;
+    for row in rows {
+        if validity.get_bit(row as usize) {
+            last_valid = Some(row);
+            n_filled = 0;
+        } else if let Some(src) = last_valid {
+            if limit.map_or(true, |limit| n_filled < limit) {
+                idx[row as usize] = Some(src);
+                n_filled += 1;
+            }
+        }
+    }
+}
+
+/// [`fill_rows`] in the direction of the fill.
+fn fill_group(
+    rows: impl DoubleEndedIterator<Item = IdxSize>,
+    forward: bool,
+    validity: &Bitmap,
+    limit: FillNullLimit,
+    idx: &mut [Option<IdxSize>],
+) {
+    if forward {
+        fill_rows(rows, validity, limit, idx)
+    } else {
+        fill_rows(rows.rev(), validity, limit, idx)
+    }
+}
+
+/// Whether a row is in more than one group, as in the windows of rolling and dynamic
+/// group-bys.
+pub fn groups_overlap(groups: &GroupsProxy, len: usize) -> bool {
+    let mut seen = MutableBitmap::from_len_zeroed(len);
+    let mut visit = |row: IdxSize| {
+        let overlaps = seen.get(row as usize);
+        seen.set(row as usize, true);
+        overlaps
+    };
+    match groups {
+        GroupsProxy::Idx(groups) => groups.all().iter().flatten().any(|&row| visit(row)),
+        GroupsProxy::Slice { groups, .. } => {
+            // Slices are sorted by their start, unless a previous operation reordered them.
+            if groups.windows(2).all(|w| w[0][0] <= w[1][0]) {
+                let mut end = 0;
+                groups.iter().any(|&[first, group_len]| {
+                    let overlaps = group_len > 0 && first < end;
+                    end = end.max(first + group_len);
+                    overlaps
+                })
+            } else {
+                groups
+                    .iter()
+                    .any(|&[first, group_len]| (first..first + group_len).any(&mut visit))
+            }
+        },
+    }
+}
+
+/// Fill the null values of `s` with the previous (`Forward`) or next (`Backward`) non-null
+/// value, filling at most `limit` consecutive null values. The null values that are left take
+/// the value of `fill_value` in the same row, where a `fill_value` of length 1 is broadcast.
+///
+/// With `groups`, null values are only filled with the values of the same group. The fill
+/// indices of all groups are computed in a single pass and the values are gathered at once,
+/// instead of filling the values of every group separately. As the output has a value per
+/// row, the groups can't overlap, see [`groups_overlap`].
+pub fn fill_null_by_groups(
+    s: &Series,
+    groups: Option<&GroupsProxy>,
+    strategy: FillNullStrategy,
+    fill_value: Option<&Series>,
+) -> PolarsResult<Series> {
+    let (forward, limit) = match strategy {
+        FillNullStrategy::Forward(limit) => (true, limit),
+        FillNullStrategy::Backward(limit) => (false, limit),
+        _ => polars_bail!(
+            InvalidOperation: "only the 'forward' and 'backward' strategies can be combined \
+            with a fill value or applied per group, got {:?}", strategy
+        ),
+    };
+    let len = s.len();
+    if let Some(groups) = groups {
+        polars_ensure!(
+            !groups_overlap(groups, len),
+            InvalidOperation: "cannot fill the null values of overlapping groups in a single pass"
+        );
+    }
+    let dtype = match fill_value {
+        Some(fill_value) => {
+            polars_ensure!(
+                fill_value.len() == 1 || fill_value.len() == len,
+                ShapeMismatch: "the fill value of 'fill_null' should have length 1 or {}, got {}",
+                len, fill_value.len()
+            );
+            try_get_supertype(s.dtype(), fill_value.dtype())?
+        },
+        None => s.dtype().clone(),
+    };
+    if s.null_count() == 0 {
+        return s.cast(&dtype);
+    }
+
+    let validity = s.is_not_null().rechunk();
+    let validity = validity.downcast_iter().next().unwrap().values();
+
+    // The null rows that aren't filled by the strategy point to the fill value, which is
+    // appended to the values.
+    let mut idx = (0..len as IdxSize)
+        .map(|row| {
+            if validity.get_bit(row as usize) {
+                Some(row)
+            } else {
+                fill_value.map(|v| len as IdxSize + if v.len() == 1 { 0 } else { row })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    match groups {
+        None => fill_group(0..len as IdxSize, forward, validity, limit, &mut idx),
+        Some(GroupsProxy::Idx(groups)) => {
+            for (_, group) in groups.iter() {
+                fill_group(group.iter().copied(), forward, validity, limit, &mut idx)
+            }
+        },
+        Some(GroupsProxy::Slice { groups, .. }) => {
+            for &[first, group_len] in groups {
+                fill_group(first..first + group_len, forward, validity, limit, &mut idx)
+            }
+        },
+    }
+
+    let mut table = s.cast(&dtype)?;
+    if let Some(fill_value) = fill_value {
+        table.append(&fill_value.cast(&dtype)?)?;
+    }
+    let idx = idx.into_iter().collect::<IdxCa>();
+    Ok(table.take(&idx)?.with_name(s.name()))
+}
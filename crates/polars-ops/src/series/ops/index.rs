@@ -1,7 +1,24 @@
 use num_traits::{Signed, Zero};
-use polars_core::error::{polars_ensure, PolarsResult};
-use polars_core::prelude::{ChunkedArray, DataType, IdxCa, PolarsIntegerType, Series, IDX_DTYPE};
+use polars_core::error::{polars_bail, polars_ensure, PolarsResult};
+use polars_core::prelude::{
+    ChunkedArray, DataType, IdxCa, IdxSize, PolarsIntegerType, Series, IDX_DTYPE,
+};
 use polars_utils::index::ToIdx;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How to handle indices that are out of bounds when gathering values.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutOfBoundsPolicy {
+    /// Raise an error.
+    #[default]
+    Error,
+    /// Gather a null value.
+    Null,
+    /// Gather the first or the last value, whichever is closest.
+    Clamp,
+}
 
 fn convert<T>(ca: &ChunkedArray<T>, target_len: usize) -> PolarsResult<IdxCa>
 where
@@ -44,6 +61,48 @@ pub fn convert_to_unsigned_index(s: &Series, target_len: usize) -> PolarsResult<
     }
 }
 
+/// Resolve a possibly negative index into a collection of length `len`, applying `policy` if
+/// it is out of bounds. Returns `None` if a null value should be gathered.
+pub fn resolve_index(
+    idx: i64,
+    len: usize,
+    policy: OutOfBoundsPolicy,
+) -> PolarsResult<Option<IdxSize>> {
+    let len = len as i64;
+    let idx = if idx < 0 { idx + len } else { idx };
+    if (0..len).contains(&idx) {
+        return Ok(Some(idx as IdxSize));
+    }
+    match policy {
+        OutOfBoundsPolicy::Error => polars_bail!(OutOfBounds: "gather indices are out of bounds"),
+        OutOfBoundsPolicy::Null => Ok(None),
+        OutOfBoundsPolicy::Clamp if len == 0 => Ok(None),
+        OutOfBoundsPolicy::Clamp => Ok(Some(idx.clamp(0, len - 1) as IdxSize)),
+    }
+}
+
+/// Like [`convert_to_unsigned_index`], but applies `policy` to the indices that are out of
+/// bounds.
+pub fn convert_to_unsigned_index_with_policy(
+    s: &Series,
+    target_len: usize,
+    policy: OutOfBoundsPolicy,
+) -> PolarsResult<IdxCa> {
+    if policy == OutOfBoundsPolicy::Error {
+        return convert_to_unsigned_index(s, target_len);
+    }
+    polars_ensure!(s.dtype().is_integer(), InvalidOperation: "expected integers as index");
+    let s = s.strict_cast(&DataType::Int64)?;
+    s.i64()
+        .unwrap()
+        .iter()
+        .map(|idx| match idx {
+            Some(idx) => resolve_index(idx, target_len, policy),
+            None => Ok(None),
+        })
+        .collect()
+}
+
 /// May give false negatives because it ignores the null values.
 fn is_positive_idx_uncertain_impl<T>(ca: &ChunkedArray<T>) -> bool
 where
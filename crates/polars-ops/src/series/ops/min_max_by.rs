@@ -0,0 +1,137 @@
+use std::cmp::Ordering;
+
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// How nulls in the `by` column of [`min_by`] and [`max_by`] are handled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ByNullPolicy {
+    /// Skip the rows where `by` is null.
+    #[default]
+    Ignore,
+    /// Return null if `by` contains a null.
+    Propagate,
+}
+
+/// Which row [`min_by`] and [`max_by`] pick if the extremum of `by` occurs more than once.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ByTiePolicy {
+    /// The first row with the extremum.
+    #[default]
+    First,
+    /// The last row with the extremum.
+    Last,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinMaxByOptions {
+    pub nulls: ByNullPolicy,
+    pub ties: ByTiePolicy,
+}
+
+/// The index of the extremum of `iter` in a single pass.
+fn arg_extremum<V, I, C>(iter: I, cmp: C, max: bool, options: MinMaxByOptions) -> Option<usize>
+where
+    I: Iterator<Item = Option<V>>,
+    C: Fn(&V, &V) -> Ordering,
+{
+    let mut best: Option<(usize, V)> = None;
+    for (idx, opt_v) in iter.enumerate() {
+        let Some(v) = opt_v else {
+            match options.nulls {
+                ByNullPolicy::Ignore => continue,
+                ByNullPolicy::Propagate => return None,
+            }
+        };
+        let replace = match &best {
+            None => true,
+            Some((_, best_v)) => {
+                let ord = if max {
+                    cmp(&v, best_v)
+                } else {
+                    cmp(best_v, &v)
+                };
+                match ord {
+                    Ordering::Greater => true,
+                    Ordering::Equal => options.ties == ByTiePolicy::Last,
+                    Ordering::Less => false,
+                }
+            },
+        };
+        if replace {
+            best = Some((idx, v));
+        }
+    }
+    best.map(|(idx, _)| idx)
+}
+
+/// The index of the row where `by` has its minimum (or maximum if `max`).
+///
+/// Floats are compared in total order, so `NaN` is larger than every other value.
+pub fn arg_min_max_by(
+    by: &Series,
+    max: bool,
+    options: MinMaxByOptions,
+) -> PolarsResult<Option<usize>> {
+    let by = by.to_physical_repr();
+    let idx = match by.dtype() {
+        DataType::String => {
+            let ca = by.str().unwrap();
+            arg_extremum(ca.iter(), |a, b| a.cmp(b), max, options)
+        },
+        DataType::Binary => {
+            let ca = by.binary().unwrap();
+            arg_extremum(ca.iter(), |a, b| a.cmp(b), max, options)
+        },
+        DataType::Boolean => {
+            let ca = by.bool().unwrap();
+            arg_extremum(ca.iter(), |a, b| a.cmp(b), max, options)
+        },
+        dt if dt.is_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = by.as_ref().as_ref().as_ref();
+                arg_extremum(ca.iter(), |a, b| a.tot_cmp(b), max, options)
+            })
+        },
+        dt => polars_bail!(opq = min_max_by, dt),
+    };
+    Ok(idx)
+}
+
+fn min_max_by(
+    s: &Series,
+    by: &Series,
+    max: bool,
+    options: MinMaxByOptions,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == by.len(),
+        ShapeMismatch: "`by` must have the same length as the values, got {} and {}",
+        s.len(), by.len()
+    );
+    Ok(match arg_min_max_by(by, max, options)? {
+        Some(idx) => s.slice(idx as i64, 1),
+        None => Series::full_null(s.name(), 1, s.dtype()),
+    })
+}
+
+/// The value of `s` in the row where `by` is minimal, as a unit length Series.
+///
+/// The result is null if `by` has no (non-null) values.
+pub fn min_by(s: &Series, by: &Series, options: MinMaxByOptions) -> PolarsResult<Series> {
+    min_max_by(s, by, false, options)
+}
+
+/// The value of `s` in the row where `by` is maximal, as a unit length Series.
+///
+/// The result is null if `by` has no (non-null) values.
+pub fn max_by(s: &Series, by: &Series, options: MinMaxByOptions) -> PolarsResult<Series> {
+    min_max_by(s, by, true, options)
+}
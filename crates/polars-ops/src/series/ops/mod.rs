@@ -7,6 +7,7 @@ mod approx_unique;
 mod arg_min_max;
 #[cfg(feature = "business")]
 mod business;
+mod case_when;
 mod clip;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
@@ -18,6 +19,7 @@ mod diff;
 mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
+mod fill_null;
 #[cfg(feature = "round_series")]
 mod floor_divide;
 #[cfg(feature = "fused")]
@@ -37,9 +39,17 @@ mod is_last_distinct;
 mod is_unique;
 #[cfg(feature = "log")]
 mod log;
+mod min_max_by;
 #[cfg(feature = "moment")]
 mod moment;
+#[cfg(feature = "dtype-struct")]
+mod multi_output;
 mod negate;
+#[cfg(feature = "ols")]
+mod ols;
+#[cfg(feature = "outliers")]
+mod outliers;
+mod overflow;
 #[cfg(feature = "pct_change")]
 mod pct_change;
 #[cfg(feature = "rank")]
@@ -56,6 +66,8 @@ mod rolling;
 mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "time_weighted")]
+mod time_weighted;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
@@ -71,6 +83,7 @@ pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
 #[cfg(feature = "business")]
 pub use business::*;
+pub use case_when::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
@@ -82,6 +95,7 @@ pub use diff::*;
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
 pub use ewm_by::*;
+pub use fill_null::*;
 #[cfg(feature = "round_series")]
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
@@ -101,9 +115,17 @@ pub use is_last_distinct::*;
 pub use is_unique::*;
 #[cfg(feature = "log")]
 pub use log::*;
+pub use min_max_by::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
+#[cfg(feature = "dtype-struct")]
+pub use multi_output::*;
 pub use negate::*;
+#[cfg(feature = "ols")]
+pub use ols::*;
+#[cfg(feature = "outliers")]
+pub use outliers::*;
+pub use overflow::*;
 #[cfg(feature = "pct_change")]
 pub use pct_change::*;
 pub use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
@@ -122,6 +144,8 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+#[cfg(feature = "time_weighted")]
+pub use time_weighted::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
@@ -0,0 +1,167 @@
+use std::hash::{Hash, Hasher};
+
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A kernel that computes several statistics of a column in one pass over the data.
+///
+/// The outputs are returned as the fields of a struct, so they can be split into columns
+/// with `unnest`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MultiOutputKernel {
+    /// The fields `min` and `max`.
+    MinMax,
+    /// The fields `mean` and `std`.
+    MeanStd { ddof: u8 },
+    /// The fields `lower` and `upper`, the quantiles of the column at `lower` and `upper`.
+    QuantileBounds {
+        lower: f64,
+        upper: f64,
+        interpol: QuantileInterpolOptions,
+    },
+}
+
+impl Hash for MultiOutputKernel {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            MultiOutputKernel::MinMax => {},
+            MultiOutputKernel::MeanStd { ddof } => ddof.hash(state),
+            MultiOutputKernel::QuantileBounds {
+                lower,
+                upper,
+                interpol,
+            } => {
+                lower.to_bits().hash(state);
+                upper.to_bits().hash(state);
+                interpol.hash(state);
+            },
+        }
+    }
+}
+
+impl MultiOutputKernel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MultiOutputKernel::MinMax => "min_max",
+            MultiOutputKernel::MeanStd { .. } => "mean_std",
+            MultiOutputKernel::QuantileBounds { .. } => "quantile_bounds",
+        }
+    }
+
+    /// The fields of the struct this kernel returns for input of type `dtype`.
+    pub fn output_fields(&self, dtype: &DataType) -> Vec<Field> {
+        let float_dtype = match dtype {
+            DataType::Float32 => DataType::Float32,
+            _ => DataType::Float64,
+        };
+        let (names, dtype) = match self {
+            MultiOutputKernel::MinMax => (["min", "max"], dtype.clone()),
+            MultiOutputKernel::MeanStd { .. } => (["mean", "std"], float_dtype),
+            MultiOutputKernel::QuantileBounds { .. } => (["lower", "upper"], float_dtype),
+        };
+        names
+            .iter()
+            .map(|name| Field::new(name, dtype.clone()))
+            .collect()
+    }
+}
+
+fn min_max(s: &Series) -> PolarsResult<[Series; 2]> {
+    let phys = s.to_physical_repr();
+    if !phys.dtype().is_numeric() {
+        return Ok([s.min_as_series()?, s.max_as_series()?]);
+    }
+    let (min, max) = with_match_physical_numeric_polars_type!(phys.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = phys.as_ref().as_ref().as_ref();
+        let (min, max) = ca.min_max().unzip();
+        (
+            ChunkedArray::<$T>::from_slice_options("", &[min]).into_series(),
+            ChunkedArray::<$T>::from_slice_options("", &[max]).into_series(),
+        )
+    });
+    // SAFETY: the values are taken from the physical representation of `s`.
+    unsafe {
+        Ok([
+            min.cast_unchecked(s.dtype())?,
+            max.cast_unchecked(s.dtype())?,
+        ])
+    }
+}
+
+/// Welford's algorithm, so the mean and the variance are computed in the same pass.
+fn mean_std(s: &Series, ddof: u8) -> PolarsResult<[Series; 2]> {
+    polars_ensure!(
+        s.dtype().is_numeric() || s.dtype().is_bool(),
+        InvalidOperation: "`mean_std` operation not supported for dtype `{}`", s.dtype()
+    );
+    let values = s.cast(&DataType::Float64)?;
+    let mut count = 0usize;
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    for v in values.f64()?.into_iter().flatten() {
+        count += 1;
+        let delta = v - mean;
+        mean += delta / count as f64;
+        m2 += delta * (v - mean);
+    }
+    let mean = (count > 0).then_some(mean);
+    let std = (count > ddof as usize).then(|| (m2 / (count - ddof as usize) as f64).sqrt());
+
+    let float_dtype = match s.dtype() {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    Ok([
+        Float64Chunked::from_slice_options("", &[mean])
+            .into_series()
+            .cast(&float_dtype)?,
+        Float64Chunked::from_slice_options("", &[std])
+            .into_series()
+            .cast(&float_dtype)?,
+    ])
+}
+
+/// Sort once, so the second quantile is read from the sorted data.
+fn quantile_bounds(
+    s: &Series,
+    lower: f64,
+    upper: f64,
+    interpol: QuantileInterpolOptions,
+) -> PolarsResult<[Series; 2]> {
+    polars_ensure!(
+        lower <= upper,
+        ComputeError: "lower quantile {} should not exceed upper quantile {}", lower, upper
+    );
+    let sorted = s.sort(SortOptions::default())?;
+    Ok([
+        sorted.quantile_as_series(lower, interpol)?,
+        sorted.quantile_as_series(upper, interpol)?,
+    ])
+}
+
+/// Compute the outputs of `kernel` on `s` and return them as a struct of length 1.
+pub fn multi_output(s: &Series, kernel: MultiOutputKernel) -> PolarsResult<Series> {
+    let outputs = match kernel {
+        MultiOutputKernel::MinMax => min_max(s)?,
+        MultiOutputKernel::MeanStd { ddof } => mean_std(s, ddof)?,
+        MultiOutputKernel::QuantileBounds {
+            lower,
+            upper,
+            interpol,
+        } => quantile_bounds(s, lower, upper, interpol)?,
+    };
+    let fields = kernel
+        .output_fields(s.dtype())
+        .into_iter()
+        .zip(outputs)
+        .map(|(field, mut out)| {
+            out.rename(field.name());
+            out
+        })
+        .collect::<Vec<_>>();
+    Ok(StructChunked::new(s.name(), &fields)?.into_series())
+}
@@ -0,0 +1,391 @@
+use polars_core::prelude::*;
+
+/// Running sums of an ordinary least squares problem.
+///
+/// Rows can be added and removed, which makes it usable for both grouped and
+/// rolling fits without revisiting the data.
+struct OlsState {
+    k: usize,
+    n: usize,
+    xtx: Vec<f64>,
+    xty: Vec<f64>,
+    yty: f64,
+    sum_y: f64,
+}
+
+impl OlsState {
+    fn new(k: usize) -> Self {
+        Self {
+            k,
+            n: 0,
+            xtx: vec![0.0; k * k],
+            xty: vec![0.0; k],
+            yty: 0.0,
+            sum_y: 0.0,
+        }
+    }
+
+    fn update(&mut self, x: &[f64], y: f64, sign: f64) {
+        let k = self.k;
+        for (i, xi) in x.iter().enumerate() {
+            let xi = xi * sign;
+            for (xtx, xj) in self.xtx[i * k..(i + 1) * k].iter_mut().zip(x) {
+                *xtx += xi * xj;
+            }
+            self.xty[i] += xi * y;
+        }
+        self.yty += sign * y * y;
+        self.sum_y += sign * y;
+        if sign > 0.0 {
+            self.n += 1;
+        } else {
+            self.n -= 1;
+        }
+    }
+
+    fn add(&mut self, x: &[f64], y: f64) {
+        self.update(x, y, 1.0)
+    }
+
+    fn remove(&mut self, x: &[f64], y: f64) {
+        self.update(x, y, -1.0)
+    }
+
+    /// Solve the normal equations. Returns `None` if the system is singular.
+    fn solve(&self) -> Option<Vec<f64>> {
+        let k = self.k;
+        if self.n < k {
+            return None;
+        }
+        // Gaussian elimination with partial pivoting on `[XᵀX | Xᵀy]`.
+        let mut a = self.xtx.clone();
+        let mut b = self.xty.clone();
+        let scale = a.iter().fold(0.0f64, |acc, v| acc.max(v.abs()));
+        let eps = scale * f64::EPSILON * k as f64;
+        for col in 0..k {
+            let pivot = (col..k)
+                .max_by(|&l, &r| a[l * k + col].abs().total_cmp(&a[r * k + col].abs()))
+                .unwrap();
+            if a[pivot * k + col].abs() <= eps {
+                return None;
+            }
+            if pivot != col {
+                for j in 0..k {
+                    a.swap(pivot * k + j, col * k + j);
+                }
+                b.swap(pivot, col);
+            }
+            for row in col + 1..k {
+                let factor = a[row * k + col] / a[col * k + col];
+                for j in col..k {
+                    a[row * k + j] -= factor * a[col * k + j];
+                }
+                b[row] -= factor * b[col];
+            }
+        }
+        let mut beta = vec![0.0; k];
+        for row in (0..k).rev() {
+            let mut acc = b[row];
+            for j in row + 1..k {
+                acc -= a[row * k + j] * beta[j];
+            }
+            beta[row] = acc / a[row * k + row];
+        }
+        Some(beta)
+    }
+
+    /// Coefficient of determination of the fit `beta`. Returns `None` if `y` has no
+    /// variation to explain, e.g. if it is constant and there is an intercept.
+    fn r2(&self, beta: &[f64], fit_intercept: bool) -> Option<f64> {
+        let k = self.k;
+        let mut bxty = 0.0;
+        let mut bxtxb = 0.0;
+        for i in 0..k {
+            bxty += beta[i] * self.xty[i];
+            for j in 0..k {
+                bxtxb += beta[i] * self.xtx[i * k + j] * beta[j];
+            }
+        }
+        let ssr = self.yty - 2.0 * bxty + bxtxb;
+        let sst = if fit_intercept {
+            self.yty - self.sum_y * self.sum_y / self.n as f64
+        } else {
+            self.yty
+        };
+        // The centered sum of squares may be a rounding error away from zero.
+        if sst <= self.yty * f64::EPSILON * self.n as f64 {
+            return None;
+        }
+        Some(1.0 - ssr / sst)
+    }
+}
+
+/// Collects the inputs as `Float64` and yields the design row of every
+/// observation; rows with a missing or non-finite value yield `None`.
+struct DesignMatrix {
+    y: Float64Chunked,
+    xs: Vec<Float64Chunked>,
+    fit_intercept: bool,
+}
+
+impl DesignMatrix {
+    fn new(y: &Series, xs: &[Series], fit_intercept: bool) -> PolarsResult<Self> {
+        polars_ensure!(
+            !xs.is_empty(),
+            ComputeError: "least squares needs at least one independent variable"
+        );
+        let cast = |s: &Series| -> PolarsResult<Float64Chunked> {
+            polars_ensure!(
+                s.dtype().is_numeric(),
+                InvalidOperation: "least squares is not supported for dtype '{}'", s.dtype()
+            );
+            polars_ensure!(
+                s.len() == y.len(),
+                ShapeMismatch: "least squares inputs must have equal length, got {} and {}",
+                y.len(), s.len()
+            );
+            Ok(s.cast(&DataType::Float64)?.f64()?.rechunk())
+        };
+        Ok(Self {
+            y: cast(y)?,
+            xs: xs.iter().map(cast).collect::<PolarsResult<_>>()?,
+            fit_intercept,
+        })
+    }
+
+    fn n_params(&self) -> usize {
+        self.xs.len() + self.fit_intercept as usize
+    }
+
+    fn len(&self) -> usize {
+        self.y.len()
+    }
+
+    /// Write the design row of observation `idx` into `row`, returning its target value.
+    ///
+    /// Rows with a NaN or an infinite value are skipped like rows with a null, as they would
+    /// make the running sums NaN for good.
+    fn row(&self, idx: usize, row: &mut [f64]) -> Option<f64> {
+        let y = self.y.get(idx).filter(|y| y.is_finite())?;
+        for (dst, x) in row.iter_mut().zip(&self.xs) {
+            *dst = x.get(idx).filter(|x| x.is_finite())?;
+        }
+        if self.fit_intercept {
+            row[self.xs.len()] = 1.0;
+        }
+        Some(y)
+    }
+
+    fn output_fields(&self) -> PolarsResult<Vec<Field>> {
+        ols_output_fields(self.xs.iter().map(|x| x.name()), self.fit_intercept)
+    }
+}
+
+/// Fields of the struct returned by [`least_squares`] and [`rolling_ols`].
+///
+/// Errors if two fields would get the same name, e.g. if an independent variable is
+/// named `r2`.
+pub fn ols_output_fields<'a>(
+    x_names: impl Iterator<Item = &'a str>,
+    fit_intercept: bool,
+) -> PolarsResult<Vec<Field>> {
+    let mut fields: Vec<_> = x_names
+        .map(|name| Field::new(name, DataType::Float64))
+        .collect();
+    if fit_intercept {
+        fields.push(Field::new("intercept", DataType::Float64));
+    }
+    fields.push(Field::new("r2", DataType::Float64));
+    let mut names = PlHashSet::new();
+    for field in &fields {
+        polars_ensure!(
+            names.insert(field.name()),
+            Duplicate: "least squares output field '{}' is not unique; alias the independent variables",
+            field.name()
+        );
+    }
+    Ok(fields)
+}
+
+fn finish(name: &str, fields: &[Field], columns: Vec<Vec<Option<f64>>>) -> PolarsResult<Series> {
+    let fields = fields
+        .iter()
+        .zip(columns)
+        .map(|(field, values)| {
+            Float64Chunked::from_slice_options(field.name(), &values).into_series()
+        })
+        .collect::<Vec<_>>();
+    Ok(StructChunked::new(name, &fields)?.into_series())
+}
+
+fn push_fit(columns: &mut [Vec<Option<f64>>], state: &OlsState, fit_intercept: bool) {
+    match state.solve() {
+        Some(beta) => {
+            let r2 = state.r2(&beta, fit_intercept);
+            for (col, b) in columns.iter_mut().zip(beta) {
+                col.push(Some(b));
+            }
+            columns.last_mut().unwrap().push(r2);
+        },
+        None => columns.iter_mut().for_each(|col| col.push(None)),
+    }
+}
+
+/// Fit `y` against `xs` with ordinary least squares.
+///
+/// Returns a struct [`Series`] of length one holding a coefficient per independent
+/// variable, the intercept (if `fit_intercept`) and the R². Rows with missing or
+/// non-finite values are ignored. If the fit is underdetermined all fields are null, and the R² is null
+/// if `y` has no variation.
+pub fn least_squares(y: &Series, xs: &[Series], fit_intercept: bool) -> PolarsResult<Series> {
+    let design = DesignMatrix::new(y, xs, fit_intercept)?;
+    let k = design.n_params();
+    let mut state = OlsState::new(k);
+    let mut row = vec![0.0; k];
+    for idx in 0..design.len() {
+        if let Some(y) = design.row(idx, &mut row) {
+            state.add(&row, y);
+        }
+    }
+    let fields = design.output_fields()?;
+    let mut columns = vec![Vec::with_capacity(1); fields.len()];
+    push_fit(&mut columns, &state, fit_intercept);
+    finish(y.name(), &fields, columns)
+}
+
+/// Fit `y` against `xs` with ordinary least squares over a rolling window.
+///
+/// Every row gets the fit of the `window_size` rows ending at it, in the same
+/// layout as [`least_squares`]. The fit is null if the window holds fewer than
+/// `min_periods` complete rows or is underdetermined. The sums are updated as the
+/// window slides, so the cost per row doesn't depend on the window size. They are
+/// recomputed from the window once every `window_size` rows, so that the rounding
+/// errors of removing rows don't accumulate.
+pub fn rolling_ols(
+    y: &Series,
+    xs: &[Series],
+    window_size: usize,
+    min_periods: usize,
+    fit_intercept: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        window_size > 0 && min_periods <= window_size,
+        ComputeError: "rolling_ols needs 0 < min_periods <= window_size, got min_periods={} and window_size={}",
+        min_periods, window_size
+    );
+    let design = DesignMatrix::new(y, xs, fit_intercept)?;
+    let k = design.n_params();
+    let len = design.len();
+    let mut state = OlsState::new(k);
+    let mut row = vec![0.0; k];
+    let fields = design.output_fields()?;
+    let mut columns = vec![Vec::with_capacity(len); fields.len()];
+    for idx in 0..len {
+        if idx >= window_size && idx % window_size == 0 {
+            state = OlsState::new(k);
+            for idx in idx + 1 - window_size..=idx {
+                if let Some(y) = design.row(idx, &mut row) {
+                    state.add(&row, y);
+                }
+            }
+        } else {
+            if let Some(y) = design.row(idx, &mut row) {
+                state.add(&row, y);
+            }
+            if idx >= window_size {
+                if let Some(y) = design.row(idx - window_size, &mut row) {
+                    state.remove(&row, y);
+                }
+            }
+        }
+        if state.n >= min_periods.max(1) {
+            push_fit(&mut columns, &state, fit_intercept);
+        } else {
+            columns.iter_mut().for_each(|col| col.push(None));
+        }
+    }
+    finish(y.name(), &fields, columns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn field(s: &Series, name: &str) -> Vec<Option<f64>> {
+        s.struct_()
+            .unwrap()
+            .field_by_name(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .map(|v| v.map(|v| (v * 1e6).round() / 1e6))
+            .collect()
+    }
+
+    #[test]
+    fn test_least_squares() -> PolarsResult<()> {
+        // y = 2 * a - 3 * b + 1
+        let a = Series::new("a", &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let b = Series::new("b", &[Some(2.0), Some(1.0), None, Some(4.0), Some(3.0)]);
+        let y = Series::new("y", &[-3.0, 2.0, 100.0, -3.0, 2.0]);
+
+        let out = least_squares(&y, &[a, b.clone()], true)?;
+        assert_eq!(field(&out, "a"), &[Some(2.0)]);
+        assert_eq!(field(&out, "b"), &[Some(-3.0)]);
+        assert_eq!(field(&out, "intercept"), &[Some(1.0)]);
+        assert_eq!(field(&out, "r2"), &[Some(1.0)]);
+
+        let out = rolling_ols(&y, &[b], 2, 2, false)?;
+        assert_eq!(
+            field(&out, "b"),
+            &[None, Some(-0.8), None, None, Some(-0.24)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_ols_non_finite() -> PolarsResult<()> {
+        // y = 2 * x + 1, with a NaN that must only drop its own row.
+        let x = Series::new("x", &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let y = Series::new("y", &[3.0, 5.0, 7.0, f64::NAN, 11.0, 13.0, 15.0, 17.0]);
+
+        let out = rolling_ols(&y, &[x.clone()], 3, 2, true)?;
+        let mut expected = vec![Some(2.0); 8];
+        expected[0] = None;
+        assert_eq!(field(&out, "x"), expected);
+        let mut expected = vec![Some(1.0); 8];
+        expected[0] = None;
+        assert_eq!(field(&out, "intercept"), expected);
+
+        let y = Series::new("y", &[3.0, 5.0, 7.0, f64::INFINITY, 11.0, 13.0, 15.0, 17.0]);
+        let out = least_squares(&y, &[x], true)?;
+        assert_eq!(field(&out, "x"), &[Some(2.0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_least_squares_constant_y() -> PolarsResult<()> {
+        let x = Series::new("x", &[1.0, 2.0, 3.0]);
+        let y = Series::new("y", &[4.0, 4.0, 4.0]);
+
+        let out = least_squares(&y, &[x], true)?;
+        assert_eq!(field(&out, "x"), &[Some(0.0)]);
+        assert_eq!(field(&out, "intercept"), &[Some(4.0)]);
+        assert_eq!(field(&out, "r2"), &[None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_least_squares_duplicate_fields() {
+        let y = Series::new("y", &[1.0, 2.0, 3.0]);
+        let r2 = Series::new("r2", &[1.0, 0.0, 1.0]);
+        let intercept = Series::new("intercept", &[1.0, 0.0, 1.0]);
+        let a = Series::new("a", &[1.0, 0.0, 1.0]);
+
+        assert!(least_squares(&y, &[r2], false).is_err());
+        assert!(least_squares(&y, &[intercept.clone()], true).is_err());
+        assert!(least_squares(&y, &[intercept], false).is_ok());
+        assert!(least_squares(&y, &[a.clone(), a], false).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How [`is_outlier`] determines whether a value is an outlier.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutlierMethod {
+    /// Outside of `[q1 - threshold * iqr, q3 + threshold * iqr]`, with the first and third
+    /// quartiles `q1` and `q3` and the interquartile range `iqr = q3 - q1`.
+    #[default]
+    Iqr,
+    /// More than `threshold` standard deviations away from the mean.
+    ZScore,
+    /// A modified z-score `0.6745 * |x - median| / mad` above `threshold`, with the median
+    /// absolute deviation `mad`.
+    Mad,
+}
+
+impl OutlierMethod {
+    /// The threshold that is used if none is given.
+    pub fn default_threshold(self) -> f64 {
+        match self {
+            OutlierMethod::Iqr => 1.5,
+            OutlierMethod::ZScore => 3.0,
+            OutlierMethod::Mad => 3.5,
+        }
+    }
+}
+
+/// The valid values of `s`, without nulls and NaNs, sorted in ascending order.
+fn sorted_values(s: &Series) -> PolarsResult<Vec<f64>> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "expected a numeric series, got {}", s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    let mut values = s
+        .f64()?
+        .iter()
+        .flatten()
+        .filter(|v| !v.is_nan())
+        .collect::<Vec<_>>();
+    values.sort_unstable_by(|a, b| a.total_cmp(b));
+    Ok(values)
+}
+
+/// The quantile `q` of the non-empty, sorted `values`, linearly interpolated between the
+/// closest values.
+fn quantile(values: &[f64], q: f64) -> f64 {
+    let position = q * (values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    values[lower] + (values[upper] - values[lower]) * (position - lower as f64)
+}
+
+fn check_quantile(q: f64, name: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&q),
+        ComputeError: "`{}` must be between 0.0 and 1.0, got {}", name, q
+    );
+    Ok(())
+}
+
+/// Clip the values of `s` to its `lower_q` and `upper_q` quantiles, which are linearly
+/// interpolated. Both quantiles are taken from a single sort of the values.
+///
+/// Integer series are cast to `Float64`. Nulls and NaNs are kept as is.
+pub fn winsorize(s: &Series, lower_q: f64, upper_q: f64) -> PolarsResult<Series> {
+    check_quantile(lower_q, "lower_q")?;
+    check_quantile(upper_q, "upper_q")?;
+    polars_ensure!(
+        lower_q <= upper_q,
+        ComputeError: "`lower_q` must not be greater than `upper_q`, got {} and {}",
+        lower_q, upper_q
+    );
+    let values = sorted_values(s)?;
+    let out_dtype = match s.dtype() {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let s_float = s.cast(&DataType::Float64)?;
+    if values.is_empty() {
+        return s_float.cast(&out_dtype);
+    }
+
+    let lower = quantile(&values, lower_q);
+    let upper = quantile(&values, upper_q);
+    let out = s_float
+        .f64()?
+        .apply_values(|v| if v.is_nan() { v } else { v.clamp(lower, upper) });
+    out.into_series().cast(&out_dtype)
+}
+
+/// The bounds outside of which the sorted `values` are outliers.
+fn outlier_bounds(values: &[f64], method: OutlierMethod, threshold: f64) -> (f64, f64) {
+    if values.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    match method {
+        OutlierMethod::Iqr => {
+            let q1 = quantile(values, 0.25);
+            let q3 = quantile(values, 0.75);
+            let iqr = q3 - q1;
+            (q1 - threshold * iqr, q3 + threshold * iqr)
+        },
+        OutlierMethod::ZScore => {
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            // The sample standard deviation, which is NaN for a single value.
+            let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            let std = var.sqrt();
+            (mean - threshold * std, mean + threshold * std)
+        },
+        OutlierMethod::Mad => {
+            let median = quantile(values, 0.5);
+            let mut deviations = values
+                .iter()
+                .map(|v| (v - median).abs())
+                .collect::<Vec<_>>();
+            deviations.sort_unstable_by(|a, b| a.total_cmp(b));
+            let mad = quantile(&deviations, 0.5);
+            let max_deviation = threshold * mad / 0.6745;
+            (median - max_deviation, median + max_deviation)
+        },
+    }
+}
+
+/// Flag the values of `s` that are outliers according to `method`. If no `threshold` is
+/// given, the [default threshold](OutlierMethod::default_threshold) of the method is used.
+///
+/// Nulls stay null and NaNs are never outliers. The statistics are computed over the values
+/// that aren't null or NaN.
+pub fn is_outlier(
+    s: &Series,
+    method: OutlierMethod,
+    threshold: Option<f64>,
+) -> PolarsResult<BooleanChunked> {
+    let threshold = threshold.unwrap_or_else(|| method.default_threshold());
+    polars_ensure!(
+        threshold >= 0.0,
+        ComputeError: "the outlier threshold must not be negative, got {}", threshold
+    );
+    let values = sorted_values(s)?;
+    let (lower, upper) = outlier_bounds(&values, method, threshold);
+
+    let s_float = s.cast(&DataType::Float64)?;
+    let out: BooleanChunked = s_float
+        .f64()?
+        .apply_values_generic(|v| v < lower || v > upper);
+    Ok(out.with_name(s.name()))
+}
@@ -0,0 +1,299 @@
+use arrow::array::{Array, PrimitiveArray};
+use arrow::compute::utils::combine_validities_and;
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub, WrappingAdd,
+    WrappingMul, WrappingSub, Zero,
+};
+use polars_core::prelude::*;
+use polars_core::utils::{align_chunks_binary, try_get_supertype};
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How integer arithmetic handles results that don't fit in the output type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntegerOverflow {
+    /// Wrap around at the boundary of the type.
+    #[default]
+    Wrap,
+    /// Clamp the result to the minimum or maximum value of the type.
+    Saturate,
+    /// Raise an error that reports the column and the row that overflowed.
+    Error,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OverflowOperator {
+    Add,
+    Sub,
+    Mul,
+}
+
+trait OverflowNative:
+    CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + SaturatingAdd
+    + SaturatingSub
+    + SaturatingMul
+    + WrappingAdd
+    + WrappingSub
+    + WrappingMul
+    + Zero
+    + Copy
+{
+    fn apply(self, rhs: Self, op: OverflowOperator, overflow: IntegerOverflow) -> Option<Self> {
+        use OverflowOperator::*;
+        match (overflow, op) {
+            (IntegerOverflow::Wrap, Add) => Some(self.wrapping_add(&rhs)),
+            (IntegerOverflow::Wrap, Sub) => Some(self.wrapping_sub(&rhs)),
+            (IntegerOverflow::Wrap, Mul) => Some(self.wrapping_mul(&rhs)),
+            (IntegerOverflow::Saturate, Add) => Some(self.saturating_add(&rhs)),
+            (IntegerOverflow::Saturate, Sub) => Some(self.saturating_sub(&rhs)),
+            (IntegerOverflow::Saturate, Mul) => Some(self.saturating_mul(&rhs)),
+            (IntegerOverflow::Error, Add) => self.checked_add(&rhs),
+            (IntegerOverflow::Error, Sub) => self.checked_sub(&rhs),
+            (IntegerOverflow::Error, Mul) => self.checked_mul(&rhs),
+        }
+    }
+}
+
+impl<T> OverflowNative for T where
+    T: CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + SaturatingAdd
+        + SaturatingSub
+        + SaturatingMul
+        + WrappingAdd
+        + WrappingSub
+        + WrappingMul
+        + Zero
+        + Copy
+{
+}
+
+impl OverflowOperator {
+    fn symbol(self) -> &'static str {
+        match self {
+            OverflowOperator::Add => "+",
+            OverflowOperator::Sub => "-",
+            OverflowOperator::Mul => "*",
+        }
+    }
+}
+
+fn arithmetic_arr<T: NumericNative + OverflowNative>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    op: OverflowOperator,
+    overflow: IntegerOverflow,
+    offset: usize,
+    err: impl Fn(usize) -> PolarsError,
+) -> PolarsResult<PrimitiveArray<T>> {
+    let validity = combine_validities_and(lhs.validity(), rhs.validity());
+    let out = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .enumerate()
+        .map(|(i, (&l, &r))| match l.apply(r, op, overflow) {
+            Some(v) => Ok(v),
+            // The values of null rows are arbitrary, they may overflow.
+            None if validity.as_ref().map_or(false, |v| !v.get_bit(i)) => Ok(T::zero()),
+            None => Err(err(offset + i)),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(PrimitiveArray::from_data_default(out.into(), validity))
+}
+
+fn arithmetic_helper<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    op: OverflowOperator,
+    overflow: IntegerOverflow,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    let err = |row| {
+        polars_err!(
+            ComputeError: "integer overflow in '{}' {} '{}' at row {}",
+            lhs.name(), op.symbol(), rhs.name(), row
+        )
+    };
+    // Broadcast unit length operands.
+    let len = std::cmp::max(lhs.len(), rhs.len());
+    let lhs_expanded;
+    let lhs = if lhs.len() == 1 && len != 1 {
+        lhs_expanded = lhs.new_from_index(0, len);
+        &lhs_expanded
+    } else {
+        lhs
+    };
+    let rhs_expanded;
+    let rhs = if rhs.len() == 1 && len != 1 {
+        rhs_expanded = rhs.new_from_index(0, len);
+        &rhs_expanded
+    } else {
+        rhs
+    };
+
+    let (lhs, rhs) = align_chunks_binary(lhs, rhs);
+    let mut offset = 0;
+    let chunks = lhs
+        .downcast_iter()
+        .zip(rhs.downcast_iter())
+        .map(|(l, r)| {
+            let out = arithmetic_arr(l, r, op, overflow, offset, err);
+            offset += l.len();
+            out
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(ChunkedArray::from_chunk_iter(lhs.name(), chunks))
+}
+
+/// Apply an integer `op` to `lhs` and `rhs`, handling overflow according to `overflow`.
+///
+/// Non-integer inputs fall back to the default arithmetic.
+pub fn arithmetic_with_overflow(
+    lhs: &Series,
+    rhs: &Series,
+    op: OverflowOperator,
+    overflow: IntegerOverflow,
+) -> PolarsResult<Series> {
+    if !(lhs.dtype().is_integer() && rhs.dtype().is_integer()) {
+        return match op {
+            OverflowOperator::Add => lhs.try_add(rhs),
+            OverflowOperator::Sub => Ok(lhs - rhs),
+            OverflowOperator::Mul => Ok(lhs * rhs),
+        };
+    }
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        ComputeError: "cannot evaluate two Series of different lengths ({} and {})",
+        lhs.len(), rhs.len(),
+    );
+    let dtype = try_get_supertype(lhs.dtype(), rhs.dtype())?;
+    let lhs = lhs.cast(&dtype)?;
+    let rhs = rhs.cast(&dtype)?;
+
+    with_match_physical_integer_polars_type!(&dtype, |$T| {
+        let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
+        arithmetic_helper(lhs, rhs, op, overflow).map(|ca| ca.into_series())
+    })
+}
+
+fn sum_err(name: &str, row: usize) -> PolarsError {
+    polars_err!(ComputeError: "integer overflow in the sum of '{}' at row {}", name, row)
+}
+
+/// Sum the values of `rows` of `arr`.
+fn sum_rows<T, I>(
+    arr: &PrimitiveArray<T>,
+    rows: I,
+    overflow: IntegerOverflow,
+    name: &str,
+) -> PolarsResult<T>
+where
+    T: NumericNative + OverflowNative,
+    I: IntoIterator<Item = usize>,
+{
+    let mut acc = T::zero();
+    for row in rows {
+        if arr.is_valid(row) {
+            acc = acc
+                .apply(arr.value(row), OverflowOperator::Add, overflow)
+                .ok_or_else(|| sum_err(name, row))?;
+        }
+    }
+    Ok(acc)
+}
+
+fn sum_helper<T>(ca: &ChunkedArray<T>, overflow: IntegerOverflow) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    let mut acc = T::Native::zero();
+    let mut offset = 0;
+    for arr in ca.downcast_iter() {
+        for (i, v) in arr.iter().enumerate() {
+            if let Some(&v) = v {
+                acc = acc
+                    .apply(v, OverflowOperator::Add, overflow)
+                    .ok_or_else(|| sum_err(ca.name(), offset + i))?;
+            }
+        }
+        offset += arr.len();
+    }
+    Ok(ChunkedArray::from_slice(ca.name(), &[acc]))
+}
+
+fn agg_sum_helper<T>(
+    ca: &ChunkedArray<T>,
+    groups: &GroupsProxy,
+    overflow: IntegerOverflow,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let sums = match groups {
+        GroupsProxy::Idx(groups) => groups
+            .all()
+            .iter()
+            .map(|rows| sum_rows(arr, rows.iter().map(|&i| i as usize), overflow, ca.name()))
+            .collect::<PolarsResult<Vec<_>>>()?,
+        GroupsProxy::Slice { groups, .. } => groups
+            .iter()
+            .map(|&[first, len]| {
+                let rows = first as usize..(first + len) as usize;
+                sum_rows(arr, rows, overflow, ca.name())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?,
+    };
+    Ok(ChunkedArray::from_vec(ca.name(), sums))
+}
+
+/// Sum the values of `s`, handling overflow according to `overflow`.
+///
+/// Follows the output types of [`Series::sum_as_series`]. Non-integer inputs fall back to it.
+pub fn sum_with_overflow(s: &Series, overflow: IntegerOverflow) -> PolarsResult<Series> {
+    use DataType::*;
+    match s.dtype() {
+        Int8 | UInt8 | Int16 | UInt16 => sum_with_overflow(&s.cast(&Int64)?, overflow),
+        dt if dt.is_integer() => with_match_physical_integer_polars_type!(dt, |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            sum_helper(ca, overflow).map(|ca| ca.into_series())
+        }),
+        _ => s.sum_as_series(),
+    }
+}
+
+/// Sum the values of every group of `s`, handling overflow according to `overflow`. An
+/// overflow is reported at its row in `s`, rather than in its group.
+///
+/// Follows the output types of [`Series::agg_sum`]. Non-integer inputs fall back to it.
+pub fn agg_sum_with_overflow(
+    s: &Series,
+    groups: &GroupsProxy,
+    overflow: IntegerOverflow,
+) -> PolarsResult<Series> {
+    use DataType::*;
+    match s.dtype() {
+        Int8 | UInt8 | Int16 | UInt16 => agg_sum_with_overflow(&s.cast(&Int64)?, groups, overflow),
+        dt if dt.is_integer() => with_match_physical_integer_polars_type!(dt, |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            agg_sum_helper(ca, groups, overflow).map(|ca| ca.into_series())
+        }),
+        // SAFETY: the groups are in bounds of the series they were computed for.
+        _ => Ok(unsafe { s.agg_sum(groups) }),
+    }
+}
@@ -0,0 +1,107 @@
+use polars_core::prelude::*;
+
+/// The number of time units per second of a temporal `time` column, or `1.0` if the column
+/// is numeric.
+fn time_scale(time: &Series) -> PolarsResult<f64> {
+    let scale = match time.dtype() {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, _) => time_unit_scale(*tu),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => time_unit_scale(*tu),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => 1.0 / 86_400.0,
+        dt if dt.is_numeric() => 1.0,
+        dt => polars_bail!(
+            InvalidOperation: "`time` must be numeric or temporal, got {}", dt
+        ),
+    };
+    Ok(scale)
+}
+
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn time_unit_scale(tu: TimeUnit) -> f64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1e9,
+        TimeUnit::Microseconds => 1e6,
+        TimeUnit::Milliseconds => 1e3,
+    }
+}
+
+/// The trapezoidal integral of a series over time.
+struct Integral {
+    area: f64,
+    span: f64,
+    // the value of the only valid row, if there is exactly one
+    single_value: Option<f64>,
+}
+
+/// Integrate `values` over `time` with the trapezoidal rule. Returns `None` if there are no
+/// valid rows.
+fn integrate(values: &Series, time: &Series) -> PolarsResult<Option<Integral>> {
+    polars_ensure!(
+        values.len() == time.len(),
+        ShapeMismatch: "`values` and `time` must have the same length, got {} and {}",
+        values.len(), time.len()
+    );
+    polars_ensure!(
+        values.dtype().is_numeric(),
+        InvalidOperation: "`values` must be numeric, got {}", values.dtype()
+    );
+    let scale = time_scale(time)?;
+    let values = values.cast(&DataType::Float64)?;
+    let time = time.to_physical_repr().cast(&DataType::Float64)?;
+
+    let mut area = 0.0;
+    let mut n = 0;
+    let mut first = None;
+    let mut prev: Option<(f64, f64)> = None;
+    // Rows where either the value or the time is missing are skipped.
+    for (t, v) in time.f64()?.into_iter().zip(values.f64()?) {
+        let (Some(t), Some(v)) = (t, v) else {
+            continue;
+        };
+        let t = t / scale;
+        if let Some((prev_t, prev_v)) = prev {
+            polars_ensure!(
+                t >= prev_t,
+                ComputeError: "`time` must be sorted in ascending order"
+            );
+            area += (t - prev_t) * (v + prev_v) / 2.0;
+        } else {
+            first = Some((t, v));
+        }
+        prev = Some((t, v));
+        n += 1;
+    }
+    Ok(first
+        .zip(prev)
+        .map(|((first_t, first_v), (last_t, _))| Integral {
+            area,
+            span: last_t - first_t,
+            single_value: (n == 1).then_some(first_v),
+        }))
+}
+
+/// The integral of `values` over `time` with the trapezoidal rule.
+///
+/// Temporal `time` columns are measured in seconds, numeric ones in their own unit. The
+/// `time` must be sorted in ascending order, rows with missing values are ignored.
+pub fn trapezoidal_integral(values: &Series, time: &Series) -> PolarsResult<Series> {
+    let out = integrate(values, time)?.map(|integral| integral.area);
+    Ok(Series::new(values.name(), [out]))
+}
+
+/// The time weighted mean of `values`, linearly interpolated between consecutive `time`s.
+///
+/// This is the [`trapezoidal_integral`] divided by the covered time span. A single valid
+/// row is its own mean, and the mean is null if several rows share a single time.
+pub fn time_weighted_mean(values: &Series, time: &Series) -> PolarsResult<Series> {
+    let out = integrate(values, time)?.and_then(|integral| {
+        if integral.span > 0.0 {
+            Some(integral.area / integral.span)
+        } else {
+            integral.single_value
+        }
+    });
+    Ok(Series::new(values.name(), [out]))
+}
@@ -1,8 +1,10 @@
 pub mod read;
 pub mod write;
 
-#[cfg(feature = "io_parquet_bloom_filter")]
-#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+#[cfg(feature = "bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bloom_filter")))]
 pub use crate::parquet::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// The key of the metadata of an arrow field with the field id of its parquet column.
+pub const FIELD_ID_META_KEY: &str = "PARQUET:field_id";
@@ -12,7 +12,7 @@ use super::super::utils::{
 };
 use super::super::{utils, PagesIter};
 use crate::parquet::deserialize::SliceFilteredIter;
-use crate::parquet::encoding::{hybrid_rle, Encoding};
+use crate::parquet::encoding::{byte_stream_split, hybrid_rle, Encoding};
 use crate::parquet::page::{split_buffer, DataPage, DictPage};
 use crate::parquet::types::{decode, NativeType as ParquetNativeType};
 
@@ -97,6 +97,13 @@ where
     OptionalDictionary(OptionalPageValidity<'a>, ValuesDictionary<'a, T>),
     FilteredRequired(FilteredRequiredValues<'a>),
     FilteredOptional(FilteredOptionalPageValidity<'a>, Values<'a>),
+    ByteStreamSplitRequired(byte_stream_split::Decoder<'a>),
+    ByteStreamSplitOptional(OptionalPageValidity<'a>, byte_stream_split::Decoder<'a>),
+    FilteredByteStreamSplitRequired(SliceFilteredIter<byte_stream_split::Decoder<'a>>),
+    FilteredByteStreamSplitOptional(
+        FilteredOptionalPageValidity<'a>,
+        byte_stream_split::Decoder<'a>,
+    ),
 }
 
 impl<'a, T> utils::PageState<'a> for State<'a, T>
@@ -111,6 +118,10 @@ where
             State::OptionalDictionary(optional, _) => optional.len(),
             State::FilteredRequired(values) => values.len(),
             State::FilteredOptional(optional, _) => optional.len(),
+            State::ByteStreamSplitRequired(values) => values.size_hint().0,
+            State::ByteStreamSplitOptional(optional, _) => optional.len(),
+            State::FilteredByteStreamSplitRequired(values) => values.size_hint().0,
+            State::FilteredByteStreamSplitOptional(optional, _) => optional.len(),
         }
     }
 }
@@ -191,6 +202,28 @@ where
                 FilteredOptionalPageValidity::try_new(page)?,
                 Values::try_new::<P>(page)?,
             )),
+            (Encoding::ByteStreamSplit, _, false, false) => {
+                Ok(State::ByteStreamSplitRequired(byte_stream_split_decoder::<
+                    P,
+                >(page)?))
+            },
+            (Encoding::ByteStreamSplit, _, true, false) => Ok(State::ByteStreamSplitOptional(
+                OptionalPageValidity::try_new(page)?,
+                byte_stream_split_decoder::<P>(page)?,
+            )),
+            (Encoding::ByteStreamSplit, _, false, true) => {
+                let values = byte_stream_split_decoder::<P>(page)?;
+                let rows = get_selected_rows(page);
+                Ok(State::FilteredByteStreamSplitRequired(
+                    SliceFilteredIter::new(values, rows),
+                ))
+            },
+            (Encoding::ByteStreamSplit, _, true, true) => {
+                Ok(State::FilteredByteStreamSplitOptional(
+                    FilteredOptionalPageValidity::try_new(page)?,
+                    byte_stream_split_decoder::<P>(page)?,
+                ))
+            },
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -260,6 +293,46 @@ where
                     page_values.values.by_ref().map(decode).map(self.op),
                 );
             },
+            State::ByteStreamSplitRequired(page) => {
+                values.extend(
+                    page.by_ref()
+                        .map(|x| decode_split::<P>(&x))
+                        .map(self.op)
+                        .take(remaining),
+                );
+            },
+            State::ByteStreamSplitOptional(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values
+                        .by_ref()
+                        .map(|x| decode_split::<P>(&x))
+                        .map(self.op),
+                )
+            },
+            State::FilteredByteStreamSplitRequired(page) => {
+                values.extend(
+                    page.by_ref()
+                        .map(|x| decode_split::<P>(&x))
+                        .map(self.op)
+                        .take(remaining),
+                );
+            },
+            State::FilteredByteStreamSplitOptional(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values
+                        .by_ref()
+                        .map(|x| decode_split::<P>(&x))
+                        .map(self.op),
+                );
+            },
         }
         Ok(())
     }
@@ -360,6 +433,22 @@ where
     }
 }
 
+fn byte_stream_split_decoder<P: ParquetNativeType>(
+    page: &DataPage,
+) -> PolarsResult<byte_stream_split::Decoder> {
+    let (_, _, values) = split_buffer(page)?;
+    Ok(byte_stream_split::Decoder::try_new(
+        values,
+        std::mem::size_of::<P>(),
+    )?)
+}
+
+/// Decode a value from the bytes decoded by a [`byte_stream_split::Decoder`].
+#[inline]
+fn decode_split<P: ParquetNativeType>(bytes: &[u8; byte_stream_split::MAX_SIZE]) -> P {
+    decode(&bytes[..std::mem::size_of::<P>()])
+}
+
 pub(super) fn deserialize_plain<T, P, F>(values: &[u8], op: F) -> Vec<T>
 where
     T: NativeType,
@@ -1,4 +1,7 @@
-use arrow::array::{Array, BinaryArray, PrimitiveArray, Utf8Array};
+use arrow::array::{
+    Array, BinaryArray, BinaryViewArray, MutableBinaryViewArray, PrimitiveArray, Utf8Array,
+    Utf8ViewArray,
+};
 use arrow::datatypes::{ArrowDataType, PhysicalType};
 use arrow::trusted_len::TrustedLen;
 use polars_error::{to_compute_err, PolarsResult};
@@ -39,6 +42,16 @@ fn deserialize_binary_iter<'a, I: TrustedLen<Item = Option<&'a Vec<u8>>>>(
                 Utf8Array::<i64>::try_from_trusted_len_iter(iter).map_err(to_compute_err)?,
             ))
         },
+        PhysicalType::BinaryView => Ok(Box::new(BinaryViewArray::from(
+            iter.collect::<MutableBinaryViewArray<[u8]>>(),
+        ))),
+        PhysicalType::Utf8View => {
+            let values = iter
+                .map(|x| x.map(|x| std::str::from_utf8(x)).transpose())
+                .collect::<Result<MutableBinaryViewArray<str>, _>>()
+                .map_err(to_compute_err)?;
+            Ok(Box::new(Utf8ViewArray::from(values)))
+        },
         _ => Ok(Box::new(BinaryArray::<i32>::from_iter(iter))),
     }
 }
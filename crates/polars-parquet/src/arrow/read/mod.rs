@@ -24,17 +24,23 @@ pub use row_group::*;
 pub use schema::{infer_schema, FileMetaData};
 
 #[cfg(feature = "async")]
-pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_metadata_async};
+pub use crate::parquet::read::{
+    get_page_stream, read_metadata_async as _read_metadata_async,
+    read_metadata_with_decryption_async as _read_metadata_with_decryption_async,
+};
 // re-exports of crate::parquet's relevant APIs
 pub use crate::parquet::{
+    encryption::{FileDecryptionProperties, KeyRetriever},
     error::Error as ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetaData, ColumnDescriptor, RowGroupMetaData},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
         decompress, get_column_iterator, read_columns_indexes as _read_columns_indexes,
-        read_metadata as _read_metadata, read_pages_locations, BasicDecompressor, Decompressor,
-        MutStreamingIterator, PageFilter, PageReader, ReadColumnIterator, State,
+        read_metadata as _read_metadata,
+        read_metadata_with_decryption as _read_metadata_with_decryption, read_pages_locations,
+        BasicDecompressor, Decompressor, MutStreamingIterator, PageFilter, PageReader,
+        ReadColumnIterator, State,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -63,6 +69,17 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetaDat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting the metadata of encrypted files.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<FileMetaData> {
+    Ok(_read_metadata_with_decryption(
+        reader, file_size, decryption,
+    )?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -71,6 +88,15 @@ pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
     Ok(_read_metadata_async(reader).await?)
 }
 
+/// Reads parquets' metadata asynchronously, decrypting the metadata of encrypted files.
+#[cfg(feature = "async")]
+pub async fn read_metadata_with_decryption_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<FileMetaData> {
+    Ok(_read_metadata_with_decryption_async(reader, decryption).await?)
+}
+
 fn convert_days_ms(value: &[u8]) -> arrow::types::days_ms {
     arrow::types::days_ms(
         i32::from_le_bytes(value[4..8].try_into().unwrap()),
@@ -1,7 +1,7 @@
 use std::io::{Read, Seek};
 
 use arrow::array::Array;
-use arrow::datatypes::Field;
+use arrow::datatypes::{ArrowDataType, Field};
 use arrow::record_batch::RecordBatch;
 use polars_error::PolarsResult;
 
@@ -85,6 +85,40 @@ pub fn get_field_columns<'a>(
         .collect()
 }
 
+/// Returns the [`ColumnChunkMetaData`] of the leaves of `field`.
+///
+/// Unlike [`get_field_columns`], `field` may leave out fields of (nested) structs of the field
+/// in the file, so that only the columns of the remaining fields are read.
+pub fn get_field_leaf_columns<'a>(
+    columns: &'a [ColumnChunkMetaData],
+    field: &Field,
+) -> Vec<&'a ColumnChunkMetaData> {
+    get_field_columns(columns, &field.name)
+        .into_iter()
+        .filter(|x| is_leaf_of(&field.data_type, &x.descriptor().path_in_schema[1..]))
+        .collect()
+}
+
+/// Whether the column at `path` below a field of type `data_type` is one of its leaves.
+fn is_leaf_of(data_type: &ArrowDataType, path: &[String]) -> bool {
+    match data_type.to_logical_type() {
+        ArrowDataType::Struct(fields) => match path.split_first() {
+            Some((name, path)) => fields
+                .iter()
+                .any(|field| &field.name == name && is_leaf_of(&field.data_type, path)),
+            None => false,
+        },
+        // A list is stored as a repeated group, which contains the element in a group of its
+        // own if it has the three-level structure.
+        ArrowDataType::List(inner)
+        | ArrowDataType::LargeList(inner)
+        | ArrowDataType::FixedSizeList(inner, _) => {
+            (1..=2).any(|n| path.len() >= n && is_leaf_of(&inner.data_type, &path[n..]))
+        },
+        _ => true,
+    }
+}
+
 /// Returns all [`ColumnChunkMetaData`] associated to `field_name`.
 /// For non-nested parquet types, this returns a single column
 pub fn get_field_pages<'a, T>(
@@ -32,7 +32,7 @@ mod struct_;
 mod utf8;
 
 use self::list::DynMutableListArray;
-use super::get_field_columns;
+use super::get_field_leaf_columns;
 
 /// Arrow-deserialized parquet Statistics of a file
 #[derive(Debug, PartialEq)]
@@ -541,7 +541,7 @@ fn push(
 pub fn deserialize(field: &Field, row_group: &RowGroupMetaData) -> PolarsResult<Statistics> {
     let mut statistics = MutableStatistics::try_new(field)?;
 
-    let columns = get_field_columns(row_group.columns(), field.name.as_ref());
+    let columns = get_field_leaf_columns(row_group.columns(), field);
     let mut stats = columns
         .into_iter()
         .map(|column| {
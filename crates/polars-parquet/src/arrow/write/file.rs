@@ -5,7 +5,8 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{to_parquet_schema, ThriftFileMetaData, WriteOptions};
-use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
+use crate::parquet::encryption::FileEncryptionProperties;
+use crate::parquet::metadata::{KeyValue, SchemaDescriptor, SortingColumn};
 use crate::parquet::write::{RowGroupIter, WriteOptions as FileWriteOptions};
 
 /// Attaches [`ArrowSchema`] to `key_value_metadata`
@@ -70,17 +71,51 @@ impl<W: Write> FileWriter<W> {
         })
     }
 
+    /// Encrypts the file with `encryption`.
+    pub fn with_encryption(self, encryption: FileEncryptionProperties) -> PolarsResult<Self> {
+        Ok(Self {
+            writer: self.writer.with_encryption(encryption)?,
+            ..self
+        })
+    }
+
+    /// Declares the columns by which the rows of every row group are sorted.
+    pub fn with_sorting_columns(self, sorting_columns: Option<Vec<SortingColumn>>) -> Self {
+        Self {
+            writer: self.writer.with_sorting_columns(sorting_columns),
+            ..self
+        }
+    }
+
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIter<'_, PolarsError>) -> PolarsResult<()> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file, with the bitsets of the split-block bloom filters of
+    /// its columns.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters(
+        &mut self,
+        row_group: RowGroupIter<'_, PolarsError>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_bloom_filters(row_group, bloom_filters)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
         Ok(self.writer.end(key_value_metadata)?)
     }
 
+    /// Returns the [`ThriftFileMetaData`]. This is `Some` iff [`Self::end`] has been called.
+    pub fn metadata(&self) -> Option<&ThriftFileMetaData> {
+        self.writer.metadata()
+    }
+
     /// Consumes this writer and returns the inner writer
     pub fn into_inner(self) -> W {
         self.writer.into_inner()
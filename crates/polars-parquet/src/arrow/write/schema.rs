@@ -4,7 +4,7 @@ use base64::engine::general_purpose;
 use base64::Engine as _;
 use polars_error::{polars_bail, PolarsResult};
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, FIELD_ID_META_KEY};
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
 use crate::parquet::schema::types::{
@@ -78,6 +78,23 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
 
 /// Creates a [`ParquetType`] from a [`Field`].
 pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
+    let mut parquet_type = to_parquet_type_without_id(field)?;
+    // The field id is taken from the metadata of the field, as pyarrow does.
+    if let Some(id) = field.metadata.get(FIELD_ID_META_KEY) {
+        let Ok(id) = id.parse::<i32>() else {
+            polars_bail!(
+                InvalidOperation: "invalid parquet field id '{}' of the field '{}'", id, field.name
+            )
+        };
+        match &mut parquet_type {
+            ParquetType::PrimitiveType(primitive) => primitive.field_info.id = Some(id),
+            ParquetType::GroupType { field_info, .. } => field_info.id = Some(id),
+        }
+    }
+    Ok(parquet_type)
+}
+
+fn to_parquet_type_without_id(field: &Field) -> PolarsResult<ParquetType> {
     let name = field.name.clone();
     let repetition = if field.is_nullable {
         Repetition::Optional
@@ -0,0 +1,42 @@
+use std::io::Write;
+
+use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
+use parquet_format_safe::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::Error;
+
+/// The size of a block of a split-block bloom filter in bytes.
+const BLOCK_SIZE: usize = 32;
+/// The maximum size of a bloom filter in bytes, as in parquet-mr.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// The number of bytes of a bloom filter of `ndv` distinct values with a false positive
+/// probability of `fpp`, rounded up to a power of two.
+///
+/// See <https://github.com/apache/parquet-format/blob/master/BloomFilter.md#sizing-an-sbbf>.
+pub fn optimal_num_bytes(ndv: usize, fpp: f64) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(BLOCK_SIZE, MAX_NUM_BYTES)
+        .next_power_of_two()
+        .min(MAX_NUM_BYTES)
+}
+
+/// Writes the header and the `bitset` of a split-block bloom filter. Returns the number of
+/// bytes written.
+pub fn write<W: Write>(mut writer: &mut W, bitset: &[u8]) -> Result<u64, Error> {
+    let header = BloomFilterHeader {
+        num_bytes: bitset.len().try_into()?,
+        algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        hash: BloomFilterHash::XXHASH(XxHash {}),
+        compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    };
+    let mut protocol = TCompactOutputProtocol::new(&mut writer);
+    let header_len = header.write_to_out_protocol(&mut protocol)? as u64;
+    writer.write_all(bitset)?;
+    Ok(header_len + bitset.len() as u64)
+}
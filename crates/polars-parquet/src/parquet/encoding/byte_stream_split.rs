@@ -0,0 +1,104 @@
+//! The `BYTE_STREAM_SPLIT` encoding, which scatters the bytes of every value to `size` streams,
+//! one per byte. The streams of floating point values compress better than the plain values.
+use crate::parquet::error::{Error, Result};
+
+/// The largest values that can be encoded, in bytes.
+pub const MAX_SIZE: usize = 8;
+
+/// Encodes the plain encoded values of `size` bytes each in `values` to `buffer`.
+pub fn encode(values: &[u8], size: usize, buffer: &mut Vec<u8>) {
+    debug_assert_eq!(values.len() % size, 0);
+    let num_values = values.len() / size;
+    let start = buffer.len();
+    buffer.resize(start + values.len(), 0);
+    let streams = &mut buffer[start..];
+    for (i, value) in values.chunks_exact(size).enumerate() {
+        for (byte, value_byte) in value.iter().enumerate() {
+            streams[byte * num_values + i] = *value_byte;
+        }
+    }
+}
+
+/// Decodes the values of a `BYTE_STREAM_SPLIT` encoded buffer into their little endian bytes,
+/// of which the first `size` are set.
+#[derive(Debug)]
+pub struct Decoder<'a> {
+    values: &'a [u8],
+    size: usize,
+    num_values: usize,
+    index: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn try_new(values: &'a [u8], size: usize) -> Result<Self> {
+        if size == 0 || size > MAX_SIZE || values.len() % size != 0 {
+            return Err(Error::oos(
+                "A BYTE_STREAM_SPLIT encoded buffer must hold a whole number of values",
+            ));
+        }
+        Ok(Self {
+            values,
+            size,
+            num_values: values.len() / size,
+            index: 0,
+        })
+    }
+}
+
+impl<'a> Iterator for Decoder<'a> {
+    type Item = [u8; MAX_SIZE];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.num_values {
+            return None;
+        }
+        let mut value = [0; MAX_SIZE];
+        for (byte, value_byte) in value[..self.size].iter_mut().enumerate() {
+            *value_byte = self.values[byte * self.num_values + self.index];
+        }
+        self.index += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.num_values);
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.num_values - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() -> Result<()> {
+        let data = [1.5f32, -2.25, 0.0, f32::MAX];
+        let plain = data
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>();
+
+        let mut buffer = vec![];
+        encode(&plain, 4, &mut buffer);
+        assert_eq!(&buffer[..4], &[plain[0], plain[4], plain[8], plain[12]]);
+
+        let result = Decoder::try_new(&buffer, 4)?
+            .map(|x| f32::from_le_bytes(x[..4].try_into().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(result, data);
+
+        let mut decoder = Decoder::try_new(&buffer, 4)?;
+        let third = decoder.nth(2).unwrap();
+        assert_eq!(f32::from_le_bytes(third[..4].try_into().unwrap()), 0.0);
+        assert_eq!(decoder.size_hint(), (1, Some(1)));
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+#[cfg(feature = "encryption")]
+use aes_gcm::aead::consts::U12;
+#[cfg(feature = "encryption")]
+use aes_gcm::aead::rand_core::RngCore;
+#[cfg(feature = "encryption")]
+use aes_gcm::aead::{Aead, KeyInit, OsRng, Payload};
+#[cfg(feature = "encryption")]
+use aes_gcm::aes::Aes192;
+#[cfg(feature = "encryption")]
+use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm, Nonce};
+
+#[cfg(not(feature = "encryption"))]
+use crate::parquet::error::Feature;
+use crate::parquet::error::{Error, Result};
+
+pub(crate) const NONCE_LEN: usize = 12;
+pub(crate) const TAG_LEN: usize = 16;
+/// The size of the length that prefixes every encrypted module.
+pub(crate) const LENGTH_LEN: usize = 4;
+
+#[cfg(feature = "encryption")]
+type Aes192Gcm = AesGcm<Aes192, U12>;
+
+#[cfg(feature = "encryption")]
+enum Aes {
+    Aes128(Aes128Gcm),
+    Aes192(Aes192Gcm),
+    Aes256(Aes256Gcm),
+}
+
+#[cfg(not(feature = "encryption"))]
+fn not_active() -> Error {
+    Error::FeatureNotActive(
+        Feature::Encryption,
+        "encrypt or decrypt parquet files".to_string(),
+    )
+}
+
+/// Fill `bytes` with cryptographically secure random bytes.
+pub(crate) fn fill_random(bytes: &mut [u8]) -> Result<()> {
+    #[cfg(feature = "encryption")]
+    {
+        OsRng.fill_bytes(bytes);
+        Ok(())
+    }
+    #[cfg(not(feature = "encryption"))]
+    {
+        let _ = bytes;
+        Err(not_active())
+    }
+}
+
+/// Encrypts and decrypts the modules of a file with the key of its footer or of a column.
+///
+/// An encrypted module consists of the length of the rest of the module as a 4-byte little
+/// endian integer, the nonce, the ciphertext and the tag.
+pub(crate) struct ModuleCipher {
+    #[cfg(feature = "encryption")]
+    aes: Aes,
+}
+
+impl std::fmt::Debug for ModuleCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't leak the key.
+        f.write_str("ModuleCipher")
+    }
+}
+
+impl ModuleCipher {
+    /// Returns a new [`ModuleCipher`] for a key of 16, 24 or 32 bytes.
+    pub(crate) fn try_new(key: &[u8]) -> Result<Self> {
+        #[cfg(feature = "encryption")]
+        {
+            let aes = match key.len() {
+                16 => Aes::Aes128(Aes128Gcm::new_from_slice(key).unwrap()),
+                24 => Aes::Aes192(Aes192Gcm::new_from_slice(key).unwrap()),
+                32 => Aes::Aes256(Aes256Gcm::new_from_slice(key).unwrap()),
+                len => {
+                    return Err(Error::InvalidParameter(format!(
+                        "AES-GCM keys must have 16, 24 or 32 bytes, got {len}"
+                    )))
+                },
+            };
+            Ok(Self { aes })
+        }
+        #[cfg(not(feature = "encryption"))]
+        {
+            let _ = key;
+            Err(not_active())
+        }
+    }
+
+    /// Returns the ciphertext followed by the tag.
+    fn seal(&self, nonce: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        #[cfg(feature = "encryption")]
+        {
+            let nonce = Nonce::<U12>::from_slice(nonce);
+            let payload = Payload {
+                msg: plaintext,
+                aad,
+            };
+            match &self.aes {
+                Aes::Aes128(aes) => aes.encrypt(nonce, payload),
+                Aes::Aes192(aes) => aes.encrypt(nonce, payload),
+                Aes::Aes256(aes) => aes.encrypt(nonce, payload),
+            }
+            .map_err(|_| Error::InvalidParameter("failed to encrypt a module".to_string()))
+        }
+        #[cfg(not(feature = "encryption"))]
+        {
+            let _ = (nonce, plaintext, aad);
+            Err(not_active())
+        }
+    }
+
+    /// Returns the plaintext of the ciphertext followed by the tag.
+    fn open(&self, nonce: &[u8], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        #[cfg(feature = "encryption")]
+        {
+            let nonce = Nonce::<U12>::from_slice(nonce);
+            let payload = Payload {
+                msg: ciphertext,
+                aad,
+            };
+            match &self.aes {
+                Aes::Aes128(aes) => aes.decrypt(nonce, payload),
+                Aes::Aes192(aes) => aes.decrypt(nonce, payload),
+                Aes::Aes256(aes) => aes.decrypt(nonce, payload),
+            }
+            .map_err(|_| {
+                Error::InvalidParameter(
+                    "failed to decrypt a module: the key or the AAD prefix is wrong, or the \
+                    file is corrupted"
+                        .to_string(),
+                )
+            })
+        }
+        #[cfg(not(feature = "encryption"))]
+        {
+            let _ = (nonce, ciphertext, aad);
+            Err(not_active())
+        }
+    }
+
+    /// Encrypt a module with a random nonce.
+    pub(crate) fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0; NONCE_LEN];
+        fill_random(&mut nonce)?;
+        let ciphertext = self.seal(&nonce, plaintext, aad)?;
+
+        let len = u32::try_from(NONCE_LEN + ciphertext.len())?;
+        let mut module = Vec::with_capacity(LENGTH_LEN + len as usize);
+        module.extend_from_slice(&len.to_le_bytes());
+        module.extend_from_slice(&nonce);
+        module.extend_from_slice(&ciphertext);
+        Ok(module)
+    }
+
+    /// Decrypt a module, including its length.
+    pub(crate) fn decrypt(&self, module: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        if module.len() < LENGTH_LEN + NONCE_LEN + TAG_LEN {
+            return Err(Error::oos("An encrypted module is too short"));
+        }
+        let (len, module) = module.split_at(LENGTH_LEN);
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        if len != module.len() {
+            return Err(Error::oos(
+                "The length of an encrypted module doesn't match its size",
+            ));
+        }
+        let (nonce, ciphertext) = module.split_at(NONCE_LEN);
+        self.open(nonce, ciphertext, aad)
+    }
+
+    /// The signature of a plaintext footer: a random nonce followed by the tag of the footer.
+    pub(crate) fn sign(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0; NONCE_LEN];
+        fill_random(&mut nonce)?;
+        let ciphertext = self.seal(&nonce, plaintext, aad)?;
+
+        let mut signature = Vec::with_capacity(NONCE_LEN + TAG_LEN);
+        signature.extend_from_slice(&nonce);
+        signature.extend_from_slice(&ciphertext[ciphertext.len() - TAG_LEN..]);
+        Ok(signature)
+    }
+
+    /// Verify the signature of a plaintext footer.
+    pub(crate) fn verify(&self, plaintext: &[u8], signature: &[u8], aad: &[u8]) -> Result<()> {
+        if signature.len() != NONCE_LEN + TAG_LEN {
+            return Err(Error::oos("The signature of the footer is too short"));
+        }
+        let (nonce, tag) = signature.split_at(NONCE_LEN);
+        let ciphertext = self.seal(nonce, plaintext, aad)?;
+        if &ciphertext[ciphertext.len() - TAG_LEN..] != tag {
+            return Err(Error::InvalidParameter(
+                "the signature of the plaintext footer doesn't match: the footer key is wrong \
+                or the footer was tampered with"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
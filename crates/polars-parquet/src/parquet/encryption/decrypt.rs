@@ -0,0 +1,280 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
+use parquet_format_safe::{
+    ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm, FileMetaData as TFileMetaData,
+};
+use polars_utils::aliases::PlHashMap;
+
+use super::cipher::LENGTH_LEN;
+use super::{footer_aad, module_aad, FileDecryptionProperties, ModuleCipher, ModuleType};
+use crate::parquet::error::{Error, Result};
+use crate::parquet::page::ParquetPageHeader;
+
+/// Decrypts the footer and the column metadata of a file that is read.
+pub(crate) struct FileDecryptor {
+    properties: FileDecryptionProperties,
+    file_aad: Arc<[u8]>,
+    /// The cipher of the footer, if its key is given or can be retrieved.
+    footer: Option<Arc<ModuleCipher>>,
+}
+
+impl FileDecryptor {
+    pub(crate) fn try_new(
+        properties: &FileDecryptionProperties,
+        algorithm: &EncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+    ) -> Result<Self> {
+        let algorithm = match algorithm {
+            EncryptionAlgorithm::AESGCMV1(algorithm) => algorithm,
+            EncryptionAlgorithm::AESGCMCTRV1(_) => {
+                return Err(Error::FeatureNotSupported(
+                    "reading files encrypted with AES_GCM_CTR_V1".to_string(),
+                ))
+            },
+        };
+        let mut file_aad = match (properties.aad_prefix(), &algorithm.aad_prefix) {
+            (Some(aad_prefix), _) => aad_prefix.to_vec(),
+            (None, Some(aad_prefix)) => aad_prefix.clone(),
+            (None, None) if algorithm.supply_aad_prefix.unwrap_or(false) => {
+                return Err(Error::InvalidParameter(
+                    "the AAD prefix of the file isn't stored in the file and must be given"
+                        .to_string(),
+                ))
+            },
+            (None, None) => vec![],
+        };
+        file_aad.extend_from_slice(algorithm.aad_file_unique.as_deref().unwrap_or_default());
+
+        let footer = properties
+            .footer_key(footer_key_metadata)
+            .transpose()?
+            .map(|key| ModuleCipher::try_new(&key).map(Arc::new))
+            .transpose()?;
+
+        Ok(Self {
+            properties: properties.clone(),
+            file_aad: file_aad.into(),
+            footer,
+        })
+    }
+
+    fn footer_cipher(&self) -> Result<&ModuleCipher> {
+        self.footer.as_deref().ok_or_else(|| {
+            Error::InvalidParameter("no key to decrypt the footer is given".to_string())
+        })
+    }
+
+    /// Decrypt an encrypted footer, including its length.
+    pub(crate) fn decrypt_footer(&self, module: &[u8]) -> Result<Vec<u8>> {
+        self.footer_cipher()?
+            .decrypt(module, &footer_aad(&self.file_aad))
+    }
+
+    /// Verify the signature of a plaintext footer. The signature can only be verified with
+    /// the footer key, without which the footer is trusted as is.
+    pub(crate) fn verify_footer(&self, footer: &[u8], signature: &[u8]) -> Result<()> {
+        match &self.footer {
+            Some(cipher) => cipher.verify(footer, signature, &footer_aad(&self.file_aad)),
+            None => Ok(()),
+        }
+    }
+
+    /// Decrypt the metadata of the encrypted columns of `metadata` and return the decryptors
+    /// of the columns of every row group, which are `None` for the columns that aren't
+    /// encrypted.
+    ///
+    /// The columns whose key isn't available are left as is if their metadata is in plaintext,
+    /// so that the other columns can be read. Reading their pages fails.
+    pub(crate) fn decrypt_columns(
+        &self,
+        metadata: &mut TFileMetaData,
+    ) -> Result<Vec<Vec<Option<ColumnDecryptor>>>> {
+        let mut ciphers = PlHashMap::<Vec<String>, Arc<ModuleCipher>>::new();
+        let mut decryptors = Vec::with_capacity(metadata.row_groups.len());
+        for (i, row_group) in metadata.row_groups.iter_mut().enumerate() {
+            let row_group_ordinal = row_group.ordinal.map_or(i, |ordinal| ordinal as usize);
+            let mut row_group_decryptors = Vec::with_capacity(row_group.columns.len());
+            for (column, column_chunk) in row_group.columns.iter_mut().enumerate() {
+                let cipher = match &column_chunk.crypto_metadata {
+                    None => None,
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => self.footer.clone(),
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(crypto_metadata)) => {
+                        let path = &crypto_metadata.path_in_schema;
+                        match ciphers.get(path) {
+                            Some(cipher) => Some(cipher.clone()),
+                            None => {
+                                let key_metadata = crypto_metadata.key_metadata.as_deref();
+                                match self.properties.column_key(path, key_metadata) {
+                                    Some(key) => {
+                                        let cipher = Arc::new(ModuleCipher::try_new(&key?)?);
+                                        ciphers.insert(path.clone(), cipher.clone());
+                                        Some(cipher)
+                                    },
+                                    None => None,
+                                }
+                            },
+                        }
+                    },
+                };
+                let Some(cipher) = cipher else {
+                    if column_chunk.meta_data.is_none() {
+                        return Err(Error::InvalidParameter(format!(
+                            "no key to decrypt the column '{}' is given",
+                            column_key_path(&column_chunk.crypto_metadata)
+                        )));
+                    }
+                    row_group_decryptors.push(None);
+                    continue;
+                };
+
+                let decryptor = ColumnDecryptor {
+                    cipher,
+                    file_aad: self.file_aad.clone(),
+                    row_group: row_group_ordinal,
+                    column,
+                };
+                if let Some(module) = &column_chunk.encrypted_column_metadata {
+                    let buffer = decryptor.decrypt(module, ModuleType::ColumnMetaData, None)?;
+                    let max_size = buffer.len() * 2 + 1024;
+                    let mut prot = TCompactInputProtocol::new(buffer.as_slice(), max_size);
+                    column_chunk.meta_data =
+                        Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+                }
+                row_group_decryptors.push(Some(decryptor));
+            }
+            decryptors.push(row_group_decryptors);
+        }
+        Ok(decryptors)
+    }
+}
+
+/// The dot-separated path of a column that is encrypted with its own key, for error messages.
+fn column_key_path(crypto_metadata: &Option<ColumnCryptoMetaData>) -> String {
+    match crypto_metadata {
+        Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(crypto_metadata)) => {
+            crypto_metadata.path_in_schema.join(".")
+        },
+        _ => "<footer key>".to_string(),
+    }
+}
+
+/// Decrypts the modules of an encrypted column chunk.
+#[derive(Clone)]
+pub(crate) struct ColumnDecryptor {
+    cipher: Arc<ModuleCipher>,
+    file_aad: Arc<[u8]>,
+    row_group: usize,
+    column: usize,
+}
+
+impl std::fmt::Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("row_group", &self.row_group)
+            .field("column", &self.column)
+            .finish()
+    }
+}
+
+impl ColumnDecryptor {
+    fn decrypt(
+        &self,
+        module: &[u8],
+        module_type: ModuleType,
+        page: Option<usize>,
+    ) -> Result<Vec<u8>> {
+        let aad = module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group,
+            self.column,
+            page,
+        )?;
+        self.cipher.decrypt(module, &aad)
+    }
+
+    /// The decryptor of the pages of the column chunk, whose first page is a dictionary page
+    /// iff `has_dictionary`.
+    pub(crate) fn pages(&self, has_dictionary: bool) -> PageDecryptor {
+        PageDecryptor {
+            column: self.clone(),
+            page: 0,
+            dictionary: has_dictionary,
+        }
+    }
+}
+
+/// Decrypts the pages of a column chunk in order.
+#[derive(Debug)]
+pub(crate) struct PageDecryptor {
+    column: ColumnDecryptor,
+    /// The ordinal of the next data page.
+    page: usize,
+    /// Whether the next page is the dictionary page.
+    dictionary: bool,
+}
+
+/// Read an encrypted module, including its length.
+fn read_module<R: Read>(reader: &mut R, max_size: usize) -> Result<Vec<u8>> {
+    let mut len = [0; LENGTH_LEN];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    if len > max_size {
+        return Err(Error::WouldOverAllocate);
+    }
+
+    let mut module = Vec::new();
+    module.try_reserve(LENGTH_LEN + len)?;
+    module.extend_from_slice(&(len as u32).to_le_bytes());
+    let bytes_read = reader.take(len as u64).read_to_end(&mut module)?;
+    if bytes_read != len {
+        return Err(Error::oos("An encrypted module is truncated"));
+    }
+    Ok(module)
+}
+
+impl PageDecryptor {
+    /// Read and decrypt the header and the data of the next page into `buffer`.
+    pub(crate) fn read_page<R: Read>(
+        &mut self,
+        reader: &mut R,
+        max_page_size: usize,
+        buffer: &mut Vec<u8>,
+    ) -> Result<ParquetPageHeader> {
+        let (header_type, page_type, page) = if self.dictionary {
+            (
+                ModuleType::DictionaryPageHeader,
+                ModuleType::DictionaryPage,
+                None,
+            )
+        } else {
+            (
+                ModuleType::DataPageHeader,
+                ModuleType::DataPage,
+                Some(self.page),
+            )
+        };
+
+        let header = read_module(reader, max_page_size)?;
+        let header = self.column.decrypt(&header, header_type, page)?;
+        let mut prot = TCompactInputProtocol::new(header.as_slice(), max_page_size);
+        let header = ParquetPageHeader::read_from_in_protocol(&mut prot)?;
+
+        let data = read_module(reader, max_page_size)?;
+        if data.len() != header.compressed_page_size as usize {
+            return Err(Error::oos(
+                "The page header reported the wrong page size".to_string(),
+            ));
+        }
+        *buffer = self.column.decrypt(&data, page_type, page)?;
+
+        if self.dictionary {
+            self.dictionary = false;
+        } else {
+            self.page += 1;
+        }
+        Ok(header)
+    }
+}
@@ -0,0 +1,270 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
+use parquet_format_safe::{
+    AesGcmV1, ColumnChunk, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, FileCryptoMetaData, PageType,
+};
+use polars_utils::aliases::PlHashMap;
+
+use super::cipher::fill_random;
+use super::{footer_aad, module_aad, FileEncryptionProperties, ModuleCipher, ModuleType};
+use crate::parquet::error::Result;
+use crate::parquet::metadata::ThriftFileMetaData;
+use crate::parquet::page::ParquetPageHeader;
+use crate::parquet::{PARQUET_MAGIC, PARQUET_MAGIC_ENCRYPTED_FOOTER};
+
+/// The length of the unique part of the AAD of a file.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// Encrypts the modules of a file that is written.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    file_aad: Arc<[u8]>,
+    aad_file_unique: Vec<u8>,
+    footer: Arc<ModuleCipher>,
+    /// The ciphers and key metadata of the columns that are encrypted with their own key, by
+    /// path.
+    columns: PlHashMap<String, (Arc<ModuleCipher>, Option<Vec<u8>>)>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(properties: FileEncryptionProperties) -> Result<Self> {
+        let footer = Arc::new(ModuleCipher::try_new(properties.footer_key())?);
+        let columns = properties
+            .column_keys()
+            .map(|(path, key, key_metadata)| {
+                let cipher = Arc::new(ModuleCipher::try_new(key)?);
+                Ok((path.clone(), (cipher, key_metadata.cloned())))
+            })
+            .collect::<Result<_>>()?;
+
+        let mut aad_file_unique = vec![0; AAD_FILE_UNIQUE_LEN];
+        fill_random(&mut aad_file_unique)?;
+        let mut file_aad = properties.aad_prefix().cloned().unwrap_or_default();
+        file_aad.extend_from_slice(&aad_file_unique);
+
+        Ok(Self {
+            properties,
+            file_aad: file_aad.into(),
+            aad_file_unique,
+            footer,
+            columns,
+        })
+    }
+
+    /// The magic at the start and the end of the file.
+    pub(crate) fn magic(&self) -> [u8; 4] {
+        if self.properties.plaintext_footer() {
+            PARQUET_MAGIC
+        } else {
+            PARQUET_MAGIC_ENCRYPTED_FOOTER
+        }
+    }
+
+    fn algorithm(&self) -> EncryptionAlgorithm {
+        let aad_prefix = self.properties.aad_prefix();
+        let store_aad_prefix = self.properties.store_aad_prefix();
+        EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix: aad_prefix.filter(|_| store_aad_prefix).cloned(),
+            aad_file_unique: Some(self.aad_file_unique.clone()),
+            supply_aad_prefix: (aad_prefix.is_some() && !store_aad_prefix).then_some(true),
+        })
+    }
+
+    /// The cipher and the crypto metadata of the column at `path`, or `None` if the column
+    /// isn't encrypted.
+    fn column_cipher(&self, path: &[String]) -> Option<(&Arc<ModuleCipher>, ColumnCryptoMetaData)> {
+        if self.columns.is_empty() {
+            let crypto_metadata =
+                ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {});
+            return Some((&self.footer, crypto_metadata));
+        }
+        let (cipher, key_metadata) = self.columns.get(&path.join("."))?;
+        let crypto_metadata =
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                path_in_schema: path.to_vec(),
+                key_metadata: key_metadata.clone(),
+            });
+        Some((cipher, crypto_metadata))
+    }
+
+    /// Whether the column at `path` is encrypted.
+    pub(crate) fn is_encrypted(&self, path: &[String]) -> bool {
+        self.column_cipher(path).is_some()
+    }
+
+    /// The encryptor of the pages and indexes of the column at `path`, or `None` if the column
+    /// isn't encrypted.
+    pub(crate) fn column(
+        &self,
+        path: &[String],
+        row_group: usize,
+        column: usize,
+    ) -> Option<ColumnEncryptor> {
+        let (cipher, _) = self.column_cipher(path)?;
+        Some(ColumnEncryptor {
+            cipher: cipher.clone(),
+            file_aad: self.file_aad.clone(),
+            row_group,
+            column,
+            page: 0,
+        })
+    }
+
+    /// Set the crypto metadata of an encrypted column chunk and encrypt its metadata.
+    ///
+    /// The metadata of a column that is encrypted with its own key, or of any encrypted
+    /// column if the footer is in plaintext, is only stored encrypted. A plaintext footer keeps
+    /// the metadata without the statistics, so that readers without the keys can read the
+    /// schema.
+    pub(crate) fn encrypt_column_metadata(
+        &self,
+        column_chunk: &mut ColumnChunk,
+        row_group: usize,
+        column: usize,
+    ) -> Result<()> {
+        let metadata = column_chunk.meta_data.as_mut().unwrap();
+        let Some((cipher, crypto_metadata)) = self.column_cipher(&metadata.path_in_schema) else {
+            return Ok(());
+        };
+        let footer_key = matches!(
+            crypto_metadata,
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)
+        );
+        column_chunk.crypto_metadata = Some(crypto_metadata);
+        if footer_key && !self.properties.plaintext_footer() {
+            // The metadata is encrypted with the footer.
+            return Ok(());
+        }
+
+        let mut buffer = vec![];
+        metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut buffer))?;
+        let aad = module_aad(
+            &self.file_aad,
+            ModuleType::ColumnMetaData,
+            row_group,
+            column,
+            None,
+        )?;
+        column_chunk.encrypted_column_metadata = Some(cipher.encrypt(&buffer, &aad)?);
+        if self.properties.plaintext_footer() {
+            metadata.statistics = None;
+            metadata.encoding_stats = None;
+        } else {
+            column_chunk.meta_data = None;
+        }
+        Ok(())
+    }
+
+    /// Write the footer of the file, including its length and the magic. The metadata of the
+    /// encrypted columns must be encrypted already.
+    pub(crate) fn write_footer<W: Write>(
+        &self,
+        writer: &mut W,
+        metadata: &ThriftFileMetaData,
+    ) -> Result<u64> {
+        let aad = footer_aad(&self.file_aad);
+        let mut footer = vec![];
+        if self.properties.plaintext_footer() {
+            let mut metadata = metadata.clone();
+            metadata.encryption_algorithm = Some(self.algorithm());
+            metadata.footer_signing_key_metadata = self.properties.footer_key_metadata().cloned();
+            metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))?;
+            let signature = self.footer.sign(&footer, &aad)?;
+            footer.extend_from_slice(&signature);
+        } else {
+            let crypto_metadata = FileCryptoMetaData {
+                encryption_algorithm: self.algorithm(),
+                key_metadata: self.properties.footer_key_metadata().cloned(),
+            };
+            crypto_metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))?;
+            let mut buffer = vec![];
+            metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut buffer))?;
+            footer.extend_from_slice(&self.footer.encrypt(&buffer, &aad)?);
+        }
+
+        let footer_len = i32::try_from(footer.len())?;
+        writer.write_all(&footer)?;
+        writer.write_all(&footer_len.to_le_bytes())?;
+        writer.write_all(&self.magic())?;
+        writer.flush()?;
+        Ok(footer.len() as u64 + 8)
+    }
+}
+
+/// Encrypts the pages and indexes of a column chunk that is written.
+pub(crate) struct ColumnEncryptor {
+    cipher: Arc<ModuleCipher>,
+    file_aad: Arc<[u8]>,
+    row_group: usize,
+    column: usize,
+    /// The ordinal of the next data page.
+    page: usize,
+}
+
+impl ColumnEncryptor {
+    fn aad(&self, module_type: ModuleType, page: Option<usize>) -> Result<Vec<u8>> {
+        module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group,
+            self.column,
+            page,
+        )
+    }
+
+    /// Write the encrypted header and the encrypted data of a page. The compressed size of
+    /// `header` is set to the size of the encrypted data.
+    ///
+    /// Returns the size of the encrypted header and the total number of bytes written.
+    pub(crate) fn write_page<W: Write>(
+        &mut self,
+        writer: &mut W,
+        header: &mut ParquetPageHeader,
+        data: &[u8],
+    ) -> Result<(u64, u64)> {
+        let (page_type, header_type, page) = if header.type_ == PageType::DICTIONARY_PAGE {
+            (
+                ModuleType::DictionaryPage,
+                ModuleType::DictionaryPageHeader,
+                None,
+            )
+        } else {
+            (
+                ModuleType::DataPage,
+                ModuleType::DataPageHeader,
+                Some(self.page),
+            )
+        };
+
+        let data = self.cipher.encrypt(data, &self.aad(page_type, page)?)?;
+        header.compressed_page_size = data.len().try_into()?;
+
+        let mut buffer = vec![];
+        header.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut buffer))?;
+        let header = self
+            .cipher
+            .encrypt(&buffer, &self.aad(header_type, page)?)?;
+
+        writer.write_all(&header)?;
+        writer.write_all(&data)?;
+        if page.is_some() {
+            self.page += 1;
+        }
+        Ok((header.len() as u64, (header.len() + data.len()) as u64))
+    }
+
+    /// Write the encrypted column index or offset index of the column chunk.
+    pub(crate) fn write_index<W: Write>(
+        &self,
+        writer: &mut W,
+        index: &[u8],
+        module_type: ModuleType,
+    ) -> Result<u64> {
+        let index = self.cipher.encrypt(index, &self.aad(module_type, None)?)?;
+        writer.write_all(&index)?;
+        Ok(index.len() as u64)
+    }
+}
@@ -0,0 +1,66 @@
+//! [Parquet modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Files are encrypted with the `AES_GCM_V1` algorithm, which encrypts every module of the
+//! file (the footer, the metadata, the pages and the indexes of the columns) with AES-GCM.
+//! The footer can be encrypted, or written in plaintext and signed so that readers without
+//! the keys can read the columns that aren't encrypted.
+mod cipher;
+mod decrypt;
+mod encrypt;
+mod properties;
+
+use cipher::ModuleCipher;
+pub(crate) use decrypt::{ColumnDecryptor, FileDecryptor, PageDecryptor};
+pub(crate) use encrypt::{ColumnEncryptor, FileEncryptor};
+pub use properties::{FileDecryptionProperties, FileEncryptionProperties, KeyRetriever};
+
+use crate::parquet::error::{Error, Result};
+
+/// The modules of a file, which are encrypted with different AADs (additional authenticated
+/// data), so that they can't be swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+}
+
+/// The AAD of a module: the AAD of the file, followed by the type of the module and the
+/// ordinals of its row group, column and page.
+///
+/// The footer has no ordinals and only data pages and their headers have a page ordinal.
+pub(crate) fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group: usize,
+    column: usize,
+    page: Option<usize>,
+) -> Result<Vec<u8>> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    if module_type == ModuleType::Footer {
+        return Ok(aad);
+    }
+    for ordinal in [Some(row_group), Some(column), page].into_iter().flatten() {
+        let ordinal = i16::try_from(ordinal).map_err(|_| {
+            Error::InvalidParameter(format!(
+                "encrypted files can have at most {} row groups, columns and pages per column \
+                chunk",
+                i16::MAX
+            ))
+        })?;
+        aad.extend_from_slice(&ordinal.to_le_bytes());
+    }
+    Ok(aad)
+}
+
+/// The AAD of the footer.
+pub(crate) fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    module_aad(file_aad, ModuleType::Footer, 0, 0, None).unwrap()
+}
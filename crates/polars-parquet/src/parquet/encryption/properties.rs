@@ -0,0 +1,222 @@
+use std::sync::Arc;
+
+use polars_error::PolarsResult;
+use polars_utils::aliases::PlHashMap;
+
+use crate::parquet::error::{Error, Result};
+
+/// Retrieves the keys of encrypted files from their key metadata, e.g. from a key management
+/// service.
+///
+/// This is implemented for closures that take the key metadata and return the key.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the key of the footer or of a column with `key_metadata`, as written by
+    /// [`FileEncryptionProperties`].
+    fn retrieve_key(&self, key_metadata: &[u8]) -> PolarsResult<Vec<u8>>;
+}
+
+impl<F> KeyRetriever for F
+where
+    F: Fn(&[u8]) -> PolarsResult<Vec<u8>> + Send + Sync,
+{
+    fn retrieve_key(&self, key_metadata: &[u8]) -> PolarsResult<Vec<u8>> {
+        self(key_metadata)
+    }
+}
+
+/// The keys to read encrypted files.
+///
+/// The keys that aren't given explicitly are retrieved from their key metadata in the file
+/// with the [`KeyRetriever`], if any.
+#[derive(Clone, Default)]
+pub struct FileDecryptionProperties {
+    footer_key: Option<Vec<u8>>,
+    column_keys: PlHashMap<String, Vec<u8>>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
+    aad_prefix: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't leak the keys.
+        f.debug_struct("FileDecryptionProperties")
+            .field("footer_key", &self.footer_key.is_some())
+            .field("column_keys", &self.column_keys.keys().collect::<Vec<_>>())
+            .field("key_retriever", &self.key_retriever.is_some())
+            .field("aad_prefix", &self.aad_prefix.is_some())
+            .finish()
+    }
+}
+
+impl FileDecryptionProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The key of the footer, which also decrypts the columns that are encrypted with the
+    /// footer key.
+    pub fn with_footer_key(mut self, key: Vec<u8>) -> Self {
+        self.footer_key = Some(key);
+        self
+    }
+
+    /// The key of the column at the dot-separated `path`, e.g. `a.b` for the field `b` of the
+    /// struct column `a`.
+    pub fn with_column_key(mut self, path: impl Into<String>, key: Vec<u8>) -> Self {
+        self.column_keys.insert(path.into(), key);
+        self
+    }
+
+    /// Retrieve the keys that aren't given explicitly from their key metadata.
+    pub fn with_key_retriever(mut self, key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        self.key_retriever = Some(key_retriever);
+        self
+    }
+
+    /// The AAD prefix of the files, if it isn't stored in the files.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    pub(crate) fn aad_prefix(&self) -> Option<&[u8]> {
+        self.aad_prefix.as_deref()
+    }
+
+    fn retrieve_key(&self, key_metadata: Option<&[u8]>) -> Option<Result<Vec<u8>>> {
+        let retriever = self.key_retriever.as_ref()?;
+        let key = retriever
+            .retrieve_key(key_metadata.unwrap_or_default())
+            .map_err(|e| Error::InvalidParameter(format!("failed to retrieve the key: {e}")));
+        Some(key)
+    }
+
+    /// The key of the footer, if it can be obtained.
+    pub(crate) fn footer_key(&self, key_metadata: Option<&[u8]>) -> Option<Result<Vec<u8>>> {
+        match &self.footer_key {
+            Some(key) => Some(Ok(key.clone())),
+            None => self.retrieve_key(key_metadata),
+        }
+    }
+
+    /// The key of the column at `path`, if it can be obtained.
+    pub(crate) fn column_key(
+        &self,
+        path: &[String],
+        key_metadata: Option<&[u8]>,
+    ) -> Option<Result<Vec<u8>>> {
+        match self.column_keys.get(&path.join(".")) {
+            Some(key) => Some(Ok(key.clone())),
+            None => self.retrieve_key(key_metadata),
+        }
+    }
+}
+
+/// The key of a column that is encrypted with its own key.
+#[derive(Clone)]
+struct ColumnKey {
+    key: Vec<u8>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+/// The keys and options to write encrypted files.
+///
+/// By default, the footer and all columns are encrypted with the footer key. Once column keys
+/// are given, only the columns with a key are encrypted, each with its own key.
+#[derive(Clone)]
+pub struct FileEncryptionProperties {
+    footer_key: Vec<u8>,
+    footer_key_metadata: Option<Vec<u8>>,
+    column_keys: PlHashMap<String, ColumnKey>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+    plaintext_footer: bool,
+}
+
+impl std::fmt::Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't leak the keys.
+        f.debug_struct("FileEncryptionProperties")
+            .field("column_keys", &self.column_keys.keys().collect::<Vec<_>>())
+            .field("aad_prefix", &self.aad_prefix.is_some())
+            .field("store_aad_prefix", &self.store_aad_prefix)
+            .field("plaintext_footer", &self.plaintext_footer)
+            .finish()
+    }
+}
+
+impl FileEncryptionProperties {
+    /// Encrypt with the footer key `footer_key` of 16, 24 or 32 bytes.
+    pub fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key,
+            footer_key_metadata: None,
+            column_keys: Default::default(),
+            aad_prefix: None,
+            store_aad_prefix: true,
+            plaintext_footer: false,
+        }
+    }
+
+    /// Store `key_metadata` in the file, from which readers retrieve the footer key.
+    pub fn with_footer_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.footer_key_metadata = Some(key_metadata);
+        self
+    }
+
+    /// Encrypt the column at the dot-separated `path` with `key`, and store `key_metadata` in
+    /// the file, from which readers retrieve the key.
+    pub fn with_column_key(
+        mut self,
+        path: impl Into<String>,
+        key: Vec<u8>,
+        key_metadata: Option<Vec<u8>>,
+    ) -> Self {
+        self.column_keys
+            .insert(path.into(), ColumnKey { key, key_metadata });
+        self
+    }
+
+    /// Bind the file to `aad_prefix`, e.g. its path, so that it can't be replaced by another
+    /// file encrypted with the same keys. If it isn't stored in the file, readers must supply
+    /// it.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>, store_aad_prefix: bool) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self.store_aad_prefix = store_aad_prefix;
+        self
+    }
+
+    /// Write the footer in plaintext, signed with the footer key, so that readers without the
+    /// keys can read the schema and the columns that aren't encrypted.
+    pub fn with_plaintext_footer(mut self, plaintext_footer: bool) -> Self {
+        self.plaintext_footer = plaintext_footer;
+        self
+    }
+
+    pub(crate) fn footer_key(&self) -> &[u8] {
+        &self.footer_key
+    }
+
+    pub(crate) fn footer_key_metadata(&self) -> Option<&Vec<u8>> {
+        self.footer_key_metadata.as_ref()
+    }
+
+    /// The paths, keys and key metadata of the columns that are encrypted with their own key.
+    pub(crate) fn column_keys(&self) -> impl Iterator<Item = (&String, &[u8], Option<&Vec<u8>>)> {
+        self.column_keys
+            .iter()
+            .map(|(path, key)| (path, key.key.as_slice(), key.key_metadata.as_ref()))
+    }
+
+    pub(crate) fn aad_prefix(&self) -> Option<&Vec<u8>> {
+        self.aad_prefix.as_ref()
+    }
+
+    pub(crate) fn store_aad_prefix(&self) -> bool {
+        self.store_aad_prefix
+    }
+
+    pub(crate) fn plaintext_footer(&self) -> bool {
+        self.plaintext_footer
+    }
+}
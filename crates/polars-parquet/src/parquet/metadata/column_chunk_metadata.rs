@@ -4,6 +4,7 @@ use parquet_format_safe::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::{deserialize_statistics, Statistics};
@@ -38,6 +39,10 @@ pub struct ColumnChunkMetaData {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    /// The decryptor of the modules of the column chunk, if it is encrypted and its key is
+    /// available.
+    #[cfg_attr(feature = "serde_types", serde(skip))]
+    decryptor: Option<ColumnDecryptor>,
 }
 
 #[cfg(feature = "serde_types")]
@@ -75,6 +80,7 @@ impl ColumnChunkMetaData {
         Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         }
     }
 
@@ -91,6 +97,19 @@ impl ColumnChunkMetaData {
         self.column_chunk.file_offset
     }
 
+    /// Whether the column chunk is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto_metadata.is_some()
+    }
+
+    pub(crate) fn decryptor(&self) -> Option<&ColumnDecryptor> {
+        self.decryptor.as_ref()
+    }
+
+    pub(crate) fn set_decryptor(&mut self, decryptor: Option<ColumnDecryptor>) {
+        self.decryptor = decryptor;
+    }
+
     /// Returns this column's [`ColumnChunk`]
     pub fn column_chunk(&self) -> &ColumnChunk {
         &self.column_chunk
@@ -200,6 +219,7 @@ impl ColumnChunkMetaData {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         })
     }
 
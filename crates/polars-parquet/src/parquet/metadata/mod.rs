@@ -10,7 +10,7 @@ pub use column_chunk_metadata::ColumnChunkMetaData;
 pub use column_descriptor::{ColumnDescriptor, Descriptor};
 pub use column_order::ColumnOrder;
 pub use file_metadata::{FileMetaData, KeyValue};
-pub use row_metadata::RowGroupMetaData;
+pub use row_metadata::{RowGroupMetaData, SortingColumn};
 pub use schema_descriptor::SchemaDescriptor;
 pub use sort::*;
 
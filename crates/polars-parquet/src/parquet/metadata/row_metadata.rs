@@ -1,4 +1,4 @@
-use parquet_format_safe::RowGroup;
+use parquet_format_safe::{RowGroup, SortingColumn as ThriftSortingColumn};
 #[cfg(feature = "serde_types")]
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +7,36 @@ use super::schema_descriptor::SchemaDescriptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::write::ColumnOffsetsMetadata;
 
+/// A column by which the rows of a row group are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct SortingColumn {
+    /// The index of the leaf column in the schema.
+    pub column_idx: usize,
+    /// Whether the values are sorted in descending order.
+    pub descending: bool,
+    /// Whether the nulls come before the values.
+    pub nulls_first: bool,
+}
+
+impl SortingColumn {
+    fn try_from_thrift(column: ThriftSortingColumn) -> Result<Self> {
+        Ok(Self {
+            column_idx: column.column_idx.try_into()?,
+            descending: column.descending,
+            nulls_first: column.nulls_first,
+        })
+    }
+
+    pub(crate) fn into_thrift(self) -> ThriftSortingColumn {
+        ThriftSortingColumn {
+            column_idx: self.column_idx as i32,
+            descending: self.descending,
+            nulls_first: self.nulls_first,
+        }
+    }
+}
+
 /// Metadata for a row group.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
@@ -14,6 +44,7 @@ pub struct RowGroupMetaData {
     columns: Vec<ColumnChunkMetaData>,
     num_rows: usize,
     total_byte_size: usize,
+    sorting_columns: Option<Vec<SortingColumn>>,
 }
 
 impl RowGroupMetaData {
@@ -27,6 +58,7 @@ impl RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns: None,
         }
     }
 
@@ -35,6 +67,10 @@ impl RowGroupMetaData {
         &self.columns
     }
 
+    pub(crate) fn columns_mut(&mut self) -> &mut [ColumnChunkMetaData] {
+        &mut self.columns
+    }
+
     /// Number of rows in this row group.
     pub fn num_rows(&self) -> usize {
         self.num_rows
@@ -45,6 +81,11 @@ impl RowGroupMetaData {
         self.total_byte_size
     }
 
+    /// The columns by which the rows of this row group are sorted, if they are declared.
+    pub fn sorting_columns(&self) -> Option<&[SortingColumn]> {
+        self.sorting_columns.as_deref()
+    }
+
     /// Total size of all compressed column data in this row group.
     pub fn compressed_size(&self) -> usize {
         self.columns
@@ -71,11 +112,21 @@ impl RowGroupMetaData {
                 ColumnChunkMetaData::try_from_thrift(descriptor.clone(), column_chunk)
             })
             .collect::<Result<Vec<_>>>()?;
+        let sorting_columns = rg
+            .sorting_columns
+            .map(|columns| {
+                columns
+                    .into_iter()
+                    .map(SortingColumn::try_from_thrift)
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?;
 
         Ok(RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns,
         })
     }
 
@@ -94,7 +145,9 @@ impl RowGroupMetaData {
             columns: self.columns.into_iter().map(|v| v.into_thrift()).collect(),
             total_byte_size: self.total_byte_size as i64,
             num_rows: self.num_rows as i64,
-            sorting_columns: None,
+            sorting_columns: self
+                .sorting_columns
+                .map(|columns| columns.into_iter().map(|c| c.into_thrift()).collect()),
             file_offset,
             total_compressed_size,
             ordinal: None,
@@ -5,6 +5,7 @@ pub mod bloom_filter;
 pub mod compression;
 pub mod deserialize;
 pub mod encoding;
+pub mod encryption;
 pub mod indexes;
 pub mod metadata;
 pub mod page;
@@ -21,6 +22,8 @@ pub use streaming_decompression::{fallible_streaming_iterator, FallibleStreaming
 pub const HEADER_SIZE: u64 = PARQUET_MAGIC.len() as u64;
 pub const FOOTER_SIZE: u64 = 8;
 pub const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+/// The magic of files with an encrypted footer.
+pub const PARQUET_MAGIC_ENCRYPTED_FOOTER: [u8; 4] = [b'P', b'A', b'R', b'E'];
 
 /// The number of bytes read at the end of the parquet file on first read
 const DEFAULT_FOOTER_READ_SIZE: u64 = 64 * 1024;
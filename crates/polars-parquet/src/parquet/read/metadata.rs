@@ -2,10 +2,14 @@ use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
 
 use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
-use parquet_format_safe::FileMetaData as TFileMetaData;
+use parquet_format_safe::{FileCryptoMetaData, FileMetaData as TFileMetaData};
 
+use super::super::encryption::{FileDecryptionProperties, FileDecryptor};
 use super::super::metadata::FileMetaData;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC,
+    PARQUET_MAGIC_ENCRYPTED_FOOTER,
+};
 use crate::parquet::error::{Error, Result};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> i32 {
@@ -28,15 +32,32 @@ fn stream_len(seek: &mut impl Seek) -> std::result::Result<u64, std::io::Error>
 
 /// Reads a [`FileMetaData`] from the reader, located at the end of the file.
 pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetaData> {
+    read_metadata_with_decryption(reader, None)
+}
+
+/// Reads a [`FileMetaData`] from the reader, located at the end of the file, decrypting the
+/// footer and the metadata of the columns of encrypted files.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> Result<FileMetaData> {
     // check file is large enough to hold footer
     let file_size = stream_len(reader)?;
-    read_metadata_with_size(reader, file_size)
+    read_footer(reader, file_size, decryption)
 }
 
 /// Reads a [`FileMetaData`] from the reader, located at the end of the file, with known file size.
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> Result<FileMetaData> {
+    read_footer(reader, file_size, None)
+}
+
+fn read_footer<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> Result<FileMetaData> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(Error::oos(
@@ -55,9 +76,7 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(Error::oos("The file must end with PAR1"));
-    }
+    let magic = check_magic(&buffer[default_end_len - 4..])?;
 
     let metadata_len = metadata_len(&buffer, default_end_len);
 
@@ -85,10 +104,86 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         &buffer
     };
 
+    deserialize_footer(
+        &reader[..reader.len() - FOOTER_SIZE as usize],
+        magic,
+        decryption,
+    )
+}
+
+/// Checks that `magic` is the magic of a parquet file, with a plaintext or encrypted footer.
+pub(super) fn check_magic(magic: &[u8]) -> Result<[u8; 4]> {
+    let magic: [u8; 4] = magic.try_into().unwrap();
+    if magic != PARQUET_MAGIC && magic != PARQUET_MAGIC_ENCRYPTED_FOOTER {
+        return Err(Error::oos("The file must end with PAR1 or PARE"));
+    }
+    Ok(magic)
+}
+
+/// Parse the footer of a file, without its length and the `magic` that follow it.
+///
+/// The footer of files that end with `PARE` is encrypted and can only be read with
+/// `decryption`. The plaintext footer of encrypted files is verified and the metadata of their
+/// encrypted columns is decrypted if `decryption` is given.
+pub fn deserialize_footer(
+    footer: &[u8],
+    magic: [u8; 4],
+    decryption: Option<&FileDecryptionProperties>,
+) -> Result<FileMetaData> {
     // a highly nested but sparse struct could result in many allocations
-    let max_size = reader.len() * 2 + 1024;
+    let max_size = footer.len() * 2 + 1024;
+    let mut reader = footer;
+
+    if magic != PARQUET_MAGIC_ENCRYPTED_FOOTER {
+        let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+        let metadata = TFileMetaData::read_from_in_protocol(&mut prot)?;
+        let (Some(algorithm), Some(decryption)) = (&metadata.encryption_algorithm, decryption)
+        else {
+            return FileMetaData::try_from_thrift(metadata);
+        };
+        let decryptor = FileDecryptor::try_new(
+            decryption,
+            algorithm,
+            metadata.footer_signing_key_metadata.as_deref(),
+        )?;
+        // The signature follows the footer.
+        let signature = reader;
+        decryptor.verify_footer(&footer[..footer.len() - signature.len()], signature)?;
+        return decrypt_metadata(metadata, &decryptor);
+    }
 
-    deserialize_metadata(reader, max_size)
+    let decryption = decryption.ok_or_else(|| {
+        Error::InvalidParameter(
+            "the footer of the file is encrypted, but no keys to decrypt it are given".to_string(),
+        )
+    })?;
+    let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+    let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+    let decryptor = FileDecryptor::try_new(
+        decryption,
+        &crypto_metadata.encryption_algorithm,
+        crypto_metadata.key_metadata.as_deref(),
+    )?;
+    // The encrypted metadata follows the crypto metadata.
+    let footer = decryptor.decrypt_footer(reader)?;
+    let mut prot = TCompactInputProtocol::new(footer.as_slice(), max_size);
+    let metadata = TFileMetaData::read_from_in_protocol(&mut prot)?;
+    decrypt_metadata(metadata, &decryptor)
+}
+
+/// Decrypt the metadata of the encrypted columns and attach their decryptors.
+fn decrypt_metadata(
+    mut metadata: TFileMetaData,
+    decryptor: &FileDecryptor,
+) -> Result<FileMetaData> {
+    let decryptors = decryptor.decrypt_columns(&mut metadata)?;
+    let mut metadata = FileMetaData::try_from_thrift(metadata)?;
+    for (row_group, decryptors) in metadata.row_groups.iter_mut().zip(decryptors) {
+        for (column, decryptor) in row_group.columns_mut().iter_mut().zip(decryptors) {
+            column.set_decryptor(decryptor);
+        }
+    }
+    Ok(metadata)
 }
 
 /// Parse loaded metadata bytes
@@ -5,6 +5,7 @@ use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
 
 use super::PageIterator;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::PageDecryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::indexes::Interval;
 use crate::parquet::metadata::{ColumnChunkMetaData, Descriptor};
@@ -84,6 +85,12 @@ pub struct PageReader<R: Read> {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Whether the column chunk is encrypted.
+    encrypted: bool,
+
+    // The decryptor of the pages of an encrypted column chunk whose key is available.
+    decryptor: Option<PageDecryptor>,
 }
 
 impl<R: Read> PageReader<R> {
@@ -98,7 +105,13 @@ impl<R: Read> PageReader<R> {
         scratch: Vec<u8>,
         max_page_size: usize,
     ) -> Self {
-        Self::new_with_page_meta(reader, column.into(), pages_filter, scratch, max_page_size)
+        let mut reader =
+            Self::new_with_page_meta(reader, column.into(), pages_filter, scratch, max_page_size);
+        reader.encrypted = column.is_encrypted();
+        reader.decryptor = column
+            .decryptor()
+            .map(|decryptor| decryptor.pages(column.dictionary_page_offset().is_some()));
+        reader
     }
 
     /// Create a a new [`PageReader`] with [`PageMetaData`].
@@ -120,6 +133,8 @@ impl<R: Read> PageReader<R> {
             pages_filter,
             scratch,
             max_page_size,
+            encrypted: false,
+            decryptor: None,
         }
     }
 
@@ -184,6 +199,10 @@ pub(super) fn build_page<R: Read>(
     reader: &mut PageReader<R>,
     buffer: &mut Vec<u8>,
 ) -> Result<Option<CompressedPage>> {
+    if reader.encrypted {
+        return build_encrypted_page(reader, buffer).map(Some);
+    }
+
     let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
 
     reader.seen_num_values += get_page_header(&page_header)?
@@ -220,6 +239,31 @@ pub(super) fn build_page<R: Read>(
     .map(Some)
 }
 
+fn build_encrypted_page<R: Read>(
+    reader: &mut PageReader<R>,
+    buffer: &mut Vec<u8>,
+) -> Result<CompressedPage> {
+    let decryptor = reader.decryptor.as_mut().ok_or_else(|| {
+        Error::InvalidParameter(format!(
+            "the column '{}' is encrypted and no key to decrypt it is given",
+            reader.descriptor.primitive_type.field_info.name
+        ))
+    })?;
+    let page_header = decryptor.read_page(&mut reader.reader, reader.max_page_size, buffer)?;
+
+    reader.seen_num_values += get_page_header(&page_header)?
+        .map(|x| x.num_values() as i64)
+        .unwrap_or_default();
+
+    finish_page(
+        page_header,
+        buffer,
+        reader.compression,
+        &reader.descriptor,
+        None,
+    )
+}
+
 pub(super) fn finish_page(
     page_header: ParquetPageHeader,
     data: &mut Vec<u8>,
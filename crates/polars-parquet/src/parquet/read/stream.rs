@@ -2,9 +2,10 @@ use std::io::SeekFrom;
 
 use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 
+use super::super::encryption::FileDecryptionProperties;
 use super::super::metadata::FileMetaData;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_MAGIC};
-use super::metadata::{deserialize_metadata, metadata_len};
+use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE};
+use super::metadata::{check_magic, deserialize_footer, metadata_len};
 use crate::parquet::error::{Error, Result};
 use crate::parquet::HEADER_SIZE;
 
@@ -26,6 +27,15 @@ async fn stream_len(
 /// Asynchronously reads the files' metadata
 pub async fn read_metadata<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>(
     reader: &mut R,
+) -> Result<FileMetaData> {
+    read_metadata_with_decryption(reader, None).await
+}
+
+/// Asynchronously reads the files' metadata, decrypting the footer and the metadata of the
+/// columns of encrypted files.
+pub async fn read_metadata_with_decryption<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> Result<FileMetaData> {
     let file_size = stream_len(reader).await?;
 
@@ -49,9 +59,7 @@ pub async fn read_metadata<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>
         .await?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(Error::oos("Invalid Parquet file. Corrupt footer"));
-    }
+    let magic = check_magic(&buffer[default_end_len - 4..])?;
 
     let metadata_len = metadata_len(&buffer, default_end_len);
     let metadata_len: u64 = metadata_len.try_into()?;
@@ -81,8 +89,9 @@ pub async fn read_metadata<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>
         &buffer
     };
 
-    // a highly nested but sparse struct could result in many allocations
-    let max_size = reader.len() * 2 + 1024;
-
-    deserialize_metadata(reader, max_size)
+    deserialize_footer(
+        &reader[..reader.len() - FOOTER_SIZE as usize],
+        magic,
+        decryption,
+    )
 }
@@ -10,11 +10,12 @@ use polars_utils::aliases::PlHashSet;
 
 #[cfg(feature = "async")]
 use super::page::write_page_async;
-use super::page::{write_page, PageWriteSpec};
+use super::page::{is_data_page, write_page, PageWriteSpec};
 use super::statistics::reduce;
 use super::DynStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
@@ -26,6 +27,7 @@ pub fn write_column_chunk<W, E>(
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    mut encryptor: Option<ColumnEncryptor>,
 ) -> Result<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -38,13 +40,18 @@ where
 
     let mut specs = vec![];
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = write_page(writer, offset, compressed_page, encryptor.as_mut())?;
         offset += spec.bytes_written;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let column_chunk = build_column_chunk(&specs, descriptor, encryptor.is_some())?;
+
+    // the metadata of encrypted columns is only written encrypted, in the footer
+    if encryptor.is_some() {
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
@@ -80,7 +87,7 @@ where
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let column_chunk = build_column_chunk(&specs, descriptor, false)?;
 
     // write metadata
     let mut protocol = TCompactOutputStreamProtocol::new(writer);
@@ -97,6 +104,7 @@ where
 fn build_column_chunk(
     specs: &[PageWriteSpec],
     descriptor: &ColumnDescriptor,
+    encrypted: bool,
 ) -> Result<ColumnChunk> {
     // compute stats to build header at the end of the chunk
 
@@ -124,7 +132,23 @@ fn build_column_chunk(
         .iter()
         .map(|x| x.header_size as i64 + x.header.uncompressed_page_size as i64)
         .sum();
-    let data_page_offset = specs.first().map(|spec| spec.offset).unwrap_or(0) as i64;
+    let start = specs.first().map(|spec| spec.offset).unwrap_or(0) as i64;
+    // readers of encrypted columns need to know whether the first page is a dictionary page,
+    // as the pages are authenticated by their ordinal. SPEC: the dictionary page, if any, is
+    // the first page of the column chunk
+    let (dictionary_page_offset, data_page_offset) = if encrypted {
+        let dictionary_page_offset = specs
+            .first()
+            .filter(|spec| !is_data_page(spec))
+            .map(|spec| spec.offset as i64);
+        let data_page_offset = specs
+            .iter()
+            .find(|spec| is_data_page(spec))
+            .map_or(start, |spec| spec.offset as i64);
+        (dictionary_page_offset, data_page_offset)
+    } else {
+        (None, start)
+    };
     let num_values = specs
         .iter()
         .map(|spec| {
@@ -188,7 +212,7 @@ fn build_column_chunk(
         key_value_metadata: None,
         data_page_offset,
         index_page_offset: None,
-        dictionary_page_offset: None,
+        dictionary_page_offset,
         statistics,
         encoding_stats: None,
         bloom_filter_offset: None,
@@ -196,7 +220,7 @@ fn build_column_chunk(
 
     Ok(ColumnChunk {
         file_path: None, // same file for now.
-        file_offset: data_page_offset + total_compressed_size,
+        file_offset: start + total_compressed_size,
         meta_data: Some(metadata),
         offset_index_offset: None,
         offset_index_length: None,
@@ -1,15 +1,18 @@
 use std::io::Write;
 
 use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
-use parquet_format_safe::RowGroup;
+use parquet_format_safe::{ColumnChunk, RowGroup};
 
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIter, WriteOptions};
+use crate::parquet::encryption::{
+    ColumnEncryptor, FileEncryptionProperties, FileEncryptor, ModuleType,
+};
 use crate::parquet::error::{Error, Result};
 pub use crate::parquet::metadata::KeyValue;
-use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetaData};
+use crate::parquet::metadata::{SchemaDescriptor, SortingColumn, ThriftFileMetaData};
 use crate::parquet::write::State;
 use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
 
@@ -49,6 +52,18 @@ fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<parquet_format_sa
         .collect()
 }
 
+/// The encryptor of the indexes of the column chunk `column` of the row group `row_group`, if
+/// it is encrypted.
+fn column_encryptor(
+    encryptor: Option<&FileEncryptor>,
+    column: &ColumnChunk,
+    row_group: usize,
+    ordinal: usize,
+) -> Option<ColumnEncryptor> {
+    let path = &column.meta_data.as_ref()?.path_in_schema;
+    encryptor?.column(path, row_group, ordinal)
+}
+
 /// An interface to write a parquet file.
 /// Use `start` to write the header, `write` to write a row group,
 /// and `end` to write the footer.
@@ -61,10 +76,16 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// The bitsets of the bloom filters of the columns of every row group, if any.
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
     metadata: Option<ThriftFileMetaData>,
+    /// Encrypts the file, if it is encrypted.
+    encryptor: Option<FileEncryptor>,
+    /// The columns by which the rows of every row group are sorted.
+    sorting_columns: Option<Vec<SortingColumn>>,
 }
 
 /// Writes a parquet file containing only the header and footer
@@ -120,11 +141,30 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
             state: State::Initialised,
             metadata: None,
+            encryptor: None,
+            sorting_columns: None,
         }
     }
 
+    /// Encrypts the file with `encryption`.
+    ///
+    /// # Errors
+    /// Returns an error if a key isn't a valid AES key or the feature `encryption` isn't active.
+    pub fn with_encryption(mut self, encryption: FileEncryptionProperties) -> Result<Self> {
+        self.encryptor = Some(FileEncryptor::try_new(encryption)?);
+        Ok(self)
+    }
+
+    /// Declares the columns by which the rows of every row group are sorted. The sortedness
+    /// isn't checked.
+    pub fn with_sorting_columns(mut self, sorting_columns: Option<Vec<SortingColumn>>) -> Self {
+        self.sorting_columns = sorting_columns;
+        self
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -133,7 +173,14 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> Result<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = match &self.encryptor {
+                Some(encryptor) => {
+                    let magic = encryptor.magic();
+                    self.writer.write_all(&magic)?;
+                    magic.len() as u64
+                },
+                None => start_file(&mut self.writer)?,
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -155,16 +202,63 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
+        group.sorting_columns = self
+            .sorting_columns
+            .as_ref()
+            .map(|columns| columns.iter().map(|c| c.into_thrift()).collect());
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(vec![]);
+        Ok(())
+    }
+
+    /// Writes a row group to the file, with the bitsets of the split-block bloom filters of
+    /// its columns. The bloom filters are written with the footer.
+    ///
+    /// This call is IO-bounded
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIter<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> Result<()>
+    where
+        Error: From<E>,
+        E: std::error::Error,
+    {
+        self.write(row_group)?;
+        *self.bloom_filters.last_mut().unwrap() = bloom_filters;
+        Ok(())
+    }
+
+    /// Writes the bloom filters of the row groups and sets their offsets in the metadata of
+    /// the column chunks.
+    #[cfg(feature = "bloom_filter")]
+    fn write_bloom_filters(&mut self) -> Result<()> {
+        for (group, bloom_filters) in self.row_groups.iter_mut().zip(&self.bloom_filters) {
+            for (column, bitset) in group.columns.iter_mut().zip(bloom_filters) {
+                let (Some(bitset), Some(metadata)) = (bitset, column.meta_data.as_mut()) else {
+                    continue;
+                };
+                // the bloom filters of encrypted columns would leak their values
+                if let Some(encryptor) = &self.encryptor {
+                    if encryptor.is_encrypted(&metadata.path_in_schema) {
+                        continue;
+                    }
+                }
+                metadata.bloom_filter_offset = Some(self.offset as i64);
+                self.offset += crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+            }
+        }
         Ok(())
     }
 
@@ -183,22 +277,45 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        #[cfg(feature = "bloom_filter")]
+        self.write_bloom_filters()?;
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
                 .iter_mut()
                 .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
+                .enumerate()
+                .try_for_each(|(row_group, (group, pages))| {
+                    group
+                        .columns
+                        .iter_mut()
+                        .zip(pages.iter())
+                        .enumerate()
+                        .try_for_each(|(ordinal, (column, pages))| {
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
+                            self.offset += match column_encryptor(
+                                self.encryptor.as_ref(),
+                                column,
+                                row_group,
+                                ordinal,
+                            ) {
+                                Some(encryptor) => {
+                                    let mut index = vec![];
+                                    write_column_index(&mut index, pages)?;
+                                    encryptor.write_index(
+                                        &mut self.writer,
+                                        &index,
+                                        ModuleType::ColumnIndex,
+                                    )?
+                                },
+                                None => write_column_index(&mut self.writer, pages)?,
+                            };
                             let length = self.offset - offset;
                             column.column_index_length = Some(length as i32);
                             Result::Ok(())
-                        },
-                    )?;
+                        })?;
                     Result::Ok(())
                 })?;
         };
@@ -207,15 +324,33 @@ impl<W: Write> FileWriter<W> {
         self.row_groups
             .iter_mut()
             .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
+            .enumerate()
+            .try_for_each(|(row_group, (group, pages))| {
                 group
                     .columns
                     .iter_mut()
                     .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
+                    .enumerate()
+                    .try_for_each(|(ordinal, (column, pages))| {
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
+                        self.offset += match column_encryptor(
+                            self.encryptor.as_ref(),
+                            column,
+                            row_group,
+                            ordinal,
+                        ) {
+                            Some(encryptor) => {
+                                let mut index = vec![];
+                                write_offset_index(&mut index, pages)?;
+                                encryptor.write_index(
+                                    &mut self.writer,
+                                    &index,
+                                    ModuleType::OffsetIndex,
+                                )?
+                            },
+                            None => write_offset_index(&mut self.writer, pages)?,
+                        };
                         column.offset_index_length = Some((self.offset - offset) as i32);
                         Result::Ok(())
                     })?;
@@ -234,7 +369,20 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => {
+                // the metadata of the encrypted columns is encrypted in the footer, the
+                // plaintext metadata is kept for `Self::metadata`
+                let mut encrypted = metadata.clone();
+                for (row_group, group) in encrypted.row_groups.iter_mut().enumerate() {
+                    for (ordinal, column) in group.columns.iter_mut().enumerate() {
+                        encryptor.encrypt_column_metadata(column, row_group, ordinal)?;
+                    }
+                }
+                encryptor.write_footer(&mut self.writer, &encrypted)?
+            },
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
@@ -9,6 +9,7 @@ use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
 use parquet_format_safe::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -50,30 +51,31 @@ pub struct PageWriteSpec {
     pub statistics: Option<Arc<dyn Statistics>>,
 }
 
+/// Writes a page, encrypted with `encryptor` if the column chunk is encrypted.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    encryptor: Option<&mut ColumnEncryptor>,
 ) -> Result<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let selected_rows = compressed_page.selected_rows();
 
-    let header = match &compressed_page {
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
-
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+    let buffer = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
+    let (header_size, bytes_written) = match encryptor {
+        Some(encryptor) => encryptor.write_page(writer, &mut header, buffer)?,
+        None => {
+            let header_size = write_page_header(writer, &header)?;
+            writer.write_all(buffer)?;
+            (header_size, header_size + buffer.len() as u64)
         },
     };
 
@@ -9,6 +9,7 @@ use super::column_chunk::write_column_chunk;
 use super::column_chunk::write_column_chunk_async;
 use super::page::{is_data_page, PageWriteSpec};
 use super::{DynIter, DynStreamingIterator};
+use crate::parquet::encryption::FileEncryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::metadata::{ColumnChunkMetaData, ColumnDescriptor};
 use crate::parquet::page::CompressedPage;
@@ -78,6 +79,7 @@ pub fn write_row_group<
     descriptors: &[ColumnDescriptor],
     columns: DynIter<'a, std::result::Result<DynStreamingIterator<'a, CompressedPage, E>, E>>,
     ordinal: usize,
+    encryptor: Option<&FileEncryptor>,
 ) -> Result<(RowGroup, Vec<Vec<PageWriteSpec>>, u64)>
 where
     W: Write,
@@ -88,9 +90,13 @@ where
 
     let initial = offset;
     let columns = column_iter
-        .map(|(descriptor, page_iter)| {
+        .enumerate()
+        .map(|(column, (descriptor, page_iter))| {
+            let encryptor = encryptor.and_then(|encryptor| {
+                encryptor.column(&descriptor.path_in_schema, ordinal, column)
+            });
             let (column, page_specs, size) =
-                write_column_chunk(writer, offset, descriptor, page_iter?)?;
+                write_column_chunk(writer, offset, descriptor, page_iter?, encryptor)?;
             offset += size;
             Ok((column, page_specs))
         })
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use smartstring::alias::String as SmartString;
+
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// Melts every chunk on its own and emits a chunk per value variable, so the output chunks
+/// don't grow with the number of value variables.
+#[derive(Clone)]
+pub(crate) struct MeltOperator {
+    id_vars: Arc<[SmartString]>,
+    value_vars: Arc<[SmartString]>,
+    variable_name: SmartString,
+    value_name: SmartString,
+    // Supertype of the value variables.
+    dtype: DataType,
+    // Positions of the id and the value variables in the chunks, resolved on the first chunk.
+    positions: Option<(Vec<usize>, Vec<usize>)>,
+    // The value variable that is emitted next for the current chunk.
+    next: usize,
+}
+
+impl MeltOperator {
+    pub(crate) fn new(args: &MeltArgs, input_schema: &Schema) -> PolarsResult<Self> {
+        let value_vars = if args.value_vars.is_empty() {
+            input_schema
+                .iter_names()
+                .filter(|name| !args.id_vars.contains(name))
+                .cloned()
+                .collect()
+        } else {
+            args.value_vars.clone()
+        };
+        let mut dtype = DataType::Null;
+        for name in &value_vars {
+            dtype = try_get_supertype(&dtype, input_schema.try_get(name)?)?;
+        }
+        Ok(Self {
+            id_vars: args.id_vars.clone().into(),
+            value_vars: value_vars.into(),
+            variable_name: args.variable_name.as_deref().unwrap_or("variable").into(),
+            value_name: args.value_name.as_deref().unwrap_or("value").into(),
+            dtype,
+            positions: None,
+            next: 0,
+        })
+    }
+
+    fn positions(&mut self, df: &DataFrame) -> PolarsResult<&(Vec<usize>, Vec<usize>)> {
+        if self.positions.is_none() {
+            let schema = df.schema();
+            let resolve = |names: &[SmartString]| {
+                names
+                    .iter()
+                    .map(|name| Ok(schema.try_get_full(name)?.0))
+                    .collect::<PolarsResult<Vec<_>>>()
+            };
+            self.positions = Some((resolve(&self.id_vars)?, resolve(&self.value_vars)?));
+        }
+        Ok(self.positions.as_ref().unwrap())
+    }
+}
+
+impl Operator for MeltOperator {
+    fn execute(
+        &mut self,
+        _context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let height = chunk.data.height();
+        let variable_name = self.variable_name.clone();
+        let value_name = self.value_name.clone();
+        let dtype = self.dtype.clone();
+        let next = self.next;
+        let n_value_vars = self.value_vars.len();
+        let (id_positions, value_positions) = self.positions(&chunk.data)?;
+
+        let columns = chunk.data.get_columns();
+        if n_value_vars == 0 {
+            let mut out = id_positions
+                .iter()
+                .map(|&i| columns[i].clear())
+                .collect::<Vec<_>>();
+            out.push(Series::new_empty(&variable_name, &DataType::String));
+            out.push(Series::new_empty(&value_name, &DataType::Null));
+            // SAFETY: all columns are empty and have unique names.
+            let df = unsafe { DataFrame::new_no_checks(out) };
+            return Ok(OperatorResult::Finished(chunk.with_data(df)));
+        }
+        let mut out = id_positions
+            .iter()
+            .map(|&i| columns[i].clone())
+            .collect::<Vec<_>>();
+
+        let value = &columns[value_positions[next]];
+        out.push(StringChunked::full(&variable_name, value.name(), height).into_series());
+        let mut value = value.cast(&dtype)?;
+        value.rename(&value_name);
+        out.push(value);
+        // SAFETY: all columns have the height of the chunk and unique names.
+        let df = unsafe { DataFrame::new_no_checks(out) };
+
+        self.next = (next + 1) % n_value_vars;
+        if self.next == 0 {
+            Ok(OperatorResult::Finished(chunk.with_data(df)))
+        } else {
+            Ok(OperatorResult::HaveMoreOutPut(chunk.with_data(df)))
+        }
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "melt"
+    }
+}
@@ -1,5 +1,6 @@
 mod filter;
 mod function;
+mod melt;
 mod pass;
 mod placeholder;
 mod projection;
@@ -7,6 +8,7 @@ mod reproject;
 
 pub(crate) use filter::*;
 pub(crate) use function::*;
+pub(crate) use melt::*;
 pub(crate) use pass::Pass;
 pub(crate) use placeholder::PlaceHolder;
 pub(crate) use projection::*;
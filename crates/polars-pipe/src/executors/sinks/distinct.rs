@@ -0,0 +1,366 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_plan::prelude::SpillOptions;
+
+use crate::executors::sinks::SortSink;
+use crate::executors::sources::ReProjectSource;
+use crate::operators::{
+    DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult, Source, SourceResult,
+};
+
+/// The position of every row in the input of a distinct that is found by sorting.
+pub(crate) const DISTINCT_ROW_COLUMN: &str = "__POLARS_DISTINCT_ROW";
+
+/// Whether row `i + 1` starts a new run of consecutive rows with equal `keys`, for every row
+/// `i` but the last.
+fn run_starts(keys: &[Series], height: usize) -> PolarsResult<BooleanChunked> {
+    let mut run_starts = BooleanChunked::full("", false, height.saturating_sub(1));
+    if height < 2 {
+        return Ok(run_starts);
+    }
+    for s in keys {
+        let differs = s
+            .slice(1, height - 1)
+            .not_equal_missing(&s.slice(0, height - 1))?;
+        run_starts = &run_starts | &differs;
+    }
+    Ok(run_starts)
+}
+
+/// Keep the first, or the last, row of every run of consecutive rows with equal `keys`.
+fn reduce_runs(df: &DataFrame, keys: &[Series], keep_last: bool) -> PolarsResult<DataFrame> {
+    let height = df.height();
+    if height < 2 {
+        return Ok(df.clone());
+    }
+    let run_starts = run_starts(keys, height)?;
+
+    let mut mask = BooleanChunked::from_slice("", &[true]);
+    if keep_last {
+        let mut run_ends = run_starts;
+        run_ends.append(&mask);
+        mask = run_ends;
+    } else {
+        mask.append(&run_starts);
+    }
+    df.filter(&mask)
+}
+
+/// Reduces the chunks of a distinct whose keys are sorted before they are sent to the
+/// group-by that finds the distinct rows.
+///
+/// The rows with equal keys of a sorted chunk are consecutive, so only the first or the last
+/// row of every run has to be hashed.
+pub(crate) struct SortedDistinctSink {
+    sink: Box<dyn Sink>,
+    keys: Arc<[String]>,
+    keep_last: bool,
+}
+
+impl SortedDistinctSink {
+    pub(crate) fn new(sink: Box<dyn Sink>, keys: Arc<[String]>, keep_last: bool) -> Self {
+        Self {
+            sink,
+            keys,
+            keep_last,
+        }
+    }
+}
+
+impl Sink for SortedDistinctSink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let keys = chunk.data.select_series(self.keys.as_ref())?;
+        // Only the sortedness of the first key is known, in which case runs are likely.
+        if keys[0].is_sorted_flag() == IsSorted::Not {
+            return self.sink.sink(context, chunk);
+        }
+        let data = reduce_runs(&chunk.data, &keys, self.keep_last)?;
+        self.sink.sink(context, chunk.with_data(data))
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.sink.combine(other.sink.as_mut())
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            sink: self.sink.split(thread_no),
+            keys: self.keys.clone(),
+            keep_last: self.keep_last,
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        self.sink.finalize(context)
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        self.sink.fmt()
+    }
+}
+
+/// Which row of every run of rows with equal keys a distinct by sorting keeps.
+#[derive(Clone, Copy)]
+pub(crate) enum KeepRow {
+    First,
+    Last,
+    /// Only keep the rows that are the only row of their run.
+    Only,
+}
+
+/// Keeps a row of every run of rows with equal keys in a stream of chunks that are sorted by
+/// their keys. The last row of a chunk is held back until it is known whether the next chunk
+/// continues its run.
+struct RunReducer {
+    keys: Arc<[String]>,
+    keep: KeepRow,
+    /// The last row of the previous chunk, and whether it starts a run.
+    held: Option<(DataFrame, bool)>,
+}
+
+impl RunReducer {
+    fn new(keys: Arc<[String]>, keep: KeepRow) -> Self {
+        Self {
+            keys,
+            keep,
+            held: None,
+        }
+    }
+
+    fn push(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+        if df.height() == 0 {
+            return Ok(df);
+        }
+        let (df, first_starts) = match self.held.take() {
+            Some((mut held, starts)) => {
+                held.vstack_mut(&df)?;
+                (held, starts)
+            },
+            None => (df, true),
+        };
+        let height = df.height();
+        let keys = df.select_series(self.keys.as_ref())?;
+
+        // Whether every row starts a run, and whether every row but the last ends one.
+        let mut starts = BooleanChunked::from_slice("", &[first_starts]);
+        starts.append(&run_starts(&keys, height)?);
+        let ends = starts.slice(1, height - 1);
+        let last_starts = starts.get(height - 1).unwrap();
+        let starts = starts.slice(0, height - 1);
+
+        let mask = match self.keep {
+            KeepRow::First => starts,
+            KeepRow::Last => ends,
+            KeepRow::Only => &starts & &ends,
+        };
+        self.held = Some((df.slice(height as i64 - 1, 1), last_starts));
+        let mut out = df.slice(0, height - 1).filter(&mask)?;
+        out.as_single_chunk();
+        Ok(out)
+    }
+
+    /// The row that was held back, if it is kept, once all chunks are pushed.
+    fn finish(&mut self) -> Option<DataFrame> {
+        let (row, starts) = self.held.take()?;
+        match self.keep {
+            KeepRow::First | KeepRow::Only => starts.then_some(row),
+            KeepRow::Last => Some(row),
+        }
+    }
+}
+
+/// Reduces the runs of the chunks of a source that are sorted by their keys.
+struct RunReducerSource {
+    source: Box<dyn Source>,
+    reducer: RunReducer,
+    chunk_index: IdxSize,
+    finished: bool,
+}
+
+impl Source for RunReducerSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        while !self.finished {
+            match self.source.get_batches(context)? {
+                SourceResult::GotMoreData(chunks) => {
+                    let mut out = Vec::with_capacity(chunks.len());
+                    for chunk in chunks {
+                        self.chunk_index = chunk.chunk_index;
+                        let data = self.reducer.push(chunk.data.clone())?;
+                        if data.height() > 0 {
+                            out.push(chunk.with_data(data));
+                        }
+                    }
+                    if !out.is_empty() {
+                        return Ok(SourceResult::GotMoreData(out));
+                    }
+                },
+                SourceResult::Finished => {
+                    self.finished = true;
+                    if let Some(row) = self.reducer.finish() {
+                        let chunk = DataChunk::new(self.chunk_index + 1, row);
+                        return Ok(SourceResult::GotMoreData(vec![chunk]));
+                    }
+                },
+            }
+        }
+        Ok(SourceResult::Finished)
+    }
+
+    fn fmt(&self) -> &str {
+        "distinct-runs-source"
+    }
+}
+
+/// Finds the distinct rows by sorting them by their keys, so that the rows with equal keys
+/// are consecutive, and keeping a row of every run. Unlike the group-by that is used
+/// otherwise, this knows the position of every row, so it can keep the rows whose keys only
+/// occur once, keep the row with the smallest or largest value of a column and maintain the
+/// order of the rows. The sorts spill to disk if the rows don't fit in memory.
+///
+/// The sorted sink gets the rows with their position, the [`DISTINCT_ROW_COLUMN`], and
+/// sorts them by their keys and then by their position, so that ties keep the first row.
+pub(crate) struct DistinctBySortSink {
+    sort: Box<dyn Sink>,
+    keys: Arc<[String]>,
+    keep: KeepRow,
+    maintain_order: bool,
+    /// The schema of the sorted rows, with the position of every row.
+    sort_schema: SchemaRef,
+    /// The schema of the input, without the position of every row.
+    schema: SchemaRef,
+    spill_options: SpillOptions,
+}
+
+impl DistinctBySortSink {
+    pub(crate) fn new(
+        sort: Box<dyn Sink>,
+        keys: Arc<[String]>,
+        keep: KeepRow,
+        maintain_order: bool,
+        sort_schema: SchemaRef,
+        schema: SchemaRef,
+        spill_options: SpillOptions,
+    ) -> Self {
+        Self {
+            sort,
+            keys,
+            keep,
+            maintain_order,
+            sort_schema,
+            schema,
+            spill_options,
+        }
+    }
+
+    /// Sort the distinct rows by their position in the input.
+    fn sort_by_row(
+        &self,
+        context: &PExecutionContext,
+        distinct: FinalizedSink,
+    ) -> PolarsResult<FinalizedSink> {
+        Ok(match distinct {
+            FinalizedSink::Finished(df) => FinalizedSink::Finished(
+                df.sort([DISTINCT_ROW_COLUMN], SortMultipleOptions::default())?,
+            ),
+            FinalizedSink::Source(mut source) => {
+                let mut sort = SortSink::new(
+                    self.sort_schema.try_index_of(DISTINCT_ROW_COLUMN)?,
+                    None,
+                    SortMultipleOptions::default(),
+                    self.sort_schema.clone(),
+                    self.spill_options,
+                );
+                while let SourceResult::GotMoreData(chunks) = source.get_batches(context)? {
+                    for chunk in chunks {
+                        sort.sink(context, chunk)?;
+                    }
+                }
+                sort.finalize(context)?
+            },
+            FinalizedSink::Operator => unreachable!(),
+        })
+    }
+}
+
+impl Sink for DistinctBySortSink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // The rows are ordered by the index of their chunk and their position in it.
+        let offset = (chunk.chunk_index as u64) << 32;
+        let rows = UInt64Chunked::from_iter_values(
+            DISTINCT_ROW_COLUMN,
+            (0..chunk.data.height() as u64).map(|i| offset | i),
+        );
+        let mut data = chunk.data.clone();
+        // SAFETY: the column has the height of the chunk.
+        unsafe { data.with_column_unchecked(rows.into_series()) };
+        self.sort.sink(context, chunk.with_data(data))
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.sort.combine(other.sort.as_mut())
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            sort: self.sort.split(thread_no),
+            keys: self.keys.clone(),
+            keep: self.keep,
+            maintain_order: self.maintain_order,
+            sort_schema: self.sort_schema.clone(),
+            schema: self.schema.clone(),
+            spill_options: self.spill_options,
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let mut reducer = RunReducer::new(self.keys.clone(), self.keep);
+        let distinct = match self.sort.finalize(context)? {
+            FinalizedSink::Finished(df) => {
+                let mut df = reducer.push(df)?;
+                if let Some(row) = reducer.finish() {
+                    df.vstack_mut(&row)?;
+                }
+                FinalizedSink::Finished(df)
+            },
+            FinalizedSink::Source(source) => FinalizedSink::Source(Box::new(RunReducerSource {
+                source,
+                reducer,
+                chunk_index: 0,
+                finished: false,
+            })),
+            FinalizedSink::Operator => unreachable!(),
+        };
+        let distinct = if self.maintain_order {
+            self.sort_by_row(context, distinct)?
+        } else {
+            distinct
+        };
+
+        // Drop the position of the rows.
+        Ok(match distinct {
+            FinalizedSink::Finished(df) => {
+                FinalizedSink::Finished(df.select(self.schema.iter_names())?)
+            },
+            FinalizedSink::Source(source) => {
+                FinalizedSink::Source(Box::new(ReProjectSource::new(self.schema.clone(), source)))
+            },
+            FinalizedSink::Operator => unreachable!(),
+        })
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "distinct_by_sort"
+    }
+}
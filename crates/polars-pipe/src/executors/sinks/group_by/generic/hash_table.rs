@@ -60,6 +60,10 @@ impl<const FIXED: bool> AggHashTable<FIXED> {
         self.inner_map.is_empty()
     }
 
+    pub(super) fn len(&self) -> usize {
+        self.inner_map.len()
+    }
+
     fn get_entry(&mut self, hash: u64, row: &[u8]) -> RawEntryMut<Key, u32, IdBuildHasher> {
         let keys = self.keys.as_ptr();
 
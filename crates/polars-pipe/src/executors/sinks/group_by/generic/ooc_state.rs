@@ -1,4 +1,5 @@
 use polars_core::config::verbose;
+use polars_plan::prelude::SpillOptions;
 
 use super::*;
 use crate::executors::sinks::memory::MemTracker;
@@ -16,6 +17,7 @@ pub(super) struct OocState {
     pub(super) io_thread: IOThreadRef,
     count: u16,
     to_disk_threshold: f64,
+    pub(super) spill_options: SpillOptions,
 }
 
 impl Default for OocState {
@@ -32,6 +34,7 @@ impl Default for OocState {
             io_thread: Default::default(),
             count: 0,
             to_disk_threshold,
+            spill_options: SpillOptions::default(),
         }
     }
 }
@@ -59,7 +62,11 @@ impl OocState {
         // start IO thread
         let mut iot = self.io_thread.lock().unwrap();
         if iot.is_none() {
-            *iot = Some(IOThread::try_new(Arc::new(spill_schema), "group_by").unwrap());
+            *iot = Some(IOThread::try_new(
+                Arc::new(spill_schema),
+                "group_by",
+                self.spill_options,
+            )?);
         }
         Ok(())
     }
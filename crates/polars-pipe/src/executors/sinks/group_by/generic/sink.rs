@@ -1,6 +1,7 @@
 use std::cell::UnsafeCell;
 
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::prelude::SpillOptions;
 
 use super::*;
 use crate::executors::sinks::group_by::generic::global::GlobalTable;
@@ -14,6 +15,8 @@ pub(crate) struct GenericGroupby2 {
     global_table: Arc<GlobalTable>,
     eval: Eval,
     slice: Option<(i64, usize)>,
+    // finish once a thread has found this many groups
+    finish_after: Option<usize>,
     ooc_state: OocState,
 }
 
@@ -52,9 +55,24 @@ impl GenericGroupby2 {
             global_table: Arc::new(global_map),
             eval: Eval::new(key_columns, aggregation_columns),
             slice,
+            finish_after: None,
             ooc_state: Default::default(),
         }
     }
+
+    /// Set how the state is spilled to disk when the sink runs out of memory.
+    pub(crate) fn with_spill_options(mut self, spill_options: SpillOptions) -> Self {
+        self.ooc_state.spill_options = spill_options;
+        self
+    }
+
+    /// Stop consuming input once a thread has found `n_groups` groups. Only valid if any
+    /// `n_groups` groups form a correct result, e.g. for a `unique(keep="any")` that is
+    /// sliced to `n_groups` rows.
+    pub(crate) fn with_finish_after(mut self, n_groups: usize) -> Self {
+        self.finish_after = Some(n_groups);
+        self
+    }
 }
 
 impl Sink for GenericGroupby2 {
@@ -93,6 +111,14 @@ impl Sink for GenericGroupby2 {
             self.eval.clear()
         };
 
+        if let Some(n_groups) = self.finish_after {
+            // SAFETY: we don't hold mutable refs
+            let table = unsafe { &*self.thread_local_table.get() };
+            if table.n_groups().is_some_and(|len| len >= n_groups) {
+                return Ok(SinkResult::Finished);
+            }
+        }
+
         // indicates if we should early merge a partition
         // other scenario could be that we must spill to disk
         match self
@@ -129,6 +155,7 @@ impl Sink for GenericGroupby2 {
             thread_local_table: UnsafeCell::new(map),
             global_table: self.global_table.clone(),
             slice: self.slice,
+            finish_after: self.finish_after,
             ooc_state: self.ooc_state.clone(),
         })
     }
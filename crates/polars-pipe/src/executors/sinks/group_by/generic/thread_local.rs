@@ -256,6 +256,11 @@ impl ThreadLocalTable {
         }
     }
 
+    /// The number of groups, if none of them have spilled.
+    pub(super) fn n_groups(&self) -> Option<usize> {
+        (!self.spill_partitions.spilled).then(|| self.inner_map.len())
+    }
+
     pub(super) fn get_inner_map_mut(&mut self) -> &mut AggHashTable<true> {
         &mut self.inner_map
     }
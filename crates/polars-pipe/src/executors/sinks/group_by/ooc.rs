@@ -105,6 +105,7 @@ impl Source for GroupBySource {
                             .map(|data| {
                                 let chunk = DataChunk {
                                     chunk_index: self.chunk_idx,
+                                    batch_index: 0,
                                     data,
                                 };
                                 self.chunk_idx += 1;
@@ -2,6 +2,7 @@ use std::sync::Mutex;
 
 use polars_core::config::verbose;
 use polars_core::prelude::*;
+use polars_plan::prelude::SpillOptions;
 
 use crate::executors::sinks::io::IOThread;
 use crate::executors::sinks::memory::MemTracker;
@@ -40,7 +41,12 @@ impl OocState {
         // start IO thread
         let mut iot = self.io_thread.lock().unwrap();
         if iot.is_none() {
-            *iot = Some(IOThread::try_new(input_schema, "group_by")?)
+            // These sinks never go out of core, see above.
+            *iot = Some(IOThread::try_new(
+                input_schema,
+                "group_by",
+                SpillOptions::default(),
+            )?)
         }
         Ok(())
     }
@@ -8,7 +8,9 @@ use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
 use polars_core::error::ErrString;
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
+use polars_core::POOL;
 use polars_io::prelude::*;
+use polars_plan::prelude::SpillOptions;
 
 use crate::executors::sinks::get_base_temp_dir;
 use crate::pipeline::morsels_per_sink;
@@ -28,6 +30,7 @@ pub(crate) struct IOThread {
     pub(in crate::executors::sinks) total: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) thread_local_count: Arc<AtomicUsize>,
     schema: SchemaRef,
+    spill_options: SpillOptions,
 }
 
 fn get_lockfile_path(dir: &Path) -> PathBuf {
@@ -58,6 +61,16 @@ fn get_spill_dir(operation_name: &'static str) -> PolarsResult<PathBuf> {
     Ok(dir)
 }
 
+/// Check the options before the IO thread starts, as it can't report errors.
+fn check_spill_options(options: &SpillOptions) -> PolarsResult<()> {
+    IpcCompression::check_level(options.compression, options.compression_level)?;
+    polars_ensure!(
+        options.decompression_threads != Some(0),
+        InvalidOperation: "the number of spill decompression threads must be positive"
+    );
+    Ok(())
+}
+
 fn clean_after_delay(time: Option<SystemTime>, secs: u64, path: &Path) {
     if let Some(time) = time {
         let modified_since = SystemTime::now().duration_since(time).unwrap().as_secs();
@@ -130,7 +143,9 @@ impl IOThread {
         schema: SchemaRef,
         // Will be used as subdirectory name in `~/.base_dir/polars/`
         operation_name: &'static str,
+        spill_options: SpillOptions,
     ) -> PolarsResult<Self> {
+        check_spill_options(&spill_options)?;
         let dir = get_spill_dir(operation_name)?;
 
         // make sure we create lockfile before we GC
@@ -177,7 +192,10 @@ impl IOThread {
                         path.push(format!("{count}.ipc"));
 
                         let file = File::create(path).unwrap();
-                        let writer = IpcWriter::new(file).with_pl_flavor(true);
+                        let writer = IpcWriter::new(file)
+                            .with_compression(spill_options.compression)
+                            .with_compression_level(spill_options.compression_level)
+                            .with_pl_flavor(true);
                         let mut writer = writer.batched(&schema).unwrap();
                         writer.write_batch(&df).unwrap();
                         writer.finish().unwrap();
@@ -188,7 +206,10 @@ impl IOThread {
                     path.push(format!("{count}_0_pass.ipc"));
 
                     let file = File::create(path).unwrap();
-                    let writer = IpcWriter::new(file).with_pl_flavor(true);
+                    let writer = IpcWriter::new(file)
+                        .with_compression(spill_options.compression)
+                        .with_compression_level(spill_options.compression_level)
+                        .with_pl_flavor(true);
                     let mut writer = writer.batched(&schema).unwrap();
 
                     for mut df in iter {
@@ -212,9 +233,17 @@ impl IOThread {
             _lockfile: lockfile,
             thread_local_count,
             schema,
+            spill_options,
         })
     }
 
+    /// The number of spilled files that are read and decompressed at a time.
+    pub(in crate::executors::sinks) fn decompression_threads(&self) -> usize {
+        self.spill_options
+            .decompression_threads
+            .unwrap_or_else(|| POOL.current_num_threads())
+    }
+
     pub(in crate::executors::sinks) fn dump_chunk(&self, mut df: DataFrame) {
         // if IO thread is blocked
         // we write locally on this thread
@@ -227,7 +256,10 @@ impl IOThread {
             path.push(format!("_{count}_full.ipc"));
 
             let file = File::create(path).unwrap();
-            let mut writer = IpcWriter::new(file).with_pl_flavor(true);
+            let mut writer = IpcWriter::new(file)
+                .with_compression(self.spill_options.compression)
+                .with_compression_level(self.spill_options.compression_level)
+                .with_pl_flavor(true);
             writer.finish(&mut df).unwrap();
         } else {
             let iter = Box::new(std::iter::once(df));
@@ -260,7 +292,10 @@ impl IOThread {
         // duplicates
         path.push(format!("_{count}.ipc"));
         let file = File::create(path).unwrap();
-        let writer = IpcWriter::new(file).with_pl_flavor(true);
+        let writer = IpcWriter::new(file)
+            .with_compression(self.spill_options.compression)
+            .with_compression_level(self.spill_options.compression_level)
+            .with_pl_flavor(true);
         let mut writer = writer.batched(&self.schema).unwrap();
         writer.write_batch(&df).unwrap();
         writer.finish().unwrap();
@@ -74,6 +74,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             let tmp = DataChunk {
                 data: df_a.slice(0, 1),
                 chunk_index: 0,
+                batch_index: 0,
             };
             // remove duplicate_names caused by joining
             // on the same column
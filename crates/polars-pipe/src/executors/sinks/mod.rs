@@ -1,3 +1,4 @@
+mod distinct;
 pub(crate) mod group_by;
 mod io;
 mod joins;
@@ -11,6 +12,7 @@ mod utils;
 
 use std::sync::OnceLock;
 
+pub(crate) use distinct::*;
 pub(crate) use joins::*;
 pub(crate) use ordered::*;
 #[cfg(any(
@@ -14,6 +14,40 @@ pub(super) trait SinkWriter {
     fn _finish(&mut self) -> PolarsResult<()>;
 }
 
+type Commit = Box<dyn FnOnce() -> PolarsResult<()> + Send>;
+
+/// Commits the output of a writer once it is completely written.
+pub(super) struct CommittingWriter {
+    writer: Box<dyn SinkWriter + Send>,
+    commit: Option<Commit>,
+}
+
+impl CommittingWriter {
+    pub(super) fn new(
+        writer: Box<dyn SinkWriter + Send>,
+        commit: impl FnOnce() -> PolarsResult<()> + Send + 'static,
+    ) -> Self {
+        Self {
+            writer,
+            commit: Some(Box::new(commit)),
+        }
+    }
+}
+
+impl SinkWriter for CommittingWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.writer._write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.writer._finish()?;
+        match self.commit.take() {
+            Some(commit) => commit(),
+            None => Ok(()),
+        }
+    }
+}
+
 pub(super) fn init_writer_thread(
     receiver: Receiver<Option<DataChunk>>,
     mut writer: Box<dyn SinkWriter + Send>,
@@ -22,7 +56,7 @@ pub(super) fn init_writer_thread(
     // all chunks per push should be collected to determine in which order they should
     // be written
     morsels_per_sink: usize,
-) -> JoinHandle<()> {
+) -> JoinHandle<PolarsResult<()>> {
     std::thread::spawn(move || {
         // keep chunks around until all chunks per sink are written
         // then we write them all at once.
@@ -51,7 +85,7 @@ pub(super) fn init_writer_thread(
                         if df.n_chunks() > 1 {
                             df.as_single_chunk();
                         }
-                        writer._write_batch(&df).unwrap();
+                        writer._write_batch(&df)?;
                     }
                 }
                 // all chunks are written remove them
@@ -62,13 +96,13 @@ pub(super) fn init_writer_thread(
                         if df.n_chunks() > 1 {
                             df.as_single_chunk();
                         }
-                        writer._write_batch(&df).unwrap();
+                        writer._write_batch(&df)?;
                     }
-                    writer._finish().unwrap();
-                    return;
+                    return writer._finish();
                 }
             }
         }
+        Ok(())
     })
 }
 
@@ -76,14 +110,15 @@ pub(super) fn init_writer_thread(
 #[derive(Clone)]
 pub struct FilesSink {
     pub(crate) sender: Sender<Option<DataChunk>>,
-    pub(crate) io_thread_handle: Arc<Option<JoinHandle<()>>>,
+    pub(crate) io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
 }
 
 impl Sink for FilesSink {
     fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
         // don't add empty dataframes
-        if chunk.data.height() > 0 {
-            self.sender.send(Some(chunk)).unwrap();
+        // The io thread only stops early on an error, which is returned by `finalize`.
+        if chunk.data.height() > 0 && self.sender.send(Some(chunk)).is_err() {
+            return Ok(SinkResult::Finished);
         };
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -97,7 +132,7 @@ impl Sink for FilesSink {
     }
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         // `None` indicates that we can flush all remaining chunks.
-        self.sender.send(None).unwrap();
+        let _ = self.sender.send(None);
 
         // wait until all files written
         // some unwrap/mut kung-fu to get a hold of `self`
@@ -106,7 +141,7 @@ impl Sink for FilesSink {
             .take()
             .unwrap()
             .join()
-            .unwrap();
+            .unwrap()?;
 
         // return a dummy dataframe;
         Ok(FinalizedSink::Finished(Default::default()))
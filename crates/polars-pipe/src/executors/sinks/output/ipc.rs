@@ -5,19 +5,40 @@ use polars_core::prelude::*;
 use polars_io::ipc::IpcWriterOptions;
 use polars_io::prelude::*;
 
-use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::executors::sinks::output::file_sink::{
+    init_writer_thread, CommittingWriter, FilesSink, SinkWriter,
+};
 use crate::pipeline::morsels_per_sink;
 
+/// The writer of an IPC sink, which writes the stream format if the options ask for it.
+pub(super) fn batched_ipc_writer<W: std::io::Write + Send + 'static>(
+    writer: W,
+    options: IpcWriterOptions,
+    schema: &Schema,
+) -> PolarsResult<Box<dyn SinkWriter + Send>> {
+    if options.stream {
+        let writer = IpcStreamWriter::new(writer)
+            .with_compression(options.compression)
+            .with_compression_level(options.compression_level)
+            .with_dictionary_mode(options.dictionary_mode)
+            .batched(schema)?;
+        Ok(Box::new(writer))
+    } else {
+        let writer = IpcWriter::new(writer)
+            .with_compression(options.compression)
+            .with_compression_level(options.compression_level)
+            .batched(schema)?;
+        Ok(Box::new(writer))
+    }
+}
+
 pub struct IpcSink {}
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = IpcWriter::new(file)
-            .with_compression(options.compression)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+        let (file, commit) = FileCommit::create(path, options.commit_strategy)?;
+        let writer = batched_ipc_writer(file, options, schema)?;
+        let writer = Box::new(CommittingWriter::new(writer, move || commit.commit()));
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -49,12 +70,12 @@ impl IpcCloudSink {
         ipc_options: IpcWriterOptions,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
-        let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
-        let writer = IpcWriter::new(cloud_writer)
-            .with_compression(ipc_options.compression)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+        let mut cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
+        // An object only becomes visible once its upload is completed, whatever the commit
+        // strategy, and completing it here reports its errors.
+        let commit = cloud_writer.deferred_commit();
+        let writer = batched_ipc_writer(cloud_writer, ipc_options, schema)?;
+        let writer = Box::new(CommittingWriter::new(writer, move || commit.commit()));
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -84,3 +105,14 @@ impl<W: std::io::Write> SinkWriter for polars_io::ipc::BatchedWriter<W> {
         Ok(())
     }
 }
+
+impl<W: std::io::Write> SinkWriter for polars_io::ipc::BatchedStreamWriter<W> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.finish()?;
+        Ok(())
+    }
+}
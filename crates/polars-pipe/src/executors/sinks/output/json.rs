@@ -3,11 +3,14 @@ use std::path::Path;
 use crossbeam_channel::bounded;
 use polars_core::prelude::*;
 use polars_io::json::{BatchedWriter, JsonWriterOptions};
+use polars_io::utils::FileCommit;
 
-use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::executors::sinks::output::file_sink::{
+    init_writer_thread, CommittingWriter, FilesSink, SinkWriter,
+};
 use crate::pipeline::morsels_per_sink;
 
-impl SinkWriter for BatchedWriter<std::fs::File> {
+impl<W: std::io::Write> SinkWriter for BatchedWriter<W> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
@@ -25,10 +28,9 @@ impl JsonSink {
         options: JsonWriterOptions,
         _schema: &Schema,
     ) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = BatchedWriter::new(file);
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+        let (file, commit) = FileCommit::create(path, options.commit_strategy)?;
+        let writer = Box::new(BatchedWriter::new(file));
+        let writer = Box::new(CommittingWriter::new(writer, move || commit.commit()));
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -4,22 +4,28 @@ use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
 use polars_core::prelude::*;
-use polars_io::parquet::write::{BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIter};
-
-use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use polars_io::parquet::write::{
+    BatchedWriter, BloomFilters, ParquetSinkOptions, ParquetWriter, RowGroupIter,
+};
+use polars_io::utils::FileCommit;
+
+use crate::executors::sinks::output::file_sink::{
+    init_writer_thread, CommittingWriter, FilesSink, SinkWriter,
+};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-type RowGroups = Vec<RowGroupIter<'static, PolarsError>>;
+type RowGroups = Vec<(RowGroupIter<'static, PolarsError>, BloomFilters)>;
 
 pub(super) fn init_row_group_writer_thread(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
     writer: Arc<BatchedWriter<std::fs::File>>,
+    commit: FileCommit,
     // this is used to determine when a batch of chunks should be written to disk
     // all chunks per push should be collected to determine in which order they should
     // be written
     morsels_per_sink: usize,
-) -> JoinHandle<()> {
+) -> JoinHandle<PolarsResult<()>> {
     std::thread::spawn(move || {
         // keep chunks around until all chunks per sink are written
         // then we write them all at once.
@@ -38,36 +44,50 @@ pub(super) fn init_row_group_writer_thread(
                 batched.sort_by_key(|chunk| chunk.0);
 
                 for (_, rg) in batched.drain(0..) {
-                    writer.write_row_groups(rg).unwrap()
+                    writer.write_row_groups(rg)?;
                 }
             }
             if last_write {
-                writer.finish().unwrap();
-                return;
+                writer.finish()?;
+                return commit.commit();
             }
         }
+        Ok(())
     })
 }
 
+pub(super) fn batched_parquet_writer<W: std::io::Write>(
+    writer: W,
+    options: &ParquetSinkOptions,
+    schema: &Schema,
+) -> PolarsResult<BatchedWriter<W>> {
+    let write_options = options.write_options;
+    ParquetWriter::new(writer)
+        .with_compression(write_options.compression)
+        .with_data_page_size(write_options.data_pagesize_limit)
+        .with_statistics(write_options.statistics)
+        .with_bloom_filters(write_options.bloom_filters)
+        .with_row_group_size(write_options.row_group_size)
+        .with_column_options(options.column_options.clone())
+        .with_sorting_columns(options.sorting_columns.clone())
+        .with_key_value_metadata(options.key_value_metadata.clone())
+        // This is important! Otherwise we will deadlock
+        // See: #7074
+        .set_parallel(false)
+        .batched(schema)
+}
+
 #[derive(Clone)]
 pub struct ParquetSink {
     writer: Arc<BatchedWriter<std::fs::File>>,
-    io_thread_handle: Arc<Option<JoinHandle<()>>>,
+    io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
     sender: Sender<Option<(IdxSize, RowGroups)>>,
 }
 impl ParquetSink {
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(path: &Path, options: ParquetWriteOptions, schema: &Schema) -> PolarsResult<Self> {
-        let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_page_size(options.data_pagesize_limit)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
+    pub fn new(path: &Path, options: ParquetSinkOptions, schema: &Schema) -> PolarsResult<Self> {
+        let (file, commit) = FileCommit::create(path, options.write_options.commit_strategy)?;
+        let writer = batched_parquet_writer(file, &options, schema)?;
 
         let writer = Arc::new(writer);
         let morsels_per_sink = morsels_per_sink();
@@ -78,6 +98,7 @@ impl ParquetSink {
         let io_thread_handle = Arc::new(Some(init_row_group_writer_thread(
             receiver,
             writer.clone(),
+            commit,
             morsels_per_sink,
         )));
 
@@ -96,10 +117,15 @@ impl Sink for ParquetSink {
             .writer
             .encode_and_compress(&chunk.data)
             .collect::<PolarsResult<Vec<_>>>()?;
-        // Only then send the compressed pages to the writer.
-        self.sender
+        // Only then send the compressed pages to the writer. The io thread only stops early on
+        // an error, which is returned by `finalize`.
+        if self
+            .sender
             .send(Some((chunk.chunk_index, row_groups)))
-            .unwrap();
+            .is_err()
+        {
+            return Ok(SinkResult::Finished);
+        }
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -113,7 +139,7 @@ impl Sink for ParquetSink {
 
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         // `None` indicates that we can flush all remaining chunks.
-        self.sender.send(None).unwrap();
+        let _ = self.sender.send(None);
 
         // wait until all files written
         // some unwrap/mut kung-fu to get a hold of `self`
@@ -122,7 +148,7 @@ impl Sink for ParquetSink {
             .take()
             .unwrap()
             .join()
-            .unwrap();
+            .unwrap()?;
 
         // return a dummy dataframe;
         Ok(FinalizedSink::Finished(Default::default()))
@@ -146,21 +172,17 @@ impl ParquetCloudSink {
     pub async fn new(
         uri: &str,
         cloud_options: Option<&polars_io::cloud::CloudOptions>,
-        parquet_options: ParquetWriteOptions,
+        parquet_options: ParquetSinkOptions,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
-        let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
-        let writer = ParquetWriter::new(cloud_writer)
-            .with_compression(parquet_options.compression)
-            .with_data_page_size(parquet_options.data_pagesize_limit)
-            .with_statistics(parquet_options.statistics)
-            .with_row_group_size(parquet_options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+        let mut cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
+        // An object only becomes visible once its upload is completed, whatever the commit
+        // strategy, and completing it here reports its errors.
+        let commit = cloud_writer.deferred_commit();
+        let writer = batched_parquet_writer(cloud_writer, &parquet_options, schema)?;
+        let writer = Box::new(CommittingWriter::new(Box::new(writer), move || {
+            commit.commit()
+        }));
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
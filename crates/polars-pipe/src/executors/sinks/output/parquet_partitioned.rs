@@ -0,0 +1,690 @@
+use std::any::Any;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::JoinHandle;
+
+use crossbeam_channel::{bounded, Receiver, Sender};
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::parquet::write::{
+    write_metadata_file, BatchedWriter, BloomFilters, ParquetSinkOptions, RowGroupIter,
+    ThriftFileMetaData,
+};
+use polars_io::parquet::{DatasetStatistics, FileFingerprint, STATISTICS_FILE_NAME};
+use polars_io::utils::{hive_partition_dir, is_cloud_url, FileCommit};
+use polars_io::{CommitStrategy, ExistingDataBehavior, PartitionedWriteOptions};
+
+use super::parquet::batched_parquet_writer;
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::morsels_per_sink;
+
+type RowGroups = Vec<(RowGroupIter<'static, PolarsError>, BloomFilters)>;
+
+/// The number of rows of the row groups, if the options don't set it.
+const DEFAULT_ROW_GROUP_SIZE: usize = 128 * 1024;
+/// The maximum number of rows that are buffered over all partitions, in row groups.
+const MAX_BUFFERED_ROW_GROUPS: usize = 8;
+
+/// The rows of a part of a chunk that belong to a single partition.
+enum PartitionPart {
+    /// Fewer rows than a row group, which the io thread buffers until they fill one.
+    Rows(DataFrame),
+    /// Enough rows to fill row groups by themselves, which are encoded on the compute threads,
+    /// with their number of rows and the statistics of the row groups, if they are written.
+    RowGroups(usize, RowGroups, Vec<DataFrame>),
+}
+
+/// The parts of a chunk, with the directories of their partitions, relative to the root
+/// directory.
+type PartitionParts = Vec<(PathBuf, PartitionPart)>;
+
+/// Encode `df` as a row group per chunk, with the statistics of the row groups if `statistics`
+/// is set.
+fn encode_row_groups(
+    encoder: &BatchedWriter<std::io::Sink>,
+    df: &DataFrame,
+    statistics: bool,
+) -> PolarsResult<(RowGroups, Vec<DataFrame>)> {
+    let row_groups = encoder
+        .encode_and_compress(df)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let statistics = if statistics {
+        df.clone()
+            .split_chunks()
+            .filter(|chunk| chunk.height() > 0)
+            .map(|chunk| DatasetStatistics::row_group_statistics(&chunk))
+            .collect::<PolarsResult<Vec<_>>>()?
+    } else {
+        vec![]
+    };
+    Ok((row_groups, statistics))
+}
+
+/// The file that is written of a partition.
+struct PartitionFile {
+    writer: BatchedWriter<File>,
+    /// The path relative to the root directory.
+    path: PathBuf,
+    num_rows: usize,
+    /// The statistics of the row groups that are written, for the `_statistics` file.
+    statistics: Vec<DataFrame>,
+}
+
+/// A path relative to the root directory, with `/` as separator.
+fn file_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The index after the largest index of the `part-{i}.parquet` files in `dir`.
+fn next_file_index(dir: &Path) -> PolarsResult<usize> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let mut next = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name();
+        let index = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("part-")?.strip_suffix(".parquet"))
+            .and_then(|index| index.parse::<usize>().ok());
+        if let Some(index) = index {
+            next = next.max(index + 1);
+        }
+    }
+    Ok(next)
+}
+
+/// A hidden directory next to the root directory, to which the files are written if they are
+/// committed atomically. The files are only moved to the root directory once all of them are
+/// written, so that a query that fails leaves the existing data as it is, and scans of the
+/// dataset don't see the files that are being written.
+///
+/// Every partition directory is moved with a single rename, but the partitions are moved one
+/// after the other: a commit that fails halfway leaves some partitions replaced.
+struct StagingDir {
+    dir: PathBuf,
+}
+
+impl StagingDir {
+    fn new(root: &Path) -> PolarsResult<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        // The directory is on the same file system as the root, so that its files can be
+        // renamed into it.
+        let root = std::fs::canonicalize(root)?;
+        let (Some(parent), Some(name)) = (root.parent(), root.file_name()) else {
+            polars_bail!(
+                InvalidOperation: "the root directory '{}' of an atomic partitioned sink needs \
+                a parent directory", root.display()
+            )
+        };
+        let dir = parent.join(format!(
+            ".{}.polars-staging-{}-{}",
+            name.to_string_lossy(),
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Move the files of the partition directories `dirs` and the `_metadata` and `_statistics`
+    /// files to `root`.
+    fn commit(
+        self,
+        root: &Path,
+        dirs: &[PathBuf],
+        behavior: ExistingDataBehavior,
+    ) -> PolarsResult<()> {
+        for dir in dirs {
+            let staged = self.dir.join(dir);
+            let target = root.join(dir);
+            if behavior == ExistingDataBehavior::DeleteMatching {
+                // Replace the whole partition directory.
+                if target.exists() {
+                    std::fs::remove_dir_all(&target)?;
+                }
+                if let Some(parent) = target.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::rename(&staged, &target)?;
+            } else {
+                std::fs::create_dir_all(&target)?;
+                for entry in std::fs::read_dir(&staged)? {
+                    let entry = entry?;
+                    std::fs::rename(entry.path(), target.join(entry.file_name()))?;
+                }
+            }
+        }
+        for name in ["_metadata", STATISTICS_FILE_NAME] {
+            let file = self.dir.join(name);
+            if file.exists() {
+                std::fs::rename(&file, root.join(name))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// The state of a partition on the io thread.
+#[derive(Default)]
+struct Partition {
+    /// The file that is written, if one is open.
+    file: Option<PartitionFile>,
+    /// The index of the next file of the partition.
+    next_index: usize,
+    /// The rows that aren't written yet, as they don't fill a row group.
+    buffer: Vec<DataFrame>,
+    buffered_rows: usize,
+    /// The time of the last write to the file, to finish the least recently used file.
+    last_write: usize,
+}
+
+/// Writes the row groups of the partitions to their files, on the io thread.
+struct PartitionWriters {
+    root: PathBuf,
+    staging: Option<StagingDir>,
+    options: ParquetSinkOptions,
+    partitioned_options: PartitionedWriteOptions,
+    /// The schema of the files, without the partition columns.
+    schema: Schema,
+    /// Encodes the buffered rows.
+    encoder: Arc<BatchedWriter<std::io::Sink>>,
+    row_group_size: usize,
+    partitions: PlHashMap<PathBuf, Partition>,
+    open_files: usize,
+    buffered_rows: usize,
+    /// The number of writes, which orders the writes to the files.
+    writes: usize,
+    /// The paths and the metadata of the files that are finished, for the `_metadata` file.
+    finished: Vec<(String, ThriftFileMetaData)>,
+    /// The statistics of the files that are finished, for the `_statistics` file.
+    statistics: DatasetStatistics,
+}
+
+impl PartitionWriters {
+    /// The directory the files are written to.
+    fn out_dir(&self) -> &Path {
+        self.staging
+            .as_ref()
+            .map_or(&self.root, |staging| &staging.dir)
+    }
+
+    fn finish_file(&mut self, file: PartitionFile) -> PolarsResult<()> {
+        file.writer.finish()?;
+        self.open_files -= 1;
+        let path = file_path(&file.path);
+        if self.partitioned_options.write_statistics_file {
+            // Moving the staged file keeps its modification time.
+            let fingerprint = FileFingerprint::of(&self.out_dir().join(&file.path))?;
+            self.statistics
+                .push_file(&path, fingerprint, &file.statistics)?;
+        }
+        if self.partitioned_options.write_metadata_file {
+            self.finished.push((path, file.writer.metadata().unwrap()));
+        }
+        Ok(())
+    }
+
+    /// Prepare the directory of a partition the first time it is written to.
+    fn init_partition(&mut self, dir: &Path) -> PolarsResult<()> {
+        if self.partitions.contains_key(dir) {
+            return Ok(());
+        }
+        let abs_dir = self.root.join(dir);
+        let first_index = match self.partitioned_options.existing_data_behavior {
+            // The directory is replaced when the staged files are committed.
+            ExistingDataBehavior::DeleteMatching if self.staging.is_some() => 0,
+            ExistingDataBehavior::DeleteMatching => {
+                if abs_dir.exists() {
+                    std::fs::remove_dir_all(&abs_dir)?;
+                }
+                0
+            },
+            ExistingDataBehavior::Append => next_file_index(&abs_dir)?,
+            _ => 0,
+        };
+        if self.partitioned_options.existing_data_behavior == ExistingDataBehavior::DeleteMatching {
+            // The statistics of the files of the partition that is replaced are dropped.
+            let prefix = format!("{}/", file_path(dir));
+            self.statistics
+                .remove_files(|path| path.starts_with(&prefix))?;
+        }
+        std::fs::create_dir_all(self.out_dir().join(dir))?;
+        let partition = Partition {
+            next_index: first_index,
+            ..Default::default()
+        };
+        self.partitions.insert(dir.to_path_buf(), partition);
+        Ok(())
+    }
+
+    /// Open the next file of a partition, after finishing the least recently used file if
+    /// the maximum number of files are open.
+    fn open_file(&mut self, dir: &Path) -> PolarsResult<()> {
+        if self.open_files >= self.partitioned_options.max_open_files {
+            let least_recently_used = self
+                .partitions
+                .iter()
+                .filter(|(_, partition)| partition.file.is_some())
+                .min_by_key(|(_, partition)| partition.last_write)
+                .map(|(dir, _)| dir.clone());
+            if let Some(lru_dir) = least_recently_used {
+                self.flush(&lru_dir)?;
+                if let Some(file) = self.partitions.get_mut(&lru_dir).unwrap().file.take() {
+                    self.finish_file(file)?;
+                }
+            }
+        }
+        let next_index = self.partitions[dir].next_index;
+        let path = dir.join(format!("part-{next_index}.parquet"));
+        let out = File::create(self.out_dir().join(&path))?;
+        let writer = batched_parquet_writer(out, &self.options, &self.schema)?;
+        let partition = self.partitions.get_mut(dir).unwrap();
+        partition.file = Some(PartitionFile {
+            writer,
+            path,
+            num_rows: 0,
+            statistics: vec![],
+        });
+        partition.next_index += 1;
+        self.open_files += 1;
+        Ok(())
+    }
+
+    /// Write row groups to the file of a partition, starting a new file if it would have too
+    /// many rows.
+    fn write_to_file(
+        &mut self,
+        dir: &Path,
+        num_rows: usize,
+        row_groups: RowGroups,
+        statistics: Vec<DataFrame>,
+    ) -> PolarsResult<()> {
+        let max_rows_per_file = self.partitioned_options.max_rows_per_file;
+        let partition = self.partitions.get_mut(dir).unwrap();
+        let full = match (&partition.file, max_rows_per_file) {
+            (Some(file), Some(max_rows)) => {
+                file.num_rows > 0 && file.num_rows + num_rows > max_rows
+            },
+            _ => false,
+        };
+        if full {
+            let previous = partition.file.take().unwrap();
+            self.finish_file(previous)?;
+        }
+        if self.partitions[dir].file.is_none() {
+            self.open_file(dir)?;
+        }
+
+        self.writes += 1;
+        let partition = self.partitions.get_mut(dir).unwrap();
+        partition.last_write = self.writes;
+        let file = partition.file.as_mut().unwrap();
+        file.writer.write_row_groups(row_groups)?;
+        file.num_rows += num_rows;
+        file.statistics.extend(statistics);
+        Ok(())
+    }
+
+    /// Write the buffered rows of a partition as a row group.
+    fn flush(&mut self, dir: &Path) -> PolarsResult<()> {
+        let partition = self.partitions.get_mut(dir).unwrap();
+        if partition.buffer.is_empty() {
+            return Ok(());
+        }
+        let buffer = std::mem::take(&mut partition.buffer);
+        self.buffered_rows -= std::mem::take(&mut partition.buffered_rows);
+
+        let mut df = accumulate_dataframes_vertical_unchecked(buffer);
+        df.as_single_chunk();
+        let max_rows = self
+            .partitioned_options
+            .max_rows_per_file
+            .unwrap_or(usize::MAX);
+        let mut offset = 0;
+        while offset < df.height() {
+            let slice = df.slice(offset as i64, max_rows);
+            offset += slice.height();
+            let write_statistics = self.partitioned_options.write_statistics_file;
+            let (row_groups, statistics) =
+                encode_row_groups(&self.encoder, &slice, write_statistics)?;
+            self.write_to_file(dir, slice.height(), row_groups, statistics)?;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, dir: PathBuf, part: PartitionPart) -> PolarsResult<()> {
+        self.init_partition(&dir)?;
+        match part {
+            PartitionPart::Rows(df) => {
+                let partition = self.partitions.get_mut(&dir).unwrap();
+                partition.buffered_rows += df.height();
+                self.buffered_rows += df.height();
+                partition.buffer.push(df);
+                if partition.buffered_rows >= self.row_group_size {
+                    self.flush(&dir)?;
+                }
+                // Bound the memory of the buffers of many partitions.
+                while self.buffered_rows > MAX_BUFFERED_ROW_GROUPS * self.row_group_size {
+                    let largest = self
+                        .partitions
+                        .iter()
+                        .max_by_key(|(_, partition)| partition.buffered_rows)
+                        .map(|(dir, _)| dir.clone())
+                        .unwrap();
+                    self.flush(&largest)?;
+                }
+                Ok(())
+            },
+            PartitionPart::RowGroups(num_rows, row_groups, statistics) => {
+                // The buffered rows come first.
+                self.flush(&dir)?;
+                self.write_to_file(&dir, num_rows, row_groups, statistics)
+            },
+        }
+    }
+
+    fn finish(mut self) -> PolarsResult<()> {
+        let dirs = self.partitions.keys().cloned().collect::<Vec<_>>();
+        for dir in &dirs {
+            self.flush(dir)?;
+        }
+        let files = std::mem::take(&mut self.partitions)
+            .into_values()
+            .filter_map(|partition| partition.file)
+            .collect::<Vec<_>>();
+        for file in files {
+            self.finish_file(file)?;
+        }
+        if self.partitioned_options.write_metadata_file && !self.finished.is_empty() {
+            // Sort the files, so that the row groups are in a deterministic order.
+            self.finished.sort_by(|a, b| a.0.cmp(&b.0));
+            let path = self.out_dir().join("_metadata");
+            let (mut file, commit) = FileCommit::create(&path, CommitStrategy::Atomic)?;
+            write_metadata_file(&mut file, std::mem::take(&mut self.finished))?;
+            commit.commit()?;
+        }
+        if self.partitioned_options.write_statistics_file && !self.statistics.is_empty() {
+            // The file is replaced at once, so that scans never read a partially written one.
+            let path = self.out_dir().join(STATISTICS_FILE_NAME);
+            let (file, commit) = FileCommit::create(&path, CommitStrategy::Atomic)?;
+            self.statistics.write(file)?;
+            commit.commit()?;
+        }
+        if let Some(staging) = self.staging.take() {
+            let behavior = self.partitioned_options.existing_data_behavior;
+            staging.commit(&self.root, &dirs, behavior)?;
+        }
+        Ok(())
+    }
+}
+
+fn init_partition_writer_thread(
+    receiver: Receiver<Option<(IdxSize, PartitionParts)>>,
+    mut writers: PartitionWriters,
+    morsels_per_sink: usize,
+) -> JoinHandle<PolarsResult<()>> {
+    std::thread::spawn(move || {
+        // keep chunks around until all chunks per sink are received, so that the rows of
+        // every partition are written in order.
+        let mut batched = Vec::with_capacity(morsels_per_sink);
+        while let Ok(chunk) = receiver.recv() {
+            let last_write = if let Some(chunk) = chunk {
+                batched.push(chunk);
+                false
+            } else {
+                true
+            };
+
+            if batched.len() == morsels_per_sink || last_write {
+                batched.sort_by_key(|chunk| chunk.0);
+
+                for (_, parts) in batched.drain(0..) {
+                    for (dir, part) in parts {
+                        writers.write(dir, part)?;
+                    }
+                }
+            }
+            if last_write {
+                return writers.finish();
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Check that the root directory can be written to, according to the existing data behavior.
+fn prepare_root(root: &Path, behavior: ExistingDataBehavior) -> PolarsResult<()> {
+    if behavior == ExistingDataBehavior::Error && root.exists() {
+        polars_ensure!(
+            std::fs::read_dir(root)?.next().is_none(),
+            ComputeError: "the directory '{}' of the partitioned sink isn't empty; set the existing \
+            data behavior to overwrite or delete the existing data",
+            root.display()
+        );
+    }
+    std::fs::create_dir_all(root)?;
+    Ok(())
+}
+
+/// Writes parquet files to Hive-style partition directories, `key=value/...`.
+///
+/// The parts of the chunks that fill row groups are encoded on the compute threads. The
+/// smaller parts are buffered per partition on the io thread until they fill a row group, so
+/// that many partitions don't lead to tiny row groups.
+#[derive(Clone)]
+pub struct PartitionedParquetSink {
+    /// Encodes the row groups of the files. It doesn't write a file itself.
+    encoder: Arc<BatchedWriter<std::io::Sink>>,
+    partition_by: Arc<Vec<String>>,
+    max_rows_per_file: Option<usize>,
+    row_group_size: usize,
+    /// Compute the statistics of the row groups for the `_statistics` file.
+    statistics: bool,
+    io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
+    sender: Sender<Option<(IdxSize, PartitionParts)>>,
+}
+
+impl PartitionedParquetSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        root: &Path,
+        options: ParquetSinkOptions,
+        partitioned_options: PartitionedWriteOptions,
+        schema: &Schema,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            !is_cloud_url(root),
+            InvalidOperation: "a partitioned sink can only write to a local directory"
+        );
+        let partition_by = partitioned_options.partition_by.clone();
+        polars_ensure!(
+            !partition_by.is_empty(),
+            InvalidOperation: "a partitioned sink needs at least one column to partition by"
+        );
+        let mut file_schema = schema.clone();
+        for name in &partition_by {
+            polars_ensure!(
+                file_schema.shift_remove(name).is_some(),
+                ColumnNotFound: "the partition column '{}' isn't a column", name
+            );
+        }
+        polars_ensure!(
+            !file_schema.is_empty(),
+            InvalidOperation: "a partitioned sink needs at least one column that isn't a \
+            partition column"
+        );
+        polars_ensure!(
+            partitioned_options.max_rows_per_file != Some(0),
+            InvalidOperation: "the maximum number of rows per file must be positive"
+        );
+        polars_ensure!(
+            !partitioned_options.write_metadata_file
+                || partitioned_options.existing_data_behavior == ExistingDataBehavior::Error,
+            InvalidOperation: "a `_metadata` file can only be written to an empty directory, as \
+            it would only contain the metadata of the new files"
+        );
+        prepare_root(root, partitioned_options.existing_data_behavior)?;
+        let staging = match options.write_options.commit_strategy {
+            CommitStrategy::Direct => None,
+            CommitStrategy::Atomic => Some(StagingDir::new(root)?),
+        };
+
+        // The statistics of the existing files that aren't replaced are kept. If they can't be
+        // read, the existing files have none, and scans read all of them.
+        let existing_statistics = root.join(STATISTICS_FILE_NAME);
+        let statistics = if partitioned_options.write_statistics_file
+            && partitioned_options.existing_data_behavior != ExistingDataBehavior::Error
+        {
+            File::open(existing_statistics)
+                .ok()
+                .and_then(|file| DatasetStatistics::read(file).ok())
+                .unwrap_or_default()
+        } else {
+            DatasetStatistics::default()
+        };
+
+        polars_ensure!(
+            partitioned_options.max_open_files > 0,
+            InvalidOperation: "the maximum number of open files must be positive"
+        );
+        let encoder = Arc::new(batched_parquet_writer(
+            std::io::sink(),
+            &options,
+            &file_schema,
+        )?);
+        let max_rows_per_file = partitioned_options.max_rows_per_file;
+        let row_group_size = options
+            .write_options
+            .row_group_size
+            .unwrap_or(DEFAULT_ROW_GROUP_SIZE)
+            .max(1);
+        let write_statistics = partitioned_options.write_statistics_file;
+        let writers = PartitionWriters {
+            root: root.to_path_buf(),
+            staging,
+            options,
+            partitioned_options,
+            schema: file_schema,
+            encoder: encoder.clone(),
+            row_group_size,
+            partitions: PlHashMap::new(),
+            open_files: 0,
+            buffered_rows: 0,
+            writes: 0,
+            finished: vec![],
+            statistics,
+        };
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 4;
+        let (sender, receiver) = bounded(backpressure);
+        let io_thread_handle = Arc::new(Some(init_partition_writer_thread(
+            receiver,
+            writers,
+            morsels_per_sink,
+        )));
+
+        Ok(Self {
+            encoder,
+            partition_by: Arc::new(partition_by),
+            max_rows_per_file,
+            row_group_size,
+            statistics: write_statistics,
+            io_thread_handle,
+            sender,
+        })
+    }
+
+    /// Split `df` into its partitions, and encode the row groups of the partitions that fill
+    /// them.
+    fn encode_partitions(&self, df: &DataFrame) -> PolarsResult<PartitionParts> {
+        let mut out = vec![];
+        for part in df.partition_by(self.partition_by.as_slice(), true)? {
+            let values = self
+                .partition_by
+                .iter()
+                .map(|name| part.column(name)?.get(0))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let dir = hive_partition_dir(self.partition_by.as_slice(), &values);
+            let part = part.drop_many(self.partition_by.as_slice());
+            if part.height() < self.row_group_size {
+                out.push((dir, PartitionPart::Rows(part)));
+                continue;
+            }
+
+            let max_rows = self.max_rows_per_file.unwrap_or(usize::MAX);
+            let mut offset = 0;
+            while offset < part.height() {
+                let slice = part.slice(offset as i64, max_rows.min(self.row_group_size));
+                offset += slice.height();
+                let (row_groups, statistics) =
+                    encode_row_groups(&self.encoder, &slice, self.statistics)?;
+                let part = PartitionPart::RowGroups(slice.height(), row_groups, statistics);
+                out.push((dir.clone(), part));
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl Sink for PartitionedParquetSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if chunk.data.height() == 0 {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        // Encode and compress the row-groups of the partitions on every thread.
+        let parts = self.encode_partitions(&chunk.data)?;
+        // The io thread only stops early on an error, which is returned by `finalize`.
+        if self.sender.send(Some((chunk.chunk_index, parts))).is_err() {
+            return Ok(SinkResult::Finished);
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // Nothing to do
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // `None` indicates that we can flush all remaining chunks.
+        let _ = self.sender.send(None);
+
+        // wait until all files are written
+        Arc::get_mut(&mut self.io_thread_handle)
+            .unwrap()
+            .take()
+            .unwrap()
+            .join()
+            .unwrap()?;
+
+        // return a dummy dataframe;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "partitioned_parquet_sink"
+    }
+}
@@ -0,0 +1,429 @@
+#[cfg(feature = "parquet")]
+use std::any::Any;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "parquet")]
+use std::thread::JoinHandle;
+
+use crossbeam_channel::bounded;
+#[cfg(feature = "parquet")]
+use crossbeam_channel::{Receiver, Sender};
+use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::write::{BatchedWriter, BloomFilters, RowGroupIter};
+use polars_io::utils::FileCommit;
+use polars_io::{CommitStrategy, SplitWriteOptions};
+use polars_plan::prelude::FileType;
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::operators::Sink;
+#[cfg(feature = "parquet")]
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, SinkResult};
+use crate::pipeline::morsels_per_sink;
+
+/// The row groups of a batch of parquet data, which are encoded on the compute threads.
+#[cfg(feature = "parquet")]
+type EncodedBatch = Vec<(RowGroupIter<'static, PolarsError>, BloomFilters)>;
+#[cfg(not(feature = "parquet"))]
+type EncodedBatch = std::convert::Infallible;
+
+/// A file that counts the bytes that are written to it.
+struct CountingWriter {
+    file: std::fs::File,
+    bytes: Arc<AtomicUsize>,
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.file.write(buf)?;
+        self.bytes.fetch_add(written, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// The writer of a single file of a split sink.
+enum FileWriter {
+    Batched(Box<dyn SinkWriter + Send>),
+    /// Writes the row groups that are encoded on the compute threads as they are.
+    #[cfg(feature = "parquet")]
+    Parquet(BatchedWriter<CountingWriter>),
+}
+
+type CreateWriter = Box<dyn Fn(CountingWriter) -> PolarsResult<FileWriter> + Send>;
+
+struct SplitFile {
+    writer: FileWriter,
+    commit: FileCommit,
+    bytes: Arc<AtomicUsize>,
+    rows: usize,
+}
+
+impl SplitFile {
+    /// Write `df`, from its `encoded` row groups if these are given.
+    #[cfg_attr(not(feature = "parquet"), allow(unused_variables))]
+    fn write(&mut self, df: &DataFrame, encoded: Option<EncodedBatch>) -> PolarsResult<()> {
+        match &mut self.writer {
+            FileWriter::Batched(writer) => writer._write_batch(df)?,
+            #[cfg(feature = "parquet")]
+            FileWriter::Parquet(writer) => match encoded {
+                Some(row_groups) => writer.write_row_groups(row_groups)?,
+                // The chunks of the columns must be aligned.
+                None => writer.write_batch(&df.agg_chunks())?,
+            },
+        }
+        self.rows += df.height();
+        Ok(())
+    }
+
+    fn finish(mut self) -> PolarsResult<()> {
+        match &mut self.writer {
+            FileWriter::Batched(writer) => writer._finish()?,
+            #[cfg(feature = "parquet")]
+            FileWriter::Parquet(writer) => {
+                writer.finish()?;
+            },
+        }
+        self.commit.commit()
+    }
+}
+
+/// Writes the batches to a file until it is full, after which a new file is started.
+struct SplitFilesWriter {
+    dir: PathBuf,
+    options: SplitWriteOptions,
+    extension: &'static str,
+    commit_strategy: CommitStrategy,
+    create_writer: CreateWriter,
+    file: Option<SplitFile>,
+    num_files: usize,
+}
+
+impl SplitFilesWriter {
+    fn is_full(&self, file: &SplitFile) -> bool {
+        self.options
+            .max_rows_per_file
+            .is_some_and(|max_rows| file.rows >= max_rows)
+            || self
+                .options
+                .max_bytes_per_file
+                .is_some_and(|max_bytes| file.bytes.load(Ordering::Relaxed) >= max_bytes)
+    }
+
+    fn next_file(&mut self) -> PolarsResult<SplitFile> {
+        let name = self.options.file_name(self.num_files, self.extension);
+        self.num_files += 1;
+        let (file, commit) = FileCommit::create(&self.dir.join(name), self.commit_strategy)?;
+        let bytes = Arc::new(AtomicUsize::new(0));
+        let writer = (self.create_writer)(CountingWriter {
+            file,
+            bytes: bytes.clone(),
+        })?;
+        Ok(SplitFile {
+            writer,
+            commit,
+            bytes,
+            rows: 0,
+        })
+    }
+
+    /// Write `df`, starting new files as the files are full. The `encoded` row groups of `df`
+    /// are written as they are if `df` fits in the current file, otherwise the rows are
+    /// encoded again for every file that they are split over.
+    fn write(&mut self, df: &DataFrame, mut encoded: Option<EncodedBatch>) -> PolarsResult<()> {
+        let mut offset = 0;
+        while offset < df.height() {
+            let mut file = match self.file.take() {
+                Some(file) if !self.is_full(&file) => file,
+                Some(file) => {
+                    file.finish()?;
+                    self.next_file()?
+                },
+                None => self.next_file()?,
+            };
+            let len = self
+                .options
+                .max_rows_per_file
+                .map_or(usize::MAX, |max_rows| max_rows - file.rows);
+            let batch = df.slice(offset as i64, len);
+            offset += batch.height();
+            let encoded = encoded.take().filter(|_| batch.height() == df.height());
+            file.write(&batch, encoded)?;
+            self.file = Some(file);
+        }
+        Ok(())
+    }
+
+    /// Remove the files of earlier runs that are named after the template, but whose index
+    /// is past the files that are written now, so that the directory only holds the output
+    /// of this run.
+    fn remove_stale_files(&self) -> PolarsResult<()> {
+        for entry in std::fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let is_stale = entry.file_name().to_str().map_or(false, |name| {
+                self.options
+                    .file_index(name, self.extension)
+                    .map_or(false, |index| index >= self.num_files)
+            });
+            if is_stale && entry.file_type()?.is_file() {
+                std::fs::remove_file(entry.path())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for SplitFilesWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write(df, None)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        // Write a single file without rows if the result is empty, so that its schema is kept.
+        if self.num_files == 0 {
+            self.file = Some(self.next_file()?);
+        }
+        if let Some(file) = self.file.take() {
+            file.finish()?;
+        }
+        self.remove_stale_files()
+    }
+}
+
+#[cfg(feature = "parquet")]
+type EncodedChunk = (IdxSize, DataFrame, EncodedBatch);
+
+#[cfg(feature = "parquet")]
+fn init_split_parquet_writer_thread(
+    receiver: Receiver<Option<EncodedChunk>>,
+    mut writer: SplitFilesWriter,
+    // this is used to determine when a batch of chunks should be written to disk
+    // all chunks per push should be collected to determine in which order they should
+    // be written
+    morsels_per_sink: usize,
+) -> JoinHandle<PolarsResult<()>> {
+    std::thread::spawn(move || {
+        let mut batched = Vec::with_capacity(morsels_per_sink);
+        while let Ok(chunk) = receiver.recv() {
+            // `last_write` indicates if all chunks are processed, e.g. this is the last write.
+            let last_write = if let Some(chunk) = chunk {
+                batched.push(chunk);
+                false
+            } else {
+                true
+            };
+
+            if batched.len() == morsels_per_sink || last_write {
+                batched.sort_by_key(|chunk: &EncodedChunk| chunk.0);
+                for (_, df, row_groups) in batched.drain(..) {
+                    writer.write(&df, Some(row_groups))?;
+                }
+            }
+            if last_write {
+                return writer._finish();
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Splits parquet files like [`SplitFilesSink`], but encodes and compresses the row groups on
+/// the compute threads, like `ParquetSink`. The io thread only writes them, and only encodes
+/// the rows of a chunk again if the chunk is split over multiple files.
+#[cfg(feature = "parquet")]
+#[derive(Clone)]
+struct SplitParquetSink {
+    /// Only used to encode the row groups, its output is discarded.
+    encoder: Arc<BatchedWriter<std::io::Sink>>,
+    sender: Sender<Option<EncodedChunk>>,
+    io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
+}
+
+#[cfg(feature = "parquet")]
+impl Sink for SplitParquetSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if chunk.data.height() == 0 {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let row_groups = self
+            .encoder
+            .encode_and_compress(&chunk.data)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // The io thread only stops early on an error, which is returned by `finalize`.
+        if self
+            .sender
+            .send(Some((chunk.chunk_index, chunk.data, row_groups)))
+            .is_err()
+        {
+            return Ok(SinkResult::Finished);
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // Nothing to do
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // `None` indicates that we can flush all remaining chunks.
+        let _ = self.sender.send(None);
+
+        // wait until all files written
+        Arc::get_mut(&mut self.io_thread_handle)
+            .unwrap()
+            .take()
+            .unwrap()
+            .join()
+            .unwrap()?;
+
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "parquet_sink"
+    }
+}
+
+/// Writes files to a directory, which are split by their number of rows or size.
+///
+/// The files of earlier runs in the directory that are named after the template are replaced,
+/// or removed if this run writes fewer files.
+pub struct SplitFilesSink {}
+impl SplitFilesSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        dir: &Path,
+        file_type: &FileType,
+        options: SplitWriteOptions,
+        schema: &Schema,
+    ) -> PolarsResult<Box<dyn Sink>> {
+        polars_ensure!(
+            options.file_name_template.contains("{i}"),
+            InvalidOperation: "the file name template must contain `{{i}}`, got '{}'",
+            options.file_name_template
+        );
+        polars_ensure!(
+            options.max_rows_per_file != Some(0) && options.max_bytes_per_file != Some(0),
+            InvalidOperation: "the maximum number of rows and bytes per file must be positive"
+        );
+        std::fs::create_dir_all(dir)?;
+
+        let (extension, maintain_order, commit_strategy, create_writer) = match file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(parquet_options) => {
+                let commit_strategy = parquet_options.write_options.commit_strategy;
+                let parquet_options = parquet_options.clone();
+                let schema = schema.clone();
+                let create_writer: CreateWriter = Box::new(move |writer| {
+                    let writer =
+                        super::parquet::batched_parquet_writer(writer, &parquet_options, &schema)?;
+                    Ok(FileWriter::Parquet(writer))
+                });
+                ("parquet", true, commit_strategy, create_writer)
+            },
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(ipc_options) => {
+                let ipc_options = *ipc_options;
+                let schema = schema.clone();
+                let create_writer: CreateWriter = Box::new(move |writer| {
+                    let writer = super::ipc::batched_ipc_writer(writer, ipc_options, &schema)?;
+                    Ok(FileWriter::Batched(writer))
+                });
+                let commit_strategy = ipc_options.commit_strategy;
+                (
+                    "ipc",
+                    ipc_options.maintain_order,
+                    commit_strategy,
+                    create_writer,
+                )
+            },
+            #[cfg(feature = "csv")]
+            FileType::Csv(csv_options) => {
+                let maintain_order = csv_options.maintain_order;
+                let commit_strategy = csv_options.commit_strategy;
+                let csv_options = csv_options.clone();
+                let schema = schema.clone();
+                let create_writer: CreateWriter = Box::new(move |writer| {
+                    let writer =
+                        super::csv::batched_csv_writer(writer, csv_options.clone(), &schema)?;
+                    Ok(FileWriter::Batched(Box::new(writer)))
+                });
+                ("csv", maintain_order, commit_strategy, create_writer)
+            },
+            #[cfg(feature = "json")]
+            FileType::Json(json_options) => {
+                let create_writer: CreateWriter = Box::new(|writer| {
+                    let writer = polars_io::json::BatchedWriter::new(writer);
+                    Ok(FileWriter::Batched(Box::new(writer)))
+                });
+                let commit_strategy = json_options.commit_strategy;
+                (
+                    "ndjson",
+                    json_options.maintain_order,
+                    commit_strategy,
+                    create_writer,
+                )
+            },
+            #[allow(unreachable_patterns)]
+            other_file_type => polars_bail!(
+                InvalidOperation: "split sinking of the file type {other_file_type:?} is not supported"
+            ),
+        };
+
+        let writer = SplitFilesWriter {
+            dir: dir.to_path_buf(),
+            options,
+            extension,
+            commit_strategy,
+            create_writer,
+            file: None,
+            num_files: 0,
+        };
+        let morsels_per_sink = morsels_per_sink();
+
+        #[cfg(feature = "parquet")]
+        if let FileType::Parquet(parquet_options) = file_type {
+            let encoder =
+                super::parquet::batched_parquet_writer(std::io::sink(), parquet_options, schema)?;
+            let backpressure = morsels_per_sink * 4;
+            let (sender, receiver) = bounded(backpressure);
+            let io_thread_handle = Arc::new(Some(init_split_parquet_writer_thread(
+                receiver,
+                writer,
+                morsels_per_sink,
+            )));
+            return Ok(Box::new(SplitParquetSink {
+                encoder: Arc::new(encoder),
+                sender,
+                io_thread_handle,
+            }));
+        }
+
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            Box::new(writer),
+            maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(Box::new(FilesSink {
+            sender,
+            io_thread_handle,
+        }))
+    }
+}
@@ -8,6 +8,7 @@ use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{AnyValue, SchemaRef, Series, SortOptions};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::prelude::SpillOptions;
 
 use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
 use crate::executors::sinks::memory::MemTracker;
@@ -39,6 +40,7 @@ pub struct SortSink {
     current_chunks_size: usize,
     // Start time of OOC phase.
     ooc_start: Option<Instant>,
+    spill_options: SpillOptions,
 }
 
 impl SortSink {
@@ -47,6 +49,7 @@ impl SortSink {
         slice: Option<(i64, usize)>,
         sort_options: SortMultipleOptions,
         schema: SchemaRef,
+        spill_options: SpillOptions,
     ) -> Self {
         // for testing purposes
         let ooc = std::env::var(FORCE_OOC).is_ok();
@@ -65,6 +68,7 @@ impl SortSink {
             current_chunk_rows: 0,
             current_chunks_size: 0,
             ooc_start: None,
+            spill_options,
         };
         if ooc {
             if verbose() {
@@ -85,7 +89,11 @@ impl SortSink {
         // start IO thread
         let mut iot = self.io_thread.write().unwrap();
         if iot.is_none() {
-            *iot = Some(IOThread::try_new(self.schema.clone(), "sort")?)
+            *iot = Some(IOThread::try_new(
+                self.schema.clone(),
+                "sort",
+                self.spill_options,
+            )?)
         }
         Ok(())
     }
@@ -180,6 +188,7 @@ impl Sink for SortSink {
             current_chunk_rows: 0,
             current_chunks_size: 0,
             ooc_start: self.ooc_start,
+            spill_options: self.spill_options,
         })
     }
 
@@ -5,6 +5,7 @@ use polars_core::prelude::sort::_broadcast_descending;
 use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_compat_array;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
+use polars_plan::prelude::SpillOptions;
 use polars_row::decode::decode_rows_from_binary;
 use polars_row::EncodingField;
 
@@ -140,6 +141,7 @@ impl SortSinkMultiple {
         sort_options: SortMultipleOptions,
         output_schema: SchemaRef,
         sort_idx: Vec<usize>,
+        spill_options: SpillOptions,
     ) -> PolarsResult<Self> {
         let can_decode = sort_column_can_be_decoded(&output_schema, &sort_idx);
         let mut schema = (*output_schema).clone();
@@ -176,6 +178,7 @@ impl SortSinkMultiple {
                 .with_nulls_last(false)
                 .with_maintain_order(false),
             Arc::new(schema),
+            spill_options,
         ));
 
         Ok(SortSinkMultiple {
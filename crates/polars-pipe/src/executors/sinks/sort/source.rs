@@ -17,6 +17,7 @@ use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
 pub struct SortSource {
     files: Peekable<std::vec::IntoIter<(u32, PathBuf)>>,
     n_threads: usize,
+    decompression_threads: usize,
     sort_idx: usize,
     descending: bool,
     nulls_last: bool,
@@ -59,6 +60,7 @@ impl SortSource {
         Self {
             files,
             n_threads,
+            decompression_threads: io_thread.decompression_threads(),
             sort_idx,
             descending,
             nulls_last,
@@ -81,6 +83,7 @@ impl SortSource {
             .enumerate()
             .map(|(i, df)| DataChunk {
                 chunk_index: chunk_offset + i as IdxSize,
+                batch_index: 0,
                 data: df,
             })
             .collect()
@@ -203,16 +206,21 @@ impl Source for SortSource {
 
                     let files = std::fs::read_dir(&path)?.collect::<std::io::Result<Vec<_>>>()?;
 
-                    // read the files in a single partition in parallel
+                    // read the files in a single partition in parallel, with at most
+                    // `decompression_threads` files at a time
+                    let chunk_size = files.len().div_ceil(self.decompression_threads).max(1);
                     let dfs = POOL.install(|| {
                         files
-                            .par_iter()
-                            .map(|entry| {
-                                let df = read_df(&entry.path())?;
-                                Ok(df)
+                            .par_chunks(chunk_size)
+                            .map(|files| {
+                                files
+                                    .iter()
+                                    .map(|entry| read_df(&entry.path()))
+                                    .collect::<PolarsResult<Vec<DataFrame>>>()
                             })
-                            .collect::<PolarsResult<Vec<DataFrame>>>()
+                            .collect::<PolarsResult<Vec<_>>>()
                     })?;
+                    let dfs = dfs.into_iter().flatten();
 
                     let df = accumulate_dataframes_vertical_unchecked(dfs);
                     read_size += df.estimated_size();
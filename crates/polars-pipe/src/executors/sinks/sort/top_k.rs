@@ -0,0 +1,119 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::chunked_array::ops::SortMultipleOptions;
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::SchemaRef;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// Keeps the `offset + len` smallest rows of the stream.
+///
+/// Whenever more than twice that amount of rows is buffered, the buffer is reduced to the
+/// `offset + len` smallest rows, so the memory use doesn't grow with the length of the input.
+pub struct TopKSink {
+    schema: SchemaRef,
+    chunks: Vec<DataFrame>,
+    // total rows in `chunks`
+    n_rows: usize,
+    // location in the dataframe of the columns to sort by
+    sort_idx: Arc<[usize]>,
+    offset: usize,
+    len: usize,
+    sort_options: SortMultipleOptions,
+}
+
+impl TopKSink {
+    pub(crate) fn new(
+        sort_idx: Vec<usize>,
+        offset: usize,
+        len: usize,
+        sort_options: SortMultipleOptions,
+        schema: SchemaRef,
+    ) -> Self {
+        Self {
+            schema,
+            chunks: vec![],
+            n_rows: 0,
+            sort_idx: Arc::from(sort_idx),
+            offset,
+            len,
+            sort_options,
+        }
+    }
+
+    fn k(&self) -> usize {
+        self.offset.saturating_add(self.len)
+    }
+
+    /// Take the rows `offset..offset + len` of the sorted buffer.
+    fn take_bottom_k(&mut self, offset: usize, len: usize) -> PolarsResult<DataFrame> {
+        let df = accumulate_dataframes_vertical_unchecked(std::mem::take(&mut self.chunks));
+        self.n_rows = 0;
+        let by_column = self
+            .sort_idx
+            .iter()
+            .map(|i| df.get_columns()[*i].clone())
+            .collect();
+        df.bottom_k_slice_impl(offset, len, by_column, self.sort_options.clone())
+    }
+
+    fn prune(&mut self) -> PolarsResult<()> {
+        let df = self.take_bottom_k(0, self.k())?;
+        self.n_rows = df.height();
+        self.chunks.push(df);
+        Ok(())
+    }
+}
+
+impl Sink for TopKSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let height = chunk.data.height();
+        if height > 0 {
+            self.n_rows += height;
+            self.chunks.push(chunk.data);
+            if self.n_rows > self.k().saturating_mul(2) {
+                self.prune()?;
+            }
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.n_rows += other.n_rows;
+        self.chunks.append(&mut other.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            schema: self.schema.clone(),
+            chunks: vec![],
+            n_rows: 0,
+            sort_idx: self.sort_idx.clone(),
+            offset: self.offset,
+            len: self.len,
+            sort_options: self.sort_options.clone(),
+        })
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        if self.chunks.is_empty() {
+            return Ok(FinalizedSink::Finished(DataFrame::from(
+                self.schema.as_ref(),
+            )));
+        }
+        let df = self.take_bottom_k(self.offset, self.len)?;
+        Ok(FinalizedSink::Finished(df))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "top_k"
+    }
+}
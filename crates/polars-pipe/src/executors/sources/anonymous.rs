@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::POOL;
+use polars_plan::prelude::{AnonymousScan, AnonymousScanArgs, AnonymousScanBatches};
+use polars_utils::iter::EnumerateIdxTrait;
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+use crate::pipeline::determine_chunk_size;
+
+/// Streams an [`AnonymousScan`] that can be read in batches.
+pub(crate) struct AnonymousSource {
+    function: Arc<dyn AnonymousScan>,
+    args: Option<AnonymousScanArgs>,
+    batches: Option<Box<dyn AnonymousScanBatches>>,
+    n_threads: usize,
+    fmt: &'static str,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        args: AnonymousScanArgs,
+        fmt: &'static str,
+    ) -> Self {
+        Self {
+            function,
+            args: Some(args),
+            batches: None,
+            n_threads: POOL.current_num_threads(),
+            fmt,
+        }
+    }
+
+    // Delay initializing the reader, so that not all files are opened
+    // during construction of the pipeline.
+    fn init_batches(&mut self) -> PolarsResult<&mut Box<dyn AnonymousScanBatches>> {
+        if self.batches.is_none() {
+            let args = self.args.take().unwrap();
+            let n_cols = args.output_schema.as_ref().unwrap_or(&args.schema).len();
+            let chunk_size = determine_chunk_size(n_cols, self.n_threads)?;
+            self.batches = Some(self.function.batched(args, chunk_size)?);
+        }
+        Ok(self.batches.as_mut().unwrap())
+    }
+}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let n_threads = self.n_threads;
+        let batches = self.init_batches()?.next_batches(n_threads)?;
+        Ok(match batches {
+            None => SourceResult::Finished,
+            Some(batches) => {
+                let index = get_source_index(0);
+                let out = batches
+                    .into_iter()
+                    .enumerate_u32()
+                    .map(|(i, data)| DataChunk {
+                        chunk_index: (index + i) as IdxSize,
+                        batch_index: 0,
+                        data,
+                    })
+                    .collect::<Vec<_>>();
+                get_source_index(out.len() as u32);
+                SourceResult::GotMoreData(out)
+            },
+        })
+    }
+
+    fn fmt(&self) -> &str {
+        self.fmt
+    }
+}
@@ -6,6 +6,7 @@ use polars_core::POOL;
 use polars_io::csv::read::{
     BatchedCsvReaderMmap, BatchedCsvReaderRead, CsvEncoding, CsvReader, CsvReaderOptions,
 };
+use polars_io::utils::max_concurrent_decoders;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::prelude::FileScanOptions;
 use polars_utils::iter::EnumerateIdxTrait;
@@ -59,6 +60,9 @@ impl CsvSource {
         if self.verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
         }
+        // Bound the number of chunks that are decoded at the same time.
+        self.n_threads =
+            max_concurrent_decoders(options.low_memory, options.n_threads, self.n_threads);
 
         let reader = CsvReader::from_path(&path)
             .unwrap()
@@ -173,6 +177,7 @@ impl Source for CsvSource {
                     .enumerate_u32()
                     .map(|(i, data)| DataChunk {
                         chunk_index: (index + i) as IdxSize,
+                        batch_index: 0,
                         data,
                     })
                     .collect::<Vec<_>>();
@@ -30,6 +30,7 @@ impl Source for DataFrameSource {
         let chunks = (&mut self.dfs)
             .map(|(chunk_index, data)| DataChunk {
                 chunk_index: (chunk_index as u32 + idx_offset) as IdxSize,
+                batch_index: 0,
                 data,
             })
             .take(self.n_threads)
@@ -10,13 +10,15 @@ use polars_core::prelude::Series;
 use polars_core::POOL;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
-use polars_io::parquet::read::{BatchedParquetReader, ParquetOptions, ParquetReader};
+use polars_io::parquet::read::{
+    BatchedParquetReader, FileDecryptionProperties, NestedProjection, ParquetOptions, ParquetReader,
+};
 use polars_io::pl_async::get_runtime;
 use polars_io::predicates::PhysicalIoExpr;
 use polars_io::prelude::materialize_projection;
 #[cfg(feature = "async")]
 use polars_io::prelude::ParquetAsyncReader;
-use polars_io::utils::{check_projected_arrow_schema, is_cloud_url};
+use polars_io::utils::{check_projected_arrow_schema, is_cloud_url, max_concurrent_decoders};
 use polars_io::SerReader;
 use polars_plan::logical_plan::FileInfo;
 use polars_plan::prelude::FileScanOptions;
@@ -43,6 +45,8 @@ pub struct ParquetSource {
     run_async: bool,
     prefetch_size: usize,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    nested_projection: NestedProjection,
+    decryption: Option<Arc<FileDecryptionProperties>>,
 }
 
 impl ParquetSource {
@@ -134,7 +138,11 @@ impl ParquetSource {
                 .with_row_index(file_options.row_index)
                 .with_predicate(predicate.clone())
                 .with_projection(projection)
+                .with_nested_projection(self.nested_projection.clone())
+                .with_decryption(self.decryption.clone())
                 .use_statistics(options.use_statistics)
+                .set_low_memory(options.low_memory)
+                .set_max_decoders(options.max_decoders)
                 .with_hive_partition_columns(hive_partitions)
                 .batched(chunk_size)?
         };
@@ -149,9 +157,10 @@ impl ParquetSource {
                 .with_columns
                 .as_ref()
                 .map(|v| v.as_slice());
+            let reader_schema = self.file_info.reader_schema.as_ref().unwrap();
             check_projected_arrow_schema(
                 batched_reader.schema().as_ref(),
-                self.file_info.reader_schema.as_ref().unwrap(),
+                &self.nested_projection.project_schema(reader_schema),
                 with_columns,
                 "schema of all files in a single scan_parquet must be equal",
             )?;
@@ -173,11 +182,15 @@ impl ParquetSource {
             let uri = path.to_string_lossy();
             ParquetAsyncReader::from_uri(&uri, cloud_options.as_ref(), reader_schema, metadata)
                 .await?
+                .with_decryption(self.decryption.clone())
                 .with_n_rows(file_options.n_rows)
                 .with_row_index(file_options.row_index)
                 .with_projection(projection)
                 .with_predicate(predicate.clone())
+                .with_nested_projection(self.nested_projection.clone())
                 .use_statistics(options.use_statistics)
+                .set_low_memory(options.low_memory)
+                .set_max_decoders(options.max_decoders)
                 .with_hive_partition_columns(hive_partitions)
                 .batched(chunk_size)
                 .await?
@@ -196,12 +209,19 @@ impl ParquetSource {
         file_info: FileInfo,
         verbose: bool,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        nested_projection: NestedProjection,
+        decryption: Option<Arc<FileDecryptionProperties>>,
     ) -> PolarsResult<Self> {
         let n_threads = POOL.current_num_threads();
 
         let iter = 0..paths.len();
 
-        let prefetch_size = get_file_prefetch_size();
+        // In low memory mode we only open a single file at a time.
+        let prefetch_size = if options.low_memory {
+            1
+        } else {
+            get_file_prefetch_size()
+        };
         if verbose {
             eprintln!("POLARS PREFETCH_SIZE: {}", prefetch_size)
         }
@@ -222,6 +242,8 @@ impl ParquetSource {
             run_async,
             prefetch_size,
             predicate,
+            nested_projection,
+            decryption,
         };
         // Already start downloading when we deal with cloud urls.
         if run_async {
@@ -282,8 +304,13 @@ impl Source for ParquetSource {
             return Ok(SourceResult::Finished);
         };
 
-        let batches =
-            get_runtime().block_on_potential_spawn(reader.next_batches(self.n_threads))?;
+        // Bound the number of row groups that are decoded at the same time.
+        let n_batches = max_concurrent_decoders(
+            self.options.low_memory,
+            self.options.max_decoders,
+            self.n_threads,
+        );
+        let batches = get_runtime().block_on_potential_spawn(reader.next_batches(n_batches))?;
 
         Ok(match batches {
             None => {
@@ -308,6 +335,7 @@ impl Source for ParquetSource {
 
                         DataChunk {
                             chunk_index: (idx_offset + i) as IdxSize,
+                            batch_index: 0,
                             data,
                         }
                     })
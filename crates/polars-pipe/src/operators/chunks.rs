@@ -5,6 +5,10 @@ use super::*;
 #[derive(Clone, Debug)]
 pub struct DataChunk {
     pub chunk_index: IdxSize,
+    /// The position of the chunk among the chunks that the sources of its pipeline produced.
+    /// Unlike the `chunk_index`, it doesn't depend on the other queries that run at the same
+    /// time, so it is the same in every run of the query.
+    pub batch_index: u64,
     pub data: DataFrame,
 }
 
@@ -17,10 +21,17 @@ impl DataChunk {
                 assert_eq!(c.chunks().len(), 1);
             }
         }
-        Self { chunk_index, data }
+        Self {
+            chunk_index,
+            batch_index: 0,
+            data,
+        }
     }
     pub(crate) fn with_data(&self, data: DataFrame) -> Self {
-        Self::new(self.chunk_index, data)
+        Self {
+            batch_index: self.batch_index,
+            ..Self::new(self.chunk_index, data)
+        }
     }
     pub(crate) fn is_empty(&self) -> bool {
         self.data.height() == 0
@@ -117,6 +117,8 @@ where
                     options: parquet_options,
                     cloud_options,
                     metadata,
+                    nested_projection,
+                    decryption,
                 } => {
                     let predicate = predicate
                         .as_ref()
@@ -149,9 +151,27 @@ where
                         file_info,
                         verbose,
                         predicate,
+                        nested_projection,
+                        decryption.map(|d| d.0),
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                FileScan::Anonymous { options, function } => {
+                    let args = AnonymousScanArgs {
+                        n_rows: file_options.n_rows,
+                        with_columns: file_options.with_columns,
+                        schema: file_info.schema,
+                        output_schema,
+                        // The predicate is applied by the filter operator, sources may only
+                        // use it to skip data.
+                        predicate: predicate
+                            .filter(|_| push_predicate && function.allows_predicate_pushdown())
+                            .map(|predicate| predicate.to_expr(expr_arena)),
+                    };
+                    let src = sources::AnonymousSource::new(function, args, options.fmt_str);
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
         },
@@ -164,6 +184,7 @@ pub fn get_sink<F>(
     lp_arena: &Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     to_physical: &F,
+    spill_options: SpillOptions,
     callbacks: &mut CallBacks,
 ) -> PolarsResult<Box<dyn SinkTrait>>
 where
@@ -185,7 +206,7 @@ where
                     match &file_type {
                         #[cfg(feature = "parquet")]
                         FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(ParquetSink::new(path, options.clone(), input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
                         #[cfg(feature = "ipc")]
@@ -207,6 +228,49 @@ where
                         _ => unreachable!(),
                     }
                 },
+                #[allow(unused_variables)]
+                SinkType::Partitioned {
+                    path,
+                    file_type,
+                    options,
+                } => match &file_type {
+                    #[cfg(feature = "parquet")]
+                    FileType::Parquet(parquet_options) => Box::new(PartitionedParquetSink::new(
+                        path.as_ref().as_path(),
+                        parquet_options.clone(),
+                        options.clone(),
+                        input_schema.as_ref(),
+                    )?) as Box<dyn SinkTrait>,
+                    #[allow(unreachable_patterns)]
+                    other_file_type => polars_bail!(
+                        InvalidOperation: "partitioned sinking of the file type {other_file_type:?} is not supported"
+                    ),
+                },
+                #[cfg(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                ))]
+                SinkType::Split {
+                    path,
+                    file_type,
+                    options,
+                } => SplitFilesSink::new(
+                    path.as_ref().as_path(),
+                    file_type,
+                    options.clone(),
+                    input_schema.as_ref(),
+                )?,
+                #[cfg(not(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                )))]
+                SinkType::Split { .. } => {
+                    polars_bail!(InvalidOperation: "split sinking needs a file type feature")
+                },
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud {
                     #[cfg(any(feature = "parquet", feature = "ipc"))]
@@ -221,7 +285,7 @@ where
                         FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *parquet_options,
+                            parquet_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -352,7 +416,13 @@ where
                     .unwrap();
                 let index = input_schema.try_index_of(by_column.as_ref())?;
 
-                let sort_sink = SortSink::new(index, *slice, sort_options.clone(), input_schema);
+                let sort_sink = SortSink::new(
+                    index,
+                    *slice,
+                    sort_options.clone(),
+                    input_schema,
+                    spill_options,
+                );
                 Box::new(sort_sink) as Box<dyn SinkTrait>
             } else {
                 let sort_idx = by_column
@@ -365,11 +435,102 @@ where
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
-                let sort_sink =
-                    SortSinkMultiple::new(*slice, sort_options.clone(), input_schema, sort_idx)?;
+                let sort_sink = SortSinkMultiple::new(
+                    *slice,
+                    sort_options.clone(),
+                    input_schema,
+                    sort_idx,
+                    spill_options,
+                )?;
                 Box::new(sort_sink) as Box<dyn SinkTrait>
             }
         },
+        TopK {
+            input,
+            by_column,
+            offset,
+            len,
+            sort_options,
+        } => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            let sort_idx = by_column
+                .iter()
+                .map(|e| {
+                    let name = aexpr_to_leaf_names_iter(e.node(), expr_arena)
+                        .next()
+                        .unwrap();
+                    input_schema.try_index_of(name.as_ref())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            let top_k_sink =
+                TopKSink::new(sort_idx, *offset, *len, sort_options.clone(), input_schema);
+            Box::new(top_k_sink) as Box<dyn SinkTrait>
+        },
+        Distinct { input, options } if distinct_by_sorting(options) => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            let keys: Arc<[String]> = match &options.subset {
+                Some(subset) => subset.iter().cloned().collect(),
+                None => input_schema
+                    .iter_names()
+                    .map(|name| name.to_string())
+                    .collect(),
+            };
+            let mut sort_schema = (*input_schema).clone();
+            sort_schema.with_column(DISTINCT_ROW_COLUMN.into(), DataType::UInt64);
+            let sort_schema = Arc::new(sort_schema);
+
+            // Sort by the keys, then by the column that decides which row is kept, if any,
+            // and then by the position of the rows, so that ties keep the first row.
+            let mut sort_idx = keys
+                .iter()
+                .map(|key| sort_schema.try_index_of(key))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut descending = vec![false; sort_idx.len()];
+            let keep = match options.keep_strategy {
+                UniqueKeepStrategy::First | UniqueKeepStrategy::Any => KeepRow::First,
+                UniqueKeepStrategy::Last => KeepRow::Last,
+                UniqueKeepStrategy::None => KeepRow::Only,
+                keep @ (UniqueKeepStrategy::MaxBy | UniqueKeepStrategy::MinBy) => {
+                    let by = options.keep_by.as_deref().ok_or_else(|| {
+                        polars_err!(
+                            InvalidOperation: "keep strategy {:?} requires a column to compare",
+                            keep
+                        )
+                    })?;
+                    let by_idx = sort_schema.try_index_of(by)?;
+                    if !sort_idx.contains(&by_idx) {
+                        sort_idx.push(by_idx);
+                        descending.push(keep == UniqueKeepStrategy::MaxBy);
+                    }
+                    KeepRow::First
+                },
+            };
+            sort_idx.push(sort_schema.len() - 1);
+            descending.push(false);
+
+            // Null values of the column that decides which row is kept are sorted last, so
+            // that they are only kept if there is no other value.
+            let sort_options = SortMultipleOptions::default()
+                .with_order_descendings(descending)
+                .with_nulls_last(true);
+            let sort_sink = SortSinkMultiple::new(
+                None,
+                sort_options,
+                sort_schema.clone(),
+                sort_idx,
+                spill_options,
+            )?;
+            Box::new(DistinctBySortSink::new(
+                Box::new(sort_sink),
+                keys,
+                keep,
+                options.maintain_order,
+                sort_schema,
+                input_schema,
+                spill_options,
+            )) as Box<dyn SinkTrait>
+        },
         Distinct { input, options } => {
             // We create a Groupby.agg_first()/agg_last (depending on the keep strategy
             let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
@@ -420,7 +581,9 @@ where
                                     UniqueKeepStrategy::Last => {
                                         expr_arena.add(AExpr::Agg(AAggExpr::Last(col)))
                                     },
-                                    UniqueKeepStrategy::None => {
+                                    UniqueKeepStrategy::None
+                                    | UniqueKeepStrategy::MaxBy
+                                    | UniqueKeepStrategy::MinBy => {
                                         unreachable!()
                                     },
                                 };
@@ -452,16 +615,36 @@ where
             }
             let aggregation_columns = Arc::new(aggregation_columns);
 
-            let group_by_sink = Box::new(GenericGroupby2::new(
+            let mut group_by_sink = GenericGroupby2::new(
                 key_columns,
                 aggregation_columns,
                 Arc::from(agg_fns),
                 output_schema,
                 input_agg_dtypes,
                 options.slice,
-            ));
+            )
+            .with_spill_options(spill_options);
+            // Any row of a group will do, so the first groups that are found are kept.
+            if let (UniqueKeepStrategy::Any, Some((0, len))) =
+                (&options.keep_strategy, options.slice)
+            {
+                group_by_sink = group_by_sink.with_finish_after(len);
+            }
 
-            Box::new(ReProjectSink::new(input_schema, group_by_sink))
+            let distinct_keys: Arc<[String]> = match &options.subset {
+                Some(subset) => subset.iter().cloned().collect(),
+                None => input_schema
+                    .iter_names()
+                    .map(|name| name.to_string())
+                    .collect(),
+            };
+            let keep_last = matches!(options.keep_strategy, UniqueKeepStrategy::Last);
+            let sink = ReProjectSink::new(input_schema, Box::new(group_by_sink));
+            Box::new(SortedDistinctSink::new(
+                Box::new(sink),
+                distinct_keys,
+                keep_last,
+            ))
         },
         GroupBy {
             input,
@@ -493,14 +676,17 @@ where
             let aggregation_columns = Arc::new(aggregation_columns);
 
             if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
-                Box::new(GenericGroupby2::new(
-                    key_columns,
-                    aggregation_columns,
-                    Arc::from(agg_fns),
-                    output_schema.clone(),
-                    input_agg_dtypes,
-                    options.slice,
-                ))
+                Box::new(
+                    GenericGroupby2::new(
+                        key_columns,
+                        aggregation_columns,
+                        Arc::from(agg_fns),
+                        output_schema.clone(),
+                        input_agg_dtypes,
+                        options.slice,
+                    )
+                    .with_spill_options(spill_options),
+                )
             } else {
                 match (
                     output_schema.get_at_index(0).unwrap().1.to_physical(),
@@ -526,14 +712,17 @@ where
                         output_schema.clone(),
                         options.slice,
                     )) as Box<dyn SinkTrait>,
-                    _ => Box::new(GenericGroupby2::new(
-                        key_columns,
-                        aggregation_columns,
-                        Arc::from(agg_fns),
-                        output_schema.clone(),
-                        input_agg_dtypes,
-                        options.slice,
-                    )),
+                    _ => Box::new(
+                        GenericGroupby2::new(
+                            key_columns,
+                            aggregation_columns,
+                            Arc::from(agg_fns),
+                            output_schema.clone(),
+                            input_agg_dtypes,
+                            options.slice,
+                        )
+                        .with_spill_options(spill_options),
+                    ),
                 }
             }
         },
@@ -645,6 +834,14 @@ where
             let op = operators::FilterOperator { predicate };
             Box::new(op) as Box<dyn Operator>
         },
+        MapFunction {
+            function: FunctionNode::Melt { args, .. },
+            input,
+        } => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let op = operators::MeltOperator::new(args, &input_schema)?;
+            Box::new(op) as Box<dyn Operator>
+        },
         MapFunction { function, .. } => {
             let op = operators::FunctionOperator::new(function.clone());
             Box::new(op) as Box<dyn Operator>
@@ -670,6 +867,7 @@ pub fn create_pipeline<F>(
     expr_arena: &mut Arena<AExpr>,
     to_physical: F,
     verbose: bool,
+    spill_options: SpillOptions,
     // Shared sinks are stored in a cache, so that they share state.
     // If the shared sink is already in cache, that one is used.
     sink_cache: &mut PlHashMap<usize, Box<dyn SinkTrait>>,
@@ -737,11 +935,25 @@ where
             // ensure that shared sinks are really shared
             // to achieve this we store/fetch them in a cache
             let sink = if *shared_count.borrow() == 1 {
-                get_sink(node, lp_arena, expr_arena, &to_physical, callbacks)?
+                get_sink(
+                    node,
+                    lp_arena,
+                    expr_arena,
+                    &to_physical,
+                    spill_options,
+                    callbacks,
+                )?
             } else {
                 match sink_cache.entry(node.0) {
                     Entry::Vacant(entry) => {
-                        let sink = get_sink(node, lp_arena, expr_arena, &to_physical, callbacks)?;
+                        let sink = get_sink(
+                            node,
+                            lp_arena,
+                            expr_arena,
+                            &to_physical,
+                            spill_options,
+                            callbacks,
+                        )?;
                         entry.insert(sink.split(0));
                         sink
                     },
@@ -766,6 +978,16 @@ where
     ))
 }
 
+/// Whether the distinct rows are found by sorting the rows instead of with a group-by: if
+/// the position of the rows decides which rows are kept, or in which order.
+pub fn distinct_by_sorting(options: &DistinctOptions) -> bool {
+    options.maintain_order
+        || matches!(
+            options.keep_strategy,
+            UniqueKeepStrategy::None | UniqueKeepStrategy::MaxBy | UniqueKeepStrategy::MinBy
+        )
+}
+
 pub fn swap_join_order(options: &JoinOptions) -> bool {
     matches!(options.args.how, JoinType::Left)
         || match (options.rows_left, options.rows_right) {
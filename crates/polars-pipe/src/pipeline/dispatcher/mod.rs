@@ -181,15 +181,22 @@ impl PipeLine {
         // however if the sink is finished early, (for instance a `head`)
         // we don't want to run the rest of the pipelines and we finalize early
         let mut sink_finished = false;
+        // The chunks are numbered in the order that the sources produce them, which is the
+        // same in every run of the query.
+        let mut batch_index = 0;
 
         for (i, mut sink) in std::mem::take(&mut self.sinks).into_iter().enumerate() {
             for src in &mut std::mem::take(&mut self.sources) {
                 let mut next_batches = src.get_batches(ec)?;
 
                 let must_flush: AtomicBool = AtomicBool::new(false);
-                while let SourceResult::GotMoreData(chunks) = next_batches {
+                while let SourceResult::GotMoreData(mut chunks) = next_batches {
                     // Every batches iteration we check if we must continue.
                     ec.execution_state.should_stop()?;
+                    for chunk in &mut chunks {
+                        chunk.batch_index = batch_index;
+                        batch_index += 1;
+                    }
 
                     let (sink_result, next_batches2) = par_process_chunks(
                         chunks,
@@ -3,7 +3,8 @@ mod convert;
 mod dispatcher;
 
 pub use convert::{
-    create_pipeline, get_dummy_operator, get_operator, get_sink, swap_join_order, CallBacks,
+    create_pipeline, distinct_by_sorting, get_dummy_operator, get_operator, get_sink,
+    swap_join_order, CallBacks,
 };
 pub use dispatcher::{execute_pipeline, PipeLine};
 use polars_core::prelude::*;
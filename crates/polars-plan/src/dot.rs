@@ -400,6 +400,8 @@ impl DslPlan {
                     fmt: match payload {
                         SinkType::Memory => "SINK (MEMORY)",
                         SinkType::File { .. } => "SINK (FILE)",
+                        SinkType::Partitioned { .. } => "SINK (PARTITIONED)",
+                        SinkType::Split { .. } => "SINK (SPLIT)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (CLOUD)",
                     },
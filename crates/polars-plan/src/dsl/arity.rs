@@ -137,6 +137,70 @@ pub fn when<E: Into<Expr>>(condition: E) -> When {
     }
 }
 
+/// Utility struct for the `case` expression.
+///
+/// Represents the state of the expression after [case] or `then` is called.
+#[derive(Clone, Default)]
+pub struct Case {
+    conditions: Vec<Expr>,
+    statements: Vec<Expr>,
+}
+
+/// Utility struct for the `case` expression.
+///
+/// Represents the state of the expression after `when` is called.
+///
+/// In this state, `then` must be called to continue to finish the expression.
+#[derive(Clone)]
+pub struct CaseWhen {
+    case: Case,
+}
+
+impl Case {
+    /// Add a condition to the `case` expression.
+    pub fn when<E: Into<Expr>>(mut self, condition: E) -> CaseWhen {
+        self.conditions.push(condition.into());
+        CaseWhen { case: self }
+    }
+
+    /// Define a default for the `case` expression.
+    ///
+    /// Unlike a chain of `when-then-otherwise` expressions, all branches are evaluated in a
+    /// single pass that selects the statement of the first true condition for every row.
+    pub fn otherwise<E: Into<Expr>>(self, statement: E) -> Expr {
+        let otherwise = statement.into();
+        if self.conditions.is_empty() {
+            return otherwise;
+        }
+        // The statements come first, so that the output takes the name of the first one.
+        let mut input = self.statements;
+        input.push(otherwise);
+        input.extend(self.conditions);
+        Expr::Function {
+            input,
+            function: FunctionExpr::CaseWhen,
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ElementWise,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl CaseWhen {
+    /// Attach a statement to the corresponding condition.
+    pub fn then<E: Into<Expr>>(mut self, statement: E) -> Case {
+        self.case.statements.push(statement.into());
+        self.case
+    }
+}
+
+/// Start a `case` expression with many branches, e.g.
+/// `case().when(c1).then(e1).when(c2).then(e2).otherwise(e3)`.
+pub fn case() -> Case {
+    Case::default()
+}
+
 pub fn ternary_expr(predicate: Expr, truthy: Expr, falsy: Expr) -> Expr {
     Expr::Ternary {
         predicate: Arc::new(predicate),
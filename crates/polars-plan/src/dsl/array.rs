@@ -183,6 +183,66 @@ impl ArrayNameSpace {
             .with_fmt("arr.to_struct")
     }
 
+    #[cfg(feature = "array_linalg")]
+    /// Compute the dot product of every sub-array with the sub-array of `other` in the same row.
+    ///
+    /// A single sub-array in `other` is used for all rows.
+    pub fn dot(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Dot),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Multiply the matrix produced by `matrix` with every sub-array as a column vector.
+    ///
+    /// `matrix` should be a nested array whose inner width is the width of the sub-arrays.
+    /// A single matrix is used for all rows.
+    pub fn matmul(self, matrix: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::MatMul),
+            &[matrix],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn reduce_axis(self, agg: ArrayAxisAgg, axis: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ReduceAxis {
+                agg,
+                axis,
+            }))
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the sum along `axis` of the matrix in every nested sub-array.
+    pub fn sum_axis(self, axis: u8) -> Expr {
+        self.reduce_axis(ArrayAxisAgg::Sum, axis)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the mean along `axis` of the matrix in every nested sub-array.
+    pub fn mean_axis(self, axis: u8) -> Expr {
+        self.reduce_axis(ArrayAxisAgg::Mean, axis)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the minimum along `axis` of the matrix in every nested sub-array.
+    pub fn min_axis(self, axis: u8) -> Expr {
+        self.reduce_axis(ArrayAxisAgg::Min, axis)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the maximum along `axis` of the matrix in every nested sub-array.
+    pub fn max_axis(self, axis: u8) -> Expr {
+        self.reduce_axis(ArrayAxisAgg::Max, axis)
+    }
+
     /// Shift every sub-array.
     pub fn shift(self, n: Expr) -> Expr {
         self.0.map_many_private(
@@ -27,6 +27,9 @@ impl DateLikeNameSpace {
 
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// Durations are formatted as ISO 8601 durations with `"iso"`, e.g. `P1DT2H3M`, or as
+    /// they are printed with `"polars"`, e.g. `1d 2h 3m`.
     pub fn to_string(self, format: &str) -> Expr {
         let format = format.to_string();
         self.0
@@ -90,6 +90,8 @@ pub enum Expr {
         expr: Arc<Expr>,
         idx: Arc<Expr>,
         returns_scalar: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        out_of_bounds: OutOfBoundsPolicy,
     },
     SortBy {
         expr: Arc<Expr>,
@@ -224,10 +226,12 @@ impl Hash for Expr {
                 expr,
                 idx,
                 returns_scalar,
+                out_of_bounds,
             } => {
                 expr.hash(state);
                 idx.hash(state);
                 returns_scalar.hash(state);
+                out_of_bounds.hash(state);
             },
             // already hashed by discriminant
             Expr::Wildcard | Expr::Len => {},
@@ -30,6 +30,15 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    #[cfg(feature = "array_linalg")]
+    Dot,
+    #[cfg(feature = "array_linalg")]
+    MatMul,
+    #[cfg(feature = "array_linalg")]
+    ReduceAxis {
+        agg: ArrayAxisAgg,
+        axis: u8,
+    },
 }
 
 impl ArrayFunction {
@@ -56,6 +65,14 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            #[cfg(feature = "array_linalg")]
+            Dot => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "array_linalg")]
+            MatMul => mapper.try_map_dtypes(|dtypes| matmul_output_dtype(dtypes[1])),
+            #[cfg(feature = "array_linalg")]
+            ReduceAxis { axis, .. } => {
+                mapper.try_map_dtype(|dtype| reduce_axis_output_dtype(dtype, *axis))
+            },
         }
     }
 }
@@ -96,6 +113,17 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            #[cfg(feature = "array_linalg")]
+            Dot => "dot",
+            #[cfg(feature = "array_linalg")]
+            MatMul => "matmul",
+            #[cfg(feature = "array_linalg")]
+            ReduceAxis { agg, .. } => match agg {
+                ArrayAxisAgg::Sum => "sum_axis",
+                ArrayAxisAgg::Mean => "mean_axis",
+                ArrayAxisAgg::Min => "min_axis",
+                ArrayAxisAgg::Max => "max_axis",
+            },
         };
         write!(f, "arr.{name}")
     }
@@ -129,6 +157,12 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            #[cfg(feature = "array_linalg")]
+            Dot => map_as_slice!(dot),
+            #[cfg(feature = "array_linalg")]
+            MatMul => map_as_slice!(matmul),
+            #[cfg(feature = "array_linalg")]
+            ReduceAxis { agg, axis } => map!(reduce_axis, agg, axis),
         }
     }
 }
@@ -243,3 +277,22 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
 
     ca.array_shift(n)
 }
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn dot(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let other = s[1].array()?;
+    Ok(ca.array_dot(other)?.into_series())
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn matmul(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let matrix = s[1].array()?;
+    Ok(ca.array_matmul(matrix)?.into_series())
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn reduce_axis(s: &Series, agg: ArrayAxisAgg, axis: u8) -> PolarsResult<Series> {
+    Ok(s.array()?.array_reduce_axis(agg, axis)?.into_series())
+}
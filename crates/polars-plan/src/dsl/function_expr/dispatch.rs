@@ -28,6 +28,16 @@ pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
     Ok(s.to_physical_repr().into_owned())
 }
 
+/// `column_metadata` is replaced by a literal when the projections are expanded, so it is only
+/// evaluated in contexts that aren't expanded, such as `list.eval`.
+pub(super) fn column_metadata(s: &Series, key: &str) -> PolarsResult<Series> {
+    polars_bail!(
+        InvalidOperation:
+        "cannot read the metadata key '{}' of '{}' in this context; `column_metadata` can only be \
+        used on the columns of a projection", key, s.name()
+    )
+}
+
 pub(super) fn set_sorted_flag(s: &Series, sorted: IsSorted) -> PolarsResult<Series> {
     let mut s = s.clone();
     s.set_sorted_flag(sorted);
@@ -61,12 +71,26 @@ pub(super) fn reshape(s: &Series, dimensions: Vec<i64>) -> PolarsResult<Series>
     s.reshape(&dimensions)
 }
 
+#[cfg(feature = "repeat_by")]
+fn repeat_counts(by: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        by.dtype().is_integer() || by.dtype() == &DataType::Null,
+        InvalidOperation: "`repeat_by` expects integer counts, got {}", by.dtype()
+    );
+    // Negative counts fail the strict cast.
+    by.strict_cast(&IDX_DTYPE)
+}
+
 #[cfg(feature = "repeat_by")]
 pub(super) fn repeat_by(s: &[Series]) -> PolarsResult<Series> {
-    let by = &s[1];
-    let s = &s[0];
-    let by = by.cast(&IDX_DTYPE)?;
-    polars_ops::chunked_array::repeat_by(s, by.idx()?).map(|ok| ok.into_series())
+    let by = repeat_counts(&s[1])?;
+    polars_ops::chunked_array::repeat_by(&s[0], by.idx()?).map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "repeat_by")]
+pub(super) fn repeat_by_exploded(s: &[Series]) -> PolarsResult<Series> {
+    let by = repeat_counts(&s[1])?;
+    polars_ops::chunked_array::repeat_by_exploded(&s[0], by.idx()?)
 }
 
 pub(super) fn backward_fill(s: &Series, limit: FillNullLimit) -> PolarsResult<Series> {
@@ -117,11 +141,38 @@ pub(super) fn arg_unique(s: &Series) -> PolarsResult<Series> {
     s.arg_unique().map(|ok| ok.into_series())
 }
 
+#[cfg(feature = "dtype-struct")]
+pub(super) fn multi_output(s: &Series, kernel: MultiOutputKernel) -> PolarsResult<Series> {
+    polars_ops::series::multi_output(s, kernel)
+}
+
+pub(super) fn min_by(s: &[Series], options: MinMaxByOptions) -> PolarsResult<Series> {
+    polars_ops::series::min_by(&s[0], &s[1], options)
+}
+
+pub(super) fn max_by(s: &[Series], options: MinMaxByOptions) -> PolarsResult<Series> {
+    polars_ops::series::max_by(&s[0], &s[1], options)
+}
+
 #[cfg(feature = "rank")]
 pub(super) fn rank(s: &Series, options: RankOptions, seed: Option<u64>) -> PolarsResult<Series> {
     Ok(s.rank(options, seed))
 }
 
+#[cfg(feature = "outliers")]
+pub(super) fn winsorize(s: &Series, lower_q: f64, upper_q: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::winsorize(s, lower_q, upper_q)
+}
+
+#[cfg(feature = "outliers")]
+pub(super) fn is_outlier(
+    s: &Series,
+    method: OutlierMethod,
+    threshold: Option<f64>,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::is_outlier(s, method, threshold).map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Series],
@@ -145,10 +196,15 @@ pub(super) fn replace(s: &[Series], return_dtype: Option<DataType>) -> PolarsRes
 }
 
 pub(super) fn fill_null_with_strategy(
-    s: &Series,
+    s: &[Series],
     strategy: FillNullStrategy,
 ) -> PolarsResult<Series> {
-    s.fill_null(strategy)
+    match s.get(1) {
+        Some(fill_value) => {
+            polars_ops::series::fill_null_by_groups(&s[0], None, strategy, Some(fill_value))
+        },
+        None => s[0].fill_null(strategy),
+    }
 }
 
 pub(super) fn gather_every(s: &Series, n: usize, offset: usize) -> PolarsResult<Series> {
@@ -161,6 +217,13 @@ pub(super) fn reinterpret(s: &Series, signed: bool) -> PolarsResult<Series> {
     polars_ops::series::reinterpret(s, signed)
 }
 
+pub(super) fn case_when(s: &[Series]) -> PolarsResult<Series> {
+    // The values come first, followed by `otherwise` and the conditions.
+    let n_branches = s.len() / 2;
+    let (values, conditions) = s.split_at(n_branches + 1);
+    polars_ops::series::case_when(conditions, &values[..n_branches], &values[n_branches])
+}
+
 pub(super) fn negate(s: &Series) -> PolarsResult<Series> {
     polars_ops::series::negate(s)
 }
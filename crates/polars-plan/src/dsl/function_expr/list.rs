@@ -43,6 +43,8 @@ pub enum ListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    #[cfg(feature = "list_rolling")]
+    Rolling(ListRollingOptions),
     Sort(SortOptions),
     Reverse,
     Unique(bool),
@@ -89,6 +91,11 @@ impl ListFunction {
             ArgMax => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "diff")]
             Diff { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "list_rolling")]
+            Rolling(options) => mapper.map_dtype(|dt| match dt {
+                DataType::List(inner) => DataType::List(Box::new(options.output_dtype(inner))),
+                dt => dt.clone(),
+            }),
             Sort(_) => mapper.with_same_dtype(),
             Reverse => mapper.with_same_dtype(),
             Unique(_) => mapper.with_same_dtype(),
@@ -154,6 +161,13 @@ impl Display for ListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            #[cfg(feature = "list_rolling")]
+            Rolling(options) => match options.method {
+                ListRollingMethod::Sum => "rolling_sum",
+                ListRollingMethod::Mean => "rolling_mean",
+                ListRollingMethod::Min => "rolling_min",
+                ListRollingMethod::Max => "rolling_max",
+            },
             Length => "length",
             Sort(_) => "sort",
             Reverse => "reverse",
@@ -222,6 +236,8 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ArgMax => map!(arg_max),
             #[cfg(feature = "diff")]
             Diff { n, null_behavior } => map!(diff, n, null_behavior),
+            #[cfg(feature = "list_rolling")]
+            Rolling(options) => map!(rolling, options),
             Sort(options) => map!(sort, options),
             Reverse => map!(reverse),
             Unique(is_stable) => map!(unique, is_stable),
@@ -555,6 +571,11 @@ pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsRes
     Ok(s.list()?.lst_diff(n, null_behavior)?.into_series())
 }
 
+#[cfg(feature = "list_rolling")]
+pub(super) fn rolling(s: &Series, options: ListRollingOptions) -> PolarsResult<Series> {
+    Ok(s.list()?.lst_rolling(options)?.into_series())
+}
+
 pub(super) fn sort(s: &Series, options: SortOptions) -> PolarsResult<Series> {
     Ok(s.list()?.lst_sort(options)?.into_series())
 }
@@ -34,6 +34,9 @@ mod list;
 #[cfg(feature = "log")]
 mod log;
 mod nan;
+#[cfg(feature = "ols")]
+mod ols;
+mod overflow;
 #[cfg(feature = "peaks")]
 mod peaks;
 #[cfg(feature = "ffi_plugin")]
@@ -62,6 +65,8 @@ mod strings;
 mod struct_;
 #[cfg(any(feature = "temporal", feature = "date_offset"))]
 mod temporal;
+#[cfg(feature = "time_weighted")]
+mod time_weighted;
 #[cfg(feature = "trigonometry")]
 pub mod trigonometry;
 mod unique;
@@ -75,9 +80,11 @@ pub(super) use array::ArrayFunction;
 pub(crate) use correlation::CorrelationMethod;
 #[cfg(feature = "fused")]
 pub(crate) use fused::FusedOperator;
-pub(super) use list::ListFunction;
+pub(crate) use list::ListFunction;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
+pub use random::with_random_batch;
+#[cfg(feature = "random")]
 pub(crate) use random::RandomMethod;
 use schema::FieldsMapper;
 #[cfg(feature = "serde")]
@@ -96,6 +103,8 @@ pub use self::pow::PowFunction;
 pub(super) use self::range::RangeFunction;
 #[cfg(feature = "rolling_window")]
 pub(super) use self::rolling::RollingFunction;
+#[cfg(feature = "string_mask")]
+pub(crate) use self::strings::PseudonymizationKey;
 #[cfg(feature = "strings")]
 pub(crate) use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
@@ -159,6 +168,11 @@ pub enum FunctionExpr {
     ShiftAndFill,
     Shift,
     DropNans,
+    IntegerArithmetic {
+        op: OverflowOperator,
+        overflow: IntegerOverflow,
+    },
+    IntegerSum(IntegerOverflow),
     DropNulls,
     #[cfg(feature = "mode")]
     Mode,
@@ -169,7 +183,14 @@ pub enum FunctionExpr {
     Reshape(Vec<i64>),
     #[cfg(feature = "repeat_by")]
     RepeatBy,
+    /// `repeat_by` followed by `explode`, created by the optimizer.
+    #[cfg(feature = "repeat_by")]
+    RepeatByExploded,
     ArgUnique,
+    #[cfg(feature = "dtype-struct")]
+    MultiOutput(MultiOutputKernel),
+    MinBy(MinMaxByOptions),
+    MaxBy(MinMaxByOptions),
     #[cfg(feature = "rank")]
     Rank {
         options: RankOptions,
@@ -222,6 +243,7 @@ pub enum FunctionExpr {
     ApproxNUnique,
     Coalesce,
     ShrinkType,
+    CaseWhen,
     #[cfg(feature = "diff")]
     Diff(i64, NullBehavior),
     #[cfg(feature = "pct_change")]
@@ -264,6 +286,30 @@ pub enum FunctionExpr {
         method: correlation::CorrelationMethod,
         ddof: u8,
     },
+    #[cfg(feature = "ols")]
+    LeastSquares {
+        fit_intercept: bool,
+    },
+    #[cfg(feature = "ols")]
+    RollingOls {
+        window_size: usize,
+        min_periods: usize,
+        fit_intercept: bool,
+    },
+    #[cfg(feature = "time_weighted")]
+    TimeWeightedMean,
+    #[cfg(feature = "time_weighted")]
+    TrapezoidalIntegral,
+    #[cfg(feature = "outliers")]
+    Winsorize {
+        lower_q: f64,
+        upper_q: f64,
+    },
+    #[cfg(feature = "outliers")]
+    IsOutlier {
+        method: OutlierMethod,
+        threshold: Option<f64>,
+    },
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
@@ -288,6 +334,9 @@ pub enum FunctionExpr {
     #[cfg(feature = "rle")]
     RLEID,
     ToPhysical,
+    /// The value of a key in the metadata of the input column. This is replaced by a literal
+    /// during expression expansion.
+    ColumnMetadata(Arc<str>),
     #[cfg(feature = "random")]
     Random {
         method: random::RandomMethod,
@@ -375,6 +424,18 @@ impl Hash for FunctionExpr {
             Random { method, .. } => method.hash(state),
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "ols")]
+            LeastSquares { fit_intercept } => fit_intercept.hash(state),
+            #[cfg(feature = "ols")]
+            RollingOls {
+                window_size,
+                min_periods,
+                fit_intercept,
+            } => {
+                window_size.hash(state);
+                min_periods.hash(state);
+                fit_intercept.hash(state);
+            },
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
@@ -397,11 +458,19 @@ impl Hash for FunctionExpr {
             },
             MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
             | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
+            MinBy(options) | MaxBy(options) => options.hash(state),
+            #[cfg(feature = "dtype-struct")]
+            MultiOutput(kernel) => kernel.hash(state),
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
+            IntegerArithmetic { op, overflow } => {
+                op.hash(state);
+                overflow.hash(state);
+            },
+            IntegerSum(overflow) => overflow.hash(state),
             NullCount => {},
             #[cfg(feature = "date_offset")]
             DateOffset => {},
@@ -460,6 +529,7 @@ impl Hash for FunctionExpr {
             ApproxNUnique => {},
             Coalesce => {},
             ShrinkType => {},
+            CaseWhen => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
             #[cfg(feature = "log")]
@@ -485,6 +555,18 @@ impl Hash for FunctionExpr {
             UpperBound => {},
             LowerBound => {},
             ConcatExpr(a) => a.hash(state),
+            #[cfg(feature = "time_weighted")]
+            TimeWeightedMean | TrapezoidalIntegral => {},
+            #[cfg(feature = "outliers")]
+            Winsorize { lower_q, upper_q } => {
+                lower_q.to_bits().hash(state);
+                upper_q.to_bits().hash(state);
+            },
+            #[cfg(feature = "outliers")]
+            IsOutlier { method, threshold } => {
+                method.hash(state);
+                threshold.map(f64::to_bits).hash(state);
+            },
             #[cfg(feature = "peaks")]
             PeakMin => {},
             #[cfg(feature = "peaks")]
@@ -507,6 +589,8 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "repeat_by")]
             RepeatBy => {},
+            #[cfg(feature = "repeat_by")]
+            RepeatByExploded => {},
             #[cfg(feature = "cutqcut")]
             QCut {
                 probs,
@@ -527,6 +611,7 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => {},
             ToPhysical => {},
+            ColumnMetadata(key) => key.hash(state),
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
             BackwardFill { limit } | ForwardFill { limit } => limit.hash(state),
             #[cfg(feature = "ewma")]
@@ -611,6 +696,12 @@ impl Display for FunctionExpr {
             RollingExpr(func, ..) => return write!(f, "{func}"),
             ShiftAndFill => "shift_and_fill",
             DropNans => "drop_nans",
+            IntegerArithmetic { op, .. } => match op {
+                OverflowOperator::Add => "add",
+                OverflowOperator::Sub => "sub",
+                OverflowOperator::Mul => "mul",
+            },
+            IntegerSum(_) => "sum",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
             Mode => "mode",
@@ -619,6 +710,10 @@ impl Display for FunctionExpr {
             #[cfg(feature = "moment")]
             Kurtosis(..) => "kurtosis",
             ArgUnique => "arg_unique",
+            #[cfg(feature = "dtype-struct")]
+            MultiOutput(kernel) => kernel.name(),
+            MinBy(_) => "min_by",
+            MaxBy(_) => "max_by",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
             #[cfg(feature = "round_series")]
@@ -662,6 +757,7 @@ impl Display for FunctionExpr {
             ApproxNUnique => "approx_n_unique",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
+            CaseWhen => "case_when",
             #[cfg(feature = "diff")]
             Diff(_, _) => "diff",
             #[cfg(feature = "pct_change")]
@@ -698,6 +794,18 @@ impl Display for FunctionExpr {
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "ols")]
+            LeastSquares { .. } => "least_squares",
+            #[cfg(feature = "ols")]
+            RollingOls { .. } => "rolling_ols",
+            #[cfg(feature = "time_weighted")]
+            TimeWeightedMean => "time_weighted_mean",
+            #[cfg(feature = "time_weighted")]
+            TrapezoidalIntegral => "trapezoidal_integral",
+            #[cfg(feature = "outliers")]
+            Winsorize { .. } => "winsorize",
+            #[cfg(feature = "outliers")]
+            IsOutlier { .. } => "is_outlier",
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
@@ -709,11 +817,14 @@ impl Display for FunctionExpr {
             Reshape(_) => "reshape",
             #[cfg(feature = "repeat_by")]
             RepeatBy => "repeat_by",
+            #[cfg(feature = "repeat_by")]
+            RepeatByExploded => "repeat_by_exploded",
             #[cfg(feature = "rle")]
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
             ToPhysical => "to_physical",
+            ColumnMetadata(_) => "column_metadata",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
             SetSortedFlag(_) => "set_sorted",
@@ -922,6 +1033,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     VarBy(options) => map_as_slice!(rolling::rolling_var_by, options.clone()),
                     Std(options) => map!(rolling::rolling_std, options.clone()),
                     StdBy(options) => map_as_slice!(rolling::rolling_std_by, options.clone()),
+                    CountBy(options) => map_as_slice!(rolling::rolling_count_by, options.clone()),
+                    NUniqueBy(options) => {
+                        map_as_slice!(rolling::rolling_n_unique_by, options.clone())
+                    },
                     #[cfg(feature = "moment")]
                     Skew(window_size, bias) => map!(rolling::rolling_skew, window_size, bias),
                 }
@@ -943,6 +1058,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map_as_slice!(shift_and_fill::shift_and_fill)
             },
             DropNans => map_owned!(nan::drop_nans),
+            IntegerArithmetic { op, overflow } => {
+                map_as_slice!(overflow::integer_arithmetic, op, overflow)
+            },
+            IntegerSum(overflow) => map!(overflow::integer_sum, overflow),
             DropNulls => map!(dispatch::drop_nulls),
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
@@ -955,6 +1074,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "moment")]
             Kurtosis(fisher, bias) => map!(dispatch::kurtosis, fisher, bias),
             ArgUnique => map!(dispatch::arg_unique),
+            #[cfg(feature = "dtype-struct")]
+            MultiOutput(kernel) => map!(dispatch::multi_output, kernel),
+            MinBy(options) => map_as_slice!(dispatch::min_by, options),
+            MaxBy(options) => map_as_slice!(dispatch::max_by, options),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
             #[cfg(feature = "dtype-struct")]
@@ -987,6 +1110,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             ApproxNUnique => map!(dispatch::approx_n_unique),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
+            CaseWhen => map_as_slice!(dispatch::case_when),
             #[cfg(feature = "diff")]
             Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
             #[cfg(feature = "pct_change")]
@@ -1019,12 +1143,30 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             ConcatExpr(rechunk) => map_as_slice!(concat::concat_expr, rechunk),
             #[cfg(feature = "cov")]
             Correlation { method, ddof } => map_as_slice!(correlation::corr, ddof, method),
+            #[cfg(feature = "ols")]
+            LeastSquares { fit_intercept } => map_as_slice!(ols::least_squares, fit_intercept),
+            #[cfg(feature = "ols")]
+            RollingOls {
+                window_size,
+                min_periods,
+                fit_intercept,
+            } => map_as_slice!(ols::rolling_ols, window_size, min_periods, fit_intercept),
+            #[cfg(feature = "time_weighted")]
+            TimeWeightedMean => map_as_slice!(time_weighted::time_weighted_mean),
+            #[cfg(feature = "time_weighted")]
+            TrapezoidalIntegral => map_as_slice!(time_weighted::trapezoidal_integral),
+            #[cfg(feature = "outliers")]
+            Winsorize { lower_q, upper_q } => map!(dispatch::winsorize, lower_q, upper_q),
+            #[cfg(feature = "outliers")]
+            IsOutlier { method, threshold } => map!(dispatch::is_outlier, method, threshold),
             #[cfg(feature = "peaks")]
             PeakMin => map!(peaks::peak_min),
             #[cfg(feature = "peaks")]
             PeakMax => map!(peaks::peak_max),
             #[cfg(feature = "repeat_by")]
             RepeatBy => map_as_slice!(dispatch::repeat_by),
+            #[cfg(feature = "repeat_by")]
+            RepeatByExploded => map_as_slice!(dispatch::repeat_by_exploded),
             Reshape(dims) => map!(dispatch::reshape, dims.clone()),
             #[cfg(feature = "cutqcut")]
             Cut {
@@ -1059,6 +1201,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "rle")]
             RLEID => map!(rle_id),
             ToPhysical => map!(dispatch::to_physical),
+            ColumnMetadata(key) => map!(dispatch::column_metadata, &key),
             #[cfg(feature = "random")]
             Random { method, seed } => {
                 use RandomMethod::*;
@@ -1075,6 +1218,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                         }
                     },
+                    Uniform { low, high } => map!(random::uniform, low, high, seed),
+                    Normal { mean, std_dev } => map!(random::normal, mean, std_dev, seed),
+                    Bernoulli { p } => map!(random::bernoulli, p, seed),
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
@@ -1112,7 +1258,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Replace { return_dtype } => {
                 map_as_slice!(dispatch::replace, return_dtype.clone())
             },
-            FillNullWithStrategy(strategy) => map!(dispatch::fill_null_with_strategy, strategy),
+            FillNullWithStrategy(strategy) => {
+                map_as_slice!(dispatch::fill_null_with_strategy, strategy)
+            },
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
@@ -0,0 +1,14 @@
+use super::*;
+
+pub(super) fn least_squares(s: &[Series], fit_intercept: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::least_squares(&s[0], &s[1..], fit_intercept)
+}
+
+pub(super) fn rolling_ols(
+    s: &[Series],
+    window_size: usize,
+    min_periods: usize,
+    fit_intercept: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::rolling_ols(&s[0], &s[1..], window_size, min_periods, fit_intercept)
+}
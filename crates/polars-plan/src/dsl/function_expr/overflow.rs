@@ -0,0 +1,13 @@
+use super::*;
+
+pub(super) fn integer_arithmetic(
+    s: &[Series],
+    op: OverflowOperator,
+    overflow: IntegerOverflow,
+) -> PolarsResult<Series> {
+    arithmetic_with_overflow(&s[0], &s[1], op, overflow)
+}
+
+pub(super) fn integer_sum(s: &Series, overflow: IntegerOverflow) -> PolarsResult<Series> {
+    sum_with_overflow(s, overflow)
+}
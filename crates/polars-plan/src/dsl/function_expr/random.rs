@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use polars_core::prelude::DataType::Float64;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -15,6 +17,17 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    Uniform {
+        low: f64,
+        high: f64,
+    },
+    Normal {
+        mean: f64,
+        std_dev: f64,
+    },
+    Bernoulli {
+        p: f64,
+    },
 }
 
 impl Hash for RandomMethod {
@@ -72,3 +85,52 @@ pub(super) fn sample_n(
         None => Ok(Series::new_empty(src.name(), src.dtype())),
     }
 }
+
+thread_local! {
+    /// The index of the batch that the random expressions evaluated on this thread draw their
+    /// values for, see [`with_random_batch`].
+    static BATCH_INDEX: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Evaluate `f` with the random expressions that it evaluates on this thread drawing the
+/// values of batch `index`.
+///
+/// The streaming engine evaluates an expression once per batch. A seeded random expression
+/// draws the values of every batch from a generator seeded with both its seed and the index of
+/// the batch, so that the batches don't repeat each other and the result doesn't depend on the
+/// order in which the batches are evaluated. Outside of it every evaluation is of batch `0`,
+/// whose generator is seeded with the seed itself.
+pub fn with_random_batch<R>(index: u64, f: impl FnOnce() -> R) -> R {
+    let previous = BATCH_INDEX.with(|batch| batch.replace(index));
+    let out = f();
+    BATCH_INDEX.with(|batch| batch.set(previous));
+    out
+}
+
+/// The seed of the values of the current batch.
+fn batch_seed(seed: Option<u64>) -> Option<u64> {
+    let batch = BATCH_INDEX.with(Cell::get);
+    seed.map(|seed| seed.wrapping_add(batch.wrapping_mul(0x9E37_79B9_7F4A_7C15)))
+}
+
+pub(super) fn uniform(s: &Series, low: f64, high: f64, seed: Option<u64>) -> PolarsResult<Series> {
+    let seed = batch_seed(seed);
+    Float64Chunked::rand_uniform_seeded(s.name(), s.len(), low, high, seed)
+        .map(|ca| ca.into_series())
+}
+
+pub(super) fn normal(
+    s: &Series,
+    mean: f64,
+    std_dev: f64,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    let seed = batch_seed(seed);
+    Float64Chunked::rand_normal_seeded(s.name(), s.len(), mean, std_dev, seed)
+        .map(|ca| ca.into_series())
+}
+
+pub(super) fn bernoulli(s: &Series, p: f64, seed: Option<u64>) -> PolarsResult<Series> {
+    let seed = batch_seed(seed);
+    BooleanChunked::rand_bernoulli_seeded(s.name(), s.len(), p, seed).map(|ca| ca.into_series())
+}
@@ -19,6 +19,8 @@ pub enum RollingFunction {
     VarBy(RollingOptions),
     Std(RollingOptions),
     StdBy(RollingOptions),
+    CountBy(RollingOptions),
+    NUniqueBy(RollingOptions),
     #[cfg(feature = "moment")]
     Skew(usize, bool),
 }
@@ -42,6 +44,8 @@ impl Display for RollingFunction {
             VarBy(_) => "rolling_var_by",
             Std(_) => "rolling_std",
             StdBy(_) => "rolling_std_by",
+            CountBy(_) => "rolling_count_by",
+            NUniqueBy(_) => "rolling_n_unique_by",
             #[cfg(feature = "moment")]
             Skew(..) => "rolling_skew",
         };
@@ -185,6 +189,22 @@ pub(super) fn rolling_std_by(s: &[Series], options: RollingOptions) -> PolarsRes
     convert(|options| s[0].rolling_std(options), s, "rolling_std")(options)
 }
 
+pub(super) fn rolling_count_by(s: &[Series], options: RollingOptions) -> PolarsResult<Series> {
+    convert(
+        |options| s[0].rolling_count_by(options),
+        s,
+        "rolling_count_by",
+    )(options)
+}
+
+pub(super) fn rolling_n_unique_by(s: &[Series], options: RollingOptions) -> PolarsResult<Series> {
+    convert(
+        |options| s[0].rolling_n_unique_by(options),
+        s,
+        "rolling_n_unique_by",
+    )(options)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn rolling_skew(s: &Series, window_size: usize, bias: bool) -> PolarsResult<Series> {
     s.rolling_skew(window_size, bias)
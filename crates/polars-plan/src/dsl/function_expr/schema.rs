@@ -69,12 +69,20 @@ impl FunctionExpr {
                     },
                     Mean(_) | MeanBy(_) | Quantile(_) | QuantileBy(_) | Var(_) | VarBy(_)
                     | Std(_) | StdBy(_) => mapper.map_to_float_dtype(),
+                    CountBy(_) | NUniqueBy(_) => mapper.with_dtype(IDX_DTYPE),
                     #[cfg(feature = "moment")]
                     Skew(..) => mapper.map_to_float_dtype(),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
             DropNans => mapper.with_same_dtype(),
+            IntegerArithmetic { .. } => mapper.map_to_supertype(),
+            IntegerSum(_) => mapper.map_dtype(|dtype| match dtype {
+                DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
+                    DataType::Int64
+                },
+                dt => dt.clone(),
+            }),
             DropNulls => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
@@ -85,6 +93,11 @@ impl FunctionExpr {
             #[cfg(feature = "moment")]
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
             ArgUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "dtype-struct")]
+            MultiOutput(kernel) => {
+                mapper.map_dtype(|dt| DataType::Struct(kernel.output_fields(dt)))
+            },
+            MinBy(_) | MaxBy(_) => mapper.with_same_dtype(),
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
                 RankMethod::Average => DataType::Float64,
@@ -190,6 +203,11 @@ impl FunctionExpr {
                     }
                 })
             },
+            CaseWhen => {
+                // The values come first, followed by `otherwise` and the conditions.
+                let n_values = fields.len() / 2 + 1;
+                FieldsMapper::new(&fields[..n_values]).map_to_supertype()
+            },
             #[cfg(feature = "log")]
             Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             Unique(_) => mapper.with_same_dtype(),
@@ -201,6 +219,20 @@ impl FunctionExpr {
             ConcatExpr(_) => mapper.map_to_supertype(),
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "ols")]
+            LeastSquares { fit_intercept } | RollingOls { fit_intercept, .. } => {
+                let struct_fields = ols_output_fields(
+                    fields[1..].iter().map(|f| f.name().as_str()),
+                    *fit_intercept,
+                )?;
+                mapper.with_dtype(DataType::Struct(struct_fields))
+            },
+            #[cfg(feature = "time_weighted")]
+            TimeWeightedMean | TrapezoidalIntegral => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "outliers")]
+            Winsorize { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "outliers")]
+            IsOutlier { .. } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "peaks")]
             PeakMin => mapper.with_same_dtype(),
             #[cfg(feature = "peaks")]
@@ -228,6 +260,8 @@ impl FunctionExpr {
             },
             #[cfg(feature = "repeat_by")]
             RepeatBy => mapper.map_dtype(|dt| DataType::List(dt.clone().into())),
+            #[cfg(feature = "repeat_by")]
+            RepeatByExploded => mapper.with_same_dtype(),
             Reshape(dims) => mapper.map_dtype(|dt| {
                 let dtype = dt.inner_dtype().unwrap_or(dt).clone();
                 if dims.len() == 1 {
@@ -267,8 +301,15 @@ impl FunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(DataType::UInt32),
             ToPhysical => mapper.to_physical_type(),
+            ColumnMetadata(_) => mapper.with_dtype(DataType::String),
             #[cfg(feature = "random")]
-            Random { .. } => mapper.with_same_dtype(),
+            Random { method, .. } => match method {
+                RandomMethod::Uniform { .. } | RandomMethod::Normal { .. } => {
+                    mapper.with_dtype(DataType::Float64)
+                },
+                RandomMethod::Bernoulli { .. } => mapper.with_dtype(DataType::Boolean),
+                _ => mapper.with_same_dtype(),
+            },
             SetSortedFlag(_) => mapper.with_same_dtype(),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
@@ -298,7 +339,7 @@ impl FunctionExpr {
             EwmVar { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "replace")]
             Replace { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
-            FillNullWithStrategy(_) => mapper.with_same_dtype(),
+            FillNullWithStrategy(_) => mapper.map_to_supertype(),
             GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => {
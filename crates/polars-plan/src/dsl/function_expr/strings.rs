@@ -19,6 +19,54 @@ use polars_utils::format_smartstring;
 use super::*;
 use crate::{map, map_as_slice};
 
+/// The secret key of [`StringFunction::Pseudonymize`].
+///
+/// The key is never serialized, so that it can't leak through a stored plan: serializing a
+/// plan that pseudonymizes fails.
+#[cfg(feature = "string_mask")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PseudonymizationKey(Arc<[u8]>);
+
+#[cfg(feature = "string_mask")]
+impl PseudonymizationKey {
+    pub fn new(key: &[u8]) -> Self {
+        Self(Arc::from(key))
+    }
+}
+
+#[cfg(feature = "string_mask")]
+impl std::fmt::Debug for PseudonymizationKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PseudonymizationKey(***)")
+    }
+}
+
+#[cfg(all(feature = "string_mask", feature = "serde"))]
+impl Serialize for PseudonymizationKey {
+    fn serialize<S>(&self, _serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(
+            "cannot serialize a plan with 'pseudonymize': its key is not serialized",
+        ))
+    }
+}
+
+#[cfg(all(feature = "string_mask", feature = "serde"))]
+impl<'a> Deserialize<'a> for PseudonymizationKey {
+    fn deserialize<D>(_deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom(
+            "cannot deserialize 'pseudonymize': its key is not serialized",
+        ))
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum StringFunction {
@@ -47,6 +95,12 @@ pub enum StringFunction {
         dtype: DataType,
         pat: String,
     },
+    #[cfg(feature = "extract_pattern")]
+    ExtractPattern {
+        dtype: DataType,
+        // The regex that the grok pattern is expanded to.
+        regex: String,
+    },
     #[cfg(feature = "regex")]
     Find {
         literal: bool,
@@ -73,6 +127,27 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_mask")]
+    MaskExceptLast {
+        n: usize,
+        mask_char: char,
+    },
+    #[cfg(feature = "string_mask")]
+    TokenizeFormatPreserving {
+        key: String,
+    },
+    #[cfg(feature = "string_mask")]
+    Pseudonymize {
+        key: PseudonymizationKey,
+    },
+    #[cfg(feature = "string_url")]
+    UrlDecode,
+    #[cfg(feature = "string_url")]
+    UrlEncode,
+    #[cfg(feature = "string_url")]
+    ParseUrl,
+    #[cfg(feature = "string_url")]
+    ParseQueryString,
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -112,6 +187,11 @@ pub enum StringFunction {
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -142,6 +222,8 @@ impl StringFunction {
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "extract_groups")]
             ExtractGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "extract_pattern")]
+            ExtractPattern { dtype, .. } => mapper.with_dtype(dtype.clone()),
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => mapper.with_dtype(DataType::Int64),
             #[cfg(feature = "regex")]
@@ -156,6 +238,18 @@ impl StringFunction {
             Replace { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_mask")]
+            MaskExceptLast { .. } | TokenizeFormatPreserving { .. } | Pseudonymize { .. } => {
+                mapper.with_same_dtype()
+            },
+            #[cfg(feature = "string_url")]
+            UrlDecode | UrlEncode => mapper.with_same_dtype(),
+            #[cfg(feature = "string_url")]
+            ParseUrl => mapper.with_dtype(polars_ops::chunked_array::strings::parse_url_dtype()),
+            #[cfg(feature = "string_url")]
+            ParseQueryString => {
+                mapper.with_dtype(polars_ops::chunked_array::strings::parse_query_string_dtype())
+            },
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
@@ -163,6 +257,8 @@ impl StringFunction {
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, .. } => mapper.with_dtype(DataType::Duration(*time_unit)),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -212,6 +308,8 @@ impl Display for StringFunction {
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
+            #[cfg(feature = "extract_pattern")]
+            ExtractPattern { .. } => "extract_pattern",
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => "to_integer",
             #[cfg(feature = "regex")]
@@ -233,6 +331,20 @@ impl Display for StringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_mask")]
+            MaskExceptLast { .. } => "mask_except_last",
+            #[cfg(feature = "string_mask")]
+            TokenizeFormatPreserving { .. } => "tokenize_format_preserving",
+            #[cfg(feature = "string_mask")]
+            Pseudonymize { .. } => "pseudonymize",
+            #[cfg(feature = "string_url")]
+            UrlDecode => "url_decode",
+            #[cfg(feature = "string_url")]
+            UrlEncode => "url_encode",
+            #[cfg(feature = "string_url")]
+            ParseUrl => "parse_url",
+            #[cfg(feature = "string_url")]
+            ParseQueryString => "parse_query_string",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -271,6 +383,8 @@ impl Display for StringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -302,6 +416,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ExtractGroups { pat, dtype } => {
                 map!(strings::extract_groups, &pat, &dtype)
             },
+            #[cfg(feature = "extract_pattern")]
+            ExtractPattern { regex, dtype } => {
+                map!(strings::extract_pattern, &regex, &dtype)
+            },
             #[cfg(feature = "regex")]
             Find { literal, strict } => map_as_slice!(strings::find, literal, strict),
             LenBytes => map!(strings::len_bytes),
@@ -322,6 +440,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Strptime(dtype, options) => {
                 map_as_slice!(strings::strptime, dtype.clone(), &options)
             },
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, strict } => map!(strings::to_duration, time_unit, strict),
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
@@ -343,6 +463,22 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_mask")]
+            MaskExceptLast { n, mask_char } => map!(strings::mask_except_last, n, mask_char),
+            #[cfg(feature = "string_mask")]
+            TokenizeFormatPreserving { key } => {
+                map!(strings::tokenize_format_preserving, &key)
+            },
+            #[cfg(feature = "string_mask")]
+            Pseudonymize { key } => map!(strings::pseudonymize, &key),
+            #[cfg(feature = "string_url")]
+            UrlDecode => map!(strings::url_decode),
+            #[cfg(feature = "string_url")]
+            UrlEncode => map!(strings::url_encode),
+            #[cfg(feature = "string_url")]
+            ParseUrl => map!(strings::parse_url),
+            #[cfg(feature = "string_url")]
+            ParseQueryString => map!(strings::parse_query_string),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -483,6 +619,12 @@ pub(super) fn extract_groups(s: &Series, pat: &str, dtype: &DataType) -> PolarsR
     ca.extract_groups(pat, dtype)
 }
 
+#[cfg(feature = "extract_pattern")]
+pub(super) fn extract_pattern(s: &Series, regex: &str, dtype: &DataType) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.extract_pattern(regex, dtype)?.into_series())
+}
+
 #[cfg(feature = "string_pad")]
 pub(super) fn pad_start(s: &Series, length: usize, fill_char: char) -> PolarsResult<Series> {
     let ca = s.str()?;
@@ -712,6 +854,17 @@ fn to_time(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     Ok(out.into_series())
 }
 
+#[cfg(feature = "dtype-duration")]
+pub(super) fn to_duration(s: &Series, time_unit: TimeUnit, strict: bool) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let out = ca.as_duration(time_unit).into_series();
+
+    if strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s, &out)?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "concat_str")]
 pub(super) fn concat(s: &Series, delimiter: &str, ignore_nulls: bool) -> PolarsResult<Series> {
     let str_s = s.cast(&DataType::String)?;
@@ -901,6 +1054,48 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(ca.str_reverse().into_series())
 }
 
+#[cfg(feature = "string_mask")]
+pub(super) fn mask_except_last(s: &Series, n: usize, mask_char: char) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_mask_except_last(n, mask_char).into_series())
+}
+
+#[cfg(feature = "string_mask")]
+pub(super) fn tokenize_format_preserving(s: &Series, key: &str) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_tokenize_format_preserving(key).into_series())
+}
+
+#[cfg(feature = "string_mask")]
+pub(super) fn pseudonymize(s: &Series, key: &PseudonymizationKey) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_pseudonymize(&key.0).into_series())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_decode(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_url_decode().into_series())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_encode(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_url_encode().into_series())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn parse_url(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_parse_url()?.into_series())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn parse_query_string(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_parse_query_string()?.into_series())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Series], strict: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
@@ -13,6 +13,8 @@ pub enum StructFunction {
     SuffixFields(Arc<str>),
     #[cfg(feature = "json")]
     JsonEncode,
+    #[cfg(feature = "json")]
+    JsonEncodeWithOptions(JsonEncodeOptions),
 }
 
 impl StructFunction {
@@ -89,7 +91,7 @@ impl StructFunction {
                 _ => polars_bail!(op = "suffix_fields", got = dt, expected = "Struct"),
             }),
             #[cfg(feature = "json")]
-            JsonEncode => mapper.with_dtype(DataType::String),
+            JsonEncode | JsonEncodeWithOptions(_) => mapper.with_dtype(DataType::String),
         }
     }
 }
@@ -104,7 +106,7 @@ impl Display for StructFunction {
             PrefixFields(_) => write!(f, "name.prefix_fields"),
             SuffixFields(_) => write!(f, "name.suffixFields"),
             #[cfg(feature = "json")]
-            JsonEncode => write!(f, "struct.to_json"),
+            JsonEncode | JsonEncodeWithOptions(_) => write!(f, "struct.to_json"),
         }
     }
 }
@@ -119,7 +121,9 @@ impl From<StructFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             PrefixFields(prefix) => map!(struct_::prefix_fields, prefix.clone()),
             SuffixFields(suffix) => map!(struct_::suffix_fields, suffix.clone()),
             #[cfg(feature = "json")]
-            JsonEncode => map!(struct_::to_json),
+            JsonEncode => map!(struct_::to_json, &JsonEncodeOptions::default()),
+            #[cfg(feature = "json")]
+            JsonEncodeWithOptions(options) => map!(struct_::to_json, &options),
         }
     }
 }
@@ -175,14 +179,18 @@ pub(super) fn suffix_fields(s: &Series, suffix: Arc<str>) -> PolarsResult<Series
 }
 
 #[cfg(feature = "json")]
-pub(super) fn to_json(s: &Series) -> PolarsResult<Series> {
+pub(super) fn to_json(s: &Series, options: &JsonEncodeOptions) -> PolarsResult<Series> {
     let ca = s.struct_()?;
     let dtype = ca.dtype().to_arrow(true);
 
-    let iter = ca.chunks().iter().map(|arr| {
-        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype).unwrap();
-        polars_json::json::write::serialize_to_utf8(arr.as_ref())
-    });
+    let chunks = ca
+        .chunks()
+        .iter()
+        .map(|arr| {
+            let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype).unwrap();
+            polars_json::json::write::serialize_to_utf8_with_options(arr.as_ref(), options)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
 
-    Ok(StringChunked::from_chunk_iter(ca.name(), iter).into_series())
+    Ok(StringChunked::from_chunk_iter(ca.name(), chunks).into_series())
 }
@@ -0,0 +1,9 @@
+use super::*;
+
+pub(super) fn time_weighted_mean(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::time_weighted_mean(&s[0], &s[1])
+}
+
+pub(super) fn trapezoidal_integral(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::trapezoidal_integral(&s[0], &s[1])
+}
@@ -12,12 +12,16 @@ mod correlation;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "ols")]
+mod ols;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
 mod selectors;
 mod syntactic_sugar;
 mod temporal;
+#[cfg(feature = "time_weighted")]
+mod time_weighted;
 
 pub use arity::*;
 #[cfg(all(feature = "business", feature = "dtype-date"))]
@@ -30,6 +34,8 @@ pub use correlation::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+#[cfg(feature = "ols")]
+pub use ols::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
 #[cfg(all(feature = "range", feature = "temporal"))]
@@ -42,6 +48,8 @@ pub use repeat::*;
 pub use selectors::*;
 pub use syntactic_sugar::*;
 pub use temporal::*;
+#[cfg(feature = "time_weighted")]
+pub use time_weighted::*;
 
 #[cfg(feature = "arg_where")]
 use crate::dsl::function_expr::FunctionExpr;
@@ -0,0 +1,53 @@
+use super::*;
+
+/// Fit `y` against `xs` with ordinary least squares.
+///
+/// Returns a struct with a coefficient per `x` (named after it), an `intercept`
+/// field if `fit_intercept` is set, and the `r2` of the fit. Rows with missing
+/// values are ignored. The `r2` is null if `y` has no variation to explain. The
+/// fields must have distinct names, so an `x` named `intercept` or `r2` must be
+/// aliased.
+pub fn least_squares(y: Expr, xs: Vec<Expr>, fit_intercept: bool) -> Expr {
+    let mut input = vec![y];
+    input.extend(xs);
+    Expr::Function {
+        input,
+        function: FunctionExpr::LeastSquares { fit_intercept },
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            returns_scalar: true,
+            // The coefficients are named after the `xs`.
+            pass_name_to_apply: true,
+            ..Default::default()
+        },
+    }
+}
+
+/// Fit `y` against `xs` with ordinary least squares over a rolling window of
+/// `window_size` rows.
+///
+/// Returns a struct per row in the layout of [`least_squares`]. The fit is null
+/// if the window holds fewer than `min_periods` complete rows.
+pub fn rolling_ols(
+    y: Expr,
+    xs: Vec<Expr>,
+    window_size: usize,
+    min_periods: usize,
+    fit_intercept: bool,
+) -> Expr {
+    let mut input = vec![y];
+    input.extend(xs);
+    Expr::Function {
+        input,
+        function: FunctionExpr::RollingOls {
+            window_size,
+            min_periods,
+            fit_intercept,
+        },
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            pass_name_to_apply: true,
+            ..Default::default()
+        },
+    }
+}
@@ -35,6 +35,12 @@ pub fn quantile(name: &str, quantile: Expr, interpol: QuantileInterpolOptions) -
     col(name).quantile(quantile, interpol)
 }
 
+/// Get the value of `key` in the metadata of the column named `name`. Shorthand for
+/// `col(name).column_metadata(key)`.
+pub fn col_meta(name: &str, key: &str) -> Expr {
+    col(name).column_metadata(key)
+}
+
 /// Negates a boolean column.
 pub fn not(expr: Expr) -> Expr {
     expr.not()
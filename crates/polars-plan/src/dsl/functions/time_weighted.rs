@@ -0,0 +1,32 @@
+use super::*;
+
+fn time_weighted_function(values: Expr, time: Expr, function: FunctionExpr) -> Expr {
+    Expr::Function {
+        input: vec![values, time],
+        function,
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            returns_scalar: true,
+            ..Default::default()
+        },
+    }
+}
+
+/// The mean of `values` weighted by the time between consecutive `time`s, with the
+/// values linearly interpolated in between.
+///
+/// Temporal `time` columns are measured in seconds. The `time` must be sorted in
+/// ascending order and rows with missing values are ignored. Use it in an aggregation
+/// to compute it per group or per rolling window.
+pub fn time_weighted_mean(values: Expr, time: Expr) -> Expr {
+    time_weighted_function(values, time, FunctionExpr::TimeWeightedMean)
+}
+
+/// The integral of `values` over `time` with the trapezoidal rule.
+///
+/// Temporal `time` columns are measured in seconds. The `time` must be sorted in
+/// ascending order and rows with missing values are ignored. Use it in an aggregation
+/// to compute it per group or per rolling window.
+pub fn trapezoidal_integral(values: Expr, time: Expr) -> Expr {
+    time_weighted_function(values, time, FunctionExpr::TrapezoidalIntegral)
+}
@@ -229,6 +229,42 @@ impl ListNameSpace {
             }))
     }
 
+    #[cfg(feature = "list_rolling")]
+    fn rolling(self, method: ListRollingMethod, window_size: usize, min_periods: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::Rolling(
+                ListRollingOptions {
+                    method,
+                    window_size,
+                    min_periods,
+                },
+            )))
+    }
+
+    /// Apply a rolling sum within every sublist.
+    #[cfg(feature = "list_rolling")]
+    pub fn rolling_sum(self, window_size: usize, min_periods: usize) -> Expr {
+        self.rolling(ListRollingMethod::Sum, window_size, min_periods)
+    }
+
+    /// Apply a rolling mean within every sublist.
+    #[cfg(feature = "list_rolling")]
+    pub fn rolling_mean(self, window_size: usize, min_periods: usize) -> Expr {
+        self.rolling(ListRollingMethod::Mean, window_size, min_periods)
+    }
+
+    /// Apply a rolling min within every sublist.
+    #[cfg(feature = "list_rolling")]
+    pub fn rolling_min(self, window_size: usize, min_periods: usize) -> Expr {
+        self.rolling(ListRollingMethod::Min, window_size, min_periods)
+    }
+
+    /// Apply a rolling max within every sublist.
+    #[cfg(feature = "list_rolling")]
+    pub fn rolling_max(self, window_size: usize, min_periods: usize) -> Expr {
+        self.rolling(ListRollingMethod::Max, window_size, min_periods)
+    }
+
     /// Shift every sublist.
     pub fn shift(self, periods: Expr) -> Expr {
         self.0.map_many_private(
@@ -61,7 +61,7 @@ use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
 use polars_core::utils::try_get_supertype;
-pub(crate) use selector::Selector;
+pub use selector::{DataTypeClass, Selector};
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
@@ -340,6 +340,22 @@ impl Expr {
         )
     }
 
+    /// Get the value of this expression in the row where `by` is minimal.
+    ///
+    /// Nulls in `by` and ties are handled as set in `options`. In a `group_by` or `over`
+    /// context, every group is scanned once instead of being sorted.
+    pub fn min_by<E: Into<Expr>>(self, by: E, options: MinMaxByOptions) -> Self {
+        self.apply_many_private(FunctionExpr::MinBy(options), &[by.into()], true, false)
+    }
+
+    /// Get the value of this expression in the row where `by` is maximal.
+    ///
+    /// Nulls in `by` and ties are handled as set in `options`. In a `group_by` or `over`
+    /// context, every group is scanned once instead of being sorted.
+    pub fn max_by<E: Into<Expr>>(self, by: E, options: MinMaxByOptions) -> Self {
+        self.apply_many_private(FunctionExpr::MaxBy(options), &[by.into()], true, false)
+    }
+
     /// Get the index values that would sort this expression.
     pub fn arg_sort(self, sort_options: SortOptions) -> Self {
         let options = FunctionOptions {
@@ -393,10 +409,21 @@ impl Expr {
 
     /// Take the values by idx.
     pub fn gather<E: Into<Expr>>(self, idx: E) -> Self {
+        self.gather_with_policy(idx, OutOfBoundsPolicy::Error)
+    }
+
+    /// Take the values by idx, applying `out_of_bounds` to the indices that are out of
+    /// bounds. In a group context, the indices are relative to each group.
+    pub fn gather_with_policy<E: Into<Expr>>(
+        self,
+        idx: E,
+        out_of_bounds: OutOfBoundsPolicy,
+    ) -> Self {
         Expr::Gather {
             expr: Arc::new(self),
             idx: Arc::new(idx.into()),
             returns_scalar: false,
+            out_of_bounds,
         }
     }
 
@@ -406,6 +433,7 @@ impl Expr {
             expr: Arc::new(self),
             idx: Arc::new(idx.into()),
             returns_scalar: true,
+            out_of_bounds: OutOfBoundsPolicy::Error,
         }
     }
 
@@ -974,12 +1002,13 @@ impl Expr {
 
     #[cfg(feature = "dynamic_group_by")]
     pub fn rolling(self, options: RollingGroupOptions) -> Self {
-        // We add the index column as `partition expr` so that the optimizer will
-        // not ignore it.
-        let index_col = col(options.index_column.as_str());
+        // We add the index and time zone columns as `partition expr` so that the optimizer
+        // will not ignore them.
+        let mut partition_by = vec![col(options.index_column.as_str())];
+        partition_by.extend(options.time_zone_column.as_deref().map(col));
         Expr::Window {
             function: Arc::new(self),
-            partition_by: vec![index_col],
+            partition_by,
             options: WindowType::Rolling(options),
         }
     }
@@ -1007,6 +1036,30 @@ impl Expr {
         self.apply_private(FunctionExpr::FillNullWithStrategy(strategy))
     }
 
+    /// Replace the null values with a `Forward` or `Backward` fill strategy, and the null values
+    /// that are left, e.g. beyond the fill limit, by `fill_value`.
+    pub fn fill_null_with_strategy_and_value<E: Into<Expr>>(
+        self,
+        strategy: FillNullStrategy,
+        fill_value: E,
+    ) -> Self {
+        self.apply_many_private(
+            FunctionExpr::FillNullWithStrategy(strategy),
+            &[fill_value.into()],
+            false,
+            true,
+        )
+    }
+
+    /// Treat the floating point `NaN` values as missing values.
+    ///
+    /// Subsequent aggregations, comparisons and sorts will then follow the null semantics,
+    /// e.g. `NaN` values are skipped by `sum` and `mean`, instead of propagating. This is
+    /// an alias of `fill_nan` with a null value.
+    pub fn nan_to_null(self) -> Self {
+        self.fill_nan(lit(NULL))
+    }
+
     /// Replace the floating point `NaN` values by a value.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> Self {
         // we take the not branch so that self is truthy value of `when -> then -> otherwise`
@@ -1332,6 +1385,32 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Std, RollingFunction::StdBy)
     }
 
+    /// Count the non-null values in a trailing temporal window per row, the window being
+    /// defined by the `Date`/`Datetime` column `by`.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_count_by(self, by: &str, mut options: RollingOptions) -> Expr {
+        options.by = Some(by.to_string());
+        self.apply_many_private(
+            FunctionExpr::RollingExpr(RollingFunction::CountBy(options)),
+            &[col(by)],
+            false,
+            false,
+        )
+    }
+
+    /// Count the distinct values in a trailing temporal window per row, the window being
+    /// defined by the `Date`/`Datetime` column `by`. Null is counted as a distinct value.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_n_unique_by(self, by: &str, mut options: RollingOptions) -> Expr {
+        options.by = Some(by.to_string());
+        self.apply_many_private(
+            FunctionExpr::RollingExpr(RollingFunction::NUniqueBy(options)),
+            &[col(by)],
+            false,
+            false,
+        )
+    }
+
     /// Apply a rolling skew.
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
@@ -1406,6 +1485,20 @@ impl Expr {
         self.apply_private(FunctionExpr::PeakMax)
     }
 
+    #[cfg(feature = "outliers")]
+    /// Clip the values to their `lower_q` and `upper_q` quantiles, which are linearly
+    /// interpolated. Integer columns are cast to `Float64`.
+    pub fn winsorize(self, lower_q: f64, upper_q: f64) -> Expr {
+        self.apply_private(FunctionExpr::Winsorize { lower_q, upper_q })
+    }
+
+    #[cfg(feature = "outliers")]
+    /// Flag the values that are outliers according to `method`, using its default threshold
+    /// if no `threshold` is given. Nulls stay null.
+    pub fn is_outlier(self, method: OutlierMethod, threshold: Option<f64>) -> Expr {
+        self.apply_private(FunctionExpr::IsOutlier { method, threshold })
+    }
+
     #[cfg(feature = "rank")]
     /// Assign ranks to data, dealing with ties appropriately.
     pub fn rank(self, options: RankOptions, seed: Option<u64>) -> Expr {
@@ -1564,6 +1657,45 @@ impl Expr {
             })
     }
 
+    #[cfg(feature = "dtype-struct")]
+    fn multi_output(self, kernel: MultiOutputKernel) -> Expr {
+        self.apply_private(FunctionExpr::MultiOutput(kernel))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Get the minimum and the maximum in a single pass, as a struct with the fields `min`
+    /// and `max`. Use `unnest` to split them into columns.
+    pub fn min_max(self) -> Expr {
+        self.multi_output(MultiOutputKernel::MinMax)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Get the mean and the standard deviation in a single pass, as a struct with the
+    /// fields `mean` and `std`. Use `unnest` to split them into columns.
+    pub fn mean_std(self, ddof: u8) -> Expr {
+        self.multi_output(MultiOutputKernel::MeanStd { ddof })
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Get the quantiles at `lower` and `upper` from a single sort, as a struct with the
+    /// fields `lower` and `upper`. Use `unnest` to split them into columns.
+    pub fn quantile_bounds(
+        self,
+        lower: f64,
+        upper: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Expr {
+        self.multi_output(MultiOutputKernel::QuantileBounds {
+            lower,
+            upper,
+            interpol,
+        })
+    }
+
     /// Get maximal value that could be hold by this dtype.
     pub fn upper_bound(self) -> Expr {
         self.map_private(FunctionExpr::UpperBound)
@@ -1724,6 +1856,12 @@ impl Expr {
         self.map_private(FunctionExpr::ToPhysical)
     }
 
+    /// Get the value of `key` in the metadata of this column, or null if the column doesn't have
+    /// that key. The value is looked up in the schema when the plan is built.
+    pub fn column_metadata(self, key: &str) -> Expr {
+        self.map_private(FunctionExpr::ColumnMetadata(Arc::from(key)))
+    }
+
     pub fn gather_every(self, n: usize, offset: usize) -> Expr {
         self.apply_private(FunctionExpr::GatherEvery { n, offset })
     }
@@ -1,3 +1,5 @@
+#[cfg(feature = "json")]
+pub use polars_json::json::write::JsonEncodeOptions;
 use polars_ops::prelude::{JoinArgs, JoinType};
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
@@ -48,6 +50,10 @@ pub struct JoinOptions {
     /// Holds `(Option<known_size>, estimated_size)`
     pub rows_left: (Option<usize>, usize),
     pub rows_right: (Option<usize>, usize),
+    /// Only use the results of the key expressions that aren't columns as join keys, instead
+    /// of adding them to the output in place of the columns they are named after.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub drop_key_expressions: bool,
 }
 
 impl Default for JoinOptions {
@@ -58,6 +64,7 @@ impl Default for JoinOptions {
             args: JoinArgs::new(JoinType::Left),
             rows_left: (None, usize::MAX),
             rows_right: (None, usize::MAX),
+            drop_key_expressions: false,
         }
     }
 }
@@ -51,4 +51,34 @@ impl Expr {
             false,
         )
     }
+
+    /// Draw a value from the uniform distribution over `[low, high)` for every row of this
+    /// expression. The values of the expression itself are ignored.
+    ///
+    /// With a `seed`, the values are reproducible. In the streaming engine every batch draws
+    /// from its own stream, derived from the `seed`.
+    pub fn random_uniform(self, low: f64, high: f64, seed: Option<u64>) -> Self {
+        self.map_private(FunctionExpr::Random {
+            method: RandomMethod::Uniform { low, high },
+            seed,
+        })
+    }
+
+    /// Draw a value from the normal distribution with `mean` and `std_dev` for every row of
+    /// this expression. See [`Expr::random_uniform`] for the seeding.
+    pub fn random_normal(self, mean: f64, std_dev: f64, seed: Option<u64>) -> Self {
+        self.map_private(FunctionExpr::Random {
+            method: RandomMethod::Normal { mean, std_dev },
+            seed,
+        })
+    }
+
+    /// Draw `true` with probability `p` for every row of this expression. See
+    /// [`Expr::random_uniform`] for the seeding.
+    pub fn random_bernoulli(self, p: f64, seed: Option<u64>) -> Self {
+        self.map_private(FunctionExpr::Random {
+            method: RandomMethod::Bernoulli { p },
+            seed,
+        })
+    }
 }
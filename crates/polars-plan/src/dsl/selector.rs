@@ -1,24 +1,189 @@
-use std::ops::{Add, BitAnd, Sub};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Not, Sub};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::*;
+use crate::logical_plan::expr_expansion::expand_selector;
 
+/// Selects columns of a schema, e.g. all numeric columns or the columns that match a regex.
+///
+/// Selectors can be combined with set operations: `|` (or `+`) for the union, `&` for the
+/// intersection, `-` for the difference, `^` for the symmetric difference and `!` for the
+/// complement. They are resolved against the schema when the query is planned.
+///
+/// ```rust
+/// use polars_plan::prelude::*;
+///
+/// // All numeric columns, except for "id".
+/// let selector = Selector::numeric() - Selector::by_name(["id"]);
+/// let expr: Expr = selector.into();
+/// ```
 #[derive(Clone, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Selector {
     Add(Box<Selector>, Box<Selector>),
     Sub(Box<Selector>, Box<Selector>),
     InterSect(Box<Selector>, Box<Selector>),
+    /// The columns that are selected by exactly one of the selectors.
+    Xor(Box<Selector>, Box<Selector>),
     Root(Box<Expr>),
+    /// The columns with a dtype of this class.
+    ByDtypeClass(DataTypeClass),
+    /// The columns that have the metadata `key`, with the given `value` if any.
+    ByMetadata {
+        key: Arc<str>,
+        value: Option<Arc<str>>,
+    },
+}
+
+/// A class of data types to select columns by, see [`Selector::by_dtype_class`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DataTypeClass {
+    /// Integers, floats and decimals.
+    Numeric,
+    Integer,
+    SignedInteger,
+    UnsignedInteger,
+    Float,
+    Decimal,
+    /// Dates, datetimes of any time unit and time zone, durations and times.
+    Temporal,
+    String,
+    Categorical,
+    Boolean,
+    Binary,
+    /// Lists, arrays and structs.
+    Nested,
+}
+
+impl DataTypeClass {
+    /// Whether `dtype` belongs to this class.
+    pub fn contains(&self, dtype: &DataType) -> bool {
+        use DataTypeClass::*;
+        match self {
+            Numeric => dtype.is_numeric() || dtype.is_decimal(),
+            Integer => dtype.is_integer(),
+            SignedInteger => dtype.is_signed_integer(),
+            UnsignedInteger => dtype.is_unsigned_integer(),
+            Float => dtype.is_float(),
+            Decimal => dtype.is_decimal(),
+            Temporal => dtype.is_temporal(),
+            String => dtype.is_string(),
+            Categorical => dtype.is_categorical() || dtype.is_enum(),
+            Boolean => dtype.is_bool(),
+            Binary => dtype.is_binary(),
+            Nested => dtype.is_nested(),
+        }
+    }
 }
 
 impl Selector {
-    #[cfg(feature = "meta")]
-    pub(crate) fn new(e: Expr) -> Self {
+    pub fn new(e: Expr) -> Self {
         Self::Root(Box::new(e))
     }
+
+    /// Select all columns.
+    pub fn all() -> Self {
+        Self::new(Expr::Wildcard)
+    }
+
+    /// Select the columns with these names.
+    pub fn by_name<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::new(cols(names))
+    }
+
+    /// Select the columns with one of these dtypes.
+    pub fn by_dtype<I: IntoIterator<Item = DataType>>(dtypes: I) -> Self {
+        Self::new(dtype_cols(dtypes.into_iter().collect::<Vec<_>>()))
+    }
+
+    /// Select the columns with a dtype of this class.
+    pub fn by_dtype_class(class: DataTypeClass) -> Self {
+        Self::ByDtypeClass(class)
+    }
+
+    /// Select the columns that have the metadata `key`. If `value` is given, the metadata
+    /// must have this value.
+    pub fn by_metadata(key: &str, value: Option<&str>) -> Self {
+        Self::ByMetadata {
+            key: Arc::from(key),
+            value: value.map(Arc::from),
+        }
+    }
+
+    /// Select the columns whose name matches the regex `pattern`.
+    ///
+    /// Unlike the regexes of [`col`], the pattern doesn't need to match the whole name.
+    #[cfg(feature = "regex")]
+    pub fn matches(pattern: &str) -> Self {
+        // Regex columns start with `^` and end with `$`. The group keeps the alternations and
+        // the anchors of the pattern within it.
+        Self::new(col(&format!("^(?:.*(?:{pattern}).*)$")))
+    }
+
+    /// Select the integer, float and decimal columns.
+    pub fn numeric() -> Self {
+        Self::ByDtypeClass(DataTypeClass::Numeric)
+    }
+
+    /// Select the integer columns.
+    pub fn integer() -> Self {
+        Self::ByDtypeClass(DataTypeClass::Integer)
+    }
+
+    /// Select the float columns.
+    pub fn float() -> Self {
+        Self::ByDtypeClass(DataTypeClass::Float)
+    }
+
+    /// Select the temporal columns.
+    pub fn temporal() -> Self {
+        Self::ByDtypeClass(DataTypeClass::Temporal)
+    }
+
+    /// Select the String columns.
+    pub fn string() -> Self {
+        Self::ByDtypeClass(DataTypeClass::String)
+    }
+
+    /// Select the Boolean columns.
+    pub fn boolean() -> Self {
+        Self::ByDtypeClass(DataTypeClass::Boolean)
+    }
+
+    /// Deselect the columns with these names.
+    pub fn exclude<I, S>(self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self - Self::by_name(names)
+    }
+
+    /// Resolve the names of the selected columns against `schema`.
+    pub fn expand(&self, schema: &Schema) -> PolarsResult<Vec<ColumnName>> {
+        expand_selector(self.clone(), schema, &[])
+    }
+
+    /// The part of `schema` that is selected.
+    pub fn select_schema(&self, schema: &Schema) -> PolarsResult<Schema> {
+        self.expand(schema)?
+            .into_iter()
+            .map(|name| Ok(schema.try_get_field(&name)?))
+            .collect()
+    }
+}
+
+impl From<Selector> for Expr {
+    fn from(s: Selector) -> Self {
+        Expr::Selector(s)
+    }
 }
 
 impl Add for Selector {
@@ -29,6 +194,14 @@ impl Add for Selector {
     }
 }
 
+impl BitOr for Selector {
+    type Output = Selector;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Selector::Add(Box::new(self), Box::new(rhs))
+    }
+}
+
 impl Sub for Selector {
     type Output = Selector;
 
@@ -46,3 +219,19 @@ impl BitAnd for Selector {
         Selector::InterSect(Box::new(self), Box::new(rhs))
     }
 }
+
+impl BitXor for Selector {
+    type Output = Selector;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Selector::Xor(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Not for Selector {
+    type Output = Selector;
+
+    fn not(self) -> Self::Output {
+        Selector::all() - self
+    }
+}
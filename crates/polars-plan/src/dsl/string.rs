@@ -161,6 +161,28 @@ impl StringNameSpace {
         ))
     }
 
+    #[cfg(feature = "extract_pattern")]
+    /// Extract the fields of a grok pattern as a struct.
+    ///
+    /// A grok pattern has the form `%{SYNTAX}`, `%{SYNTAX:name}` or `%{SYNTAX:name:type}`,
+    /// where `SYNTAX` is a pattern of the built-in library or of `custom_patterns`. The match
+    /// of a pattern with a `name` is extracted into a field that is cast to `type`: `int`,
+    /// `float` or `string`. Named capture groups of the pattern are extracted as `String`
+    /// fields. Strings that don't match have null fields.
+    pub fn extract_pattern(
+        self,
+        pat: &str,
+        custom_patterns: &[(&str, &str)],
+    ) -> PolarsResult<Expr> {
+        // The pattern is expanded here to determine the output datatype, the expanded regex
+        // is compiled again at execution.
+        let (regex, dtype) =
+            polars_ops::chunked_array::strings::expand_grok_pattern(pat, custom_patterns)?;
+        Ok(self
+            .0
+            .map_private(StringFunction::ExtractPattern { dtype, regex }.into()))
+    }
+
     /// Pad the start of the string until it reaches the given length.
     ///
     /// Padding is done using the specified `fill_char`.
@@ -293,6 +315,16 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a String column into a Duration column.
+    ///
+    /// The values may be ISO 8601 durations, e.g. `P1DT2H3M`, or as durations are printed,
+    /// e.g. `1d 2h 3m`. Values that can't be parsed become null, or raise if `strict`.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, strict: bool) -> Expr {
+        self.0
+            .map_private(StringFunction::ToDuration { time_unit, strict }.into())
+    }
+
     /// Convert a String column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, infer_length: usize) -> Expr {
@@ -412,6 +444,63 @@ impl StringNameSpace {
         )
     }
 
+    /// Replace all but the last `n` characters of each string with `mask_char`.
+    #[cfg(feature = "string_mask")]
+    pub fn mask_except_last(self, n: usize, mask_char: char) -> Expr {
+        self.0
+            .map_private(StringFunction::MaskExceptLast { n, mask_char }.into())
+    }
+
+    /// Replace the ASCII digits and letters of each string with digits and letters derived
+    /// from `key`, keeping the format of the string. Equal strings get equal tokens.
+    #[cfg(feature = "string_mask")]
+    pub fn tokenize_format_preserving(self, key: &str) -> Expr {
+        self.0.map_private(
+            StringFunction::TokenizeFormatPreserving {
+                key: key.to_string(),
+            }
+            .into(),
+        )
+    }
+
+    /// Replace each string with its hexadecimal HMAC-SHA256, keyed by `key`.
+    ///
+    /// The key is not serialized with the plan, so plans that use this can't be serialized.
+    #[cfg(feature = "string_mask")]
+    pub fn pseudonymize(self, key: &[u8]) -> Expr {
+        self.0.map_private(
+            StringFunction::Pseudonymize {
+                key: PseudonymizationKey::new(key),
+            }
+            .into(),
+        )
+    }
+
+    /// Decode the percent-encoded characters of each string.
+    #[cfg(feature = "string_url")]
+    pub fn url_decode(self) -> Expr {
+        self.0.map_private(StringFunction::UrlDecode.into())
+    }
+
+    /// Percent-encode all characters of each string except the unreserved ones.
+    #[cfg(feature = "string_url")]
+    pub fn url_encode(self) -> Expr {
+        self.0.map_private(StringFunction::UrlEncode.into())
+    }
+
+    /// Split each string into a struct of the components of a URL: `scheme`, `host`,
+    /// `port`, `path`, `query` and `fragment`.
+    #[cfg(feature = "string_url")]
+    pub fn parse_url(self) -> Expr {
+        self.0.map_private(StringFunction::ParseUrl.into())
+    }
+
+    /// Parse each string as a query string into a list of `key` and `value` structs.
+    #[cfg(feature = "string_url")]
+    pub fn parse_query_string(self) -> Expr {
+        self.0.map_private(StringFunction::ParseQueryString.into())
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_many_private(
@@ -40,4 +40,18 @@ impl StructNameSpace {
         self.0
             .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode))
     }
+
+    /// Serialize the values of the [`StructChunked`] to JSON objects, formatting dates and
+    /// datetimes, nulls and the order of the fields as configured in `options`.
+    #[cfg(feature = "json")]
+    pub fn json_encode_with_options(self, options: JsonEncodeOptions) -> Expr {
+        // The default options keep the function of plans that were serialized before the
+        // options existed.
+        let function = if options == JsonEncodeOptions::default() {
+            StructFunction::JsonEncode
+        } else {
+            StructFunction::JsonEncodeWithOptions(options)
+        };
+        self.0.map_private(FunctionExpr::StructExpr(function))
+    }
 }
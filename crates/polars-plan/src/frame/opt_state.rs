@@ -1,3 +1,9 @@
+use polars_ops::series::IntegerOverflow;
+
+#[cfg(feature = "streaming")]
+use crate::prelude::SpillOptions;
+use crate::prelude::{CastPolicy, ChunkPolicy};
+
 #[derive(Copy, Clone, Debug)]
 /// State of the allowed optimizations
 pub struct OptState {
@@ -29,6 +35,24 @@ pub struct OptState {
     pub fast_projection: bool,
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub row_estimate: bool,
+    /// Reorder chains of inner joins by their estimated cardinalities, so that the joins
+    /// that produce the fewest rows are done first.
+    pub join_reorder: bool,
+    /// How integer arithmetic and sums handle overflow.
+    pub integer_overflow: IntegerOverflow,
+    /// Treat the floating point `NaN` values as missing values in aggregations, comparisons
+    /// and sorts.
+    pub nan_as_null: bool,
+    /// How implicit casts inserted by type coercion are checked.
+    pub cast_policy: CastPolicy,
+    /// When operators rechunk their data and how large the chunks of the result are.
+    pub chunk_policy: ChunkPolicy,
+    #[cfg(feature = "streaming")]
+    /// How the streaming engine spills data to disk when it runs out of memory.
+    pub spill_options: SpillOptions,
+    /// Cache the results of expressions that are evaluated in more than one node of the
+    /// plan, and reuse them when they are evaluated on the same columns.
+    pub expr_cache: bool,
 }
 
 impl Default for OptState {
@@ -49,6 +73,14 @@ impl Default for OptState {
             fast_projection: true,
             eager: false,
             row_estimate: true,
+            join_reorder: false,
+            integer_overflow: IntegerOverflow::Wrap,
+            nan_as_null: false,
+            cast_policy: CastPolicy::Lenient,
+            chunk_policy: ChunkPolicy::default(),
+            #[cfg(feature = "streaming")]
+            spill_options: SpillOptions::default(),
+            expr_cache: false,
         }
     }
 }
@@ -146,6 +146,7 @@ pub enum AExpr {
         expr: Node,
         idx: Node,
         returns_scalar: bool,
+        out_of_bounds: OutOfBoundsPolicy,
     },
     SortBy {
         expr: Node,
@@ -245,7 +246,7 @@ impl AExpr {
     }
 
     /// Push nodes at this level to a pre-allocated stack
-    pub(crate) fn nodes<C: PushNode>(&self, container: &mut C) {
+    pub fn nodes<C: PushNode>(&self, container: &mut C) {
         use AExpr::*;
 
         match self {
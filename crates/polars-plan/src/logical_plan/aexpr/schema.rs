@@ -45,10 +45,12 @@ impl AExpr {
                     Ok(field)
                 }
             },
-            Alias(expr, name) => Ok(Field::new(
-                name,
-                arena.get(*expr).get_type(schema, ctxt, arena)?,
-            )),
+            Alias(expr, name) => {
+                // An alias renames the column, it keeps the metadata of its input.
+                let mut field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                field.set_name(name.as_ref().into());
+                Ok(field)
+            },
             Column(name) => {
                 let field = schema
                     .get_field(name)
@@ -0,0 +1,96 @@
+use super::*;
+
+/// How an operation wants the chunks of its input to be laid out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChunkRequirement {
+    /// Works on any chunk layout.
+    Any,
+    /// Works on any chunk layout, but is faster on contiguous input.
+    Preferred,
+    /// Needs contiguous input and always rechunks.
+    Contiguous,
+}
+
+impl IR {
+    /// The chunk layout this node wants for its input.
+    pub fn chunk_requirement(&self) -> ChunkRequirement {
+        use IR::*;
+        match self {
+            Sort { .. } => ChunkRequirement::Preferred,
+            #[cfg(feature = "dynamic_group_by")]
+            GroupBy { options, .. } if options.dynamic.is_some() || options.rolling.is_some() => {
+                ChunkRequirement::Contiguous
+            },
+            GroupBy { .. } => ChunkRequirement::Preferred,
+            Union { options, .. } if options.rechunk => ChunkRequirement::Contiguous,
+            MapFunction {
+                function: FunctionNode::Rechunk,
+                ..
+            } => ChunkRequirement::Contiguous,
+            _ => ChunkRequirement::Any,
+        }
+    }
+
+    /// Whether this node rechunks its input under `policy`.
+    pub fn rechunks_input(&self, policy: &ChunkPolicy) -> bool {
+        match self.chunk_requirement() {
+            ChunkRequirement::Contiguous => true,
+            ChunkRequirement::Preferred => policy.auto_rechunk,
+            ChunkRequirement::Any => false,
+        }
+    }
+}
+
+/// A rough upper bound of the rows produced by `node`, if the sources are known.
+fn estimate_rows(node: Node, lp_arena: &Arena<IR>) -> Option<usize> {
+    use IR::*;
+    match lp_arena.get(node) {
+        DataFrameScan { df, .. } => Some(df.height()),
+        Scan { file_info, .. } => match file_info.row_estimation {
+            (Some(known), _) => Some(known),
+            (None, estimated) if estimated > 0 && estimated != usize::MAX => Some(estimated),
+            _ => None,
+        },
+        Slice { input, len, .. } => {
+            let len = *len as usize;
+            Some(estimate_rows(*input, lp_arena).map_or(len, |rows| rows.min(len)))
+        },
+        TopK { input, len, .. } => {
+            Some(estimate_rows(*input, lp_arena).map_or(*len, |rows| rows.min(*len)))
+        },
+        Union { inputs, .. } => inputs
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena))
+            .sum(),
+        lp => lp
+            .get_inputs()
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena))
+            .try_fold(0, |acc, rows| rows.map(|rows| acc.max(rows))),
+    }
+}
+
+/// Warn about the nodes of the plan that will rechunk more than
+/// [`ChunkPolicy::warn_rechunk_rows`] rows.
+pub fn warn_large_rechunks(root: Node, lp_arena: &Arena<IR>, policy: &ChunkPolicy) {
+    let Some(threshold) = policy.warn_rechunk_rows else {
+        return;
+    };
+    for (_, lp) in lp_arena.iter(root) {
+        if !lp.rechunks_input(policy) {
+            continue;
+        }
+        let rows = lp
+            .get_inputs()
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena))
+            .sum::<Option<usize>>();
+        if let Some(rows) = rows.filter(|rows| *rows >= threshold) {
+            polars_warn!(
+                "{} will rechunk an estimated {} rows into contiguous memory",
+                lp.name(),
+                rows
+            );
+        }
+    }
+}
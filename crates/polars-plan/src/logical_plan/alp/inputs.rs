@@ -79,6 +79,19 @@ impl IR {
                 slice: *slice,
                 sort_options: sort_options.clone(),
             },
+            TopK {
+                by_column,
+                offset,
+                len,
+                sort_options,
+                ..
+            } => TopK {
+                input: inputs[0],
+                by_column: by_column.clone(),
+                offset: *offset,
+                len: *len,
+                sort_options: sort_options.clone(),
+            },
             Cache { id, cache_hits, .. } => Cache {
                 input: inputs[0],
                 id: *id,
@@ -168,7 +181,9 @@ impl IR {
         use IR::*;
         match self {
             Slice { .. } | Cache { .. } | Distinct { .. } | Union { .. } | MapFunction { .. } => {},
-            Sort { by_column, .. } => container.extend_from_slice(by_column),
+            Sort { by_column, .. } | TopK { by_column, .. } => {
+                container.extend_from_slice(by_column)
+            },
             Filter { predicate, .. } => container.push(predicate.clone()),
             Select { expr, .. } => container.extend_from_slice(expr),
             GroupBy { keys, aggs, .. } => {
@@ -233,6 +248,7 @@ impl IR {
             Select { input, .. } => *input,
             SimpleProjection { input, .. } => *input,
             Sort { input, .. } => *input,
+            TopK { input, .. } => *input,
             Cache { input, .. } => *input,
             GroupBy { input, .. } => *input,
             Join {
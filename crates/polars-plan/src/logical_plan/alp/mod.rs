@@ -1,9 +1,11 @@
+mod chunking;
 mod inputs;
 mod schema;
 
 use std::borrow::Cow;
 use std::path::PathBuf;
 
+pub use chunking::{warn_large_rechunks, ChunkRequirement};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::unitvec;
@@ -67,6 +69,15 @@ pub enum IR {
         slice: Option<(i64, usize)>,
         sort_options: SortMultipleOptions,
     },
+    /// A [`IR::Sort`] followed by a slice with a non-negative offset. Only the
+    /// `offset + len` smallest rows have to be kept while the input is consumed.
+    TopK {
+        input: Node,
+        by_column: Vec<ExprIR>,
+        offset: usize,
+        len: usize,
+        sort_options: SortMultipleOptions,
+    },
     Cache {
         input: Node,
         // Unique ID.
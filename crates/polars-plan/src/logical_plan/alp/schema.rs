@@ -24,6 +24,7 @@ impl IR {
             DataFrameScan { .. } => "df",
             Select { .. } => "projection",
             Sort { .. } => "sort",
+            TopK { .. } => "top_k",
             Cache { .. } => "cache",
             GroupBy { .. } => "aggregate",
             Join { .. } => "join",
@@ -36,6 +37,8 @@ impl IR {
             Sink { payload, .. } => match payload {
                 SinkType::Memory => "sink (memory)",
                 SinkType::File { .. } => "sink (file)",
+                SinkType::Partitioned { .. } => "sink (partitioned)",
+                SinkType::Split { .. } => "sink (split)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
             },
@@ -68,7 +71,7 @@ impl IR {
             Union { inputs, .. } => return arena.get(inputs[0]).schema(arena),
             HConcat { schema, .. } => schema,
             Cache { input, .. } => return arena.get(*input).schema(arena),
-            Sort { input, .. } => return arena.get(*input).schema(arena),
+            Sort { input, .. } | TopK { input, .. } => return arena.get(*input).schema(arena),
             Scan {
                 output_schema,
                 file_info,
@@ -14,6 +14,12 @@ pub struct AnonymousScanArgs {
     pub predicate: Option<Expr>,
 }
 
+/// Produces the output of an [`AnonymousScan`] in batches for the streaming engine.
+pub trait AnonymousScanBatches: Send + Sync {
+    /// Read the next `n` batches. Returns `None` once the scan is exhausted.
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>>;
+}
+
 pub trait AnonymousScan: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     /// Creates a DataFrame from the supplied function & scan options.
@@ -42,6 +48,35 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// specify if the scan provider can be read in batches with [`AnonymousScan::batched`]
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
+    /// Creates a reader that produces the DataFrame in batches of about `chunk_size` rows.
+    /// Only called if [`AnonymousScan::allows_streaming`] returns `true`.
+    ///
+    /// A pushed down predicate is applied to the batches by the streaming engine, the reader may
+    /// only use it to skip data.
+    fn batched(
+        &self,
+        _scan_opts: AnonymousScanArgs,
+        _chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        polars_bail!(ComputeError: "this anonymous scan cannot be read in batches");
+    }
+    /// specify if the scan provider can count its rows with [`AnonymousScan::count_rows`]
+    ///
+    /// Defaults to `false`
+    fn allows_count_rows(&self) -> bool {
+        false
+    }
+    /// Count the rows of the scan without reading them, e.g. for `select(len())` queries.
+    /// Only called if [`AnonymousScan::allows_count_rows`] returns `true`.
+    fn count_rows(&self) -> PolarsResult<usize> {
+        polars_bail!(ComputeError: "this anonymous scan cannot count its rows");
+    }
 }
 
 impl<F> AnonymousScan for F
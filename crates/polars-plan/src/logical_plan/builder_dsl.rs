@@ -6,7 +6,7 @@ use polars_io::csv::read::{CommentPrefix, CsvEncoding, CsvReaderOptions, NullVal
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetOptions;
+use polars_io::parquet::read::{ParquetDecryption, ParquetOptions};
 use polars_io::HiveOptions;
 #[cfg(any(
     feature = "parquet",
@@ -94,9 +94,11 @@ impl DslBuilder {
         row_index: Option<RowIndex>,
         rechunk: bool,
         low_memory: bool,
+        max_decoders: Option<usize>,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
         hive_options: HiveOptions,
+        decryption: Option<Arc<polars_io::parquet::read::FileDecryptionProperties>>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -118,10 +120,15 @@ impl DslBuilder {
                 options: ParquetOptions {
                     parallel,
                     low_memory,
+                    max_decoders,
                     use_statistics,
+                    n_rows_after_predicate: None,
+                    skip_rows: 0,
                 },
                 cloud_options,
                 metadata: None,
+                nested_projection: Default::default(),
+                decryption: decryption.map(ParquetDecryption),
             },
         }
         .into())
@@ -236,6 +243,8 @@ impl DslBuilder {
                     skip_rows_after_header,
                     infer_schema_length,
                     decimal_comma,
+                    // The parse error policies are applied on top of the scan.
+                    parse_error_policies: None,
                 },
             },
         }
@@ -342,6 +351,7 @@ impl DslBuilder {
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
             slice: None,
+            map_groups: Default::default(),
         };
 
         DslPlan::GroupBy {
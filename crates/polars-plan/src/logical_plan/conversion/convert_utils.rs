@@ -1,9 +1,13 @@
+use polars_core::utils::try_get_supertype;
+
 use super::*;
+use crate::logical_plan::optimizer::check_implicit_cast;
 
 pub(super) fn convert_st_union(
     inputs: &mut [Node],
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
+    cast_policy: CastPolicy,
 ) -> PolarsResult<()> {
     let mut schema = (**lp_arena.get(inputs[0]).schema(lp_arena)).clone();
 
@@ -18,6 +22,11 @@ pub(super) fn convert_st_union(
             let mut exprs = vec![];
             let input_schema = lp_arena.get(*input).schema(lp_arena);
 
+            for ((name, dtype), st) in input_schema.iter().zip(schema.iter_dtypes()) {
+                if dtype != st {
+                    check_implicit_cast(name, dtype, st, cast_policy)?;
+                }
+            }
             let to_cast = input_schema.iter().zip(schema.iter_dtypes()).flat_map(
                 |((left_name, left_type), st)| {
                     if left_type != st {
@@ -42,3 +51,79 @@ pub(super) fn convert_st_union(
     }
     Ok(())
 }
+
+/// Project every input of a diagonal union on the union of the input schemas. Columns that
+/// are missing in an input are filled with nulls and, if `to_supertypes`, the columns are
+/// cast to the supertype of their dtypes in all inputs.
+pub(super) fn convert_diagonal_union(
+    inputs: &mut Vec<Node>,
+    to_supertypes: bool,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    cast_policy: CastPolicy,
+) -> PolarsResult<()> {
+    let schemas = inputs
+        .iter()
+        .map(|input| lp_arena.get(*input).schema(lp_arena).into_owned())
+        .collect::<Vec<_>>();
+
+    // Inputs without columns don't contribute to the union.
+    if schemas.iter().any(|schema| !schema.is_empty()) {
+        let mut keep = schemas.iter().map(|schema| !schema.is_empty());
+        inputs.retain(|_| keep.next().unwrap());
+    }
+
+    let mut schema = Schema::with_capacity(schemas.iter().map(|s| s.len()).sum());
+    for input_schema in schemas.iter().filter(|s| !s.is_empty()) {
+        for (name, dtype) in input_schema.iter() {
+            match schema.get(name) {
+                None => {
+                    schema.with_column(name.clone(), dtype.clone());
+                },
+                Some(current) if current == dtype => {},
+                Some(current) => {
+                    polars_ensure!(
+                        to_supertypes,
+                        SchemaMismatch: "column '{}' has dtype {} in one input and {} in \
+                        another input of a diagonal concat; use 'diagonal_relaxed' to cast \
+                        them to their supertype",
+                        name, current, dtype
+                    );
+                    let st = try_get_supertype(current, dtype)?;
+                    schema.with_column(name.clone(), st);
+                },
+            }
+        }
+    }
+
+    for input in inputs.iter_mut() {
+        let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+        if input_schema.len() == schema.len()
+            && input_schema
+                .iter()
+                .zip(schema.iter())
+                .all(|(left, right)| left == right)
+        {
+            continue;
+        }
+
+        let mut exprs = Vec::with_capacity(schema.len());
+        for (name, st) in schema.iter() {
+            let expr = match input_schema.get(name) {
+                Some(dtype) if dtype == st => col(name),
+                Some(dtype) => {
+                    check_implicit_cast(name, dtype, st, cast_policy)?;
+                    col(name).cast(st.clone())
+                },
+                None => NULL.lit().cast(st.clone()).alias(name),
+            };
+            exprs.push(expr);
+        }
+        let exprs = to_expr_irs(exprs, expr_arena);
+        let lp = IRBuilder::new(*input, expr_arena, lp_arena)
+            .project(exprs, Default::default())
+            .build();
+        *input = lp_arena.add(lp);
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use super::stack_opt::ConversionOpt;
 use super::*;
+use crate::constants::JOIN_KEY_PREFIX;
 use crate::logical_plan::expr_expansion::{is_regex_projection, rewrite_projections};
 use crate::logical_plan::projection_expr::ProjectionExprs;
 
@@ -47,8 +48,9 @@ pub fn to_alp(
     lp_arena: &mut Arena<IR>,
     simplify_expr: bool,
     type_coercion: bool,
+    cast_policy: CastPolicy,
 ) -> PolarsResult<Node> {
-    let mut convert = ConversionOpt::new(simplify_expr, type_coercion);
+    let mut convert = ConversionOpt::new(simplify_expr, type_coercion, cast_policy);
     to_alp_impl(lp, expr_arena, lp_arena, &mut convert)
 }
 
@@ -95,11 +97,16 @@ pub fn to_alp_impl(
                     FileScan::Parquet {
                         cloud_options,
                         metadata,
+                        decryption,
                         ..
                     } => {
-                        let (file_info, md) =
-                            scans::parquet_file_info(&paths, &file_options, cloud_options.as_ref())
-                                .map_err(|e| e.context(failed_here!(parquet scan)))?;
+                        let (file_info, md) = scans::parquet_file_info(
+                            &paths,
+                            &file_options,
+                            cloud_options.as_ref(),
+                            decryption.as_ref().map(|d| d.0.clone()),
+                        )
+                        .map_err(|e| e.context(failed_here!(parquet scan)))?;
                         *metadata = md;
                         file_info
                     },
@@ -153,9 +160,23 @@ pub fn to_alp_impl(
                 .collect::<PolarsResult<Vec<_>>>()
                 .map_err(|e| e.context(failed_input!(vertical concat)))?;
 
-            if args.to_supertypes {
-                convert_utils::convert_st_union(&mut inputs, lp_arena, expr_arena)
-                    .map_err(|e| e.context(failed_input!(vertical concat)))?;
+            if args.diagonal {
+                convert_utils::convert_diagonal_union(
+                    &mut inputs,
+                    args.to_supertypes,
+                    lp_arena,
+                    expr_arena,
+                    convert.cast_policy,
+                )
+                .map_err(|e| e.context(failed_input!(vertical concat)))?;
+            } else if args.to_supertypes {
+                convert_utils::convert_st_union(
+                    &mut inputs,
+                    lp_arena,
+                    expr_arena,
+                    convert.cast_policy,
+                )
+                .map_err(|e| e.context(failed_input!(vertical concat)))?;
             }
             let options = args.into();
             IR::Union { inputs, options }
@@ -321,6 +342,29 @@ pub fn to_alp_impl(
                 }
             }
 
+            let is_equi_join = match options.args.how {
+                JoinType::Cross => false,
+                #[cfg(feature = "asof_join")]
+                JoinType::AsOf(_) => false,
+                _ => true,
+            };
+            let has_expression_keys = left_on
+                .iter()
+                .chain(right_on.iter())
+                .any(|e| !matches!(e, Expr::Column(_)));
+            if is_equi_join && options.drop_key_expressions && has_expression_keys {
+                return join_on_expression_keys(
+                    input_left,
+                    input_right,
+                    left_on,
+                    right_on,
+                    options,
+                    expr_arena,
+                    lp_arena,
+                    convert,
+                );
+            }
+
             let input_left = to_alp_impl(owned(input_left), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(join left)))?;
             let input_right = to_alp_impl(owned(input_right), expr_arena, lp_arena, convert)
@@ -549,6 +593,91 @@ pub fn to_alp_impl(
     Ok(lp_arena.add(v))
 }
 
+/// Join on keys that are expressions by computing them in temporary columns of the inputs,
+/// which are removed from the output of the join. The key columns of the inputs are left as
+/// they are.
+///
+/// The keys of an outer join that coalesces them aren't in any other column, so the coalesced
+/// keys of the left key expressions are kept, under the output names of the expressions, with
+/// the suffix of the join if the output already has a column of that name.
+#[allow(clippy::too_many_arguments)]
+fn join_on_expression_keys(
+    input_left: Arc<DslPlan>,
+    input_right: Arc<DslPlan>,
+    left_on: Vec<Expr>,
+    right_on: Vec<Expr>,
+    options: Arc<JoinOptions>,
+    expr_arena: &mut Arena<AExpr>,
+    lp_arena: &mut Arena<IR>,
+    convert: &mut ConversionOpt,
+) -> PolarsResult<Node> {
+    // The temporary key columns and the output names of their expressions.
+    let mut key_names = PlHashMap::new();
+    let mut with_key_columns = |input: Arc<DslPlan>, on: Vec<Expr>, side: &str| {
+        let mut key_exprs = vec![];
+        let on = on
+            .into_iter()
+            .enumerate()
+            .map(|(i, e)| match e {
+                Expr::Column(_) => Ok(e),
+                e => {
+                    let name = format!("{JOIN_KEY_PREFIX}{side}_{i}");
+                    key_names.insert(name.clone(), expr_output_name(&e));
+                    key_exprs.push(e.alias(&name));
+                    Ok(col(&name))
+                },
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if key_exprs.is_empty() {
+            return Ok((input, on));
+        }
+        let input = DslBuilder::from(Arc::unwrap_or_clone(input))
+            .with_columns(key_exprs, Default::default())
+            .build();
+        PolarsResult::Ok((Arc::new(input), on))
+    };
+    let (input_left, left_on) = with_key_columns(input_left, left_on, "LEFT")?;
+    let (input_right, right_on) = with_key_columns(input_right, right_on, "RIGHT")?;
+
+    let keep_coalesced_keys = matches!(options.args.how, JoinType::Outer)
+        && options.args.coalesce.coalesce(&options.args.how);
+    let suffix = options.args.suffix().to_string();
+    let lp = DslPlan::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+    };
+    let node = to_alp_impl(lp, expr_arena, lp_arena, convert)?;
+    let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+
+    let mut exprs = vec![];
+    for name in schema.iter_names() {
+        let output_name = if !name.starts_with(JOIN_KEY_PREFIX) {
+            OutputName::ColumnLhs(ColumnName::from(name.as_str()))
+        } else if let Some(key_name) = keep_coalesced_keys
+            .then(|| key_names.remove(name.as_str()))
+            .flatten()
+        {
+            let key_name = key_name?;
+            if schema.contains(&key_name) {
+                OutputName::Alias(ColumnName::from(format!("{key_name}{suffix}")))
+            } else {
+                OutputName::Alias(key_name)
+            }
+        } else {
+            continue;
+        };
+        let node = expr_arena.add(AExpr::Column(ColumnName::from(name.as_str())));
+        exprs.push(ExprIR::new(node, output_name));
+    }
+    let lp = IRBuilder::new(node, expr_arena, lp_arena)
+        .project(exprs, Default::default())
+        .build();
+    Ok(lp_arena.add(lp))
+}
+
 fn expand_filter(predicate: Expr, input: Node, lp_arena: &Arena<IR>) -> PolarsResult<Expr> {
     let schema = lp_arena.get(input).schema(lp_arena);
     let predicate = if has_expr(&predicate, |e| match e {
@@ -155,10 +155,12 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
             expr,
             idx,
             returns_scalar,
+            out_of_bounds,
         } => AExpr::Gather {
             expr: to_aexpr_impl(owned(expr), arena, state),
             idx: to_aexpr_impl_materialized_lit(owned(idx), arena, state),
             returns_scalar,
+            out_of_bounds,
         },
         Expr::Sort { expr, options } => AExpr::Sort {
             expr: to_aexpr_impl(owned(expr), arena, state),
@@ -149,6 +149,22 @@ impl IR {
                     sort_options,
                 }
             },
+            IR::TopK {
+                input,
+                by_column,
+                offset,
+                len,
+                sort_options,
+            } => {
+                let input = Arc::new(convert_to_lp(input, lp_arena));
+                let by_column = expr_irs_to_exprs(by_column, expr_arena);
+                DslPlan::Sort {
+                    input,
+                    by_column,
+                    slice: Some((offset as i64, len)),
+                    sort_options,
+                }
+            },
             IR::Cache {
                 input,
                 id,
@@ -29,6 +29,7 @@ pub(super) fn parquet_file_info(
     paths: &[PathBuf],
     file_options: &FileScanOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<Arc<FileDecryptionProperties>>,
 ) -> PolarsResult<(FileInfo, Option<FileMetaDataRef>)> {
     let path = get_path(paths)?;
 
@@ -40,8 +41,9 @@ pub(super) fn parquet_file_info(
         {
             let uri = path.to_string_lossy();
             get_runtime().block_on(async {
-                let mut reader =
-                    ParquetAsyncReader::from_uri(&uri, cloud_options, None, None).await?;
+                let mut reader = ParquetAsyncReader::from_uri(&uri, cloud_options, None, None)
+                    .await?
+                    .with_decryption(decryption);
                 let reader_schema = reader.schema().await?;
                 let num_rows = reader.num_rows().await?;
                 let metadata = reader.get_metadata().await?.clone();
@@ -53,7 +55,7 @@ pub(super) fn parquet_file_info(
         }
     } else {
         let file = polars_utils::open_file(path)?;
-        let mut reader = ParquetReader::new(file);
+        let mut reader = ParquetReader::new(file).with_decryption(decryption);
         let reader_schema = reader.schema()?;
         let schema = prepare_schema((&reader_schema).into(), file_options.row_index.as_ref());
         (
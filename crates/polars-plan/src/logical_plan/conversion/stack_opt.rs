@@ -6,10 +6,11 @@ pub(super) struct ConversionOpt {
     scratch: Vec<Node>,
     simplify: Option<SimplifyExprRule>,
     coerce: Option<TypeCoercionRule>,
+    pub(super) cast_policy: CastPolicy,
 }
 
 impl ConversionOpt {
-    pub(super) fn new(simplify: bool, type_coercion: bool) -> Self {
+    pub(super) fn new(simplify: bool, type_coercion: bool, cast_policy: CastPolicy) -> Self {
         let simplify = if simplify {
             Some(SimplifyExprRule {})
         } else {
@@ -17,7 +18,7 @@ impl ConversionOpt {
         };
 
         let coerce = if type_coercion {
-            Some(TypeCoercionRule {})
+            Some(TypeCoercionRule::new(cast_policy))
         } else {
             None
         };
@@ -26,6 +27,7 @@ impl ConversionOpt {
             scratch: Vec::with_capacity(8),
             simplify,
             coerce,
+            cast_policy,
         }
     }
 
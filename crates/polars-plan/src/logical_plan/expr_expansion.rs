@@ -229,6 +229,26 @@ fn struct_index_to_field(expr: Expr, schema: &Schema) -> PolarsResult<Expr> {
     })
 }
 
+/// This replaces `column_metadata` functions with a literal of the metadata value of their input
+/// column, or a null literal if the column doesn't have the key.
+fn replace_column_metadata(expr: Expr, schema: &Schema) -> PolarsResult<Expr> {
+    expr.try_map_expr(|e| match e {
+        Expr::Function {
+            input,
+            function: FunctionExpr::ColumnMetadata(key),
+            ..
+        } => {
+            let field = input[0].to_field(schema, Context::Default)?;
+            let value = match field.metadata().and_then(|md| md.get(key.as_ref())) {
+                Some(value) => lit(value.as_str()),
+                None => lit(NULL).cast(DataType::String),
+            };
+            Ok(value.alias(field.name()))
+        },
+        e => Ok(e),
+    })
+}
+
 /// This replaces the columns Expr with a Column Expr. It also removes the Exclude Expr from the
 /// expression chain.
 pub(super) fn replace_columns_with_column(
@@ -385,6 +405,7 @@ struct ExpansionFlags {
     has_exclude: bool,
     #[cfg(feature = "dtype-struct")]
     has_struct_field_by_index: bool,
+    has_column_metadata: bool,
 }
 
 fn find_flags(expr: &Expr) -> ExpansionFlags {
@@ -395,6 +416,7 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
     let mut has_exclude = false;
     #[cfg(feature = "dtype-struct")]
     let mut has_struct_field_by_index = false;
+    let mut has_column_metadata = false;
 
     // Do a single pass and collect all flags at once.
     // Supertypes/modification that can be done in place are also done in that pass
@@ -411,6 +433,10 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
             } => {
                 has_struct_field_by_index = true;
             },
+            Expr::Function {
+                function: FunctionExpr::ColumnMetadata(_),
+                ..
+            } => has_column_metadata = true,
             Expr::Exclude(_, _) => has_exclude = true,
             _ => {},
         }
@@ -423,6 +449,7 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
         has_exclude,
         #[cfg(feature = "dtype-struct")]
         has_struct_field_by_index,
+        has_column_metadata,
     }
 }
 
@@ -436,7 +463,6 @@ pub(crate) fn rewrite_projections(
     let mut result = Vec::with_capacity(exprs.len() + schema.len());
 
     for mut expr in exprs {
-        #[cfg(feature = "dtype-struct")]
         let result_offset = result.len();
 
         // Functions can have col(["a", "b"]) or col(String) as inputs.
@@ -457,6 +483,11 @@ pub(crate) fn rewrite_projections(
                 *e = struct_index_to_field(std::mem::take(e), schema)?;
             }
         }
+        if flags.has_column_metadata {
+            for e in &mut result[result_offset..] {
+                *e = replace_column_metadata(std::mem::take(e), schema)?;
+            }
+        }
     }
     Ok(result)
 }
@@ -564,10 +595,74 @@ fn replace_selector_inner(
 
             *members = members.intersection(&rhs_members).cloned().collect()
         },
+        Selector::Xor(lhs, rhs) => {
+            // fill lhs
+            replace_selector_inner(*lhs, members, scratch, schema, keys)?;
+
+            // fill rhs
+            let mut rhs_members = Default::default();
+            replace_selector_inner(*rhs, &mut rhs_members, scratch, schema, keys)?;
+
+            *members = members
+                .symmetric_difference(&rhs_members)
+                .cloned()
+                .collect()
+        },
+        Selector::ByDtypeClass(class) => {
+            let exclude = prepare_excluded(&Expr::Wildcard, schema, keys, false)?;
+            members.extend(
+                schema
+                    .iter()
+                    .filter(|(name, dtype)| {
+                        class.contains(dtype) && !exclude.contains(name.as_str())
+                    })
+                    .map(|(name, _)| Expr::Column(ColumnName::from(name.as_str()))),
+            )
+        },
+        Selector::ByMetadata { key, value } => {
+            let exclude = prepare_excluded(&Expr::Wildcard, schema, keys, false)?;
+            members.extend(
+                schema
+                    .iter_names()
+                    .filter(|name| {
+                        let md_value = schema
+                            .get_metadata(name)
+                            .and_then(|md| md.get(key.as_ref()));
+                        let is_match = match (md_value, &value) {
+                            (Some(md_value), Some(value)) => md_value.as_str() == value.as_ref(),
+                            (Some(_), None) => true,
+                            (None, _) => false,
+                        };
+                        is_match && !exclude.contains(name.as_str())
+                    })
+                    .map(|name| Expr::Column(ColumnName::from(name.as_str()))),
+            )
+        },
     }
     Ok(())
 }
 
+/// Resolve the names of the columns selected by `s`.
+pub(crate) fn expand_selector(
+    s: Selector,
+    schema: &Schema,
+    keys: &[Expr],
+) -> PolarsResult<Vec<ColumnName>> {
+    let mut members = PlIndexSet::new();
+    replace_selector_inner(s, &mut members, &mut vec![], schema, keys)?;
+
+    let names = members
+        .into_iter()
+        .map(|e| {
+            let Expr::Column(name) = e else {
+                unreachable!()
+            };
+            name
+        })
+        .collect();
+    Ok(names)
+}
+
 fn replace_selector(expr: Expr, schema: &Schema, keys: &[Expr]) -> PolarsResult<Expr> {
     // First pass we replace the selectors with Expr::Columns, we expand the `to_add` columns
     // and then subtract the `to_subtract` columns.
@@ -576,19 +671,9 @@ fn replace_selector(expr: Expr, schema: &Schema, keys: &[Expr]) -> PolarsResult<
             let mut swapped = Selector::Root(Box::new(Expr::Wildcard));
             std::mem::swap(&mut s, &mut swapped);
 
-            let mut members = PlIndexSet::new();
-            replace_selector_inner(swapped, &mut members, &mut vec![], schema, keys)?;
-
+            let names = expand_selector(swapped, schema, keys)?;
             Ok(Expr::Columns(
-                members
-                    .into_iter()
-                    .map(|e| {
-                        let Expr::Column(name) = e else {
-                            unreachable!()
-                        };
-                        name.to_string()
-                    })
-                    .collect(),
+                names.into_iter().map(|name| name.to_string()).collect(),
             ))
         },
         e => Ok(e),
@@ -7,7 +7,7 @@ use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetaDataRef;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetOptions;
+use polars_io::parquet::read::{NestedProjection, ParquetDecryption, ParquetOptions};
 
 use super::*;
 
@@ -22,6 +22,13 @@ pub enum FileScan {
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<FileMetaDataRef>,
+        /// Only read these fields of the nested columns. This is set by the optimizer when
+        /// struct columns are only accessed through some of their fields.
+        #[cfg_attr(feature = "serde", serde(default))]
+        nested_projection: NestedProjection,
+        /// The keys to read encrypted files with.
+        #[cfg_attr(feature = "serde", serde(default))]
+        decryption: Option<ParquetDecryption>,
     },
     #[cfg(feature = "ipc")]
     Ipc {
@@ -47,14 +54,18 @@ impl PartialEq for FileScan {
                 FileScan::Parquet {
                     options: opt_l,
                     cloud_options: c_l,
+                    nested_projection: n_l,
+                    decryption: d_l,
                     ..
                 },
                 FileScan::Parquet {
                     options: opt_r,
                     cloud_options: c_r,
+                    nested_projection: n_r,
+                    decryption: d_r,
                     ..
                 },
-            ) => opt_l == opt_r && c_l == c_r,
+            ) => opt_l == opt_r && c_l == c_r && n_l == n_r && d_l == d_r,
             #[cfg(feature = "ipc")]
             (
                 FileScan::Ipc {
@@ -86,9 +97,13 @@ impl Hash for FileScan {
                 options,
                 cloud_options,
                 metadata: _,
+                nested_projection,
+                decryption,
             } => {
                 options.hash(state);
-                cloud_options.hash(state)
+                cloud_options.hash(state);
+                nested_projection.hash(state);
+                decryption.hash(state)
             },
             #[cfg(feature = "ipc")]
             FileScan::Ipc {
@@ -136,6 +151,7 @@ impl FileScan {
             Self::Ipc { .. } => false,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => true,
+            Self::Anonymous { function, .. } => function.allows_streaming(),
             #[allow(unreachable_patterns)]
             _ => false,
         }
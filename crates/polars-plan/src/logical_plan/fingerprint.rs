@@ -0,0 +1,105 @@
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use polars_core::prelude::*;
+use polars_io::utils::is_cloud_url;
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::logical_plan::visitor::IRNode;
+use crate::prelude::*;
+
+/// Whether the result of `lp` is determined by the plan and the files it reads, so that it
+/// can be identified by a fingerprint.
+fn is_deterministic(lp: &IR, expr_arena: &Arena<AExpr>) -> bool {
+    let deterministic_node = match lp {
+        // In-memory data and functions can't be identified across runs.
+        IR::DataFrameScan { .. } | IR::Sink { .. } => false,
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => false,
+        IR::Scan {
+            paths, scan_type, ..
+        } => {
+            !matches!(scan_type, FileScan::Anonymous { .. })
+                && !paths.iter().any(|path| is_cloud_url(path))
+        },
+        IR::MapFunction { function, .. } => match function {
+            FunctionNode::Opaque { .. } | FunctionNode::Pipeline { .. } => false,
+            FunctionNode::Count {
+                paths, scan_type, ..
+            } => {
+                !matches!(scan_type, FileScan::Anonymous { .. })
+                    && !paths.iter().any(|path| is_cloud_url(path))
+            },
+            #[cfg(feature = "python")]
+            FunctionNode::OpaquePython { .. } => false,
+            _ => true,
+        },
+        _ => true,
+    };
+    deterministic_node
+        && lp.get_exprs().iter().all(|e| {
+            !has_aexpr_non_deterministic(e.node(), expr_arena)
+                && !has_aexpr(e.node(), expr_arena, |ae| {
+                    matches!(ae, AExpr::Literal(LiteralValue::Series(_)))
+                })
+        })
+}
+
+/// Hash the size and the modification time of a file, which change when the file does.
+fn hash_file_version<H: Hasher>(path: &Path, state: &mut H) -> PolarsResult<()> {
+    let metadata = std::fs::metadata(path)?;
+    metadata.len().hash(state);
+    let modified = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |modified| modified.as_nanos());
+    modified.hash(state);
+    Ok(())
+}
+
+/// A fingerprint of the plan rooted at `root` and of the versions of the files it reads.
+///
+/// Running a plan with the same fingerprint with the same version of Polars produces the
+/// same result, as long as the files don't change within the resolution of their
+/// modification times. Returns `None` if the result isn't determined by the plan and its
+/// files, e.g. because it reads in-memory data, draws random values or runs user-defined
+/// functions.
+///
+/// The fingerprint keys results that are persisted across processes, so it is computed with
+/// xxh3, whose output doesn't change between Rust releases.
+pub fn plan_fingerprint(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Option<u64>> {
+    let mut state = Xxh3::new();
+    env!("CARGO_PKG_VERSION").hash(&mut state);
+    for (node, lp) in lp_arena.iter(root) {
+        if !is_deterministic(lp, expr_arena) {
+            return Ok(None);
+        }
+        IRNode::new(node)
+            .hashable_and_cmp(lp_arena, expr_arena)
+            .hash(&mut state);
+        lp.get_inputs_vec().len().hash(&mut state);
+        let paths = match lp {
+            IR::Scan { paths, .. }
+            | IR::MapFunction {
+                function: FunctionNode::Count { paths, .. },
+                ..
+            } => paths.as_ref(),
+            _ => &[],
+        };
+        for path in paths {
+            hash_file_version(path, &mut state)?;
+        }
+    }
+    // The hashes of the nodes skip some details, e.g. the dtypes of casts, which the
+    // description of the plan and the output schema include.
+    node_to_lp_cloned(root, expr_arena, lp_arena)
+        .describe()
+        .hash(&mut state);
+    format!("{:?}", lp_arena.get(root).schema(lp_arena)).hash(&mut state);
+    Ok(Some(state.finish()))
+}
@@ -243,6 +243,8 @@ impl DslPlan {
                 let name = match payload {
                     SinkType::Memory => "SINK (memory)",
                     SinkType::File { .. } => "SINK (file)",
+                    SinkType::Partitioned { .. } => "SINK (partitioned)",
+                    SinkType::Split { .. } => "SINK (split)",
                     #[cfg(feature = "cloud")]
                     SinkType::Cloud { .. } => "SINK (cloud)",
                 };
@@ -327,9 +329,15 @@ impl Debug for Expr {
                 expr,
                 idx,
                 returns_scalar,
+                out_of_bounds,
             } => {
                 if *returns_scalar {
                     write!(f, "{expr:?}.get({idx:?})")
+                } else if *out_of_bounds != OutOfBoundsPolicy::Error {
+                    write!(
+                        f,
+                        "{expr:?}.gather({idx:?}, out_of_bounds={out_of_bounds:?})"
+                    )
                 } else {
                     write!(f, "{expr:?}.gather({idx:?})")
                 }
@@ -9,7 +9,7 @@ use polars_io::csv::read::count_rows as count_rows_csv;
 #[cfg(all(feature = "parquet", feature = "cloud"))]
 use polars_io::parquet::read::ParquetAsyncReader;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetReader;
+use polars_io::parquet::read::{FileDecryptionProperties, ParquetReader};
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
 #[cfg(feature = "parquet")]
@@ -42,8 +42,13 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
             .unwrap())
         },
         #[cfg(feature = "parquet")]
-        FileScan::Parquet { cloud_options, .. } => {
-            let n_rows = count_rows_parquet(paths, cloud_options.as_ref())?;
+        FileScan::Parquet {
+            cloud_options,
+            decryption,
+            ..
+        } => {
+            let decryption = decryption.as_ref().map(|d| d.0.clone());
+            let n_rows = count_rows_parquet(paths, cloud_options.as_ref(), decryption)?;
             Ok(DataFrame::new(vec![Series::new(
                 crate::constants::LEN,
                 [n_rows as IdxSize],
@@ -66,8 +71,13 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
             .map_err(to_compute_err)?;
             Ok(DataFrame::new(vec![Series::new(crate::constants::LEN, [count])]).unwrap())
         },
-        FileScan::Anonymous { .. } => {
-            unreachable!();
+        FileScan::Anonymous { function, .. } => {
+            let n_rows = function.count_rows()?;
+            Ok(DataFrame::new(vec![Series::new(
+                crate::constants::LEN,
+                [n_rows as IdxSize],
+            )])
+            .unwrap())
         },
     }
 }
@@ -75,6 +85,7 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
 pub(super) fn count_rows_parquet(
     paths: &Arc<[PathBuf]>,
     cloud_options: Option<&CloudOptions>,
+    decryption: Option<Arc<FileDecryptionProperties>>,
 ) -> PolarsResult<usize> {
     if paths.is_empty() {
         return Ok(0);
@@ -87,14 +98,14 @@ pub(super) fn count_rows_parquet(
 
         #[cfg(feature = "cloud")]
         {
-            get_runtime().block_on(count_rows_cloud_parquet(paths, cloud_options))
+            get_runtime().block_on(count_rows_cloud_parquet(paths, cloud_options, decryption))
         }
     } else {
         paths
             .iter()
             .map(|path| {
                 let file = polars_utils::open_file(path)?;
-                let mut reader = ParquetReader::new(file);
+                let mut reader = ParquetReader::new(file).with_decryption(decryption.clone());
                 reader.num_rows()
             })
             .sum::<PolarsResult<usize>>()
@@ -105,12 +116,14 @@ pub(super) fn count_rows_parquet(
 async fn count_rows_cloud_parquet(
     paths: &Arc<[PathBuf]>,
     cloud_options: Option<&CloudOptions>,
+    decryption: Option<Arc<FileDecryptionProperties>>,
 ) -> PolarsResult<usize> {
     let collection = paths.iter().map(|path| {
         with_concurrency_budget(1, || async {
             let mut reader =
                 ParquetAsyncReader::from_uri(&path.to_string_lossy(), cloud_options, None, None)
-                    .await?;
+                    .await?
+                    .with_decryption(decryption.clone());
             reader.num_rows().await
         })
     });
@@ -6,6 +6,8 @@ mod merge_sorted;
 mod python_udf;
 mod rename;
 mod schema;
+#[cfg(feature = "upsample")]
+mod upsample;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -15,6 +17,8 @@ use std::sync::Arc;
 
 pub use dsl::*;
 use polars_core::prelude::*;
+#[cfg(feature = "upsample")]
+use polars_time::Duration;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -101,6 +105,22 @@ pub enum FunctionNode {
         schema: CachedSchema,
         offset: Option<IdxSize>,
     },
+    /// Upsample at a regular frequency, for every group of the `by` columns.
+    #[cfg(feature = "upsample")]
+    Upsample {
+        by: Arc<[Arc<str>]>,
+        time_column: Arc<str>,
+        every: Duration,
+        offset: Duration,
+    },
+    /// Insert the missing rows of a time series, for every group of the `by` columns.
+    #[cfg(feature = "upsample")]
+    Complete {
+        time_column: Arc<str>,
+        every: Duration,
+        by: Arc<[Arc<str>]>,
+        fill: Option<FillNullStrategy>,
+    },
 }
 
 impl Eq for FunctionNode {}
@@ -110,7 +130,18 @@ impl PartialEq for FunctionNode {
         use FunctionNode::*;
         match (self, other) {
             (Rechunk, Rechunk) => true,
-            (Count { paths: paths_l, .. }, Count { paths: paths_r, .. }) => paths_l == paths_r,
+            (
+                Count {
+                    paths: paths_l,
+                    scan_type: scan_type_l,
+                    ..
+                },
+                Count {
+                    paths: paths_r,
+                    scan_type: scan_type_r,
+                    ..
+                },
+            ) => paths_l == paths_r && scan_type_l == scan_type_r,
             (
                 Rename {
                     existing: existing_l,
@@ -128,6 +159,46 @@ impl PartialEq for FunctionNode {
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
             #[cfg(feature = "merge_sorted")]
             (MergeSorted { column: l }, MergeSorted { column: r }) => l == r,
+            #[cfg(feature = "upsample")]
+            (
+                Upsample {
+                    by: by_l,
+                    time_column: time_column_l,
+                    every: every_l,
+                    offset: offset_l,
+                },
+                Upsample {
+                    by: by_r,
+                    time_column: time_column_r,
+                    every: every_r,
+                    offset: offset_r,
+                },
+            ) => {
+                by_l == by_r
+                    && time_column_l == time_column_r
+                    && every_l == every_r
+                    && offset_l == offset_r
+            },
+            #[cfg(feature = "upsample")]
+            (
+                Complete {
+                    time_column: time_column_l,
+                    every: every_l,
+                    by: by_l,
+                    fill: fill_l,
+                },
+                Complete {
+                    time_column: time_column_r,
+                    every: every_r,
+                    by: by_r,
+                    fill: fill_r,
+                },
+            ) => {
+                time_column_l == time_column_r
+                    && every_l == every_r
+                    && by_l == by_r
+                    && fill_l == fill_r
+            },
             _ => false,
         }
     }
@@ -173,6 +244,30 @@ impl Hash for FunctionNode {
                 name.hash(state);
                 offset.hash(state);
             },
+            #[cfg(feature = "upsample")]
+            FunctionNode::Upsample {
+                by,
+                time_column,
+                every,
+                offset,
+            } => {
+                by.hash(state);
+                time_column.hash(state);
+                every.hash(state);
+                offset.hash(state);
+            },
+            #[cfg(feature = "upsample")]
+            FunctionNode::Complete {
+                time_column,
+                every,
+                by,
+                fill,
+            } => {
+                time_column.hash(state);
+                every.hash(state);
+                by.hash(state);
+                fill.hash(state);
+            },
         }
     }
 }
@@ -191,6 +286,9 @@ impl FunctionNode {
             #[cfg(feature = "python")]
             OpaquePython { streamable, .. } => *streamable,
             RowIndex { .. } => false,
+            // The rows are completed across batches, the input is streamed up to here.
+            #[cfg(feature = "upsample")]
+            Upsample { .. } | Complete { .. } => false,
         }
     }
 
@@ -201,6 +299,8 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             Explode { .. } | Melt { .. } => true,
+            #[cfg(feature = "upsample")]
+            Upsample { .. } | Complete { .. } => true,
             _ => false,
         }
     }
@@ -215,6 +315,9 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             RowIndex { .. } | Count { .. } => false,
+            // The inserted rows depend on all of the rows of a group.
+            #[cfg(feature = "upsample")]
+            Upsample { .. } | Complete { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -234,6 +337,8 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             RowIndex { .. } => true,
+            #[cfg(feature = "upsample")]
+            Upsample { .. } | Complete { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -245,6 +350,17 @@ impl FunctionNode {
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
+            #[cfg(feature = "upsample")]
+            Upsample {
+                by, time_column, ..
+            }
+            | Complete {
+                by, time_column, ..
+            } => {
+                let mut columns = vec![time_column.clone()];
+                columns.extend(by.iter().cloned());
+                Cow::Owned(columns)
+            },
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -299,6 +415,20 @@ impl FunctionNode {
                 df.melt2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.as_ref(), *offset),
+            #[cfg(feature = "upsample")]
+            Upsample {
+                by,
+                time_column,
+                every,
+                offset,
+            } => upsample::upsample(df, by, time_column, *every, *offset),
+            #[cfg(feature = "upsample")]
+            Complete {
+                time_column,
+                every,
+                by,
+                fill,
+            } => upsample::complete(df, time_column, *every, by, *fill),
         }
     }
 }
@@ -339,6 +469,10 @@ impl Display for FunctionNode {
             Explode { .. } => write!(f, "EXPLODE"),
             Melt { .. } => write!(f, "MELT"),
             RowIndex { .. } => write!(f, "WITH ROW INDEX"),
+            #[cfg(feature = "upsample")]
+            Upsample { .. } => write!(f, "UPSAMPLE"),
+            #[cfg(feature = "upsample")]
+            Complete { .. } => write!(f, "COMPLETE"),
         }
     }
 }
@@ -99,6 +99,13 @@ impl FunctionNode {
             },
             Explode { schema, columns } => explode_schema(schema, input_schema, columns),
             Melt { schema, args } => melt_schema(args, schema, input_schema),
+            #[cfg(feature = "upsample")]
+            Upsample { time_column, .. } => Ok(Cow::Owned(upsample::upsample_schema(
+                input_schema,
+                time_column,
+            )?)),
+            #[cfg(feature = "upsample")]
+            Complete { .. } => Ok(Cow::Borrowed(input_schema)),
         }
     }
 }
@@ -0,0 +1,107 @@
+use polars_core::series::IsSorted;
+use polars_time::{Duration, PolarsUpsample};
+
+use super::*;
+
+/// Marks the rows of a group that existed before it was completed; it is null in the
+/// inserted rows.
+const EXISTING_ROW: &str = "__POLARS_COMPLETE_EXISTING_ROW";
+
+/// Sort `df` by `time_column`, unless it is known to be sorted, as upsampling requires.
+fn sort_by_time(df: DataFrame, time_column: &str) -> PolarsResult<DataFrame> {
+    if df.column(time_column)?.is_sorted_flag() == IsSorted::Ascending {
+        return Ok(df);
+    }
+    df.sort(
+        [time_column],
+        SortMultipleOptions::default().with_maintain_order(true),
+    )
+}
+
+pub(super) fn upsample(
+    df: DataFrame,
+    by: &[Arc<str>],
+    time_column: &str,
+    every: Duration,
+    offset: Duration,
+) -> PolarsResult<DataFrame> {
+    let df = sort_by_time(df, time_column)?;
+    let by = by.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    df.upsample_stable(by, time_column, every, offset)
+}
+
+/// The output schema of [`FunctionNode::Upsample`]: the time column becomes the first
+/// column.
+pub(super) fn upsample_schema(
+    input_schema: &SchemaRef,
+    time_column: &str,
+) -> PolarsResult<SchemaRef> {
+    let dtype = input_schema.try_get(time_column)?.clone();
+    let schema = input_schema.new_inserting_at_index(0, time_column.into(), dtype)?;
+    Ok(Arc::new(schema))
+}
+
+/// Insert the missing rows of a single group, whose keys are the `by` columns.
+fn complete_group(
+    group: DataFrame,
+    time_column: &str,
+    every: Duration,
+    by: &[Arc<str>],
+    fill: Option<FillNullStrategy>,
+) -> PolarsResult<DataFrame> {
+    let names = group.get_column_names_owned();
+    let keys = group.select(by)?.head(Some(1));
+    let mut group = sort_by_time(group.drop_many(by), time_column)?;
+    group.with_column(BooleanChunked::full(EXISTING_ROW, true, group.height()))?;
+    let mut out = group.upsample(
+        Vec::<String>::new(),
+        time_column,
+        every,
+        Duration::parse("0ns"),
+    )?;
+
+    // The inserted rows belong to the group, so they get its keys.
+    for key in keys.get_columns() {
+        out.with_column(key.new_from_index(0, out.height()))?;
+    }
+    if let Some(strategy) = fill {
+        // Only the inserted rows are filled, the nulls of the existing rows are kept.
+        let inserted = out.column(EXISTING_ROW)?.is_null();
+        let columns = out
+            .get_columns()
+            .iter()
+            .map(|s| {
+                if s.name() == time_column
+                    || s.name() == EXISTING_ROW
+                    || by.iter().any(|key| key.as_ref() == s.name())
+                {
+                    Ok(s.clone())
+                } else {
+                    // Keep the type of the column, e.g. the mean of integers.
+                    let filled = s.fill_null(strategy)?.cast(s.dtype())?;
+                    filled.zip_with(&inserted, s)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        out = DataFrame::new(columns)?;
+    }
+    out.select(names)
+}
+
+pub(super) fn complete(
+    df: DataFrame,
+    time_column: &str,
+    every: Duration,
+    by: &[Arc<str>],
+    fill: Option<FillNullStrategy>,
+) -> PolarsResult<DataFrame> {
+    if df.height() == 0 {
+        return Ok(df);
+    }
+    if by.is_empty() {
+        complete_group(df, time_column, every, by, fill)
+    } else {
+        df.group_by_stable(by)?
+            .apply(|group| complete_group(group, time_column, every, by, fill))
+    }
+}
@@ -63,13 +63,15 @@ impl HivePartitions {
             None => Arc::new(partitions.as_slice().into()),
         };
 
+        // The partition values hold for every row of the file, so they are the statistics of a
+        // single row. This lets null count statistics prune `is_null`/`is_not_null` predicates.
         let stats = BatchStats::new(
             schema,
             partitions
                 .into_iter()
                 .map(ColumnStats::from_column_literal)
                 .collect(),
-            None,
+            Some(1),
         );
 
         Ok(Some(HivePartitions { stats }))
@@ -0,0 +1,273 @@
+use std::collections::BTreeSet;
+
+use polars_core::prelude::*;
+use recursive::recursive;
+
+use crate::prelude::*;
+
+/// A column of a source of a plan, e.g. a column of a scanned file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceColumn {
+    /// The path of the scanned file, or a description of a source that is not a file, e.g.
+    /// `"DataFrame"` for an in-memory frame.
+    pub source: String,
+    pub column: String,
+}
+
+/// The lineage of an output column of a plan, see [`column_lineage`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnLineage {
+    pub column: String,
+    /// The source columns that the values of the column are computed from.
+    pub sources: BTreeSet<SourceColumn>,
+    /// The expressions that are applied to the source columns, from the sources to the
+    /// output.
+    pub expressions: Vec<String>,
+}
+
+impl ColumnLineage {
+    fn with_name(&self, column: &str) -> Self {
+        Self {
+            column: column.to_string(),
+            ..self.clone()
+        }
+    }
+
+    /// Add the sources and the expressions of `other`.
+    fn merge(&mut self, other: &Self) {
+        self.sources.extend(other.sources.iter().cloned());
+        for expr in &other.expressions {
+            self.push_expression(expr);
+        }
+    }
+
+    fn push_expression(&mut self, expr: &str) {
+        if !self.expressions.iter().any(|e| e == expr) {
+            self.expressions.push(expr.to_string())
+        }
+    }
+}
+
+/// The lineage of the columns of the output of a node, by name.
+type Lineages = PlIndexMap<SmartString, ColumnLineage>;
+
+struct LineageVisitor<'a> {
+    lp_arena: &'a Arena<IR>,
+    expr_arena: &'a Arena<AExpr>,
+}
+
+impl LineageVisitor<'_> {
+    fn sources(&self, schema: &Schema, sources: &[String]) -> Lineages {
+        schema
+            .iter_names()
+            .map(|name| {
+                let sources = sources
+                    .iter()
+                    .map(|source| SourceColumn {
+                        source: source.clone(),
+                        column: name.to_string(),
+                    })
+                    .collect();
+                let lineage = ColumnLineage {
+                    column: name.to_string(),
+                    sources,
+                    expressions: vec![],
+                };
+                (name.clone(), lineage)
+            })
+            .collect()
+    }
+
+    /// The lineage of an expression evaluated on a frame with the columns of `input`.
+    fn expr(&self, e: &ExprIR, input: &Lineages) -> ColumnLineage {
+        let name = e.output_name();
+        let mut lineage = ColumnLineage {
+            column: name.to_string(),
+            ..Default::default()
+        };
+        for leaf in aexpr_to_leaf_names_iter(e.node(), self.expr_arena) {
+            if let Some(input) = input.get(leaf.as_ref()) {
+                lineage.merge(input);
+            }
+        }
+        // Columns that are passed through unchanged don't add an expression.
+        if !matches!(self.expr_arena.get(e.node()), AExpr::Column(column) if column.as_ref() == name)
+        {
+            let expr = node_to_expr(e.node(), self.expr_arena);
+            let expr = match e.get_alias() {
+                Some(alias) => expr.alias(alias),
+                None => expr,
+            };
+            lineage.push_expression(&format!("{expr}"));
+        }
+        lineage
+    }
+
+    fn exprs(&self, exprs: &[ExprIR], input: &Lineages) -> Lineages {
+        exprs
+            .iter()
+            .map(|e| (e.output_name().into(), self.expr(e, input)))
+            .collect()
+    }
+
+    /// Every output column is derived from every input column.
+    fn opaque(&self, schema: &Schema, inputs: &[Lineages], function: &str) -> Lineages {
+        let mut all = ColumnLineage::default();
+        for input in inputs {
+            for lineage in input.values() {
+                all.merge(lineage);
+            }
+        }
+        all.push_expression(function);
+        schema
+            .iter_names()
+            .map(|name| (name.clone(), all.with_name(name)))
+            .collect()
+    }
+
+    #[recursive]
+    fn visit(&self, node: Node) -> Lineages {
+        use IR::*;
+        let lp = self.lp_arena.get(node);
+        let inputs = lp
+            .get_inputs_vec()
+            .into_iter()
+            .map(|input| self.visit(input))
+            .collect::<Vec<_>>();
+        let schema = lp.schema(self.lp_arena);
+        // The columns that are passed through by name.
+        let from_inputs = || {
+            schema
+                .iter_names()
+                .filter_map(|name| {
+                    let lineage = inputs.iter().find_map(|input| input.get(name))?;
+                    Some((name.clone(), lineage.clone()))
+                })
+                .collect::<Lineages>()
+        };
+
+        match lp {
+            Scan {
+                paths, scan_type, ..
+            } => {
+                let sources = match scan_type {
+                    FileScan::Anonymous { options, .. } => vec![options.fmt_str.to_string()],
+                    _ => paths
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect(),
+                };
+                self.sources(&schema, &sources)
+            },
+            DataFrameScan { .. } => self.sources(&schema, &["DataFrame".to_string()]),
+            #[cfg(feature = "python")]
+            PythonScan { .. } => self.sources(&schema, &["python scan".to_string()]),
+            Select { expr, .. } => self.exprs(expr, &inputs[0]),
+            HStack { exprs, .. } => {
+                let mut lineages = inputs[0].clone();
+                lineages.extend(self.exprs(exprs, &inputs[0]));
+                lineages
+            },
+            GroupBy {
+                keys, aggs, apply, ..
+            } => match apply {
+                Some(_) => self.opaque(&schema, &inputs, "group_by().apply()"),
+                None => {
+                    let mut lineages = self.exprs(keys, &inputs[0]);
+                    lineages.extend(self.exprs(aggs, &inputs[0]));
+                    lineages
+                },
+            },
+            Union { .. } => {
+                // The values of a column come from the columns of the same name of all inputs.
+                schema
+                    .iter_names()
+                    .map(|name| {
+                        let mut lineage = ColumnLineage {
+                            column: name.to_string(),
+                            ..Default::default()
+                        };
+                        for input in inputs.iter().filter_map(|input| input.get(name)) {
+                            lineage.merge(input);
+                        }
+                        (name.clone(), lineage)
+                    })
+                    .collect()
+            },
+            Join {
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                let (left, right) = (&inputs[0], &inputs[1]);
+                let suffix = options.args.suffix();
+                let mut lineages = schema
+                    .iter_names()
+                    .filter_map(|name| {
+                        let lineage = left
+                            .get(name)
+                            .or_else(|| right.get(name))
+                            .or_else(|| right.get(name.strip_suffix(suffix)?))?;
+                        Some((name.clone(), lineage.with_name(name)))
+                    })
+                    .collect::<Lineages>();
+                // The values of coalesced keys come from both sides.
+                if options.args.coalesce.coalesce(&options.args.how) {
+                    for (left_key, right_key) in left_on.iter().zip(right_on) {
+                        if let Some(lineage) = lineages.get_mut(left_key.output_name()) {
+                            lineage.merge(&self.expr(right_key, right));
+                        }
+                    }
+                }
+                lineages
+            },
+            MapFunction { function, .. } => match function {
+                FunctionNode::Rename { existing, new, .. } => {
+                    let mut lineages = inputs[0].clone();
+                    for (existing, new) in existing.iter().zip(new.iter()) {
+                        if let Some(lineage) = inputs[0].get(existing) {
+                            lineages.insert(new.clone(), lineage.with_name(new));
+                        }
+                    }
+                    schema
+                        .iter_names()
+                        .filter_map(|name| Some((name.clone(), lineages.get(name)?.clone())))
+                        .collect()
+                },
+                FunctionNode::Explode { .. } | FunctionNode::Rechunk => from_inputs(),
+                _ => self.opaque(&schema, &inputs, &format!("{function}")),
+            },
+            _ => from_inputs(),
+        }
+    }
+}
+
+/// Extract the lineage of every output column of the plan rooted at `root`: the source
+/// columns it is computed from and the expressions that are applied along the way.
+///
+/// The lineage follows the values of the columns, the columns that are only used to filter,
+/// sort or join rows are not included. The outputs of user-defined functions on frames
+/// are taken to depend on all of their input columns.
+pub fn column_lineage(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<ColumnLineage> {
+    let visitor = LineageVisitor {
+        lp_arena,
+        expr_arena,
+    };
+    let lineages = visitor.visit(root);
+    let schema = lp_arena.get(root).schema(lp_arena);
+    schema
+        .iter_names()
+        .map(|name| match lineages.get(name) {
+            Some(lineage) => lineage.with_name(name),
+            None => ColumnLineage {
+                column: name.to_string(),
+                ..Default::default()
+            },
+        })
+        .collect()
+}
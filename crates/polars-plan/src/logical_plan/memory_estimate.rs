@@ -0,0 +1,270 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+use recursive::recursive;
+
+use crate::prelude::*;
+
+/// The assumed size of a value of a variable-width type, e.g. a string, in bytes.
+const VARIABLE_WIDTH: f64 = 32.0;
+/// The assumed number of values of a list.
+const LIST_LENGTH: f64 = 4.0;
+
+/// The estimated memory use of a node of a plan, see [`estimate_memory`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryEstimate {
+    /// The name of the node, as shown in the plan.
+    pub node: &'static str,
+    /// The depth of the node in the plan, the root being at depth 0.
+    pub depth: usize,
+    /// The estimated number of rows that the node produces.
+    pub rows: Option<u64>,
+    /// The estimated size of the output of the node in bytes.
+    pub output_bytes: Option<u64>,
+    /// The estimated peak memory of the node in bytes: its inputs and its output.
+    pub peak_bytes: Option<u64>,
+    /// Whether the node is expected to spill to disk: it holds all of its input at once and
+    /// its peak memory exceeds the budget.
+    pub spills: bool,
+}
+
+fn format_bytes(bytes: Option<u64>) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let Some(bytes) = bytes else {
+        return "?".to_string();
+    };
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+impl Display for MemoryEstimate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows = self.rows.map_or("?".to_string(), |rows| rows.to_string());
+        write!(
+            f,
+            "{:indent$}{}: rows {}, output {}, peak {}",
+            "",
+            self.node,
+            rows,
+            format_bytes(self.output_bytes),
+            format_bytes(self.peak_bytes),
+            indent = 2 * self.depth
+        )?;
+        if self.spills {
+            write!(f, " [spills]")?;
+        }
+        Ok(())
+    }
+}
+
+/// The assumed size of a value of `dtype` in bytes.
+fn dtype_width(dtype: &DataType) -> f64 {
+    use DataType::*;
+    match dtype {
+        Boolean => 0.125,
+        Null => 0.0,
+        String | Binary | BinaryOffset => VARIABLE_WIDTH,
+        List(inner) => 8.0 + LIST_LENGTH * dtype_width(inner),
+        #[cfg(feature = "dtype-array")]
+        Array(inner, width) => *width as f64 * dtype_width(inner),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => fields
+            .iter()
+            .map(|field| dtype_width(field.data_type()))
+            .sum(),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => 16.0,
+        dtype => match dtype.to_physical() {
+            Int8 | UInt8 => 1.0,
+            Int16 | UInt16 => 2.0,
+            Int32 | UInt32 | Float32 => 4.0,
+            _ => 8.0,
+        },
+    }
+}
+
+/// The estimated rows and the bytes per row of every column of the output of a node.
+struct NodeStats {
+    rows: Option<f64>,
+    widths: PlHashMap<SmartString, f64>,
+}
+
+impl NodeStats {
+    fn bytes(&self) -> Option<f64> {
+        Some(self.rows? * self.widths.values().sum::<f64>())
+    }
+}
+
+struct MemoryEstimator<'a> {
+    lp_arena: &'a Arena<IR>,
+    budget: u64,
+    estimates: Vec<MemoryEstimate>,
+}
+
+impl MemoryEstimator<'_> {
+    /// The widths of the columns of `schema`, taken from the inputs of the node if they hold
+    /// a column of the same name.
+    fn widths(schema: &Schema, inputs: &[NodeStats]) -> PlHashMap<SmartString, f64> {
+        schema
+            .iter()
+            .map(|(name, dtype)| {
+                let width = inputs
+                    .iter()
+                    .find_map(|input| input.widths.get(name).copied())
+                    .unwrap_or_else(|| dtype_width(dtype));
+                (name.clone(), width)
+            })
+            .collect()
+    }
+
+    /// The widths of the columns of a scan, taken from the file statistics if available.
+    fn scan_widths(lp: &IR, schema: &Schema) -> PlHashMap<SmartString, f64> {
+        let mut widths = Self::widths(schema, &[]);
+        match lp {
+            IR::DataFrameScan { df, .. } if df.height() > 0 => {
+                for s in df.get_columns() {
+                    if let Some(width) = widths.get_mut(s.name()) {
+                        *width = s.estimated_size() as f64 / df.height() as f64;
+                    }
+                }
+            },
+            #[cfg(feature = "parquet")]
+            IR::Scan {
+                scan_type:
+                    FileScan::Parquet {
+                        metadata: Some(metadata),
+                        ..
+                    },
+                ..
+            } if metadata.num_rows > 0 => {
+                // The uncompressed size of the columns approximates their size in memory.
+                let mut sizes = PlHashMap::<&str, f64>::new();
+                for rg in &metadata.row_groups {
+                    for column in rg.columns() {
+                        *sizes
+                            .entry(column.descriptor().path_in_schema[0].as_str())
+                            .or_default() += column.uncompressed_size() as f64;
+                    }
+                }
+                for (name, width) in widths.iter_mut() {
+                    if let Some(size) = sizes.get(name.as_str()) {
+                        *width = size / metadata.num_rows as f64;
+                    }
+                }
+            },
+            _ => {},
+        }
+        widths
+    }
+
+    fn rows(lp: &IR, inputs: &[NodeStats]) -> Option<f64> {
+        use IR::*;
+        let input_rows = || inputs.first()?.rows;
+        match lp {
+            DataFrameScan { df, .. } => Some(df.height() as f64),
+            Scan {
+                file_info,
+                file_options,
+                ..
+            } => {
+                let rows = match file_info.row_estimation {
+                    (Some(known), _) => known,
+                    (None, estimated) if estimated != usize::MAX => estimated,
+                    _ => return None,
+                };
+                Some(file_options.n_rows.map_or(rows, |n_rows| rows.min(n_rows)) as f64)
+            },
+            Slice { len, .. } | TopK { len, .. } => Some(input_rows()?.min(*len as f64)),
+            GroupBy { keys, .. } if keys.is_empty() => Some(1.0),
+            Join { options, .. } => {
+                let (left, right) = (inputs[0].rows?, inputs[1].rows?);
+                match options.args.how {
+                    JoinType::Cross => Some(left * right),
+                    #[cfg(feature = "semi_anti_join")]
+                    JoinType::Semi | JoinType::Anti => Some(left),
+                    _ => Some(left.max(right)),
+                }
+            },
+            Union { .. } => inputs.iter().map(|input| input.rows).sum(),
+            HConcat { .. } => inputs
+                .iter()
+                .map(|input| input.rows)
+                .try_fold(0.0, |acc: f64, rows| Some(acc.max(rows?))),
+            // Filters are assumed to keep all rows, so that the estimate errs on the high side.
+            _ => input_rows(),
+        }
+    }
+
+    #[recursive]
+    fn visit(&mut self, node: Node, depth: usize) -> NodeStats {
+        let lp = self.lp_arena.get(node);
+        let idx = self.estimates.len();
+        self.estimates.push(MemoryEstimate {
+            node: lp.name(),
+            depth,
+            rows: None,
+            output_bytes: None,
+            peak_bytes: None,
+            spills: false,
+        });
+        let inputs = lp
+            .get_inputs_vec()
+            .into_iter()
+            .map(|input| self.visit(input, depth + 1))
+            .collect::<Vec<_>>();
+
+        let schema = lp.schema(self.lp_arena);
+        let widths = match lp {
+            IR::DataFrameScan { .. } | IR::Scan { .. } => Self::scan_widths(lp, &schema),
+            _ => Self::widths(&schema, &inputs),
+        };
+        let stats = NodeStats {
+            rows: Self::rows(lp, &inputs),
+            widths,
+        };
+        let output_bytes = stats.bytes();
+        let peak_bytes = inputs
+            .iter()
+            .map(|input| input.bytes())
+            .sum::<Option<f64>>()
+            .zip(output_bytes)
+            .map(|(inputs, output)| inputs + output);
+        // These nodes hold all of their input before they produce any output.
+        let is_blocking = matches!(
+            lp,
+            IR::Sort { .. } | IR::GroupBy { .. } | IR::Join { .. } | IR::Distinct { .. }
+        );
+
+        let estimate = &mut self.estimates[idx];
+        estimate.rows = stats.rows.map(|rows| rows as u64);
+        estimate.output_bytes = output_bytes.map(|bytes| bytes as u64);
+        estimate.peak_bytes = peak_bytes.map(|bytes| bytes as u64);
+        estimate.spills = is_blocking && peak_bytes.is_some_and(|peak| peak > self.budget as f64);
+        stats
+    }
+}
+
+/// Estimate the rows, the output size and the peak memory of every node of the plan rooted at
+/// `root`, in pre-order. Nodes that hold all of their input at once, e.g. sorts and group-bys,
+/// are flagged to spill if their peak memory exceeds `budget` bytes.
+///
+/// The rows are estimated from the sizes of the sources, the bytes per row from the data of
+/// in-memory frames, the statistics of Parquet files and otherwise from the dtypes.
+pub fn estimate_memory(root: Node, lp_arena: &Arena<IR>, budget: u64) -> Vec<MemoryEstimate> {
+    let mut estimator = MemoryEstimator {
+        lp_arena,
+        budget,
+        estimates: vec![],
+    };
+    estimator.visit(root, 0);
+    estimator.estimates
+}
@@ -22,11 +22,14 @@ pub(crate) mod debug;
 pub(crate) mod expr_expansion;
 pub mod expr_ir;
 mod file_scan;
+mod fingerprint;
 mod format;
 mod functions;
 pub(super) mod hive;
 pub(crate) mod iterator;
+mod lineage;
 mod lit;
+mod memory_estimate;
 pub(crate) mod optimizer;
 pub(crate) mod options;
 mod projection_expr;
@@ -45,9 +48,12 @@ pub use builder_ir::*;
 pub use conversion::*;
 pub(crate) use expr_ir::*;
 pub use file_scan::*;
+pub use fingerprint::*;
 pub use functions::*;
 pub use iterator::*;
+pub use lineage::*;
 pub use lit::*;
+pub use memory_estimate::*;
 pub use optimizer::*;
 pub use schema::*;
 #[cfg(feature = "serde")]
@@ -234,7 +240,14 @@ impl DslPlan {
         let mut lp_arena = Arena::with_capacity(16);
         let mut expr_arena = Arena::with_capacity(16);
 
-        let node = to_alp(self, &mut expr_arena, &mut lp_arena, true, true)?;
+        let node = to_alp(
+            self,
+            &mut expr_arena,
+            &mut lp_arena,
+            true,
+            true,
+            CastPolicy::default(),
+        )?;
 
         Ok((node, lp_arena, expr_arena))
     }
@@ -28,6 +28,10 @@ impl SimpleProjectionAndCollapse {
 }
 
 impl OptimizationRule for SimpleProjectionAndCollapse {
+    fn name(&self) -> &'static str {
+        "simple_projection"
+    }
+
     fn optimize_plan(
         &mut self,
         lp_arena: &mut Arena<IR>,
@@ -143,6 +147,26 @@ impl OptimizationRule for SimpleProjectionAndCollapse {
                 }),
                 _ => None,
             },
+            TopK {
+                input,
+                by_column,
+                offset,
+                len,
+                sort_options,
+            } => match lp_arena.get(*input) {
+                Sort {
+                    input: inner,
+                    slice: None,
+                    ..
+                } => Some(TopK {
+                    input: *inner,
+                    by_column: by_column.clone(),
+                    offset: *offset,
+                    len: *len,
+                    sort_options: sort_options.clone(),
+                }),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -1,6 +1,8 @@
+use std::any::Any;
 use std::path::PathBuf;
 
 use super::*;
+use crate::logical_plan::optimizer::predicate_pushdown::HiveEval;
 
 pub(super) struct CountStar;
 
@@ -11,6 +13,10 @@ impl CountStar {
 }
 
 impl OptimizationRule for CountStar {
+    fn name(&self) -> &'static str {
+        "count_star"
+    }
+
     // Replace select count(*) from datasource with specialized map function.
     fn optimize_plan(
         &mut self,
@@ -46,6 +52,251 @@ impl OptimizationRule for CountStar {
     }
 }
 
+/// Replace `select(len())` from a scan with a predicate that only refers to hive partition
+/// columns with a count over the files for which that predicate holds.
+///
+/// The hive partition values are constant within a file, so the predicate is evaluated
+/// exactly once per file and the surviving files are counted from their metadata.
+/// This must run after predicate pushdown has moved the filter into the scan.
+pub(super) fn hive_filtered_count_star(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    hive_partition_eval: HiveEval<'_>,
+) -> PolarsResult<()> {
+    let Some(hive_partition_eval) = hive_partition_eval else {
+        return Ok(());
+    };
+    let IR::Select { input, expr, .. } = lp_arena.get(node) else {
+        return Ok(());
+    };
+    if expr.len() != 1 {
+        return Ok(());
+    }
+    let (valid, alias) = is_valid_count_expr(&expr[0], expr_arena);
+    if !valid {
+        return Ok(());
+    }
+    let IR::Scan {
+        paths,
+        file_info,
+        predicate: Some(predicate),
+        scan_type,
+        file_options,
+        ..
+    } = lp_arena.get(*input)
+    else {
+        return Ok(());
+    };
+    if matches!(scan_type, FileScan::Anonymous { .. })
+        || file_options.n_rows.is_some()
+        || file_options.row_index.is_some()
+    {
+        return Ok(());
+    }
+    let Some(hive_parts) = file_info.hive_parts.as_deref() else {
+        return Ok(());
+    };
+
+    let hive_schema = hive_parts.schema();
+    let mut leaf_names = aexpr_to_leaf_names_iter(predicate.node(), expr_arena).peekable();
+    if leaf_names.peek().is_none() || !leaf_names.all(|name| hive_schema.contains(&name)) {
+        return Ok(());
+    }
+    let Some(io_expr) = hive_partition_eval(predicate, expr_arena) else {
+        return Ok(());
+    };
+
+    let mut file_info = file_info.clone();
+    let mut new_paths = Vec::with_capacity(paths.len());
+    for path in paths.iter() {
+        file_info.update_hive_partitions(path)?;
+        let hive_parts = file_info.hive_parts.as_deref().unwrap();
+        let df = DataFrame::new(hive_parts.materialize_partition_columns())?;
+        let mask = io_expr.evaluate_io(&df)?;
+        if mask.bool()?.get(0).unwrap_or(false) {
+            new_paths.push(path.clone());
+        }
+    }
+
+    let mut scan_type = scan_type.clone();
+    if new_paths.len() != paths.len() {
+        scan_type.remove_metadata();
+    }
+
+    let placeholder = IR::DataFrameScan {
+        df: Arc::new(Default::default()),
+        schema: Arc::new(Default::default()),
+        output_schema: None,
+        projection: None,
+        selection: None,
+    };
+    let placeholder_node = lp_arena.add(placeholder);
+    lp_arena.replace(
+        node,
+        IR::MapFunction {
+            input: placeholder_node,
+            function: FunctionNode::Count {
+                paths: new_paths.into(),
+                scan_type,
+                alias,
+            },
+        },
+    );
+    Ok(())
+}
+
+/// Replace `select(len())` from a parquet scan with a predicate that the statistics of the row
+/// groups decide with the number of rows for which it holds.
+///
+/// A row group has no matching rows if its statistics rule them all out, and only matching
+/// rows if they rule out all rows of the complement of the predicate, and the columns of the
+/// predicate have no nulls in it. If any row group of any file isn't decided, the scan is kept.
+/// This must run after predicate pushdown has moved the filter into the scan.
+#[cfg(feature = "parquet")]
+pub(super) fn statistics_filtered_count_star(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    hive_partition_eval: HiveEval<'_>,
+) -> PolarsResult<()> {
+    use polars_io::parquet::read::ParquetReader;
+    use polars_io::utils::is_cloud_url;
+    use polars_io::SerReader;
+
+    let Some(hive_partition_eval) = hive_partition_eval else {
+        return Ok(());
+    };
+    let IR::Select { input, expr, .. } = lp_arena.get(node) else {
+        return Ok(());
+    };
+    if expr.len() != 1 {
+        return Ok(());
+    }
+    let (valid, alias) = is_valid_count_expr(&expr[0], expr_arena);
+    if !valid {
+        return Ok(());
+    }
+    let IR::Scan {
+        paths,
+        file_info,
+        predicate: Some(predicate),
+        scan_type: FileScan::Parquet { decryption, .. },
+        file_options,
+        ..
+    } = lp_arena.get(*input)
+    else {
+        return Ok(());
+    };
+    if file_options.n_rows.is_some()
+        || file_options.row_index.is_some()
+        || paths.iter().any(|path| is_cloud_url(path))
+    {
+        return Ok(());
+    }
+
+    // The predicate must only refer to columns of the files, whose statistics are exact.
+    let hive_schema = file_info.hive_parts.as_deref().map(|parts| parts.schema());
+    let has_exact_statistics = |name: &Arc<str>| {
+        hive_schema.map_or(true, |schema| !schema.contains(name))
+            && file_info.schema.get(name).map_or(false, has_exact_min_max)
+    };
+    if !aexpr_to_leaf_names_iter(predicate.node(), expr_arena)
+        .all(|name| has_exact_statistics(&name))
+    {
+        return Ok(());
+    }
+    let predicate = predicate.clone();
+    let paths = paths.clone();
+    let decryption = decryption.as_ref().map(|d| d.0.clone());
+    let Some(complement) = complement(predicate.node(), expr_arena) else {
+        return Ok(());
+    };
+    let complement = ExprIR::from_node(complement, expr_arena);
+    let (Some(predicate), Some(complement)) = (
+        hive_partition_eval(&predicate, expr_arena),
+        hive_partition_eval(&complement, expr_arena),
+    ) else {
+        return Ok(());
+    };
+
+    let mut num_rows = 0;
+    for path in paths.iter() {
+        let file = polars_utils::open_file(path)?;
+        let mut reader = ParquetReader::new(file).with_decryption(decryption.clone());
+        match reader.num_rows_with_predicate(predicate.as_ref(), complement.as_ref())? {
+            Some(n) => num_rows += n,
+            None => return Ok(()),
+        }
+    }
+
+    let name = alias.as_deref().unwrap_or(crate::constants::LEN);
+    let df = DataFrame::new(vec![Series::new(name, [num_rows as IdxSize])])?;
+    let schema = Arc::new(df.schema());
+    lp_arena.replace(
+        node,
+        IR::DataFrameScan {
+            df: Arc::new(df),
+            schema,
+            output_schema: None,
+            projection: None,
+            selection: None,
+        },
+    );
+    Ok(())
+}
+
+/// Whether the minimum and maximum in the statistics of a column bound all of its values.
+/// Those of floats don't include NaN, which compares greater than all other values.
+#[cfg(feature = "parquet")]
+fn has_exact_min_max(dtype: &DataType) -> bool {
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(dtype, DataType::String | DataType::Boolean)
+}
+
+/// The complement of a predicate that compares columns with non-null literals, combined with
+/// `&` and `|`: it holds exactly for the rows without nulls for which the predicate doesn't.
+#[cfg(feature = "parquet")]
+fn complement(node: Node, expr_arena: &mut Arena<AExpr>) -> Option<Node> {
+    use Operator::*;
+
+    let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node).clone() else {
+        return None;
+    };
+    let op = match op {
+        And | LogicalAnd | Or | LogicalOr => {
+            let left = complement(left, expr_arena)?;
+            let right = complement(right, expr_arena)?;
+            let op = if matches!(op, And | LogicalAnd) {
+                Or
+            } else {
+                And
+            };
+            return Some(expr_arena.add(AExpr::BinaryExpr { left, op, right }));
+        },
+        Eq => NotEq,
+        NotEq => Eq,
+        Lt => GtEq,
+        LtEq => Gt,
+        Gt => LtEq,
+        GtEq => Lt,
+        _ => return None,
+    };
+    let is_operand = |node: Node| match expr_arena.get(node) {
+        AExpr::Column(_) => true,
+        AExpr::Literal(lv) => !matches!(
+            lv,
+            LiteralValue::Null | LiteralValue::Series(_) | LiteralValue::Range { .. }
+        ),
+        _ => false,
+    };
+    if !(is_operand(left) && is_operand(right)) {
+        return None;
+    }
+    Some(expr_arena.add(AExpr::BinaryExpr { left, op, right }))
+}
+
 struct CountStarExpr {
     // Top node of the projection to replace
     node: Node,
@@ -69,10 +320,14 @@ fn visit_logical_plan_for_scan_paths(
         IR::Union { inputs, .. } => {
             let mut scan_type: Option<FileScan> = None;
             let mut paths = Vec::with_capacity(inputs.len());
+            let mut anonymous_scans = vec![];
             for input in inputs {
                 match visit_logical_plan_for_scan_paths(*input, lp_arena, expr_arena, true) {
                     Some(expr) => {
                         paths.extend(expr.paths.iter().cloned());
+                        if let FileScan::Anonymous { function, .. } = &expr.scan_type {
+                            anonymous_scans.push(function.clone());
+                        }
                         match &scan_type {
                             None => scan_type = Some(expr.scan_type),
                             Some(scan_type) => {
@@ -88,16 +343,27 @@ fn visit_logical_plan_for_scan_paths(
                     None => return None,
                 }
             }
+            // Anonymous scans don't have paths, every scan counts its own rows.
+            let scan_type = match scan_type.unwrap() {
+                FileScan::Anonymous { options, .. } => FileScan::Anonymous {
+                    options,
+                    function: Arc::new(UnionCount(anonymous_scans)),
+                },
+                scan_type => scan_type,
+            };
             Some(CountStarExpr {
                 paths: paths.into(),
-                scan_type: scan_type.unwrap(),
+                scan_type,
                 node,
                 alias: None,
             })
         },
         IR::Scan {
-            scan_type, paths, ..
-        } if !matches!(scan_type, FileScan::Anonymous { .. }) => Some(CountStarExpr {
+            scan_type,
+            paths,
+            file_options,
+            ..
+        } if can_count_rows(scan_type, file_options) => Some(CountStarExpr {
             paths: paths.clone(),
             scan_type: scan_type.clone(),
             node,
@@ -126,6 +392,38 @@ fn visit_logical_plan_for_scan_paths(
     }
 }
 
+fn can_count_rows(scan_type: &FileScan, file_options: &FileScanOptions) -> bool {
+    match scan_type {
+        FileScan::Anonymous { options, function } => {
+            function.allows_count_rows()
+                && options.skip_rows.is_none()
+                && file_options.n_rows.is_none()
+        },
+        _ => true,
+    }
+}
+
+/// The anonymous scans of a union, which are counted together.
+struct UnionCount(Vec<Arc<dyn AnonymousScan>>);
+
+impl AnonymousScan for UnionCount {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        unreachable!()
+    }
+
+    fn allows_count_rows(&self) -> bool {
+        true
+    }
+
+    fn count_rows(&self) -> PolarsResult<usize> {
+        self.0.iter().map(|function| function.count_rows()).sum()
+    }
+}
+
 fn is_valid_count_expr(e: &ExprIR, expr_arena: &Arena<AExpr>) -> (bool, Option<Arc<str>>) {
     match expr_arena.get(e.node()) {
         AExpr::Len => (true, e.get_alias().cloned()),
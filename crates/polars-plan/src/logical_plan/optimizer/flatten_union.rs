@@ -14,6 +14,10 @@ fn get_union_inputs(node: Node, lp_arena: &Arena<IR>) -> Option<&[Node]> {
 }
 
 impl OptimizationRule for FlattenUnionRule {
+    fn name(&self) -> &'static str {
+        "flatten_union"
+    }
+
     fn optimize_plan(
         &mut self,
         lp_arena: &mut polars_utils::arena::Arena<IR>,
@@ -57,6 +57,10 @@ fn check_eligible(
 }
 
 impl OptimizationRule for FusedArithmetic {
+    fn name(&self) -> &'static str {
+        "fused_arithmetic"
+    }
+
     #[allow(clippy::float_cmp)]
     fn optimize_expr(
         &mut self,
@@ -0,0 +1,88 @@
+use super::*;
+
+/// Replaces integer arithmetic and sums with kernels that follow the query's
+/// [`IntegerOverflow`] setting. This is only added if the setting is not the
+/// default wrapping behavior.
+pub(super) struct IntegerOverflowRule {
+    overflow: IntegerOverflow,
+}
+
+impl IntegerOverflowRule {
+    pub(super) fn new(overflow: IntegerOverflow) -> Self {
+        Self { overflow }
+    }
+}
+
+fn is_integer(node: Node, schema: &Schema, expr_arena: &Arena<AExpr>) -> PolarsResult<bool> {
+    Ok(expr_arena
+        .get(node)
+        .get_type(schema, Context::Default, expr_arena)?
+        .is_integer())
+}
+
+impl OptimizationRule for IntegerOverflowRule {
+    fn name(&self) -> &'static str {
+        "integer_overflow"
+    }
+
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        lp_arena: &Arena<IR>,
+        lp_node: Node,
+    ) -> PolarsResult<Option<AExpr>> {
+        let Some(input_node) = lp_arena.get(lp_node).get_input() else {
+            return Ok(None);
+        };
+        let schema = lp_arena.get(input_node).schema(lp_arena);
+
+        match expr_arena.get(expr_node) {
+            AExpr::BinaryExpr { left, op, right } => {
+                let op = match op {
+                    Operator::Plus => OverflowOperator::Add,
+                    Operator::Minus => OverflowOperator::Sub,
+                    Operator::Multiply => OverflowOperator::Mul,
+                    _ => return Ok(None),
+                };
+                let (left, right) = (*left, *right);
+                if !(is_integer(left, &schema, expr_arena)?
+                    && is_integer(right, &schema, expr_arena)?)
+                {
+                    return Ok(None);
+                }
+                Ok(Some(AExpr::Function {
+                    input: vec![
+                        ExprIR::from_node(left, expr_arena),
+                        ExprIR::from_node(right, expr_arena),
+                    ],
+                    function: FunctionExpr::IntegerArithmetic {
+                        op,
+                        overflow: self.overflow,
+                    },
+                    options: FunctionOptions {
+                        collect_groups: ApplyOptions::ElementWise,
+                        cast_to_supertypes: true,
+                        ..Default::default()
+                    },
+                }))
+            },
+            AExpr::Agg(AAggExpr::Sum(input)) => {
+                let input = *input;
+                if !is_integer(input, &schema, expr_arena)? {
+                    return Ok(None);
+                }
+                Ok(Some(AExpr::Function {
+                    input: vec![ExprIR::from_node(input, expr_arena)],
+                    function: FunctionExpr::IntegerSum(self.overflow),
+                    options: FunctionOptions {
+                        collect_groups: ApplyOptions::GroupWise,
+                        returns_scalar: true,
+                        ..Default::default()
+                    },
+                }))
+            },
+            _ => Ok(None),
+        }
+    }
+}
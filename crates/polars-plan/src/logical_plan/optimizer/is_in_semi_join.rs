@@ -0,0 +1,131 @@
+use super::*;
+use crate::utils::{has_aexpr, single_aexpr_is_elementwise};
+
+/// The number of values from which an `is_in` filter is rewritten to a semi join.
+const SEMI_JOIN_THRESHOLD: usize = 1000;
+
+/// Split a predicate into the predicates that are combined with `&`.
+fn split_conjunction(node: Node, expr_arena: &Arena<AExpr>, out: &mut Vec<Node>) {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjunction(*left, expr_arena, out);
+            split_conjunction(*right, expr_arena, out);
+        },
+        _ => out.push(node),
+    }
+}
+
+/// The column and the values of a `col(name).is_in(values)` predicate, if it can be
+/// evaluated by a semi join against the values.
+fn is_in_values(
+    node: Node,
+    input_schema: &Schema,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(ColumnName, Series)> {
+    let AExpr::Function {
+        function: FunctionExpr::Boolean(BooleanFunction::IsIn),
+        input,
+        ..
+    } = expr_arena.get(node)
+    else {
+        return None;
+    };
+    let (AExpr::Column(name), AExpr::Literal(LiteralValue::Series(values))) = (
+        expr_arena.get(input[0].node()),
+        expr_arena.get(input[1].node()),
+    ) else {
+        return None;
+    };
+    // A join doesn't cast its keys and never matches nulls.
+    let dtype = input_schema.get(name)?;
+    (values.len() >= SEMI_JOIN_THRESHOLD && values.dtype() == dtype && values.null_count() == 0)
+        .then(|| (name.clone(), Series::clone(values)))
+}
+
+/// Rewrites filters on `col(name).is_in(values)` with a large literal Series of values to a
+/// semi join against the values, so that they are evaluated by a hash join.
+///
+/// Must run before the pushdowns, so that the joins take part in them.
+pub(super) fn rewrite_is_in_to_semi_join(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let filters = lp_arena
+        .iter(root)
+        .filter_map(|(node, lp)| matches!(lp, IR::Filter { .. }).then_some(node))
+        .collect::<Vec<_>>();
+    let mut conjunction = vec![];
+    for node in filters {
+        let IR::Filter { input, predicate } = lp_arena.get(node) else {
+            unreachable!()
+        };
+        let input = *input;
+        conjunction.clear();
+        split_conjunction(predicate.node(), expr_arena, &mut conjunction);
+        let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+        let (semi_joins, remaining): (Vec<_>, Vec<_>) = conjunction
+            .iter()
+            .map(|&e| is_in_values(e, &input_schema, expr_arena).ok_or(e))
+            .partition(|candidate| candidate.is_ok());
+        // The other predicates are evaluated on the rows that are left by the joins, which only
+        // gives the same result if they don't depend on the other rows, e.g. by aggregating.
+        if semi_joins.is_empty()
+            || remaining
+                .iter()
+                .filter_map(|e| e.as_ref().err())
+                .any(|&e| has_aexpr(e, expr_arena, |ae| !single_aexpr_is_elementwise(ae)))
+        {
+            continue;
+        }
+
+        let mut current = input;
+        for (name, values) in semi_joins.into_iter().map(Result::unwrap) {
+            let values = values.with_name(&name).into_frame();
+            let right = lp_arena.add(IR::DataFrameScan {
+                schema: Arc::new(values.schema()),
+                df: Arc::new(values),
+                output_schema: None,
+                projection: None,
+                selection: None,
+            });
+            let mut key = || {
+                let node = expr_arena.add(AExpr::Column(name.clone()));
+                vec![ExprIR::new(node, OutputName::ColumnLhs(name.clone()))]
+            };
+            let (left_on, right_on) = (key(), key());
+            let options = JoinOptions {
+                args: JoinArgs::new(JoinType::Semi),
+                ..Default::default()
+            };
+            let lp = IRBuilder::new(current, expr_arena, lp_arena)
+                .join(right, left_on, right_on, Arc::new(options))
+                .build();
+            current = lp_arena.add(lp);
+        }
+        // The other predicates stay in the filter.
+        let predicate = remaining
+            .into_iter()
+            .map(|e| e.unwrap_err())
+            .reduce(|left, right| {
+                expr_arena.add(AExpr::BinaryExpr {
+                    left,
+                    op: Operator::And,
+                    right,
+                })
+            });
+        let lp = match predicate {
+            Some(predicate) => IR::Filter {
+                input: current,
+                predicate: ExprIR::from_node(predicate, expr_arena),
+            },
+            None => lp_arena.take(current),
+        };
+        trace_applied("is_in_semi_join", &lp);
+        lp_arena.replace(node, lp);
+    }
+}
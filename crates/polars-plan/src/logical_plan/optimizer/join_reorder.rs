@@ -0,0 +1,329 @@
+use super::*;
+
+/// The fraction of rows that is assumed to pass every binary expression of a filter.
+const FILTER_SELECTIVITY: f64 = 0.9;
+/// The number of rows of an in-memory key column that are read to estimate its number of
+/// distinct values.
+const DISTINCT_SAMPLE_ROWS: usize = 1 << 16;
+
+/// An inner join of a chain, the left input being the result of the previous joins.
+struct ChainJoin {
+    input: Node,
+    left_on: Vec<ExprIR>,
+    right_on: Vec<ExprIR>,
+    options: Arc<JoinOptions>,
+}
+
+/// Reorders chains of inner joins, so that the joins that are estimated to produce the
+/// fewest rows are done first.
+///
+/// The leftmost input of a chain stays the left input, the other inputs are joined in a
+/// greedy order: each step joins the input (whose left keys are available) that gives the
+/// smallest estimated result. Chains are only reordered if the rows of all inputs can be
+/// estimated.
+pub(super) struct JoinReorder {
+    scratch: Vec<Node>,
+}
+
+impl JoinReorder {
+    pub(super) fn new() -> Self {
+        Self { scratch: vec![] }
+    }
+
+    pub(super) fn optimize(
+        &mut self,
+        root: Node,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<()> {
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            match collect_chain(node, lp_arena, expr_arena) {
+                Some((base, joins)) => {
+                    stack.push(base);
+                    stack.extend(joins.iter().map(|join| join.input));
+                    reorder_chain(node, base, joins, lp_arena, expr_arena)?;
+                },
+                None => {
+                    lp_arena.get(node).copy_inputs(&mut self.scratch);
+                    stack.append(&mut self.scratch);
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_column(e: &ExprIR, expr_arena: &Arena<AExpr>) -> bool {
+    matches!(expr_arena.get(e.node()), AExpr::Column(_))
+}
+
+/// Whether the inputs of this join may be joined in another order: an inner equi-join on
+/// columns, of which the output doesn't depend on the join order.
+fn is_reorderable(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(Node, ChainJoin)> {
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+    let args = &options.args;
+    if !matches!(args.how, JoinType::Inner)
+        || args.slice.is_some()
+        || args.validation != JoinValidation::ManyToMany
+        || !args.coalesce.coalesce(&args.how)
+        || !left_on
+            .iter()
+            .chain(right_on)
+            .all(|e| is_column(e, expr_arena))
+    {
+        return None;
+    }
+    // Suffixed columns are named after the join order.
+    let schema_left = lp_arena.get(*input_left).schema(lp_arena);
+    let schema_right = lp_arena.get(*input_right).schema(lp_arena);
+    let has_conflicts = schema_right.iter_names().any(|name| {
+        schema_left.contains(name.as_str())
+            && !right_on.iter().any(|e| e.output_name() == name.as_str())
+    });
+    if has_conflicts {
+        return None;
+    }
+    Some((
+        *input_left,
+        ChainJoin {
+            input: *input_right,
+            left_on: left_on.clone(),
+            right_on: right_on.clone(),
+            options: options.clone(),
+        },
+    ))
+}
+
+/// The leftmost input and the joins of the chain of inner joins rooted at `node`, if there
+/// are at least two joins.
+fn collect_chain(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(Node, Vec<ChainJoin>)> {
+    let mut joins = vec![];
+    let mut current = node;
+    while let Some((input_left, join)) = is_reorderable(current, lp_arena, expr_arena) {
+        joins.push(join);
+        current = input_left;
+    }
+    if joins.len() < 2 {
+        return None;
+    }
+    joins.reverse();
+    Some((current, joins))
+}
+
+fn filter_selectivity(predicate: &ExprIR, expr_arena: &Arena<AExpr>) -> f64 {
+    let n = expr_arena
+        .iter(predicate.node())
+        .filter(|(_, ae)| matches!(ae, AExpr::BinaryExpr { .. }))
+        .count()
+        .max(1);
+    FILTER_SELECTIVITY.powi(n as i32)
+}
+
+/// The estimated number of rows produced by `node`, if the rows of its sources are known.
+fn estimate_rows(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<f64> {
+    use IR::*;
+    let rows = match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => {
+            let rows = df.height() as f64;
+            match selection {
+                Some(predicate) => rows * filter_selectivity(predicate, expr_arena),
+                None => rows,
+            }
+        },
+        Scan {
+            file_info,
+            predicate,
+            file_options,
+            ..
+        } => {
+            let mut rows = match file_info.row_estimation {
+                (Some(known), _) => known,
+                (None, estimated) if estimated != usize::MAX => estimated,
+                _ => return None,
+            } as f64;
+            if let Some(n_rows) = file_options.n_rows {
+                rows = rows.min(n_rows as f64);
+            }
+            match predicate {
+                Some(predicate) => rows * filter_selectivity(predicate, expr_arena),
+                None => rows,
+            }
+        },
+        Filter { input, predicate } => {
+            estimate_rows(*input, lp_arena, expr_arena)? * filter_selectivity(predicate, expr_arena)
+        },
+        Slice { input, len, .. } => estimate_rows(*input, lp_arena, expr_arena)?.min(*len as f64),
+        TopK { input, len, .. } => estimate_rows(*input, lp_arena, expr_arena)?.min(*len as f64),
+        Union { inputs, .. } => inputs
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena, expr_arena))
+            .sum::<Option<f64>>()?,
+        Join { .. } | HConcat { .. } | ExtContext { .. } => return None,
+        lp => match lp.get_inputs_vec().as_slice() {
+            [input] => estimate_rows(*input, lp_arena, expr_arena)?,
+            _ => return None,
+        },
+    };
+    Some(rows)
+}
+
+/// The estimated number of distinct values of `column` in the output of `node`.
+///
+/// The distinct values of in-memory columns are counted on a sample, other columns are
+/// assumed to be unique.
+fn estimate_distinct(node: Node, column: &str, rows: f64, lp_arena: &Arena<IR>) -> f64 {
+    use IR::*;
+    let distinct = match lp_arena.get(node) {
+        DataFrameScan { df, .. } => match df.column(column) {
+            Ok(s) => {
+                let sample_len = s.len().min(DISTINCT_SAMPLE_ROWS);
+                match s.slice(0, sample_len).n_unique() {
+                    // A sample with few distinct values probably holds all of them, otherwise
+                    // the distinct values are assumed to grow with the rows.
+                    Ok(n) if n * 2 <= sample_len || sample_len == s.len() => n as f64,
+                    Ok(n) => n as f64 * s.len() as f64 / sample_len as f64,
+                    Err(_) => rows,
+                }
+            },
+            Err(_) => rows,
+        },
+        Filter { input, .. } | Slice { input, .. } | Cache { input, .. } | Sort { input, .. } => {
+            estimate_distinct(*input, column, rows, lp_arena)
+        },
+        SimpleProjection { input, columns, .. } if columns.contains(column) => {
+            estimate_distinct(*input, column, rows, lp_arena)
+        },
+        _ => rows,
+    };
+    distinct.clamp(1.0, rows.max(1.0))
+}
+
+/// The estimated number of distinct combinations of `keys`.
+fn estimate_distinct_keys(distinct: impl Iterator<Item = f64>, rows: f64) -> f64 {
+    distinct
+        .fold(1.0, |acc, d| acc * d)
+        .clamp(1.0, rows.max(1.0))
+}
+
+fn reorder_chain(
+    node: Node,
+    base: Node,
+    joins: Vec<ChainJoin>,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let Some(mut rows) = estimate_rows(base, lp_arena, expr_arena) else {
+        return Ok(());
+    };
+    let Some(input_rows) = joins
+        .iter()
+        .map(|join| estimate_rows(join.input, lp_arena, expr_arena))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(());
+    };
+
+    // The input that each of the joined columns comes from, with its estimated rows.
+    let mut origins: PlHashMap<SmartString, (Node, f64)> = lp_arena
+        .get(base)
+        .schema(lp_arena)
+        .iter_names()
+        .map(|name| (name.clone(), (base, rows)))
+        .collect();
+    let mut remaining = (0..joins.len()).collect::<Vec<_>>();
+    let mut order = Vec::with_capacity(joins.len());
+    while !remaining.is_empty() {
+        let best = remaining
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, &i)| {
+                let join = &joins[i];
+                let left_distinct = join
+                    .left_on
+                    .iter()
+                    .map(|e| {
+                        let (input, input_rows) = origins.get(e.output_name())?;
+                        Some(estimate_distinct(
+                            *input,
+                            e.output_name(),
+                            *input_rows,
+                            lp_arena,
+                        ))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                let left_distinct = estimate_distinct_keys(left_distinct.into_iter(), rows);
+                let right_distinct = estimate_distinct_keys(
+                    join.right_on.iter().map(|e| {
+                        estimate_distinct(join.input, e.output_name(), input_rows[i], lp_arena)
+                    }),
+                    input_rows[i],
+                );
+                let out = rows * input_rows[i] / left_distinct.max(right_distinct);
+                Some((pos, out))
+            })
+            // Ties are joined in the original order.
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+        // The remaining joins are on columns that are not available, which can't happen in
+        // a valid plan.
+        let Some((pos, out)) = best else {
+            return Ok(());
+        };
+        let i = remaining.remove(pos);
+        let join = &joins[i];
+        for name in lp_arena.get(join.input).schema(lp_arena).iter_names() {
+            // The right keys are dropped from the result.
+            if join
+                .right_on
+                .iter()
+                .all(|e| e.output_name() != name.as_str())
+            {
+                origins
+                    .entry(name.clone())
+                    .or_insert((join.input, input_rows[i]));
+            }
+        }
+        rows = out;
+        order.push(i);
+    }
+    if order.iter().copied().eq(0..joins.len()) {
+        return Ok(());
+    }
+
+    let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+    let mut builder = IRBuilder::new(base, expr_arena, lp_arena);
+    for i in order {
+        let join = &joins[i];
+        builder = builder.join(
+            join.input,
+            join.left_on.clone(),
+            join.right_on.clone(),
+            join.options.clone(),
+        );
+    }
+    // Restore the column order of the original chain.
+    let lp = builder
+        .project_simple(schema.iter_names().map(|name| name.as_str()))?
+        .build();
+    trace_applied("join_reorder", &lp);
+    lp_arena.replace(node, lp);
+    Ok(())
+}
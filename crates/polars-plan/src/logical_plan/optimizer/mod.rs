@@ -13,6 +13,13 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod integer_overflow;
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+mod is_in_semi_join;
+mod join_reorder;
+mod nan_as_null;
+#[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+mod nested_projection;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
@@ -20,10 +27,18 @@ mod simplify_functions;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+mod trace;
 mod type_coercion;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
 use delay_rechunk::DelayRechunk;
+use integer_overflow::IntegerOverflowRule;
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+use is_in_semi_join::rewrite_is_in_to_semi_join;
+use join_reorder::JoinReorder;
+use nan_as_null::NanAsNullRule;
+#[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+use nested_projection::push_down_nested_projection;
 use polars_core::config::verbose;
 use polars_io::predicates::PhysicalIoExpr;
 pub use predicate_pushdown::PredicatePushDown;
@@ -31,11 +46,16 @@ pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, StackOptimizer};
+pub(crate) use trace::{trace_applied, trace_blocked};
+pub use trace::{trace_optimizer, OptimizerEvent, OptimizerOutcome};
+pub(crate) use type_coercion::check_implicit_cast;
 pub use type_coercion::TypeCoercionRule;
 
 use self::flatten_union::FlattenUnionRule;
 pub use crate::frame::{AllowedOptimizations, OptState};
-use crate::logical_plan::optimizer::count_star::CountStar;
+#[cfg(feature = "parquet")]
+use crate::logical_plan::optimizer::count_star::statistics_filtered_count_star;
+use crate::logical_plan::optimizer::count_star::{hive_filtered_count_star, CountStar};
 #[cfg(feature = "cse")]
 use crate::logical_plan::optimizer::cse::prune_unused_caches;
 #[cfg(feature = "cse")]
@@ -74,6 +94,8 @@ pub fn optimize(
     let slice_pushdown = opt_state.slice_pushdown;
     let streaming = opt_state.streaming;
     let fast_projection = opt_state.fast_projection;
+    let integer_overflow = opt_state.integer_overflow;
+    let join_reorder = opt_state.join_reorder;
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
     let eager = opt_state.eager;
@@ -98,6 +120,7 @@ pub fn optimize(
         lp_arena,
         simplify_expr,
         type_coercion,
+        opt_state.cast_policy,
     )?;
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
@@ -109,6 +132,15 @@ pub fn optimize(
         members.collect(lp_top, lp_arena, expr_arena)
     }
 
+    // Must run before fused arithmetic, as that would hide the integer operations.
+    if integer_overflow != IntegerOverflow::Wrap {
+        rules.push(Box::new(IntegerOverflowRule::new(integer_overflow)));
+    }
+
+    if opt_state.nan_as_null {
+        rules.push(Box::new(NanAsNullRule {}));
+    }
+
     if simplify_expr {
         #[cfg(feature = "fused")]
         rules.push(Box::new(fused::FusedArithmetic {}));
@@ -126,6 +158,9 @@ pub fn optimize(
         let (lp, changed, cid2c) = cse::elim_cmn_subplans(lp_top, lp_arena, expr_arena);
 
         prune_unused_caches(lp_arena, cid2c);
+        if changed {
+            trace_applied("comm_subplan_elim", lp_arena.get(lp));
+        }
 
         lp_top = lp;
         members.has_cache |= changed;
@@ -136,6 +171,22 @@ pub fn optimize(
     #[cfg(not(feature = "cse"))]
     let _cse_plan_changed = false;
 
+    for (optimization, enabled) in [
+        ("projection_pushdown", projection_pushdown),
+        ("predicate_pushdown", predicate_pushdown),
+        ("slice_pushdown", slice_pushdown),
+    ] {
+        if !enabled {
+            trace_blocked(optimization, lp_arena.get(lp_top), "disabled");
+        }
+    }
+
+    #[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+    if predicate_pushdown && !eager {
+        rewrite_is_in_to_semi_join(lp_top, lp_arena, expr_arena);
+    }
+
+    let mut is_count_star = false;
     // Should be run before predicate pushdown.
     if projection_pushdown {
         let mut projection_pushdown_opt = ProjectionPushDown::new();
@@ -144,6 +195,7 @@ pub fn optimize(
         lp_arena.replace(lp_top, alp);
 
         if projection_pushdown_opt.is_count_star {
+            is_count_star = true;
             let mut count_star_opt = CountStar::new();
             count_star_opt.optimize_plan(lp_arena, expr_arena, lp_top);
         }
@@ -154,6 +206,25 @@ pub fn optimize(
         let alp = lp_arena.take(lp_top);
         let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
         lp_arena.replace(lp_top, alp);
+
+        // A count over a scan filtered only on hive partitions or on columns whose statistics
+        // decide the predicate can be answered from metadata.
+        if is_count_star {
+            hive_filtered_count_star(lp_top, lp_arena, expr_arena, hive_partition_eval)?;
+            #[cfg(feature = "parquet")]
+            statistics_filtered_count_star(lp_top, lp_arena, expr_arena, hive_partition_eval)?;
+        }
+    }
+
+    // Run after predicate pushdown, so that the predicates of the scans are known.
+    #[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+    if projection_pushdown {
+        push_down_nested_projection(lp_top, lp_arena, expr_arena);
+    }
+
+    // Run after predicate pushdown, so that the estimates see the filtered inputs.
+    if join_reorder && !eager {
+        JoinReorder::new().optimize(lp_top, lp_arena, expr_arena)?;
     }
 
     // Make sure its before slice pushdown.
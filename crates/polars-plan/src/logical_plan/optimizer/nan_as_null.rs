@@ -0,0 +1,176 @@
+use super::*;
+
+/// Treats the floating point `NaN` values as missing values in aggregations, comparisons
+/// and sorts, by replacing the `NaN` values of their float inputs with nulls. This is only
+/// added if the query enables it.
+pub(super) struct NanAsNullRule {}
+
+/// Whether `node` already replaces the `NaN` values of its input.
+fn is_nan_to_null(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    match expr_arena.get(node) {
+        AExpr::Ternary { predicate, .. } => matches!(
+            expr_arena.get(*predicate),
+            AExpr::Function {
+                function: FunctionExpr::Boolean(BooleanFunction::IsNotNan),
+                ..
+            }
+        ),
+        _ => false,
+    }
+}
+
+/// Wrap `node` in `when(node.is_not_nan()).then(node).otherwise(null)`, which is how
+/// `fill_nan` with a null value is planned. A null input yields a null predicate and thus
+/// stays null.
+fn nan_to_null(
+    node: Node,
+    schema: &Schema,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<Node>> {
+    if is_nan_to_null(node, expr_arena) {
+        return Ok(None);
+    }
+    let dtype = expr_arena
+        .get(node)
+        .get_type(schema, Context::Default, expr_arena)?;
+    if !dtype.is_float() {
+        return Ok(None);
+    }
+    let predicate = expr_arena.add(AExpr::Function {
+        input: vec![ExprIR::from_node(node, expr_arena)],
+        function: FunctionExpr::Boolean(BooleanFunction::IsNotNan),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            ..Default::default()
+        },
+    });
+    let null = expr_arena.add(AExpr::Literal(LiteralValue::Null));
+    let falsy = expr_arena.add(AExpr::Cast {
+        expr: null,
+        data_type: dtype,
+        strict: false,
+    });
+    Ok(Some(expr_arena.add(AExpr::Ternary {
+        predicate,
+        truthy: node,
+        falsy,
+    })))
+}
+
+impl OptimizationRule for NanAsNullRule {
+    fn name(&self) -> &'static str {
+        "nan_as_null"
+    }
+
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<IR> {
+        // The sort keys of a plan are mostly columns, which `optimize_expr` doesn't visit.
+        let IR::Sort {
+            input,
+            by_column,
+            slice,
+            sort_options,
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        let schema = lp_arena.get(*input).schema(lp_arena);
+        let mut changed = false;
+        let by_column = by_column
+            .iter()
+            .map(|e| match nan_to_null(e.node(), &schema, expr_arena) {
+                Ok(Some(node)) => {
+                    changed = true;
+                    let mut e = e.clone();
+                    e.set_node(node);
+                    e
+                },
+                _ => e.clone(),
+            })
+            .collect();
+        changed.then(|| IR::Sort {
+            input: *input,
+            by_column,
+            slice: *slice,
+            sort_options: sort_options.clone(),
+        })
+    }
+
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        lp_arena: &Arena<IR>,
+        lp_node: Node,
+    ) -> PolarsResult<Option<AExpr>> {
+        let Some(input_node) = lp_arena.get(lp_node).get_input() else {
+            return Ok(None);
+        };
+        let schema = lp_arena.get(input_node).schema(lp_arena);
+
+        match expr_arena.get(expr_node).clone() {
+            AExpr::Agg(
+                AAggExpr::Implode(_)
+                | AAggExpr::AggGroups(_)
+                | AAggExpr::Min {
+                    propagate_nans: true,
+                    ..
+                }
+                | AAggExpr::Max {
+                    propagate_nans: true,
+                    ..
+                },
+            ) => Ok(None),
+            AExpr::Agg(mut agg) => {
+                let input = agg.get_input().first();
+                Ok(nan_to_null(input, &schema, expr_arena)?.map(|input| {
+                    agg.set_input(input);
+                    AExpr::Agg(agg)
+                }))
+            },
+            AExpr::BinaryExpr { left, op, right } if op.is_comparison() => {
+                let new_left = nan_to_null(left, &schema, expr_arena)?;
+                let new_right = nan_to_null(right, &schema, expr_arena)?;
+                if new_left.is_none() && new_right.is_none() {
+                    return Ok(None);
+                }
+                Ok(Some(AExpr::BinaryExpr {
+                    left: new_left.unwrap_or(left),
+                    op,
+                    right: new_right.unwrap_or(right),
+                }))
+            },
+            AExpr::Sort { expr, options } => {
+                Ok(nan_to_null(expr, &schema, expr_arena)?
+                    .map(|expr| AExpr::Sort { expr, options }))
+            },
+            AExpr::SortBy {
+                expr,
+                by,
+                sort_options,
+            } => {
+                let mut changed = false;
+                let mut new_by = Vec::with_capacity(by.len());
+                for node in by {
+                    match nan_to_null(node, &schema, expr_arena)? {
+                        Some(node) => {
+                            changed = true;
+                            new_by.push(node)
+                        },
+                        None => new_by.push(node),
+                    }
+                }
+                Ok(changed.then_some(AExpr::SortBy {
+                    expr,
+                    by: new_by,
+                    sort_options,
+                }))
+            },
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use polars_io::parquet::read::NestedProjection;
+
+use super::*;
+
+/// The path of `node` if it is a column or a (nested) field of a struct column, e.g.
+/// `["a", "b"]` for `col("a").struct.field("b")`. The elements of lists are taken with
+/// `explode` or `list.get`, so `["a", "b"]` is also the path of
+/// `col("a").list.first().struct.field("b")` if `a` is a list of structs. The other inputs of
+/// the expressions on the path, such as the index of `list.get`, are added to `inputs`.
+fn field_path(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    inputs: &mut Vec<Node>,
+) -> Option<Vec<Arc<str>>> {
+    match expr_arena.get(node) {
+        AExpr::Column(name) => Some(vec![name.clone()]),
+        AExpr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::FieldByName(field)),
+            ..
+        } => {
+            let mut path = field_path(input[0].node(), expr_arena, inputs)?;
+            path.push(field.clone());
+            Some(path)
+        },
+        AExpr::Explode(input) => field_path(*input, expr_arena, inputs),
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ListExpr(ListFunction::Get(_)),
+            ..
+        } => {
+            inputs.extend(input[1..].iter().map(|e| e.node()));
+            field_path(input[0].node(), expr_arena, inputs)
+        },
+        _ => None,
+    }
+}
+
+/// Collect the paths of the columns and the struct fields that the expression at `node` reads.
+fn collect_field_paths(node: Node, expr_arena: &Arena<AExpr>, paths: &mut Vec<Vec<Arc<str>>>) {
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        let mut inputs = vec![];
+        match field_path(node, expr_arena, &mut inputs) {
+            Some(path) => {
+                paths.push(path);
+                stack.extend(inputs);
+            },
+            None => expr_arena.get(node).nodes(&mut stack),
+        }
+    }
+}
+
+/// Whether `dtype` is a struct, or a (nested) list of structs.
+fn has_struct(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Struct(_) => true,
+        DataType::List(inner) => has_struct(inner),
+        _ => false,
+    }
+}
+
+/// Whether the struct fields of `path` exist in `dtype`, where the fields of lists of structs
+/// are the fields of their elements.
+fn has_field(dtype: &DataType, path: &[Arc<str>]) -> bool {
+    let Some((name, rest)) = path.split_first() else {
+        return true;
+    };
+    match dtype {
+        DataType::Struct(fields) => fields
+            .iter()
+            .any(|fld| fld.name().as_str() == name.as_ref() && has_field(fld.data_type(), rest)),
+        DataType::List(inner) => has_field(inner, path),
+        _ => false,
+    }
+}
+
+/// The fields of the struct columns of `schema` that are read by the expressions at `nodes`,
+/// for the columns that are only accessed through their fields.
+fn nested_projection(
+    nodes: &[Node],
+    schema: &Schema,
+    expr_arena: &Arena<AExpr>,
+) -> NestedProjection {
+    let mut paths = vec![];
+    for node in nodes {
+        collect_field_paths(*node, expr_arena, &mut paths);
+    }
+
+    let mut projection = NestedProjection::default();
+    for (name, dtype) in schema.iter() {
+        if !has_struct(dtype) {
+            continue;
+        }
+        let mut column_paths = paths
+            .iter()
+            .filter(|path| path[0].as_ref() == name.as_str())
+            .peekable();
+        if column_paths.peek().is_none()
+            || !column_paths
+                .clone()
+                .all(|path| path.len() > 1 && has_field(dtype, &path[1..]))
+        {
+            continue;
+        }
+        for path in column_paths {
+            projection.add_path(&path.iter().map(|name| name.as_ref()).collect::<Vec<_>>());
+        }
+    }
+    projection
+}
+
+/// Only read the used fields of the struct columns of parquet scans, if the columns are
+/// only accessed through their fields, e.g. with `col("a").struct.field("b")`.
+///
+/// This applies to scans below a projection, possibly with filters in between, as the
+/// projection doesn't pass the struct columns on.
+pub(super) fn push_down_nested_projection(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let mut scans = vec![];
+    for (_, lp) in lp_arena.iter(root) {
+        let IR::Select { input, expr, .. } = lp else {
+            continue;
+        };
+        let mut nodes = expr.iter().map(|e| e.node()).collect::<Vec<_>>();
+        let mut input = *input;
+        while let IR::Filter {
+            input: next,
+            predicate,
+        } = lp_arena.get(input)
+        {
+            nodes.push(predicate.node());
+            input = *next;
+        }
+        if let IR::Scan {
+            scan_type: FileScan::Parquet { .. },
+            predicate,
+            ..
+        } = lp_arena.get(input)
+        {
+            nodes.extend(predicate.as_ref().map(|predicate| predicate.node()));
+            scans.push((input, nodes));
+        }
+    }
+
+    for (node, nodes) in scans {
+        let IR::Scan {
+            file_info,
+            output_schema,
+            scan_type: FileScan::Parquet {
+                nested_projection, ..
+            },
+            ..
+        } = lp_arena.get_mut(node)
+        else {
+            unreachable!()
+        };
+        let Some(reader_schema) = &file_info.reader_schema else {
+            continue;
+        };
+        let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+        let projection = nested_projection(&nodes, schema, expr_arena);
+        if projection.is_empty() {
+            continue;
+        }
+
+        // The struct columns are read without the fields that aren't projected.
+        let projected_schema = Schema::from(&projection.project_schema(reader_schema));
+        let mut schema = (**schema).clone();
+        for (name, dtype) in projected_schema.iter() {
+            if has_struct(dtype) && schema.contains(name) {
+                schema.set_dtype(name, dtype.clone());
+            }
+        }
+        *output_schema = Some(Arc::new(schema));
+        *nested_projection = projection;
+        trace_applied("nested_projection_pushdown", lp_arena.get(node));
+    }
+}
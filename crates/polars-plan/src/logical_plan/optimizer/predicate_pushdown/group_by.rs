@@ -24,6 +24,13 @@ pub(super) fn process_group_by(
 
     // Don't pushdown predicates on these cases.
     if apply.is_some() || no_push || options.slice.is_some() {
+        let reason = if apply.is_some() {
+            "predicates don't pass a group by with a function"
+        } else if no_push {
+            "predicates don't pass a dynamic or rolling group by"
+        } else {
+            "filtering before the slice of the group by changes which groups are sliced"
+        };
         let lp = GroupBy {
             input,
             keys,
@@ -33,7 +40,7 @@ pub(super) fn process_group_by(
             maintain_order,
             options,
         };
-        return opt.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena);
+        return opt.no_pushdown_restart_opt(lp, acc_predicates, reason, lp_arena, expr_arena);
     }
 
     // If the predicate only resolves to the keys we can push it down.
@@ -80,5 +87,11 @@ pub(super) fn process_group_by(
         maintain_order,
         options,
     };
-    Ok(opt.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+    Ok(opt.optional_apply_predicate(
+        lp,
+        local_predicates,
+        "predicate refers to an aggregated column or counts the rows",
+        lp_arena,
+        expr_arena,
+    ))
 }
@@ -216,5 +216,11 @@ pub(super) fn process_join(
         schema,
         options,
     };
-    Ok(opt.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+    Ok(opt.optional_apply_predicate(
+        lp,
+        local_predicates,
+        "predicate can't be applied to an input of the join without changing the result",
+        lp_arena,
+        expr_arena,
+    ))
 }
@@ -1,6 +1,7 @@
 mod group_by;
 mod join;
 mod keys;
+mod ranges;
 mod rename;
 mod utils;
 
@@ -19,6 +20,22 @@ use crate::utils::{check_input_node, has_aexpr};
 pub type HiveEval<'a> =
     Option<&'a dyn Fn(&ExprIR, &Arena<AExpr>) -> Option<Arc<dyn PhysicalIoExpr>>>;
 
+fn apply_predicates(
+    lp: IR,
+    local_predicates: Vec<ExprIR>,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> IR {
+    if !local_predicates.is_empty() {
+        let predicate = combine_predicates(local_predicates.into_iter(), expr_arena);
+        let input = lp_arena.add(lp);
+
+        IR::Filter { input, predicate }
+    } else {
+        lp
+    }
+}
+
 pub struct PredicatePushDown<'a> {
     hive_partition_eval: HiveEval<'a>,
     verbose: bool,
@@ -39,21 +56,20 @@ impl<'a> PredicatePushDown<'a> {
         self
     }
 
+    /// Apply the `local_predicates` at `lp`, which can't be pushed past it because of
+    /// `reason`.
     fn optional_apply_predicate(
         &self,
         lp: IR,
         local_predicates: Vec<ExprIR>,
+        reason: &'static str,
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
     ) -> IR {
         if !local_predicates.is_empty() {
-            let predicate = combine_predicates(local_predicates.into_iter(), expr_arena);
-            let input = lp_arena.add(lp);
-
-            IR::Filter { input, predicate }
-        } else {
-            lp
+            trace_blocked("predicate_pushdown", &lp, reason);
         }
+        apply_predicates(lp, local_predicates, lp_arena, expr_arena)
     }
 
     fn pushdown_and_assign(
@@ -102,7 +118,13 @@ impl<'a> PredicatePushDown<'a> {
                     out
                 },
                 PushdownEligibility::NoPushdown => {
-                    return self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
+                    return self.no_pushdown_restart_opt(
+                        lp,
+                        acc_predicates,
+                        "an expression of the node depends on other rows, e.g. an aggregation",
+                        lp_arena,
+                        expr_arena,
+                    )
                 },
             };
 
@@ -144,7 +166,13 @@ impl<'a> PredicatePushDown<'a> {
             lp_arena.replace(input, alp);
 
             let lp = lp.with_exprs_and_input(exprs, inputs);
-            Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+            Ok(self.optional_apply_predicate(
+                lp,
+                local_predicates,
+                "predicate refers to a column that the node computes",
+                lp_arena,
+                expr_arena,
+            ))
         } else {
             let mut local_predicates = Vec::with_capacity(acc_predicates.len());
 
@@ -180,7 +208,13 @@ impl<'a> PredicatePushDown<'a> {
                 .collect::<PolarsResult<Vec<_>>>()?;
 
             let lp = lp.with_exprs_and_input(exprs, new_inputs);
-            Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+            Ok(self.optional_apply_predicate(
+                lp,
+                local_predicates,
+                "predicate refers to a column that isn't in the input of the node",
+                lp_arena,
+                expr_arena,
+            ))
         }
     }
 
@@ -189,6 +223,7 @@ impl<'a> PredicatePushDown<'a> {
         &self,
         lp: IR,
         acc_predicates: PlHashMap<Arc<str>, ExprIR>,
+        reason: &'static str,
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
     ) -> PolarsResult<IR> {
@@ -213,19 +248,20 @@ impl<'a> PredicatePushDown<'a> {
 
         // all predicates are done locally
         let local_predicates = acc_predicates.into_values().collect::<Vec<_>>();
-        Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+        Ok(self.optional_apply_predicate(lp, local_predicates, reason, lp_arena, expr_arena))
     }
 
     fn no_pushdown(
         &self,
         lp: IR,
         acc_predicates: PlHashMap<Arc<str>, ExprIR>,
+        reason: &'static str,
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
     ) -> PolarsResult<IR> {
         // all predicates are done locally
         let local_predicates = acc_predicates.into_values().collect::<Vec<_>>();
-        Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+        Ok(self.optional_apply_predicate(lp, local_predicates, reason, lp_arena, expr_arena))
     }
 
     /// Predicate pushdown optimizer
@@ -262,8 +298,15 @@ impl<'a> PredicatePushDown<'a> {
                 //
                 // (2) can be pushed past (1) but they both have the same predicate
                 // key name in the hashtable.
-                let tmp_key = Arc::<str>::from(&*temporary_unique_key(&acc_predicates));
-                acc_predicates.insert(tmp_key.clone(), predicate.clone());
+                //
+                // Conjunctions containing window expressions are split, so that the parts
+                // referring only to the partition keys can still be pushed down.
+                let mut tmp_keys = vec![];
+                for predicate in split_window_conjunctions(predicate, expr_arena) {
+                    let tmp_key = Arc::<str>::from(&*temporary_unique_key(&acc_predicates));
+                    acc_predicates.insert(tmp_key.clone(), predicate);
+                    tmp_keys.push(tmp_key);
+                }
 
                 let local_predicates =
                     match pushdown_eligibility(&[], &acc_predicates, expr_arena)?.0 {
@@ -281,9 +324,18 @@ impl<'a> PredicatePushDown<'a> {
                             out
                         },
                     };
+                if !local_predicates.is_empty() {
+                    trace_blocked(
+                        "predicate_pushdown",
+                        &lp,
+                        "predicate depends on other rows, e.g. through a window or aggregation",
+                    );
+                }
 
-                if let Some(predicate) = acc_predicates.remove(&tmp_key) {
-                    insert_and_combine_predicate(&mut acc_predicates, &predicate, expr_arena);
+                for tmp_key in tmp_keys {
+                    if let Some(predicate) = acc_predicates.remove(&tmp_key) {
+                        insert_and_combine_predicate(&mut acc_predicates, &predicate, expr_arena);
+                    }
                 }
 
                 let alp = lp_arena.take(input);
@@ -294,14 +346,12 @@ impl<'a> PredicatePushDown<'a> {
                 // predicates, we simply don't pushdown this one passed this node
                 // However, we can do better and let it pass but store the order of the predicates
                 // so that we can apply them in correct order at the deepest level
-                Ok(
-                    self.optional_apply_predicate(
-                        new_input,
-                        local_predicates,
-                        lp_arena,
-                        expr_arena,
-                    ),
-                )
+                Ok(apply_predicates(
+                    new_input,
+                    local_predicates,
+                    lp_arena,
+                    expr_arena,
+                ))
             },
             DataFrameScan {
                 df,
@@ -353,7 +403,8 @@ impl<'a> PredicatePushDown<'a> {
                         }
                     },
                 };
-                let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena);
+                let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena)
+                    .map(|predicate| ranges::extract_ranges(predicate, expr_arena));
 
                 if let (true, Some(predicate)) = (file_info.hive_parts.is_some(), &predicate) {
                     if let Some(io_expr) = self.hive_partition_eval.unwrap()(predicate, expr_arena)
@@ -413,14 +464,16 @@ impl<'a> PredicatePushDown<'a> {
                 do_optimization &= predicate.is_some();
 
                 let lp = if do_optimization {
-                    Scan {
+                    let lp = Scan {
                         paths,
                         file_info,
                         predicate,
                         file_options: options,
                         output_schema,
                         scan_type,
-                    }
+                    };
+                    trace_applied("predicate_pushdown", &lp);
+                    lp
                 } else {
                     let lp = Scan {
                         paths,
@@ -431,6 +484,15 @@ impl<'a> PredicatePushDown<'a> {
                         scan_type,
                     };
                     if let Some(predicate) = predicate {
+                        let reason = match &lp {
+                            #[cfg(feature = "csv")]
+                            Scan {
+                                scan_type: FileScan::Csv { .. },
+                                ..
+                            } => "filtering before the row limit of the scan changes which rows are read",
+                            _ => "scan function doesn't accept predicates",
+                        };
+                        trace_blocked("predicate_pushdown", &lp, reason);
                         let input = lp_arena.add(lp);
                         Filter { input, predicate }
                     } else {
@@ -438,7 +500,13 @@ impl<'a> PredicatePushDown<'a> {
                     }
                 };
 
-                Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+                Ok(self.optional_apply_predicate(
+                    lp,
+                    local_predicates,
+                    "scan applies predicates before it adds the row index",
+                    lp_arena,
+                    expr_arena,
+                ))
             },
             Distinct { input, options } => {
                 if let Some(ref subset) = options.subset {
@@ -455,10 +523,22 @@ impl<'a> PredicatePushDown<'a> {
 
                     self.pushdown_and_assign(input, acc_predicates, lp_arena, expr_arena)?;
                     let lp = Distinct { input, options };
-                    Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+                    Ok(self.optional_apply_predicate(
+                        lp,
+                        local_predicates,
+                        "predicate refers to a column outside of the distinct subset",
+                        lp_arena,
+                        expr_arena,
+                    ))
                 } else {
                     let lp = Distinct { input, options };
-                    self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
+                    self.no_pushdown_restart_opt(
+                        lp,
+                        acc_predicates,
+                        "predicates only pass a distinct with a subset of columns",
+                        lp_arena,
+                        expr_arena,
+                    )
                 }
             },
             Join {
@@ -496,6 +576,7 @@ impl<'a> PredicatePushDown<'a> {
                             Ok(self.optional_apply_predicate(
                                 lp,
                                 local_predicates,
+                                "predicate refers to a renamed column together with other columns",
                                 lp_arena,
                                 expr_arena,
                             ))
@@ -521,6 +602,7 @@ impl<'a> PredicatePushDown<'a> {
                             Ok(self.optional_apply_predicate(
                                 lp,
                                 local_predicates,
+                                "predicate refers to an exploded column",
                                 lp_arena,
                                 expr_arena,
                             ))
@@ -552,6 +634,7 @@ impl<'a> PredicatePushDown<'a> {
                             Ok(self.optional_apply_predicate(
                                 lp,
                                 local_predicates,
+                                "predicate refers to a column that the melt consumes or creates",
                                 lp_arena,
                                 expr_arena,
                             ))
@@ -565,7 +648,13 @@ impl<'a> PredicatePushDown<'a> {
                         ),
                     }
                 } else {
-                    self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
+                    self.no_pushdown_restart_opt(
+                        lp,
+                        acc_predicates,
+                        "function doesn't allow predicates to pass",
+                        lp_arena,
+                        expr_arena,
+                    )
                 }
             },
             GroupBy {
@@ -603,7 +692,13 @@ impl<'a> PredicatePushDown<'a> {
                 });
                 let lp =
                     self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)?;
-                Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+                Ok(self.optional_apply_predicate(
+                    lp,
+                    local_predicates,
+                    "predicate counts the rows, which the union changes",
+                    lp_arena,
+                    expr_arena,
+                ))
             },
             lp @ Sort { .. } => {
                 let mut local_predicates = vec![];
@@ -617,7 +712,13 @@ impl<'a> PredicatePushDown<'a> {
                 });
                 let lp =
                     self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)?;
-                Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+                Ok(self.optional_apply_predicate(
+                    lp,
+                    local_predicates,
+                    "predicate depends on the order of the rows",
+                    lp_arena,
+                    expr_arena,
+                ))
             },
             // Pushed down passed these nodes
             lp @ Sink { .. } => {
@@ -631,16 +732,30 @@ impl<'a> PredicatePushDown<'a> {
             },
             // NOT Pushed down passed these nodes
             // predicates influence slice sizes
-            lp @ Slice { .. } => {
-                self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
-            },
-            lp @ HConcat { .. } => {
-                self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
-            },
+            lp @ Slice { .. } | lp @ TopK { .. } => self.no_pushdown_restart_opt(
+                lp,
+                acc_predicates,
+                "filtering before a slice changes which rows are sliced",
+                lp_arena,
+                expr_arena,
+            ),
+            lp @ HConcat { .. } => self.no_pushdown_restart_opt(
+                lp,
+                acc_predicates,
+                "horizontal concatenation aligns the rows by position",
+                lp_arena,
+                expr_arena,
+            ),
             // Caches will run predicate push-down in the `cache_states` run.
             Cache { .. } => {
                 if self.block_at_cache {
-                    self.no_pushdown(lp, acc_predicates, lp_arena, expr_arena)
+                    self.no_pushdown(
+                        lp,
+                        acc_predicates,
+                        "cache is shared by several branches",
+                        lp_arena,
+                        expr_arena,
+                    )
                 } else {
                     self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)
                 }
@@ -694,6 +809,7 @@ impl<'a> PredicatePushDown<'a> {
                                 return Ok(self.optional_apply_predicate(
                                     lp,
                                     vec![predicate],
+                                    "predicate can't be translated to pyarrow",
                                     lp_arena,
                                     expr_arena,
                                 ));
@@ -705,6 +821,7 @@ impl<'a> PredicatePushDown<'a> {
                     self.no_pushdown_restart_opt(
                         PythonScan { options, predicate },
                         acc_predicates,
+                        "python scan doesn't accept predicates",
                         lp_arena,
                         expr_arena,
                     )
@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// A comparison of a column with a literal that bounds the values of the column.
+struct Bound {
+    value: LiteralValue,
+    inclusive: bool,
+    /// The comparison in the predicate.
+    node: Node,
+}
+
+/// The interval that the comparisons of a predicate restrict a column to.
+#[derive(Default)]
+struct ColumnRange {
+    lower: Option<Bound>,
+    upper: Option<Bound>,
+}
+
+/// Compare literals of the same dtype, `None` if they can't be ordered.
+fn compare(left: &LiteralValue, right: &LiteralValue) -> Option<Ordering> {
+    let (left, right) = (left.to_any_value()?, right.to_any_value()?);
+    if left.is_null() || right.is_null() || left.dtype() != right.dtype() {
+        return None;
+    }
+    if left.dtype().is_temporal() {
+        return left.extract::<i64>()?.partial_cmp(&right.extract::<i64>()?);
+    }
+    left.partial_cmp(&right)
+}
+
+/// Whether `new` is a tighter bound than `existing`, given that larger values are tighter
+/// bounds if `lower`.
+fn is_tighter(new: &Bound, existing: &Bound, lower: bool) -> Option<bool> {
+    let ordering = compare(&new.value, &existing.value)?;
+    let tighter = match ordering {
+        Ordering::Equal => !new.inclusive && existing.inclusive,
+        Ordering::Greater => lower,
+        Ordering::Less => !lower,
+    };
+    Some(tighter)
+}
+
+/// The column and bound of a `col(name) <op> lit(value)` comparison, or its mirror, and
+/// whether it is a lower bound.
+fn as_bound(node: Node, expr_arena: &Arena<AExpr>) -> Option<(Arc<str>, Bound, bool)> {
+    let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node) else {
+        return None;
+    };
+    let (name, value, mirrored) = match (expr_arena.get(*left), expr_arena.get(*right)) {
+        (AExpr::Column(name), AExpr::Literal(value)) => (name, value, false),
+        (AExpr::Literal(value), AExpr::Column(name)) => (name, value, true),
+        _ => return None,
+    };
+    if matches!(value, LiteralValue::Series(_) | LiteralValue::Range { .. }) {
+        return None;
+    }
+    let (inclusive, lower) = match op {
+        Operator::Gt => (false, true),
+        Operator::GtEq => (true, true),
+        Operator::Lt => (false, false),
+        Operator::LtEq => (true, false),
+        _ => return None,
+    };
+    // `lit(value) < col(name)` is a lower bound.
+    let lower = lower != mirrored;
+    let bound = Bound {
+        value: value.clone(),
+        inclusive,
+        node,
+    };
+    Some((name.clone(), bound, lower))
+}
+
+/// Extract the comparisons of columns with literals from the conjunction of a scan
+/// predicate into an interval per column, e.g. `col("ts") >= a & col("ts") < b`.
+///
+/// The predicate is rewritten to the intervals, keeping only the tightest bound of a column
+/// on either side, followed by the other conditions. The intervals are then on the left of
+/// the conjunction, so that the statistics of a scan can prune row groups on them, even if
+/// the other conditions can't be evaluated on statistics.
+pub(super) fn extract_ranges(predicate: ExprIR, expr_arena: &mut Arena<AExpr>) -> ExprIR {
+    let mut conjunction = vec![];
+    let mut stack = vec![predicate.node()];
+    while let Some(node) = stack.pop() {
+        match expr_arena.get(node) {
+            AExpr::BinaryExpr {
+                left,
+                op: Operator::And | Operator::LogicalAnd,
+                right,
+            } => {
+                stack.push(*right);
+                stack.push(*left);
+            },
+            _ => conjunction.push(node),
+        }
+    }
+
+    let mut ranges: Vec<(Arc<str>, ColumnRange)> = vec![];
+    let mut other = vec![];
+    for node in conjunction {
+        let Some((name, bound, lower)) = as_bound(node, expr_arena) else {
+            other.push(node);
+            continue;
+        };
+        let range = match ranges.iter().position(|(n, _)| *n == name) {
+            Some(i) => &mut ranges[i].1,
+            None => {
+                ranges.push((name, ColumnRange::default()));
+                &mut ranges.last_mut().unwrap().1
+            },
+        };
+        let slot = if lower {
+            &mut range.lower
+        } else {
+            &mut range.upper
+        };
+        match slot {
+            None => *slot = Some(bound),
+            Some(existing) => match is_tighter(&bound, existing, lower) {
+                Some(true) => *slot = Some(bound),
+                Some(false) => {},
+                // Bounds that can't be compared are both kept.
+                None => other.push(bound.node),
+            },
+        }
+    }
+    if ranges.is_empty() {
+        return predicate;
+    }
+
+    let node = ranges
+        .into_iter()
+        .flat_map(|(_, range)| [range.lower, range.upper])
+        .flatten()
+        .map(|bound| bound.node)
+        .chain(other)
+        .reduce(|left, right| {
+            expr_arena.add(AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            })
+        })
+        .unwrap();
+    ExprIR::from_node(node, expr_arena)
+}
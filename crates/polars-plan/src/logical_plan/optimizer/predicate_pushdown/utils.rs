@@ -58,6 +58,39 @@ where
         .expect("an empty iterator was passed")
 }
 
+/// Split a filter predicate into its top-level conjunctions if it contains a window
+/// expression. This allows the parts that only refer to the partition keys of the
+/// window to be pushed down, while the window-dependent parts stay at this level.
+///
+/// `.filter(len().over("key").gt(1) & col("key").eq(1))`
+/// is treated as
+/// `.filter(len().over("key").gt(1)).filter(col("key").eq(1))`
+pub(super) fn split_window_conjunctions(
+    predicate: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<ExprIR> {
+    if !has_aexpr_window(predicate.node(), expr_arena) {
+        return vec![predicate.clone()];
+    }
+
+    let mut out = vec![];
+    let mut stack = vec![predicate.node()];
+    while let Some(node) = stack.pop() {
+        match expr_arena.get(node) {
+            AExpr::BinaryExpr {
+                left,
+                op: Operator::And | Operator::LogicalAnd,
+                right,
+            } => {
+                stack.push(*right);
+                stack.push(*left);
+            },
+            _ => out.push(ExprIR::from_node(node, expr_arena)),
+        }
+    }
+    out
+}
+
 pub(super) fn predicate_at_scan(
     acc_predicates: PlHashMap<Arc<str>, ExprIR>,
     predicate: Option<ExprIR>,
@@ -397,9 +430,11 @@ pub fn pushdown_eligibility(
     }
 
     // Note: has_window is constant.
+    // A window key that is overwritten by this projection refers to a different column
+    // in the input, so it cannot be used either.
     let can_use_column = |col: &Arc<str>| {
         if has_window {
-            common_window_inputs.contains(col)
+            common_window_inputs.contains(col) && !modified_projection_columns.contains(col)
         } else {
             !modified_projection_columns.contains(col)
         }
@@ -65,21 +65,21 @@ pub(super) fn process_group_by(
             add_expr_to_accumulated(key.node(), &mut acc_projections, &mut names, expr_arena);
         }
 
-        // make sure that the dynamic key is projected
+        // make sure that the dynamic key and time zones are projected
         #[cfg(feature = "dynamic_group_by")]
         if let Some(options) = &options.dynamic {
-            let node = expr_arena.add(AExpr::Column(ColumnName::from(
-                options.index_column.as_str(),
-            )));
-            add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
+            for name in std::iter::once(&options.index_column).chain(&options.time_zone_column) {
+                let node = expr_arena.add(AExpr::Column(ColumnName::from(name.as_str())));
+                add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
+            }
         }
-        // make sure that the rolling key is projected
+        // make sure that the rolling key and time zones are projected
         #[cfg(feature = "dynamic_group_by")]
         if let Some(options) = &options.rolling {
-            let node = expr_arena.add(AExpr::Column(ColumnName::from(
-                options.index_column.as_str(),
-            )));
-            add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
+            for name in std::iter::once(&options.index_column).chain(&options.time_zone_column) {
+                let node = expr_arena.add(AExpr::Column(ColumnName::from(name.as_str())));
+                add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
+            }
         }
 
         proj_pd.pushdown_and_assign(
@@ -94,6 +94,20 @@ pub(super) fn process_asof_join(
                 );
             }
         }
+        // and that the column with the strategy of every row is projected.
+        if let Some(name) = &asof_options.strategy_column {
+            let add = _projected_names.contains(name.as_str());
+
+            let node = expr_arena.add(AExpr::Column(ColumnName::from(name.as_str())));
+            add_keys_to_accumulated_state(
+                node,
+                &mut pushdown_left,
+                &mut local_projection,
+                &mut names_left,
+                expr_arena,
+                add,
+            );
+        }
 
         // The join on keys can lead that columns are already added, we don't want to create
         // duplicates so store the names.
@@ -133,6 +147,14 @@ pub(super) fn process_asof_join(
         }
 
         for proj in acc_projections {
+            // The index of the matched right rows is created by the join itself.
+            if asof_options.right_index_name.as_deref()
+                == Some(column_node_to_name(proj, expr_arena).as_ref())
+            {
+                local_projection.push(proj);
+                continue;
+            }
+
             let add_local = if already_added_local_to_local_projected.is_empty() {
                 true
             } else {
@@ -196,7 +218,7 @@ pub(super) fn process_join(
     right_on: Vec<ExprIR>,
     options: Arc<JoinOptions>,
     acc_projections: Vec<ColumnNode>,
-    _projected_names: PlHashSet<Arc<str>>,
+    projected_names: PlHashSet<Arc<str>>,
     projections_seen: usize,
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
@@ -212,7 +234,7 @@ pub(super) fn process_join(
             right_on,
             options,
             acc_projections,
-            _projected_names,
+            projected_names,
             projections_seen,
             lp_arena,
             expr_arena,
@@ -244,27 +266,30 @@ pub(super) fn process_join(
         // duplicates so store the names.
         let mut already_added_local_to_local_projected = BTreeSet::new();
 
-        // We need the join columns so we push the projection downwards
+        // We need the join columns so we push the projection downwards.
+        // The key columns are only kept in the output if they are used upstream.
         for e in &left_on {
-            let local_name = add_keys_to_accumulated_state(
+            let add_local = key_is_projected(e, &projected_names, expr_arena);
+            if let Some(local_name) = add_keys_to_accumulated_state(
                 e.node(),
                 &mut pushdown_left,
                 &mut local_projection,
                 &mut names_left,
                 expr_arena,
-                true,
-            )
-            .unwrap();
-            already_added_local_to_local_projected.insert(local_name);
+                add_local,
+            ) {
+                already_added_local_to_local_projected.insert(local_name);
+            }
         }
         // In outer joins both columns remain. So `add_local=true` also for the right table
         let add_local = matches!(options.args.how, JoinType::Outer)
             && !options.args.coalesce.coalesce(&options.args.how);
         for e in &right_on {
-            // In case of outer joins we also add the columns.
+            // In case of outer joins we also add the columns if they are used upstream.
             // But before we do that we must check if the column wasn't already added by the lhs.
             let add_local = if add_local {
                 !already_added_local_to_local_projected.contains(e.output_name())
+                    && key_is_projected(e, &projected_names, expr_arena)
             } else {
                 false
             };
@@ -338,6 +363,16 @@ pub(super) fn process_join(
     ))
 }
 
+/// Whether the output column of a join key is referenced upstream.
+fn key_is_projected(
+    key: &ExprIR,
+    projected_names: &PlHashSet<Arc<str>>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let name = aexpr_to_leaf_name(key.node(), expr_arena);
+    projected_names.contains(&name)
+}
+
 fn process_projection(
     proj_pd: &mut ProjectionPushDown,
     schema_left: &Schema,
@@ -481,18 +481,58 @@ impl ProjectionPushDown {
                     sort_options,
                 })
             },
+            TopK {
+                input,
+                by_column,
+                offset,
+                len,
+                sort_options,
+            } => {
+                if !acc_projections.is_empty() {
+                    // Make sure that the column(s) used for the sort is projected
+                    by_column.iter().for_each(|node| {
+                        add_expr_to_accumulated(
+                            node.node(),
+                            &mut acc_projections,
+                            &mut projected_names,
+                            expr_arena,
+                        );
+                    });
+                }
+
+                self.pushdown_and_assign(
+                    input,
+                    acc_projections,
+                    projected_names,
+                    projections_seen,
+                    lp_arena,
+                    expr_arena,
+                )?;
+                Ok(TopK {
+                    input,
+                    by_column,
+                    offset,
+                    len,
+                    sort_options,
+                })
+            },
             Distinct { input, options } => {
                 // make sure that the set of unique columns is projected
                 if !acc_projections.is_empty() {
                     if let Some(subset) = options.subset.as_ref() {
-                        subset.iter().for_each(|name| {
-                            add_str_to_accumulated(
-                                name,
-                                &mut acc_projections,
-                                &mut projected_names,
-                                expr_arena,
-                            )
-                        })
+                        let keep_by = options.keep_by.as_deref();
+                        subset
+                            .iter()
+                            .map(|name| name.as_str())
+                            .chain(keep_by)
+                            .for_each(|name| {
+                                add_str_to_accumulated(
+                                    name,
+                                    &mut acc_projections,
+                                    &mut projected_names,
+                                    expr_arena,
+                                )
+                            })
                     } else {
                         // distinct needs all columns
                         let input_schema = lp_arena.get(input).schema(lp_arena);
@@ -2,7 +2,7 @@ use polars_utils::floor_divmod::FloorDivMod;
 use polars_utils::total_ord::ToTotalOrd;
 
 use crate::logical_plan::*;
-use crate::prelude::optimizer::simplify_functions::optimize_functions;
+use crate::prelude::optimizer::simplify_functions::{optimize_functions, simplify_not};
 
 macro_rules! eval_binary_same_type {
     ($lhs:expr, $rhs:expr, |$l: ident, $r: ident| $ret: expr) => {{
@@ -97,6 +97,9 @@ macro_rules! eval_binary_cmp_same_type {
             (LiteralValue::Boolean(x), LiteralValue::Boolean(y)) => {
                 Some(AExpr::Literal(LiteralValue::Boolean(x $operand y)))
             },
+            (LiteralValue::String(x), LiteralValue::String(y)) => {
+                Some(AExpr::Literal(LiteralValue::Boolean(x $operand y)))
+            },
             (LiteralValue::Int(x), LiteralValue::Int(y)) => {
                 Some(AExpr::Literal(LiteralValue::Boolean(x $operand y)))
             }
@@ -115,6 +118,10 @@ macro_rules! eval_binary_cmp_same_type {
 pub struct SimplifyBooleanRule {}
 
 impl OptimizationRule for SimplifyBooleanRule {
+    fn name(&self) -> &'static str {
+        "simplify_boolean"
+    }
+
     fn optimize_expr(
         &mut self,
         expr_arena: &mut Arena<AExpr>,
@@ -242,6 +249,17 @@ impl OptimizationRule for SimplifyBooleanRule {
             {
                 Some(AExpr::Literal(LiteralValue::Boolean(true)))
             },
+            // not(a & b) => !a | !b
+            // not(a | b) => !a & !b
+            // not(not(x)) => x
+            AExpr::Function {
+                input,
+                function: FunctionExpr::Boolean(BooleanFunction::Not),
+                options,
+            } => {
+                let (input, options) = (input[0].node(), *options);
+                simplify_not(input, &options, expr_arena)
+            },
             AExpr::Function {
                 input,
                 function: FunctionExpr::Negate,
@@ -251,6 +269,19 @@ impl OptimizationRule for SimplifyBooleanRule {
                 let ae = expr_arena.get(input.node());
                 eval_negate(ae)
             },
+            // x == x => x.is_not_null()
+            // Only in filter, as a null comparison is the same as false there.
+            AExpr::BinaryExpr {
+                left,
+                op: Operator::Eq | Operator::LtEq | Operator::GtEq,
+                right,
+            } if in_filter => match (expr_arena.get(*left), expr_arena.get(*right)) {
+                (AExpr::Column(l), AExpr::Column(r)) if l == r => {
+                    let node = to_aexpr(col(l).is_not_null(), expr_arena);
+                    Some(expr_arena.get(node).clone())
+                },
+                _ => None,
+            },
             _ => None,
         };
         Ok(out)
@@ -428,6 +459,10 @@ fn string_addition_to_linear_concat(
 pub struct SimplifyExprRule {}
 
 impl OptimizationRule for SimplifyExprRule {
+    fn name(&self) -> &'static str {
+        "simplify_expr"
+    }
+
     #[allow(clippy::float_cmp)]
     fn optimize_expr(
         &mut self,
@@ -611,6 +646,23 @@ impl OptimizationRule for SimplifyExprRule {
 
                 None
             },
+            // repeat_by(by).explode() -> repeat_by_exploded(by)
+            #[cfg(feature = "repeat_by")]
+            AExpr::Explode(input) => match expr_arena.get(*input) {
+                AExpr::Function {
+                    input,
+                    function: FunctionExpr::RepeatBy,
+                    options,
+                } => Some(AExpr::Function {
+                    input: input.clone(),
+                    function: FunctionExpr::RepeatByExploded,
+                    options: FunctionOptions {
+                        changes_length: true,
+                        ..*options
+                    },
+                }),
+                _ => None,
+            },
             AExpr::Function {
                 input,
                 function,
@@ -77,59 +77,12 @@ pub(super) fn optimize_functions(
             }
         },
         FunctionExpr::Boolean(BooleanFunction::Not) => {
+            if let Some(out) = simplify_not(input[0].node(), options, expr_arena) {
+                return Ok(Some(out));
+            }
             let y = expr_arena.get(input[0].node());
 
             match y {
-                // not(a and b) => not(a) or not(b)
-                AExpr::BinaryExpr {
-                    left,
-                    op: Operator::And | Operator::LogicalAnd,
-                    right,
-                } => {
-                    let left = *left;
-                    let right = *right;
-                    Some(AExpr::BinaryExpr {
-                        left: expr_arena.add(AExpr::Function {
-                            input: vec![ExprIR::from_node(left, expr_arena)],
-                            function: FunctionExpr::Boolean(BooleanFunction::Not),
-                            options: *options,
-                        }),
-                        op: Operator::Or,
-                        right: expr_arena.add(AExpr::Function {
-                            input: vec![ExprIR::from_node(right, expr_arena)],
-                            function: FunctionExpr::Boolean(BooleanFunction::Not),
-                            options: *options,
-                        }),
-                    })
-                },
-                // not(a or b) => not(a) and not(b)
-                AExpr::BinaryExpr {
-                    left,
-                    op: Operator::Or | Operator::LogicalOr,
-                    right,
-                } => {
-                    let left = *left;
-                    let right = *right;
-                    Some(AExpr::BinaryExpr {
-                        left: expr_arena.add(AExpr::Function {
-                            input: vec![ExprIR::from_node(left, expr_arena)],
-                            function: FunctionExpr::Boolean(BooleanFunction::Not),
-                            options: *options,
-                        }),
-                        op: Operator::And,
-                        right: expr_arena.add(AExpr::Function {
-                            input: vec![ExprIR::from_node(right, expr_arena)],
-                            function: FunctionExpr::Boolean(BooleanFunction::Not),
-                            options: *options,
-                        }),
-                    })
-                },
-                // not(not x) => x
-                AExpr::Function {
-                    input,
-                    function: FunctionExpr::Boolean(BooleanFunction::Not),
-                    ..
-                } => Some(expr_arena.get(input[0].node()).clone()),
                 // not(lit x) => !x
                 AExpr::Literal(LiteralValue::Boolean(b)) => {
                     Some(AExpr::Literal(LiteralValue::Boolean(!b)))
@@ -294,3 +247,65 @@ fn get_string_concat_input(
         _ => None,
     }
 }
+
+/// Push a `not` into the `&` and `|` of its input with De Morgan's laws, and remove double
+/// negations.
+pub(super) fn simplify_not(
+    input: Node,
+    options: &FunctionOptions,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<AExpr> {
+    match expr_arena.get(input) {
+        // not(a and b) => not(a) or not(b)
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            let left = *left;
+            let right = *right;
+            Some(AExpr::BinaryExpr {
+                left: expr_arena.add(AExpr::Function {
+                    input: vec![ExprIR::from_node(left, expr_arena)],
+                    function: FunctionExpr::Boolean(BooleanFunction::Not),
+                    options: *options,
+                }),
+                op: Operator::Or,
+                right: expr_arena.add(AExpr::Function {
+                    input: vec![ExprIR::from_node(right, expr_arena)],
+                    function: FunctionExpr::Boolean(BooleanFunction::Not),
+                    options: *options,
+                }),
+            })
+        },
+        // not(a or b) => not(a) and not(b)
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::Or | Operator::LogicalOr,
+            right,
+        } => {
+            let left = *left;
+            let right = *right;
+            Some(AExpr::BinaryExpr {
+                left: expr_arena.add(AExpr::Function {
+                    input: vec![ExprIR::from_node(left, expr_arena)],
+                    function: FunctionExpr::Boolean(BooleanFunction::Not),
+                    options: *options,
+                }),
+                op: Operator::And,
+                right: expr_arena.add(AExpr::Function {
+                    input: vec![ExprIR::from_node(right, expr_arena)],
+                    function: FunctionExpr::Boolean(BooleanFunction::Not),
+                    options: *options,
+                }),
+            })
+        },
+        // not(not x) => x
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::Not),
+            ..
+        } => Some(expr_arena.get(input[0].node()).clone()),
+        _ => None,
+    }
+}
@@ -1,6 +1,9 @@
+use std::path::Path;
+
 use polars_core::prelude::*;
 use recursive::recursive;
 
+use super::{trace_applied, trace_blocked};
 use crate::logical_plan::projection_expr::ProjectionExprs;
 use crate::prelude::*;
 
@@ -58,6 +61,77 @@ fn can_pushdown_slice_past_projections(
     (true, all_elementwise_and_any_expr_has_column)
 }
 
+/// Why a slice can't be pushed past `lp`, for the optimizer trace. Only valid for the nodes
+/// that a slice reaches and doesn't pass.
+fn blocking_reason(lp: &IR) -> &'static str {
+    use IR::*;
+    match lp {
+        Filter { .. } => "filter changes which rows are sliced",
+        MapFunction {
+            function: FunctionNode::Explode { .. },
+            ..
+        } => "explode changes the number of rows",
+        MapFunction {
+            function: FunctionNode::Melt { .. },
+            ..
+        } => "melt multiplies the rows, so only a slice from the start is pushed down",
+        MapFunction { .. } => "function may change the number or order of rows",
+        Cache { .. } => "cache is shared by several branches",
+        Join { .. } => "join doesn't accept a slice in the streaming engine",
+        TopK { .. } => "top-k already limits the rows",
+        DataFrameScan { .. } => "in-memory frame is sliced once it is scanned",
+        Scan {
+            predicate: Some(_), ..
+        } => "predicate of the scan is applied before the slice",
+        #[cfg(feature = "python")]
+        PythonScan {
+            predicate: Some(_), ..
+        } => "predicate of the scan is applied before the slice",
+        Scan { .. } => "scan can't skip the leading rows",
+        #[cfg(feature = "python")]
+        PythonScan { .. } => "scan can't skip the leading rows",
+        _ => "node doesn't accept a slice",
+    }
+}
+
+/// Let the reader of a file scan skip `skip_rows` leading rows. Returns `false` if the scan
+/// doesn't support this.
+#[allow(unused_variables)]
+fn set_scan_skip_rows(scan_type: &mut FileScan, skip_rows: usize) -> bool {
+    match scan_type {
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet { options, .. } => {
+            options.skip_rows = skip_rows;
+            true
+        },
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc { options, .. } => {
+            options.skip_rows = skip_rows;
+            true
+        },
+        _ => false,
+    }
+}
+
+/// The number of rows of a single file scan. Parquet scans know it from the metadata, local
+/// IPC files are counted from their record batch headers.
+#[allow(unused_variables)]
+fn scan_num_rows(path: &Path, file_info: &FileInfo, scan_type: &FileScan) -> Option<usize> {
+    if let Some(num_rows) = file_info.row_estimation.0 {
+        return Some(num_rows);
+    }
+    match scan_type {
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc { .. } if !polars_io::utils::is_cloud_url(path) => {
+            let mut reader = std::io::BufReader::new(polars_utils::open_file(path).ok()?);
+            arrow::io::ipc::read::get_row_count(&mut reader)
+                .ok()
+                .map(|n| n as usize)
+        },
+        _ => None,
+    }
+}
+
 impl SlicePushDown {
     pub(super) fn new(streaming: bool) -> Self {
         Self {
@@ -94,9 +168,13 @@ impl SlicePushDown {
         &self,
         lp: IR,
         state: Option<State>,
+        reason: &'static str,
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
     ) -> PolarsResult<IR> {
+        if state.is_some() {
+            trace_blocked("slice_pushdown", &lp, reason);
+        }
         let inputs = lp.get_inputs();
         let exprs = lp.get_exprs();
 
@@ -163,6 +241,7 @@ impl SlicePushDown {
                     options,
                     predicate
                 };
+                trace_applied("slice_pushdown", &lp);
                 Ok(lp)
             }
             #[cfg(feature = "csv")]
@@ -185,8 +264,67 @@ impl SlicePushDown {
                     file_options: options,
                     predicate,
                 };
+                trace_applied("slice_pushdown", &lp);
                 Ok(lp)
             },
+            // The parquet reader can stop once enough rows passed the predicate. The slice is
+            // still applied on top as the reader may return more rows.
+            #[cfg(feature = "parquet")]
+            (Scan {
+                paths,
+                file_info,
+                output_schema,
+                file_options,
+                predicate: Some(predicate),
+                scan_type: FileScan::Parquet {mut options, cloud_options, metadata, nested_projection, decryption}
+            }, Some(state)) if state.offset >= 0 && file_options.row_index.is_none() => {
+                options.n_rows_after_predicate = Some((state.offset as usize).saturating_add(state.len as usize));
+                let lp = Scan {
+                    paths,
+                    file_info,
+                    output_schema,
+                    scan_type: FileScan::Parquet {options, cloud_options, metadata, nested_projection, decryption},
+                    file_options,
+                    predicate: Some(predicate),
+                };
+                trace_applied("slice_pushdown", &lp);
+                self.no_pushdown_finish_opt(lp, Some(state), lp_arena)
+            },
+            // A negative offset is resolved against the row count of the file, so the reader
+            // can skip the leading rows. The slice is still applied on top.
+            (Scan {
+                paths,
+                file_info,
+                output_schema,
+                file_options,
+                predicate: None,
+                mut scan_type,
+            }, Some(state)) if state.offset < 0
+                && paths.len() == 1
+                && file_options.n_rows.is_none() => {
+                // Only count the rows here, as this may have to read the file.
+                let applied = match scan_num_rows(&paths[0], &file_info, &scan_type) {
+                    Some(num_rows) => {
+                        let skip_rows = (num_rows as i64).saturating_add(state.offset).max(0) as usize;
+                        set_scan_skip_rows(&mut scan_type, skip_rows)
+                    },
+                    None => false,
+                };
+                let lp = Scan {
+                    paths,
+                    file_info,
+                    output_schema,
+                    file_options,
+                    predicate: None,
+                    scan_type,
+                };
+                if applied {
+                    trace_applied("slice_pushdown", &lp);
+                } else {
+                    trace_blocked("slice_pushdown", &lp, blocking_reason(&lp));
+                }
+                self.no_pushdown_finish_opt(lp, Some(state), lp_arena)
+            },
             // TODO! we currently skip slice pushdown if there is a predicate.
             (Scan {
                 paths,
@@ -205,7 +343,7 @@ impl SlicePushDown {
                     file_options: options,
                     scan_type
                 };
-
+                trace_applied("slice_pushdown", &lp);
                 Ok(lp)
             }
             (Union {mut inputs, mut options }, Some(state)) => {
@@ -217,8 +355,46 @@ impl SlicePushDown {
                         lp_arena.replace(*input, input_lp);
                     }
                 }
-                Ok(Union {inputs, options})
+                let lp = Union {inputs, options};
+                trace_applied("slice_pushdown", &lp);
+                Ok(lp)
             },
+            // [Pushdown a prefix]
+            // Output row `i` of a cross join combines left row `i / n_right` with right row
+            // `i % n_right`, so the first `k` output rows only need the first `k` rows of
+            // both inputs. The join itself still takes the slice.
+            (Join {
+                input_left,
+                input_right,
+                schema,
+                left_on,
+                right_on,
+                mut options
+            }, Some(state)) if !self.streaming && state.offset >= 0 && matches!(options.args.how, JoinType::Cross) => {
+                let prefix = State {
+                    offset: 0,
+                    len: (state.offset as u64 + state.len as u64).min(IdxSize::MAX as u64) as IdxSize,
+                };
+                for input in [input_left, input_right] {
+                    let input_lp = lp_arena.take(input);
+                    let input_lp = self.pushdown(input_lp, Some(prefix), lp_arena, expr_arena)?;
+                    lp_arena.replace(input, input_lp);
+                }
+
+                let mut_options = Arc::make_mut(&mut options);
+                mut_options.args.slice = Some((state.offset, state.len as usize));
+
+                let lp = Join {
+                    input_left,
+                    input_right,
+                    schema,
+                    left_on,
+                    right_on,
+                    options
+                };
+                trace_applied("slice_pushdown", &lp);
+                Ok(lp)
+            }
             (Join {
                 input_left,
                 input_right,
@@ -241,14 +417,16 @@ impl SlicePushDown {
                 let mut_options = Arc::make_mut(&mut options);
                 mut_options.args.slice = Some((state.offset, state.len as usize));
 
-                Ok(Join {
+                let lp = Join {
                     input_left,
                     input_right,
                     schema,
                     left_on,
                     right_on,
                     options
-                })
+                };
+                trace_applied("slice_pushdown", &lp);
+                Ok(lp)
             }
             (GroupBy { input, keys, aggs, schema, apply, maintain_order, mut options }, Some(state)) => {
                 // first restart optimization in inputs and get the updated LP
@@ -259,7 +437,7 @@ impl SlicePushDown {
                 let mut_options= Arc::make_mut(&mut options);
                 mut_options.slice = Some((state.offset, state.len as usize));
 
-                Ok(GroupBy {
+                let lp = GroupBy {
                     input,
                     keys,
                     aggs,
@@ -267,7 +445,9 @@ impl SlicePushDown {
                     apply,
                     maintain_order,
                     options
-                })
+                };
+                trace_applied("slice_pushdown", &lp);
+                Ok(lp)
             }
             (Distinct {input, mut options}, Some(state)) => {
                 // first restart optimization in inputs and get the updated LP
@@ -275,10 +455,30 @@ impl SlicePushDown {
                 let input_lp = self.pushdown(input_lp, None, lp_arena, expr_arena)?;
                 let input= lp_arena.add(input_lp);
                 options.slice = Some((state.offset, state.len as usize));
-                Ok(Distinct {
+                let lp = Distinct {
                     input,
                     options,
-                })
+                };
+                trace_applied("slice_pushdown", &lp);
+                Ok(lp)
+            }
+            // [Rewrite] a sort followed by a slice that starts at a known position only needs
+            // to keep `offset + len` rows.
+            (Sort {input, by_column, sort_options, ..}, Some(state)) if state.offset >= 0 => {
+                // first restart optimization in inputs and get the updated LP
+                let input_lp = lp_arena.take(input);
+                let input_lp = self.pushdown(input_lp, None, lp_arena, expr_arena)?;
+                let input= lp_arena.add(input_lp);
+
+                let lp = TopK {
+                    input,
+                    by_column,
+                    offset: state.offset as usize,
+                    len: state.len as usize,
+                    sort_options
+                };
+                trace_applied("slice_pushdown", &lp);
+                Ok(lp)
             }
             (Sort {input, by_column, mut slice,
                 sort_options}, Some(state)) => {
@@ -288,12 +488,14 @@ impl SlicePushDown {
                 let input= lp_arena.add(input_lp);
 
                 slice = Some((state.offset, state.len as usize));
-                Ok(Sort {
+                let lp = Sort {
                     input,
                     by_column,
                     slice,
                     sort_options
-                })
+                };
+                trace_applied("slice_pushdown", &lp);
+                Ok(lp)
             }
             (Slice {
                 input,
@@ -332,6 +534,20 @@ impl SlicePushDown {
                 });
                 self.pushdown(alp, state, lp_arena, expr_arena)
             }
+            // [Pushdown a prefix]
+            // Every output row of a melt comes from an input row at the same or an earlier
+            // position, so the first `len` output rows only need the first `len` input rows.
+            // The slice itself remains to cut the multiplied rows.
+            (MapFunction {
+                input,
+                function: function @ FunctionNode::Melt {..}
+            }, Some(state)) if state.offset == 0 => {
+                let input_lp = lp_arena.take(input);
+                let input_lp = self.pushdown(input_lp, Some(state), lp_arena, expr_arena)?;
+                lp_arena.replace(input, input_lp);
+                let lp = MapFunction {input, function};
+                self.no_pushdown_finish_opt(lp, Some(state), lp_arena)
+            }
             // [Do not pushdown] boundary
             // here we do not pushdown.
             // we reset the state and then start the optimization again
@@ -339,6 +555,7 @@ impl SlicePushDown {
             // other blocking nodes
             | m @ (DataFrameScan {..}, _)
             | m @ (Sort {..}, _)
+            | m @ (TopK {..}, _)
             | m @ (MapFunction {function: FunctionNode::Explode {..}, ..}, _)
             | m @ (MapFunction {function: FunctionNode::Melt {..}, ..}, _)
             | m @ (Cache {..}, _)
@@ -348,7 +565,8 @@ impl SlicePushDown {
             | m @ (Join{..},_)
             => {
                 let (lp, state) = m;
-                self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
+                let reason = blocking_reason(&lp);
+                self.no_pushdown_restart_opt(lp, state, reason, lp_arena, expr_arena)
             }
             // [Pushdown]
             (MapFunction {input, function}, _) if function.allow_predicate_pd() => {
@@ -358,7 +576,8 @@ impl SlicePushDown {
             // [NO Pushdown]
             m @ (MapFunction {..}, _) => {
                 let (lp, state) = m;
-                self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
+                let reason = blocking_reason(&lp);
+                self.no_pushdown_restart_opt(lp, state, reason, lp_arena, expr_arena)
             }
             // [Pushdown]
             // these nodes will be pushed down.
@@ -370,14 +589,20 @@ impl SlicePushDown {
             }
             // there is state, inspect the projection to determine how to deal with it
             (Select {input, expr, schema, options}, Some(_)) => {
-                if can_pushdown_slice_past_projections(&expr, expr_arena).1 {
+                let check = can_pushdown_slice_past_projections(&expr, expr_arena);
+                if check.1 {
                     let lp = Select {input, expr, schema, options};
                     self.pushdown_and_continue(lp, state, lp_arena, expr_arena)
                 }
                 // don't push down slice, but restart optimization
                 else {
+                    let reason = if check.0 {
+                        "projection doesn't refer to any column of its input"
+                    } else {
+                        "projection is not elementwise"
+                    };
                     let lp = Select {input, expr, schema, options};
-                    self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
+                    self.no_pushdown_restart_opt(lp, state, reason, lp_arena, expr_arena)
                 }
             }
             (HStack {input, exprs, schema, options}, _) => {
@@ -395,8 +620,13 @@ impl SlicePushDown {
                 }
                 // don't push down slice, but restart optimization
                 else {
+                    let reason = if check.0 {
+                        "projection doesn't refer to any column of its input"
+                    } else {
+                        "projection is not elementwise"
+                    };
                     let lp = HStack {input, exprs, schema, options};
-                    self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
+                    self.no_pushdown_restart_opt(lp, state, reason, lp_arena, expr_arena)
                 }
             }
             (HConcat {inputs, schema, options}, _) => {
@@ -405,6 +635,9 @@ impl SlicePushDown {
                 self.pushdown_and_continue(lp, state, lp_arena, expr_arena)
             }
             (catch_all, state) => {
+                if state.is_some() {
+                    trace_blocked("slice_pushdown", &catch_all, blocking_reason(&catch_all));
+                }
                 self.no_pushdown_finish_opt(catch_all, state, lp_arena)
             }
         }
@@ -2,6 +2,7 @@ use polars_core::prelude::PolarsResult;
 
 use crate::logical_plan::aexpr::AExpr;
 use crate::logical_plan::alp::IR;
+use crate::logical_plan::optimizer::trace_applied;
 use crate::prelude::{Arena, Node};
 
 /// Optimizer that uses a stack and memory arenas in favor of recursion
@@ -32,6 +33,7 @@ impl StackOptimizer {
                 for rule in rules.iter_mut() {
                     // keep iterating over same rule
                     while let Some(x) = rule.optimize_plan(lp_arena, expr_arena, current_node) {
+                        trace_applied(rule.name(), &x);
                         lp_arena.replace(current_node, x);
                         changed = true;
                     }
@@ -67,6 +69,7 @@ impl StackOptimizer {
                             lp_arena,
                             current_node,
                         )? {
+                            trace_applied(rule.name(), lp_arena.get(current_node));
                             expr_arena.replace(current_expr_node, x);
                             changed = true;
                         }
@@ -83,6 +86,12 @@ impl StackOptimizer {
 }
 
 pub trait OptimizationRule {
+    /// The name of the rule in the optimizer trace.
+    fn name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
     ///  Optimize (subplan) in LogicalPlan
     ///
     /// * `lp_arena` - LogicalPlan memory arena
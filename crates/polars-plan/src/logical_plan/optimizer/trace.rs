@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::IR;
+
+// Set while an optimizer trace is recorded, see [`trace_optimizer`].
+thread_local! {static TRACE: RefCell<Option<Vec<OptimizerEvent>>> = const { RefCell::new(None) }}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OptimizerOutcome {
+    Applied,
+    /// The optimization could not be applied at this node, with the reason why.
+    Blocked(&'static str),
+}
+
+/// A decision of the optimizer at a node of the plan.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OptimizerEvent {
+    /// The optimization, e.g. `slice_pushdown` or the name of an optimization rule.
+    pub optimization: &'static str,
+    /// The name of the node, as shown in the plan.
+    pub node: &'static str,
+    pub outcome: OptimizerOutcome,
+}
+
+impl OptimizerEvent {
+    pub fn is_blocked(&self) -> bool {
+        matches!(self.outcome, OptimizerOutcome::Blocked(_))
+    }
+}
+
+impl Display for OptimizerEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.outcome {
+            OptimizerOutcome::Applied => {
+                write!(f, "{} applied at {}", self.optimization, self.node)
+            },
+            OptimizerOutcome::Blocked(reason) => write!(
+                f,
+                "{} blocked at {}: {}",
+                self.optimization, self.node, reason
+            ),
+        }
+    }
+}
+
+fn record(optimization: &'static str, lp: &IR, outcome: OptimizerOutcome) {
+    TRACE.with(|trace| {
+        if let Some(events) = trace.borrow_mut().as_mut() {
+            events.push(OptimizerEvent {
+                optimization,
+                node: lp.name(),
+                outcome,
+            })
+        }
+    })
+}
+
+/// Record that `optimization` was applied at `lp` if a trace is being recorded.
+pub(crate) fn trace_applied(optimization: &'static str, lp: &IR) {
+    record(optimization, lp, OptimizerOutcome::Applied)
+}
+
+/// Record that `optimization` was blocked at `lp` if a trace is being recorded.
+pub(crate) fn trace_blocked(optimization: &'static str, lp: &IR, reason: &'static str) {
+    record(optimization, lp, OptimizerOutcome::Blocked(reason))
+}
+
+/// Run `f` and return the decisions the optimizer made on this thread while it ran.
+pub fn trace_optimizer<T>(f: impl FnOnce() -> T) -> (T, Vec<OptimizerEvent>) {
+    let outer = TRACE.with(|trace| trace.replace(Some(vec![])));
+    let out = f();
+    let events = TRACE.with(|trace| trace.replace(outer)).unwrap_or_default();
+    (out, events)
+}
@@ -205,6 +205,7 @@ pub(super) fn process_binary(
     node_left: Node,
     op: Operator,
     node_right: Node,
+    cast_policy: CastPolicy,
 ) -> PolarsResult<Option<AExpr>> {
     let input_schema = get_schema(lp_arena, lp_node);
     let (left, type_left): (&AExpr, DataType) =
@@ -246,6 +247,16 @@ pub(super) fn process_binary(
     }
 
     use DataType::*;
+    if cast_policy.is_strict() && op.is_comparison() {
+        let is_str = |dt: &DataType| matches!(dt, String | Unknown(UnknownKind::Str));
+        polars_ensure!(
+            !(is_str(&type_left) && type_right.is_numeric()
+                || is_str(&type_right) && type_left.is_numeric()),
+            InvalidOperation: "cannot compare {} with {} under the strict cast policy, add an explicit cast",
+            type_left, type_right
+        );
+    }
+
     // don't coerce string with number comparisons. They must error
     match (&type_left, &type_right, op) {
         #[cfg(not(feature = "dtype-categorical"))]
@@ -326,6 +337,19 @@ pub(super) fn process_binary(
             st = String
         }
 
+        // Literals are typed by their value, so only check the casts of materialized columns.
+        for (ae, node, dtype) in [
+            (left, node_left, &type_left),
+            (right, node_right, &type_right),
+        ] {
+            if cast_policy.is_strict() && !matches!(ae, AExpr::Literal(_)) {
+                // Expressions such as `len()` have no output name to report.
+                let name = aexpr_output_name(node, expr_arena)
+                    .map_or_else(|_| "expression".to_string(), |name| name.to_string());
+                check_implicit_cast(&name, dtype, &st, cast_policy)?;
+            }
+        }
+
         // only cast if the type is not already the super type.
         // this can prevent an expensive flattening and subsequent aggregation
         // in a group_by context. To be able to cast the groups need to be
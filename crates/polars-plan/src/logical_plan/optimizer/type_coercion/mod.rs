@@ -11,7 +11,16 @@ use polars_utils::unitvec;
 use super::*;
 use crate::logical_plan::optimizer::type_coercion::binary::process_binary;
 
-pub struct TypeCoercionRule {}
+#[derive(Default)]
+pub struct TypeCoercionRule {
+    cast_policy: CastPolicy,
+}
+
+impl TypeCoercionRule {
+    pub fn new(cast_policy: CastPolicy) -> Self {
+        Self { cast_policy }
+    }
+}
 
 macro_rules! unpack {
     ($packed:expr) => {
@@ -22,6 +31,34 @@ macro_rules! unpack {
     };
 }
 
+/// Whether an implicit cast from `from` to `to` may not preserve all values.
+fn is_lossy_implicit_cast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    match to {
+        Float32 => {
+            matches!(from, Int32 | Int64 | UInt32 | UInt64 | Float64)
+        },
+        Float64 => matches!(from, Int64 | UInt64),
+        dt if dt.is_integer() => from.is_float(),
+        _ => false,
+    }
+}
+
+/// Raise an error if the `cast_policy` doesn't allow the implicit cast of `name` to `to`.
+pub(crate) fn check_implicit_cast(
+    name: &str,
+    from: &DataType,
+    to: &DataType,
+    cast_policy: CastPolicy,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        !(cast_policy.is_strict() && is_lossy_implicit_cast(from, to)),
+        InvalidOperation: "implicit cast of '{}' from {} to {} may lose precision under the strict cast policy, \
+        add an explicit cast", name, from, to
+    );
+    Ok(())
+}
+
 /// determine if we use the supertype or not. For instance when we have a column Int64 and we compare with literal UInt32
 /// it would be wasteful to cast the column instead of the literal.
 fn modify_supertype(
@@ -108,6 +145,10 @@ fn materialize(aexpr: &AExpr) -> Option<AExpr> {
 }
 
 impl OptimizationRule for TypeCoercionRule {
+    fn name(&self) -> &'static str {
+        "type_coercion"
+    }
+
     fn optimize_expr(
         &mut self,
         expr_arena: &mut Arena<AExpr>,
@@ -177,7 +218,17 @@ impl OptimizationRule for TypeCoercionRule {
                 left: node_left,
                 op,
                 right: node_right,
-            } => return process_binary(expr_arena, lp_arena, lp_node, node_left, op, node_right),
+            } => {
+                return process_binary(
+                    expr_arena,
+                    lp_arena,
+                    lp_node,
+                    node_left,
+                    op,
+                    node_right,
+                    self.cast_policy,
+                )
+            },
             #[cfg(feature = "is_in")]
             AExpr::Function {
                 function: FunctionExpr::Boolean(BooleanFunction::IsIn),
@@ -334,6 +385,56 @@ impl OptimizationRule for TypeCoercionRule {
                 })
             },
             // generic type coercion of any function.
+            AExpr::Function {
+                function: FunctionExpr::CaseWhen,
+                ref input,
+                options,
+            } => {
+                let input_schema = get_schema(lp_arena, lp_node);
+                // The values come first, followed by `otherwise` and the conditions.
+                let n_values = input.len() / 2 + 1;
+                let mut dtypes = Vec::with_capacity(n_values);
+                for e in &input[..n_values] {
+                    let (_, dtype) =
+                        unpack!(get_aexpr_and_type(expr_arena, e.node(), &input_schema));
+                    if matches!(dtype, DataType::Unknown(UnknownKind::Any)) {
+                        return Ok(None);
+                    }
+                    dtypes.push(dtype);
+                }
+                if dtypes.iter().all_equal() {
+                    return Ok(None);
+                }
+
+                let mut super_type = dtypes[0].clone();
+                for dtype in &dtypes[1..] {
+                    super_type = unpack!(get_supertype(&super_type, dtype));
+                }
+                match super_type {
+                    DataType::Unknown(UnknownKind::Float) => super_type = DataType::Float64,
+                    DataType::Unknown(UnknownKind::Int(v)) => {
+                        super_type = materialize_dyn_int(v).dtype()
+                    },
+                    _ => {},
+                }
+
+                let mut input = input.clone();
+                for (e, dtype) in input.iter_mut().zip(dtypes) {
+                    if dtype != super_type {
+                        let n = expr_arena.add(AExpr::Cast {
+                            expr: e.node(),
+                            data_type: super_type.clone(),
+                            strict: true,
+                        });
+                        e.set_node(n);
+                    }
+                }
+                Some(AExpr::Function {
+                    function: FunctionExpr::CaseWhen,
+                    input,
+                    options,
+                })
+            },
             AExpr::Function {
                 // only for `DataType::Unknown` as it still has to be set.
                 ref function,
@@ -546,7 +647,7 @@ mod test {
         let mut expr_arena = Arena::new();
         let mut lp_arena = Arena::new();
         let optimizer = StackOptimizer {};
-        let rules: &mut [Box<dyn OptimizationRule>] = &mut [Box::new(TypeCoercionRule {})];
+        let rules: &mut [Box<dyn OptimizationRule>] = &mut [Box::new(TypeCoercionRule::default())];
 
         let df = DataFrame::new(Vec::from([Series::new_empty(
             "fruits",
@@ -559,7 +660,15 @@ mod test {
             .project(expr_in.clone(), Default::default())
             .build();
 
-        let mut lp_top = to_alp(lp, &mut expr_arena, &mut lp_arena, true, true).unwrap();
+        let mut lp_top = to_alp(
+            lp,
+            &mut expr_arena,
+            &mut lp_arena,
+            true,
+            true,
+            Default::default(),
+        )
+        .unwrap();
         lp_top = optimizer
             .optimize_loop(rules, &mut expr_arena, &mut lp_arena, lp_top)
             .unwrap();
@@ -574,7 +683,15 @@ mod test {
         let lp = DslBuilder::from_existing_df(df)
             .project(expr_in, Default::default())
             .build();
-        let mut lp_top = to_alp(lp, &mut expr_arena, &mut lp_arena, true, true).unwrap();
+        let mut lp_top = to_alp(
+            lp,
+            &mut expr_arena,
+            &mut lp_arena,
+            true,
+            true,
+            Default::default(),
+        )
+        .unwrap();
         lp_top = optimizer
             .optimize_loop(rules, &mut expr_arena, &mut lp_arena, lp_top)
             .unwrap();
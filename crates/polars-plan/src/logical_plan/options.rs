@@ -3,13 +3,15 @@ use std::path::PathBuf;
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
+#[cfg(feature = "streaming")]
+use polars_io::ipc::IpcCompression;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::write::ParquetWriteOptions;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::parquet::write::ParquetSinkOptions;
+use polars_io::{HiveOptions, PartitionedWriteOptions, RowIndex, SplitWriteOptions};
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
 #[cfg(feature = "serde")]
@@ -51,6 +53,17 @@ pub struct HConcatOptions {
     pub parallel: bool,
 }
 
+/// How the function of a group by apply is called on the groups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MapGroupsOptions {
+    /// Call the function on one group after the other instead of on many groups in
+    /// parallel, e.g. because it holds a global lock such as the python GIL.
+    pub sequential: bool,
+    /// Check that the output of every group has the declared schema.
+    pub validate_output: bool,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupbyOptions {
@@ -60,6 +73,8 @@ pub struct GroupbyOptions {
     pub rolling: Option<RollingGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub map_groups: MapGroupsOptions,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -69,11 +84,13 @@ pub struct DistinctOptions {
     pub subset: Option<Arc<Vec<String>>>,
     /// This will maintain the order of the input.
     /// Note that this is more expensive.
-    /// `maintain_order` is not supported in the streaming
-    /// engine.
     pub maintain_order: bool,
     /// Which rows to keep.
     pub keep_strategy: UniqueKeepStrategy,
+    /// The column that is compared by [`UniqueKeepStrategy::MaxBy`] and
+    /// [`UniqueKeepStrategy::MinBy`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keep_by: Option<Arc<str>>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
 }
@@ -237,6 +254,18 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    /// Files in Hive-style partition directories under `path`.
+    Partitioned {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        options: PartitionedWriteOptions,
+    },
+    /// Files in the directory `path`, which are split by their number of rows or size.
+    Split {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        options: SplitWriteOptions,
+    },
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -250,7 +279,7 @@ pub struct FileSinkOptions {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum FileType {
     #[cfg(feature = "parquet")]
-    Parquet(ParquetWriteOptions),
+    Parquet(ParquetSinkOptions),
     #[cfg(feature = "ipc")]
     Ipc(IpcWriterOptions),
     #[cfg(feature = "csv")]
@@ -311,3 +340,59 @@ impl From<UnionArgs> for UnionOptions {
         }
     }
 }
+
+/// Policy for the casts that type coercion inserts implicitly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CastPolicy {
+    /// Always cast to the supertype, even if that may lose precision.
+    #[default]
+    Lenient,
+    /// Raise an error for implicit casts that may lose precision and for comparisons
+    /// between strings and numbers.
+    Strict,
+}
+
+impl CastPolicy {
+    pub fn is_strict(&self) -> bool {
+        matches!(self, CastPolicy::Strict)
+    }
+}
+
+/// Policy for how queries rechunk their data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChunkPolicy {
+    /// Let operators that are faster on contiguous data, such as sorts and group bys, rechunk
+    /// their input into a single chunk. They also work on chunked input.
+    pub auto_rechunk: bool,
+    /// Split the result of a collected query into chunks of at most this many rows.
+    pub target_chunk_size: Option<usize>,
+    /// Warn when a query is planned if an operator will rechunk an input that is estimated
+    /// to have at least this many rows.
+    pub warn_rechunk_rows: Option<usize>,
+}
+
+impl Default for ChunkPolicy {
+    fn default() -> Self {
+        ChunkPolicy {
+            auto_rechunk: true,
+            target_chunk_size: None,
+            warn_rechunk_rows: None,
+        }
+    }
+}
+
+/// How the streaming engine spills data to disk when it runs out of memory.
+#[cfg(feature = "streaming")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpillOptions {
+    /// The compression of the spilled files. They aren't compressed by default.
+    pub compression: Option<IpcCompression>,
+    /// The level of ZSTD compression, between 1 and 22. Defaults to 3.
+    pub compression_level: Option<i32>,
+    /// The number of spilled files that are read and decompressed at a time. Defaults to the
+    /// size of the thread pool.
+    pub decompression_threads: Option<usize>,
+}
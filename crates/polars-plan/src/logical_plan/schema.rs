@@ -377,6 +377,13 @@ pub(crate) fn det_join_schema(
                 }
             }
 
+            #[cfg(feature = "asof_join")]
+            if let JoinType::AsOf(asof_options) = &options.args.how {
+                if let Some(name) = &asof_options.right_index_name {
+                    new_schema.with_column(name.clone(), IDX_DTYPE);
+                }
+            }
+
             Ok(Arc::new(new_schema))
         },
     }
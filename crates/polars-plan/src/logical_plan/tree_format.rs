@@ -301,6 +301,8 @@ impl<'a> TreeFmtNode<'a> {
                     match payload {
                         SinkType::Memory => "SINK (memory)",
                         SinkType::File { .. } => "SINK (file)",
+                        SinkType::Partitioned { .. } => "SINK (partitioned)",
+                        SinkType::Split { .. } => "SINK (split)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (cloud)",
                     },
@@ -50,7 +50,7 @@ impl TreeWalker for Expr {
             },
             Cast { expr, data_type, strict } => Cast { expr: am(expr, f)?, data_type, strict },
             Sort { expr, options } => Sort { expr: am(expr, f)?, options },
-            Gather { expr, idx, returns_scalar } => Gather { expr: am(expr, &mut f)?, idx: am(idx, f)?, returns_scalar },
+            Gather { expr, idx, returns_scalar, out_of_bounds } => Gather { expr: am(expr, &mut f)?, idx: am(idx, f)?, returns_scalar, out_of_bounds },
             SortBy { expr, by, sort_options } => SortBy { expr: am(expr, &mut f)?, by: by.into_iter().map(f).collect::<Result<_, _>>()?, sort_options },
             Agg(agg_expr) => Agg(match agg_expr {
                 Min { input, propagate_nans } => Min { input: am(input, f)?, propagate_nans },
@@ -173,8 +173,15 @@ impl AExpr {
                 },
             ) => strict_l == strict_r && dtl == dtr,
             (Sort { options: l, .. }, Sort { options: r, .. }) => l == r,
-            (Gather { .. }, Gather { .. })
-            | (Filter { .. }, Filter { .. })
+            (
+                Gather {
+                    out_of_bounds: l, ..
+                },
+                Gather {
+                    out_of_bounds: r, ..
+                },
+            ) => l == r,
+            (Filter { .. }, Filter { .. })
             | (Ternary { .. }, Ternary { .. })
             | (Len, Len)
             | (Slice { .. }, Slice { .. })
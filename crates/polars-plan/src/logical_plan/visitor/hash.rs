@@ -1,6 +1,9 @@
+use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 
+use polars_io::utils::{is_cloud_url, resolve_homedir};
 use polars_utils::arena::Arena;
 
 use super::*;
@@ -51,6 +54,49 @@ fn hash_exprs<H: Hasher>(exprs: &[ExprIR], expr_arena: &Arena<AExpr>, state: &mu
     }
 }
 
+/// The path that `path` refers to, so that different spellings of a local path compare
+/// equal. The path is resolved lexically, the file system is not accessed.
+fn resolve_path(path: &Path) -> Cow<'_, Path> {
+    if is_cloud_url(path) {
+        return Cow::Borrowed(path);
+    }
+    let path = resolve_homedir(path);
+    let path = match std::env::current_dir() {
+        Ok(cwd) if path.is_relative() => cwd.join(path),
+        _ => path,
+    };
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir
+                if matches!(
+                    resolved.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                resolved.pop();
+            },
+            component => resolved.push(component),
+        }
+    }
+    Cow::Owned(resolved)
+}
+
+fn hash_paths<H: Hasher>(paths: &[PathBuf], state: &mut H) {
+    paths.len().hash(state);
+    for path in paths {
+        resolve_path(path).hash(state);
+    }
+}
+
+fn paths_eq(l: &[PathBuf], r: &[PathBuf]) -> bool {
+    l.len() == r.len()
+        && l.iter()
+            .zip(r)
+            .all(|(l, r)| resolve_path(l) == resolve_path(r))
+}
+
 impl Hash for HashableEqLP<'_> {
     // This hashes the variant, not the whole plan
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -83,7 +129,7 @@ impl Hash for HashableEqLP<'_> {
             } => {
                 // We don't have to traverse the schema, hive partitions etc. as they are derivative from the paths.
                 scan_type.hash(state);
-                paths.hash(state);
+                hash_paths(paths, state);
                 hash_option_expr(predicate, self.expr_arena, state);
                 file_options.hash(state);
             },
@@ -125,6 +171,18 @@ impl Hash for HashableEqLP<'_> {
                 slice.hash(state);
                 sort_options.hash(state);
             },
+            IR::TopK {
+                input: _,
+                by_column,
+                offset,
+                len,
+                sort_options,
+            } => {
+                hash_exprs(by_column, self.expr_arena, state);
+                offset.hash(state);
+                len.hash(state);
+                sort_options.hash(state);
+            },
             IR::GroupBy {
                 input: _,
                 keys,
@@ -268,7 +326,7 @@ impl HashableEqLP<'_> {
                     file_options: or,
                 },
             ) => {
-                pl == pr
+                paths_eq(pl, pr)
                     && stl == str
                     && ol == or
                     && opt_expr_ir_eq(pred_l, pred_r, self.expr_arena)
@@ -336,6 +394,25 @@ impl HashableEqLP<'_> {
                 (l_slice == r_slice && l_options == r_options)
                     && expr_irs_eq(cl, cr, self.expr_arena)
             },
+            (
+                IR::TopK {
+                    input: _,
+                    by_column: cl,
+                    offset: l_offset,
+                    len: l_len,
+                    sort_options: l_options,
+                },
+                IR::TopK {
+                    input: _,
+                    by_column: cr,
+                    offset: r_offset,
+                    len: r_len,
+                    sort_options: r_options,
+                },
+            ) => {
+                (l_offset == r_offset && l_len == r_len && l_options == r_options)
+                    && expr_irs_eq(cl, cr, self.expr_arena)
+            },
             (
                 IR::GroupBy {
                     input: _,
@@ -104,6 +104,30 @@ pub fn has_aexpr_literal(current_node: Node, arena: &Arena<AExpr>) -> bool {
     has_aexpr(current_node, arena, |e| matches!(e, AExpr::Literal(_)))
 }
 
+/// Whether evaluating the expression twice on the same input may give different results,
+/// e.g. because it draws random values or calls a user-defined function.
+pub fn has_aexpr_non_deterministic(current_node: Node, arena: &Arena<AExpr>) -> bool {
+    has_aexpr(current_node, arena, single_aexpr_is_non_deterministic)
+}
+
+/// Whether this node, without its inputs, may give different results on the same input.
+pub fn single_aexpr_is_non_deterministic(ae: &AExpr) -> bool {
+    match ae {
+        AExpr::AnonymousFunction { .. } => true,
+        #[cfg(feature = "random")]
+        AExpr::Function {
+            function: FunctionExpr::Random { .. },
+            ..
+        } => true,
+        #[cfg(feature = "ffi_plugin")]
+        AExpr::Function {
+            function: FunctionExpr::FfiPlugin { .. },
+            ..
+        } => true,
+        _ => false,
+    }
+}
+
 /// Can check if an expression tree has a matching_expr. This
 /// requires a dummy expression to be created that will be used to pattern match against.
 pub(crate) fn has_expr<F>(current_expr: &Expr, matches: F) -> bool
@@ -239,6 +263,26 @@ pub fn expr_to_leaf_column_name(expr: &Expr) -> PolarsResult<Arc<str>> {
     }
 }
 
+/// The path of a column or of a (nested) field of a struct column, e.g. `["a", "b"]` for
+/// `col("a").struct.field("b")`. Aliases are ignored.
+pub fn expr_to_field_path(expr: &Expr) -> Option<Vec<Arc<str>>> {
+    match expr {
+        Expr::Column(name) => Some(vec![name.clone()]),
+        Expr::Alias(input, _) => expr_to_field_path(input),
+        #[cfg(feature = "dtype-struct")]
+        Expr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::FieldByName(name)),
+            ..
+        } => {
+            let mut path = expr_to_field_path(&input[0])?;
+            path.push(name.clone());
+            Some(path)
+        },
+        _ => None,
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub(crate) fn aexpr_to_column_nodes_iter<'a>(
     root: Node,
@@ -804,60 +804,27 @@ impl SQLExprVisitor<'_> {
                 ComputeError: "WHEN and THEN expressions must have at least one element"
             );
 
-            let mut when_thens = conditions.iter().zip(results.iter());
-            let first = when_thens.next();
-            if first.is_none() {
-                polars_bail!(ComputeError: "WHEN and THEN expressions must have at least one element");
-            }
             let else_res = match else_result {
                 Some(else_res) => self.visit_expr(else_res)?,
                 None => polars_bail!(ComputeError: "ELSE expression is required"),
             };
-            if let Some(operand_expr) = operand {
-                let first_operand_expr = self.visit_expr(operand_expr)?;
-
-                let first = first.unwrap();
-                let first_cond = first_operand_expr.eq(self.visit_expr(first.0)?);
-                let first_then = self.visit_expr(first.1)?;
-                let expr = when(first_cond).then(first_then);
-                let next = when_thens.next();
-
-                let mut when_then = if let Some((cond, res)) = next {
-                    let second_operand_expr = self.visit_expr(operand_expr)?;
-                    let cond = second_operand_expr.eq(self.visit_expr(cond)?);
-                    let res = self.visit_expr(res)?;
-                    expr.when(cond).then(res)
-                } else {
-                    return Ok(expr.otherwise(else_res));
-                };
-                for (cond, res) in when_thens {
-                    let new_operand_expr = self.visit_expr(operand_expr)?;
-                    let cond = new_operand_expr.eq(self.visit_expr(cond)?);
-                    let res = self.visit_expr(res)?;
-                    when_then = when_then.when(cond).then(res);
-                }
-                return Ok(when_then.otherwise(else_res));
-            }
-
-            let first = first.unwrap();
-            let first_cond = self.visit_expr(first.0)?;
-            let first_then = self.visit_expr(first.1)?;
-            let expr = when(first_cond).then(first_then);
-            let next = when_thens.next();
-
-            let mut when_then = if let Some((cond, res)) = next {
-                let cond = self.visit_expr(cond)?;
-                let res = self.visit_expr(res)?;
-                expr.when(cond).then(res)
-            } else {
-                return Ok(expr.otherwise(else_res));
-            };
-            for (cond, res) in when_thens {
+            let operand = operand
+                .as_ref()
+                .map(|operand| self.visit_expr(operand))
+                .transpose()?;
+
+            // All branches are evaluated in a single pass, rather than as nested
+            // `when-then-otherwise` expressions.
+            let mut case_expr = case();
+            for (cond, res) in conditions.iter().zip(results.iter()) {
                 let cond = self.visit_expr(cond)?;
-                let res = self.visit_expr(res)?;
-                when_then = when_then.when(cond).then(res);
+                let cond = match &operand {
+                    Some(operand) => operand.clone().eq(cond),
+                    None => cond,
+                };
+                case_expr = case_expr.when(cond).then(self.visit_expr(res)?);
             }
-            Ok(when_then.otherwise(else_res))
+            Ok(case_expr.otherwise(else_res))
         } else {
             unreachable!()
         }
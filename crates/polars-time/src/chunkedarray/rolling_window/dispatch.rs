@@ -234,6 +234,39 @@ pub trait SeriesOpsTime: AsSeries {
             s
         })
     }
+
+    /// Count the non-null values in the temporal window of every row.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_count_by(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        let s = self.as_series().rechunk();
+        let options = dynamic_window_options(options, "rolling_count_by")?;
+        let validity = s.chunks().first().and_then(|arr| arr.validity());
+        let out = super::rolling_kernels::count::rolling_count(options, validity)?;
+        Ok(out.with_name(s.name()).into_series())
+    }
+
+    /// Count the distinct values in the temporal window of every row. Null is counted as a
+    /// distinct value.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_n_unique_by(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        let s = self.as_series();
+        let options = dynamic_window_options(options, "rolling_n_unique_by")?;
+        let (ids, n_ids) = super::rolling_kernels::count::value_ids(s)?;
+        let out = super::rolling_kernels::count::rolling_n_unique(options, &ids, n_ids)?;
+        Ok(out.with_name(s.name()).into_series())
+    }
+}
+
+#[cfg(feature = "rolling_window")]
+fn dynamic_window_options<'a>(
+    options: RollingOptionsImpl<'a>,
+    expr_name: &str,
+) -> PolarsResult<RollingOptionsDynamicWindow<'a>> {
+    polars_ensure!(
+        options.by.is_some(),
+        InvalidOperation: "`{}` needs a `by` column", expr_name
+    );
+    options.try_into()
 }
 
 impl SeriesOpsTime for Series {}
@@ -0,0 +1,109 @@
+use arrow::bitmap::Bitmap;
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+
+use super::*;
+
+/// The `(start, len)` of the window of every row.
+fn window_offsets(options: &RollingOptionsDynamicWindow) -> PolarsResult<Vec<(IdxSize, IdxSize)>> {
+    let tu = options.tu.expect("time_unit was set in `convert` function");
+    let (period, time, closed_window) = (options.window_size, options.by, options.closed_window);
+    match options.tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => {
+            group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok())?.collect()
+        },
+        _ => group_by_values_iter(period, time, closed_window, tu, None)?.collect(),
+    }
+}
+
+/// Count the valid rows in the window of every row.
+pub(crate) fn rolling_count(
+    options: RollingOptionsDynamicWindow,
+    validity: Option<&Bitmap>,
+) -> PolarsResult<IdxCa> {
+    let offsets = window_offsets(&options)?;
+    // The number of valid rows before every row.
+    let mut valid_before = Vec::with_capacity(offsets.len() + 1);
+    valid_before.push(0 as IdxSize);
+    let mut count = 0;
+    for i in 0..offsets.len() {
+        count += validity.map_or(true, |validity| validity.get_bit(i)) as IdxSize;
+        valid_before.push(count);
+    }
+    Ok(offsets
+        .into_iter()
+        .map(|(start, len)| {
+            let (start, end) = (start as usize, (start + len) as usize);
+            (len as usize >= options.min_periods).then(|| valid_before[end] - valid_before[start])
+        })
+        .collect_trusted())
+}
+
+/// Count the distinct values in the window of every row.
+///
+/// The rows are given as the ids of their values, see [`value_ids`]. The counts are updated
+/// as the windows slide, so every row is added and removed once if `by` is sorted.
+pub(crate) fn rolling_n_unique(
+    options: RollingOptionsDynamicWindow,
+    ids: &[IdxSize],
+    n_ids: usize,
+) -> PolarsResult<IdxCa> {
+    let offsets = window_offsets(&options)?;
+    let mut counts = vec![0 as IdxSize; n_ids];
+    let mut n_unique: IdxSize = 0;
+    let (mut window_start, mut window_end) = (0, 0);
+    let remove = |i: usize, counts: &mut [IdxSize], n_unique: &mut IdxSize| {
+        let count = &mut counts[ids[i] as usize];
+        *count -= 1;
+        *n_unique -= (*count == 0) as IdxSize;
+    };
+    Ok(offsets
+        .into_iter()
+        .map(|(start, len)| {
+            let (start, end) = (start as usize, (start + len) as usize);
+            // The windows only move backwards if `by` isn't sorted, start over then.
+            if start < window_start || end < window_end {
+                for i in window_start..window_end {
+                    remove(i, &mut counts, &mut n_unique);
+                }
+                (window_start, window_end) = (start, start);
+            }
+            // The rows that left the window.
+            for i in window_start..start.min(window_end) {
+                remove(i, &mut counts, &mut n_unique);
+            }
+            window_start = start;
+            window_end = window_end.max(start);
+            // The rows that entered the window.
+            for &id in &ids[window_end..end] {
+                let count = &mut counts[id as usize];
+                n_unique += (*count == 0) as IdxSize;
+                *count += 1;
+            }
+            window_end = end;
+            (len as usize >= options.min_periods).then_some(n_unique)
+        })
+        .collect_trusted())
+}
+
+/// The id of the value of every row, equal values sharing an id, and the number of ids.
+pub(crate) fn value_ids(s: &Series) -> PolarsResult<(Vec<IdxSize>, usize)> {
+    let groups = s.group_tuples(false, false)?;
+    let mut ids = vec![0 as IdxSize; s.len()];
+    match &groups {
+        GroupsProxy::Idx(groups) => {
+            for (id, (_, idx)) in groups.iter().enumerate() {
+                for &i in idx.iter() {
+                    ids[i as usize] = id as IdxSize;
+                }
+            }
+        },
+        GroupsProxy::Slice { groups, .. } => {
+            for (id, [first, len]) in groups.iter().enumerate() {
+                ids[*first as usize..(*first + *len) as usize].fill(id as IdxSize);
+            }
+        },
+    }
+    Ok((ids, groups.len()))
+}
@@ -1,3 +1,4 @@
+pub(super) mod count;
 pub(super) mod no_nulls;
 use std::fmt::Debug;
 use std::ops::{AddAssign, Mul, SubAssign};
@@ -0,0 +1,120 @@
+//! Parsing of the formats produced by `DurationChunked::to_string`.
+//!
+//! Both formats are parsed to a number of nanoseconds, which fits durations of any
+//! [`TimeUnit`](polars_core::prelude::TimeUnit).
+
+const NS_SECOND: i128 = 1_000_000_000;
+const NS_MINUTE: i128 = 60 * NS_SECOND;
+const NS_HOUR: i128 = 60 * NS_MINUTE;
+const NS_DAY: i128 = 24 * NS_HOUR;
+const NS_WEEK: i128 = 7 * NS_DAY;
+
+/// Parse an ISO 8601 duration, e.g. `P1DT2H3M4.5S`, or the representation used when
+/// printing, e.g. `1d 2h 3m 4s 500ms`, to nanoseconds.
+pub(super) fn parse_duration(s: &str) -> Option<i128> {
+    let s = s.trim();
+    let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+    if unsigned.starts_with('P') {
+        parse_iso(s)
+    } else {
+        parse_polars(s)
+    }
+}
+
+fn parse_iso(s: &str) -> Option<i128> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let s = s.strip_prefix('P')?;
+    // Years and months don't have a fixed length, so they can't be represented.
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, Some(time)),
+        Some(_) => return None,
+        None => (s, None),
+    };
+    let (mut total, mut n) = parse_iso_components(date, &[(b'W', NS_WEEK), (b'D', NS_DAY)])?;
+    if let Some(time) = time {
+        let (time_total, time_n) = parse_iso_components(
+            time,
+            &[(b'H', NS_HOUR), (b'M', NS_MINUTE), (b'S', NS_SECOND)],
+        )?;
+        total = total.checked_add(time_total)?;
+        n += time_n;
+    }
+    if n == 0 {
+        return None;
+    }
+    Some(if negative { -total } else { total })
+}
+
+/// Parse `<number><designator>` components that appear in the order of `designators`.
+fn parse_iso_components(mut part: &str, designators: &[(u8, i128)]) -> Option<(i128, usize)> {
+    let mut allowed = designators;
+    let mut total = 0i128;
+    let mut n = 0;
+    while !part.is_empty() {
+        let end = part.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))?;
+        let (number, rest) = part.split_at(end);
+        let designator = rest.as_bytes()[0];
+        let idx = allowed.iter().position(|(d, _)| *d == designator)?;
+        let size = allowed[idx].1;
+        allowed = &allowed[idx + 1..];
+        total = total.checked_add(parse_decimal(number, size)?)?;
+        n += 1;
+        part = &rest[1..];
+    }
+    Some((total, n))
+}
+
+/// Parse a non-negative decimal number of units of `size` nanoseconds. Digits beyond
+/// nanosecond precision are truncated.
+fn parse_decimal(number: &str, size: i128) -> Option<i128> {
+    let (integer, fraction) = match number.split_once(['.', ',']) {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (number, ""),
+    };
+    if integer.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let mut total = integer.parse::<i128>().ok()?.checked_mul(size)?;
+    let mut scale = 1i128;
+    let mut fraction_total = 0i128;
+    for digit in fraction.bytes().take(20) {
+        scale *= 10;
+        fraction_total = fraction_total * 10 + (digit - b'0') as i128;
+    }
+    if scale > 1 {
+        total = total.checked_add(fraction_total * size / scale)?;
+    }
+    Some(total)
+}
+
+fn parse_polars(s: &str) -> Option<i128> {
+    let mut total = 0i128;
+    let mut n = 0;
+    for token in s.split_whitespace() {
+        let unsigned = token.strip_prefix('-').unwrap_or(token);
+        let end = unsigned.find(|c: char| !c.is_ascii_digit())?;
+        if end == 0 {
+            return None;
+        }
+        let value = token[..token.len() - unsigned.len() + end]
+            .parse::<i128>()
+            .ok()?;
+        let size = match &unsigned[end..] {
+            "w" => NS_WEEK,
+            "d" => NS_DAY,
+            "h" => NS_HOUR,
+            "m" => NS_MINUTE,
+            "s" => NS_SECOND,
+            "ms" => 1_000_000,
+            "µs" | "us" => 1_000,
+            "ns" => 1,
+            _ => return None,
+        };
+        total = total.checked_add(value.checked_mul(size)?)?;
+        n += 1;
+    }
+    (n > 0).then_some(total)
+}
@@ -1,5 +1,7 @@
 pub mod infer;
 use chrono::DateTime;
+#[cfg(feature = "dtype-duration")]
+mod duration;
 mod patterns;
 mod strptime;
 use chrono::ParseError;
@@ -121,6 +123,24 @@ pub trait StringMethods: AsString {
         Ok(ca.with_name(string_ca.name()).into())
     }
 
+    #[cfg(feature = "dtype-duration")]
+    /// Parsing string values and return a [`DurationChunked`]
+    /// The values may be ISO 8601 durations, e.g. `P1DT2H3M`, or in the representation used
+    /// when printing, e.g. `1d 2h 3m`. Precision beyond the time unit is truncated.
+    fn as_duration(&self, tu: TimeUnit) -> DurationChunked {
+        let string_ca = self.as_string();
+        let ns_per_unit: i128 = match tu {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        };
+        let ca: Int64Chunked = string_ca.apply_generic(|opt_s| {
+            let ns = duration::parse_duration(opt_s?)?;
+            i64::try_from(ns / ns_per_unit).ok()
+        });
+        ca.with_name(string_ca.name()).into_duration(tu)
+    }
+
     #[cfg(feature = "dtype-date")]
     /// Parsing string values and return a [`DateChunked`]
     /// Different from `as_date` this function allows matches that not contain the whole string
@@ -37,6 +37,10 @@ pub struct DynamicGroupOptions {
     /// In cases sortedness cannot be checked by the sorted flag,
     /// traverse the data to check sortedness.
     pub check_sorted: bool,
+    /// String column with the time zone of every row. The windows of a group are aligned to
+    /// the local calendar of its time zone, which must be the same for all its rows.
+    /// The index column must be a Datetime; naive values are taken as UTC.
+    pub time_zone_column: Option<SmartString>,
 }
 
 impl Default for DynamicGroupOptions {
@@ -51,6 +55,7 @@ impl Default for DynamicGroupOptions {
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
             check_sorted: true,
+            time_zone_column: None,
         }
     }
 }
@@ -67,6 +72,9 @@ pub struct RollingGroupOptions {
     /// In cases sortedness cannot be checked by the sorted flag,
     /// traverse the data to check sortedness.
     pub check_sorted: bool,
+    /// String column with the time zone of every row, see
+    /// [`DynamicGroupOptions::time_zone_column`].
+    pub time_zone_column: Option<SmartString>,
 }
 
 impl Default for RollingGroupOptions {
@@ -77,10 +85,79 @@ impl Default for RollingGroupOptions {
             offset: Duration::new(1),
             closed_window: ClosedWindow::Left,
             check_sorted: true,
+            time_zone_column: None,
         }
     }
 }
 
+/// Get the time zone column of the options, which requires a Datetime index column.
+fn time_zone_column(
+    df: &DataFrame,
+    name: Option<&SmartString>,
+    time_type: &DataType,
+) -> PolarsResult<Option<StringChunked>> {
+    let Some(name) = name else {
+        return Ok(None);
+    };
+    polars_ensure!(
+        cfg!(feature = "timezones"),
+        InvalidOperation: "a time zone column requires the 'timezones' feature"
+    );
+    polars_ensure!(
+        matches!(time_type, DataType::Datetime(_, _)),
+        InvalidOperation: "a time zone column requires a Datetime index column, got {}", time_type
+    );
+    Ok(Some(df.column(name)?.str()?.clone()))
+}
+
+/// The time zone the windows of a group are aligned to: the time zone of its rows if there
+/// is a time zone column, and `tz` otherwise.
+fn group_time_zone(
+    time_zones: Option<&StringChunked>,
+    tz: &Option<TimeZone>,
+    take: impl FnOnce(&StringChunked) -> StringChunked,
+) -> PolarsResult<Option<TimeZone>> {
+    let Some(time_zones) = time_zones else {
+        return Ok(tz.clone());
+    };
+    let time_zones = take(time_zones);
+    polars_ensure!(
+        time_zones.null_count() == 0,
+        ComputeError: "null values in the time zone column are not supported"
+    );
+    let mut iter = time_zones.into_no_null_iter();
+    let Some(first) = iter.next() else {
+        return Ok(tz.clone());
+    };
+    if let Some(other) = iter.find(|other| *other != first) {
+        polars_bail!(
+            ComputeError: "the time zone must be the same within a group, got '{}' and '{}'",
+            first, other
+        );
+    }
+    #[cfg(feature = "timezones")]
+    polars_core::chunked_array::temporal::parse_time_zone(first)?;
+    Ok(Some(first.to_string()))
+}
+
+/// [`group_time_zone`] for the rolling kernels, which take a parsed time zone.
+fn group_parsed_time_zone(
+    time_zones: Option<&StringChunked>,
+    tz: Option<Tz>,
+    take: impl FnOnce(&StringChunked) -> StringChunked,
+) -> PolarsResult<Option<Tz>> {
+    if time_zones.is_none() {
+        return Ok(tz);
+    }
+    match group_time_zone(time_zones, &None, take)? {
+        #[cfg(feature = "timezones")]
+        Some(tz) => Ok(Some(polars_core::chunked_array::temporal::parse_time_zone(
+            &tz,
+        )?)),
+        _ => Ok(tz),
+    }
+}
+
 fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
     polars_ensure!(v.is_sorted_ascending(), ComputeError: "input data is not sorted");
     Ok(())
@@ -143,6 +220,7 @@ impl Wrap<&DataFrame> {
         polars_ensure!(time.null_count() == 0, ComputeError: "null values in `rolling` not supported, fill nulls.");
         ensure_duration_matches_data_type(options.period, time_type, "period")?;
         ensure_duration_matches_data_type(options.offset, time_type, "offset")?;
+        let time_zones = time_zone_column(self.0, options.time_zone_column.as_ref(), time_type)?;
 
         use DataType::*;
         let (dt, tu, tz): (Series, TimeUnit, Option<TimeZone>) = match time_type {
@@ -161,6 +239,7 @@ impl Wrap<&DataFrame> {
                     options,
                     TimeUnit::Nanoseconds,
                     None,
+                    None,
                     &time_type_dt,
                 )?;
                 let out = out.cast(&Int64).unwrap().cast(time_type).unwrap();
@@ -175,6 +254,7 @@ impl Wrap<&DataFrame> {
                     options,
                     TimeUnit::Nanoseconds,
                     None,
+                    None,
                     &time_type,
                 )?;
                 let out = out.cast(&Int64).unwrap();
@@ -188,10 +268,16 @@ impl Wrap<&DataFrame> {
         };
         match tz {
             #[cfg(feature = "timezones")]
-            Some(tz) => {
-                self.impl_rolling(dt, group_by, options, tu, tz.parse::<Tz>().ok(), time_type)
-            },
-            _ => self.impl_rolling(dt, group_by, options, tu, None, time_type),
+            Some(tz) => self.impl_rolling(
+                dt,
+                group_by,
+                options,
+                tu,
+                tz.parse::<Tz>().ok(),
+                time_zones,
+                time_type,
+            ),
+            _ => self.impl_rolling(dt, group_by, options, tu, None, time_zones, time_type),
         }
     }
 
@@ -213,6 +299,7 @@ impl Wrap<&DataFrame> {
         ensure_duration_matches_data_type(options.every, time_type, "every")?;
         ensure_duration_matches_data_type(options.offset, time_type, "offset")?;
         ensure_duration_matches_data_type(options.period, time_type, "period")?;
+        let time_zones = time_zone_column(self.0, options.time_zone_column.as_ref(), time_type)?;
 
         use DataType::*;
         let (dt, tu) = match time_type {
@@ -229,6 +316,7 @@ impl Wrap<&DataFrame> {
                     group_by,
                     options,
                     TimeUnit::Nanoseconds,
+                    None,
                     &time_type,
                 )?;
                 let out = out.cast(&Int64).unwrap().cast(&Int32).unwrap();
@@ -247,6 +335,7 @@ impl Wrap<&DataFrame> {
                     group_by,
                     options,
                     TimeUnit::Nanoseconds,
+                    None,
                     &time_type,
                 )?;
                 let out = out.cast(&Int64).unwrap();
@@ -263,7 +352,7 @@ impl Wrap<&DataFrame> {
                 dt
             ),
         };
-        self.impl_group_by_dynamic(dt, group_by, options, tu, time_type)
+        self.impl_group_by_dynamic(dt, group_by, options, tu, time_zones, time_type)
     }
 
     fn impl_group_by_dynamic(
@@ -272,8 +361,10 @@ impl Wrap<&DataFrame> {
         mut by: Vec<Series>,
         options: &DynamicGroupOptions,
         tu: TimeUnit,
+        time_zones: Option<StringChunked>,
         time_type: &DataType,
     ) -> PolarsResult<(Series, Vec<Series>, GroupsProxy)> {
+        let time_zones = time_zones.as_ref();
         polars_ensure!(!options.every.negative, ComputeError: "'every' argument must be positive");
         if dt.is_empty() {
             return dt.cast(time_type).map(|s| (s, by, GroupsProxy::default()));
@@ -318,6 +409,7 @@ impl Wrap<&DataFrame> {
         let groups = if by.is_empty() {
             let vals = dt.downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
+            let tz = &group_time_zone(time_zones, tz, |ca| ca.clone())?;
             let (groups, lower, upper) = group_by_windows(
                 w,
                 ts,
@@ -354,6 +446,9 @@ impl Wrap<&DataFrame> {
                                 {
                                     check_sortedness_slice(ts)?
                                 }
+                                let tz = &group_time_zone(time_zones, tz, |ca| unsafe {
+                                    ca.take_unchecked(base_g.1)
+                                })?;
                                 let (sub_groups, lower, upper) = group_by_windows(
                                     w,
                                     ts,
@@ -388,6 +483,9 @@ impl Wrap<&DataFrame> {
                                 let dt = dt.slice(base_g[0] as i64, base_g[1] as usize);
                                 let vals = dt.downcast_iter().next().unwrap();
                                 let ts = vals.values().as_slice();
+                                let tz = &group_time_zone(time_zones, tz, |ca| {
+                                    ca.slice(base_g[0] as i64, base_g[1] as usize)
+                                })?;
                                 let (sub_groups, lower, upper) = group_by_windows(
                                     w,
                                     ts,
@@ -398,9 +496,9 @@ impl Wrap<&DataFrame> {
                                     include_upper_bound,
                                     options.start_by,
                                 );
-                                (lower, upper, update_subgroups_slice(&sub_groups, *base_g))
+                                Ok((lower, upper, update_subgroups_slice(&sub_groups, *base_g)))
                             })
-                            .collect::<Vec<_>>();
+                            .collect::<PolarsResult<Vec<_>>>()?;
 
                         let mut capacity = 0;
                         ir.iter_mut().for_each(|(lower, upper, g)| {
@@ -433,6 +531,9 @@ impl Wrap<&DataFrame> {
                                 {
                                     check_sortedness_slice(ts)?
                                 }
+                                let tz = &group_time_zone(time_zones, tz, |ca| unsafe {
+                                    ca.take_unchecked(base_g.1)
+                                })?;
                                 let (sub_groups, _, _) = group_by_windows(
                                     w,
                                     ts,
@@ -455,6 +556,9 @@ impl Wrap<&DataFrame> {
                                 let dt = dt.slice(base_g[0] as i64, base_g[1] as usize);
                                 let vals = dt.downcast_iter().next().unwrap();
                                 let ts = vals.values().as_slice();
+                                let tz = &group_time_zone(time_zones, tz, |ca| {
+                                    ca.slice(base_g[0] as i64, base_g[1] as usize)
+                                })?;
                                 let (sub_groups, _, _) = group_by_windows(
                                     w,
                                     ts,
@@ -465,9 +569,9 @@ impl Wrap<&DataFrame> {
                                     include_upper_bound,
                                     options.start_by,
                                 );
-                                update_subgroups_slice(&sub_groups, *base_g)
+                                Ok(update_subgroups_slice(&sub_groups, *base_g))
                             })
-                            .collect::<Vec<_>>();
+                            .collect::<PolarsResult<Vec<_>>>()?;
 
                         let groups = flatten_par(&groups);
 
@@ -522,6 +626,7 @@ impl Wrap<&DataFrame> {
     }
 
     /// Returns: time_keys, keys, groupsproxy
+    #[allow(clippy::too_many_arguments)]
     fn impl_rolling(
         &self,
         dt: Series,
@@ -529,8 +634,10 @@ impl Wrap<&DataFrame> {
         options: &RollingGroupOptions,
         tu: TimeUnit,
         tz: Option<Tz>,
+        time_zones: Option<StringChunked>,
         time_type: &DataType,
     ) -> PolarsResult<(Series, Vec<Series>, GroupsProxy)> {
+        let time_zones = time_zones.as_ref();
         let mut dt = dt.rechunk();
 
         let groups = if group_by.is_empty() {
@@ -540,6 +647,7 @@ impl Wrap<&DataFrame> {
             let dt = dt.datetime().unwrap();
             let vals = dt.downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
+            let tz = group_parsed_time_zone(time_zones, tz, |ca| ca.clone())?;
             PolarsResult::Ok(GroupsProxy::Slice {
                 groups: group_by_values(
                     options.period,
@@ -578,6 +686,9 @@ impl Wrap<&DataFrame> {
                             {
                                 check_sortedness_slice(ts)?
                             }
+                            let tz = group_parsed_time_zone(time_zones, tz, |ca| unsafe {
+                                ca.take_unchecked(base_g.1)
+                            })?;
 
                             let sub_groups = group_by_values(
                                 options.period,
@@ -600,6 +711,9 @@ impl Wrap<&DataFrame> {
                             let dt = dt_local.slice(base_g[0] as i64, base_g[1] as usize);
                             let vals = dt.downcast_iter().next().unwrap();
                             let ts = vals.values().as_slice();
+                            let tz = group_parsed_time_zone(time_zones, tz, |ca| {
+                                ca.slice(base_g[0] as i64, base_g[1] as usize)
+                            })?;
                             let sub_groups = group_by_values(
                                 options.period,
                                 options.offset,
@@ -972,4 +1086,54 @@ mod test {
         assert!(time_key.equals(&lower_bound));
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_dynamic_group_by_time_zone_column() -> PolarsResult<()> {
+        // 2024-01-01 22:00 and 2024-01-02 02:00 UTC, the same local day in both time zones.
+        let date = Int64Chunked::new("date", &[1704146400000, 1704160800000].repeat(2))
+            .into_datetime(TimeUnit::Milliseconds, Some("UTC".into()))
+            .into_series();
+        let city = Series::new("city", ["ny", "ny", "tokyo", "tokyo"]);
+        let tz = Series::new(
+            "tz",
+            [
+                "America/New_York",
+                "America/New_York",
+                "Asia/Tokyo",
+                "Asia/Tokyo",
+            ],
+        );
+        let df = DataFrame::new(vec![date, city.clone(), tz])?;
+        let options = DynamicGroupOptions {
+            index_column: "date".into(),
+            every: Duration::parse("1d"),
+            period: Duration::parse("1d"),
+            offset: Duration::parse("0d"),
+            ..Default::default()
+        };
+
+        let (_, _, groups) = df.group_by_dynamic(vec![city.clone()], &options)?;
+        assert_eq!(groups.len(), 4);
+
+        let options = DynamicGroupOptions {
+            time_zone_column: Some("tz".into()),
+            ..options
+        };
+        let (time_key, keys, groups) = df.group_by_dynamic(vec![city.clone()], &options)?;
+        assert_eq!(groups.len(), 2);
+        assert_eq!(time_key.dtype(), df.column("date")?.dtype());
+        assert_eq!(Vec::from(keys[0].str()?), &[Some("ny"), Some("tokyo")]);
+        // Local midnight of 2024-01-01 in New York and of 2024-01-02 in Tokyo.
+        assert_eq!(
+            Vec::from(time_key.cast(&DataType::Int64)?.i64()?),
+            &[Some(1704085200000), Some(1704121200000)]
+        );
+
+        let tz = Series::new("tz", ["UTC", "Asia/Tokyo", "Asia/Tokyo", "Asia/Tokyo"]);
+        let mut df = df;
+        df.with_column(tz)?;
+        assert!(df.group_by_dynamic(vec![city], &options).is_err());
+        Ok(())
+    }
 }
@@ -228,6 +228,7 @@ pub trait TemporalMethods: AsSeries {
 
     /// Convert Time into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    /// Durations take the format `"iso"` or `"polars"` instead.
     fn to_string(&self, format: &str) -> PolarsResult<Series> {
         let s = self.as_series();
         match s.dtype() {
@@ -239,6 +240,10 @@ pub trait TemporalMethods: AsSeries {
                 .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
             #[cfg(feature = "dtype-time")]
             DataType::Time => s.time().map(|ca| ca.to_string(format).into_series()),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => s
+                .duration()
+                .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
             dt => polars_bail!(opq = to_string, dt),
         }
     }
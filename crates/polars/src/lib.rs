@@ -204,6 +204,9 @@
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `delta` - Write Delta Lake tables
+//!     - `iceberg` - Read Apache Iceberg tables
+//!     - `orc` - Read Apache ORC format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
@@ -219,6 +222,7 @@
 //! * [`DataFrame`] operations:
 //!     - `dynamic_group_by` - Groupby based on a time window instead of predefined keys.
 //!                           Also activates rolling window group by operations.
+//!     - `upsample` - Upsample time series and insert their missing rows in the lazy engine.
 //!     - `sort_multiple` - Allow sorting a [`DataFrame`] on multiple columns
 //!     - `rows` - Create [`DataFrame`] from rows and extract rows from [`DataFrame`]s.
 //!                And activates `pivot` and `transpose` operations
@@ -272,6 +276,9 @@
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
+//!     - `ols` - Least squares and rolling regression.
+//!     - `time_weighted` - Time weighted means and trapezoidal integrals.
+//!     - `outliers` - Winsorization and outlier flags.
 //!     - `find_many` - Find/replace multiple string patterns at once.
 //! * [`DataFrame`] pretty printing
 //!     - `fmt` - Activate [`DataFrame`] formatting
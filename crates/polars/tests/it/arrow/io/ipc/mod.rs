@@ -58,7 +58,7 @@ fn write_boolean() -> PolarsResult<()> {
     let array = BooleanArray::from([Some(true), Some(false), None, Some(true)]).boxed();
     let schema = prep_schema(array.as_ref());
     let columns = RecordBatch::try_new(vec![array])?;
-    round_trip(columns, schema, None, Some(Compression::ZSTD))
+    round_trip(columns, schema, None, Some(Compression::ZSTD(0)))
 }
 
 #[test]
@@ -68,7 +68,7 @@ fn write_sliced_utf8() -> PolarsResult<()> {
         .boxed();
     let schema = prep_schema(array.as_ref());
     let columns = RecordBatch::try_new(vec![array])?;
-    round_trip(columns, schema, None, Some(Compression::ZSTD))
+    round_trip(columns, schema, None, Some(Compression::ZSTD(0)))
 }
 
 #[test]
@@ -76,5 +76,5 @@ fn write_binview() -> PolarsResult<()> {
     let array = Utf8ViewArray::from_slice([Some("foo"), Some("bar"), None, Some("hamlet")]).boxed();
     let schema = prep_schema(array.as_ref());
     let columns = RecordBatch::try_new(vec![array])?;
-    round_trip(columns, schema, None, Some(Compression::ZSTD))
+    round_trip(columns, schema, None, Some(Compression::ZSTD(0)))
 }
@@ -1,6 +1,6 @@
 use polars::export::chrono::NaiveDate;
 use polars::prelude::*;
-use polars_ops::pivot::{pivot, pivot_stable, PivotAgg};
+use polars_ops::pivot::{pivot, pivot_multi, pivot_stable, PivotAgg};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -211,6 +211,75 @@ fn test_pivot_new() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_pivot_multi() -> PolarsResult<()> {
+    let df = df![
+        "index1"=> ["foo", "foo", "foo", "foo", "foo", "bar", "bar", "bar", "bar"],
+        "cols1"=> ["small", "large", "large", "small", "small", "large", "small", "small", "large"],
+        "values1"=> [1, 2, 2, 3, 3, 4, 5, 6, 7],
+        "values2"=> [2, 4, 5, 5, 6, 6, 8, 9, 9]
+    ]?;
+
+    let out = pivot_multi(
+        &df,
+        ["index1"],
+        ["cols1"],
+        Some(["values1", "values2"]),
+        true,
+        vec![PivotAgg::Sum, PivotAgg::Max],
+        Some("{value}_{agg}_{header}"),
+        None,
+    )?;
+    let expected = df![
+        "index1" => ["foo", "bar"],
+        "values1_sum_large" => [4, 11],
+        "values1_sum_small" => [7, 11],
+        "values1_max_large" => [2, 7],
+        "values1_max_small" => [3, 6],
+        "values2_sum_large" => [9, 15],
+        "values2_sum_small" => [13, 17],
+        "values2_max_large" => [5, 9],
+        "values2_max_small" => [6, 9],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // The default names include the aggregation.
+    let out = pivot_multi(
+        &df,
+        ["index1"],
+        ["cols1"],
+        Some(["values1"]),
+        true,
+        vec![PivotAgg::Min, PivotAgg::Count],
+        None,
+        None,
+    )?;
+    assert_eq!(
+        out.get_column_names(),
+        &[
+            "index1",
+            "values1_min_cols1_large",
+            "values1_min_cols1_small",
+            "values1_count_cols1_large",
+            "values1_count_cols1_small"
+        ]
+    );
+
+    // Templates must generate unique names.
+    assert!(pivot_multi(
+        &df,
+        ["index1"],
+        ["cols1"],
+        Some(["values1"]),
+        true,
+        vec![PivotAgg::Sum, PivotAgg::Max],
+        Some("{header}"),
+        None,
+    )
+    .is_err());
+    Ok(())
+}
+
 #[test]
 fn test_pivot_2() -> PolarsResult<()> {
     let df = df![
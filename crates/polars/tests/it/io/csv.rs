@@ -1274,3 +1274,30 @@ fn test_read_io_reader() {
     let expected = CsvReader::new(file).finish().unwrap();
     assert!(df.equals(&expected))
 }
+
+#[test]
+fn test_low_memory_decodes_single_chunk() -> PolarsResult<()> {
+    let mut csv = String::from("a,b\n");
+    for i in 0..10_000 {
+        csv.push_str(&format!("{i},value_{}\n", i % 7));
+    }
+    let read = |low_memory, n_threads| {
+        CsvReader::new(Cursor::new(csv.as_str()))
+            .low_memory(low_memory)
+            .with_n_threads(n_threads)
+            .with_rechunk(false)
+            .finish()
+    };
+
+    let expected = read(false, Some(4))?;
+    assert!(expected.n_chunks() > 1);
+    // In low memory mode a single chunk is decoded at a time.
+    let df = read(true, None)?;
+    assert_eq!(df.n_chunks(), 1);
+    assert!(df.equals(&expected));
+    // The number of threads takes precedence over low memory mode.
+    let df = read(true, Some(4))?;
+    assert_eq!(df.n_chunks(), expected.n_chunks());
+    assert!(df.equals(&expected));
+    Ok(())
+}
@@ -120,12 +120,18 @@ fn test_read_ipc_with_columns() {
 fn test_write_with_compression() {
     let mut df = create_df();
 
-    let compressions = vec![None, Some(IpcCompression::LZ4), Some(IpcCompression::ZSTD)];
-
-    for compression in compressions.into_iter() {
+    let compressions = vec![
+        (None, None),
+        (Some(IpcCompression::LZ4), None),
+        (Some(IpcCompression::ZSTD), None),
+        (Some(IpcCompression::ZSTD), Some(19)),
+    ];
+
+    for (compression, level) in compressions.into_iter() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
         IpcWriter::new(&mut buf)
             .with_compression(compression)
+            .with_compression_level(level)
             .finish(&mut df)
             .expect("ipc writer");
         buf.set_position(0);
@@ -135,6 +141,20 @@ fn test_write_with_compression() {
             .unwrap_or_else(|_| panic!("IPC reader: {:?}", compression));
         assert!(df.equals(&df_read));
     }
+
+    // Only ZSTD has compression levels.
+    let invalid = [
+        (Some(IpcCompression::ZSTD), 23),
+        (Some(IpcCompression::LZ4), 3),
+        (None, 3),
+    ];
+    for (compression, level) in invalid {
+        let result = IpcWriter::new(Cursor::new(Vec::new()))
+            .with_compression(compression)
+            .with_compression_level(Some(level))
+            .finish(&mut df);
+        assert!(result.is_err());
+    }
 }
 
 #[test]
@@ -151,3 +171,39 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_write_ipc_enum_compact_keys() -> PolarsResult<()> {
+    use polars_core::export::arrow::array::Utf8ViewArray;
+    use polars_core::export::arrow::datatypes::IntegerType;
+
+    let dtype = create_enum_data_type(Utf8ViewArray::from_slice_values(["x", "y", "z"]));
+    let enums = Series::new("e", &[Some("z"), None, Some("x")]).cast(&dtype)?;
+    let cats = Series::new("c", &["a", "b", "a"])
+        .cast(&DataType::Categorical(None, Default::default()))?;
+    let mut df = DataFrame::new(vec![enums, cats])?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    // Enums get the narrowest keys, categoricals keep u32 keys.
+    let mut reader = IpcReader::new(buf);
+    let schema = reader.schema()?;
+    let key_types = schema
+        .fields
+        .iter()
+        .map(|field| match field.data_type() {
+            ArrowDataType::Dictionary(key_type, _, _) => *key_type,
+            dtype => panic!("expected a dictionary, got {dtype:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(key_types, [IntegerType::UInt8, IntegerType::UInt32]);
+
+    // The keys are widened again on read.
+    let out = reader.finish()?;
+    assert_eq!(out.column("e")?.dtype(), &dtype);
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
@@ -153,4 +153,70 @@ mod test {
         let actual = IpcStreamReader::new(reader).finish().unwrap();
         assert_df_eq!(df(), actual);
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_write_batched_categorical_dictionaries() -> PolarsResult<()> {
+        fn batch(values: &[&str]) -> PolarsResult<DataFrame> {
+            let s =
+                Series::new("c", values).cast(&DataType::Categorical(None, Default::default()))?;
+            DataFrame::new(vec![s])
+        }
+        // Every batch has its own categories, which the stream has to unify.
+        let batches = [
+            batch(&["a", "b"])?,
+            batch(&["c", "a"])?,
+            batch(&["b", "d"])?,
+        ];
+
+        for mode in [IpcDictionaryMode::Delta, IpcDictionaryMode::UnifyAtFinish] {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            let mut writer = IpcStreamWriter::new(&mut buf)
+                .with_dictionary_mode(mode)
+                .batched(&batches[0].schema())?;
+            for batch in &batches {
+                writer.write_batch(batch)?;
+            }
+            writer.finish()?;
+            buf.set_position(0);
+
+            let actual = IpcStreamReader::new(buf).finish()?;
+            let actual = actual.column("c")?.cast(&DataType::String)?;
+            let expected = Series::new("c", &["a", "b", "c", "a", "b", "d"]);
+            assert!(actual.equals(&expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_write_batched_enum() -> PolarsResult<()> {
+        use polars_core::export::arrow::array::Utf8ViewArray;
+
+        let dtype = create_enum_data_type(Utf8ViewArray::from_slice_values(["x", "y", "z"]));
+        let batch = |values: &[&str]| -> PolarsResult<DataFrame> {
+            DataFrame::new(vec![Series::new("e", values).cast(&dtype)?])
+        };
+        // The batches don't use all the categories, which the stream must keep.
+        let batches = [batch(&["z", "x"])?, batch(&["z"])?];
+
+        for mode in [IpcDictionaryMode::Delta, IpcDictionaryMode::UnifyAtFinish] {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            let mut writer = IpcStreamWriter::new(&mut buf)
+                .with_dictionary_mode(mode)
+                .batched(&batches[0].schema())?;
+            for batch in &batches {
+                writer.write_batch(batch)?;
+            }
+            writer.finish()?;
+            buf.set_position(0);
+
+            let actual = IpcStreamReader::new(buf).finish()?;
+            let actual = actual.column("e")?;
+            assert_eq!(actual.dtype(), &dtype);
+            let expected = Series::new("e", &["z", "x", "z"]).cast(&dtype)?;
+            assert!(actual.equals(&expected));
+        }
+        Ok(())
+    }
 }
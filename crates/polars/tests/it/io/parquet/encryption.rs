@@ -0,0 +1,82 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use polars::prelude::*;
+
+const FOOTER_KEY: &[u8] = b"0123456789012345";
+const COLUMN_KEY: &[u8] = b"1234567890123450";
+
+fn df() -> PolarsResult<DataFrame> {
+    df! {
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    }
+}
+
+fn write(df: &mut DataFrame, encryption: FileEncryptionProperties) -> PolarsResult<Vec<u8>> {
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_encryption(Some(encryption))
+        .finish(df)?;
+    Ok(buf.into_inner())
+}
+
+fn read(buf: Vec<u8>, decryption: Option<FileDecryptionProperties>) -> PolarsResult<DataFrame> {
+    ParquetReader::new(Cursor::new(buf))
+        .with_decryption(decryption.map(Arc::new))
+        .finish()
+}
+
+#[test]
+fn test_encryption_footer_key() -> PolarsResult<()> {
+    let mut df = df()?;
+    let buf = write(&mut df, FileEncryptionProperties::new(FOOTER_KEY.to_vec()))?;
+
+    let decryption = FileDecryptionProperties::new().with_footer_key(FOOTER_KEY.to_vec());
+    assert!(read(buf.clone(), Some(decryption))?.equals(&df));
+
+    assert!(read(buf.clone(), None).is_err());
+    let decryption = FileDecryptionProperties::new().with_footer_key(COLUMN_KEY.to_vec());
+    assert!(read(buf, Some(decryption)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_encryption_column_key_retriever() -> PolarsResult<()> {
+    let mut df = df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+        .with_footer_key_metadata(b"footer".to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec(), Some(b"column".to_vec()));
+    let buf = write(&mut df, encryption)?;
+
+    let retriever = |key_metadata: &[u8]| match key_metadata {
+        b"footer" => Ok(FOOTER_KEY.to_vec()),
+        b"column" => Ok(COLUMN_KEY.to_vec()),
+        _ => polars_bail!(ComputeError: "unknown key"),
+    };
+    let decryption = FileDecryptionProperties::new().with_key_retriever(Arc::new(retriever));
+    assert!(read(buf, Some(decryption))?.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_encryption_plaintext_footer() -> PolarsResult<()> {
+    let mut df = df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec(), None)
+        .with_plaintext_footer(true);
+    let buf = write(&mut df, encryption)?;
+
+    // The columns that aren't encrypted can be read without the keys.
+    let out = ParquetReader::new(Cursor::new(buf.clone()))
+        .with_columns(Some(vec!["a".to_string()]))
+        .finish()?;
+    assert!(out.equals(&df.select(["a"])?));
+    assert!(read(buf.clone(), None).is_err());
+
+    let decryption = FileDecryptionProperties::new()
+        .with_footer_key(FOOTER_KEY.to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec());
+    assert!(read(buf, Some(decryption))?.equals(&df));
+    Ok(())
+}
@@ -1,5 +1,6 @@
 #![forbid(unsafe_code)]
 mod arrow;
+mod encryption;
 mod read;
 mod roundtrip;
 mod write;
@@ -205,3 +206,138 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_column_write_options() -> PolarsResult<()> {
+    use std::collections::BTreeMap;
+
+    use polars_parquet::parquet::compression::Compression;
+    use polars_parquet::parquet::encoding::Encoding;
+
+    let mut df = df! {
+        "ints" => (0..1000).collect::<Vec<i64>>(),
+        "floats" => (0..1000).map(|i| i as f64 / 3.0).collect::<Vec<_>>(),
+        "strings" => (0..1000).map(|i| format!("s{}", i % 10)).collect::<Vec<_>>(),
+    }?;
+    let column_options = BTreeMap::from([
+        (
+            "ints".to_string(),
+            ParquetColumnWriteOptions::default().with_encoding(ParquetEncoding::DeltaBinaryPacked),
+        ),
+        (
+            "floats".to_string(),
+            ParquetColumnWriteOptions::default().with_encoding(ParquetEncoding::ByteStreamSplit),
+        ),
+        (
+            "strings".to_string(),
+            ParquetColumnWriteOptions::default()
+                .with_dictionary(false)
+                .with_compression(ParquetCompression::Uncompressed),
+        ),
+    ]);
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_column_options(column_options)
+        .finish(&mut df)?;
+
+    let metadata = polars_parquet::read::read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].columns();
+    let has_encoding = |i: usize, encoding: Encoding| {
+        columns[i]
+            .column_encoding()
+            .iter()
+            .any(|e| Encoding::try_from(*e).unwrap() == encoding)
+    };
+    assert!(has_encoding(0, Encoding::DeltaBinaryPacked));
+    assert!(has_encoding(1, Encoding::ByteStreamSplit));
+    assert!(columns[2].dictionary_page_offset().is_none());
+    assert_eq!(columns[0].compression(), Compression::Zstd);
+    assert_eq!(columns[2].compression(), Compression::Uncompressed);
+
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    // The encoding must be supported by the type of the column.
+    let column_options = BTreeMap::from([(
+        "strings".to_string(),
+        ParquetColumnWriteOptions::default().with_encoding(ParquetEncoding::ByteStreamSplit),
+    )]);
+    let mut buf = Cursor::new(Vec::new());
+    let result = ParquetWriter::new(&mut buf)
+        .with_column_options(column_options)
+        .finish(&mut df);
+    assert!(result.is_err());
+
+    // The leaves of nested columns are always plain encoded.
+    let mut df = df! {
+        "lists" => [Series::new("", &[1i64, 2]), Series::new("", &[3i64])],
+    }?;
+    let column_options = BTreeMap::from([(
+        "lists".to_string(),
+        ParquetColumnWriteOptions::default().with_encoding(ParquetEncoding::DeltaBinaryPacked),
+    )]);
+    let mut buf = Cursor::new(Vec::new());
+    let result = ParquetWriter::new(&mut buf)
+        .with_column_options(column_options)
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_sorting_columns_and_key_value_metadata() -> PolarsResult<()> {
+    use std::collections::BTreeMap;
+
+    use polars_parquet::write::SortingColumn;
+
+    let mut df = df! {
+        "a" => [1, 2, 3],
+        "b" => [3.0, 2.0, 1.0],
+    }?;
+    let key_value_metadata = BTreeMap::from([("lineage".to_string(), "trades".to_string())]);
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_sorting_columns(vec![
+            ParquetSortingColumn::new("b")
+                .with_descending(true)
+                .with_nulls_first(true),
+            ParquetSortingColumn::new("a"),
+        ])
+        .with_key_value_metadata(key_value_metadata)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+
+    let metadata = polars_parquet::read::read_metadata(&mut buf)?;
+    let expected = [
+        SortingColumn {
+            column_idx: 1,
+            descending: true,
+            nulls_first: true,
+        },
+        SortingColumn {
+            column_idx: 0,
+            descending: false,
+            nulls_first: false,
+        },
+    ];
+    assert_eq!(metadata.row_groups.len(), 2);
+    for row_group in &metadata.row_groups {
+        assert_eq!(row_group.sorting_columns(), Some(expected.as_slice()));
+    }
+    let key_value_metadata = metadata.key_value_metadata.unwrap();
+    assert!(key_value_metadata
+        .iter()
+        .any(|kv| kv.key == "lineage" && kv.value.as_deref() == Some("trades")));
+
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    let mut buf = Cursor::new(Vec::new());
+    let result = ParquetWriter::new(&mut buf)
+        .with_sorting_columns(vec![ParquetSortingColumn::new("c")])
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
@@ -61,3 +61,78 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_fill_null_overlapping_groups() -> PolarsResult<()> {
+    let df = df![
+        "idx" => [0i64, 1, 2, 3, 4],
+        "a" => [Some(1), None, Some(3), None, None],
+    ]?;
+    let lists = |out: &DataFrame| -> PolarsResult<Vec<Vec<Option<i32>>>> {
+        Ok(out
+            .column("a")?
+            .list()?
+            .into_no_null_iter()
+            .map(|s| s.i32().unwrap().into_iter().collect())
+            .collect())
+    };
+
+    // The windows of a rolling group-by overlap, a null value that leads one window is
+    // filled in the window before it.
+    let out = df
+        .clone()
+        .lazy()
+        .rolling(
+            col("idx"),
+            [],
+            RollingGroupOptions {
+                period: Duration::parse("2i"),
+                offset: Duration::parse("-2i"),
+                closed_window: ClosedWindow::Right,
+                ..Default::default()
+            },
+        )
+        .agg([col("a").fill_null_with_strategy(FillNullStrategy::Forward(None))])
+        .collect()?;
+    assert_eq!(
+        lists(&out)?,
+        [
+            vec![Some(1)],
+            vec![Some(1), Some(1)],
+            vec![None, Some(3)],
+            vec![Some(3), Some(3)],
+            vec![None, None],
+        ]
+    );
+
+    // Dynamic windows overlap if their period is longer than their interval.
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("idx"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1i"),
+                period: Duration::parse("3i"),
+                offset: Duration::parse("0i"),
+                closed_window: ClosedWindow::Left,
+                label: Label::Left,
+                start_by: StartBy::DataPoint,
+                ..Default::default()
+            },
+        )
+        .agg([col("a").fill_null_with_strategy_and_value(FillNullStrategy::Forward(None), lit(0))])
+        .collect()?;
+    assert_eq!(
+        lists(&out)?,
+        [
+            vec![Some(1), Some(1), Some(3)],
+            vec![Some(0), Some(3), Some(3)],
+            vec![Some(3), Some(3), Some(3)],
+            vec![Some(0), Some(0)],
+            vec![Some(0)],
+        ]
+    );
+    Ok(())
+}
@@ -194,13 +194,16 @@ from polars.io import (
     read_json,
     read_ndjson,
     read_ods,
+    read_orc,
     read_parquet,
     read_parquet_schema,
     scan_csv,
     scan_delta,
     scan_iceberg,
     scan_ipc,
+    scan_lance,
     scan_ndjson,
+    scan_orc,
     scan_parquet,
     scan_pyarrow_dataset,
 )
@@ -310,13 +313,16 @@ __all__ = [
     "read_json",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_schema",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
     "scan_ipc",
+    "scan_lance",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
     # polars.stringcache
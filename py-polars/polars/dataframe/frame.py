@@ -151,6 +151,7 @@ if TYPE_CHECKING:
         NullStrategy,
         OneOrMoreDataTypes,
         Orientation,
+        ParquetColumnOptions,
         ParquetCompression,
         PivotAgg,
         PolarsDataType,
@@ -1983,6 +1984,68 @@ class DataFrame:
             self._df.write_ndjson(file)
         return None
 
+    def serialize_rows_json(
+        self,
+        *,
+        date_format: str | None = None,
+        datetime_format: str | None = None,
+        skip_nulls: bool = False,
+        sort_keys: bool = False,
+    ) -> Series:
+        """
+        Serialize every row to a JSON object.
+
+        This is useful to send the rows to an API or a message queue one by one.
+
+        Parameters
+        ----------
+        date_format
+            A format string, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate. If no format specified, the default is `"%Y-%m-%d"`.
+        datetime_format
+            A format string, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate. If no format specified, datetimes are written like
+            `"2000-01-29 10:30:00"`, or in RFC 3339 if they have a time zone.
+        skip_nulls
+            Leave out the values that are null instead of writing them as `null`.
+        sort_keys
+            Write the columns sorted by name instead of in the order of the frame.
+
+        Returns
+        -------
+        Series
+            A String Series with a JSON object for every row.
+
+        See Also
+        --------
+        write_ndjson
+        Expr.struct.json_encode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "foo": [1, None],
+        ...         "bar": ["a", "b"],
+        ...     }
+        ... )
+        >>> df.serialize_rows_json(skip_nulls=True, sort_keys=True)
+        shape: (2,)
+        Series: '' [str]
+        [
+            "{"bar":"a","foo":1}"
+            "{"bar":"b"}"
+        ]
+        """
+        return self.to_struct().struct.json_encode(
+            date_format=date_format,
+            datetime_format=datetime_format,
+            skip_nulls=skip_nulls,
+            sort_keys=sort_keys,
+        )
+
     @overload
     def write_csv(
         self,
@@ -2717,6 +2780,7 @@ class DataFrame:
         file: None,
         compression: IpcCompression = "uncompressed",
         *,
+        compression_level: int | None = None,
         future: bool = False,
     ) -> BytesIO: ...
 
@@ -2726,6 +2790,7 @@ class DataFrame:
         file: str | Path | IO[bytes],
         compression: IpcCompression = "uncompressed",
         *,
+        compression_level: int | None = None,
         future: bool = False,
     ) -> None: ...
 
@@ -2734,6 +2799,7 @@ class DataFrame:
         file: str | Path | IO[bytes] | None,
         compression: IpcCompression = "uncompressed",
         *,
+        compression_level: int | None = None,
         future: bool = False,
     ) -> BytesIO | None:
         """
@@ -2748,6 +2814,9 @@ class DataFrame:
             written. If set to `None`, the output is returned as a BytesIO object.
         compression : {'uncompressed', 'lz4', 'zstd'}
             Compression method. Defaults to "uncompressed".
+        compression_level
+            The level of "zstd" compression, from 1 to 22. Higher levels mean smaller
+            files, but slower writes. Defaults to 3. Other compressions have no levels.
         future
             Setting this to `True` will write Polars' internal data structures that
             might not be available by other Arrow implementations.
@@ -2784,7 +2853,7 @@ class DataFrame:
                 "The `future` parameter of `DataFrame.write_ipc` is considered unstable."
             )
 
-        self._df.write_ipc(file, compression, future)
+        self._df.write_ipc(file, compression, compression_level, future)
         return file if return_bytes else None  # type: ignore[return-value]
 
     @overload
@@ -2854,6 +2923,8 @@ class DataFrame:
         statistics: bool = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filters: bool = False,
+        column_options: Mapping[str, ParquetColumnOptions] | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
     ) -> None:
@@ -2883,6 +2954,26 @@ class DataFrame:
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        bloom_filters
+            Write a bloom filter of every column chunk of the integer, temporal, string,
+            binary and categorical columns. Scans use these to skip the row groups that
+            can't contain the values of equality and `is_in` predicates. Not supported
+            with `use_pyarrow`.
+        column_options
+            Options of single columns, by name, which override the options of the
+            file. Every column can have the keys:
+
+            - dictionary: Whether to dictionary encode the column. By default, all
+              columns but the floating point columns are dictionary encoded, unless
+              an `encoding` is given. Categorical columns are always dictionary
+              encoded.
+            - encoding: The encoding of the values, one of "plain",
+              "delta_binary_packed" (integers), "delta_length_byte_array" (strings
+              and binary) and "byte_stream_split" (floats). The leaves of nested
+              columns can only be plain encoded.
+            - compression, compression_level: The compression of the column.
+
+            Not supported with `use_pyarrow`.
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -2976,6 +3067,8 @@ class DataFrame:
                 statistics,
                 row_group_size,
                 data_page_size,
+                bloom_filters,
+                dict(column_options) if column_options is not None else None,
             )
 
     @deprecate_renamed_parameter("if_exists", "if_table_exists", version="0.20.0")
@@ -5677,6 +5770,82 @@ class DataFrame:
             self._df.upsample(group_by, time_column, every, offset, maintain_order)
         )
 
+    def complete(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        group_by: str | Sequence[str] | None = None,
+        fill_strategy: FillNullStrategy | None = None,
+        limit: int | None = None,
+    ) -> DataFrame:
+        """
+        Insert the missing rows of a time series.
+
+        For every group of the `group_by` columns, a row is added for every `every`
+        interval between the first and the last value of `time_column` that is
+        missing. The inserted rows get the keys of their group.
+
+        Parameters
+        ----------
+        time_column
+            The time column in which the gaps are filled.
+        every
+            The interval between the rows of a complete time series.
+        group_by
+            The columns of the groups that are completed on their own.
+        fill_strategy : {'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            The strategy to fill the other columns of the inserted rows with, within
+            their group. They are null if it isn't given. The null values of the
+            existing rows are not filled.
+        limit
+            The number of consecutive null values to fill when using the 'forward' or
+            'backward' strategy.
+
+        Returns
+        -------
+        DataFrame
+            The groups keep the order in which they first appear, and the rows of
+            every group are sorted by `time_column`.
+
+        See Also
+        --------
+        upsample
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2024, 1, 1), date(2024, 1, 4)],
+        ...         "values": [1, 4],
+        ...     }
+        ... )
+        >>> df.complete("date", every="1d")
+        shape: (4, 2)
+        ┌────────────┬────────┐
+        │ date       ┆ values │
+        │ ---        ┆ ---    │
+        │ date       ┆ i64    │
+        ╞════════════╪════════╡
+        │ 2024-01-01 ┆ 1      │
+        │ 2024-01-02 ┆ null   │
+        │ 2024-01-03 ┆ null   │
+        │ 2024-01-04 ┆ 4      │
+        └────────────┴────────┘
+        """
+        return (
+            self.lazy()
+            .complete(
+                time_column,
+                every=every,
+                group_by=group_by,
+                fill_strategy=fill_strategy,
+                limit=limit,
+            )
+            .collect(_eager=True)
+        )
+
     def join_asof(
         self,
         other: DataFrame,
@@ -5692,6 +5861,9 @@ class DataFrame:
         tolerance: str | int | float | timedelta | None = None,
         allow_parallel: bool = True,
         force_parallel: bool = False,
+        allow_exact_matches: bool = True,
+        right_index_name: str | None = None,
+        strategy_column: str | None = None,
     ) -> DataFrame:
         """
         Perform an asof join.
@@ -5768,6 +5940,18 @@ class DataFrame:
         force_parallel
             Force the physical plan to evaluate the computation of both DataFrames up to
             the join in parallel.
+        allow_exact_matches
+            Whether rows with equal keys may be matched. If False, a "backward" search
+            only matches keys that are strictly less than the left's key, a "forward"
+            search only keys that are strictly greater, and a "nearest" search only
+            keys that differ from the left's key.
+        right_index_name
+            Add a column with this name that holds the row index of the matched row
+            of the right DataFrame, or null for the rows without a match.
+        strategy_column
+            A String column of the left DataFrame with the strategy of every row,
+            one of "backward", "forward" or "nearest", to search in a different
+            direction per key. The rows where it is null use `strategy`.
 
         Examples
         --------
@@ -5986,6 +6170,9 @@ class DataFrame:
                 tolerance=tolerance,
                 allow_parallel=allow_parallel,
                 force_parallel=force_parallel,
+                allow_exact_matches=allow_exact_matches,
+                right_index_name=right_index_name,
+                strategy_column=strategy_column,
             )
             .collect(_eager=True)
         )
@@ -6001,6 +6188,7 @@ class DataFrame:
         suffix: str = "_right",
         validate: JoinValidation = "m:m",
         join_nulls: bool = False,
+        drop_key_expressions: bool = False,
     ) -> DataFrame:
         """
         Join in SQL-like fashion.
@@ -6055,6 +6243,11 @@ class DataFrame:
                 - This is currently not supported the streaming engine.
         join_nulls
             Join on null values. By default null values will never produce matches.
+        drop_key_expressions
+            Only use the results of the expressions in `on`, `left_on` and `right_on`
+            that aren't columns as join keys, instead of adding them to the output in
+            place of the columns they are named after. The coalesced keys of an
+            `outer_coalesce` join are kept, with the suffix if their name is taken.
 
         Returns
         -------
@@ -6155,6 +6348,7 @@ class DataFrame:
                 suffix=suffix,
                 validate=validate,
                 join_nulls=join_nulls,
+                drop_key_expressions=drop_key_expressions,
             )
             .collect(_eager=True)
         )
@@ -8087,6 +8281,62 @@ class DataFrame:
         """
         return self.lazy().with_columns_seq(*exprs, **named_exprs).collect(_eager=True)
 
+    def with_columns_chained(
+        self,
+        *exprs: IntoExpr | Iterable[IntoExpr],
+        **named_exprs: IntoExpr,
+    ) -> DataFrame:
+        """
+        Add columns to this DataFrame, where an expression may refer to earlier ones.
+
+        Added columns will replace existing columns with the same name.
+
+        The expressions are added in sequential stages, like a chain of
+        `with_columns` calls. An expression starts a new stage if it refers to, or
+        replaces, a column of the current stage, so a chain of derived columns can
+        be added in a single call.
+
+        Parameters
+        ----------
+        *exprs
+            Column(s) to add, specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_exprs
+            Additional columns to add, specified as keyword arguments.
+            The columns will be renamed to the keyword used.
+
+        Returns
+        -------
+        DataFrame
+            A new DataFrame with the columns added.
+
+        See Also
+        --------
+        with_columns
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": [10, 20, 30]})
+        >>> df.with_columns_chained(
+        ...     c=pl.col("a") * 2,
+        ...     d=pl.col("c") + pl.col("b"),
+        ... )
+        shape: (3, 4)
+        ┌─────┬─────┬─────┬─────┐
+        │ a   ┆ b   ┆ c   ┆ d   │
+        │ --- ┆ --- ┆ --- ┆ --- │
+        │ i64 ┆ i64 ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╪═════╡
+        │ 1   ┆ 10  ┆ 2   ┆ 12  │
+        │ 2   ┆ 20  ┆ 4   ┆ 24  │
+        │ 3   ┆ 30  ┆ 6   ┆ 36  │
+        └─────┴─────┴─────┴─────┘
+        """
+        return self.lazy().with_columns_chained(*exprs, **named_exprs).collect(
+            _eager=True
+        )
+
     @overload
     def n_chunks(self, strategy: Literal["first"] = ...) -> int: ...
 
@@ -8808,6 +9058,7 @@ class DataFrame:
         *,
         keep: UniqueKeepStrategy = "any",
         maintain_order: bool = False,
+        by: str | None = None,
     ) -> DataFrame:
         """
         Drop duplicate rows from this dataframe.
@@ -8817,7 +9068,7 @@ class DataFrame:
         subset
             Column name(s) or selector(s), to consider when identifying
             duplicate rows. If set to `None` (default), use all columns.
-        keep : {'first', 'last', 'any', 'none'}
+        keep : {'first', 'last', 'any', 'none', 'max_by', 'min_by'}
             Which of the duplicate rows to keep.
 
             * 'any': Does not give any guarantee of which row is kept.
@@ -8825,11 +9076,14 @@ class DataFrame:
             * 'none': Don't keep duplicate rows.
             * 'first': Keep first unique row.
             * 'last': Keep last unique row.
+            * 'max_by': Keep the row with the largest value in the `by` column.
+            * 'min_by': Keep the row with the smallest value in the `by` column.
         maintain_order
             Keep the same order as the original DataFrame. This is more expensive to
-            compute.
-            Settings this to `True` blocks the possibility
-            to run on the streaming engine.
+            compute. The streaming engine then finds the unique rows by sorting them.
+        by
+            The column to compare when `keep` is 'max_by' or 'min_by'. Null values
+            are only kept if a group has no other value, and ties keep the first row.
 
         Returns
         -------
@@ -8881,10 +9135,30 @@ class DataFrame:
         │ 3   ┆ a   ┆ b   │
         │ 1   ┆ a   ┆ b   │
         └─────┴─────┴─────┘
+
+        Keep the latest version of every record.
+
+        >>> records = pl.DataFrame(
+        ...     {
+        ...         "id": [1, 1, 2, 2, 1],
+        ...         "updated": [3, 5, 1, 4, 2],
+        ...         "value": ["a", "b", "c", "d", "e"],
+        ...     }
+        ... )
+        >>> records.unique("id", keep="max_by", by="updated", maintain_order=True)
+        shape: (2, 3)
+        ┌─────┬─────────┬───────┐
+        │ id  ┆ updated ┆ value │
+        │ --- ┆ ---     ┆ ---   │
+        │ i64 ┆ i64     ┆ str   │
+        ╞═════╪═════════╪═══════╡
+        │ 1   ┆ 5       ┆ b     │
+        │ 2   ┆ 4       ┆ d     │
+        └─────┴─────────┴───────┘
         """
         return (
             self.lazy()
-            .unique(subset=subset, keep=keep, maintain_order=maintain_order)
+            .unique(subset=subset, keep=keep, maintain_order=maintain_order, by=by)
             .collect(_eager=True)
         )
 
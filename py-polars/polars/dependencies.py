@@ -13,6 +13,7 @@ _FSSPEC_AVAILABLE = True
 _GEVENT_AVAILABLE = True
 _HVPLOT_AVAILABLE = True
 _HYPOTHESIS_AVAILABLE = True
+_LANCE_AVAILABLE = True
 _NUMPY_AVAILABLE = True
 _PANDAS_AVAILABLE = True
 _PYARROW_AVAILABLE = True
@@ -154,6 +155,7 @@ if TYPE_CHECKING:
     import gevent
     import hvplot
     import hypothesis
+    import lance
     import numpy
     import pandas
     import pyarrow
@@ -177,6 +179,7 @@ else:
     fsspec, _FSSPEC_AVAILABLE = _lazy_import("fsspec")
     hvplot, _HVPLOT_AVAILABLE = _lazy_import("hvplot")
     hypothesis, _HYPOTHESIS_AVAILABLE = _lazy_import("hypothesis")
+    lance, _LANCE_AVAILABLE = _lazy_import("lance")
     numpy, _NUMPY_AVAILABLE = _lazy_import("numpy")
     pandas, _PANDAS_AVAILABLE = _lazy_import("pandas")
     pyarrow, _PYARROW_AVAILABLE = _lazy_import("pyarrow")
@@ -294,6 +297,7 @@ __all__ = [
     "fsspec",
     "gevent",
     "hvplot",
+    "lance",
     "numpy",
     "pandas",
     "pydantic",
@@ -313,6 +317,7 @@ __all__ = [
     "_GEVENT_AVAILABLE",
     "_HVPLOT_AVAILABLE",
     "_HYPOTHESIS_AVAILABLE",
+    "_LANCE_AVAILABLE",
     "_NUMPY_AVAILABLE",
     "_PANDAS_AVAILABLE",
     "_PYARROW_AVAILABLE",
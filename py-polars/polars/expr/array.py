@@ -779,3 +779,190 @@ class ExprArrayNameSpace:
         """
         n = parse_as_expression(n)
         return wrap_expr(self._pyexpr.arr_shift(n))
+
+    def dot(self, other: IntoExpr) -> Expr:
+        """
+        Compute the dot product of every sub-array with the sub-array of `other`.
+
+        The result is a `Float64` column.
+
+        Parameters
+        ----------
+        other
+            Array expression or Series of the same width. If it has a single value,
+            that sub-array is used for every row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2], [3, 4]]}, schema={"a": pl.Array(pl.Int64, 2)}
+        ... )
+        >>> ones = pl.Series([[1, 1]], dtype=pl.Array(pl.Int64, 2))
+        >>> df.with_columns(dot=pl.col("a").arr.dot(ones))
+        shape: (2, 2)
+        ┌───────────────┬─────┐
+        │ a             ┆ dot │
+        │ ---           ┆ --- │
+        │ array[i64, 2] ┆ f64 │
+        ╞═══════════════╪═════╡
+        │ [1, 2]        ┆ 3.0 │
+        │ [3, 4]        ┆ 7.0 │
+        └───────────────┴─────┘
+        """
+        other = parse_as_expression(other)
+        return wrap_expr(self._pyexpr.arr_dot(other))
+
+    def matmul(self, matrix: IntoExpr) -> Expr:
+        """
+        Multiply a matrix with every sub-array as a column vector.
+
+        The result is an `Array` column of `Float64` and width `m`.
+
+        Parameters
+        ----------
+        matrix
+            Nested array expression or Series of shape `(m, n)`, where `n` is the width
+            of the sub-arrays. If it has a single value, that matrix is used for every
+            row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2], [3, 4]]}, schema={"a": pl.Array(pl.Int64, 2)}
+        ... )
+        >>> swap = pl.Series(
+        ...     [[[0, 1], [1, 0]]], dtype=pl.Array(pl.Array(pl.Int64, 2), 2)
+        ... )
+        >>> df.with_columns(swapped=pl.col("a").arr.matmul(swap))
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ swapped       │
+        │ ---           ┆ ---           │
+        │ array[i64, 2] ┆ array[f64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 2]        ┆ [2.0, 1.0]    │
+        │ [3, 4]        ┆ [4.0, 3.0]    │
+        └───────────────┴───────────────┘
+        """
+        matrix = parse_as_expression(matrix)
+        return wrap_expr(self._pyexpr.arr_matmul(matrix))
+
+    def sum_axis(self, axis: int) -> Expr:
+        """
+        Compute the sum along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.with_columns(sum=pl.col("a").arr.sum_axis(0))
+        shape: (2, 2)
+        ┌─────────────────────────┬───────────────┐
+        │ a                       ┆ sum           │
+        │ ---                     ┆ ---           │
+        │ array[array[i64, 2], 2] ┆ array[f64, 2] │
+        ╞═════════════════════════╪═══════════════╡
+        │ [[1, 2], [3, 4]]        ┆ [4.0, 6.0]    │
+        │ [[5, 6], [7, 8]]        ┆ [12.0, 14.0]  │
+        └─────────────────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_sum_axis(axis))
+
+    def mean_axis(self, axis: int) -> Expr:
+        """
+        Compute the mean along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.with_columns(mean=pl.col("a").arr.mean_axis(0))
+        shape: (2, 2)
+        ┌─────────────────────────┬───────────────┐
+        │ a                       ┆ mean          │
+        │ ---                     ┆ ---           │
+        │ array[array[i64, 2], 2] ┆ array[f64, 2] │
+        ╞═════════════════════════╪═══════════════╡
+        │ [[1, 2], [3, 4]]        ┆ [2.0, 3.0]    │
+        │ [[5, 6], [7, 8]]        ┆ [6.0, 7.0]    │
+        └─────────────────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_mean_axis(axis))
+
+    def min_axis(self, axis: int) -> Expr:
+        """
+        Compute the minimum along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.with_columns(min=pl.col("a").arr.min_axis(0))
+        shape: (2, 2)
+        ┌─────────────────────────┬───────────────┐
+        │ a                       ┆ min           │
+        │ ---                     ┆ ---           │
+        │ array[array[i64, 2], 2] ┆ array[f64, 2] │
+        ╞═════════════════════════╪═══════════════╡
+        │ [[1, 2], [3, 4]]        ┆ [1.0, 2.0]    │
+        │ [[5, 6], [7, 8]]        ┆ [5.0, 6.0]    │
+        └─────────────────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_min_axis(axis))
+
+    def max_axis(self, axis: int) -> Expr:
+        """
+        Compute the maximum along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.with_columns(max=pl.col("a").arr.max_axis(0))
+        shape: (2, 2)
+        ┌─────────────────────────┬───────────────┐
+        │ a                       ┆ max           │
+        │ ---                     ┆ ---           │
+        │ array[array[i64, 2], 2] ┆ array[f64, 2] │
+        ╞═════════════════════════╪═══════════════╡
+        │ [[1, 2], [3, 4]]        ┆ [3.0, 4.0]    │
+        │ [[5, 6], [7, 8]]        ┆ [7.0, 8.0]    │
+        └─────────────────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_max_axis(axis))
@@ -90,6 +90,7 @@ if TYPE_CHECKING:
         MapElementsStrategy,
         NullBehavior,
         NumericLiteral,
+        OutOfBoundsPolicy,
         PolarsDataType,
         RankMethod,
         RollingInterpolationMethod,
@@ -2652,15 +2653,28 @@ class Expr:
         )
 
     def gather(
-        self, indices: int | list[int] | Expr | Series | np.ndarray[Any, Any]
+        self,
+        indices: int | list[int] | Expr | Series | np.ndarray[Any, Any],
+        *,
+        out_of_bounds: OutOfBoundsPolicy = "error",
     ) -> Self:
         """
         Take values by index.
 
+        In a group context, e.g. `group_by` or `over`, the indices are relative to
+        each group.
+
         Parameters
         ----------
         indices
             An expression that leads to a UInt32 dtyped Series.
+        out_of_bounds : {'error', 'null', 'clamp'}
+            What to do with indices that are out of bounds (of the group in a group
+            context).
+
+            - 'error': raise an error.
+            - 'null': gather a null value.
+            - 'clamp': gather the first or the last value.
 
         Returns
         -------
@@ -2698,6 +2712,21 @@ class Expr:
         │ one   ┆ [2, 98]   │
         │ two   ┆ [4, 99]   │
         └───────┴───────────┘
+
+        Gather a null value for the indices that are out of bounds of a group.
+
+        >>> df.group_by("group", maintain_order=True).agg(
+        ...     pl.col("value").gather([0, 3], out_of_bounds="null")
+        ... )
+        shape: (2, 2)
+        ┌───────┬───────────┐
+        │ group ┆ value     │
+        │ ---   ┆ ---       │
+        │ str   ┆ list[i64] │
+        ╞═══════╪═══════════╡
+        │ one   ┆ [1, null] │
+        │ two   ┆ [3, null] │
+        └───────┴───────────┘
         """
         if isinstance(indices, list) or (
             _check_for_numpy(indices) and isinstance(indices, np.ndarray)
@@ -2705,7 +2734,7 @@ class Expr:
             indices_lit = F.lit(pl.Series("", indices, dtype=Int64))._pyexpr
         else:
             indices_lit = parse_as_expression(indices)  # type: ignore[arg-type]
-        return self._from_pyexpr(self._pyexpr.gather(indices_lit))
+        return self._from_pyexpr(self._pyexpr.gather(indices_lit, out_of_bounds))
 
     def get(self, index: int | Expr) -> Self:
         """
@@ -2838,9 +2867,12 @@ class Expr:
         Parameters
         ----------
         value
-            Value used to fill null values.
+            Value used to fill null values. Combined with the 'forward' or
+            'backward' strategy, it fills the null values that are left, e.g.
+            beyond the `limit`.
         strategy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
-            Strategy used to fill null values.
+            Strategy used to fill null values. The 'forward' and 'backward'
+            strategies fill within the groups of a window expression (`over`).
         limit
             Number of consecutive null values to fill when using the 'forward' or
             'backward' strategy.
@@ -2909,8 +2941,15 @@ class Expr:
         │ 1.5 ┆ 6.0 │
         └─────┴─────┘
         """
-        if value is not None and strategy is not None:
-            msg = "cannot specify both `value` and `strategy`"
+        if (
+            value is not None
+            and strategy is not None
+            and strategy not in ("forward", "backward")
+        ):
+            msg = (
+                "cannot specify both `value` and `strategy`,"
+                " unless the strategy is 'forward' or 'backward'"
+            )
             raise ValueError(msg)
         elif value is None and strategy is None:
             msg = "must specify either a fill `value` or `strategy`"
@@ -2921,6 +2960,10 @@ class Expr:
 
         if value is not None:
             value = parse_as_expression(value, str_as_lit=True)
+            if strategy is not None:
+                return self._from_pyexpr(
+                    self._pyexpr.fill_null_with_strategy(strategy, limit, value)
+                )
             return self._from_pyexpr(self._pyexpr.fill_null(value))
         else:
             return self._from_pyexpr(
@@ -7880,6 +7923,156 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
 
+    @unstable()
+    def rolling_count_by(
+        self,
+        by: str,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Count the non-null values in a temporal window per row.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        With `closed="right"` (the default), the window of the row at time `t` is
+        `(t - window_size, t]`.
+
+        Parameters
+        ----------
+        by
+            The column that defines the windows, of dtype `{Date, Datetime}`. It should
+            be sorted in ascending order.
+        window_size
+            The length of the window, as a timedelta or in the string language of
+            :meth:`rolling_sum`, e.g. `"1h"` or `"3d"`.
+        min_periods
+            The number of rows in the window that is needed to compute a result,
+            otherwise the result is null.
+        closed : {'right', 'left', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive).
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [
+        ...             datetime(2001, 1, 1, 0, 0),
+        ...             datetime(2001, 1, 1, 0, 30),
+        ...             datetime(2001, 1, 1, 1, 0),
+        ...             datetime(2001, 1, 1, 2, 0),
+        ...             datetime(2001, 1, 1, 2, 10),
+        ...         ],
+        ...         "user": ["a", "b", "a", "c", "c"],
+        ...     }
+        ... )
+        >>> df.with_columns(events=pl.col("user").rolling_count_by("date", "1h"))
+        shape: (5, 3)
+        ┌─────────────────────┬──────┬────────┐
+        │ date                ┆ user ┆ events │
+        │ ---                 ┆ ---  ┆ ---    │
+        │ datetime[μs]        ┆ str  ┆ u32    │
+        ╞═════════════════════╪══════╪════════╡
+        │ 2001-01-01 00:00:00 ┆ a    ┆ 1      │
+        │ 2001-01-01 00:30:00 ┆ b    ┆ 2      │
+        │ 2001-01-01 01:00:00 ┆ a    ┆ 2      │
+        │ 2001-01-01 02:00:00 ┆ c    ┆ 1      │
+        │ 2001-01-01 02:10:00 ┆ c    ┆ 2      │
+        └─────────────────────┴──────┴────────┘
+        """
+        window_size, min_periods = _prepare_rolling_window_args(
+            window_size, min_periods
+        )
+        return self._from_pyexpr(
+            self._pyexpr.rolling_count_by(
+                by, window_size, min_periods, closed, warn_if_unsorted
+            )
+        )
+
+    @unstable()
+    def rolling_n_unique_by(
+        self,
+        by: str,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Count the distinct values in a temporal window per row.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        With `closed="right"` (the default), the window of the row at time `t` is
+        `(t - window_size, t]`. Null is counted as a distinct value.
+
+        Parameters
+        ----------
+        by
+            The column that defines the windows, of dtype `{Date, Datetime}`. It should
+            be sorted in ascending order.
+        window_size
+            The length of the window, as a timedelta or in the string language of
+            :meth:`rolling_sum`, e.g. `"1h"` or `"3d"`.
+        min_periods
+            The number of rows in the window that is needed to compute a result,
+            otherwise the result is null.
+        closed : {'right', 'left', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive).
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [
+        ...             datetime(2001, 1, 1, 0, 0),
+        ...             datetime(2001, 1, 1, 0, 30),
+        ...             datetime(2001, 1, 1, 1, 0),
+        ...             datetime(2001, 1, 1, 2, 0),
+        ...             datetime(2001, 1, 1, 2, 10),
+        ...         ],
+        ...         "user": ["a", "b", "a", "c", "c"],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     distinct_users=pl.col("user").rolling_n_unique_by("date", "1h")
+        ... )
+        shape: (5, 3)
+        ┌─────────────────────┬──────┬────────────────┐
+        │ date                ┆ user ┆ distinct_users │
+        │ ---                 ┆ ---  ┆ ---            │
+        │ datetime[μs]        ┆ str  ┆ u32            │
+        ╞═════════════════════╪══════╪════════════════╡
+        │ 2001-01-01 00:00:00 ┆ a    ┆ 1              │
+        │ 2001-01-01 00:30:00 ┆ b    ┆ 2              │
+        │ 2001-01-01 01:00:00 ┆ a    ┆ 2              │
+        │ 2001-01-01 02:00:00 ┆ c    ┆ 1              │
+        │ 2001-01-01 02:10:00 ┆ c    ┆ 1              │
+        └─────────────────────┴──────┴────────────────┘
+        """
+        window_size, min_periods = _prepare_rolling_window_args(
+            window_size, min_periods
+        )
+        return self._from_pyexpr(
+            self._pyexpr.rolling_n_unique_by(
+                by, window_size, min_periods, closed, warn_if_unsorted
+            )
+        )
+
     @unstable()
     def rolling_map(
         self,
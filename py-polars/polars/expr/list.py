@@ -868,6 +868,140 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_diff(n, null_behavior))
 
+    def rolling_sum(self, window_size: int, min_periods: int | None = None) -> Expr:
+        """
+        Apply a rolling sum within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+        Like :meth:`sum`, sublists of 8 and 16 bit integers are summed as `Int64`.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3, 4], [10, 2, 1]]})
+        >>> df.with_columns(rolling_sum=pl.col("n").list.rolling_sum(2))
+        shape: (2, 2)
+        ┌─────────────┬────────────────┐
+        │ n           ┆ rolling_sum    │
+        │ ---         ┆ ---            │
+        │ list[i64]   ┆ list[i64]      │
+        ╞═════════════╪════════════════╡
+        │ [1, 2, … 4] ┆ [null, 3, … 7] │
+        │ [10, 2, 1]  ┆ [null, 12, 3]  │
+        └─────────────┴────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(self._pyexpr.list_rolling_sum(window_size, min_periods))
+
+    def rolling_mean(self, window_size: int, min_periods: int | None = None) -> Expr:
+        """
+        Apply a rolling mean within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3, 4], [10, 2, 1]]})
+        >>> df.with_columns(rolling_mean=pl.col("n").list.rolling_mean(2))
+        shape: (2, 2)
+        ┌─────────────┬────────────────────┐
+        │ n           ┆ rolling_mean       │
+        │ ---         ┆ ---                │
+        │ list[i64]   ┆ list[f64]          │
+        ╞═════════════╪════════════════════╡
+        │ [1, 2, … 4] ┆ [null, 1.5, … 3.5] │
+        │ [10, 2, 1]  ┆ [null, 6.0, 1.5]   │
+        └─────────────┴────────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(self._pyexpr.list_rolling_mean(window_size, min_periods))
+
+    def rolling_min(self, window_size: int, min_periods: int | None = None) -> Expr:
+        """
+        Apply a rolling minimum within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3, 4], [10, 2, 1]]})
+        >>> df.with_columns(rolling_min=pl.col("n").list.rolling_min(2))
+        shape: (2, 2)
+        ┌─────────────┬────────────────┐
+        │ n           ┆ rolling_min    │
+        │ ---         ┆ ---            │
+        │ list[i64]   ┆ list[i64]      │
+        ╞═════════════╪════════════════╡
+        │ [1, 2, … 4] ┆ [null, 1, … 3] │
+        │ [10, 2, 1]  ┆ [null, 2, 1]   │
+        └─────────────┴────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(self._pyexpr.list_rolling_min(window_size, min_periods))
+
+    def rolling_max(self, window_size: int, min_periods: int | None = None) -> Expr:
+        """
+        Apply a rolling maximum within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3, 4], [10, 2, 1]]})
+        >>> df.with_columns(rolling_max=pl.col("n").list.rolling_max(2))
+        shape: (2, 2)
+        ┌─────────────┬────────────────┐
+        │ n           ┆ rolling_max    │
+        │ ---         ┆ ---            │
+        │ list[i64]   ┆ list[i64]      │
+        ╞═════════════╪════════════════╡
+        │ [1, 2, … 4] ┆ [null, 2, … 4] │
+        │ [10, 2, 1]  ┆ [null, 10, 2]  │
+        └─────────────┴────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(self._pyexpr.list_rolling_max(window_size, min_periods))
+
+
     @deprecate_renamed_parameter("periods", "n", version="0.19.11")
     def shift(self, n: int | IntoExprColumn = 1) -> Expr:
         """
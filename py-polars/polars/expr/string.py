@@ -19,6 +19,8 @@ from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.exceptions import ChronoFormatWarning
 
 if TYPE_CHECKING:
+    from typing import Mapping
+
     from polars import Expr
     from polars.type_aliases import (
         Ambiguous,
@@ -1681,6 +1683,86 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_extract_groups(pattern))
 
+    def extract_pattern(
+        self, pattern: str, *, custom_patterns: Mapping[str, str] | None = None
+    ) -> Expr:
+        r"""
+        Extract the fields of a grok pattern into a struct.
+
+        Parameters
+        ----------
+        pattern
+            A pattern of grok patterns of the form `%{SYNTAX}`, `%{SYNTAX:name}` or
+            `%{SYNTAX:name:type}` and regex syntax, compatible with the
+            `regex crate <https://docs.rs/regex/latest/regex/>`_. `SYNTAX` is the name
+            of a pattern of the built-in library, such as `IP`, `NUMBER`, `WORD`,
+            `TIMESTAMP_ISO8601`, `LOGLEVEL` or `COMBINEDAPACHELOG`, or of
+            `custom_patterns`. The match of a grok pattern with a `name` is extracted
+            into a field of that name, which is cast to `type`: `int`, `float` or
+            `string` (default). Named capture groups are extracted as string fields.
+        custom_patterns
+            Additional patterns by name, which may reference other patterns.
+
+        Notes
+        -----
+        The pattern is compiled once, and the strings are matched in parallel.
+        Strings that don't match have null fields.
+
+        See Also
+        --------
+        extract_groups
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "log": [
+        ...             "2024-01-01T12:00:00Z INFO 200 took 12.5ms",
+        ...             "2024-01-01T12:00:01Z WARN 503 took 250ms",
+        ...             "garbage",
+        ...         ]
+        ...     }
+        ... )
+        >>> pattern = (
+        ...     "%{TIMESTAMP_ISO8601:ts} %{LOGLEVEL:level} %{INT:status:int} "
+        ...     "took %{NUMBER:duration:float}ms"
+        ... )
+        >>> df.select(pl.col("log").str.extract_pattern(pattern)).unnest("log")
+        shape: (3, 4)
+        ┌──────────────────────┬───────┬────────┬──────────┐
+        │ ts                   ┆ level ┆ status ┆ duration │
+        │ ---                  ┆ ---   ┆ ---    ┆ ---      │
+        │ str                  ┆ str   ┆ i64    ┆ f64      │
+        ╞══════════════════════╪═══════╪════════╪══════════╡
+        │ 2024-01-01T12:00:00Z ┆ INFO  ┆ 200    ┆ 12.5     │
+        │ 2024-01-01T12:00:01Z ┆ WARN  ┆ 503    ┆ 250.0    │
+        │ null                 ┆ null  ┆ null   ┆ null     │
+        └──────────────────────┴───────┴────────┴──────────┘
+
+        Define custom patterns, and mix them with named capture groups.
+
+        >>> df = pl.DataFrame(
+        ...     {"log": ["user=alice action=login", "user=bob action=logout"]}
+        ... )
+        >>> df.select(
+        ...     pl.col("log").str.extract_pattern(
+        ...         r"user=%{NAME:user} action=(?<action>\w+)",
+        ...         custom_patterns={"NAME": "[a-z]+"},
+        ...     )
+        ... ).unnest("log")
+        shape: (2, 2)
+        ┌───────┬────────┐
+        │ user  ┆ action │
+        │ ---   ┆ ---    │
+        │ str   ┆ str    │
+        ╞═══════╪════════╡
+        │ alice ┆ login  │
+        │ bob   ┆ logout │
+        └───────┴────────┘
+        """
+        custom_patterns = list(custom_patterns.items()) if custom_patterns else []
+        return wrap_expr(self._pyexpr.str_extract_pattern(pattern, custom_patterns))
+
     def count_matches(self, pattern: str | Expr, *, literal: bool = False) -> Expr:
         r"""
         Count all successive non-overlapping regex matches.
@@ -2143,6 +2225,249 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    def mask_except_last(self, n: int, mask_char: str = "*") -> Expr:
+        """
+        Replace all but the last `n` characters of each string with `mask_char`.
+
+        Parameters
+        ----------
+        n
+            The number of trailing characters to keep. Strings with at most `n`
+            characters are returned as-is.
+        mask_char
+            The character to mask the other characters with.
+
+        See Also
+        --------
+        tokenize_format_preserving
+        pseudonymize
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"card": ["4111-1111-1111-1111", "378282246310005", "123", None]}
+        ... )
+        >>> df.with_columns(masked=pl.col("card").str.mask_except_last(4))
+        shape: (4, 2)
+        ┌─────────────────────┬─────────────────────┐
+        │ card                ┆ masked              │
+        │ ---                 ┆ ---                 │
+        │ str                 ┆ str                 │
+        ╞═════════════════════╪═════════════════════╡
+        │ 4111-1111-1111-1111 ┆ ***************1111 │
+        │ 378282246310005     ┆ ***********0005     │
+        │ 123                 ┆ 123                 │
+        │ null                ┆ null                │
+        └─────────────────────┴─────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_mask_except_last(n, mask_char))
+
+    def tokenize_format_preserving(self, key: str) -> Expr:
+        """
+        Replace each string with a token that has the same format.
+
+        Every ASCII digit is replaced with a digit and every ASCII letter with a letter
+        of the same case, while all other characters are kept. The replacements are
+        derived from `key` and the whole string, so equal strings get equal tokens and
+        the tokens can still be joined on.
+
+        Parameters
+        ----------
+        key
+            The secret that determines the tokens.
+
+        See Also
+        --------
+        mask_except_last
+        pseudonymize
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"card": ["4111-1111-1111-1111", "5500-0000-0000-0004", None]}
+        ... )
+        >>> df.select(
+        ...     same_format=pl.col("card")
+        ...     .str.tokenize_format_preserving("secret")
+        ...     .str.contains(r"^\d{4}-\d{4}-\d{4}-\d{4}$")
+        ... )
+        shape: (3, 1)
+        ┌─────────────┐
+        │ same_format │
+        │ ---         │
+        │ bool        │
+        ╞═════════════╡
+        │ true        │
+        │ true        │
+        │ null        │
+        └─────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_tokenize_format_preserving(key))
+
+    def pseudonymize(self, key: str | bytes) -> Expr:
+        """
+        Replace each string with the hexadecimal HMAC-SHA256 of the string.
+
+        Equal strings get equal pseudonyms, so the pseudonyms can still be joined on
+        and grouped by. Without the key, the pseudonyms can't be linked back to the
+        strings. Use a different key per output to prevent linking pseudonyms across
+        outputs.
+
+        Parameters
+        ----------
+        key
+            The secret key of the HMAC. A string is encoded as UTF-8.
+
+        Notes
+        -----
+        The key is not serialized, so a query that uses this can't be serialized.
+
+        See Also
+        --------
+        mask_except_last
+        tokenize_format_preserving
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"email": ["a@x.com", "b@x.com", "a@x.com"]})
+        >>> df.select(pl.col("email").str.pseudonymize("key").n_unique())
+        shape: (1, 1)
+        ┌───────┐
+        │ email │
+        │ ---   │
+        │ u32   │
+        ╞═══════╡
+        │ 2     │
+        └───────┘
+        """
+        if isinstance(key, str):
+            key = key.encode()
+        return wrap_expr(self._pyexpr.str_pseudonymize(key))
+
+    def url_decode(self) -> Expr:
+        """
+        Decode the percent-encoded characters of each string.
+
+        Malformed escapes are kept as-is. Strings that don't decode to valid UTF-8
+        become null.
+
+        See Also
+        --------
+        url_encode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"encoded": ["caf%C3%A9", "a%20b%2Bc", None]})
+        >>> df.with_columns(decoded=pl.col("encoded").str.url_decode())
+        shape: (3, 2)
+        ┌───────────┬─────────┐
+        │ encoded   ┆ decoded │
+        │ ---       ┆ ---     │
+        │ str       ┆ str     │
+        ╞═══════════╪═════════╡
+        │ caf%C3%A9 ┆ café    │
+        │ a%20b%2Bc ┆ a b+c   │
+        │ null      ┆ null    │
+        └───────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_decode())
+
+    def url_encode(self) -> Expr:
+        """
+        Percent-encode each string.
+
+        All characters are encoded except ASCII letters and digits and `-`, `_`, `.`
+        and `~`.
+
+        See Also
+        --------
+        url_decode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["café", "a b+c", None]})
+        >>> df.with_columns(encoded=pl.col("text").str.url_encode())
+        shape: (3, 2)
+        ┌───────┬───────────┐
+        │ text  ┆ encoded   │
+        │ ---   ┆ ---       │
+        │ str   ┆ str       │
+        ╞═══════╪═══════════╡
+        │ café  ┆ caf%C3%A9 │
+        │ a b+c ┆ a%20b%2Bc │
+        │ null  ┆ null      │
+        └───────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_encode())
+
+    def parse_url(self) -> Expr:
+        """
+        Split each string into the components of a URL.
+
+        Returns a struct with the fields `scheme`, `host`, `port`, `path`, `query` and
+        `fragment`, where missing components are null. The components are not decoded.
+        Raises an error if a port is not a number of at most 65535.
+
+        See Also
+        --------
+        parse_query_string
+        url_decode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://example.com:8080/a/b?q=1#top",
+        ...             "mailto:someone@example.com",
+        ...             "/relative/path?x=y",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("url").str.parse_url()).unnest("url")
+        shape: (3, 6)
+        ┌────────┬─────────────┬──────┬─────────────────────┬───────┬──────────┐
+        │ scheme ┆ host        ┆ port ┆ path                ┆ query ┆ fragment │
+        │ ---    ┆ ---         ┆ ---  ┆ ---                 ┆ ---   ┆ ---      │
+        │ str    ┆ str         ┆ u16  ┆ str                 ┆ str   ┆ str      │
+        ╞════════╪═════════════╪══════╪═════════════════════╪═══════╪══════════╡
+        │ https  ┆ example.com ┆ 8080 ┆ /a/b                ┆ q=1   ┆ top      │
+        │ mailto ┆ null        ┆ null ┆ someone@example.com ┆ null  ┆ null     │
+        │ null   ┆ null        ┆ null ┆ /relative/path      ┆ x=y   ┆ null     │
+        └────────┴─────────────┴──────┴─────────────────────┴───────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.str_parse_url())
+
+    def parse_query_string(self) -> Expr:
+        """
+        Parse each string as a query string into a list of key-value structs.
+
+        The keys and values are decoded, with `+` as a space. A leading `?` is
+        ignored, and a key without `=` has a null value.
+
+        See Also
+        --------
+        parse_url
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"query": ["a=1&b=hello+world", "c"]})
+        >>> df.select(pl.col("query").str.parse_query_string().explode()).unnest(
+        ...     "query"
+        ... )
+        shape: (3, 2)
+        ┌─────┬─────────────┐
+        │ key ┆ value       │
+        │ --- ┆ ---         │
+        │ str ┆ str         │
+        ╞═════╪═════════════╡
+        │ a   ┆ 1           │
+        │ b   ┆ hello world │
+        │ c   ┆ null        │
+        └─────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_parse_query_string())
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
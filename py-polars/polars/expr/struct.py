@@ -148,10 +148,34 @@ class ExprStructNameSpace:
         """
         return wrap_expr(self._pyexpr.struct_rename_fields(names))
 
-    def json_encode(self) -> Expr:
+    def json_encode(
+        self,
+        *,
+        date_format: str | None = None,
+        datetime_format: str | None = None,
+        skip_nulls: bool = False,
+        sort_keys: bool = False,
+    ) -> Expr:
         """
         Convert this struct to a string column with json values.
 
+        Parameters
+        ----------
+        date_format
+            A format string, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate. If no format specified, the default is `"%Y-%m-%d"`.
+        datetime_format
+            A format string, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate. If no format specified, datetimes are written like
+            `"2000-01-29 10:30:00"`, or in RFC 3339 if they have a time zone.
+        skip_nulls
+            Leave out the fields whose value is null instead of writing them as
+            `null`.
+        sort_keys
+            Write the fields sorted by name instead of in the order of the struct.
+
         Examples
         --------
         >>> pl.DataFrame(
@@ -167,4 +191,8 @@ class ExprStructNameSpace:
         │ {[9, 1, 3],null} ┆ {"a":[9,1,3],"b":null} │
         └──────────────────┴────────────────────────┘
         """
-        return wrap_expr(self._pyexpr.struct_json_encode())
+        return wrap_expr(
+            self._pyexpr.struct_json_encode(
+                date_format, datetime_format, skip_nulls, sort_keys
+            )
+        )
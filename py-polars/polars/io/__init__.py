@@ -8,7 +8,9 @@ from polars.io.delta import read_delta, scan_delta
 from polars.io.iceberg import scan_iceberg
 from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
 from polars.io.json import read_json
+from polars.io.lance import scan_lance
 from polars.io.ndjson import read_ndjson, scan_ndjson
+from polars.io.orc import read_orc, scan_orc
 from polars.io.parquet import read_parquet, read_parquet_schema, scan_parquet
 from polars.io.pyarrow_dataset import scan_pyarrow_dataset
 from polars.io.spreadsheet import read_excel, read_ods
@@ -28,13 +30,16 @@ __all__ = [
     "read_json",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_schema",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
     "scan_ipc",
+    "scan_lance",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
 ]
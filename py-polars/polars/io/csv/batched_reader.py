@@ -19,7 +19,12 @@ if TYPE_CHECKING:
     from pathlib import Path
 
     from polars import DataFrame
-    from polars.type_aliases import CsvEncoding, PolarsDataType, SchemaDict
+    from polars.type_aliases import (
+        CsvEncoding,
+        CsvParseErrorPolicy,
+        PolarsDataType,
+        SchemaDict,
+    )
 
 
 class BatchedCsvReader:
@@ -56,6 +61,7 @@ class BatchedCsvReader:
         raise_if_empty: bool = True,
         truncate_ragged_lines: bool = False,
         decimal_comma: bool = False,
+        parse_error_policy: dict[str, CsvParseErrorPolicy] | None = None,
     ):
         path = normalize_filepath(source)
 
@@ -104,6 +110,11 @@ class BatchedCsvReader:
             raise_if_empty=raise_if_empty,
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
+            parse_error_policy=(
+                list(parse_error_policy.items())
+                if parse_error_policy is not None
+                else None
+            ),
         )
         self.new_columns = new_columns
 
@@ -29,7 +29,12 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 if TYPE_CHECKING:
     from polars import DataFrame, LazyFrame
-    from polars.type_aliases import CsvEncoding, PolarsDataType, SchemaDict
+    from polars.type_aliases import (
+        CsvEncoding,
+        CsvParseErrorPolicy,
+        PolarsDataType,
+        SchemaDict,
+    )
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -71,6 +76,7 @@ def read_csv(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
+    parse_error_policy: dict[str, CsvParseErrorPolicy] | None = None,
 ) -> DataFrame:
     r"""
     Read a CSV file into a DataFrame.
@@ -191,6 +197,15 @@ def read_csv(
         Parse floats using a comma as the decimal separator instead of a period.
     glob
         Expand path given via globbing rules.
+    parse_error_policy
+        What to do with the values that cannot be parsed as the dtype of a column,
+        per column name. The dtypes of these columns must be given with `schema` or
+        `dtypes`.
+
+        - `"raise"`: Raise an error.
+        - `"null"`: Replace the values with null.
+        - `"sidecar"`: Replace the values with null and keep the raw values in an
+          extra `{column}_raw` String column.
 
     Returns
     -------
@@ -259,6 +274,7 @@ def read_csv(
         and n_threads is None
         and not low_memory
         and null_values is None
+        and parse_error_policy is None
     ):
         include_columns: Sequence[str] | None = None
         if columns:
@@ -446,6 +462,7 @@ def read_csv(
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
             glob=glob,
+            parse_error_policy=parse_error_policy,
         )
 
     if new_columns:
@@ -484,6 +501,7 @@ def _read_csv_impl(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
+    parse_error_policy: dict[str, CsvParseErrorPolicy] | None = None,
 ) -> DataFrame:
     path: str | None
     if isinstance(source, (str, Path)):
@@ -509,6 +527,9 @@ def _read_csv_impl(
             raise TypeError(msg)
 
     processed_null_values = _process_null_values(null_values)
+    parse_error_policy_list = (
+        list(parse_error_policy.items()) if parse_error_policy is not None else None
+    )
 
     if isinstance(columns, str):
         columns = [columns]
@@ -548,6 +569,7 @@ def _read_csv_impl(
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
             glob=glob,
+            parse_error_policy=parse_error_policy,
         )
         if columns is None:
             return scan.collect()
@@ -593,6 +615,7 @@ def _read_csv_impl(
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
         schema=schema,
+        parse_error_policy=parse_error_policy_list,
     )
     return wrap_df(pydf)
 
@@ -632,6 +655,7 @@ def read_csv_batched(
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
+    parse_error_policy: dict[str, CsvParseErrorPolicy] | None = None,
 ) -> BatchedCsvReader:
     r"""
     Read a CSV file in batches.
@@ -736,6 +760,14 @@ def read_csv_batched(
         Truncate lines that are longer than the schema.
     decimal_comma
         Parse floats using a comma as the decimal separator instead of a period.
+    parse_error_policy
+        What to do with the values that cannot be parsed as the dtype of a column,
+        per column name. The dtypes of these columns must be given with `dtypes`.
+
+        - `"raise"`: Raise an error.
+        - `"null"`: Replace the values with null.
+        - `"sidecar"`: Replace the values with null and keep the raw values in an
+          extra `{column}_raw` String column.
 
     Returns
     -------
@@ -898,6 +930,7 @@ def read_csv_batched(
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
+        parse_error_policy=parse_error_policy,
     )
 
 
@@ -936,6 +969,7 @@ def scan_csv(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
+    parse_error_policy: dict[str, CsvParseErrorPolicy] | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1032,6 +1066,15 @@ def scan_csv(
         Parse floats using a comma as the decimal separator instead of a period.
     glob
         Expand path given via globbing rules.
+    parse_error_policy
+        What to do with the values that cannot be parsed as the dtype of a column,
+        per column name. The dtypes of these columns must be given with `schema` or
+        `dtypes`.
+
+        - `"raise"`: Raise an error.
+        - `"null"`: Replace the values with null.
+        - `"sidecar"`: Replace the values with null and keep the raw values in an
+          extra `{column}_raw` String column.
 
     Returns
     -------
@@ -1152,6 +1195,7 @@ def scan_csv(
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
         glob=glob,
+        parse_error_policy=parse_error_policy,
     )
 
 
@@ -1184,6 +1228,7 @@ def _scan_csv_impl(
     truncate_ragged_lines: bool = True,
     decimal_comma: bool = False,
     glob: bool = True,
+    parse_error_policy: dict[str, CsvParseErrorPolicy] | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, PolarsDataType]] | None = None
     if dtypes is not None:
@@ -1191,6 +1236,9 @@ def _scan_csv_impl(
         for k, v in dtypes.items():
             dtype_list.append((k, py_type_to_dtype(v)))
     processed_null_values = _process_null_values(null_values)
+    parse_error_policy_list = (
+        list(parse_error_policy.items()) if parse_error_policy is not None else None
+    )
 
     if isinstance(source, list):
         sources = source
@@ -1226,5 +1274,6 @@ def _scan_csv_impl(
         decimal_comma=decimal_comma,
         schema=schema,
         glob=glob,
+        parse_error_policy=parse_error_policy_list,
     )
     return wrap_ldf(pylf)
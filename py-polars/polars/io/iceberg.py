@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import ast
+import contextlib
 from _ast import GtE, Lt, LtE
 from ast import (
     Attribute,
@@ -18,12 +19,17 @@ from ast import (
     UnaryOp,
 )
 from functools import partial, singledispatch
+from pathlib import Path
 from typing import TYPE_CHECKING, Any, Callable
 
 import polars._reexport as pl
 from polars._utils.convert import to_py_date, to_py_datetime
+from polars._utils.wrap import wrap_ldf
 from polars.dependencies import pyiceberg
 
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyLazyFrame
+
 if TYPE_CHECKING:
     from datetime import date, datetime
 
@@ -40,20 +46,30 @@ _temporal_conversions: dict[str, Callable[..., datetime | date]] = {
 
 
 def scan_iceberg(
-    source: str | Table,
+    source: str | Path | Table,
     *,
+    snapshot_id: int | None = None,
     storage_options: dict[str, Any] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from an Apache Iceberg table.
 
+    Tables on the local file system are read natively, without `pyiceberg`: the
+    manifests and data files that can't contain rows for which a filter holds are
+    skipped, and the delete files of the snapshot are applied. Tables on object
+    storage, tables given as a PyIceberg table and tables read with
+    `storage_options` are read with `pyiceberg`.
+
     Parameters
     ----------
     source
-        A PyIceberg table, or a direct path to the metadata.
+        A PyIceberg table, or a direct path to the metadata. A local path can also
+        be the root directory of the table.
 
         Note: For Local filesystem, absolute and relative paths are supported but
         for the supported object storages - GCS, Azure and S3 full URI must be provided.
+    snapshot_id
+        The snapshot of the table to read. The current snapshot is read by default.
     storage_options
         Extra options for the storage backends supported by `pyiceberg`.
         For cloud storages, this may include configurations for authentication etc.
@@ -127,24 +143,41 @@ def scan_iceberg(
     ...     table_path, storage_options=storage_options
     ... ).collect()  # doctest: +SKIP
     """
+    if isinstance(source, (str, Path)) and storage_options is None:
+        path = _local_path(str(source))
+        if path is not None:
+            pylf = PyLazyFrame.new_from_iceberg(path, snapshot_id, None, None)
+            return wrap_ldf(pylf)
+
     from pyiceberg.io.pyarrow import schema_to_pyarrow
     from pyiceberg.table import StaticTable
 
-    if isinstance(source, str):
+    if isinstance(source, (str, Path)):
         source = StaticTable.from_metadata(
-            metadata_location=source, properties=storage_options or {}
+            metadata_location=str(source), properties=storage_options or {}
         )
 
-    func = partial(_scan_pyarrow_dataset_impl, source)
+    func = partial(_scan_pyarrow_dataset_impl, source, snapshot_id=snapshot_id)
     arrow_schema = schema_to_pyarrow(source.schema())
     return pl.LazyFrame._scan_python_function(arrow_schema, func, pyarrow=True)
 
 
+def _local_path(source: str) -> str | None:
+    """The path of a table on the local file system, or `None` for other tables."""
+    for prefix in ("file://", "file:"):
+        if source.startswith(prefix):
+            return source[len(prefix) :]
+    if "://" in source:
+        return None
+    return source
+
+
 def _scan_pyarrow_dataset_impl(
     tbl: Table,
     with_columns: list[str] | None = None,
     predicate: str = "",
     n_rows: int | None = None,
+    snapshot_id: int | None = None,
     **kwargs: Any,
 ) -> DataFrame | Series:
     """
@@ -160,6 +193,8 @@ def _scan_pyarrow_dataset_impl(
         pyarrow expression that can be evaluated with eval
     n_rows:
         Materialize only n rows from the arrow dataset.
+    snapshot_id
+        The snapshot to read, the current snapshot by default.
     batch_size
         The maximum row count for scanned pyarrow record batches.
     kwargs:
@@ -171,7 +206,7 @@ def _scan_pyarrow_dataset_impl(
     """
     from polars import from_arrow
 
-    scan = tbl.scan(limit=n_rows)
+    scan = tbl.scan(limit=n_rows, snapshot_id=snapshot_id)
 
     if with_columns is not None:
         scan = scan.select(*with_columns)
@@ -311,6 +311,8 @@ def scan_ipc(
     row_index_offset: int = 0,
     storage_options: dict[str, Any] | None = None,
     memory_map: bool = True,
+    low_memory: bool = False,
+    max_decoders: int | None = None,
     retries: int = 0,
 ) -> LazyFrame:
     """
@@ -342,6 +344,12 @@ def scan_ipc(
         Try to memory map the file. This can greatly improve performance on repeated
         queries as the OS may cache pages.
         Only uncompressed IPC files can be memory mapped.
+    low_memory
+        Reduce memory pressure at the expense of performance by reading a single
+        file at a time.
+    max_decoders
+        The maximum number of files that are decoded at the same time, which bounds
+        the memory used for decoding. This takes precedence over `low_memory`.
     retries
         Number of retries if accessing a cloud instance fails.
 
@@ -372,6 +380,8 @@ def scan_ipc(
         rechunk,
         parse_row_index_args(row_index_name, row_index_offset),
         memory_map=memory_map,
+        low_memory=low_memory,
+        max_decoders=max_decoders,
         cloud_options=storage_options,
         retries=retries,
     )
@@ -0,0 +1,132 @@
+from __future__ import annotations
+
+from functools import partial
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+import polars._reexport as pl
+from polars.dependencies import lance
+from polars.dependencies import pyarrow as pa
+from polars.io.pyarrow_dataset.anonymous_scan import _parse_pyarrow_predicate
+
+if TYPE_CHECKING:
+    from lance import LanceDataset
+
+    from polars import DataFrame, LazyFrame
+
+__all__ = ["scan_lance"]
+
+
+def scan_lance(
+    source: str | Path | LanceDataset,
+    *,
+    version: int | str | None = None,
+    storage_options: dict[str, Any] | None = None,
+    allow_pyarrow_filter: bool = True,
+    parallel: bool = True,
+    batch_size: int | None = None,
+) -> LazyFrame:
+    """
+    Lazily read from a Lance dataset.
+
+    The dataset is read with the `lance` package, which must be installed. Only the
+    columns that the query needs are read, filters are pushed down to the scanner of
+    `lance`, and the fragments of the dataset are read concurrently. The record
+    batches are converted as they are read, rather than after the whole dataset is
+    materialized as an arrow table.
+
+    Parameters
+    ----------
+    source
+        Path or URI to the root of the Lance dataset, or an opened `LanceDataset`.
+    version
+        Version number or tag of the dataset to read. The latest version is read
+        if not provided.
+    storage_options
+        Extra options for the storage backends supported by `lance`.
+        For cloud storages, this may include configurations for authentication etc.
+    allow_pyarrow_filter
+        Allow predicates to be pushed down to `lance`. This can lead to different
+        results if comparisons are done with null values as pyarrow handles this
+        different than polars does.
+    parallel
+        Read ahead the fragments of the dataset concurrently. If False, the fragments
+        are read one after the other.
+    batch_size
+        The maximum row count of the record batches that are read from the dataset.
+
+    Returns
+    -------
+    LazyFrame
+
+    Examples
+    --------
+    >>> pl.scan_lance("/path/to/dataset.lance").select(
+    ...     "id", "vector"
+    ... ).collect()  # doctest: +SKIP
+
+    Read an earlier version of the dataset.
+
+    >>> pl.scan_lance("/path/to/dataset.lance", version=1).collect()  # doctest: +SKIP
+    """
+    if isinstance(source, (str, Path)):
+        ds = lance.dataset(
+            str(source), version=version, storage_options=storage_options
+        )
+    else:
+        ds = source
+
+    func = partial(_scan_lance_impl, ds, parallel=parallel, batch_size=batch_size)
+    return pl.LazyFrame._scan_python_function(
+        ds.schema, func, pyarrow=allow_pyarrow_filter
+    )
+
+
+def _scan_lance_impl(
+    ds: LanceDataset,
+    with_columns: list[str] | None,
+    predicate: str | None,
+    n_rows: int | None,
+    *,
+    parallel: bool,
+    batch_size: int | None,
+) -> DataFrame:
+    """
+    Take the projected columns and read the record batches of the dataset.
+
+    Parameters
+    ----------
+    ds
+        Lance dataset
+    with_columns
+        Columns that are projected
+    predicate
+        pyarrow expression that can be evaluated with eval
+    n_rows
+        Materialize only n rows from the dataset
+    parallel
+        Read ahead the fragments concurrently
+    batch_size
+        The maximum row count of the record batches
+    """
+    from polars import concat, from_arrow
+
+    _filter = _parse_pyarrow_predicate(predicate) if predicate else None
+
+    params: dict[str, Any] = {"columns": with_columns, "filter": _filter}
+    # lance stops reading as soon as it has the first rows, which may be in any
+    # fragment after filtering
+    if n_rows is not None:
+        params["limit"] = n_rows
+    if batch_size is not None:
+        params["batch_size"] = batch_size
+    if not parallel:
+        params["fragment_readahead"] = 1
+
+    frames = [from_arrow(batch, rechunk=False) for batch in ds.to_batches(**params)]
+    if not frames:
+        schema = ds.schema
+        if with_columns is not None:
+            schema = pa.schema([schema.field(name) for name in with_columns])
+        return from_arrow(schema.empty_table())  # type: ignore[return-value]
+    return concat(frames, rechunk=False)  # type: ignore[return-value]
@@ -0,0 +1,113 @@
+from __future__ import annotations
+
+import contextlib
+from pathlib import Path
+from typing import IO, TYPE_CHECKING
+
+from polars._utils.various import normalize_filepath
+from polars._utils.wrap import wrap_df, wrap_ldf
+from polars.io._utils import parse_columns_arg, parse_row_index_args
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyDataFrame, PyLazyFrame
+
+if TYPE_CHECKING:
+    from polars import DataFrame, LazyFrame
+
+
+def read_orc(
+    source: str | Path | IO[bytes] | bytes,
+    *,
+    columns: list[int] | list[str] | None = None,
+    n_rows: int | None = None,
+    parallel: bool = True,
+) -> DataFrame:
+    """
+    Read into a DataFrame from Apache ORC format.
+
+    Only the selected columns are decoded. Columns of types that can't be read, such as
+    lists, maps, structs and unions, only raise an error when they are selected.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by "file-like object" we refer to objects
+        that have a `read()` method, such as a file handler like the builtin `open`
+        function, or a `BytesIO` instance).
+    columns
+        Columns to select. Accepts a list of column indices (starting at zero) or a list
+        of column names.
+    n_rows
+        Stop reading from the Apache ORC file after reading `n_rows`.
+    parallel
+        Read the stripes of the file in parallel.
+
+    Returns
+    -------
+    DataFrame
+
+    See Also
+    --------
+    scan_orc
+
+    Examples
+    --------
+    >>> pl.read_orc("data.orc", columns=["a", "b"])  # doctest: +SKIP
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+    projection, column_names = parse_columns_arg(columns)
+
+    pydf = PyDataFrame.read_orc(source, column_names, projection, n_rows, parallel)
+    return wrap_df(pydf)
+
+
+def scan_orc(
+    source: str | Path,
+    *,
+    n_rows: int | None = None,
+    row_index_name: str | None = None,
+    row_index_offset: int = 0,
+    parallel: bool = True,
+) -> LazyFrame:
+    """
+    Lazily read from an Apache ORC file.
+
+    Only the columns that the query needs are decoded. The stripes of the file whose
+    statistics show that they have no rows for which a filter of the query holds are
+    skipped.
+
+    Parameters
+    ----------
+    source
+        Path to a file.
+    n_rows
+        Stop reading from the Apache ORC file after reading `n_rows`.
+    row_index_name
+        If not None, this will insert a row index column with the given name into the
+        DataFrame.
+    row_index_offset
+        Offset to start the row index column (only used if the name is set).
+    parallel
+        Read the stripes of the file in parallel.
+
+    Returns
+    -------
+    LazyFrame
+
+    See Also
+    --------
+    read_orc
+
+    Examples
+    --------
+    >>> pl.scan_orc("data.orc").filter(pl.col("a") > 10).collect()  # doctest: +SKIP
+    """
+    source = normalize_filepath(source)
+    pylf = PyLazyFrame.new_from_orc(
+        source,
+        n_rows,
+        parse_row_index_args(row_index_name, row_index_offset),
+        parallel,
+    )
+    return wrap_ldf(pylf)
@@ -298,6 +298,7 @@ def scan_parquet(
     hive_schema: SchemaDict | None = None,
     rechunk: bool = False,
     low_memory: bool = False,
+    max_decoders: int | None = None,
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
@@ -342,7 +343,12 @@ def scan_parquet(
         In case of reading multiple files via a glob pattern rechunk the final DataFrame
         into contiguous memory chunks.
     low_memory
-        Reduce memory pressure at the expense of performance.
+        Reduce memory pressure at the expense of performance by decoding a single
+        row group at a time.
+    max_decoders
+        The maximum number of row groups that are decoded at the same time, which
+        bounds the memory used for decoding. This takes precedence over
+        `low_memory`.
     cache
         Cache the result after reading.
     storage_options
@@ -401,6 +407,7 @@ def scan_parquet(
         row_index_offset=row_index_offset,
         storage_options=storage_options,
         low_memory=low_memory,
+        max_decoders=max_decoders,
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
@@ -420,6 +427,7 @@ def _scan_parquet_impl(
     row_index_offset: int = 0,
     storage_options: dict[str, object] | None = None,
     low_memory: bool = False,
+    max_decoders: int | None = None,
     use_statistics: bool = True,
     hive_partitioning: bool = True,
     glob: bool = True,
@@ -447,6 +455,7 @@ def _scan_parquet_impl(
         rechunk,
         parse_row_index_args(row_index_name, row_index_offset),
         low_memory,
+        max_decoders=max_decoders,
         cloud_options=storage_options,
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
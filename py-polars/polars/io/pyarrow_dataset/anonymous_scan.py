@@ -68,30 +68,7 @@ def _scan_pyarrow_dataset_impl(
     """
     from polars import from_arrow
 
-    _filter = None
-
-    if predicate:
-        from polars._utils.convert import (
-            to_py_date,
-            to_py_datetime,
-            to_py_time,
-            to_py_timedelta,
-        )
-        from polars.datatypes import Date, Datetime, Duration
-
-        _filter = eval(
-            predicate,
-            {
-                "pa": pa,
-                "Date": Date,
-                "Datetime": Datetime,
-                "Duration": Duration,
-                "to_py_date": to_py_date,
-                "to_py_datetime": to_py_datetime,
-                "to_py_time": to_py_time,
-                "to_py_timedelta": to_py_timedelta,
-            },
-        )
+    _filter = _parse_pyarrow_predicate(predicate) if predicate else None
 
     common_params = {"columns": with_columns, "filter": _filter}
     if batch_size is not None:
@@ -101,3 +78,35 @@ def _scan_pyarrow_dataset_impl(
         return from_arrow(ds.head(n_rows, **common_params))  # type: ignore[return-value]
 
     return from_arrow(ds.to_table(**common_params))  # type: ignore[return-value]
+
+
+def _parse_pyarrow_predicate(predicate: str) -> pa.compute.Expression:
+    """
+    Evaluate a predicate that was converted to a pyarrow expression string.
+
+    Parameters
+    ----------
+    predicate
+        pyarrow expression that can be evaluated with eval
+    """
+    from polars._utils.convert import (
+        to_py_date,
+        to_py_datetime,
+        to_py_time,
+        to_py_timedelta,
+    )
+    from polars.datatypes import Date, Datetime, Duration
+
+    return eval(
+        predicate,
+        {
+            "pa": pa,
+            "Date": Date,
+            "Datetime": Datetime,
+            "Duration": Duration,
+            "to_py_date": to_py_date,
+            "to_py_datetime": to_py_datetime,
+            "to_py_time": to_py_time,
+            "to_py_timedelta": to_py_timedelta,
+        },
+    )
@@ -99,15 +99,20 @@ if TYPE_CHECKING:
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
+        CommitStrategy,
         CsvQuoteStyle,
+        ExistingDataBehavior,
         FillNullStrategy,
         FrameInitTypes,
         IntoExpr,
         IntoExprColumn,
+        IpcCompression,
+        IpcDictionaryMode,
         JoinStrategy,
         JoinValidation,
         Label,
         Orientation,
+        ParquetColumnOptions,
         PolarsDataType,
         RollingInterpolationMethod,
         SchemaDefinition,
@@ -1644,6 +1649,50 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return df, timings
 
+    @unstable()
+    def with_spill_options(
+        self,
+        *,
+        compression: IpcCompression = "uncompressed",
+        compression_level: int | None = None,
+        decompression_threads: int | None = None,
+    ) -> Self:
+        """
+        Set how the `streaming` engine spills data to disk when it runs out of memory.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Compressing spilled files saves disk space and IO at the cost of CPU time.
+
+        Parameters
+        ----------
+        compression : {'uncompressed', 'lz4', 'zstd'}
+            Compression of the spilled files. Defaults to "uncompressed".
+            Choose "zstd" for good compression performance.
+            Choose "lz4" for fast compression/decompression.
+        compression_level
+            The level of "zstd" compression, from 1 to 22. Higher levels mean smaller
+            files, but slower writes. Defaults to 3. Other compressions have no levels.
+        decompression_threads
+            The number of spilled files that are read and decompressed at a time.
+            Fewer threads limit the memory that is used to read them. Defaults to the
+            size of the thread pool.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [3, 1, 2]})
+        >>> lf.with_spill_options(compression="lz4").sort("a").collect(
+        ...     streaming=True
+        ... )  # doctest: +SKIP
+        """
+        return self._from_pyldf(
+            self._ldf.with_spill_options(
+                compression, compression_level, decompression_threads
+            )
+        )
+
     @overload
     def collect(
         self,
@@ -1988,7 +2037,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool = True,
         row_group_size: int | None = None,
         data_pagesize_limit: int | None = None,
+        bloom_filters: bool = False,
         maintain_order: bool = True,
+        commit_strategy: CommitStrategy = "direct",
+        column_options: Mapping[str, ParquetColumnOptions] | None = None,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2031,9 +2083,35 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_pagesize_limit
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        bloom_filters
+            Write a bloom filter of every column chunk of the integer, temporal, string,
+            binary and categorical columns. Scans use these to skip the row groups that
+            can't contain the values of equality and `is_in` predicates.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        commit_strategy : {'direct', 'atomic'}
+            How the file is made visible at `path`.
+
+            - direct: The file is written at `path` directly. If the query fails,
+              a partially written file is left behind. This is the default.
+            - atomic: The file is written to a temporary file in the same directory,
+              which is renamed to `path` once it is complete. Readers never see a
+              partially written file, and an existing file at `path` is only
+              replaced if the query succeeds.
+        column_options
+            Options of single columns, by name, which override the options of the
+            file. Every column can have the keys:
+
+            - dictionary: Whether to dictionary encode the column. By default, all
+              columns but the floating point columns are dictionary encoded, unless
+              an `encoding` is given. Categorical columns are always dictionary
+              encoded.
+            - encoding: The encoding of the values, one of "plain",
+              "delta_binary_packed" (integers), "delta_length_byte_array" (strings
+              and binary) and "byte_stream_split" (floats). The leaves of nested
+              columns can only be plain encoded.
+            - compression, compression_level: The compression of the column.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2072,7 +2150,154 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             statistics=statistics,
             row_group_size=row_group_size,
             data_pagesize_limit=data_pagesize_limit,
+            bloom_filters=bloom_filters,
+            maintain_order=maintain_order,
+            commit_strategy=commit_strategy,
+            column_options=(
+                dict(column_options) if column_options is not None else None
+            ),
+        )
+
+    @unstable()
+    def sink_parquet_partitioned(
+        self,
+        path: str | Path,
+        partition_by: str | Sequence[str],
+        *,
+        max_rows_per_file: int | None = None,
+        max_open_files: int = 512,
+        existing_data_behavior: ExistingDataBehavior = "error",
+        write_metadata_file: bool = False,
+        write_statistics_file: bool = False,
+        compression: str = "zstd",
+        compression_level: int | None = None,
+        statistics: bool = True,
+        row_group_size: int | None = None,
+        data_pagesize_limit: int | None = None,
+        maintain_order: bool = True,
+        commit_strategy: CommitStrategy = "direct",
+        column_options: Mapping[str, ParquetColumnOptions] | None = None,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        slice_pushdown: bool = True,
+        no_optimization: bool = False,
+    ) -> None:
+        """
+        Evaluate the query in streaming mode and write to a partitioned Parquet dataset.
+
+        .. warning::
+            Streaming mode is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The files are written to one directory per partition under `path`, with one
+        directory level per partition column, e.g. `path/year=2024/month=1/`. The
+        partition columns aren't written to the files, as they can be read from the
+        paths. Only local paths are supported.
+
+        Parameters
+        ----------
+        path
+            Root directory of the dataset.
+        partition_by
+            Columns to partition by.
+        max_rows_per_file
+            Maximum number of rows of a file. If None (default), every partition is
+            written to a single file.
+        max_open_files
+            Maximum number of files that are open at once. Once it is reached, the
+            file of the partition that was written to least recently is finished, and
+            the next rows of that partition are written to a new file.
+        existing_data_behavior
+            What to do with the files that exist in `path`.
+
+            - error: Raise an error if the directory isn't empty. This is the default.
+            - overwrite_or_ignore: Overwrite the files with the names that are written
+              and keep the other files.
+            - delete_matching: Replace the directories of the partitions that are
+              written, and keep the other partitions.
+            - append: Keep the existing files and add the new files next to them.
+        write_metadata_file
+            Write a `_metadata` file with the metadata of all files to `path`. This
+            needs `existing_data_behavior="error"`.
+        write_statistics_file
+            Write a `_statistics` file with the statistics of the row groups of all
+            files to `path`. Scans of the dataset use it to skip the files that can't
+            have rows for which their filters hold, without reading their footers. The
+            statistics of the existing files that aren't replaced are kept.
+        compression : {'lz4', 'uncompressed', 'snappy', 'gzip', 'lzo', 'brotli', 'zstd'}
+            Compression of the files, see :meth:`sink_parquet`.
+        compression_level
+            The level of compression to use, see :meth:`sink_parquet`.
+        statistics
+            Write statistics to the parquet headers. This is the default behavior.
+        row_group_size
+            Size of the row groups in number of rows. Defaults to 128 * 1024 rows.
+            The rows of a partition are buffered until they fill a row group.
+        data_pagesize_limit
+            Size limit of individual data pages.
+            If not set defaults to 1024 * 1024 bytes
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will  be slightly faster.
+        commit_strategy : {'direct', 'atomic'}
+            How the files are made visible in `path`.
+
+            - direct: The files are written in `path` directly. This is the default.
+            - atomic: The files are written to a staging directory next to `path`,
+              and are only moved to `path` once all of them are written.
+        column_options
+            Options of single columns, by name, see :meth:`sink_parquet`.
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        slice_pushdown
+            Slice pushdown optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_parquet_partitioned(
+        ...     "dataset", ["year"], write_statistics_file=True
+        ... )  # doctest: +SKIP
+        """
+        lf = self._set_sink_optimizations(
+            type_coercion=type_coercion,
+            predicate_pushdown=predicate_pushdown,
+            projection_pushdown=projection_pushdown,
+            simplify_expression=simplify_expression,
+            slice_pushdown=slice_pushdown,
+            no_optimization=no_optimization,
+        )
+        if isinstance(partition_by, str):
+            partition_by = [partition_by]
+
+        return lf.sink_parquet_partitioned(
+            path=normalize_filepath(path),
+            partition_by=list(partition_by),
+            max_rows_per_file=max_rows_per_file,
+            max_open_files=max_open_files,
+            existing_data_behavior=existing_data_behavior,
+            write_metadata_file=write_metadata_file,
+            write_statistics_file=write_statistics_file,
+            compression=compression,
+            compression_level=compression_level,
+            statistics=statistics,
+            row_group_size=row_group_size,
+            data_pagesize_limit=data_pagesize_limit,
             maintain_order=maintain_order,
+            commit_strategy=commit_strategy,
+            column_options=(
+                dict(column_options) if column_options is not None else None
+            ),
         )
 
     @unstable()
@@ -2081,7 +2306,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path,
         *,
         compression: str | None = "zstd",
+        compression_level: int | None = None,
         maintain_order: bool = True,
+        commit_strategy: CommitStrategy = "direct",
+        stream: bool = False,
+        dictionary_mode: IpcDictionaryMode = "delta",
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2105,9 +2334,34 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression : {'lz4', 'zstd'}
             Choose "zstd" for good compression performance.
             Choose "lz4" for fast compression/decompression.
+        compression_level
+            The level of "zstd" compression, from 1 to 22. Higher levels mean smaller
+            files, but slower writes. Defaults to 3. Other compressions have no levels.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        commit_strategy : {'direct', 'atomic'}
+            How the file is made visible at `path`.
+
+            - direct: The file is written at `path` directly. If the query fails,
+              a partially written file is left behind. This is the default.
+            - atomic: The file is written to a temporary file in the same directory,
+              which is renamed to `path` once it is complete. Readers never see a
+              partially written file, and an existing file at `path` is only
+              replaced if the query succeeds.
+        stream
+            Write the Arrow IPC stream format instead of the file format.
+        dictionary_mode : {'delta', 'unify'}
+            How the categories of Categorical and Enum columns are written to a
+            stream, as every batch can add categories.
+
+            - delta: The categories of the first batch are written as a dictionary,
+              and the categories that later batches add as delta dictionaries.
+              This is the default.
+            - unify: The batches are held back until the stream is finished, and
+              a single dictionary with the categories of all batches is written
+              before them, for readers that don't support delta dictionaries.
+              This buffers the stream in memory.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2142,7 +2396,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         return lf.sink_ipc(
             path=path,
             compression=compression,
+            compression_level=compression_level,
             maintain_order=maintain_order,
+            commit_strategy=commit_strategy,
+            stream=stream,
+            dictionary_mode=dictionary_mode,
         )
 
     @deprecate_renamed_parameter("quote", "quote_char", version="0.19.8")
@@ -2165,6 +2423,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
         maintain_order: bool = True,
+        commit_strategy: CommitStrategy = "direct",
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2236,6 +2495,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        commit_strategy : {'direct', 'atomic'}
+            How the file is made visible at `path`.
+
+            - direct: The file is written at `path` directly. If the query fails,
+              a partially written file is left behind. This is the default.
+            - atomic: The file is written to a temporary file in the same directory,
+              which is renamed to `path` once it is complete. Readers never see a
+              partially written file, and an existing file at `path` is only
+              replaced if the query succeeds.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2287,6 +2555,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             null_value=null_value,
             quote_style=quote_style,
             maintain_order=maintain_order,
+            commit_strategy=commit_strategy,
         )
 
     @unstable()
@@ -2295,6 +2564,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path,
         *,
         maintain_order: bool = True,
+        commit_strategy: CommitStrategy = "direct",
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2318,6 +2588,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
+        commit_strategy : {'direct', 'atomic'}
+            How the file is made visible at `path`.
+
+            - direct: The file is written at `path` directly. If the query fails,
+              a partially written file is left behind. This is the default.
+            - atomic: The file is written to a temporary file in the same directory,
+              which is renamed to `path` once it is complete. Readers never see a
+              partially written file, and an existing file at `path` is only
+              replaced if the query succeeds.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2349,7 +2628,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             no_optimization=no_optimization,
         )
 
-        return lf.sink_json(path=path, maintain_order=maintain_order)
+        return lf.sink_json(
+            path=path, maintain_order=maintain_order, commit_strategy=commit_strategy
+        )
 
     def _set_sink_optimizations(
         self,
@@ -3617,6 +3898,138 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return LazyGroupBy(lgb)
 
+    def upsample(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        group_by: str | Sequence[str] | None = None,
+    ) -> Self:
+        """
+        Upsample a LazyFrame at a regular frequency.
+
+        This is the lazy counterpart of :meth:`DataFrame.upsample`. The frame is
+        sorted by `time_column` first, unless it is known to be sorted, and the order
+        of the groups is maintained.
+
+        Parameters
+        ----------
+        time_column
+            Time column will be used to determine a date_range.
+        every
+            Interval will start 'every' duration.
+        group_by
+            First group by these columns and then upsample for every group.
+
+        Returns
+        -------
+        LazyFrame
+            The time column becomes the first column. The inserted rows are null in
+            all other columns, including the `group_by` columns; see
+            :meth:`complete` to keep the keys of the groups.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "date": [date(2024, 1, 1), date(2024, 1, 3)],
+        ...         "values": [1, 3],
+        ...     }
+        ... )
+        >>> lf.upsample("date", every="1d").collect()
+        shape: (3, 2)
+        ┌────────────┬────────┐
+        │ date       ┆ values │
+        │ ---        ┆ ---    │
+        │ date       ┆ i64    │
+        ╞════════════╪════════╡
+        │ 2024-01-01 ┆ 1      │
+        │ 2024-01-02 ┆ null   │
+        │ 2024-01-03 ┆ 3      │
+        └────────────┴────────┘
+        """
+        if group_by is None:
+            group_by = []
+        if isinstance(group_by, str):
+            group_by = [group_by]
+        every = parse_as_duration_string(every)
+        return self._from_pyldf(
+            self._ldf.upsample(group_by, time_column, every, "0ns")
+        )
+
+    def complete(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        group_by: str | Sequence[str] | None = None,
+        fill_strategy: FillNullStrategy | None = None,
+        limit: int | None = None,
+    ) -> Self:
+        """
+        Insert the missing rows of a time series.
+
+        For every group of the `group_by` columns, a row is added for every `every`
+        interval between the first and the last value of `time_column` that is
+        missing. The inserted rows get the keys of their group.
+
+        Parameters
+        ----------
+        time_column
+            The time column in which the gaps are filled.
+        every
+            The interval between the rows of a complete time series.
+        group_by
+            The columns of the groups that are completed on their own.
+        fill_strategy : {'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            The strategy to fill the other columns of the inserted rows with, within
+            their group. They are null if it isn't given. The null values of the
+            existing rows are not filled.
+        limit
+            The number of consecutive null values to fill when using the 'forward' or
+            'backward' strategy.
+
+        Returns
+        -------
+        LazyFrame
+            The groups keep the order in which they first appear, and the rows of
+            every group are sorted by `time_column`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "date": [date(2024, 1, 1), date(2024, 1, 3), date(2024, 1, 2)],
+        ...         "id": ["a", "a", "b"],
+        ...         "values": [1, 3, 2],
+        ...     }
+        ... )
+        >>> lf.complete(
+        ...     "date", every="1d", group_by="id", fill_strategy="forward"
+        ... ).collect()
+        shape: (4, 3)
+        ┌────────────┬─────┬────────┐
+        │ date       ┆ id  ┆ values │
+        │ ---        ┆ --- ┆ ---    │
+        │ date       ┆ str ┆ i64    │
+        ╞════════════╪═════╪════════╡
+        │ 2024-01-01 ┆ a   ┆ 1      │
+        │ 2024-01-02 ┆ a   ┆ 1      │
+        │ 2024-01-03 ┆ a   ┆ 3      │
+        │ 2024-01-02 ┆ b   ┆ 2      │
+        └────────────┴─────┴────────┘
+        """
+        if group_by is None:
+            group_by = []
+        if isinstance(group_by, str):
+            group_by = [group_by]
+        every = parse_as_duration_string(every)
+        return self._from_pyldf(
+            self._ldf.complete(time_column, every, group_by, fill_strategy, limit)
+        )
+
     def join_asof(
         self,
         other: LazyFrame,
@@ -3632,6 +4045,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         tolerance: str | int | float | timedelta | None = None,
         allow_parallel: bool = True,
         force_parallel: bool = False,
+        allow_exact_matches: bool = True,
+        right_index_name: str | None = None,
+        strategy_column: str | None = None,
     ) -> Self:
         """
         Perform an asof join.
@@ -3708,6 +4124,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         force_parallel
             Force the physical plan to evaluate the computation of both DataFrames up to
             the join in parallel.
+        allow_exact_matches
+            Whether rows with equal keys may be matched. If False, a "backward" search
+            only matches keys that are strictly less than the left's key, a "forward"
+            search only keys that are strictly greater, and a "nearest" search only
+            keys that differ from the left's key.
+        right_index_name
+            Add a column with this name that holds the row index of the matched row
+            of the right LazyFrame, or null for the rows without a match.
+        strategy_column
+            A String column of the left LazyFrame with the strategy of every row,
+            one of "backward", "forward" or "nearest", to search in a different
+            direction per key. The rows where it is null use `strategy`.
 
         Examples
         --------
@@ -3798,6 +4226,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 strategy,
                 tolerance_num,
                 tolerance_str,
+                allow_exact_matches,
+                right_index_name,
+                strategy_column,
             )
         )
 
@@ -3814,6 +4245,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         join_nulls: bool = False,
         allow_parallel: bool = True,
         force_parallel: bool = False,
+        drop_key_expressions: bool = False,
     ) -> Self:
         """
         Add a join operation to the Logical Plan.
@@ -3875,6 +4307,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         force_parallel
             Force the physical plan to evaluate the computation of both DataFrames up to
             the join in parallel.
+        drop_key_expressions
+            Only use the results of the expressions in `on`, `left_on` and `right_on`
+            that aren't columns as join keys, instead of adding them to the output in
+            place of the columns they are named after. The coalesced keys of an
+            `outer_coalesce` join are kept, with the suffix if their name is taken.
 
         See Also
         --------
@@ -3964,6 +4401,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     how,
                     suffix,
                     validate,
+                    None,
+                    drop_key_expressions,
                 )
             )
 
@@ -3994,6 +4433,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 suffix,
                 validate,
                 coalesce,
+                drop_key_expressions,
             )
         )
 
@@ -4189,6 +4629,65 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return self._from_pyldf(self._ldf.with_columns_seq(pyexprs))
 
+    def with_columns_chained(
+        self,
+        *exprs: IntoExpr | Iterable[IntoExpr],
+        **named_exprs: IntoExpr,
+    ) -> Self:
+        """
+        Add columns to this LazyFrame, where an expression may refer to earlier ones.
+
+        Added columns will replace existing columns with the same name.
+
+        The expressions are added in sequential stages, like a chain of
+        `with_columns` calls. An expression starts a new stage if it refers to, or
+        replaces, a column of the current stage, so a chain of derived columns can
+        be added in a single call.
+
+        Parameters
+        ----------
+        *exprs
+            Column(s) to add, specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_exprs
+            Additional columns to add, specified as keyword arguments.
+            The columns will be renamed to the keyword used.
+
+        Returns
+        -------
+        LazyFrame
+            A new LazyFrame with the columns added.
+
+        See Also
+        --------
+        with_columns
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": [10, 20, 30]})
+        >>> lf.with_columns_chained(
+        ...     c=pl.col("a") * 2,
+        ...     d=pl.col("c") + pl.col("b"),
+        ... ).collect()
+        shape: (3, 4)
+        ┌─────┬─────┬─────┬─────┐
+        │ a   ┆ b   ┆ c   ┆ d   │
+        │ --- ┆ --- ┆ --- ┆ --- │
+        │ i64 ┆ i64 ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╪═════╡
+        │ 1   ┆ 10  ┆ 2   ┆ 12  │
+        │ 2   ┆ 20  ┆ 4   ┆ 24  │
+        │ 3   ┆ 30  ┆ 6   ┆ 36  │
+        └─────┴─────┴─────┴─────┘
+        """
+        structify = bool(int(os.environ.get("POLARS_AUTO_STRUCTIFY", 0)))
+
+        pyexprs = parse_as_list_of_expressions(
+            *exprs, **named_exprs, __structify=structify
+        )
+        return self._from_pyldf(self._ldf.with_columns_chained(pyexprs))
+
     def with_context(self, other: Self | list[Self]) -> Self:
         """
         Add an external context to the computation graph.
@@ -5381,6 +5880,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         keep: UniqueKeepStrategy = "any",
         maintain_order: bool = False,
+        by: str | None = None,
     ) -> Self:
         """
         Drop duplicate rows from this DataFrame.
@@ -5390,7 +5890,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         subset
             Column name(s) or selector(s), to consider when identifying
             duplicate rows. If set to `None` (default), use all columns.
-        keep : {'first', 'last', 'any', 'none'}
+        keep : {'first', 'last', 'any', 'none', 'max_by', 'min_by'}
             Which of the duplicate rows to keep.
 
             * 'any': Does not give any guarantee of which row is kept.
@@ -5398,11 +5898,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * 'none': Don't keep duplicate rows.
             * 'first': Keep first unique row.
             * 'last': Keep last unique row.
+            * 'max_by': Keep the row with the largest value in the `by` column.
+            * 'min_by': Keep the row with the smallest value in the `by` column.
         maintain_order
             Keep the same order as the original DataFrame. This is more expensive to
-            compute.
-            Settings this to `True` blocks the possibility
-            to run on the streaming engine.
+            compute. The streaming engine then finds the unique rows by sorting them.
+        by
+            The column to compare when `keep` is 'max_by' or 'min_by'. Null values
+            are only kept if a group has no other value, and ties keep the first row.
 
         Returns
         -------
@@ -5454,10 +5957,32 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 3   ┆ a   ┆ b   │
         │ 1   ┆ a   ┆ b   │
         └─────┴─────┴─────┘
+
+        Keep the latest version of every record.
+
+        >>> records = pl.LazyFrame(
+        ...     {
+        ...         "id": [1, 1, 2, 2, 1],
+        ...         "updated": [3, 5, 1, 4, 2],
+        ...         "value": ["a", "b", "c", "d", "e"],
+        ...     }
+        ... )
+        >>> records.unique(
+        ...     "id", keep="max_by", by="updated", maintain_order=True
+        ... ).collect()
+        shape: (2, 3)
+        ┌─────┬─────────┬───────┐
+        │ id  ┆ updated ┆ value │
+        │ --- ┆ ---     ┆ ---   │
+        │ i64 ┆ i64     ┆ str   │
+        ╞═════╪═════════╪═══════╡
+        │ 1   ┆ 5       ┆ b     │
+        │ 2   ┆ 4       ┆ d     │
+        └─────┴─────────┴───────┘
         """
         if subset is not None:
             subset = _expand_selectors(self, subset)
-        return self._from_pyldf(self._ldf.unique(maintain_order, subset, keep))
+        return self._from_pyldf(self._ldf.unique(maintain_order, subset, keep, by))
 
     def drop_nulls(
         self,
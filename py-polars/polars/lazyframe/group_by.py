@@ -149,6 +149,8 @@ class LazyGroupBy:
         self,
         function: Callable[[DataFrame], DataFrame],
         schema: SchemaDict | None,
+        *,
+        validate_output_schema: bool = False,
     ) -> LazyFrame:
         """
         Apply a custom/user-defined function (UDF) over the groups as a new DataFrame.
@@ -160,7 +162,8 @@ class LazyGroupBy:
         Using this is considered an anti-pattern as it will be very slow because:
 
         - it forces the engine to materialize the whole `DataFrames` for the groups.
-        - it is not parallelized
+        - the python function holds the GIL, so the groups are not processed in
+          parallel
         - it blocks optimizations as the passed python function is opaque to the
           optimizer
 
@@ -168,6 +171,9 @@ class LazyGroupBy:
 
         `pl.struct([my_columns]).apply(lambda struct_series: ..)`
 
+        The order of the groups in the output is only defined if `maintain_order`
+        was set in `group_by`.
+
         Parameters
         ----------
         function
@@ -176,6 +182,9 @@ class LazyGroupBy:
             Schema of the output function. This has to be known statically. If the
             given schema is incorrect, this is a bug in the caller's query and may
             lead to errors. If set to None, polars assumes the schema is unchanged.
+        validate_output_schema
+            Check the output of every group against `schema`, and raise an error if
+            it differs.
 
         Examples
         --------
@@ -212,7 +221,7 @@ class LazyGroupBy:
         ...     pl.int_range(pl.len()).shuffle().over("color") < 2
         ... ).collect()  # doctest: +IGNORE_RESULT
         """
-        return wrap_ldf(self.lgb.map_groups(function, schema))
+        return wrap_ldf(self.lgb.map_groups(function, schema, validate_output_schema))
 
     def head(self, n: int = 5) -> LazyFrame:
         """
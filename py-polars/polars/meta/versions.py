@@ -70,6 +70,7 @@ def _get_dependency_info() -> dict[str, str]:
         "fsspec",
         "gevent",
         "hvplot",
+        "lance",
         "matplotlib",
         "nest_asyncio",
         "numpy",
@@ -621,3 +621,164 @@ class ArrayNameSpace:
             [6, null, null]
         ]
         """
+
+    def dot(self, other: IntoExpr) -> Series:
+        """
+        Compute the dot product of every sub-array with the sub-array of `other`.
+
+        The result is a `Float64` column.
+
+        Parameters
+        ----------
+        other
+            Array expression or Series of the same width. If it has a single value,
+            that sub-array is used for every row.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Int64, 2))
+        >>> ones = pl.Series([[1, 1]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.dot(ones)
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            3.0
+            7.0
+        ]
+        """
+
+    def matmul(self, matrix: IntoExpr) -> Series:
+        """
+        Multiply a matrix with every sub-array as a column vector.
+
+        The result is an `Array` column of `Float64` and width `m`.
+
+        Parameters
+        ----------
+        matrix
+            Nested array expression or Series of shape `(m, n)`, where `n` is the width
+            of the sub-arrays. If it has a single value, that matrix is used for every
+            row.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Int64, 2))
+        >>> swap = pl.Series(
+        ...     [[[0, 1], [1, 0]]], dtype=pl.Array(pl.Array(pl.Int64, 2), 2)
+        ... )
+        >>> s.arr.matmul(swap)
+        shape: (2,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [2.0, 1.0]
+            [4.0, 3.0]
+        ]
+        """
+
+    def sum_axis(self, axis: int) -> Series:
+        """
+        Compute the sum along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.sum_axis(0)
+        shape: (2,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [4.0, 6.0]
+            [12.0, 14.0]
+        ]
+        """
+
+    def mean_axis(self, axis: int) -> Series:
+        """
+        Compute the mean along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.mean_axis(0)
+        shape: (2,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [2.0, 3.0]
+            [6.0, 7.0]
+        ]
+        """
+
+    def min_axis(self, axis: int) -> Series:
+        """
+        Compute the minimum along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.min_axis(0)
+        shape: (2,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [1.0, 2.0]
+            [5.0, 6.0]
+        ]
+        """
+
+    def max_axis(self, axis: int) -> Series:
+        """
+        Compute the maximum along an axis of the matrix in every nested sub-array.
+
+        Null values are ignored. The result is an `Array` column of `Float64`.
+
+        Parameters
+        ----------
+        axis
+            Axis `0` reduces every column of the matrices, axis `1` reduces every row.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.max_axis(0)
+        shape: (2,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [3.0, 4.0]
+            [7.0, 8.0]
+        ]
+        """
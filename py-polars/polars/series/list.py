@@ -672,6 +672,124 @@ class ListNameSpace:
         ]
         """
 
+    def rolling_sum(
+        self, window_size: int, min_periods: int | None = None
+    ) -> Series:
+        """
+        Apply a rolling sum within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+        Like :meth:`sum`, sublists of 8 and 16 bit integers are summed as `Int64`.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [10, 2, 1]])
+        >>> s.list.rolling_sum(2)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 3, … 7]
+            [null, 12, 3]
+        ]
+        """
+
+    def rolling_mean(
+        self, window_size: int, min_periods: int | None = None
+    ) -> Series:
+        """
+        Apply a rolling mean within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [10, 2, 1]])
+        >>> s.list.rolling_mean(2)
+        shape: (2,)
+        Series: 'a' [list[f64]]
+        [
+            [null, 1.5, … 3.5]
+            [null, 6.0, 1.5]
+        ]
+        """
+
+    def rolling_min(
+        self, window_size: int, min_periods: int | None = None
+    ) -> Series:
+        """
+        Apply a rolling minimum within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [10, 2, 1]])
+        >>> s.list.rolling_min(2)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 1, … 3]
+            [null, 2, 1]
+        ]
+        """
+
+    def rolling_max(
+        self, window_size: int, min_periods: int | None = None
+    ) -> Series:
+        """
+        Apply a rolling maximum within every sublist.
+
+        Every sublist is treated as a series of its own, so the windows never cross into
+        the next sublist.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in the window, ending at the current value.
+        min_periods
+            The number of non-null values the window should contain to compute a
+            result, otherwise the result is null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [10, 2, 1]])
+        >>> s.list.rolling_max(2)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 2, … 4]
+            [null, 10, 2]
+        ]
+        """
+
+
     @deprecate_renamed_parameter("periods", "n", version="0.19.11")
     def shift(self, n: int | IntoExprColumn = 1) -> Series:
         """
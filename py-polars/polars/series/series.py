@@ -4837,7 +4837,9 @@ class Series:
         Parameters
         ----------
         value
-            Value used to fill null values.
+            Value used to fill null values. Combined with the 'forward' or
+            'backward' strategy, it fills the null values that are left, e.g.
+            beyond the `limit`.
         strategy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
             Strategy used to fill null values.
         limit
@@ -10,6 +10,8 @@ from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
+    from typing import Mapping
+
     from polars import Expr, Series
     from polars.polars import PySeries
     from polars.type_aliases import (
@@ -958,6 +960,62 @@ class StringNameSpace:
         ]
         """
 
+    def extract_pattern(
+        self, pattern: str, *, custom_patterns: Mapping[str, str] | None = None
+    ) -> Series:
+        r"""
+        Extract the fields of a grok pattern into a struct.
+
+        Parameters
+        ----------
+        pattern
+            A pattern of grok patterns of the form `%{SYNTAX}`, `%{SYNTAX:name}` or
+            `%{SYNTAX:name:type}` and regex syntax, compatible with the
+            `regex crate <https://docs.rs/regex/latest/regex/>`_. `SYNTAX` is the name
+            of a pattern of the built-in library, such as `IP`, `NUMBER`, `WORD`,
+            `TIMESTAMP_ISO8601`, `LOGLEVEL` or `COMBINEDAPACHELOG`, or of
+            `custom_patterns`. The match of a grok pattern with a `name` is extracted
+            into a field of that name, which is cast to `type`: `int`, `float` or
+            `string` (default). Named capture groups are extracted as string fields.
+        custom_patterns
+            Additional patterns by name, which may reference other patterns.
+
+        Notes
+        -----
+        The pattern is compiled once, and the strings are matched in parallel.
+        Strings that don't match have null fields.
+
+        See Also
+        --------
+        extract_groups
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "log",
+        ...     [
+        ...         "2024-01-01T12:00:00Z INFO 200 took 12.5ms",
+        ...         "2024-01-01T12:00:01Z WARN 503 took 250ms",
+        ...         "garbage",
+        ...     ],
+        ... )
+        >>> pattern = (
+        ...     "%{TIMESTAMP_ISO8601:ts} %{LOGLEVEL:level} %{INT:status:int} "
+        ...     "took %{NUMBER:duration:float}ms"
+        ... )
+        >>> s.str.extract_pattern(pattern).struct.unnest()
+        shape: (3, 4)
+        ┌──────────────────────┬───────┬────────┬──────────┐
+        │ ts                   ┆ level ┆ status ┆ duration │
+        │ ---                  ┆ ---   ┆ ---    ┆ ---      │
+        │ str                  ┆ str   ┆ i64    ┆ f64      │
+        ╞══════════════════════╪═══════╪════════╪══════════╡
+        │ 2024-01-01T12:00:00Z ┆ INFO  ┆ 200    ┆ 12.5     │
+        │ 2024-01-01T12:00:01Z ┆ WARN  ┆ 503    ┆ 250.0    │
+        │ null                 ┆ null  ┆ null   ┆ null     │
+        └──────────────────────┴───────┴────────┴──────────┘
+        """
+
     def count_matches(self, pattern: str | Series, *, literal: bool = False) -> Series:
         r"""
         Count all successive non-overlapping regex matches.
@@ -1604,6 +1662,214 @@ class StringNameSpace:
         ]
         """
 
+    def mask_except_last(self, n: int, mask_char: str = "*") -> Series:
+        """
+        Replace all but the last `n` characters of each string with `mask_char`.
+
+        Parameters
+        ----------
+        n
+            The number of trailing characters to keep. Strings with at most `n`
+            characters are returned as-is.
+        mask_char
+            The character to mask the other characters with.
+
+        See Also
+        --------
+        tokenize_format_preserving
+        pseudonymize
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "card", ["4111-1111-1111-1111", "378282246310005", "123", None]
+        ... )
+        >>> s.str.mask_except_last(4)
+        shape: (4,)
+        Series: 'card' [str]
+        [
+            "***************1111"
+            "***********0005"
+            "123"
+            null
+        ]
+        """
+
+    def tokenize_format_preserving(self, key: str) -> Series:
+        """
+        Replace each string with a token that has the same format.
+
+        Every ASCII digit is replaced with a digit and every ASCII letter with a letter
+        of the same case, while all other characters are kept. The replacements are
+        derived from `key` and the whole string, so equal strings get equal tokens and
+        the tokens can still be joined on.
+
+        Parameters
+        ----------
+        key
+            The secret that determines the tokens.
+
+        See Also
+        --------
+        mask_except_last
+        pseudonymize
+
+        Examples
+        --------
+        >>> s = pl.Series("card", ["4111-1111-1111-1111", "5500-0000-0000-0004", None])
+        >>> s.str.tokenize_format_preserving("secret").str.contains(
+        ...     r"^\d{4}-\d{4}-\d{4}-\d{4}$"
+        ... )
+        shape: (3,)
+        Series: 'card' [bool]
+        [
+            true
+            true
+            null
+        ]
+        """
+
+    def pseudonymize(self, key: str | bytes) -> Series:
+        """
+        Replace each string with the hexadecimal HMAC-SHA256 of the string.
+
+        Equal strings get equal pseudonyms, so the pseudonyms can still be joined on
+        and grouped by. Without the key, the pseudonyms can't be linked back to the
+        strings. Use a different key per output to prevent linking pseudonyms across
+        outputs.
+
+        Parameters
+        ----------
+        key
+            The secret key of the HMAC. A string is encoded as UTF-8.
+
+        Notes
+        -----
+        The key is not serialized, so a query that uses this can't be serialized.
+
+        See Also
+        --------
+        mask_except_last
+        tokenize_format_preserving
+
+        Examples
+        --------
+        >>> s = pl.Series("email", ["a@x.com", "b@x.com", "a@x.com"])
+        >>> s.str.pseudonymize("key").n_unique()
+        2
+        """
+
+    def url_decode(self) -> Series:
+        """
+        Decode the percent-encoded characters of each string.
+
+        Malformed escapes are kept as-is. Strings that don't decode to valid UTF-8
+        become null.
+
+        See Also
+        --------
+        url_encode
+
+        Examples
+        --------
+        >>> s = pl.Series("encoded", ["caf%C3%A9", "a%20b%2Bc", None])
+        >>> s.str.url_decode()
+        shape: (3,)
+        Series: 'encoded' [str]
+        [
+            "café"
+            "a b+c"
+            null
+        ]
+        """
+
+    def url_encode(self) -> Series:
+        """
+        Percent-encode each string.
+
+        All characters are encoded except ASCII letters and digits and `-`, `_`, `.`
+        and `~`.
+
+        See Also
+        --------
+        url_decode
+
+        Examples
+        --------
+        >>> s = pl.Series("text", ["café", "a b+c", None])
+        >>> s.str.url_encode()
+        shape: (3,)
+        Series: 'text' [str]
+        [
+            "caf%C3%A9"
+            "a%20b%2Bc"
+            null
+        ]
+        """
+
+    def parse_url(self) -> Series:
+        """
+        Split each string into the components of a URL.
+
+        Returns a struct with the fields `scheme`, `host`, `port`, `path`, `query` and
+        `fragment`, where missing components are null. The components are not decoded.
+        Raises an error if a port is not a number of at most 65535.
+
+        See Also
+        --------
+        parse_query_string
+        url_decode
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "url",
+        ...     [
+        ...         "https://example.com:8080/a/b?q=1#top",
+        ...         "mailto:someone@example.com",
+        ...         "/relative/path?x=y",
+        ...     ],
+        ... )
+        >>> s.str.parse_url().struct.unnest()
+        shape: (3, 6)
+        ┌────────┬─────────────┬──────┬─────────────────────┬───────┬──────────┐
+        │ scheme ┆ host        ┆ port ┆ path                ┆ query ┆ fragment │
+        │ ---    ┆ ---         ┆ ---  ┆ ---                 ┆ ---   ┆ ---      │
+        │ str    ┆ str         ┆ u16  ┆ str                 ┆ str   ┆ str      │
+        ╞════════╪═════════════╪══════╪═════════════════════╪═══════╪══════════╡
+        │ https  ┆ example.com ┆ 8080 ┆ /a/b                ┆ q=1   ┆ top      │
+        │ mailto ┆ null        ┆ null ┆ someone@example.com ┆ null  ┆ null     │
+        │ null   ┆ null        ┆ null ┆ /relative/path      ┆ x=y   ┆ null     │
+        └────────┴─────────────┴──────┴─────────────────────┴───────┴──────────┘
+        """
+
+    def parse_query_string(self) -> Series:
+        """
+        Parse each string as a query string into a list of key-value structs.
+
+        The keys and values are decoded, with `+` as a space. A leading `?` is
+        ignored, and a key without `=` has a null value.
+
+        See Also
+        --------
+        parse_url
+
+        Examples
+        --------
+        >>> s = pl.Series("query", ["a=1&b=hello+world", "c"])
+        >>> s.str.parse_query_string().explode().struct.unnest()
+        shape: (3, 2)
+        ┌─────┬─────────────┐
+        │ key ┆ value       │
+        │ --- ┆ ---         │
+        │ str ┆ str         │
+        ╞═════╪═════════════╡
+        │ a   ┆ 1           │
+        │ b   ┆ hello world │
+        │ c   ┆ null        │
+        └─────┴─────────────┘
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
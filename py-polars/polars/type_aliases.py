@@ -86,7 +86,13 @@ Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
+CommitStrategy: TypeAlias = Literal["direct", "atomic"]
+ExistingDataBehavior: TypeAlias = Literal[
+    "error", "overwrite_or_ignore", "delete_matching", "append"
+]
+IpcDictionaryMode: TypeAlias = Literal["delta", "unify"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvParseErrorPolicy: TypeAlias = Literal["raise", "null", "sidecar"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
@@ -97,11 +103,15 @@ JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
+OutOfBoundsPolicy: TypeAlias = Literal["error", "null", "clamp"]
 NullStrategy: TypeAlias = Literal["ignore", "propagate"]
 ParallelStrategy: TypeAlias = Literal["auto", "columns", "row_groups", "none"]
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
+ParquetEncoding: TypeAlias = Literal[
+    "plain", "delta_binary_packed", "delta_length_byte_array", "byte_stream_split"
+]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
@@ -131,7 +141,9 @@ StartBy: TypeAlias = Literal[
     "sunday",
 ]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
-UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
+UniqueKeepStrategy: TypeAlias = Literal[
+    "first", "last", "any", "none", "max_by", "min_by"
+]
 UnstackDirection: TypeAlias = Literal["vertical", "horizontal"]
 MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 
@@ -218,6 +230,15 @@ ExcelSpreadsheetEngine: TypeAlias = Literal[
 ]
 
 
+class ParquetColumnOptions(TypedDict, total=False):
+    """Options of a single column of a Parquet file."""
+
+    dictionary: bool
+    encoding: ParquetEncoding
+    compression: ParquetCompression
+    compression_level: int
+
+
 class SeriesBuffers(TypedDict):
     """Underlying buffers of a Series."""
 
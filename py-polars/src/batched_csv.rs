@@ -30,7 +30,8 @@ impl PyBatchedCsv {
         projection, separator, rechunk, columns, encoding, n_threads, path, overwrite_dtype,
         overwrite_dtype_slice, low_memory, comment_prefix, quote_char, null_values,
         missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header, row_index,
-        sample_size, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma)
+        sample_size, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma,
+        parse_error_policy)
     )]
     fn new(
         infer_schema_length: Option<usize>,
@@ -61,6 +62,7 @@ impl PyBatchedCsv {
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
         decimal_comma: bool,
+        parse_error_policy: Option<Vec<(String, Wrap<CsvParseErrorPolicy>)>>,
     ) -> PyResult<PyBatchedCsv> {
         let null_values = null_values.map(|w| w.0);
         let eol_char = eol_char.as_bytes()[0];
@@ -92,6 +94,13 @@ impl PyBatchedCsv {
                 .collect::<Vec<_>>()
         });
 
+        let parse_error_policy = parse_error_policy.map(|policies| {
+            policies
+                .into_iter()
+                .map(|(name, policy)| (name, policy.0))
+                .collect::<Vec<_>>()
+        });
+
         let file = std::fs::File::open(path).map_err(PyPolarsErr::from)?;
         let reader = Box::new(file) as Box<dyn MmapBytesReader>;
         let reader = CsvReader::new(reader)
@@ -120,6 +129,7 @@ impl PyBatchedCsv {
             .sample_size(sample_size)
             .truncate_ragged_lines(truncate_ragged_lines)
             .with_decimal_comma(decimal_comma)
+            .with_parse_error_policies(parse_error_policy)
             .raise_if_empty(raise_if_empty);
 
         let reader = if low_memory {
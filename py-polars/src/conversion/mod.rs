@@ -1,5 +1,7 @@
 pub(crate) mod any_value;
 pub(crate) mod chunked_array;
+#[cfg(feature = "parquet")]
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 
@@ -689,6 +691,23 @@ impl FromPyObject<'_> for Wrap<CsvEncoding> {
     }
 }
 
+#[cfg(feature = "csv")]
+impl FromPyObject<'_> for Wrap<CsvParseErrorPolicy> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => CsvParseErrorPolicy::Raise,
+            "null" => CsvParseErrorPolicy::Null,
+            "sidecar" => CsvParseErrorPolicy::Sidecar,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`parse_error_policy` must be one of {{'raise', 'null', 'sidecar'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "ipc")]
 impl FromPyObject<'_> for Wrap<Option<IpcCompression>> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
@@ -908,23 +927,6 @@ impl FromPyObject<'_> for Wrap<TimeUnit> {
     }
 }
 
-impl FromPyObject<'_> for Wrap<UniqueKeepStrategy> {
-    fn extract(ob: &PyAny) -> PyResult<Self> {
-        let parsed = match &*ob.extract::<PyBackedStr>()? {
-            "first" => UniqueKeepStrategy::First,
-            "last" => UniqueKeepStrategy::Last,
-            "none" => UniqueKeepStrategy::None,
-            "any" => UniqueKeepStrategy::Any,
-            v => {
-                return Err(PyValueError::new_err(format!(
-                    "`keep` must be one of {{'first', 'last', 'any', 'none'}}, got {v}",
-                )))
-            },
-        };
-        Ok(Wrap(parsed))
-    }
-}
-
 #[cfg(feature = "ipc")]
 impl FromPyObject<'_> for Wrap<IpcCompression> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
@@ -958,6 +960,22 @@ impl FromPyObject<'_> for Wrap<SearchSortedSide> {
     }
 }
 
+impl FromPyObject<'_> for Wrap<OutOfBoundsPolicy> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "error" => OutOfBoundsPolicy::Error,
+            "null" => OutOfBoundsPolicy::Null,
+            "clamp" => OutOfBoundsPolicy::Clamp,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`out_of_bounds` must be one of {{'error', 'null', 'clamp'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<ClosedInterval> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1026,6 +1044,54 @@ impl FromPyObject<'_> for Wrap<QuoteStyle> {
     }
 }
 
+impl FromPyObject<'_> for Wrap<ExistingDataBehavior> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "error" => ExistingDataBehavior::Error,
+            "overwrite_or_ignore" => ExistingDataBehavior::OverwriteOrIgnore,
+            "delete_matching" => ExistingDataBehavior::DeleteMatching,
+            "append" => ExistingDataBehavior::Append,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`existing_data_behavior` must be one of {{'error', 'overwrite_or_ignore', 'delete_matching', 'append'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl FromPyObject<'_> for Wrap<CommitStrategy> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "direct" => CommitStrategy::Direct,
+            "atomic" => CommitStrategy::Atomic,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`commit_strategy` must be one of {{'direct', 'atomic'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "ipc")]
+impl FromPyObject<'_> for Wrap<IpcDictionaryMode> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "delta" => IpcDictionaryMode::Delta,
+            "unify" => IpcDictionaryMode::UnifyAtFinish,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`dictionary_mode` must be one of {{'delta', 'unify'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "cloud")]
 pub(crate) fn parse_cloud_options(uri: &str, kv: Vec<(String, String)>) -> PyResult<CloudOptions> {
     let out = CloudOptions::from_untyped_config(uri, kv).map_err(PyPolarsErr::from)?;
@@ -1071,6 +1137,33 @@ pub(crate) fn parse_fill_null_strategy(
     Ok(parsed)
 }
 
+/// Parse the keep strategy of `unique`, where `by` is the column that `max_by` and `min_by`
+/// compare.
+pub(crate) fn parse_unique_keep_strategy(
+    keep: &str,
+    by: Option<&str>,
+) -> PyResult<UniqueKeepStrategy> {
+    let parsed = match (keep, by) {
+        ("first", _) => UniqueKeepStrategy::First,
+        ("last", _) => UniqueKeepStrategy::Last,
+        ("none", _) => UniqueKeepStrategy::None,
+        ("any", _) => UniqueKeepStrategy::Any,
+        ("max_by", Some(_)) => UniqueKeepStrategy::MaxBy,
+        ("min_by", Some(_)) => UniqueKeepStrategy::MinBy,
+        ("max_by" | "min_by", None) => {
+            return Err(PyValueError::new_err(format!(
+                "`keep='{keep}'` requires the column to compare in `by`",
+            )))
+        },
+        (v, _) => {
+            return Err(PyValueError::new_err(format!(
+                "`keep` must be one of {{first, last, any, none, max_by, min_by}}, got {v}",
+            )))
+        },
+    };
+    Ok(parsed)
+}
+
 #[cfg(feature = "parquet")]
 pub(crate) fn parse_parquet_compression(
     compression: &str,
@@ -1114,6 +1207,83 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(feature = "parquet")]
+impl FromPyObject<'_> for Wrap<ParquetEncoding> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "plain" => ParquetEncoding::Plain,
+            "delta_binary_packed" => ParquetEncoding::DeltaBinaryPacked,
+            "delta_length_byte_array" => ParquetEncoding::DeltaLengthByteArray,
+            "byte_stream_split" => ParquetEncoding::ByteStreamSplit,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "parquet `encoding` must be one of {{'plain', 'delta_binary_packed', 'delta_length_byte_array', 'byte_stream_split'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl FromPyObject<'_> for Wrap<ParquetColumnWriteOptions> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let dict = ob.extract::<&PyDict>()?;
+        for key in dict.keys() {
+            let key = key.extract::<PyBackedStr>()?;
+            if !["dictionary", "encoding", "compression", "compression_level"].contains(&&*key) {
+                return Err(PyValueError::new_err(format!(
+                    "parquet column options must be one of {{'dictionary', 'encoding', 'compression', 'compression_level'}}, got {}",
+                    &*key
+                )));
+            }
+        }
+        let get = |key: &str| -> PyResult<Option<&PyAny>> {
+            Ok(dict.get_item(key)?.filter(|value| !value.is_none()))
+        };
+
+        let mut options = ParquetColumnWriteOptions::default();
+        if let Some(dictionary) = get("dictionary")? {
+            options = options.with_dictionary(dictionary.extract()?);
+        }
+        if let Some(encoding) = get("encoding")? {
+            options = options.with_encoding(encoding.extract::<Wrap<ParquetEncoding>>()?.0);
+        }
+        if let Some(compression) = get("compression")? {
+            let compression_level = get("compression_level")?
+                .map(|level| level.extract())
+                .transpose()?;
+            options = options.with_compression(parse_parquet_compression(
+                &compression.extract::<PyBackedStr>()?,
+                compression_level,
+            )?);
+        }
+        Ok(Wrap(options))
+    }
+}
+
+/// The options of single columns of a parquet file, by name.
+#[cfg(feature = "parquet")]
+pub(crate) fn parse_parquet_column_options(
+    column_options: Option<BTreeMap<String, Wrap<ParquetColumnWriteOptions>>>,
+) -> BTreeMap<String, ParquetColumnWriteOptions> {
+    column_options
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, options)| (name, options.0))
+        .collect()
+}
+
+#[cfg(feature = "ipc")]
+pub(crate) fn parse_ipc_compression_level(
+    compression: Option<IpcCompression>,
+    compression_level: Option<i32>,
+) -> PyResult<Option<i32>> {
+    IpcCompression::check_level(compression, compression_level)
+        .map_err(|e| PyValueError::new_err(format!("invalid ipc `compression_level`: {e}")))?;
+    Ok(compression_level)
+}
+
 pub(crate) fn strings_to_smartstrings<I, S>(container: I) -> Vec<SmartString>
 where
     I: IntoIterator<Item = S>,
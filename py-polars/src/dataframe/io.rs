@@ -1,3 +1,5 @@
+#[cfg(feature = "parquet")]
+use std::collections::BTreeMap;
 use std::io::BufWriter;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
@@ -10,9 +12,11 @@ use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 
 use super::*;
-#[cfg(feature = "parquet")]
-use crate::conversion::parse_parquet_compression;
+#[cfg(feature = "ipc")]
+use crate::conversion::parse_ipc_compression_level;
 use crate::conversion::Wrap;
+#[cfg(feature = "parquet")]
+use crate::conversion::{parse_parquet_column_options, parse_parquet_compression};
 use crate::file::{
     get_either_file, get_file_like, get_mmap_bytes_reader, read_if_bytesio, EitherRustPythonFile,
 };
@@ -26,7 +30,8 @@ impl PyDataFrame {
     skip_rows, projection, separator, rechunk, columns, encoding, n_threads, path,
     overwrite_dtype, overwrite_dtype_slice, low_memory, comment_prefix, quote_char,
     null_values, missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header,
-    row_index, sample_size, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema)
+    row_index, sample_size, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema,
+    parse_error_policy)
 )]
     pub fn read_csv(
         py: Python,
@@ -60,6 +65,7 @@ impl PyDataFrame {
         truncate_ragged_lines: bool,
         decimal_comma: bool,
         schema: Option<Wrap<Schema>>,
+        parse_error_policy: Option<Vec<(String, Wrap<CsvParseErrorPolicy>)>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let eol_char = eol_char.as_bytes()[0];
@@ -83,6 +89,13 @@ impl PyDataFrame {
                 .collect::<Vec<_>>()
         });
 
+        let parse_error_policy = parse_error_policy.map(|policies| {
+            policies
+                .into_iter()
+                .map(|(name, policy)| (name, policy.0))
+                .collect::<Vec<_>>()
+        });
+
         py_f = read_if_bytesio(py_f);
         let mmap_bytes_r = get_mmap_bytes_reader(&py_f)?;
         let df = py.allow_threads(move || {
@@ -116,6 +129,7 @@ impl PyDataFrame {
                 .raise_if_empty(raise_if_empty)
                 .truncate_ragged_lines(truncate_ragged_lines)
                 .with_decimal_comma(decimal_comma)
+                .with_parse_error_policies(parse_error_policy)
                 .finish()
                 .map_err(PyPolarsErr::from)
         })?;
@@ -332,6 +346,33 @@ impl PyDataFrame {
         Ok(PyDataFrame::new(df))
     }
 
+    #[staticmethod]
+    #[cfg(feature = "orc")]
+    #[pyo3(signature = (py_f, columns, projection, n_rows, parallel))]
+    pub fn read_orc(
+        py: Python,
+        mut py_f: Bound<PyAny>,
+        columns: Option<Vec<String>>,
+        projection: Option<Vec<usize>>,
+        n_rows: Option<usize>,
+        parallel: bool,
+    ) -> PyResult<Self> {
+        use polars::io::orc::OrcReader;
+
+        py_f = read_if_bytesio(py_f);
+        let mmap_bytes_r = get_mmap_bytes_reader(&py_f)?;
+        let df = py.allow_threads(move || {
+            OrcReader::new(mmap_bytes_r)
+                .with_projection(projection)
+                .with_columns(columns)
+                .with_n_rows(n_rows)
+                .set_parallel(parallel)
+                .finish()
+                .map_err(PyPolarsErr::from)
+        })?;
+        Ok(PyDataFrame::new(df))
+    }
+
     #[cfg(feature = "csv")]
     pub fn write_csv(
         &mut self,
@@ -395,7 +436,7 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size))]
+    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, bloom_filters, column_options))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -405,8 +446,11 @@ impl PyDataFrame {
         statistics: bool,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        bloom_filters: bool,
+        column_options: Option<BTreeMap<String, Wrap<ParquetColumnWriteOptions>>>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let column_options = parse_parquet_column_options(column_options);
 
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
             let f = std::fs::File::create(&*s)?;
@@ -416,6 +460,8 @@ impl PyDataFrame {
                     .with_statistics(statistics)
                     .with_row_group_size(row_group_size)
                     .with_data_page_size(data_page_size)
+                    .with_bloom_filters(bloom_filters)
+                    .with_column_options(column_options)
                     .finish(&mut self.df)
                     .map_err(PyPolarsErr::from)
             })?;
@@ -426,6 +472,8 @@ impl PyDataFrame {
                 .with_statistics(statistics)
                 .with_row_group_size(row_group_size)
                 .with_data_page_size(data_page_size)
+                .with_bloom_filters(bloom_filters)
+                .with_column_options(column_options)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
         }
@@ -469,13 +517,16 @@ impl PyDataFrame {
         py: Python,
         py_f: PyObject,
         compression: Wrap<Option<IpcCompression>>,
+        compression_level: Option<i32>,
         future: bool,
     ) -> PyResult<()> {
+        let compression_level = parse_ipc_compression_level(compression.0, compression_level)?;
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
             let f = std::fs::File::create(&*s)?;
             py.allow_threads(|| {
                 IpcWriter::new(f)
                     .with_compression(compression.0)
+                    .with_compression_level(compression_level)
                     .with_pl_flavor(future)
                     .finish(&mut self.df)
                     .map_err(PyPolarsErr::from)
@@ -485,6 +536,7 @@ impl PyDataFrame {
 
             IpcWriter::new(&mut buf)
                 .with_compression(compression.0)
+                .with_compression_level(compression_level)
                 .with_pl_flavor(future)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
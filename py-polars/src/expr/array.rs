@@ -121,6 +121,36 @@ impl PyExpr {
         Ok(self.inner.clone().arr().to_struct(name_gen).into())
     }
 
+    #[cfg(feature = "array_linalg")]
+    fn arr_dot(&self, other: PyExpr) -> Self {
+        self.inner.clone().arr().dot(other.inner).into()
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn arr_matmul(&self, matrix: PyExpr) -> Self {
+        self.inner.clone().arr().matmul(matrix.inner).into()
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn arr_sum_axis(&self, axis: u8) -> Self {
+        self.inner.clone().arr().sum_axis(axis).into()
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn arr_mean_axis(&self, axis: u8) -> Self {
+        self.inner.clone().arr().mean_axis(axis).into()
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn arr_min_axis(&self, axis: u8) -> Self {
+        self.inner.clone().arr().min_axis(axis).into()
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn arr_max_axis(&self, axis: u8) -> Self {
+        self.inner.clone().arr().max_axis(axis).into()
+    }
+
     fn arr_shift(&self, n: PyExpr) -> Self {
         self.inner.clone().arr().shift(n.inner).into()
     }
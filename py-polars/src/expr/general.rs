@@ -394,8 +394,11 @@ impl PyExpr {
             .search_sorted(element.inner, side.0)
             .into()
     }
-    fn gather(&self, idx: Self) -> Self {
-        self.inner.clone().gather(idx.inner).into()
+    fn gather(&self, idx: Self, out_of_bounds: Wrap<OutOfBoundsPolicy>) -> Self {
+        self.inner
+            .clone()
+            .gather_with_policy(idx.inner, out_of_bounds.0)
+            .into()
     }
 
     fn get(&self, idx: Self) -> Self {
@@ -446,9 +449,20 @@ impl PyExpr {
         self.inner.clone().fill_null(expr.inner).into()
     }
 
-    fn fill_null_with_strategy(&self, strategy: &str, limit: FillNullLimit) -> PyResult<Self> {
+    #[pyo3(signature = (strategy, limit, value=None))]
+    fn fill_null_with_strategy(
+        &self,
+        strategy: &str,
+        limit: FillNullLimit,
+        value: Option<Self>,
+    ) -> PyResult<Self> {
         let strategy = parse_fill_null_strategy(strategy, limit)?;
-        Ok(self.inner.clone().fill_null_with_strategy(strategy).into())
+        let expr = self.inner.clone();
+        let out = match value {
+            Some(v) => expr.fill_null_with_strategy_and_value(strategy, v.inner),
+            None => expr.fill_null_with_strategy(strategy),
+        };
+        Ok(out.into())
     }
 
     fn fill_nan(&self, expr: Self) -> Self {
@@ -678,6 +692,7 @@ impl PyExpr {
             offset: Duration::parse(offset),
             closed_window: closed.0,
             check_sorted,
+            time_zone_column: None,
         };
 
         self.inner.clone().rolling(options).into()
@@ -40,6 +40,42 @@ impl PyExpr {
         Ok(self.inner.clone().list().diff(n, null_behavior.0).into())
     }
 
+    #[cfg(feature = "list_rolling")]
+    fn list_rolling_sum(&self, window_size: usize, min_periods: usize) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_sum(window_size, min_periods)
+            .into()
+    }
+
+    #[cfg(feature = "list_rolling")]
+    fn list_rolling_mean(&self, window_size: usize, min_periods: usize) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_mean(window_size, min_periods)
+            .into()
+    }
+
+    #[cfg(feature = "list_rolling")]
+    fn list_rolling_min(&self, window_size: usize, min_periods: usize) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_min(window_size, min_periods)
+            .into()
+    }
+
+    #[cfg(feature = "list_rolling")]
+    fn list_rolling_max(&self, window_size: usize, min_periods: usize) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_max(window_size, min_periods)
+            .into()
+    }
+
     fn list_eval(&self, expr: PyExpr, parallel: bool) -> Self {
         self.inner.clone().list().eval(expr.inner, parallel).into()
     }
@@ -217,6 +217,44 @@ impl PyExpr {
         self.inner.clone().rolling_skew(window_size, bias).into()
     }
 
+    #[pyo3(signature = (by, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_count_by(
+        &self,
+        by: &str,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_count_by(by, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_n_unique_by(
+        &self,
+        by: &str,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_n_unique_by(by, options).into()
+    }
+
     #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
     fn rolling_map(
         &self,
@@ -157,6 +157,42 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_mask_except_last(&self, n: usize, mask_char: char) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .mask_except_last(n, mask_char)
+            .into()
+    }
+
+    fn str_tokenize_format_preserving(&self, key: &str) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .tokenize_format_preserving(key)
+            .into()
+    }
+
+    fn str_pseudonymize(&self, key: &[u8]) -> Self {
+        self.inner.clone().str().pseudonymize(key).into()
+    }
+
+    fn str_url_decode(&self) -> Self {
+        self.inner.clone().str().url_decode().into()
+    }
+
+    fn str_url_encode(&self) -> Self {
+        self.inner.clone().str().url_encode().into()
+    }
+
+    fn str_parse_url(&self) -> Self {
+        self.inner.clone().str().parse_url().into()
+    }
+
+    fn str_parse_query_string(&self) -> Self {
+        self.inner.clone().str().parse_query_string().into()
+    }
+
     fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
         self.inner.clone().str().pad_start(length, fill_char).into()
     }
@@ -264,6 +300,25 @@ impl PyExpr {
             .into())
     }
 
+    #[cfg(feature = "extract_pattern")]
+    fn str_extract_pattern(
+        &self,
+        pat: &str,
+        custom_patterns: Vec<(String, String)>,
+    ) -> PyResult<Self> {
+        let custom_patterns = custom_patterns
+            .iter()
+            .map(|(name, pattern)| (name.as_str(), pattern.as_str()))
+            .collect::<Vec<_>>();
+        Ok(self
+            .inner
+            .clone()
+            .str()
+            .extract_pattern(pat, &custom_patterns)
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+
     fn str_count_matches(&self, pat: Self, literal: bool) -> Self {
         self.inner
             .clone()
@@ -1,3 +1,4 @@
+use polars::prelude::JsonEncodeOptions;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
@@ -16,7 +17,24 @@ impl PyExpr {
         self.inner.clone().struct_().rename_fields(names).into()
     }
 
-    fn struct_json_encode(&self) -> Self {
-        self.inner.clone().struct_().json_encode().into()
+    #[pyo3(signature = (date_format, datetime_format, skip_nulls, sort_keys))]
+    fn struct_json_encode(
+        &self,
+        date_format: Option<String>,
+        datetime_format: Option<String>,
+        skip_nulls: bool,
+        sort_keys: bool,
+    ) -> Self {
+        let options = JsonEncodeOptions {
+            date_format,
+            datetime_format,
+            skip_nulls,
+            sort_keys,
+        };
+        self.inner
+            .clone()
+            .struct_()
+            .json_encode_with_options(options)
+            .into()
     }
 }
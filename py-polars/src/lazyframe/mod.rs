@@ -1,6 +1,8 @@
 mod exitable;
 mod visit;
 pub(crate) mod visitor;
+#[cfg(feature = "parquet")]
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::io::BufWriter;
 use std::num::NonZeroUsize;
@@ -144,7 +146,8 @@ impl PyLazyFrame {
     #[pyo3(signature = (path, paths, separator, has_header, ignore_errors, skip_rows, n_rows, cache, overwrite_dtype,
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
-        encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema
+        encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
+        parse_error_policy
     )
     )]
     fn new_from_csv(
@@ -175,6 +178,7 @@ impl PyLazyFrame {
         decimal_comma: bool,
         glob: bool,
         schema: Option<Wrap<Schema>>,
+        parse_error_policy: Option<Vec<(String, Wrap<CsvParseErrorPolicy>)>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.map(|s| s.as_bytes()[0]);
@@ -189,6 +193,13 @@ impl PyLazyFrame {
                 .collect::<Schema>()
         });
 
+        let parse_error_policy = parse_error_policy.map(|policies| {
+            policies
+                .into_iter()
+                .map(|(name, policy)| (name, policy.0))
+                .collect::<Vec<_>>()
+        });
+
         let r = if let Some(path) = path.as_ref() {
             LazyCsvReader::new(path)
         } else {
@@ -219,6 +230,7 @@ impl PyLazyFrame {
             .truncate_ragged_lines(truncate_ragged_lines)
             .with_decimal_comma(decimal_comma)
             .with_glob(glob)
+            .with_parse_error_policies(parse_error_policy)
             .raise_if_empty(raise_if_empty);
 
         if let Some(lambda) = with_schema_modify {
@@ -250,7 +262,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries, glob)
+        low_memory, max_decoders, cloud_options, use_statistics, hive_partitioning, hive_schema,
+        retries, glob)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -261,6 +274,7 @@ impl PyLazyFrame {
         rechunk: bool,
         row_index: Option<(String, IdxSize)>,
         low_memory: bool,
+        max_decoders: Option<usize>,
         cloud_options: Option<Vec<(String, String)>>,
         use_statistics: bool,
         hive_partitioning: bool,
@@ -305,10 +319,12 @@ impl PyLazyFrame {
             rechunk,
             row_index,
             low_memory,
+            max_decoders,
             cloud_options,
             use_statistics,
             hive_options,
             glob,
+            decryption: None,
         };
 
         let lf = if path.is_some() {
@@ -322,7 +338,9 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[staticmethod]
-    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map, cloud_options, retries))]
+    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map, low_memory,
+        max_decoders, cloud_options, retries)
+    )]
     fn new_from_ipc(
         path: Option<PathBuf>,
         paths: Vec<PathBuf>,
@@ -331,6 +349,8 @@ impl PyLazyFrame {
         rechunk: bool,
         row_index: Option<(String, IdxSize)>,
         memory_map: bool,
+        low_memory: bool,
+        max_decoders: Option<usize>,
         cloud_options: Option<Vec<(String, String)>>,
         retries: usize,
     ) -> PyResult<Self> {
@@ -368,6 +388,8 @@ impl PyLazyFrame {
             rechunk,
             row_index,
             memory_map,
+            low_memory,
+            max_decoders,
             #[cfg(feature = "cloud")]
             cloud_options,
         };
@@ -381,6 +403,44 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "iceberg")]
+    #[staticmethod]
+    #[pyo3(signature = (path, snapshot_id, n_rows, row_index))]
+    fn new_from_iceberg(
+        path: PathBuf,
+        snapshot_id: Option<i64>,
+        n_rows: Option<usize>,
+        row_index: Option<(String, IdxSize)>,
+    ) -> PyResult<Self> {
+        let row_index = row_index.map(|(name, offset)| RowIndex { name, offset });
+        let args = ScanArgsIceberg {
+            snapshot_id,
+            n_rows,
+            row_index,
+        };
+        let lf = LazyFrame::scan_iceberg(path, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
+    #[cfg(feature = "orc")]
+    #[staticmethod]
+    #[pyo3(signature = (path, n_rows, row_index, parallel))]
+    fn new_from_orc(
+        path: PathBuf,
+        n_rows: Option<usize>,
+        row_index: Option<(String, IdxSize)>,
+        parallel: bool,
+    ) -> PyResult<Self> {
+        let row_index = row_index.map(|(name, offset)| RowIndex { name, offset });
+        let args = ScanArgsOrc {
+            n_rows,
+            row_index,
+            parallel,
+        };
+        let lf = LazyFrame::scan_orc(path, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     fn scan_from_python_function_arrow_schema(
         schema: &PyList,
@@ -461,6 +521,27 @@ impl PyLazyFrame {
         ldf.into()
     }
 
+    #[cfg(all(feature = "streaming", feature = "ipc"))]
+    fn with_spill_options(
+        &self,
+        compression: Wrap<Option<IpcCompression>>,
+        compression_level: Option<i32>,
+        decompression_threads: Option<usize>,
+    ) -> PyResult<Self> {
+        let compression_level = parse_ipc_compression_level(compression.0, compression_level)?;
+        if decompression_threads == Some(0) {
+            return Err(PyValueError::new_err(
+                "`decompression_threads` must be positive",
+            ));
+        }
+        let options = SpillOptions {
+            compression: compression.0,
+            compression_level,
+            decompression_threads,
+        };
+        Ok(self.ldf.clone().with_spill_options(options).into())
+    }
+
     fn sort(
         &self,
         by_column: &str,
@@ -632,7 +713,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, bloom_filters, maintain_order, commit_strategy, column_options))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -642,17 +723,24 @@ impl PyLazyFrame {
         statistics: bool,
         row_group_size: Option<usize>,
         data_pagesize_limit: Option<usize>,
+        bloom_filters: bool,
         maintain_order: bool,
+        commit_strategy: Wrap<CommitStrategy>,
+        column_options: Option<BTreeMap<String, Wrap<ParquetColumnWriteOptions>>>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
-        let options = ParquetWriteOptions {
+        let write_options = ParquetWriteOptions {
             compression,
             statistics,
             row_group_size,
             data_pagesize_limit,
+            bloom_filters,
             maintain_order,
+            commit_strategy: commit_strategy.0,
         };
+        let options = ParquetSinkOptions::from(write_options)
+            .with_column_options(parse_parquet_column_options(column_options));
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -663,18 +751,79 @@ impl PyLazyFrame {
         Ok(())
     }
 
+    #[cfg(all(feature = "streaming", feature = "parquet"))]
+    #[pyo3(signature = (path, partition_by, max_rows_per_file, max_open_files, existing_data_behavior, write_metadata_file, write_statistics_file, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order, commit_strategy, column_options))]
+    fn sink_parquet_partitioned(
+        &self,
+        py: Python,
+        path: PathBuf,
+        partition_by: Vec<String>,
+        max_rows_per_file: Option<usize>,
+        max_open_files: usize,
+        existing_data_behavior: Wrap<ExistingDataBehavior>,
+        write_metadata_file: bool,
+        write_statistics_file: bool,
+        compression: &str,
+        compression_level: Option<i32>,
+        statistics: bool,
+        row_group_size: Option<usize>,
+        data_pagesize_limit: Option<usize>,
+        maintain_order: bool,
+        commit_strategy: Wrap<CommitStrategy>,
+        column_options: Option<BTreeMap<String, Wrap<ParquetColumnWriteOptions>>>,
+    ) -> PyResult<()> {
+        let compression = parse_parquet_compression(compression, compression_level)?;
+
+        let write_options = ParquetWriteOptions {
+            compression,
+            statistics,
+            row_group_size,
+            data_pagesize_limit,
+            bloom_filters: false,
+            maintain_order,
+            commit_strategy: commit_strategy.0,
+        };
+        let options = ParquetSinkOptions::from(write_options)
+            .with_column_options(parse_parquet_column_options(column_options));
+        let partition_options = PartitionedWriteOptions::new(partition_by)
+            .with_max_rows_per_file(max_rows_per_file)
+            .with_max_open_files(max_open_files)
+            .with_existing_data_behavior(existing_data_behavior.0)
+            .with_metadata_file(write_metadata_file)
+            .with_statistics_file(write_statistics_file);
+
+        // if we don't allow threads and we have udfs trying to acquire the gil from different
+        // threads we deadlock.
+        py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.sink_parquet_partitioned(path, options, partition_options)
+                .map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
     #[cfg(all(feature = "streaming", feature = "ipc"))]
-    #[pyo3(signature = (path, compression, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, maintain_order, commit_strategy, stream, dictionary_mode))]
     fn sink_ipc(
         &self,
         py: Python,
         path: PathBuf,
         compression: Option<Wrap<IpcCompression>>,
+        compression_level: Option<i32>,
         maintain_order: bool,
+        commit_strategy: Wrap<CommitStrategy>,
+        stream: bool,
+        dictionary_mode: Wrap<IpcDictionaryMode>,
     ) -> PyResult<()> {
+        let compression = compression.map(|c| c.0);
+        let compression_level = parse_ipc_compression_level(compression, compression_level)?;
         let options = IpcWriterOptions {
-            compression: compression.map(|c| c.0),
+            compression,
+            compression_level,
             maintain_order,
+            commit_strategy: commit_strategy.0,
+            stream,
+            dictionary_mode: dictionary_mode.0,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -687,7 +836,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "csv"))]
-    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_precision, null_value, quote_style, maintain_order))]
+    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_precision, null_value, quote_style, maintain_order, commit_strategy))]
     fn sink_csv(
         &self,
         py: Python,
@@ -705,6 +854,7 @@ impl PyLazyFrame {
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
         maintain_order: bool,
+        commit_strategy: Wrap<CommitStrategy>,
     ) -> PyResult<()> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
         let null_value = null_value.unwrap_or(SerializeOptions::default().null);
@@ -727,6 +877,7 @@ impl PyLazyFrame {
             maintain_order,
             batch_size,
             serialize_options,
+            commit_strategy: commit_strategy.0,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -740,9 +891,18 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(all(feature = "streaming", feature = "json"))]
-    #[pyo3(signature = (path, maintain_order))]
-    fn sink_json(&self, py: Python, path: PathBuf, maintain_order: bool) -> PyResult<()> {
-        let options = JsonWriterOptions { maintain_order };
+    #[pyo3(signature = (path, maintain_order, commit_strategy))]
+    fn sink_json(
+        &self,
+        py: Python,
+        path: PathBuf,
+        maintain_order: bool,
+        commit_strategy: Wrap<CommitStrategy>,
+    ) -> PyResult<()> {
+        let options = JsonWriterOptions {
+            maintain_order,
+            commit_strategy: commit_strategy.0,
+        };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -812,6 +972,7 @@ impl PyLazyFrame {
                 offset: Duration::parse(offset),
                 closed_window,
                 check_sorted,
+                time_zone_column: None,
             },
         );
 
@@ -856,13 +1017,41 @@ impl PyLazyFrame {
         PyLazyGroupBy { lgb: Some(lazy_gb) }
     }
 
+    fn upsample(&self, by: Vec<String>, time_column: &str, every: &str, offset: &str) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.upsample(
+            by,
+            time_column,
+            Duration::parse(every),
+            Duration::parse(offset),
+        )
+        .into()
+    }
+
+    fn complete(
+        &self,
+        time_column: &str,
+        every: &str,
+        by: Vec<String>,
+        fill_strategy: Option<&str>,
+        fill_limit: FillNullLimit,
+    ) -> PyResult<Self> {
+        let fill = fill_strategy
+            .map(|strategy| parse_fill_null_strategy(strategy, fill_limit))
+            .transpose()?;
+        let ldf = self.ldf.clone();
+        Ok(ldf
+            .complete(time_column, Duration::parse(every), by, fill)
+            .into())
+    }
+
     fn with_context(&self, contexts: Vec<Self>) -> Self {
         let contexts = contexts.into_iter().map(|ldf| ldf.ldf).collect::<Vec<_>>();
         self.ldf.clone().with_context(contexts).into()
     }
 
     #[cfg(feature = "asof_join")]
-    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str))]
+    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, allow_exact_matches, right_index_name, strategy_column))]
     fn join_asof(
         &self,
         other: Self,
@@ -876,6 +1065,9 @@ impl PyLazyFrame {
         strategy: Wrap<AsofStrategy>,
         tolerance: Option<Wrap<AnyValue<'_>>>,
         tolerance_str: Option<String>,
+        allow_exact_matches: bool,
+        right_index_name: Option<String>,
+        strategy_column: Option<String>,
     ) -> PyResult<Self> {
         let ldf = self.ldf.clone();
         let other = other.ldf;
@@ -894,6 +1086,9 @@ impl PyLazyFrame {
                 right_by: right_by.map(strings_to_smartstrings),
                 tolerance: tolerance.map(|t| t.0.into_static().unwrap()),
                 tolerance_str: tolerance_str.map(|s| s.into()),
+                allow_exact_matches,
+                right_index_name: right_index_name.map(|s| s.into()),
+                strategy_column: strategy_column.map(|s| s.into()),
             }))
             .suffix(suffix)
             .finish()
@@ -912,6 +1107,7 @@ impl PyLazyFrame {
         suffix: String,
         validate: Wrap<JoinValidation>,
         coalesce: Option<bool>,
+        drop_key_expressions: bool,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -939,6 +1135,7 @@ impl PyLazyFrame {
             .join_nulls(join_nulls)
             .how(how.0)
             .coalesce(coalesce)
+            .drop_key_expressions(drop_key_expressions)
             .validate(validate.0)
             .suffix(suffix)
             .finish()
@@ -960,6 +1157,11 @@ impl PyLazyFrame {
         ldf.with_columns_seq(exprs.to_exprs()).into()
     }
 
+    fn with_columns_chained(&mut self, exprs: Vec<PyExpr>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_columns_chained(exprs.to_exprs()).into()
+    }
+
     fn rename(&mut self, existing: Vec<String>, new: Vec<String>) -> Self {
         let ldf = self.ldf.clone();
         ldf.rename(existing, new).into()
@@ -1043,19 +1245,19 @@ impl PyLazyFrame {
         ldf.null_count().into()
     }
 
-    #[pyo3(signature = (maintain_order, subset, keep))]
+    #[pyo3(signature = (maintain_order, subset, keep, by))]
     fn unique(
         &self,
         maintain_order: bool,
         subset: Option<Vec<String>>,
-        keep: Wrap<UniqueKeepStrategy>,
-    ) -> Self {
+        keep: &str,
+        by: Option<String>,
+    ) -> PyResult<Self> {
+        let keep = parse_unique_keep_strategy(keep, by.as_deref())?;
         let ldf = self.ldf.clone();
-        match maintain_order {
-            true => ldf.unique_stable(subset, keep.0),
-            false => ldf.unique(subset, keep.0),
-        }
-        .into()
+        Ok(ldf
+            .unique_by(subset, keep, by.as_deref(), maintain_order)
+            .into())
     }
 
     fn drop_nulls(&self, subset: Option<Vec<String>>) -> Self {
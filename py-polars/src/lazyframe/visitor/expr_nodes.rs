@@ -441,6 +441,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
             expr,
             idx,
             returns_scalar,
+            ..
         } => Gather {
             expr: expr.0,
             idx: idx.0,
@@ -684,7 +685,15 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 FunctionExpr::Reshape(_) => return Err(PyNotImplementedError::new_err("reshape")),
                 FunctionExpr::RepeatBy => return Err(PyNotImplementedError::new_err("repeat by")),
+                FunctionExpr::RepeatByExploded => {
+                    return Err(PyNotImplementedError::new_err("repeat by exploded"))
+                },
                 FunctionExpr::ArgUnique => ("argunique",).to_object(py),
+                FunctionExpr::MultiOutput(_) => {
+                    return Err(PyNotImplementedError::new_err("multi output"))
+                },
+                FunctionExpr::MinBy(_) => return Err(PyNotImplementedError::new_err("min_by")),
+                FunctionExpr::MaxBy(_) => return Err(PyNotImplementedError::new_err("max_by")),
                 FunctionExpr::Rank {
                     options: _,
                     seed: _,
@@ -717,6 +726,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::ShrinkType => {
                     return Err(PyNotImplementedError::new_err("shrink type"))
                 },
+                FunctionExpr::CaseWhen => return Err(PyNotImplementedError::new_err("case when")),
                 FunctionExpr::Diff(_, _) => return Err(PyNotImplementedError::new_err("diff")),
                 FunctionExpr::PctChange => {
                     return Err(PyNotImplementedError::new_err("pct change"))
@@ -762,6 +772,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::ToPhysical => {
                     return Err(PyNotImplementedError::new_err("to physical"))
                 },
+                FunctionExpr::ColumnMetadata(_) => {
+                    return Err(PyNotImplementedError::new_err("column metadata"))
+                },
                 FunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"))
                 },
@@ -365,6 +365,24 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             slice: *slice,
         }
         .into_py(py),
+        // A top-k is exposed as the sort and slice it replaces.
+        IR::TopK {
+            input,
+            by_column,
+            offset,
+            len,
+            sort_options,
+        } => Sort {
+            input: input.0,
+            by_column: by_column.iter().map(|e| e.into()).collect(),
+            sort_options: (
+                sort_options.descending.clone(),
+                sort_options.nulls_last,
+                sort_options.maintain_order,
+            ),
+            slice: Some((*offset as i64, *len)),
+        }
+        .into_py(py),
         IR::Cache {
             input,
             id,
@@ -449,6 +467,8 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     UniqueKeepStrategy::Last => "last",
                     UniqueKeepStrategy::None => "none",
                     UniqueKeepStrategy::Any => "any",
+                    UniqueKeepStrategy::MaxBy => "max_by",
+                    UniqueKeepStrategy::MinBy => "min_by",
                 },
                 options
                     .subset
@@ -535,6 +555,25 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     scan_type: _,
                     alias: _,
                 } => return Err(PyNotImplementedError::new_err("function count")),
+                FunctionNode::Upsample {
+                    by,
+                    time_column,
+                    every,
+                    offset,
+                } => (
+                    "upsample",
+                    by.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    time_column.to_string(),
+                    every.to_string(),
+                    offset.to_string(),
+                )
+                    .to_object(py),
+                FunctionNode::Complete {
+                    time_column: _,
+                    every: _,
+                    by: _,
+                    fill: _,
+                } => return Err(PyNotImplementedError::new_err("function complete")),
             },
         }
         .into_py(py),
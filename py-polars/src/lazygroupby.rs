@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use polars::lazy::frame::{LazyFrame, LazyGroupBy};
-use polars::prelude::{DataFrame, PolarsError, Schema};
+use polars::prelude::{DataFrame, MapGroupsOptions, PolarsError, Schema};
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
@@ -38,6 +38,7 @@ impl PyLazyGroupBy {
         &mut self,
         lambda: PyObject,
         schema: Option<Wrap<Schema>>,
+        validate_output: bool,
     ) -> PyResult<PyLazyFrame> {
         let lgb = self.lgb.clone().unwrap();
         let schema = match schema {
@@ -75,6 +76,11 @@ impl PyLazyGroupBy {
                 Ok(pydf.df)
             })
         };
-        Ok(lgb.apply(function, schema).into())
+        // The function holds the GIL, so the groups can't be processed in parallel.
+        let options = MapGroupsOptions {
+            sequential: true,
+            validate_output,
+        };
+        Ok(lgb.apply_with_options(function, schema, options).into())
     }
 }
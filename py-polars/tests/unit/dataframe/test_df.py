@@ -2419,6 +2419,42 @@ def test_fill_null_limits() -> None:
     }
 
 
+def test_fill_null_limits_over_groups() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 1, 2, 2, 2],
+            "a": [1, None, None, None, None, 5, None],
+            "b": [10, 20, 30, 40, 50, 60, 70],
+        }
+    )
+    out = df.select(
+        forward=pl.col("a").fill_null(strategy="forward", limit=1).over("g"),
+        forward_value=pl.col("a").fill_null(0, strategy="forward", limit=1).over("g"),
+        backward_value=pl.col("a")
+        .fill_null(pl.col("b"), strategy="backward", limit=1)
+        .over("g"),
+        unlimited=pl.col("a").forward_fill().over("g"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "forward": [1, 1, None, None, None, 5, 5],
+        "forward_value": [1, 1, 0, 0, 0, 5, 5],
+        "backward_value": [1, 20, 30, 40, 5, 5, 70],
+        "unlimited": [1, 1, 1, 1, None, 5, 5],
+    }
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").fill_null(-1, strategy="forward", limit=2)
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "a": [[1, 1, 1, -1], [-1, 5, 5]],
+    }
+
+    assert df["a"].fill_null(0, strategy="backward").to_list() == [1, 5, 5, 5, 5, 5, 0]
+    with pytest.raises(ValueError, match="cannot specify both"):
+        df.select(pl.col("a").fill_null(0, strategy="max"))
+
+
 def test_lower_bound_upper_bound(fruits_cars: pl.DataFrame) -> None:
     res_expr = fruits_cars.select(pl.col("A").lower_bound())
     assert res_expr.item() == -9223372036854775808
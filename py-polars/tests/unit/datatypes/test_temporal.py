@@ -867,6 +867,89 @@ def test_upsample_index_invalid(
             )
 
 
+def test_upsample_lazy() -> None:
+    df = pl.DataFrame(
+        {
+            "groups": ["a", "a", "b", "b"],
+            "time": [
+                date(2024, 1, 1),
+                date(2024, 1, 3),
+                date(2024, 1, 2),
+                date(2024, 1, 4),
+            ],
+            "values": [1, 3, 2, 4],
+        }
+    ).set_sorted("time")
+    result = df.lazy().upsample("time", every="1d", group_by="groups").collect()
+    expected = df.upsample("time", every="1d", group_by="groups", maintain_order=True)
+    assert_frame_equal(result, expected)
+
+
+def test_complete() -> None:
+    df = pl.DataFrame(
+        {
+            "groups": ["a", "b", "a", "b"],
+            "time": [
+                date(2024, 1, 3),
+                date(2024, 1, 2),
+                date(2024, 1, 1),
+                date(2024, 1, 4),
+            ],
+            "values": [3, 2, 1, 4],
+        }
+    )
+    result = df.complete("time", every="1d", group_by="groups", fill_strategy="forward")
+    expected = pl.DataFrame(
+        {
+            "groups": ["a", "a", "a", "b", "b", "b"],
+            "time": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 3),
+                date(2024, 1, 2),
+                date(2024, 1, 3),
+                date(2024, 1, 4),
+            ],
+            "values": [1, 1, 3, 2, 2, 4],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.lazy().complete("time", every="1d").collect()
+    assert result["time"].to_list() == [
+        date(2024, 1, 1),
+        date(2024, 1, 2),
+        date(2024, 1, 3),
+        date(2024, 1, 4),
+    ]
+    assert result["groups"].null_count() == 0
+
+
+def test_complete_keeps_nulls_and_serializes() -> None:
+    lf = pl.LazyFrame(
+        {
+            "time": [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 4)],
+            "values": [1, None, 4],
+        }
+    ).complete("time", every="1d", fill_strategy="forward")
+    expected = pl.DataFrame(
+        {
+            "time": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 3),
+                date(2024, 1, 4),
+            ],
+            "values": [1, None, 1, 4],
+        }
+    )
+    assert_frame_equal(lf.collect(), expected)
+
+    # The operation is a plan node rather than an opaque function.
+    result = pl.LazyFrame.deserialize(io.StringIO(lf.serialize())).collect()
+    assert_frame_equal(result, expected)
+
+
 def test_microseconds_accuracy() -> None:
     timestamps = [
         datetime(2600, 1, 1, 0, 0, 0, 123456),
@@ -102,6 +102,21 @@ def test_concat_diagonal_relaxed_with_empty_frame() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_concat_diagonal_relaxed_lazy_pushdown() -> None:
+    lf1 = pl.LazyFrame({"a": [1, 2], "b": ["x", "y"]}, schema_overrides={"a": pl.Int32})
+    lf2 = pl.LazyFrame({"c": [1.5, 2.5], "a": [3, 4]})
+    q = (
+        pl.concat([lf1, lf2], how="diagonal_relaxed")
+        .filter(pl.col("a") > 1)
+        .select("a", "c")
+    )
+    assert q.schema == {"a": pl.Int64, "c": pl.Float64}
+
+    expected = pl.DataFrame({"a": [2, 3, 4], "c": [None, 1.5, 2.5]})
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(q.collect(streaming=True), expected)
+
+
 @pytest.mark.parametrize("lazy", [False, True])
 def test_concat_horizontal(lazy: bool) -> None:
     a = pl.DataFrame({"a": ["a", "b"], "b": [1, 2]})
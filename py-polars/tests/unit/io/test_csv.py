@@ -1853,6 +1853,60 @@ def test_provide_schema() -> None:
     }
 
 
+def test_read_csv_parse_error_policy() -> None:
+    csv = "a,b,c\n1,x,1.5\nfoo,2,\n3,bar,2.5\n"
+    schema = {"a": pl.Int64, "b": pl.Int64, "c": pl.Float64}
+    df = pl.read_csv(
+        io.StringIO(csv),
+        schema=schema,
+        parse_error_policy={"a": "sidecar", "b": "null", "c": "raise"},
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, None, 3],
+            "b": [None, 2, None],
+            "c": [1.5, None, 2.5],
+            "a_raw": [None, "foo", None],
+        },
+        schema={**schema, "a_raw": pl.String},
+    )
+    assert_frame_equal(df, expected)
+
+    with pytest.raises(ComputeError, match="conversion from `str` to `i64` failed"):
+        pl.read_csv(io.StringIO(csv), schema=schema, parse_error_policy={"b": "raise"})
+    with pytest.raises(ComputeError, match="must be given"):
+        pl.read_csv(io.StringIO(csv), parse_error_policy={"b": "null"})
+    with pytest.raises(ValueError, match="parse_error_policy"):
+        pl.read_csv(
+            io.StringIO(csv),
+            schema=schema,
+            parse_error_policy={"b": "skip"},  # type: ignore[dict-item]
+        )
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("low_memory", [False, True])
+def test_read_csv_batched_parse_error_policy(tmp_path: Path, low_memory: bool) -> None:
+    path = tmp_path / "data.csv"
+    path.write_text("a,b\n1,x\nfoo,2\n3,bar\n")
+    schema = {"a": pl.Int64, "b": pl.Int64}
+    reader = pl.read_csv_batched(
+        path,
+        dtypes=schema,
+        parse_error_policy={"a": "sidecar", "b": "null"},
+        low_memory=low_memory,
+        batch_size=1,
+    )
+    batches = []
+    while (next_batches := reader.next_batches(2)) is not None:
+        batches.extend(next_batches)
+    expected = pl.DataFrame(
+        {"a": [1, None, 3], "b": [None, 2, None], "a_raw": [None, "foo", None]},
+        schema={**schema, "a_raw": pl.String},
+    )
+    assert_frame_equal(pl.concat(batches), expected)
+
+
 def test_custom_writable_object() -> None:
     df = pl.DataFrame({"a": [10, 20, 30], "b": ["x", "y", "z"]})
 
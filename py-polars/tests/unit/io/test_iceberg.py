@@ -95,6 +95,16 @@ def test_scan_iceberg_filter_on_column(iceberg_path: str) -> None:
     ]
 
 
+@pytest.mark.write_disk()
+def test_scan_iceberg_snapshot(iceberg_path: str) -> None:
+    lf = pl.scan_iceberg(iceberg_path, snapshot_id=7051579356916758811)
+    assert lf.collect()["id"].sort().to_list() == [1, 2, 3]
+    assert lf.head(2).collect().height == 2
+
+    with pytest.raises(pl.ComputeError, match="has no snapshot 1"):
+        pl.scan_iceberg(iceberg_path, snapshot_id=1)
+
+
 def test_is_null_expression() -> None:
     from pyiceberg.expressions import IsNull
 
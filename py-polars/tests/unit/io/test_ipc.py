@@ -137,6 +137,23 @@ def test_compressed_simple(compression: IpcCompression, stream: bool) -> None:
     assert_frame_equal(df_read, df)
 
 
+def test_write_ipc_compression_level() -> None:
+    df = pl.DataFrame({"a": list(range(1000)) * 10, "b": ["foo", "bar"] * 5000})
+
+    for level in [1, 22]:
+        f = io.BytesIO()
+        df.write_ipc(f, compression="zstd", compression_level=level)
+        f.seek(0)
+        assert_frame_equal(pl.read_ipc(f, use_pyarrow=False), df)
+
+    with pytest.raises(ValueError, match="1 to 22"):
+        df.write_ipc(io.BytesIO(), compression="zstd", compression_level=23)
+    with pytest.raises(ValueError, match="compression levels"):
+        df.write_ipc(io.BytesIO(), compression="lz4", compression_level=3)
+    with pytest.raises(ValueError, match="compression levels"):
+        df.write_ipc(io.BytesIO(), compression_level=3)
+
+
 @pytest.mark.parametrize("compression", COMPRESSIONS)
 def test_ipc_schema(compression: IpcCompression) -> None:
     df = pl.DataFrame({"a": [1, 2], "b": ["a", None], "c": [True, False]})
@@ -419,3 +419,24 @@ def test_json_enum(pretty: bool) -> None:
     buf.seek(0)
     df_in = pl.read_json(buf)
     assert df_in.schema["e"] == dtype
+
+
+def test_serialize_rows_json() -> None:
+    df = pl.DataFrame(
+        {
+            "b": [1, None],
+            "a": ["x", None],
+            "date": [datetime.date(2020, 1, 31), None],
+        }
+    )
+    result = df.serialize_rows_json()
+    assert result.dtype == pl.String
+    assert result.to_list() == [
+        '{"b":1,"a":"x","date":"2020-01-31"}',
+        '{"b":null,"a":null,"date":null}',
+    ]
+
+    result = df.serialize_rows_json(
+        date_format="%Y%m%d", skip_nulls=True, sort_keys=True
+    )
+    assert result.to_list() == ['{"a":"x","b":1,"date":"20200131"}', "{}"]
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+lance = pytest.importorskip("lance")
+
+
+@pytest.fixture()
+def lance_path(tmp_path: Path) -> Path:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "label": ["a", "b", None, "d", "e"],
+            "vector": [[0.0, 1.0], [1.0, 0.0], [1.0, 1.0], [0.5, 0.5], [0.0, 0.0]],
+        }
+    )
+    path = tmp_path / "dataset.lance"
+    # write several fragments, which are read ahead concurrently
+    lance.write_dataset(df.to_arrow(), path, max_rows_per_file=2)
+    return path
+
+
+@pytest.mark.write_disk()
+def test_scan_lance(lance_path: Path) -> None:
+    ldf = pl.scan_lance(lance_path)
+    assert ldf.columns == ["id", "label", "vector"]
+
+    expected = pl.DataFrame({"id": [1, 2, 3, 4, 5]})
+    assert_frame_equal(ldf.select("id").collect(), expected)
+    out = pl.scan_lance(lance_path, parallel=False).select("id").collect()
+    assert_frame_equal(out, expected)
+    out = pl.scan_lance(lance_path, batch_size=1).select("id").collect()
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.write_disk()
+def test_scan_lance_pushdown(lance_path: Path) -> None:
+    ldf = pl.scan_lance(lance_path)
+
+    out = ldf.filter(pl.col("id") > 2).select("id", "label").collect()
+    expected = pl.DataFrame({"id": [3, 4, 5], "label": [None, "d", "e"]})
+    assert_frame_equal(out, expected)
+
+    assert ldf.head(3).collect().height == 3
+    assert ldf.filter(pl.col("id") > 10).collect().height == 0
+
+
+@pytest.mark.write_disk()
+def test_scan_lance_version(lance_path: Path) -> None:
+    lance.write_dataset(
+        pl.DataFrame({"id": [6], "label": ["f"], "vector": [[1.0, 2.0]]}).to_arrow(),
+        lance_path,
+        mode="append",
+    )
+    assert pl.scan_lance(lance_path).collect().height == 6
+    assert pl.scan_lance(lance_path, version=1).collect().height == 5
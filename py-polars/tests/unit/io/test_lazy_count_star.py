@@ -45,6 +45,18 @@ Gr1,B
     assert_frame_equal(lf.collect(), expected)
 
 
+@pytest.mark.write_disk()
+def test_count_csv_quoted_newlines(tmp_path: Path) -> None:
+    path = tmp_path / "quoted.csv"
+    path.write_text('a,b\n1,"x\ny"\n2,"z"\n3,""')
+
+    lf = pl.scan_csv(path).select(pl.len())
+
+    expected = pl.DataFrame(pl.Series("len", [3], dtype=pl.UInt32))
+    assert "FAST COUNT(*)" in lf.explain()
+    assert_frame_equal(lf.collect(), expected)
+
+
 @pytest.mark.parametrize(
     ("pattern", "n_rows"), [("small.parquet", 4), ("foods*.parquet", 54)]
 )
@@ -69,3 +81,16 @@ def test_count_ipc(io_files_path: Path, path: str, n_rows: int) -> None:
     # Check if we are using our fast count star
     assert "FAST COUNT(*)" in lf.explain()
     assert_frame_equal(lf.collect(), expected)
+
+
+@pytest.mark.parametrize(
+    ("path", "n_rows"), [("foods1.ndjson", 27), ("foods*.ndjson", 27 * 2)]
+)
+def test_count_ndjson(io_files_path: Path, path: str, n_rows: int) -> None:
+    lf = pl.scan_ndjson(io_files_path / path).select(pl.len())
+
+    expected = pl.DataFrame(pl.Series("len", [n_rows], dtype=pl.UInt32))
+
+    # Check if we are using our fast count star
+    assert "FAST COUNT(*)" in lf.explain()
+    assert_frame_equal(lf.collect(), expected)
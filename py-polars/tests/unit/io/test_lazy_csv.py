@@ -259,6 +259,30 @@ def test_scan_csv_schema_overwrite_not_projected_8483(foods_file_path: Path) ->
     assert_frame_equal(df, expected)
 
 
+def test_scan_csv_parse_error_policy(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    file_path = tmp_path / "parse_errors.csv"
+    file_path.write_text("a,b\n1,x\nfoo,2\n")
+
+    lf = pl.scan_csv(
+        file_path,
+        dtypes={"a": pl.Int64, "b": pl.Int64},
+        parse_error_policy={"a": "sidecar", "b": "null"},
+    )
+    expected = pl.DataFrame(
+        {"a": [1, None], "b": [None, 2], "a_raw": [None, "foo"]},
+        schema={"a": pl.Int64, "b": pl.Int64, "a_raw": pl.String},
+    )
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(lf.select("a_raw").collect(), expected.select("a_raw"))
+
+    lf = pl.scan_csv(
+        file_path, dtypes={"a": pl.Int64}, parse_error_policy={"a": "raise"}
+    )
+    with pytest.raises(pl.ComputeError):
+        lf.collect()
+
+
 def test_csv_list_arg(io_files_path: Path) -> None:
     first = io_files_path / "foods1.csv"
     second = io_files_path / "foods2.csv"
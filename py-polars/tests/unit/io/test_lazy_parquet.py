@@ -274,6 +274,42 @@ def test_parquet_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> Non
     )
 
 
+@pytest.mark.write_disk()
+def test_parquet_statistics_struct_field(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    df = pl.DataFrame({"idx": pl.arange(0, 100, eager=True)}).select(
+        pl.struct(
+            pl.col("idx").alias("x"),
+            pl.struct((pl.col("idx") // 25).alias("part")).alias("inner"),
+        ).alias("s")
+    )
+    df = pl.concat([df.slice(i, 25) for i in range(0, 100, 25)], rechunk=False)
+
+    file_path = tmp_path / "stats.parquet"
+    df.write_parquet(file_path, statistics=True, use_pyarrow=False)
+
+    for pred in [
+        pl.col("s").struct.field("x") < 50,
+        pl.col("s").struct.field("x").is_between(30, 40),
+        pl.col("s").struct.field("inner").struct.field("part") == 3,
+        pl.col("s").struct.field("x").is_null(),
+    ]:
+        result = pl.scan_parquet(file_path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+    captured = capfd.readouterr().err
+    assert "parquet file must be read" in captured
+    assert (
+        "parquet file can be skipped, the statistics were sufficient"
+        " to apply the predicate." in captured
+    )
+
+
 @pytest.mark.write_disk()
 def test_categorical(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -432,3 +468,213 @@ def test_scan_deadlock_rayon_spawn_from_async_15172(
     t.join(5)
 
     assert results[0].equals(df)
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("streaming", [False, True])
+@pytest.mark.parametrize("force_async", [False, True])
+def test_scan_parquet_nested_projection(
+    monkeypatch: Any, tmp_path: Path, streaming: bool, force_async: bool
+) -> None:
+    if force_async:
+        monkeypatch.setenv("POLARS_FORCE_ASYNC", "1")
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "s": [
+                {"a": 1, "b": "x", "inner": {"c": 1.0, "d": [1]}},
+                {"a": 2, "b": None, "inner": {"c": 2.0, "d": []}},
+                None,
+            ],
+            "l": [[{"e": 1}], [], None],
+        }
+    )
+    df.write_parquet(path)
+
+    q = (
+        pl.scan_parquet(path)
+        .filter(pl.col("s").struct.field("a") > 1)
+        .select(
+            pl.col("s").struct.field("inner").struct.field("c"),
+            pl.col("s").struct.field("b"),
+            pl.col("l"),
+        )
+    )
+    expected = pl.DataFrame({"c": [2.0], "b": [None], "l": [[]]}).cast(
+        {"b": pl.String, "l": pl.List(pl.Struct({"e": pl.Int64}))}
+    )
+    assert_frame_equal(q.collect(streaming=streaming), expected)
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("readahead_bytes", [None, "1"])
+def test_scan_parquet_file_readahead(
+    monkeypatch: Any, tmp_path: Path, readahead_bytes: str | None
+) -> None:
+    monkeypatch.setenv("POLARS_FORCE_ASYNC", "1")
+    monkeypatch.setenv("POLARS_PREFETCH_SIZE", "2")
+    monkeypatch.setenv("POLARS_FILE_READAHEAD_SIZE", "3")
+    if readahead_bytes is not None:
+        monkeypatch.setenv("POLARS_FILE_READAHEAD_BYTES", readahead_bytes)
+
+    dfs = [
+        pl.DataFrame({"x": range(i * 10, (i + 1) * 10), "y": [str(i)] * 10})
+        for i in range(7)
+    ]
+    for i, df in enumerate(dfs):
+        # The first row group is prefetched, the others are read as usual.
+        df.write_parquet(tmp_path / f"{i}.parquet", row_group_size=4)
+
+    expected = pl.concat(dfs).with_row_index()
+    q = pl.scan_parquet(tmp_path / "*.parquet").with_row_index()
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(q.head(25).collect(), expected.head(25))
+
+    # Only the projected columns are prefetched.
+    q = pl.scan_parquet(tmp_path / "*.parquet").filter(pl.col("x") % 3 == 0)
+    assert_frame_equal(
+        q.select("x").collect(), expected.filter(pl.col("x") % 3 == 0).select("x")
+    )
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("parallel", ["columns", "row_groups", "none"])
+def test_scan_parquet_late_materialization(
+    tmp_path: Path, parallel: ParallelStrategy
+) -> None:
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame(
+        {
+            "a": range(1000),
+            "b": [str(i) for i in range(1000)],
+            "c": [[i] for i in range(1000)],
+        }
+    )
+    df.write_parquet(path, row_group_size=100)
+
+    for pred in [
+        pl.col("a") % 250 == 3,
+        pl.col("a") > 2000,
+        (pl.col("a") < 150) & pl.col("b").str.ends_with("7"),
+        pl.col("a").is_in([5, 512]) | pl.col("b").is_null(),
+    ]:
+        q = pl.scan_parquet(path, parallel=parallel, row_index_name="index")
+        q = q.filter(pred)
+        assert_frame_equal(q.collect(), df.with_row_index().filter(pred))
+        assert_frame_equal(
+            q.select("c", "a").collect(), df.filter(pred).select("c", "a")
+        )
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("parallel", ["columns", "row_groups", "none"])
+def test_scan_parquet_page_index(tmp_path: Path, parallel: ParallelStrategy) -> None:
+    path = tmp_path / "data.parquet"
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "a": range(n),
+            "b": [f"{i:05}" for i in range(n)],
+            "c": [float(i % 7) for i in range(n)],
+            "d": [[i] for i in range(n)],
+        }
+    )
+    # Small pages, so that the row groups have many pages.
+    df.write_parquet(path, row_group_size=5_000, data_page_size=1_024)
+
+    for pred in [
+        pl.col("a") == 1234,
+        pl.col("a").is_between(4_990, 5_010),
+        (pl.col("a") > 9_000) & (pl.col("c") == 3.0),
+        pl.col("b") < "00100",
+        (pl.col("a") < 10) | (pl.col("a") > 9_990),
+        pl.col("c") > 10.0,
+    ]:
+        q = pl.scan_parquet(path, parallel=parallel, row_index_name="index")
+        q = q.filter(pred)
+        assert_frame_equal(q.collect(), df.with_row_index().filter(pred))
+        assert_frame_equal(
+            q.select("d", "a").collect(), df.filter(pred).select("d", "a")
+        )
+
+
+@pytest.mark.write_disk()
+def test_scan_parquet_bloom_filters(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    path = tmp_path / "data.parquet"
+    n = 1_000
+    # Only even values, so that the odd values are within the min and max values of
+    # every row group, but not in its bloom filter.
+    df = pl.DataFrame(
+        {
+            "a": [2 * i for i in range(n)],
+            "b": [f"{2 * i:05}" for i in range(n)],
+            "c": pl.Series([f"{i % 10}" for i in range(n)], dtype=pl.Categorical),
+        }
+    )
+    df.write_parquet(path, row_group_size=250, bloom_filters=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    for pred in [
+        pl.col("a") == 501,
+        pl.col("a") == 500,
+        pl.col("b") == "00333",
+        pl.col("b") == "00334",
+        pl.col("a").is_in([101, 303, 505]),
+        pl.col("a").is_in([101, 304]),
+        pl.col("c") == "3",
+    ]:
+        result = pl.scan_parquet(path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+    captured = capfd.readouterr().err
+    assert (
+        "parquet file can be skipped, the statistics were sufficient"
+        " to apply the predicate." in captured
+    )
+
+
+@pytest.mark.write_disk()
+def test_sink_parquet_bloom_filters(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame({"a": range(0, 200, 2), "b": [[i] for i in range(100)]})
+    df.lazy().sink_parquet(path, row_group_size=10, bloom_filters=True)
+
+    for pred in [pl.col("a") == 51, pl.col("a") == 50]:
+        result = pl.scan_parquet(path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+
+@pytest.mark.write_disk()
+def test_sink_parquet_partitioned_statistics_file(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch, capfd: Any
+) -> None:
+    df = pl.DataFrame({"year": [2023, 2024, 2023, 2024], "value": [1, 2, 3, 4]})
+    df.lazy().sink_parquet_partitioned(
+        tmp_path, "year", max_rows_per_file=1, write_statistics_file=True
+    )
+    assert (tmp_path / "_statistics").is_file()
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    result = (
+        pl.scan_parquet(tmp_path / "**" / "*.parquet", hive_partitioning=True)
+        .filter(pl.col("value") > 2)
+        .collect()
+    )
+    assert_frame_equal(
+        result.sort("value"), df.filter(pl.col("value") > 2), check_column_order=False
+    )
+    assert "skipped 2 of 4 parquet files" in capfd.readouterr().err
+
+
+@pytest.mark.write_disk()
+def test_sink_parquet_partitioned_max_open_files(tmp_path: Path) -> None:
+    df = pl.DataFrame({"key": [i % 4 for i in range(400)], "value": range(400)})
+    df.lazy().sink_parquet_partitioned(
+        tmp_path, "key", row_group_size=10, max_open_files=1
+    )
+
+    result = pl.scan_parquet(tmp_path / "**" / "*.parquet", hive_partitioning=True)
+    assert_frame_equal(result.collect().sort("value"), df, check_column_order=False)
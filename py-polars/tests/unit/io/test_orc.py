@@ -0,0 +1,108 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+orc = pytest.importorskip("pyarrow.orc")
+
+
+@pytest.fixture()
+def orc_table() -> pa.Table:
+    n = 10_000
+    return pa.table(
+        {
+            # Runs, deltas and outliers exercise all of the run length encodings.
+            "int": pa.array(
+                [i // 7 if i % 1000 else 1 << 40 for i in range(n)], pa.int64()
+            ),
+            "small": pa.array([i % 100 - 50 for i in range(n)], pa.int16()),
+            "float": pa.array([i / 3 for i in range(n)], pa.float64()),
+            "bool": pa.array([i % 3 == 0 for i in range(n)]),
+            # Few distinct values, which are dictionary encoded.
+            "str": pa.array(
+                [None if i % 11 == 0 else f"value_{i % 13}" for i in range(n)]
+            ),
+            "date": pa.array([date(2020, 1, 1 + i % 28) for i in range(n)]),
+            "datetime": pa.array(
+                [datetime(1969, 12, 31, 23, 59, 59, i % 1000 * 1000) for i in range(n)],
+                pa.timestamp("ns"),
+            ),
+            "instant": pa.array(
+                [datetime(2024, 3, 1, i % 24) for i in range(n)],
+                pa.timestamp("ns", tz="UTC"),
+            ),
+            "decimal": pa.array(
+                [Decimal(f"{i}.{i % 100:02}") for i in range(n)], pa.decimal128(10, 2)
+            ),
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    "compression", ["uncompressed", "zlib", "snappy", "lz4", "zstd"]
+)
+@pytest.mark.parametrize("file_version", ["0.11", "0.12"])
+@pytest.mark.write_disk()
+def test_read_orc(
+    orc_table: pa.Table, compression: str, file_version: str, tmp_path: Path
+) -> None:
+    path = tmp_path / "data.orc"
+    orc.write_table(
+        orc_table,
+        path,
+        compression=compression,
+        file_version=file_version,
+        stripe_size=64 * 1024,
+        dictionary_key_size_threshold=1.0,
+    )
+    expected = pl.from_arrow(orc_table)
+    assert isinstance(expected, pl.DataFrame)
+
+    assert_frame_equal(pl.read_orc(path), expected)
+    assert_frame_equal(pl.read_orc(path, parallel=False), expected)
+    assert_frame_equal(
+        pl.read_orc(path, columns=["str", "int"], n_rows=10),
+        expected.select("str", "int").head(10),
+    )
+
+
+@pytest.mark.write_disk()
+def test_scan_orc(orc_table: pa.Table, tmp_path: Path) -> None:
+    path = tmp_path / "data.orc"
+    orc.write_table(orc_table, path, stripe_size=64 * 1024)
+    expected = pl.from_arrow(orc_table)
+    assert isinstance(expected, pl.DataFrame)
+
+    lf = pl.scan_orc(path, row_index_name="index")
+    assert lf.schema["instant"] == pl.Datetime("ns", "UTC")
+    assert lf.schema["decimal"] == pl.Decimal(10, 2)
+    result = lf.filter(pl.col("int") > 1000).select("index", "str").collect()
+    assert_frame_equal(
+        result,
+        expected.with_row_index("index")
+        .filter(pl.col("int") > 1000)
+        .select("index", "str"),
+    )
+
+
+@pytest.mark.write_disk()
+def test_orc_unsupported_column(tmp_path: Path) -> None:
+    path = tmp_path / "data.orc"
+    table = pa.table({"a": [1, 2], "b": [[1], [2, 3]]})
+    orc.write_table(table, path)
+
+    result = pl.scan_orc(path).select("a").collect()
+    assert_frame_equal(result, pl.DataFrame({"a": [1, 2]}))
+    with pytest.raises(pl.ComputeError, match="column 'b' is not supported"):
+        pl.read_orc(path)
+
@@ -778,6 +778,41 @@ def test_parquet_string_rle_encoding() -> None:
     )
 
 
+def test_write_parquet_column_options() -> None:
+    df = pl.DataFrame(
+        {
+            "ints": range(1000),
+            "floats": [i / 3 for i in range(1000)],
+            "strings": [f"s{i % 10}" for i in range(1000)],
+        }
+    )
+    f = io.BytesIO()
+    df.write_parquet(
+        f,
+        column_options={
+            "ints": {"encoding": "delta_binary_packed"},
+            "floats": {"encoding": "byte_stream_split"},
+            "strings": {"dictionary": False, "compression": "uncompressed"},
+        },
+    )
+    f.seek(0)
+    columns = pq.read_metadata(f).row_group(0)
+    assert "DELTA_BINARY_PACKED" in columns.column(0).encodings
+    assert "BYTE_STREAM_SPLIT" in columns.column(1).encodings
+    assert "RLE_DICTIONARY" not in columns.column(2).encodings
+    assert columns.column(2).compression == "UNCOMPRESSED"
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    invalid = {"ints": {"encoding": "rle"}}
+    with pytest.raises(ValueError, match="parquet `encoding` must be one of"):
+        df.write_parquet(io.BytesIO(), column_options=invalid)  # type: ignore[arg-type]
+    with pytest.raises(pl.InvalidOperationError, match="can only be plain encoded"):
+        pl.DataFrame({"a": [[1, 2], [3]]}).write_parquet(
+            io.BytesIO(), column_options={"a": {"encoding": "delta_binary_packed"}}
+        )
+
+
 def test_sliced_dict_with_nulls_14904() -> None:
     df = (
         pl.DataFrame({"x": [None, None]})
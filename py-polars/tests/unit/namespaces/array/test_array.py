@@ -449,3 +449,36 @@ def test_array_n_unique() -> None:
         {"n_unique": [2, 1, 1, None]}, schema={"n_unique": pl.UInt32}
     )
     assert_frame_equal(out, expected)
+
+
+def test_arr_dot_matmul() -> None:
+    s = pl.Series("a", [[1, 2], None, [3, 4]], dtype=pl.Array(pl.Int64, 2))
+    ones = pl.Series([[1, 1]], dtype=pl.Array(pl.Int64, 2))
+    assert s.arr.dot(ones).to_list() == [3.0, None, 7.0]
+    assert s.arr.dot(s).to_list() == [5.0, None, 25.0]
+
+    matrix = pl.Series(
+        [[[1, 0], [0, 1], [1, 1]]], dtype=pl.Array(pl.Array(pl.Int64, 2), 3)
+    )
+    out = s.arr.matmul(matrix)
+    assert out.dtype == pl.Array(pl.Float64, 3)
+    assert out.to_list() == [[1.0, 2.0, 3.0], None, [3.0, 4.0, 7.0]]
+
+    with pytest.raises(pl.ShapeError):
+        s.arr.dot(pl.Series([[1, 1, 1]], dtype=pl.Array(pl.Int64, 3)))
+
+
+def test_arr_reduce_axis() -> None:
+    s = pl.Series(
+        "a",
+        [[[1, 2], [3, None]], None],
+        dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+    )
+    assert s.arr.sum_axis(0).to_list() == [[4.0, 2.0], None]
+    assert s.arr.sum_axis(1).to_list() == [[3.0, 3.0], None]
+    assert s.arr.mean_axis(0).to_list() == [[2.0, 2.0], None]
+    assert s.arr.min_axis(1).to_list() == [[1.0, 3.0], None]
+    assert s.arr.max_axis(0).to_list() == [[3.0, 2.0], None]
+
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series([[1, 2]], dtype=pl.Array(pl.Int64, 2)).arr.sum_axis(0)
@@ -329,6 +329,29 @@ def test_list_diff() -> None:
     assert s.list.diff().to_list() == expected.to_list()
 
 
+def test_list_rolling() -> None:
+    s = pl.Series("a", [[1, 2, 3], None, [4, None, 6]])
+    assert s.list.rolling_sum(2).to_list() == [[None, 3, 5], None, [None, None, None]]
+    assert s.list.rolling_mean(2, min_periods=1).to_list() == [
+        [1.0, 1.5, 2.5],
+        None,
+        [4.0, 4.0, 6.0],
+    ]
+    assert s.list.rolling_min(3, min_periods=1).to_list() == [
+        [1, 1, 1],
+        None,
+        [4, 4, 4],
+    ]
+    assert s.list.rolling_max(3, min_periods=1).to_list() == [
+        [1, 2, 3],
+        None,
+        [4, 4, 6],
+    ]
+
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series([["a"]]).list.rolling_sum(2)
+
+
 def test_slice() -> None:
     vals = [[1, 2, 3, 4], [10, 2, 1]]
     s = pl.Series("a", vals)
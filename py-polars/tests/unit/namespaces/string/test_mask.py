@@ -0,0 +1,60 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+
+def test_str_mask_except_last() -> None:
+    s = pl.Series("a", ["4111-1111-1111-1111", "ñandú", "abc", "", None])
+
+    assert_series_equal(
+        s.str.mask_except_last(4),
+        pl.Series("a", ["***************1111", "*andú", "abc", "", None]),
+    )
+    assert_series_equal(
+        s.str.mask_except_last(0, "#"),
+        pl.Series("a", ["###################", "#####", "###", "", None]),
+    )
+
+
+def test_str_tokenize_format_preserving() -> None:
+    s = pl.Series("a", ["4111-1111-1111-1111", "Ab-12 ñ", "4111-1111-1111-1111", None])
+
+    out = s.str.tokenize_format_preserving("key")
+    assert out[0] == out[2]
+    assert out[0] != s[0]
+    assert out.str.contains(r"^\d{4}-\d{4}-\d{4}-\d{4}$")[0]
+    assert out.str.contains(r"^[A-Z][a-z]-\d{2} ñ$")[1]
+    assert out[3] is None
+
+    assert_series_equal(out, s.str.tokenize_format_preserving("key"))
+    assert out[0] != s.str.tokenize_format_preserving("other key")[0]
+
+
+def test_str_pseudonymize() -> None:
+    df = pl.DataFrame({"email": ["a@x.com", "b@x.com", "a@x.com", None]})
+
+    out = df.select(
+        pl.col("email").str.pseudonymize("key").alias("a"),
+        pl.col("email").str.pseudonymize(b"other key").alias("b"),
+    )
+    assert out["a"][0] == out["a"][2]
+    assert out["a"].n_unique() == 3
+    assert out["a"].drop_nulls().str.contains(r"^[0-9a-f]{64}$").all()
+    assert out["a"][0] != out["b"][0]
+    assert out["a"][3] is None
+
+    # Test case 2 of RFC 4231.
+    s = pl.Series(["what do ya want for nothing?"])
+    assert (
+        s.str.pseudonymize(b"Jefe")[0]
+        == "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    )
+
+
+def test_str_pseudonymize_key_not_serialized() -> None:
+    lf = pl.LazyFrame({"a": ["x"]}).select(pl.col("a").str.pseudonymize("key"))
+    with pytest.raises(ValueError, match="key is not serialized"):
+        lf.serialize()
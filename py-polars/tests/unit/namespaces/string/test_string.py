@@ -1158,6 +1158,64 @@ def test_extract_groups() -> None:
     ).to_dict(as_series=False) == {"literal": [{"foo": "foo", "bar": None}]}
 
 
+def test_extract_pattern() -> None:
+    s = pl.Series(
+        "log",
+        [
+            '127.0.0.1 - bob [10/Oct/2000:13:55:36 -0700] "GET /a HTTP/1.0" 200 2326',
+            '::1 - - [10/Oct/2000:13:55:37 -0700] "POST /form HTTP/1.1" 404 -',
+            "not a log line",
+            None,
+        ],
+    )
+
+    out = s.str.extract_pattern("%{COMMONAPACHELOG}").struct.unnest()
+    assert out.columns == [
+        "clientip",
+        "ident",
+        "auth",
+        "timestamp",
+        "verb",
+        "request",
+        "httpversion",
+        "rawrequest",
+        "response",
+        "bytes",
+    ]
+    assert out.select("clientip", "verb", "request", "response", "bytes").rows() == [
+        ("127.0.0.1", "GET", "/a", 200, 2326),
+        ("::1", "POST", "/form", 404, None),
+        (None, None, None, None, None),
+        (None, None, None, None, None),
+    ]
+    assert out["response"].dtype == pl.Int64
+
+
+def test_extract_pattern_custom_and_named_groups() -> None:
+    df = pl.DataFrame({"a": ["id=7 ratio=0.5 tag=x", "id=8 ratio=1 tag=y"]})
+
+    out = df.select(
+        pl.col("a").str.extract_pattern(
+            r"id=%{ID:id:int} ratio=%{NUMBER:ratio:float} tag=(?<tag>\w)",
+            custom_patterns={"ID": "%{POSINT}"},
+        )
+    ).unnest("a")
+    expected = pl.DataFrame({"id": [7, 8], "ratio": [0.5, 1.0], "tag": ["x", "y"]})
+    assert_frame_equal(out, expected)
+
+
+def test_extract_pattern_errors() -> None:
+    s = pl.Series(["a"])
+    with pytest.raises(pl.ComputeError, match="unknown grok pattern"):
+        s.str.extract_pattern("%{NOPE:a}")
+    with pytest.raises(pl.ComputeError, match="unknown grok type"):
+        s.str.extract_pattern("%{WORD:a:bool}")
+    with pytest.raises(pl.ComputeError, match="recursive"):
+        s.str.extract_pattern("%{A}", custom_patterns={"A": "%{A}"})
+    with pytest.raises(pl.DuplicateError):
+        s.str.extract_pattern("%{WORD:a} %{WORD:a}")
+
+
 def test_starts_ends_with() -> None:
     df = pl.DataFrame(
         {
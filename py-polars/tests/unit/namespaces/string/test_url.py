@@ -0,0 +1,78 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_str_url_encode_decode() -> None:
+    s = pl.Series("a", ["café au lait", "a/b?c=d&e", "-_.~", "", None])
+
+    encoded = s.str.url_encode()
+    assert_series_equal(
+        encoded,
+        pl.Series(
+            "a", ["caf%C3%A9%20au%20lait", "a%2Fb%3Fc%3Dd%26e", "-_.~", "", None]
+        ),
+    )
+    assert_series_equal(encoded.str.url_decode(), s)
+
+
+def test_str_url_decode_malformed() -> None:
+    s = pl.Series("a", ["100%", "%zz", "%4", "a+b", "%FF"])
+    assert_series_equal(
+        s.str.url_decode(),
+        pl.Series("a", ["100%", "%zz", "%4", "a+b", None]),
+    )
+
+
+def test_str_parse_url() -> None:
+    df = pl.DataFrame(
+        {
+            "url": [
+                "https://user:pw@example.com:8080/a/b?q=1&r=2#top",
+                "http://[::1]:80/",
+                "file:///tmp/x.csv",
+                "example",
+                None,
+            ]
+        }
+    )
+    out = df.select(pl.col("url").str.parse_url()).unnest("url")
+
+    expected = pl.DataFrame(
+        {
+            "scheme": ["https", "http", "file", None, None],
+            "host": ["example.com", "[::1]", None, None, None],
+            "port": [8080, 80, None, None, None],
+            "path": ["/a/b", "/", "/tmp/x.csv", "example", None],
+            "query": ["q=1&r=2", None, None, None, None],
+            "fragment": ["top", None, None, None, None],
+        },
+        schema_overrides={"port": pl.UInt16},
+    )
+    assert_frame_equal(out, expected)
+
+    # An empty port is missing, an invalid one is an error.
+    out = pl.Series(["http://example.com:/"]).str.parse_url()
+    assert out.struct.field("port").to_list() == [None]
+    for url in ["http://example.com:http/", "http://example.com:70000/"]:
+        with pytest.raises(pl.ComputeError, match="invalid port"):
+            pl.Series([url]).str.parse_url()
+
+
+def test_str_parse_query_string() -> None:
+    df = pl.DataFrame({"q": ["?a=1&b=x+y%21&&c", "", None]})
+    out = df.select(pl.col("q").str.parse_query_string())
+
+    assert out.schema["q"] == pl.List(pl.Struct({"key": pl.String, "value": pl.String}))
+    assert out["q"].to_list() == [
+        [
+            {"key": "a", "value": "1"},
+            {"key": "b", "value": "x y!"},
+            {"key": "c", "value": None},
+        ],
+        [],
+        None,
+    ]
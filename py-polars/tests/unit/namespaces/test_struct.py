@@ -1,8 +1,11 @@
 from __future__ import annotations
 
 import datetime
+import json
 from collections import OrderedDict
 
+import pytest
+
 import polars as pl
 from polars.testing import assert_frame_equal
 
@@ -64,6 +67,60 @@ def test_struct_json_encode_logical_type() -> None:
     }
 
 
+def test_struct_json_encode_options() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [
+                {
+                    "z": datetime.date(1997, 1, 1),
+                    "b": datetime.datetime(2000, 1, 29, 10, 30),
+                    "c": {"y": None, "x": 1},
+                },
+                {"z": None, "b": None, "c": None},
+            ]
+        }
+    )
+    assert df.select(
+        pl.col("a").struct.json_encode(
+            date_format="%d/%m/%Y",
+            datetime_format="%Y-%m-%dT%H:%M",
+            skip_nulls=True,
+            sort_keys=True,
+        )
+    ).to_series().to_list() == [
+        '{"b":"2000-01-29T10:30","c":{"x":1},"z":"01/01/1997"}',
+        "{}",
+    ]
+    assert df["a"].struct.json_encode().to_list() == [
+        '{"z":"1997-01-01","b":"2000-01-29 10:30:00","c":{"y":null,"x":1}}',
+        '{"z":null,"b":null,"c":null}',
+    ]
+
+    # The literal characters of a format are escaped.
+    assert df["a"].struct.json_encode(date_format='"%Y"').to_list()[0].startswith(
+        '{"z":"\\"1997\\"",'
+    )
+
+    with pytest.raises(pl.ComputeError, match="cannot format NaiveDateTime"):
+        df["a"].struct.json_encode(datetime_format="%Y %z")
+
+
+def test_struct_json_encode_escape() -> None:
+    values = [
+        "",
+        "plain",
+        "a string longer than eight bytes without escapes",
+        'quote " and backslash \\ in a longer string',
+        "control \x00\x01\x08\t\n\x0c\r\x1f\x7f characters",
+        "unicode: é 🐻‍❄️ \u2028",
+        "x" * 15 + "\n",
+    ]
+    result = pl.DataFrame({"s": values}).to_struct("a").struct.json_encode()
+    assert result.to_list() == [
+        json.dumps({"s": v}, ensure_ascii=False, separators=(",", ":")) for v in values
+    ]
+
+
 def test_map_fields() -> None:
     df = pl.DataFrame({"x": {"a": 1, "b": 2}})
     assert df.schema == OrderedDict([("x", pl.Struct({"a": pl.Int64, "b": pl.Int64}))])
@@ -35,6 +35,25 @@ def test_map_groups_lazy() -> None:
     assert result.schema == expected.schema
 
 
+def test_map_groups_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": [2, 1, 2, 3], "b": [1.0, 2.0, 3.0, 4.0]})
+
+    result = (
+        lf.group_by("a", maintain_order=True)
+        .map_groups(lambda df: df.sum(), schema=None)
+        .collect()
+    )
+    expected = pl.DataFrame({"a": [4, 1, 3], "b": [4.0, 2.0, 4.0]})
+    assert_frame_equal(result, expected)
+
+    result = lf.group_by("a").map_groups(lambda df: df.select("b"), schema=None)
+    assert result.collect().columns == ["b"]
+    with pytest.raises(pl.SchemaError, match="map_groups"):
+        lf.group_by("a").map_groups(
+            lambda df: df.select("b"), schema=None, validate_output_schema=True
+        ).collect()
+
+
 def test_map_groups_rolling() -> None:
     df = pl.DataFrame(
         {
@@ -986,3 +986,35 @@ def test_temporal_windows_size_without_by_15977() -> None:
         pl.InvalidOperationError, match="the `by` argument must be passed"
     ):
         df.select(pl.col("a").rolling_mean("3d"))
+
+
+@pytest.mark.parametrize("closed", ["left", "right", "both", "none"])
+def test_rolling_count_and_n_unique_by(closed: ClosedInterval) -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [
+                datetime(2021, 1, 1),
+                datetime(2021, 1, 2),
+                datetime(2021, 1, 2),
+                datetime(2021, 1, 4),
+                datetime(2021, 1, 5),
+                datetime(2021, 1, 9),
+            ],
+            "values": [1, 2, None, 1, 3, 3],
+        }
+    ).set_sorted("dt")
+
+    result = df.select(
+        pl.col("values").rolling_count_by("dt", "3d", closed=closed).alias("count"),
+        pl.col("values")
+        .rolling_n_unique_by("dt", "3d", closed=closed)
+        .alias("n_unique"),
+    )
+    expected = df.rolling("dt", period="3d", closed=closed).agg(
+        pl.col("values").count().alias("count"),
+        pl.col("values").n_unique().alias("n_unique"),
+    )
+    assert_frame_equal(result, expected.select("count", "n_unique"), check_dtype=False)
+
+    result = df.select(pl.col("values").rolling_n_unique_by("dt", "3d", min_periods=2))
+    assert result["values"].to_list() == [None, 3, 3, 3, 2, None]
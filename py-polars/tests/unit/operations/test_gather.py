@@ -1,4 +1,7 @@
+import pytest
+
 import polars as pl
+from polars.testing import assert_frame_equal
 
 
 def test_negative_index() -> None:
@@ -32,3 +35,54 @@ def test_gather_agg_schema() -> None:
         .schema["value"]
         == pl.Int64
     )
+
+
+def test_gather_out_of_bounds() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2], "a": [1, 2, 3, 4, 5]})
+
+    with pytest.raises(pl.OutOfBoundsError):
+        df.group_by("g").agg(pl.col("a").gather([0, 2]))
+
+    result = df.group_by("g", maintain_order=True).agg(
+        null=pl.col("a").gather([0, 2], out_of_bounds="null"),
+        clamp=pl.col("a").gather([-5, 2], out_of_bounds="clamp"),
+        scalar=pl.col("a").gather(2, out_of_bounds="null"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "null": [[1, 3], [4, None]],
+            "clamp": [[1, 3], [4, 5]],
+            "scalar": [[3], [None]],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.select(
+        pl.col("a").gather(pl.col("a") - 2, out_of_bounds="null").over("g")
+    )
+    assert result["a"].to_list() == [3, 1, 2, None, None]
+
+    result = df.select(pl.col("a").gather([0, 9], out_of_bounds="clamp"))
+    assert result["a"].to_list() == [1, 5]
+
+
+def test_gather_every_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 2, 1, 2, 1, 1], "a": [1, 2, 3, 4, 5, 6]})
+    result = df.group_by("g", maintain_order=True).agg(
+        every=pl.col("a").gather_every(2),
+        offset=pl.col("a").gather_every(2, offset=1),
+        sorted=pl.col("a").sort(descending=True).gather_every(3),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "every": [[1, 5], [2]],
+            "offset": [[3, 6], [4]],
+            "sorted": [[6, 1], [4]],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(pl.InvalidOperationError):
+        df.group_by("g").agg(pl.col("a").gather_every(0))
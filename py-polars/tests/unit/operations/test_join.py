@@ -151,6 +151,42 @@ def test_join_on_expressions() -> None:
     ].to_list() == [1, 4, 9, 9]
 
 
+def test_join_on_expressions_not_materialized() -> None:
+    df_a = pl.DataFrame({"a": [1, 2, 3], "x": ["p", "q", "r"]})
+    df_b = pl.DataFrame({"b": [2, 4, 6], "y": [10, 20, 30]})
+
+    # The key expressions are not added to the output, and don't replace the columns
+    # they are computed from.
+    result = df_a.lazy().join(
+        df_b.lazy(),
+        left_on=pl.col("a") * 2,
+        right_on=pl.col("b"),
+        how="left",
+        drop_key_expressions=True,
+    )
+    assert result.columns == ["a", "x", "y"]
+    expected = pl.DataFrame({"a": [1, 2, 3], "x": ["p", "q", "r"], "y": [10, 20, 30]})
+    assert_frame_equal(result.collect(), expected)
+
+    # The coalesced keys of an outer join are kept.
+    result = df_a.join(
+        df_b,
+        left_on=pl.col("a") * 2,
+        right_on="b",
+        how="outer_coalesce",
+        drop_key_expressions=True,
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2, 3],
+            "x": ["p", "q", "r"],
+            "a_right": [2, 4, 6],
+            "y": [10, 20, 30],
+        }
+    )
+    assert_frame_equal(result.sort("a"), expected)
+
+
 def test_join() -> None:
     df_left = pl.DataFrame(
         {
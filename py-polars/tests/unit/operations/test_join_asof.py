@@ -1,5 +1,7 @@
+from __future__ import annotations
+
 from datetime import date, datetime, timedelta
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 import numpy as np
 import pytest
@@ -7,6 +9,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from polars.type_aliases import AsofJoinStrategy
+
 
 def test_asof_join_singular_right_11966() -> None:
     df = pl.DataFrame({"id": [1, 2, 3], "time": [0.9, 2.1, 2.8]}).sort("time")
@@ -1165,3 +1170,89 @@ def test_join_asof_invalid_args() -> None:
         TypeError, match="expected `right_on` to be str or Expr, got 'list'"
     ):
         df1.join_asof(df2, left_on="a", right_on=["a"])  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    ("strategy", "expected"),
+    [
+        ("backward", [None, 10, 20, 30]),
+        ("forward", [20, 30, None, None]),
+        ("nearest", [20, 30, 20, 30]),
+    ],
+)
+def test_join_asof_disallow_exact_matches(
+    strategy: AsofJoinStrategy, expected: list[int | None]
+) -> None:
+    left = pl.DataFrame({"a": [1, 2, 3, 4], "g": ["x", "x", "x", "x"]}).set_sorted("a")
+    right = pl.DataFrame(
+        {"a": [1, 2, 3], "g": ["x", "x", "x"], "b": [10, 20, 30]}
+    ).set_sorted("a")
+
+    out = left.join_asof(right, on="a", strategy=strategy, allow_exact_matches=False)
+    assert out["b"].to_list() == expected
+
+    out = left.join_asof(
+        right, on="a", by="g", strategy=strategy, allow_exact_matches=False
+    )
+    assert out["b"].to_list() == expected
+
+
+def test_join_asof_right_index_name() -> None:
+    trades = pl.DataFrame(
+        {"time": [1, 3, 5, 7], "ticker": ["a", "b", "a", "b"]}
+    ).set_sorted("time")
+    quotes = pl.DataFrame(
+        {
+            "time": [2, 2, 4, 6],
+            "ticker": ["b", "a", "a", "c"],
+            "bid": [1.0, 2.0, 3.0, 4.0],
+        }
+    ).set_sorted("time")
+
+    out = trades.join_asof(quotes, on="time", right_index_name="quote_idx")
+    assert out.columns == ["time", "ticker", "ticker_right", "bid", "quote_idx"]
+    assert out.schema["quote_idx"] == pl.UInt32
+    assert out["quote_idx"].to_list() == [None, 1, 2, 3]
+
+    out = trades.lazy().join_asof(
+        quotes.lazy(), on="time", by="ticker", right_index_name="quote_idx"
+    )
+    assert out.select("quote_idx").collect()["quote_idx"].to_list() == [
+        None,
+        0,
+        2,
+        0,
+    ]
+
+
+def test_join_asof_strategy_column() -> None:
+    trades = pl.DataFrame(
+        {
+            "time": [1, 2, 3, 4, 5],
+            "ticker": ["a", "b", "a", "b", "a"],
+            "direction": ["forward", None, "nearest", "backward", "forward"],
+        }
+    ).set_sorted("time")
+    quotes = pl.DataFrame(
+        {
+            "time": [2, 4, 4, 6],
+            "ticker": ["a", "b", "a", "a"],
+            "bid": [20, 40, 41, 60],
+        }
+    ).set_sorted("time")
+
+    out = trades.join_asof(quotes, on="time", strategy_column="direction")
+    assert out["bid"].to_list() == [20, 20, 41, 41, 60]
+
+    out = (
+        trades.lazy()
+        .join_asof(quotes.lazy(), on="time", by="ticker", strategy_column="direction")
+        .select("time", "bid")
+        .collect()
+    )
+    assert out["bid"].to_list() == [20, None, 41, 40, 60]
+
+    with pytest.raises(pl.InvalidOperationError, match="invalid asof join strategy"):
+        trades.with_columns(pl.lit("up").alias("direction")).join_asof(
+            quotes, on="time", strategy_column="direction"
+        )
@@ -1015,6 +1015,17 @@ def test_top_k_9385() -> None:
     ].to_list() == [False]
 
 
+@pytest.mark.parametrize("streaming", [False, True])
+def test_sort_slice_with_offset(streaming: bool) -> None:
+    lf = pl.LazyFrame({"a": [5, None, 3, 1, 4, 2, None], "b": list(range(7))})
+    df = lf.collect()
+
+    for offset, length in [(2, 3), (10, 2)]:
+        q = lf.sort("a", descending=True, nulls_last=True).slice(offset, length)
+        expected = df.sort("a", descending=True, nulls_last=True).slice(offset, length)
+        assert_frame_equal(q.collect(streaming=streaming), expected)
+
+
 def test_sorted_flag_partition_by() -> None:
     assert (
         pl.DataFrame({"one": [1, 2, 3], "two": ["a", "a", "b"]})
@@ -169,6 +169,28 @@ def test_with_columns_seq() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_with_columns_chained() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [10, 20, 30]})
+    result = df.with_columns_chained(
+        c=pl.col("a") * 2,
+        d=pl.col("c") + pl.col("b"),
+        a=pl.col("d") - 1,
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [11, 23, 35],
+            "b": [10, 20, 30],
+            "c": [2, 4, 6],
+            "d": [12, 24, 36],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    # `with_columns_seq` only sees the input columns.
+    with pytest.raises(pl.ColumnNotFoundError):
+        df.with_columns_seq(c=pl.col("a") * 2, d=pl.col("c") + 1)
+
+
 # https://github.com/pola-rs/polars/issues/15588
 def test_with_columns_invalid_type() -> None:
     lf = pl.LazyFrame({"a": [1, 2, 3]})
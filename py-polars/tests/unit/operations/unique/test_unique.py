@@ -154,3 +154,30 @@ def test_unique_with_null() -> None:
         {"a": [1, 2, 3, 4], "b": ["a", "b", "c", "c"], "c": [None, None, None, None]}
     )
     assert_frame_equal(df.unique(maintain_order=True), expected_df)
+
+
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_unique_keep_max_by_min_by(maintain_order: bool) -> None:
+    lf = pl.LazyFrame(
+        {
+            "id": [1, 1, 2, 2, 1, 3],
+            "updated": [date(2024, 1, d) for d in [3, 5, 1, 4, 2, 1]],
+            "value": ["a", "b", "c", "d", "e", "f"],
+        }
+    )
+
+    result = lf.unique(
+        "id", keep="max_by", by="updated", maintain_order=maintain_order
+    )
+    # The column to compare is kept by projection pushdown.
+    result = result.select("id", "value").sort("id").collect()
+    expected = {"id": [1, 2, 3], "value": ["b", "d", "f"]}
+    assert result.to_dict(as_series=False) == expected
+
+    result = lf.collect().unique(
+        "id", keep="min_by", by="updated", maintain_order=maintain_order
+    )
+    assert result.sort("id")["value"].to_list() == ["a", "c", "f"]
+
+    with pytest.raises(ValueError, match="requires the column"):
+        lf.unique("id", keep="max_by")
@@ -11,6 +11,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars.type_aliases import IpcDictionaryMode
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -98,6 +100,32 @@ def test_sink_ipc(io_files_path: Path, tmp_path: Path) -> None:
         df_read = pl.read_parquet(file)
         assert_frame_equal(result, df_read)
 
+    df_scanned.sink_ipc(file_path, compression="zstd", compression_level=19)
+    with pl.StringCache():
+        assert_frame_equal(pl.read_ipc(file_path), pl.read_parquet(file))
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("dictionary_mode", ["delta", "unify"])
+def test_sink_ipc_stream_categorical(
+    tmp_path: Path, dictionary_mode: IpcDictionaryMode
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    with pl.StringCache():
+        # every file is a batch with other categories
+        for i, values in enumerate([["a", "b"], ["c", "a"], ["b", "d"]]):
+            df = pl.DataFrame({"c": values}, schema={"c": pl.Categorical})
+            df.write_ipc(tmp_path / f"{i}.ipc")
+
+        file_path = tmp_path / "sink.arrows"
+        pl.scan_ipc(tmp_path / "*.ipc").sink_ipc(
+            file_path, stream=True, dictionary_mode=dictionary_mode
+        )
+        result = pl.read_ipc_stream(file_path)
+
+    expected = pl.DataFrame({"c": ["a", "b", "c", "a", "b", "d"]})
+    assert_frame_equal(result.cast(pl.String), expected)
+
 
 @pytest.mark.write_disk()
 def test_sink_csv(io_files_path: Path, tmp_path: Path) -> None:
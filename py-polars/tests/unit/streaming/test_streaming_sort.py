@@ -92,6 +92,38 @@ def test_ooc_sort(tmp_path: Path, monkeypatch: Any) -> None:
         assert_series_equal(out, s.sort(descending=descending))
 
 
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("compression", ["lz4", "zstd"])
+def test_ooc_sort_spill_compression(
+    tmp_path: Path, monkeypatch: Any, compression: str
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    s = pl.arange(0, 10_000, eager=True).rename("idx")
+    df = s.shuffle(seed=1).to_frame()
+
+    level = 5 if compression == "zstd" else None
+    lf = df.lazy().with_spill_options(
+        compression=compression,  # type: ignore[arg-type]
+        compression_level=level,
+        decompression_threads=2,
+    )
+    out = lf.sort("idx").collect(streaming=True).to_series()
+    assert_series_equal(out, s)
+
+
+def test_spill_options_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(ValueError, match="compression levels"):
+        lf.with_spill_options(compression="lz4", compression_level=3)
+    with pytest.raises(ValueError, match="1 to 22"):
+        lf.with_spill_options(compression="zstd", compression_level=23)
+    with pytest.raises(ValueError, match="must be positive"):
+        lf.with_spill_options(decompression_threads=0)
+
+
 @pytest.mark.debug()
 @pytest.mark.write_disk()
 @pytest.mark.parametrize("spill_source", [True, False])
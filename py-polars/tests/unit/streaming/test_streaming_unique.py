@@ -10,6 +10,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars.type_aliases import UniqueKeepStrategy
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -52,3 +54,68 @@ def test_streaming_unique(monkeypatch: Any, capfd: Any) -> None:
     assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))
     (_, err) = capfd.readouterr()
     assert "df -> re-project-sink -> sort_multiple" in err
+
+
+@pytest.mark.parametrize("keep", ["first", "last", "any"])
+def test_streaming_unique_sorted_keys(keep: UniqueKeepStrategy) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 1, 1, 2, 2, 3, 3, 3, 3],
+            "b": [1, 1, 2, 2, 2, 3, 3, 4, 4],
+            "row": range(9),
+        }
+    ).set_sorted("a")
+
+    q = df.lazy().unique(subset=["a", "b"], keep=keep).sort("a", "b")
+    result = q.collect(streaming=True)
+    if keep == "any":
+        assert_frame_equal(result.select("a", "b"), q.collect().select("a", "b"))
+    else:
+        assert_frame_equal(result, q.collect())
+
+    q = df.lazy().unique(keep=keep).sort("row")
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+
+
+@pytest.mark.parametrize("maintain_order", [False, True])
+@pytest.mark.parametrize("keep", ["first", "last", "none", "max_by", "min_by"])
+def test_streaming_unique_by_sorting(
+    keep: UniqueKeepStrategy, maintain_order: bool, monkeypatch: Any, capfd: Any
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    df = pl.DataFrame(
+        {
+            "a": [3, 1, 2, 1, 3, None, None, 4, 3],
+            "b": [1.0, 2.0, None, 4.0, 5.0, 6.0, 6.0, 8.0, 5.0],
+            "row": range(9),
+        }
+    )
+    by = "b" if keep in ("max_by", "min_by") else None
+    q = df.lazy().unique("a", keep=keep, maintain_order=maintain_order, by=by)
+    if not maintain_order:
+        q = q.sort("row")
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+    (_, err) = capfd.readouterr()
+    # Without an order, the first and last rows are found with the group-by.
+    if maintain_order or keep not in ("first", "last"):
+        assert "df -> distinct_by_sort" in err
+    else:
+        assert "df -> re-project-sink" in err
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("keep", ["first", "last", "none"])
+def test_streaming_unique_by_sorting_out_of_core(
+    keep: UniqueKeepStrategy, tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    df = pl.DataFrame({"a": [i % 1000 for i in range(10_000)], "row": range(10_000)})
+    df = df.filter(pl.col("row") % 7 != 0)
+
+    q = df.lazy().unique("a", keep=keep, maintain_order=True)
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+    (_, err) = capfd.readouterr()
+    assert "df -> distinct_by_sort" in err